@@ -12,11 +12,15 @@ use triton_vm::table::constraint_circuit::InputIndicator;
 use triton_vm::table::hash_table::ExtHashTable;
 use triton_vm::table::jump_stack_table::ExtJumpStackTable;
 use triton_vm::table::lookup_table::ExtLookupTable;
+use triton_vm::table::memory_table::ExtMemoryTable;
 use triton_vm::table::op_stack_table::ExtOpStackTable;
 use triton_vm::table::processor_table::ExtProcessorTable;
 use triton_vm::table::program_table::ExtProgramTable;
 use triton_vm::table::ram_table::ExtRamTable;
+use triton_vm::table::register_table::ExtRegisterTable;
+use triton_vm::table::tape_table::ExtTapeTable;
 use triton_vm::table::u32_table::ExtU32Table;
+use triton_vm::table::zmips_processor_table::ExtZmipsProcessorTable;
 
 fn main() {
     let (table_name_snake, table_name_camel) = construct_needed_table_identifiers(&["program"]);
@@ -119,6 +123,51 @@ fn main() {
     );
     write(&table_name_snake, source_code);
 
+    let (table_name_snake, table_name_camel) =
+        construct_needed_table_identifiers(&["zmips", "processor"]);
+    let source_code = gen(
+        &table_name_snake,
+        &table_name_camel,
+        &mut ExtZmipsProcessorTable::ext_initial_constraints_as_circuits(),
+        &mut ExtZmipsProcessorTable::ext_consistency_constraints_as_circuits(),
+        &mut ExtZmipsProcessorTable::ext_transition_constraints_as_circuits(),
+        &mut ExtZmipsProcessorTable::ext_terminal_constraints_as_circuits(),
+    );
+    write(&table_name_snake, source_code);
+
+    let (table_name_snake, table_name_camel) = construct_needed_table_identifiers(&["register"]);
+    let source_code = gen(
+        &table_name_snake,
+        &table_name_camel,
+        &mut ExtRegisterTable::ext_initial_constraints_as_circuits(),
+        &mut ExtRegisterTable::ext_consistency_constraints_as_circuits(),
+        &mut ExtRegisterTable::ext_transition_constraints_as_circuits(),
+        &mut ExtRegisterTable::ext_terminal_constraints_as_circuits(),
+    );
+    write(&table_name_snake, source_code);
+
+    let (table_name_snake, table_name_camel) = construct_needed_table_identifiers(&["tape"]);
+    let source_code = gen(
+        &table_name_snake,
+        &table_name_camel,
+        &mut ExtTapeTable::ext_initial_constraints_as_circuits(),
+        &mut ExtTapeTable::ext_consistency_constraints_as_circuits(),
+        &mut ExtTapeTable::ext_transition_constraints_as_circuits(),
+        &mut ExtTapeTable::ext_terminal_constraints_as_circuits(),
+    );
+    write(&table_name_snake, source_code);
+
+    let (table_name_snake, table_name_camel) = construct_needed_table_identifiers(&["memory"]);
+    let source_code = gen(
+        &table_name_snake,
+        &table_name_camel,
+        &mut ExtMemoryTable::ext_initial_constraints_as_circuits(),
+        &mut ExtMemoryTable::ext_consistency_constraints_as_circuits(),
+        &mut ExtMemoryTable::ext_transition_constraints_as_circuits(),
+        &mut ExtMemoryTable::ext_terminal_constraints_as_circuits(),
+    );
+    write(&table_name_snake, source_code);
+
     if let Err(fmt_failed) = Command::new("cargo").arg("fmt").output() {
         println!("cargo fmt failed: {fmt_failed}");
     }
@@ -0,0 +1,106 @@
+use triton_opcodes::instruction::AnInstruction::Call;
+use triton_opcodes::ord_n::Ord16;
+use triton_program::Machine;
+use triton_vm::machine::TritonMachine;
+use triton_vm::machine::ZmipsMachine;
+use triton_vm::vm::VMOutput;
+use triton_zmips::instruction::AnInstruction::JAL;
+use triton_zmips::instruction::AnInstruction::JALR;
+use triton_zmips::regs::Reg;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+
+/// A [`Machine`] the debugger can inspect.
+pub trait Debuggee<'pgm>: Machine<'pgm, Output = VMOutput> + Clone {
+    fn instruction_pointer(&self) -> usize;
+
+    fn cycle_count(&self) -> u64;
+
+    /// The address execution continues at once the current instruction returns, if the current
+    /// instruction is a subroutine call.
+    fn return_address(&self) -> Option<usize>;
+
+    /// The number of subroutine calls that have not returned yet, if the machine keeps track.
+    fn call_depth(&self) -> usize;
+
+    /// The content of the RAM at the given address.
+    fn ram(&self, address: BFieldElement) -> BFieldElement;
+
+    /// The content of the register with the given name, or `None` if no such register exists.
+    fn register(&self, name: &str) -> Option<BFieldElement>;
+
+    /// A human-readable rendering of the machine's state.
+    fn display(&self) -> String;
+}
+
+impl<'pgm> Debuggee<'pgm> for TritonMachine<'pgm> {
+    fn instruction_pointer(&self) -> usize {
+        self.state.instruction_pointer
+    }
+
+    fn cycle_count(&self) -> u64 {
+        self.state.cycle_count
+    }
+
+    fn return_address(&self) -> Option<usize> {
+        match self.state.current_instruction() {
+            Ok(instruction @ Call(_)) => Some(self.state.instruction_pointer + instruction.size()),
+            _ => None,
+        }
+    }
+
+    fn call_depth(&self) -> usize {
+        self.state.jump_stack.len()
+    }
+
+    fn ram(&self, address: BFieldElement) -> BFieldElement {
+        self.state.ram.get(&address).copied().unwrap_or_default()
+    }
+
+    /// The op-stack registers `st0` through `st15`.
+    fn register(&self, name: &str) -> Option<BFieldElement> {
+        let index: usize = name.strip_prefix("st")?.parse().ok()?;
+        let register = Ord16::try_from(index).ok()?;
+        Some(self.state.op_stack.safe_peek(register))
+    }
+
+    fn display(&self) -> String {
+        self.state.to_string()
+    }
+}
+
+impl<'pgm> Debuggee<'pgm> for ZmipsMachine<'pgm> {
+    fn instruction_pointer(&self) -> usize {
+        self.state.instruction_pointer
+    }
+
+    fn cycle_count(&self) -> u64 {
+        self.state.cycle_count
+    }
+
+    fn return_address(&self) -> Option<usize> {
+        match self.state.current_instruction() {
+            Ok(JAL(_) | JALR(_)) => Some(self.state.instruction_pointer + 1),
+            _ => None,
+        }
+    }
+
+    /// zMIPS keeps return addresses in general purpose registers, so calls cannot be tracked.
+    fn call_depth(&self) -> usize {
+        0
+    }
+
+    fn ram(&self, address: BFieldElement) -> BFieldElement {
+        self.state.ram.get(&address).copied().unwrap_or_default()
+    }
+
+    /// The general purpose registers, with or without prefix `$`, _e.g._, `$t0` or `t0`.
+    fn register(&self, name: &str) -> Option<BFieldElement> {
+        let register: Reg = name.parse().ok()?;
+        let index: usize = register.into();
+        Some(self.state.regs_pool.regs[index])
+    }
+
+    fn display(&self) -> String {
+        self.state.to_string()
+    }
+}
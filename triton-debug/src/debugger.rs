@@ -0,0 +1,541 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+use anyhow::anyhow;
+use anyhow::bail;
+use anyhow::Result;
+use itertools::Itertools;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+
+use triton_vm::vm::VMOutput::WriteOutputSymbol;
+
+use crate::debuggee::Debuggee;
+
+/// The number of steps that can be undone with `reverse-step`, unless configured otherwise.
+pub const DEFAULT_HISTORY_DEPTH: usize = 10_000;
+
+pub const HELP: &str = "\
+Commands:
+  break <label|address>    b   Stop whenever execution reaches the label or address.
+  delete <label|address>   d   Remove a breakpoint.
+  watch ram <address>      w   Stop whenever the RAM content at the address changes.
+  watch reg <register>     w   Stop whenever the register changes, e.g., `st0` or `$t0`.
+  unwatch <ram|reg> <x>        Remove a watchpoint.
+  step [n]                 s   Execute the next n instructions, 1 by default.
+  next                     n   Like `step`, but execute subroutine calls in their entirety.
+  continue                 c   Execute until a breakpoint, a watchpoint, or termination.
+  reverse-step [n]         rs  Undo the last n recorded steps, 1 by default.
+  print                    p   Print the machine's state.
+  ram <address>                Print the RAM content at the address.
+  reg <register>               Print the register's content.
+  output                   o   Print the output written so far.
+  info                     i   List all breakpoints and watchpoints.
+  help                     h   Print this help.
+  quit                     q   Exit the debugger.
+An empty line repeats the last command.";
+
+/// A location in RAM or a register the debugger watches for changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Watchpoint {
+    Ram(BFieldElement),
+    Register(String),
+}
+
+impl Display for Watchpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Watchpoint::Ram(address) => write!(f, "ram[{address}]"),
+            Watchpoint::Register(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Break(String),
+    Delete(String),
+    Watch(Watchpoint),
+    Unwatch(Watchpoint),
+    Step(usize),
+    Next,
+    Continue,
+    ReverseStep(usize),
+    Print,
+    Ram(BFieldElement),
+    Register(String),
+    Output,
+    Info,
+    Help,
+    Quit,
+}
+
+impl Command {
+    pub fn parse(line: &str) -> Result<Self> {
+        let words = line.split_whitespace().collect_vec();
+        let Some((&command, arguments)) = words.split_first() else {
+            bail!("No command given.");
+        };
+        let command = match (command, arguments) {
+            ("break" | "b", [location]) => Command::Break(location.to_string()),
+            ("delete" | "d", [location]) => Command::Delete(location.to_string()),
+            ("watch" | "w", [kind, location]) => Command::Watch(Self::watchpoint(kind, location)?),
+            ("unwatch", [kind, location]) => Command::Unwatch(Self::watchpoint(kind, location)?),
+            ("step" | "s", []) => Command::Step(1),
+            ("step" | "s", [n]) => Command::Step(n.parse()?),
+            ("next" | "n", []) => Command::Next,
+            ("continue" | "c", []) => Command::Continue,
+            ("reverse-step" | "rs", []) => Command::ReverseStep(1),
+            ("reverse-step" | "rs", [n]) => Command::ReverseStep(n.parse()?),
+            ("print" | "p", []) => Command::Print,
+            ("ram", [address]) => Command::Ram(Self::address(address)?),
+            ("reg", [register]) => Command::Register(register.to_string()),
+            ("output" | "o", []) => Command::Output,
+            ("info" | "i", []) => Command::Info,
+            ("help" | "h", []) => Command::Help,
+            ("quit" | "q", []) => Command::Quit,
+            _ => bail!("Unknown command or wrong number of arguments: `{line}`. Try `help`."),
+        };
+        Ok(command)
+    }
+
+    fn watchpoint(kind: &str, location: &str) -> Result<Watchpoint> {
+        match kind {
+            "ram" => Ok(Watchpoint::Ram(Self::address(location)?)),
+            "reg" => Ok(Watchpoint::Register(location.to_string())),
+            _ => bail!("Can only watch `ram` or `reg`, not `{kind}`."),
+        }
+    }
+
+    fn address(address: &str) -> Result<BFieldElement> {
+        let address = match address.strip_prefix("0x") {
+            Some(hex_address) => u64::from_str_radix(hex_address, 16)?,
+            None => address.parse()?,
+        };
+        Ok(BFieldElement::new(address))
+    }
+}
+
+/// The reason execution stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StopReason {
+    StepsCompleted,
+    Halted,
+    Breakpoint(usize),
+    Watchpoint(Watchpoint, BFieldElement, BFieldElement),
+    Failed(String),
+}
+
+impl Display for StopReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StopReason::StepsCompleted => Ok(()),
+            StopReason::Halted => write!(f, "Program halted."),
+            StopReason::Breakpoint(address) => {
+                write!(f, "Breakpoint reached at address {address}.")
+            }
+            StopReason::Watchpoint(watchpoint, old, new) => {
+                write!(f, "Watchpoint {watchpoint} changed from {old} to {new}.")
+            }
+            StopReason::Failed(err) => write!(f, "Execution failed: {err}"),
+        }
+    }
+}
+
+/// Executes a program step by step, recording the most recent states to allow stepping
+/// backwards.
+pub struct Debugger<'pgm, M: Debuggee<'pgm>> {
+    /// The most recent states the machine has been in, alongside the length of the output at that
+    /// point. The last entry is the current state. Once more than `history_depth` steps have been
+    /// recorded, the oldest state is dropped.
+    history: VecDeque<(M, usize)>,
+    history_depth: usize,
+    stdout: Vec<BFieldElement>,
+    labels: HashMap<String, usize>,
+    breakpoints: BTreeSet<usize>,
+    watchpoints: Vec<Watchpoint>,
+    _program: PhantomData<&'pgm ()>,
+}
+
+impl<'pgm, M: Debuggee<'pgm>> Debugger<'pgm, M> {
+    pub fn new(initial_state: M, labels: HashMap<String, usize>) -> Self {
+        Self {
+            history: VecDeque::from([(initial_state, 0)]),
+            history_depth: DEFAULT_HISTORY_DEPTH,
+            stdout: vec![],
+            labels,
+            breakpoints: BTreeSet::new(),
+            watchpoints: vec![],
+            _program: PhantomData,
+        }
+    }
+
+    /// Set the number of steps that can be undone with `reverse-step`. Each step keeps a copy of
+    /// the machine's state in memory.
+    pub fn with_history_depth(mut self, history_depth: usize) -> Self {
+        self.history_depth = history_depth;
+        self.truncate_history();
+        self
+    }
+
+    pub fn current_state(&self) -> &M {
+        let (current_state, _) = self.history.back().expect("history must not be empty");
+        current_state
+    }
+
+    pub fn output(&self) -> &[BFieldElement] {
+        &self.stdout
+    }
+
+    /// Execute the command and return the text to show the user.
+    pub fn execute(&mut self, command: &Command) -> Result<String> {
+        let response = match command {
+            Command::Break(location) => {
+                let address = self.resolve(location)?;
+                self.breakpoints.insert(address);
+                format!("Breakpoint set at address {address}.")
+            }
+            Command::Delete(location) => {
+                let address = self.resolve(location)?;
+                match self.breakpoints.remove(&address) {
+                    true => format!("Breakpoint at address {address} deleted."),
+                    false => format!("No breakpoint at address {address}."),
+                }
+            }
+            Command::Watch(watchpoint) => {
+                self.watch_value(watchpoint)?;
+                self.watchpoints.push(watchpoint.clone());
+                format!("Watching {watchpoint}.")
+            }
+            Command::Unwatch(watchpoint) => {
+                self.watchpoints.retain(|watched| watched != watchpoint);
+                format!("No longer watching {watchpoint}.")
+            }
+            Command::Step(num_steps) => self.step(*num_steps).to_string(),
+            Command::Next => self.next().to_string(),
+            Command::Continue => self.continue_execution().to_string(),
+            Command::ReverseStep(num_steps) => self.reverse_step(*num_steps),
+            Command::Print => self.current_state().display(),
+            Command::Ram(address) => format!("{}", self.current_state().ram(*address)),
+            Command::Register(name) => format!("{}", self.register(name)?),
+            Command::Output => self.output().iter().join(", "),
+            Command::Info => self.info(),
+            Command::Help => HELP.to_string(),
+            Command::Quit => String::new(),
+        };
+        Ok(response)
+    }
+
+    /// Resolve a label or an address, given in decimal or with prefix `0x`, to an address.
+    fn resolve(&self, location: &str) -> Result<usize> {
+        if let Some(&address) = self.labels.get(location) {
+            return Ok(address);
+        }
+        let address = Command::address(location)
+            .map_err(|_| anyhow!("`{location}` is neither a label nor an address."))?;
+        Ok(address.value() as usize)
+    }
+
+    fn register(&self, name: &str) -> Result<BFieldElement> {
+        self.current_state()
+            .register(name)
+            .ok_or_else(|| anyhow!("Unknown register `{name}`."))
+    }
+
+    fn watch_value(&self, watchpoint: &Watchpoint) -> Result<BFieldElement> {
+        match watchpoint {
+            Watchpoint::Ram(address) => Ok(self.current_state().ram(*address)),
+            Watchpoint::Register(name) => self.register(name),
+        }
+    }
+
+    fn info(&self) -> String {
+        let breakpoints = self.breakpoints.iter().map(|address| {
+            let labels = self
+                .labels
+                .iter()
+                .filter(|&(_, &label_address)| label_address == *address)
+                .map(|(label, _)| label)
+                .sorted()
+                .join(", ");
+            format!("breakpoint at {address} {labels}")
+                .trim_end()
+                .to_string()
+        });
+        let watchpoints = self
+            .watchpoints
+            .iter()
+            .map(|watchpoint| format!("watchpoint on {watchpoint}"));
+        breakpoints.chain(watchpoints).join("\n")
+    }
+
+    /// Execute a single instruction. Returns why execution must stop, if it must.
+    fn step_once(&mut self) -> Option<StopReason> {
+        if self.current_state().is_halting() {
+            return Some(StopReason::Halted);
+        }
+
+        let watched_values = self
+            .watchpoints
+            .iter()
+            .map(|watchpoint| self.watch_value(watchpoint).ok())
+            .collect_vec();
+        let mut next_state = self.current_state().clone();
+        match next_state.step() {
+            Err(err) => return Some(StopReason::Failed(err.to_string())),
+            Ok(Some(WriteOutputSymbol(written_word))) => self.stdout.push(written_word),
+            Ok(_) => (),
+        }
+        self.history.push_back((next_state, self.stdout.len()));
+        self.truncate_history();
+
+        if self.current_state().is_halting() {
+            return Some(StopReason::Halted);
+        }
+        for (watchpoint, old_value) in self.watchpoints.iter().zip_eq(watched_values) {
+            let new_value = self.watch_value(watchpoint).ok();
+            if let (Some(old_value), Some(new_value)) = (old_value, new_value) {
+                if old_value != new_value {
+                    let watchpoint = watchpoint.clone();
+                    return Some(StopReason::Watchpoint(watchpoint, old_value, new_value));
+                }
+            }
+        }
+        None
+    }
+
+    pub fn step(&mut self, num_steps: usize) -> StopReason {
+        for _ in 0..num_steps {
+            if let Some(stop_reason) = self.step_once() {
+                return stop_reason;
+            }
+        }
+        StopReason::StepsCompleted
+    }
+
+    /// Execute the next instruction. If it is a subroutine call, execute until the subroutine
+    /// returns, unless execution stops for a different reason first.
+    pub fn next(&mut self) -> StopReason {
+        let Some(return_address) = self.current_state().return_address() else {
+            return self.step(1);
+        };
+        let call_depth = self.current_state().call_depth();
+        loop {
+            if let Some(stop_reason) = self.step_once() {
+                return stop_reason;
+            }
+            let current_state = self.current_state();
+            let has_returned = current_state.instruction_pointer() == return_address
+                && current_state.call_depth() <= call_depth;
+            if has_returned {
+                return StopReason::StepsCompleted;
+            }
+            if self.is_at_breakpoint() {
+                return StopReason::Breakpoint(current_state.instruction_pointer());
+            }
+        }
+    }
+
+    /// Execute until reaching a breakpoint, a watchpoint triggers, or the program terminates.
+    pub fn continue_execution(&mut self) -> StopReason {
+        loop {
+            if let Some(stop_reason) = self.step_once() {
+                return stop_reason;
+            }
+            if self.is_at_breakpoint() {
+                return StopReason::Breakpoint(self.current_state().instruction_pointer());
+            }
+        }
+    }
+
+    fn is_at_breakpoint(&self) -> bool {
+        let instruction_pointer = self.current_state().instruction_pointer();
+        self.breakpoints.contains(&instruction_pointer)
+    }
+
+    /// Drop the oldest states until at most `history_depth` steps can be undone.
+    fn truncate_history(&mut self) {
+        while self.history.len() > self.history_depth + 1 {
+            self.history.pop_front();
+        }
+    }
+
+    /// Undo the last `num_steps` steps, but never go back further than the oldest recorded state.
+    pub fn reverse_step(&mut self, num_steps: usize) -> String {
+        let num_undoable_steps = self.history.len() - 1;
+        let num_steps = num_steps.min(num_undoable_steps);
+        self.history.truncate(self.history.len() - num_steps);
+        let (current_state, output_length) = self.history.back().unwrap();
+        self.stdout.truncate(*output_length);
+        format!(
+            "Went back {num_steps} step(s) to cycle {}.",
+            current_state.cycle_count()
+        )
+    }
+}
+
+#[cfg(test)]
+mod debugger_tests {
+    use triton_opcodes::instruction::label_map;
+    use triton_opcodes::parser::parse;
+    use triton_opcodes::parser::to_labelled;
+    use triton_opcodes::program::Program;
+    use triton_program::Machine;
+    use triton_program::PrecomputedNonDeterminism;
+    use triton_vm::machine::TritonMachine;
+    use triton_vm::machine::ZmipsMachine;
+
+    use super::*;
+
+    const CALL_PROGRAM: &str = "
+        push 3 call double write_io
+        push 1 push 42 write_mem pop
+        halt
+        double: dup 0 add return
+    ";
+
+    fn triton_program(code: &str) -> (Program, HashMap<String, usize>) {
+        let labelled_instructions = to_labelled(&parse(code).unwrap());
+        let labels = label_map(&labelled_instructions);
+        (Program::new(&labelled_instructions), labels)
+    }
+
+    #[test]
+    fn parse_commands_test() {
+        assert_eq!(Command::Step(1), Command::parse("s").unwrap());
+        assert_eq!(Command::Step(5), Command::parse("step 5").unwrap());
+        let watchpoint = Watchpoint::Ram(BFieldElement::new(16));
+        assert_eq!(
+            Command::Watch(watchpoint),
+            Command::parse("watch ram 0x10").unwrap()
+        );
+        assert!(Command::parse("step five").is_err());
+        assert!(Command::parse("frobnicate").is_err());
+        assert!(Command::parse("").is_err());
+    }
+
+    #[test]
+    fn breakpoint_on_label_test() {
+        let (program, labels) = triton_program(CALL_PROGRAM);
+        let machine = TritonMachine::new(
+            &program,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+        );
+        let mut debugger = Debugger::new(machine, labels.clone());
+
+        debugger.execute(&Command::Break("double".into())).unwrap();
+        let stop_reason = debugger.continue_execution();
+        assert_eq!(StopReason::Breakpoint(labels["double"]), stop_reason);
+        assert_eq!(StopReason::Halted, debugger.continue_execution());
+        assert_eq!(vec![BFieldElement::new(6)], debugger.output());
+    }
+
+    #[test]
+    fn next_steps_over_calls_test() {
+        let (program, labels) = triton_program(CALL_PROGRAM);
+        let machine = TritonMachine::new(
+            &program,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+        );
+        let mut debugger = Debugger::new(machine, labels);
+
+        debugger.step(1);
+        let call_address = debugger.current_state().instruction_pointer();
+        assert_eq!(StopReason::StepsCompleted, debugger.next());
+        assert_eq!(
+            call_address + 2,
+            debugger.current_state().instruction_pointer()
+        );
+        assert_eq!(0, debugger.current_state().call_depth());
+    }
+
+    #[test]
+    fn watchpoint_and_reverse_step_test() {
+        let (program, labels) = triton_program(CALL_PROGRAM);
+        let machine = TritonMachine::new(
+            &program,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+        );
+        let mut debugger = Debugger::new(machine, labels);
+
+        let watchpoint = Watchpoint::Ram(BFieldElement::new(1));
+        debugger
+            .execute(&Command::Watch(watchpoint.clone()))
+            .unwrap();
+        let stop_reason = debugger.continue_execution();
+        let expected =
+            StopReason::Watchpoint(watchpoint, BFieldElement::new(0), BFieldElement::new(42));
+        assert_eq!(expected, stop_reason);
+        assert_eq!(1, debugger.output().len());
+
+        let cycle_count = debugger.current_state().cycle_count();
+        debugger.reverse_step(5);
+        assert_eq!(cycle_count - 5, debugger.current_state().cycle_count());
+        assert!(debugger.output().is_empty());
+
+        debugger.reverse_step(100);
+        assert_eq!(0, debugger.current_state().cycle_count());
+    }
+
+    #[test]
+    fn history_depth_bounds_reverse_step_test() {
+        let (program, labels) = triton_program(CALL_PROGRAM);
+        let machine = TritonMachine::new(
+            &program,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+        );
+        let mut debugger = Debugger::new(machine, labels).with_history_depth(3);
+
+        debugger.execute(&Command::Step(5)).unwrap();
+        assert_eq!(5, debugger.current_state().cycle_count());
+        debugger.reverse_step(100);
+        assert_eq!(2, debugger.current_state().cycle_count());
+    }
+
+    #[test]
+    fn zmips_register_watchpoint_test() {
+        let code = "
+            li $t0, 1
+            li $t1, 2
+            li $t0, 3
+            exit $zero
+        ";
+        let labelled_instructions =
+            triton_zmips::parser::to_labelled(&triton_zmips::parser::parse(code).unwrap());
+        let labels = triton_zmips::instruction::label_map(&labelled_instructions).unwrap();
+        let program = triton_zmips::program::Program::new(&labelled_instructions).unwrap();
+        let machine = ZmipsMachine::new(
+            &program,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+        );
+        let mut debugger = Debugger::new(machine, labels);
+
+        let watchpoint = Watchpoint::Register("$t0".into());
+        debugger
+            .execute(&Command::Watch(watchpoint.clone()))
+            .unwrap();
+        let stop_reason = debugger.continue_execution();
+        let expected = StopReason::Watchpoint(
+            watchpoint.clone(),
+            BFieldElement::new(0),
+            BFieldElement::new(1),
+        );
+        assert_eq!(expected, stop_reason);
+
+        let stop_reason = debugger.continue_execution();
+        let expected =
+            StopReason::Watchpoint(watchpoint, BFieldElement::new(1), BFieldElement::new(3));
+        assert_eq!(expected, stop_reason);
+        assert_eq!(StopReason::Halted, debugger.continue_execution());
+
+        let unknown_register = Command::Watch(Watchpoint::Register("$t42".into()));
+        assert!(debugger.execute(&unknown_register).is_err());
+    }
+}
@@ -0,0 +1,215 @@
+//! An interactive stepping debugger for programs written in Triton assembly or zMIPS.
+//!
+//! Usage: `triton-debug <program> [--isa triton|zmips] [--input <file>] [--secret-input <file>]
+//! [--history <depth>]`
+//!
+//! Unless specified with `--isa`, the instruction set architecture is derived from the program's
+//! file extension: `.tasm` for Triton assembly, `.s` or `.asm` for zMIPS. Input files contain
+//! whitespace-separated integers. The debugger keeps the last `depth` states to step backwards,
+//! 10000 by default. Type `help` at the prompt for a list of commands.
+
+use std::fs;
+use std::io::BufRead;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use anyhow::bail;
+use anyhow::Result;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+
+use triton_program::Machine;
+use triton_program::PrecomputedNonDeterminism;
+use triton_vm::machine::TritonMachine;
+use triton_vm::machine::ZmipsMachine;
+
+use crate::debuggee::Debuggee;
+use crate::debugger::Command;
+use crate::debugger::Debugger;
+use crate::debugger::DEFAULT_HISTORY_DEPTH;
+
+mod debuggee;
+mod debugger;
+
+const PROMPT: &str = "(triton-debug) ";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SourceLanguage {
+    Triton,
+    Zmips,
+}
+
+impl SourceLanguage {
+    fn from_name(name: &str) -> Result<Self> {
+        match name {
+            "triton" | "tasm" => Ok(SourceLanguage::Triton),
+            "zmips" | "mips" => Ok(SourceLanguage::Zmips),
+            _ => bail!("Unknown instruction set architecture `{name}`."),
+        }
+    }
+
+    fn from_path(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("tasm") => Ok(SourceLanguage::Triton),
+            Some("s" | "asm") => Ok(SourceLanguage::Zmips),
+            _ => bail!("Cannot derive the instruction set architecture. Use `--isa`."),
+        }
+    }
+}
+
+struct Arguments {
+    program_path: PathBuf,
+    language: SourceLanguage,
+    public_input: Vec<BFieldElement>,
+    secret_input: Vec<BFieldElement>,
+    history_depth: usize,
+}
+
+impl Arguments {
+    fn parse(mut arguments: impl Iterator<Item = String>) -> Result<Self> {
+        let mut program_path = None;
+        let mut language = None;
+        let mut public_input = vec![];
+        let mut secret_input = vec![];
+        let mut history_depth = DEFAULT_HISTORY_DEPTH;
+        while let Some(argument) = arguments.next() {
+            let mut value = || {
+                arguments
+                    .next()
+                    .ok_or_else(|| anyhow!("Missing value for `{argument}`."))
+            };
+            match argument.as_str() {
+                "--isa" => language = Some(SourceLanguage::from_name(&value()?)?),
+                "--input" => public_input = read_input(Path::new(&value()?))?,
+                "--secret-input" => secret_input = read_input(Path::new(&value()?))?,
+                "--history" => {
+                    let depth = value()?;
+                    history_depth = depth
+                        .parse()
+                        .map_err(|_| anyhow!("History depth `{depth}` is not a number."))?;
+                }
+                _ if program_path.is_none() => program_path = Some(PathBuf::from(&argument)),
+                _ => bail!("Unexpected argument `{argument}`."),
+            }
+        }
+
+        let Some(program_path) = program_path else {
+            bail!(
+                "Usage: triton-debug <program> [--isa triton|zmips] [--input <file>] \
+                [--secret-input <file>] [--history <depth>]"
+            );
+        };
+        let language = match language {
+            Some(language) => language,
+            None => SourceLanguage::from_path(&program_path)?,
+        };
+        Ok(Self {
+            program_path,
+            language,
+            public_input,
+            secret_input,
+            history_depth,
+        })
+    }
+}
+
+/// Read whitespace-separated integers from the file at the given path.
+fn read_input(path: &Path) -> Result<Vec<BFieldElement>> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| anyhow!("Cannot read input file {}: {err}", path.display()))?;
+    content
+        .split_whitespace()
+        .map(|symbol| match symbol.parse::<u64>() {
+            Ok(value) if value <= BFieldElement::MAX => Ok(BFieldElement::new(value)),
+            _ => bail!("Input symbol `{symbol}` is not a canonical field element."),
+        })
+        .collect()
+}
+
+fn main() {
+    if let Err(err) = Arguments::parse(std::env::args().skip(1)).and_then(debug) {
+        eprintln!("{err}");
+        std::process::exit(1);
+    }
+}
+
+fn debug(arguments: Arguments) -> Result<()> {
+    let source_code = fs::read_to_string(&arguments.program_path).map_err(|err| {
+        let path = arguments.program_path.display();
+        anyhow!("Cannot read program {path}: {err}")
+    })?;
+    let file = arguments.program_path.display().to_string();
+    let public_input = arguments.public_input;
+    let non_determinism = Box::new(PrecomputedNonDeterminism::from(arguments.secret_input));
+    let history_depth = arguments.history_depth;
+
+    match arguments.language {
+        SourceLanguage::Triton => {
+            let parsed_program =
+                triton_opcodes::parser::parse(&source_code).map_err(|err| anyhow!("{err}"))?;
+            let labelled_instructions = triton_opcodes::parser::to_labelled(&parsed_program);
+            let labels = triton_opcodes::instruction::label_map(&labelled_instructions);
+            let mut program = triton_opcodes::program::Program::new(&labelled_instructions);
+            program.source_map =
+                triton_opcodes::parser::source_map(&source_code, &parsed_program).in_file(&file);
+            let machine = TritonMachine::new(&program, public_input, non_determinism);
+            repl(Debugger::new(machine, labels).with_history_depth(history_depth))
+        }
+        SourceLanguage::Zmips => {
+            let parsed_program =
+                triton_zmips::parser::parse(&source_code).map_err(|err| anyhow!("{err}"))?;
+            let labelled_instructions = triton_zmips::parser::to_labelled(&parsed_program);
+            let labels = triton_zmips::instruction::label_map(&labelled_instructions)?;
+            let mut program = triton_zmips::program::Program::new(&labelled_instructions)?;
+            program.source_map =
+                triton_zmips::parser::source_map(&source_code, &parsed_program).in_file(&file);
+            let machine = ZmipsMachine::new(&program, public_input, non_determinism);
+            repl(Debugger::new(machine, labels).with_history_depth(history_depth))
+        }
+    }
+}
+
+/// Read commands from the standard input until the user quits. An empty line repeats the last
+/// command.
+fn repl<'pgm, M: Debuggee<'pgm>>(mut debugger: Debugger<'pgm, M>) -> Result<()> {
+    println!("{}", debugger.current_state().display());
+
+    let mut last_command = None;
+    let mut lines = std::io::stdin().lock().lines();
+    loop {
+        print!("{PROMPT}");
+        std::io::stdout().flush()?;
+        let Some(line) = lines.next() else {
+            return Ok(());
+        };
+        let line = line?;
+
+        let command = match (line.trim().is_empty(), &last_command) {
+            (true, None) => continue,
+            (true, Some(last_command)) => Command::clone(last_command),
+            (false, _) => match Command::parse(&line) {
+                Ok(command) => command,
+                Err(err) => {
+                    println!("{err}");
+                    continue;
+                }
+            },
+        };
+        if command == Command::Quit {
+            return Ok(());
+        }
+
+        match debugger.execute(&command) {
+            Ok(response) if response.is_empty() => (),
+            Ok(response) => println!("{response}"),
+            Err(err) => println!("{err}"),
+        }
+        if let Command::Step(_) | Command::Next | Command::Continue | Command::ReverseStep(_) =
+            command
+        {
+            println!("{}", debugger.current_state().display());
+        }
+        last_command = Some(command);
+    }
+}
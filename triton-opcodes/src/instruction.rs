@@ -52,8 +52,26 @@ impl Display for LabelledInstruction {
     }
 }
 
-#[derive(Debug, DisplayMacro, Clone, Copy, PartialEq, Eq, Hash, EnumCountMacro)]
-pub enum DivinationHint {}
+/// A hint for instruction `divine`, written after the instruction name, _e.g._, `divine sqrt`.
+/// Instead of reading the divined element from the secret input, the VM computes it from the
+/// stack, which it leaves unchanged. Hints are not part of the program's encoding: the proof
+/// treats a hinted `divine` like any other `divine`, and the program must still check the
+/// divined element.
+#[derive(Debug, DisplayMacro, Clone, Copy, PartialEq, Eq, Hash, EnumCountMacro, EnumIter)]
+#[strum(serialize_all = "lowercase")]
+pub enum DivinationHint {
+    /// `_ d n` → `_ d n q`: the quotient `q` of the integer division of `n` by `d`, both of which
+    /// must be u32s.
+    Quotient,
+
+    /// `_ a` → `_ a b`: the multiplicative inverse `b` of `a`, which must not be 0.
+    Inverse,
+
+    /// `_ a` → `_ a b`: the square root `b` of `a` with the smaller canonical representation.
+    /// The element `a` must be a square. No AIR constraint forces the smaller root: a malicious
+    /// prover can divine `-b` instead, so a program relying on the choice must check it.
+    Sqrt,
+}
 
 /// A Triton VM instruction. See the
 /// [Instruction Set Architecture](https://triton-vm.org/spec/isa.html)
@@ -320,7 +338,7 @@ impl<Dest: Display + PartialEq + Default> Display for AnInstruction<Dest> {
         write!(f, "{}", self.name())?;
         match self {
             Push(arg) => write!(f, " {arg}"),
-            Divine(Some(hint)) => write!(f, "_{}", format!("{hint}").to_ascii_lowercase()),
+            Divine(Some(hint)) => write!(f, " {hint}"),
             Dup(arg) | Swap(arg) => write!(f, " {arg}"),
             Call(arg) => write!(f, " {arg}"),
             _ => Ok(()),
@@ -383,10 +401,21 @@ pub fn convert_labels(program: &[LabelledInstruction]) -> Vec<Instruction> {
     //     .iter()
     //     .flat_map(|x| x.as_any().downcast_ref())
     //     .collect::<Vec<_>>();
+    // 1. Add all labels to a map
+    let label_map = label_map(program);
+
+    // 2. Convert every label to the lookup value of that map
+    program
+        .iter()
+        .flat_map(|labelled_instruction| convert_labels_helper(labelled_instruction, &label_map))
+        .collect()
+}
+
+/// The absolute address of every label in the program.
+pub fn label_map(program: &[LabelledInstruction]) -> HashMap<String, usize> {
     let mut label_map = HashMap::<String, usize>::new();
     let mut instruction_pointer: usize = 0;
 
-    // 1. Add all labels to a map
     for labelled_instruction in program.iter() {
         match labelled_instruction {
             LabelledInstruction::Label(label_name) => {
@@ -399,11 +428,7 @@ pub fn convert_labels(program: &[LabelledInstruction]) -> Vec<Instruction> {
         }
     }
 
-    // 2. Convert every label to the lookup value of that map
-    program
-        .iter()
-        .flat_map(|labelled_instruction| convert_labels_helper(labelled_instruction, &label_map))
-        .collect()
+    label_map
 }
 
 fn convert_labels_helper(
@@ -11,6 +11,7 @@ use nom::combinator::cut;
 use nom::combinator::eof;
 use nom::combinator::fail;
 use nom::combinator::opt;
+use nom::combinator::value;
 use nom::error::context;
 use nom::error::convert_error;
 use nom::error::ErrorKind;
@@ -20,10 +21,14 @@ use nom::multi::many0;
 use nom::multi::many1;
 use nom::Finish;
 use nom::IResult;
+use triton_program::line_column;
+use triton_program::SourceLocation;
+use triton_program::SourceMap;
 use twenty_first::shared_math::b_field_element::BFieldElement;
 
 use crate::instruction::AnInstruction;
 use crate::instruction::AnInstruction::*;
+use crate::instruction::DivinationHint;
 use crate::instruction::LabelledInstruction;
 use crate::instruction::ALL_INSTRUCTION_NAMES;
 use crate::ord_n::Ord16;
@@ -83,6 +88,31 @@ pub fn to_labelled(instructions: &[ParsedInstruction]) -> Vec<LabelledInstructio
         .collect()
 }
 
+/// The [`SourceMap`] of the program parsed from `input`. Only the first address of an
+/// instruction taking up two words is mapped.
+pub fn source_map(input: &str, instructions: &[ParsedInstruction]) -> SourceMap {
+    let mut source_map = SourceMap::default();
+    let mut address = 0;
+    let mut label = None;
+    for instruction in instructions {
+        match instruction {
+            ParsedInstruction::Instruction(instr, token_str) => {
+                let (line, column) = line_column(input, token_str);
+                let location = SourceLocation {
+                    file: None,
+                    line,
+                    column,
+                    label: label.clone(),
+                };
+                source_map.insert(address, location);
+                address += instr.size();
+            }
+            ParsedInstruction::Label(label_name, _) => label = Some(label_name.to_owned()),
+        }
+    }
+    source_map
+}
+
 /// Pretty-print a parse error
 ///
 /// This function wraps `convert_error()`.
@@ -206,7 +236,7 @@ fn an_instruction(s: &str) -> ParseResult<AnInstruction<String>> {
     // OpStack manipulation
     let pop = instruction("pop", Pop);
     let push = push_instruction();
-    let divine = instruction("divine", Divine(None));
+    let divine = divine_instruction();
     let dup = dup_instruction();
     let swap = swap_instruction();
 
@@ -304,6 +334,26 @@ fn instruction<'a>(
     }
 }
 
+fn divine_instruction() -> impl Fn(&str) -> ParseResult<AnInstruction<String>> {
+    move |s: &str| {
+        let (s, _) = token1("divine")(s)?; // require space after instruction name
+        let (s, hint) = opt(divination_hint)(s)?;
+
+        Ok((s, Divine(hint)))
+    }
+}
+
+fn divination_hint(s: &str) -> ParseResult<DivinationHint> {
+    let (s, hint) = alt((
+        value(DivinationHint::Quotient, tag("quotient")),
+        value(DivinationHint::Inverse, tag("inverse")),
+        value(DivinationHint::Sqrt, tag("sqrt")),
+    ))(s)?;
+    let (s, _) = comment_or_whitespace1(s)?; // require space after hint
+
+    Ok((s, hint))
+}
+
 fn push_instruction() -> impl Fn(&str) -> ParseResult<AnInstruction<String>> {
     move |s: &str| {
         let (s, _) = token1("push")(s)?; // require space after instruction name
@@ -895,6 +945,50 @@ mod parser_tests {
         })
     }
 
+    #[test]
+    fn parse_program_divination_hints_test() {
+        parse_program_prop(TestCase {
+            input: "divine divine quotient divine inverse divine sqrt",
+            expected: Program::new(&[
+                Instruction(Divine(None)),
+                Instruction(Divine(Some(DivinationHint::Quotient))),
+                Instruction(Divine(Some(DivinationHint::Inverse))),
+                Instruction(Divine(Some(DivinationHint::Sqrt))),
+            ]),
+            message: "divination hints follow instruction `divine`",
+        });
+
+        parse_program_prop(TestCase {
+            input: "divine sqrt: call sqrt",
+            expected: Program::new(&[
+                Instruction(Divine(None)),
+                Label("sqrt".to_string()),
+                Instruction(Call("sqrt".to_string())),
+            ]),
+            message: "labels may be named like divination hints",
+        });
+    }
+
+    #[test]
+    fn source_map_test() {
+        let code = "push 1\nloop:\n  dup 0 call loop\nhalt";
+        let source_map = Program::from_code(code).unwrap().source_map;
+
+        let location = |line, column, label: Option<&str>| SourceLocation {
+            file: None,
+            line,
+            column,
+            label: label.map(|label| label.to_string()),
+        };
+        assert_eq!(Some(&location(1, 1, None)), source_map.get(0));
+        assert_eq!(None, source_map.get(1));
+        assert_eq!(Some(&location(3, 3, Some("loop"))), source_map.get(2));
+        assert_eq!(None, source_map.get(3));
+        assert_eq!(Some(&location(3, 9, Some("loop"))), source_map.get(4));
+        assert_eq!(None, source_map.get(5));
+        assert_eq!(Some(&location(4, 1, Some("loop"))), source_map.get(6));
+    }
+
     #[test]
     fn parse_program_test() {
         for size in 0..100 {
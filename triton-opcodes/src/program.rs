@@ -4,7 +4,9 @@ use std::io::Cursor;
 
 use anyhow::Result;
 use itertools::Itertools;
-use triton_program::{AbstractInstruction, AbstractLabelledInstruction, AbstractProgram, FromCode};
+use triton_program::{
+    AbstractInstruction, AbstractLabelledInstruction, AbstractProgram, FromCode, Isa, SourceMap,
+};
 use twenty_first::shared_math::b_field_element::BFieldElement;
 use twenty_first::util_types::algebraic_hasher::Hashable;
 
@@ -12,13 +14,28 @@ use crate::instruction::convert_labels;
 use crate::instruction::Instruction;
 use crate::instruction::LabelledInstruction;
 use crate::parser::parse;
+use crate::parser::source_map;
 use crate::parser::to_labelled;
 
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Default)]
 pub struct Program {
     pub instructions: Vec<Instruction>,
+
+    /// The locations in the source code the instructions originate from. Empty unless the
+    /// program was parsed from source code.
+    pub source_map: SourceMap,
+}
+
+/// The source map is not part of a program's identity: programs parsed from differently
+/// formatted source code are equal if their instructions are.
+impl PartialEq for Program {
+    fn eq(&self, other: &Self) -> bool {
+        self.instructions == other.instructions
+    }
 }
 
+impl Eq for Program {}
+
 impl Display for Program {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut stream = self.instructions.iter();
@@ -82,10 +99,16 @@ impl Program {
             .flat_map(|instr| vec![*instr; instr.size()])
             .collect::<Vec<_>>();
 
-        Program { instructions }
+        let source_map = SourceMap::default();
+
+        Program {
+            instructions,
+            source_map,
+        }
     }
 
-    /// Create a `Program` by parsing source code.
+    /// Create a `Program` by parsing source code. The program's [`SourceMap`] refers to the
+    /// given source code.
     pub fn from_code(code: &str) -> Result<Self> {
         // parse(code)
         //     .map(|program| {
@@ -97,9 +120,10 @@ impl Program {
         //         )
         //     })
         //     .map_err(|err| anyhow::anyhow!("{}", err))
-        parse(code)
-            .map(|program| Program::new(&to_labelled(&program)))
-            .map_err(|err| anyhow::anyhow!("{}", err))
+        let parsed_program = parse(code).map_err(|err| anyhow::anyhow!("{}", err))?;
+        let mut program = Program::new(&to_labelled(&parsed_program));
+        program.source_map = source_map(code, &parsed_program);
+        Ok(program)
     }
 
     /// Convert a `Program` to a `Vec<BFieldElement>`.
@@ -138,6 +162,17 @@ impl AbstractInstruction for Instruction {
     }
 }
 
+/// Triton VM's native, stack-based instruction set architecture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TritonIsa;
+
+impl Isa for TritonIsa {
+    const NAME: &'static str = "Triton assembly";
+
+    type Instruction = Instruction;
+    type Program = Program;
+}
+
 impl AbstractProgram for Program {
     fn to_bwords(&self) -> Vec<BFieldElement> {
         self.to_bwords()
@@ -162,6 +197,10 @@ impl AbstractProgram for Program {
         self.to_sequence()
     }
 
+    fn data_bwords(&self) -> Vec<BFieldElement> {
+        vec![]
+    }
+
     fn clone_(&self) -> Box<dyn AbstractProgram> {
         Box::new(self.clone()) as Box<dyn AbstractProgram>
     }
@@ -169,6 +208,10 @@ impl AbstractProgram for Program {
     fn as_any(&self) -> &dyn Any {
         self as &dyn Any
     }
+
+    fn source_map(&self) -> &SourceMap {
+        &self.source_map
+    }
 }
 
 impl FromCode for Program {
@@ -179,13 +222,13 @@ impl FromCode for Program {
         Program::from_code(code).map(|x| Box::new(x) as Box<dyn AbstractProgram>)
     }
 
-    fn create(input: &[Box<dyn AbstractLabelledInstruction>]) -> Box<dyn AbstractProgram> {
+    fn create(input: &[Box<dyn AbstractLabelledInstruction>]) -> Result<Box<dyn AbstractProgram>> {
         let input = input
             .iter()
             .map(|x| x.as_any().downcast_ref::<LabelledInstruction>().unwrap())
             .cloned()
             .collect_vec();
-        Box::new(Program::new(input.as_slice())) as Box<dyn AbstractProgram>
+        Ok(Box::new(Program::new(input.as_slice())) as Box<dyn AbstractProgram>)
     }
 
     // fn create(input: &[LabelledInstruction]) -> Box<dyn AbstractProgram> {
@@ -1,7 +1,11 @@
-use anyhow::*;
+use anyhow::Result;
 use std::any::Any;
-use std::fmt::{Debug, Formatter};
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter};
 use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::tip5::Digest;
 use twenty_first::util_types::algebraic_hasher::Hashable;
 
 pub trait AbstractInstruction {
@@ -17,15 +21,212 @@ pub trait AbstractProgram {
     fn is_empty(&self) -> bool;
     fn get_instructions(&self) -> Vec<Box<dyn AbstractInstruction>>;
     fn to_sequence_(&self) -> Vec<BFieldElement>;
+
+    /// The initial content of the program's data segment, one word per element. Empty for
+    /// programs without a data segment.
+    fn data_bwords(&self) -> Vec<BFieldElement>;
+
     fn clone_(&self) -> Box<dyn AbstractProgram>;
     fn as_any(&self) -> &dyn Any;
+
+    /// The locations in the source code the program's instructions originate from.
+    fn source_map(&self) -> &SourceMap;
+}
+
+/// An instruction set architecture, _i.e._, the kind of programs a [`Machine`] can execute.
+pub trait Isa {
+    /// The human-readable name of the instruction set architecture.
+    const NAME: &'static str;
+
+    type Instruction: Debug + Clone + PartialEq;
+    type Program: AbstractProgram + FromCode + 'static;
+}
+
+/// The secret input of a program: everything the prover knows that the verifier doesn't. A
+/// program can request secret input in the form of individual tokens, _e.g._, using Triton
+/// assembly's `divine` or zMIPS's `secread`, or in the form of digests, using Triton assembly's
+/// `divine_sibling`. Additionally, the RAM may be initialized non-deterministically.
+///
+/// Secret input is requested one token or digest at a time, allowing implementations to compute
+/// secrets lazily instead of precomputing all of them. See [`PrecomputedNonDeterminism`] for an
+/// implementation that does precompute all secrets.
+pub trait NonDeterminism {
+    /// The next individual token of secret input, or `None` if there is none.
+    fn next_token(&mut self) -> Option<BFieldElement>;
+
+    /// The next digest of secret input, or `None` if there is none.
+    fn next_digest(&mut self) -> Option<Digest>;
+
+    /// The content of the RAM before execution begins. Addresses not contained in the map are
+    /// initialized to 0.
+    ///
+    /// Triton VM's RAM Table requires every RAM address to be written to before a non-zero value
+    /// can be read from it. Proofs of Triton assembly programs that read non-deterministically
+    /// initialized RAM before writing to it therefore don't verify.
+    fn initial_ram(&self) -> HashMap<BFieldElement, BFieldElement>;
+
+    fn clone_(&self) -> Box<dyn NonDeterminism>;
+}
+
+/// Secret input that is known in its entirety before execution begins.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PrecomputedNonDeterminism {
+    pub individual_tokens: VecDeque<BFieldElement>,
+    pub digests: VecDeque<Digest>,
+    pub ram: HashMap<BFieldElement, BFieldElement>,
+}
+
+impl PrecomputedNonDeterminism {
+    pub fn new(
+        individual_tokens: Vec<BFieldElement>,
+        digests: Vec<Digest>,
+        ram: HashMap<BFieldElement, BFieldElement>,
+    ) -> Self {
+        Self {
+            individual_tokens: individual_tokens.into(),
+            digests: digests.into(),
+            ram,
+        }
+    }
+}
+
+impl From<Vec<BFieldElement>> for PrecomputedNonDeterminism {
+    /// Secret input consisting of individual tokens only.
+    fn from(individual_tokens: Vec<BFieldElement>) -> Self {
+        Self::new(individual_tokens, vec![], HashMap::new())
+    }
+}
+
+impl NonDeterminism for PrecomputedNonDeterminism {
+    fn next_token(&mut self) -> Option<BFieldElement> {
+        self.individual_tokens.pop_front()
+    }
+
+    fn next_digest(&mut self) -> Option<Digest> {
+        self.digests.pop_front()
+    }
+
+    fn initial_ram(&self) -> HashMap<BFieldElement, BFieldElement> {
+        self.ram.clone()
+    }
+
+    fn clone_(&self) -> Box<dyn NonDeterminism> {
+        Box::new(self.clone())
+    }
+}
+
+/// A machine executing programs of some [`Isa`]. The machine's state includes the program's
+/// public input and its [secret input](NonDeterminism). While executing, the machine can record
+/// its steps in a `Trace`, the witness required for proving correct execution.
+pub trait Machine<'pgm>: Sized {
+    type Isa: Isa;
+
+    /// The execution trace the machine records its steps in.
+    type Trace;
+
+    /// Everything a step produces in addition to the state transition, _e.g._, public output.
+    type Output;
+
+    /// The initial state for executing the given program on the given input.
+    fn new(
+        program: &'pgm <Self::Isa as Isa>::Program,
+        public_input: Vec<BFieldElement>,
+        non_determinism: Box<dyn NonDeterminism>,
+    ) -> Self;
+
+    /// Whether the machine has terminated gracefully.
+    fn is_halting(&self) -> bool;
+
+    fn current_instruction(&self) -> Result<<Self::Isa as Isa>::Instruction>;
+
+    /// Execute the current instruction.
+    fn step(&mut self) -> Result<Option<Self::Output>>;
+
+    /// Record everything in the trace that precedes the machine's first step, for example the
+    /// rows of tables the machine does not use.
+    fn initialize_trace(&self, trace: &mut Self::Trace);
+
+    /// Execute the current instruction and record the rows it gives rise to in the trace. On
+    /// failure, the rows up to the point of failure are recorded.
+    fn record_step(&mut self, trace: &mut Self::Trace) -> Result<Option<Self::Output>>;
+}
+
+/// The location in the source code an instruction originates from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    /// The file containing the source code, if known.
+    pub file: Option<String>,
+
+    /// The line, starting at 1.
+    pub line: usize,
+
+    /// The column, starting at 1.
+    pub column: usize,
+
+    /// The label most recently defined before the instruction, if any.
+    pub label: Option<String>,
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{file}:")?;
+        }
+        write!(f, "{}:{}", self.line, self.column)?;
+        if let Some(label) = &self.label {
+            write!(f, " in `{label}`")?;
+        }
+        Ok(())
+    }
+}
+
+/// Maps the addresses of a program's instructions to the [`SourceLocation`]s they originate
+/// from. Programs that were not parsed from source code, _e.g._, programs decoded from words,
+/// have an empty source map.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    locations: BTreeMap<usize, SourceLocation>,
+}
+
+impl SourceMap {
+    pub fn insert(&mut self, address: usize, location: SourceLocation) {
+        self.locations.insert(address, location);
+    }
+
+    /// The location of the instruction at the given address, if known.
+    pub fn get(&self, address: usize) -> Option<&SourceLocation> {
+        self.locations.get(&address)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.locations.is_empty()
+    }
+
+    /// Attribute all locations to the given file.
+    pub fn in_file(mut self, file: &str) -> Self {
+        for location in self.locations.values_mut() {
+            location.file = Some(file.to_string());
+        }
+        self
+    }
+}
+
+/// The line and column, both starting at 1, at which `substring` starts in `input`. The
+/// `substring` must be a slice of `input`, like the remaining input of a parser.
+pub fn line_column(input: &str, substring: &str) -> (usize, usize) {
+    let offset = substring.as_ptr() as usize - input.as_ptr() as usize;
+    let preceding = &input[..offset.min(input.len())];
+    let line = preceding.matches('\n').count() + 1;
+    let line_start = preceding.rfind('\n').map_or(0, |newline| newline + 1);
+    let column = preceding[line_start..].chars().count() + 1;
+    (line, column)
 }
 
 pub trait FromCode {
     fn from_code(code: &str) -> Result<Box<dyn AbstractProgram>>
     where
         Self: Sized;
-    fn create(input: &[Box<dyn AbstractLabelledInstruction>]) -> Box<dyn AbstractProgram>;
+    fn create(input: &[Box<dyn AbstractLabelledInstruction>]) -> Result<Box<dyn AbstractProgram>>;
 }
 
 impl Clone for Box<dyn AbstractProgram> {
@@ -50,6 +251,18 @@ impl Hashable for Box<dyn AbstractProgram> {
     }
 }
 
+impl Clone for Box<dyn NonDeterminism> {
+    fn clone(&self) -> Self {
+        self.clone_()
+    }
+}
+
+impl Debug for dyn NonDeterminism {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NonDeterminism {{ }}")
+    }
+}
+
 impl Debug for dyn AbstractInstruction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "Instruction {{ }}")
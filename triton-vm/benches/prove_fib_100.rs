@@ -12,11 +12,13 @@ use triton_profiler::prof_stop;
 use triton_profiler::triton_profiler::Report;
 use triton_profiler::triton_profiler::TritonProfiler;
 use triton_program::AbstractProgram;
+use triton_program::PrecomputedNonDeterminism;
 use triton_vm::proof::Claim;
 use triton_vm::shared_tests::FIBONACCI_SEQUENCE;
 use triton_vm::stark::Stark;
 use triton_vm::table::master_table::MasterBaseTable;
 use triton_vm::vm::simulate;
+use triton_vm::vm::ExecutionBudget;
 use triton_vm::StarkParameters;
 
 /// cargo criterion --bench prove_fib_100
@@ -39,7 +41,12 @@ fn prove_fib_100(criterion: &mut Criterion) {
     let input = vec![100];
     let public_input = input.iter().map(|&e| BFieldElement::new(e)).collect();
     prof_start!(maybe_profiler, "generate AET");
-    let (aet, output, err) = simulate(program.clone(), public_input, vec![]);
+    let (aet, output, err) = simulate(
+        program.clone(),
+        public_input,
+        Box::new(PrecomputedNonDeterminism::default()),
+        ExecutionBudget::default(),
+    );
     prof_stop!(maybe_profiler, "generate AET");
     if let Some(error) = err {
         panic!("The VM encountered the following problem: {error}");
@@ -52,6 +59,8 @@ fn prove_fib_100(criterion: &mut Criterion) {
         program_digest: Tip5::hash(&program),
         output,
         padded_height,
+        exit_code: aet.exit_code.value(),
+        final_answer: aet.final_answer.map(|answer| answer.value()),
     };
     let parameters = StarkParameters::default();
     let _proof = Stark::prove(&parameters, &claim, &aet, &mut maybe_profiler);
@@ -9,12 +9,14 @@ use triton_profiler::prof_start;
 use triton_profiler::prof_stop;
 use triton_profiler::triton_profiler::Report;
 use triton_profiler::triton_profiler::TritonProfiler;
+use triton_program::PrecomputedNonDeterminism;
 use triton_vm::proof::Claim;
 use triton_vm::shared_tests::save_proof;
 use triton_vm::stark::Stark;
 use triton_vm::stark::StarkParameters;
 use triton_vm::table::master_table::MasterBaseTable;
 use triton_vm::vm::simulate;
+use triton_vm::vm::ExecutionBudget;
 
 /// cargo criterion --bench prove_halt
 fn prove_halt(_criterion: &mut Criterion) {
@@ -31,7 +33,12 @@ fn prove_halt(_criterion: &mut Criterion) {
 
     // witness
     prof_start!(maybe_profiler, "generate AET");
-    let (aet, output, err) = simulate(Box::new(program), vec![], vec![]);
+    let (aet, output, err) = simulate(
+        Box::new(program.clone()),
+        vec![],
+        Box::new(PrecomputedNonDeterminism::default()),
+        ExecutionBudget::default(),
+    );
     prof_stop!(maybe_profiler, "generate AET");
     if let Some(error) = err {
         panic!("The VM encountered the following problem: {error}");
@@ -45,6 +52,8 @@ fn prove_halt(_criterion: &mut Criterion) {
         program_digest: Tip5::hash(&program),
         output,
         padded_height,
+        exit_code: aet.exit_code.value(),
+        final_answer: aet.final_answer.map(|answer| answer.value()),
     };
     let parameters = StarkParameters::default();
     let proof = Stark::prove(&parameters, &claim, &aet, &mut maybe_profiler);
@@ -4,6 +4,7 @@ use criterion::BenchmarkId;
 use criterion::Criterion;
 use triton_opcodes::program::Program;
 use triton_profiler::triton_profiler::TritonProfiler;
+use triton_program::PrecomputedNonDeterminism;
 use twenty_first::shared_math::tip5::Tip5;
 use twenty_first::util_types::algebraic_hasher::AlgebraicHasher;
 
@@ -14,6 +15,7 @@ use triton_vm::shared_tests::save_proof;
 use triton_vm::stark::Stark;
 use triton_vm::stark::StarkParameters;
 use triton_vm::vm::simulate;
+use triton_vm::vm::ExecutionBudget;
 
 /// cargo criterion --bench verify_halt
 fn verify_halt(criterion: &mut Criterion) {
@@ -27,6 +29,8 @@ fn verify_halt(criterion: &mut Criterion) {
         program_digest: Tip5::hash(&program),
         output: vec![],
         padded_height: 256, // domain-specific knowledge
+        exit_code: 0,
+        final_answer: None,
     };
 
     let filename = "halt.tsp";
@@ -37,7 +41,12 @@ fn verify_halt(criterion: &mut Criterion) {
             .map_err(|e| panic!("Could not load proof from disk: {e:?}"))
             .unwrap()
     } else {
-        let (aet, _, err) = simulate(Box::new(program), vec![], vec![]);
+        let (aet, _, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
         if let Some(error) = err {
             panic!("The VM encountered the following problem: {error}");
         }
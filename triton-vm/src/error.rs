@@ -3,6 +3,7 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 
 use anyhow::Result;
+use triton_program::SourceLocation;
 use twenty_first::shared_math::b_field_element::BFieldElement;
 
 use InstructionError::*;
@@ -13,14 +14,22 @@ pub enum InstructionError {
     InstructionPointerOverflow(usize),
     OpStackTooShallow,
     JumpStackTooShallow,
-    AssertionFailed(usize, u32, BFieldElement),
+    AssertionFailed(BFieldElement),
     InverseOfZero,
     DivisionByZero,
+    NotASquare(BFieldElement),
     LogarithmOfZero,
     RunawayInstructionArg,
     UngracefulTermination,
     FailedU32Conversion(BFieldElement),
-    ExecuteReturnFailureValue(BFieldElement)
+    InputTapeExhausted(usize),
+    IntegerOverflow,
+    UnknownSyscall(u32),
+    UnknownProgramType,
+    CycleLimitExceeded(u64),
+    PaddedHeightLimitExceeded(usize),
+    SecretInputExhausted,
+    SecretDigestsExhausted,
 }
 
 impl Display for InstructionError {
@@ -42,11 +51,8 @@ impl Display for InstructionError {
                 write!(f, "Jump stack does not contain return address")
             }
 
-            AssertionFailed(ip, clk, st0) => {
-                write!(
-                    f,
-                    "Assertion failed: st0 must be 1. ip: {ip}, clk: {clk}, st0: {st0}"
-                )
+            AssertionFailed(st0) => {
+                write!(f, "Assertion failed: st0 must be 1 but is {st0}")
             }
 
             InverseOfZero => {
@@ -57,6 +63,10 @@ impl Display for InstructionError {
                 write!(f, "Division by 0 is impossible")
             }
 
+            NotASquare(elem) => {
+                write!(f, "{elem} does not have a square root")
+            }
+
             LogarithmOfZero => {
                 write!(f, "The logarithm of 0 does not exist")
             }
@@ -82,12 +92,132 @@ impl Display for InstructionError {
                     word.value()
                 )
             }
+
+            InputTapeExhausted(position) => {
+                write!(f, "Input tape has no symbol at position {position}")
+            }
+
+            IntegerOverflow => {
+                write!(f, "Signed 32-bit integer arithmetic overflowed")
+            }
+
+            UnknownSyscall(code) => {
+                write!(f, "No syscall with code {code} exists")
+            }
+
+            UnknownProgramType => {
+                write!(
+                    f,
+                    "The program does not belong to any supported instruction set architecture"
+                )
+            }
+
+            CycleLimitExceeded(cycle_limit) => {
+                write!(
+                    f,
+                    "The program did not terminate within {cycle_limit} cycles"
+                )
+            }
+
+            PaddedHeightLimitExceeded(max_padded_height) => {
+                write!(
+                    f,
+                    "The execution trace's padded height exceeds {max_padded_height}"
+                )
+            }
+
+            SecretInputExhausted => {
+                write!(f, "Secret input has no more individual tokens")
+            }
+
+            SecretDigestsExhausted => {
+                write!(f, "Secret input has no more digests")
+            }
         }
     }
 }
 
 impl Error for InstructionError {}
 
+/// An entry of the jump stack: a call of the instruction at address `callee`, which returns to
+/// address `return_address`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallFrame {
+    pub callee: usize,
+    pub return_address: usize,
+
+    /// The label of the callee, if the program's [`SourceMap`](triton_program::SourceMap) knows
+    /// it.
+    pub callee_label: Option<String>,
+}
+
+/// An [`InstructionError`] together with a report of the virtual machine's state at the time the
+/// error occurred.
+#[derive(Debug, Clone)]
+pub struct VmError {
+    pub source: InstructionError,
+
+    /// The address of the failing instruction.
+    pub instruction_pointer: usize,
+
+    /// The number of cycles executed before the failing instruction.
+    pub cycle_count: u64,
+
+    /// The failing instruction, or `None` if the instruction pointer points outside the program.
+    pub instruction: Option<String>,
+
+    /// The location of the failing instruction in the source code, if the program's
+    /// [`SourceMap`](triton_program::SourceMap) knows it.
+    pub source_location: Option<SourceLocation>,
+
+    /// The named registers before the failing instruction was executed. For Triton VM, these are
+    /// the top elements of the operational stack, `st0` through `st15`.
+    pub registers: Vec<(String, BFieldElement)>,
+
+    /// The jump stack when the error occurred, innermost call first.
+    pub backtrace: Vec<CallFrame>,
+}
+
+impl Display for VmError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.source)?;
+        let instruction = self.instruction.as_deref().unwrap_or("END-OF-FILE");
+        write!(
+            f,
+            "\n  at ip {}, clk {}: {instruction}",
+            self.instruction_pointer, self.cycle_count
+        )?;
+        if let Some(source_location) = &self.source_location {
+            write!(f, " ({source_location})")?;
+        }
+        for registers in self.registers.chunks(4) {
+            let registers = registers
+                .iter()
+                .map(|(name, value)| format!("{name: <5} {: >20}", value.value()))
+                .collect::<Vec<_>>();
+            write!(f, "\n  {}", registers.join(" │ "))?;
+        }
+        for (depth, frame) in self.backtrace.iter().enumerate() {
+            let callee = match &frame.callee_label {
+                Some(label) => format!("`{label}` ({})", frame.callee),
+                None => frame.callee.to_string(),
+            };
+            write!(
+                f,
+                "\n  #{depth}: in call of {callee}, returning to {}",
+                frame.return_address
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for VmError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
 pub fn vm_err<T>(runtime_error: InstructionError) -> Result<T> {
     Err(vm_fail(runtime_error))
 }
@@ -2,7 +2,13 @@
 //! of programs written in Triton assembly. The proof system is a zk-STARK, which is a
 //! state-of-the-art ZKPS.
 
+use anyhow::bail;
+use anyhow::Result;
 use triton_program::FromCode;
+pub use triton_program::NonDeterminism;
+pub use triton_program::PrecomputedNonDeterminism;
+pub use triton_program::SourceLocation;
+pub use triton_program::SourceMap;
 pub use twenty_first::shared_math::b_field_element::BFieldElement;
 pub use twenty_first::shared_math::tip5::Digest;
 use twenty_first::shared_math::tip5::Tip5;
@@ -13,20 +19,22 @@ use crate::proof::Proof;
 use crate::stark::Stark;
 pub use crate::stark::StarkParameters;
 use crate::table::master_table::MasterBaseTable;
+pub use crate::vm::ExecutionBudget;
 
 pub mod arithmetic_domain;
 pub mod bfield_codec;
 pub mod error;
 pub mod fri;
+pub mod machine;
 pub mod op_stack;
 pub mod proof;
 pub mod proof_item;
 pub mod proof_stream;
+pub mod regs_pool;
 pub mod shared_tests;
 pub mod stark;
 pub mod table;
 pub mod vm;
-pub mod regs_pool;
 
 /// Prove correct execution of a program written in Triton assembly.
 /// This is a convenience function, abstracting away the details of the STARK construction.
@@ -34,60 +42,61 @@ pub mod regs_pool;
 /// reference for how to use Triton VM.
 ///
 /// Note that all arithmetic is in the prime field with 2^64 - 2^32 + 1 elements. If the
-/// provided public input or secret input contains elements larger than this, proof generation
-/// will be aborted.
+/// provided public input contains elements larger than this, proof generation fails.
+/// The secret input is supplied by the given [`NonDeterminism`], for example a
+/// [`PrecomputedNonDeterminism`].
 ///
 /// The program executed by Triton VM must terminate gracefully, i.e., with instruction `halt`.
-/// If the program crashes, _e.g._, due to an out-of-bounds instruction pointer or a failing
-/// `assert` instruction, proof generation will fail.
+/// If the program does not parse, or if it crashes, _e.g._, due to an out-of-bounds instruction
+/// pointer or a failing `assert` instruction, proof generation fails. Proof generation also fails
+/// if execution exceeds the given [`ExecutionBudget`], in which case no tables are allocated.
+///
+/// A failing instruction results in a [`VmError`](error::VmError), reachable with
+/// `downcast::<VmError>()`, which reports the machine's state and whose `source` is the
+/// [`InstructionError`](error::InstructionError). Exceeding the budget is not the fault of any
+/// instruction: such errors are bare `InstructionError`s, _i.e._,
+/// [`CycleLimitExceeded`](error::InstructionError::CycleLimitExceeded) or
+/// [`PaddedHeightLimitExceeded`](error::InstructionError::PaddedHeightLimitExceeded).
 ///
 /// The default STARK parameters used by Triton VM give a (conjectured) security level of 160 bits.
 pub fn prove<T>(
     source_code: &str,
     public_input: &[u64],
-    secret_input: &[u64],
-) -> (StarkParameters, Claim, Proof)
+    non_determinism: Box<dyn NonDeterminism>,
+    budget: ExecutionBudget,
+) -> Result<(StarkParameters, Claim, Proof)>
 where
     T: FromCode,
 {
-    let canonical_representation_error =
-        "input must contain only elements in canonical representation, i.e., \
-        elements smaller than the prime field's modulus 2^64 - 2^32 + 1.";
-    assert!(
-        public_input.iter().all(|&e| e <= BFieldElement::MAX),
-        "Public {canonical_representation_error}"
-    );
-    assert!(
-        secret_input.iter().all(|&e| e <= BFieldElement::MAX),
-        "Secret {canonical_representation_error}"
-    );
-
-    // Convert the public and secret inputs to BFieldElements.
+    if public_input.iter().any(|&e| e > BFieldElement::MAX) {
+        bail!(
+            "Public input must contain only elements in canonical representation, i.e., \
+            elements smaller than the prime field's modulus 2^64 - 2^32 + 1."
+        );
+    }
+
+    // Convert the public input to BFieldElements.
     let public_input_bfe = public_input
         .iter()
         .map(|&e| BFieldElement::new(e))
         .collect::<Vec<_>>();
-    let secret_input_bfe = secret_input
-        .iter()
-        .map(|&e| BFieldElement::new(e))
-        .collect::<Vec<_>>();
 
     // Parse the Triton assembly into a program.
-    let program = T::from_code(source_code).unwrap();
+    let program = T::from_code(source_code)?;
 
     // Generate
     // - the witness required for proof generation, i.e., the Algebraic Execution Trace (AET),
     // - the (public) output of the program, and
     // - an error, if the program crashes.
     let (aet, public_output, maybe_error) =
-        vm::simulate(program.clone(), public_input_bfe, secret_input_bfe);
+        vm::simulate(program.clone(), public_input_bfe, non_determinism, budget);
 
     // Check for VM crashes, for example due to failing `assert` instructions or an out-of-bounds
     // instruction pointer. Crashes can occur if any of the two inputs does not conform to the
     // program, or because of a bug in the program, among other things.
-    // If the VM crashes, proof generation will fail.
+    // If the VM crashes, proof generation fails.
     if let Some(error) = maybe_error {
-        panic!("Execution error: {error}");
+        return Err(error);
     }
 
     // Convert the public output to a vector of u64.
@@ -102,6 +111,8 @@ where
         program_digest: Tip5::hash(&program),
         output: public_output,
         padded_height: MasterBaseTable::padded_height(&aet),
+        exit_code: aet.exit_code.value(),
+        final_answer: aet.final_answer.map(|answer| answer.value()),
     };
 
     // The default parameters give a (conjectured) security level of 160 bits.
@@ -110,10 +121,52 @@ where
     // Generate the proof.
     let proof = Stark::prove(&parameters, &claim, &aet, &mut None);
 
-    (parameters, claim, proof)
+    Ok((parameters, claim, proof))
 }
 
 /// Verify a proof generated by [`prove`].
 pub fn verify(parameters: &StarkParameters, claim: &Claim, proof: &Proof) -> bool {
     Stark::verify(parameters, claim, proof, &mut None).unwrap_or(false)
 }
+
+#[cfg(test)]
+mod lib_tests {
+    use triton_opcodes::program::Program;
+
+    use crate::error::InstructionError;
+    use crate::error::InstructionError::AssertionFailed;
+    use crate::error::InstructionError::CycleLimitExceeded;
+    use crate::error::VmError;
+
+    use super::*;
+
+    #[test]
+    fn prove_reports_exceeded_execution_budget_test() {
+        let code = "call loop halt loop: push 1 pop recurse";
+        let non_determinism = Box::new(PrecomputedNonDeterminism::default());
+        let budget = ExecutionBudget::new(1000, usize::MAX);
+        let err = prove::<Program>(code, &[], non_determinism, budget).unwrap_err();
+        assert!(matches!(
+            err.downcast::<InstructionError>(),
+            Ok(CycleLimitExceeded(1000))
+        ));
+    }
+
+    #[test]
+    fn prove_reports_failing_instruction_test() {
+        let non_determinism = Box::new(PrecomputedNonDeterminism::default());
+        let budget = ExecutionBudget::default();
+        let err = prove::<Program>("push 0 assert halt", &[], non_determinism, budget).unwrap_err();
+        let vm_error = err.downcast::<VmError>().unwrap();
+        assert!(matches!(vm_error.source, AssertionFailed(_)));
+        assert_eq!(2, vm_error.instruction_pointer);
+    }
+
+    #[test]
+    fn prove_rejects_non_canonical_public_input_test() {
+        let non_determinism = Box::new(PrecomputedNonDeterminism::default());
+        let public_input = [BFieldElement::MAX + 1];
+        let result = prove::<Program>("halt", &public_input, non_determinism, Default::default());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,245 @@
+//! The [`Machine`]s executing the instruction set architectures supported by Triton VM.
+//!
+//! Functions like [`simulate`](crate::vm::simulate) and [`run`](crate::vm::run) accept any
+//! [`AbstractProgram`] and use [`dispatch`] to find the machine for the program's instruction
+//! set architecture.
+//!
+//! The set of instruction set architectures is closed: [`dispatch`] knows Triton assembly and
+//! zMIPS by name, and every machine records its steps in the same [`AlgebraicExecutionTrace`],
+//! which has dedicated fields for the tables of both. Supporting another instruction set
+//! architecture requires implementing [`Isa`](triton_program::Isa) and [`Machine`] for it, adding
+//! it to [`dispatch`], and extending the [`AlgebraicExecutionTrace`] and the master tables with
+//! the tables arithmetizing its execution.
+
+use anyhow::Result;
+use num_traits::One;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+
+use triton_opcodes::instruction::Instruction;
+use triton_opcodes::program::TritonIsa;
+use triton_program::AbstractProgram;
+use triton_program::Isa;
+use triton_program::Machine;
+use triton_program::NonDeterminism;
+use triton_zmips::program::ZmipsIsa;
+
+use crate::error::vm_err;
+use crate::error::InstructionError::*;
+use crate::table::processor_table::ProcessorTable;
+use crate::table::table_column::MasterBaseTableColumn;
+use crate::table::table_column::ZmipsProcessorBaseTableColumn;
+use crate::table::zmips_processor_table::ZmipsProcessorTable;
+use crate::vm::AlgebraicExecutionTrace;
+use crate::vm::InputTape;
+use crate::vm::VMOutput;
+use crate::vm::VMOutput::*;
+use crate::vm::VMState;
+
+/// An operation on a program that is generic over the [`Machine`] executing the program. Every
+/// machine records its steps in an [`AlgebraicExecutionTrace`].
+pub trait MachineVisitor {
+    type Output;
+
+    fn visit<'pgm, M>(self, program: &'pgm <M::Isa as Isa>::Program) -> Self::Output
+    where
+        M: Machine<'pgm, Trace = AlgebraicExecutionTrace, Output = VMOutput>;
+}
+
+/// Apply the visitor to the given program, using the machine for the program's instruction set
+/// architecture. Fails if no supported instruction set architecture has programs of the given
+/// type. The supported instruction set architectures are listed here explicitly; see the
+/// [module documentation](self) for what adding one involves.
+pub fn dispatch<V: MachineVisitor>(program: &dyn AbstractProgram, visitor: V) -> Result<V::Output> {
+    let program = program.as_any();
+    if let Some(program) = program.downcast_ref::<<TritonIsa as Isa>::Program>() {
+        return Ok(visitor.visit::<TritonMachine>(program));
+    }
+    if let Some(program) = program.downcast_ref::<<ZmipsIsa as Isa>::Program>() {
+        return Ok(visitor.visit::<ZmipsMachine>(program));
+    }
+    vm_err(UnknownProgramType)
+}
+
+/// Triton VM's stack machine, reading its input from the standard input and its secret input.
+#[derive(Debug, Clone)]
+pub struct TritonMachine<'pgm> {
+    pub state: VMState<'pgm, Instruction>,
+    pub stdin: Vec<BFieldElement>,
+    pub non_determinism: Box<dyn NonDeterminism>,
+}
+
+impl<'pgm> Machine<'pgm> for TritonMachine<'pgm> {
+    type Isa = TritonIsa;
+    type Trace = AlgebraicExecutionTrace;
+    type Output = VMOutput;
+
+    fn new(
+        program: &'pgm <TritonIsa as Isa>::Program,
+        public_input: Vec<BFieldElement>,
+        non_determinism: Box<dyn NonDeterminism>,
+    ) -> Self {
+        let mut state = VMState::new_triton(&program.instructions);
+        state.source_map = Some(&program.source_map);
+        state.ram = non_determinism.initial_ram();
+        Self {
+            state,
+            stdin: public_input,
+            non_determinism,
+        }
+    }
+
+    fn is_halting(&self) -> bool {
+        self.state.halting
+    }
+
+    fn current_instruction(&self) -> Result<Instruction> {
+        self.state.current_instruction()
+    }
+
+    fn step(&mut self) -> Result<Option<VMOutput>> {
+        self.state
+            .step_mut(&mut self.stdin, self.non_determinism.as_mut())
+    }
+
+    /// The Processor Table executes the program, the zMIPS Processor Table remains empty.
+    fn initialize_trace(&self, _aet: &mut AlgebraicExecutionTrace) {}
+
+    fn record_step(&mut self, aet: &mut AlgebraicExecutionTrace) -> Result<Option<VMOutput>> {
+        aet.processor_trace
+            .push_row(self.state.to_processor_row().view())
+            .expect("shapes must be identical");
+
+        // An instruction pointer outside the program makes the step fail with a `VmError`.
+        let instruction_pointer = self.state.instruction_pointer;
+        if let Some(multiplicity) = aet.instruction_multiplicities.get_mut(instruction_pointer) {
+            *multiplicity += 1;
+        }
+
+        let vm_output = self.step()?;
+        match &vm_output {
+            Some(Tip5Trace(Instruction::Hash, tip5_trace)) => aet.append_hash_trace(**tip5_trace),
+            Some(Tip5Trace(instruction, tip5_trace)) => {
+                aet.append_sponge_trace(*instruction, **tip5_trace)
+            }
+            Some(U32TableEntries(u32_entries)) => {
+                for &u32_entry in u32_entries {
+                    aet.u32_entries
+                        .entry(u32_entry)
+                        .and_modify(|multiplicity| *multiplicity += 1)
+                        .or_insert(1);
+                }
+            }
+            Some(FinalAnswer(_)) | Some(ExitCode(_)) => {
+                unreachable!("Triton VM instructions don't report answers or exit codes")
+            }
+            Some(WriteOutputSymbol(_)) | None => (),
+        }
+        Ok(vm_output)
+    }
+}
+
+/// The zMIPS register machine, reading its input from a public and a secret [`InputTape`]. The
+/// secret input tape is filled with individual tokens of the secret input as the program reads
+/// them.
+#[derive(Debug, Clone)]
+pub struct ZmipsMachine<'pgm> {
+    pub state: VMState<'pgm, triton_zmips::instruction::Instruction>,
+    pub public_input: InputTape,
+    pub secret_input: InputTape,
+    pub non_determinism: Box<dyn NonDeterminism>,
+}
+
+impl<'pgm> Machine<'pgm> for ZmipsMachine<'pgm> {
+    type Isa = ZmipsIsa;
+    type Trace = AlgebraicExecutionTrace;
+    type Output = VMOutput;
+
+    fn new(
+        program: &'pgm <ZmipsIsa as Isa>::Program,
+        public_input: Vec<BFieldElement>,
+        non_determinism: Box<dyn NonDeterminism>,
+    ) -> Self {
+        // The program's data segment takes precedence over non-deterministically initialized RAM.
+        let mut state = VMState::new_zmips(program);
+        let mut ram = non_determinism.initial_ram();
+        ram.extend(state.ram);
+        state.ram = ram;
+        Self {
+            state,
+            public_input: InputTape::new(public_input),
+            secret_input: InputTape::default(),
+            non_determinism,
+        }
+    }
+
+    fn is_halting(&self) -> bool {
+        self.state.halting
+    }
+
+    fn current_instruction(&self) -> Result<triton_zmips::instruction::Instruction> {
+        self.state.current_instruction()
+    }
+
+    fn step(&mut self) -> Result<Option<VMOutput>> {
+        if self.state.reads_secret_input() {
+            self.secret_input.fill_from(self.non_determinism.as_mut());
+        }
+        self.state
+            .step_mut(&mut self.public_input, &mut self.secret_input)
+    }
+
+    /// The zMIPS Processor Table executes the program, the Processor Table idles.
+    fn initialize_trace(&self, aet: &mut AlgebraicExecutionTrace) {
+        aet.public_input_tape = self.public_input.symbols().to_vec();
+        aet.secret_input_tape = self.secret_input.symbols().to_vec();
+        aet.processor_trace
+            .push_row(ProcessorTable::idle_row().view())
+            .expect("shapes must be identical");
+    }
+
+    fn record_step(&mut self, aet: &mut AlgebraicExecutionTrace) -> Result<Option<VMOutput>> {
+        let mut row = self
+            .state
+            .to_processor_row(&self.public_input, &self.secret_input);
+        let current_instruction = self.current_instruction()?;
+        aet.instruction_multiplicities[self.state.instruction_pointer] += 1;
+
+        let vm_output = self.step();
+        let num_recorded_secret_symbols = aet.secret_input_tape.len();
+        aet.secret_input_tape
+            .extend_from_slice(&self.secret_input.symbols()[num_recorded_secret_symbols..]);
+        match &vm_output {
+            Ok(Some(FinalAnswer(answer))) => aet.final_answer = Some(*answer),
+            Ok(Some(ExitCode(exit_code))) => aet.exit_code = *exit_code,
+            _ => (),
+        }
+
+        // The destination register's content and the final answer are recorded after execution.
+        let rd: usize = current_instruction.operands().rd.into();
+        row[ZmipsProcessorBaseTableColumn::RdVal.base_table_index()] =
+            self.state.regs_pool.regs[rd];
+        if let Some(answer) = aet.final_answer {
+            row[ZmipsProcessorBaseTableColumn::FinalAnswer.base_table_index()] = answer;
+            row[ZmipsProcessorBaseTableColumn::HasFinalAnswer.base_table_index()] =
+                BFieldElement::one();
+        }
+        if vm_output.is_ok() {
+            ZmipsProcessorTable::fill_u32_helper_variables(row.view_mut());
+            for u32_entry in ZmipsProcessorTable::u32_table_entries(row.view()) {
+                aet.u32_entries
+                    .entry(u32_entry)
+                    .and_modify(|multiplicity| *multiplicity += 1)
+                    .or_insert(1);
+            }
+        }
+        aet.zmips_processor_trace
+            .push_row(row.view())
+            .expect("shapes must be identical");
+
+        let vm_output = vm_output?;
+        if let Some(Tip5Trace(..)) | Some(U32TableEntries(_)) = vm_output {
+            unreachable!("zMIPS instructions don't use Triton VM's coprocessors")
+        }
+        Ok(vm_output)
+    }
+}
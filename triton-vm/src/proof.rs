@@ -23,6 +23,16 @@ pub struct Claim {
 
     /// An upper bound on the length of the computation.
     pub padded_height: usize,
+
+    /// The exit code of the computation. Always 0 for Triton VM programs, which can only
+    /// terminate using `halt`. Must be a canonical field element, _i.e._, at most
+    /// [`BFieldElement::MAX`].
+    pub exit_code: u64,
+
+    /// The final answer of the computation, _i.e._, the argument of the last executed `answer`, if
+    /// any. Always `None` for Triton VM programs. Like the exit code, must be a canonical field
+    /// element.
+    pub final_answer: Option<u64>,
 }
 
 impl Claim {
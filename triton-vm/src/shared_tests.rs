@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fs::create_dir_all;
 use std::fs::File;
 use std::io::Read;
@@ -8,6 +9,7 @@ use anyhow::Error;
 use anyhow::Result;
 use triton_opcodes::program::Program;
 use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::tip5::Digest;
 use twenty_first::shared_math::tip5::Tip5;
 use twenty_first::util_types::algebraic_hasher::AlgebraicHasher;
 
@@ -15,6 +17,8 @@ use triton_profiler::prof_start;
 use triton_profiler::prof_stop;
 use triton_profiler::triton_profiler::TritonProfiler;
 use triton_program::FromCode;
+use triton_program::NonDeterminism;
+use triton_program::PrecomputedNonDeterminism;
 
 use crate::proof::Claim;
 use crate::proof::Proof;
@@ -23,11 +27,12 @@ use crate::stark::StarkParameters;
 use crate::table::master_table::MasterBaseTable;
 use crate::vm::simulate;
 use crate::vm::AlgebraicExecutionTrace;
+use crate::vm::ExecutionBudget;
 
 pub fn parse_setup_simulate<T>(
     code: &str,
     input_symbols: Vec<u64>,
-    secret_input_symbols: Vec<u64>,
+    non_determinism: Box<dyn NonDeterminism>,
     maybe_profiler: &mut Option<TritonProfiler>,
 ) -> (AlgebraicExecutionTrace, Vec<u64>)
 where
@@ -37,13 +42,14 @@ where
 
     let program = program.expect("Program must parse.");
     let public_input = input_symbols.into_iter().map(BFieldElement::new).collect();
-    let secret_input = secret_input_symbols
-        .into_iter()
-        .map(BFieldElement::new)
-        .collect();
 
     prof_start!(maybe_profiler, "simulate");
-    let (aet, stdout, err) = simulate(program, public_input, secret_input);
+    let (aet, stdout, err) = simulate(
+        program,
+        public_input,
+        non_determinism,
+        ExecutionBudget::default(),
+    );
     if let Some(error) = err {
         panic!("The VM encountered the following problem: {error}");
     }
@@ -56,18 +62,14 @@ where
 pub fn parse_simulate_prove<T>(
     code: &str,
     input_symbols: Vec<u64>,
-    secret_input_symbols: Vec<u64>,
+    non_determinism: Box<dyn NonDeterminism>,
     maybe_profiler: &mut Option<TritonProfiler>,
 ) -> (StarkParameters, Claim, Proof)
 where
     T: FromCode,
 {
-    let (aet, output_symbols) = parse_setup_simulate::<T>(
-        code,
-        input_symbols.clone(),
-        secret_input_symbols,
-        maybe_profiler,
-    );
+    let (aet, output_symbols) =
+        parse_setup_simulate::<T>(code, input_symbols.clone(), non_determinism, maybe_profiler);
 
     let padded_height = MasterBaseTable::padded_height(&aet);
     let claim = Claim {
@@ -75,6 +77,8 @@ where
         program_digest: Tip5::hash(&aet.program),
         output: output_symbols,
         padded_height,
+        exit_code: aet.exit_code.value(),
+        final_answer: aet.final_answer.map(|answer| answer.value()),
     };
     let log_expansion_factor = 2;
     let security_level = 32;
@@ -92,6 +96,7 @@ pub struct SourceCodeAndInput {
     pub source_code: String,
     pub input: Vec<u64>,
     pub secret_input: Vec<u64>,
+    pub digests: Vec<Digest>,
 }
 
 impl SourceCodeAndInput {
@@ -100,6 +105,7 @@ impl SourceCodeAndInput {
             source_code: source_code.to_string(),
             input: vec![],
             secret_input: vec![],
+            digests: vec![],
         }
     }
 
@@ -114,11 +120,25 @@ impl SourceCodeAndInput {
             .collect()
     }
 
+    /// The secret input, consisting of the individual tokens and the digests.
+    pub fn non_determinism(&self) -> Box<dyn NonDeterminism> {
+        let non_determinism = PrecomputedNonDeterminism::new(
+            self.secret_input(),
+            self.digests.clone(),
+            HashMap::new(),
+        );
+        Box::new(non_determinism)
+    }
+
     #[deprecated(since = "0.19.0", note = "use `simulate` instead")]
     pub fn run(&self) -> Vec<BFieldElement> {
         let program = Program::from_code(&self.source_code).expect("Could not load source code");
-        let (_, output, err) =
-            simulate(Box::new(program), self.public_input(), self.secret_input());
+        let (_, output, err) = simulate(
+            Box::new(program),
+            self.public_input(),
+            self.non_determinism(),
+            ExecutionBudget::default(),
+        );
         if let Some(e) = err {
             panic!("Running the program failed: {e}")
         }
@@ -127,7 +147,12 @@ impl SourceCodeAndInput {
 
     pub fn simulate(&self) -> (AlgebraicExecutionTrace, Vec<BFieldElement>, Option<Error>) {
         let program = Program::from_code(&self.source_code).expect("Could not load source code.");
-        simulate(Box::new(program), self.public_input(), self.secret_input())
+        simulate(
+            Box::new(program),
+            self.public_input(),
+            self.non_determinism(),
+            ExecutionBudget::default(),
+        )
     }
 }
 
@@ -194,19 +219,19 @@ pub fn save_proof(filename: &str, proof: Proof) -> Result<()> {
     Ok(())
 }
 
-pub const SPECK128_ZMIPS: &str = "
-move $t4, 32
+pub const SPECK64_ZMIPS: &str = "
+move $t4, 27
 secread $t2
 secread $t3
 __L1__:
     srl $t5, $t3, 8
-    sll $t6, $t3, 56
+    sll $t6, $t3, 24
     or $t6, $t5, $t6
-    add $t3, $t6, $t2
+    addu $t3, $t6, $t2
     secread $t7
 
     xor $t3, $t3, $t7
-    srl $t5, $t2, 61
+    srl $t5, $t2, 29
     sll $t6, $t2, 3
     or $t6, $t5, $t6
     xor $t2, $t6, $t3
@@ -216,6 +241,7 @@ bgt $t4, $t1, __L1__
 print $t2
 print $t3
 answer $t3
+exit $zero
 ";
 
 pub const FIBONACCI_SEQUENCE: &str = "
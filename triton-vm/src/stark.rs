@@ -165,11 +165,7 @@ impl Stark {
         let mut proof_stream = StarkProofStream::new();
         proof_stream.enqueue(&ProofItem::MerkleRoot(base_merkle_tree_root), true);
         let extension_weights = proof_stream.sample_scalars(Challenges::num_challenges_to_sample());
-        let extension_challenges = Challenges::new(
-            extension_weights,
-            &claim.public_input(),
-            &claim.public_output(),
-        );
+        let extension_challenges = Challenges::new(extension_weights, claim);
         prof_stop!(maybe_profiler, "Fiat-Shamir");
 
         prof_start!(maybe_profiler, "extend");
@@ -573,6 +569,18 @@ impl Stark {
         proof: &Proof,
         maybe_profiler: &mut Option<TritonProfiler>,
     ) -> Result<bool> {
+        // `Challenges::new` reduces the claimed exit code and final answer modulo the field's
+        // prime. Non-canonical representatives must be rejected, or different claims would be
+        // accepted for the same proof.
+        if claim.exit_code > BFieldElement::MAX {
+            bail!("The claimed exit code must be a canonical field element.");
+        }
+        if let Some(final_answer) = claim.final_answer {
+            if final_answer > BFieldElement::MAX {
+                bail!("The claimed final answer must be a canonical field element.");
+            }
+        }
+
         prof_start!(maybe_profiler, "derive additional parameters");
         let max_degree =
             Self::derive_max_degree(claim.padded_height, parameters.num_trace_randomizers);
@@ -588,11 +596,7 @@ impl Stark {
         let base_merkle_tree_root = proof_stream.dequeue(true)?.as_merkle_root()?;
         let extension_challenge_weights =
             proof_stream.sample_scalars(Challenges::num_challenges_to_sample());
-        let challenges = Challenges::new(
-            extension_challenge_weights,
-            &claim.public_input(),
-            &claim.public_output(),
-        );
+        let challenges = Challenges::new(extension_challenge_weights, claim);
         let extension_tree_merkle_root = proof_stream.dequeue(true)?.as_merkle_root()?;
         // Sample weights for quotient codeword, which is a part of the combination codeword.
         // See corresponding part in the prover for a more detailed explanation.
@@ -900,6 +904,10 @@ pub(crate) mod triton_stark_tests {
     use rand_core::RngCore;
     use triton_opcodes::instruction::AnInstruction;
     use triton_opcodes::program::Program;
+    use triton_program::FromCode;
+    use triton_program::NonDeterminism;
+    use triton_program::PrecomputedNonDeterminism;
+    use triton_zmips::instruction::Instruction as ZmipsInstruction;
     use twenty_first::shared_math::other::random_elements;
 
     use crate::shared_tests::*;
@@ -919,41 +927,50 @@ pub(crate) mod triton_stark_tests {
     use crate::table::master_table::all_degrees_with_origin;
     use crate::table::master_table::MasterExtTable;
     use crate::table::master_table::TableId::ProcessorTable;
+    use crate::table::memory_table::ExtMemoryTable;
     use crate::table::op_stack_table::ExtOpStackTable;
     use crate::table::processor_table::ExtProcessorTable;
     use crate::table::program_table::ExtProgramTable;
     use crate::table::ram_table::ExtRamTable;
+    use crate::table::register_table::ExtRegisterTable;
     use crate::table::table_column::MasterBaseTableColumn;
     use crate::table::table_column::MasterExtTableColumn;
     use crate::table::table_column::ProcessorBaseTableColumn;
     use crate::table::table_column::ProcessorExtTableColumn::InputTableEvalArg;
     use crate::table::table_column::ProcessorExtTableColumn::OutputTableEvalArg;
     use crate::table::table_column::RamBaseTableColumn;
+    use crate::table::table_column::ZmipsProcessorBaseTableColumn;
+    use crate::table::tape_table::ExtTapeTable;
     use crate::table::u32_table::ExtU32Table;
+    use crate::table::zmips_processor_table::ExtZmipsProcessorTable;
     use crate::vm::simulate;
     use crate::vm::triton_vm_tests::property_based_test_programs;
+    use crate::vm::triton_vm_tests::property_based_zmips_test_programs;
     use crate::vm::triton_vm_tests::small_tasm_test_programs;
     use crate::vm::triton_vm_tests::test_hash_nop_nop_lt;
+    use crate::vm::triton_vm_tests::zmips_test_programs;
     use crate::vm::AlgebraicExecutionTrace;
+    use crate::vm::ExecutionBudget;
 
     use super::*;
 
-    pub fn parse_setup_simulate(
+    pub fn parse_setup_simulate<T: FromCode>(
         code: &str,
         input_symbols: Vec<u64>,
-        secret_input_symbols: Vec<u64>,
+        non_determinism: Box<dyn NonDeterminism>,
     ) -> (AlgebraicExecutionTrace, Vec<u64>) {
-        let program = Program::from_code(code);
+        let program = T::from_code(code);
 
         assert!(program.is_ok(), "program parses correctly");
         let program = program.unwrap();
         let public_input = input_symbols.into_iter().map(BFieldElement::new).collect();
-        let secret_input = secret_input_symbols
-            .into_iter()
-            .map(BFieldElement::new)
-            .collect();
 
-        let (aet, stdout, err) = simulate(Box::new(program), public_input, secret_input);
+        let (aet, stdout, err) = simulate(
+            program,
+            public_input,
+            non_determinism,
+            ExecutionBudget::default(),
+        );
         if let Some(error) = err {
             panic!("The VM encountered the following problem: {error}");
         }
@@ -962,12 +979,12 @@ pub(crate) mod triton_stark_tests {
         (aet, stdout)
     }
 
-    pub fn parse_simulate_pad(
+    pub fn parse_simulate_pad<T: FromCode>(
         code: &str,
         stdin: Vec<u64>,
-        secret_in: Vec<u64>,
+        non_determinism: Box<dyn NonDeterminism>,
     ) -> (StarkParameters, Claim, MasterBaseTable, MasterBaseTable) {
-        let (aet, stdout) = parse_setup_simulate(code, stdin.clone(), secret_in);
+        let (aet, stdout) = parse_setup_simulate::<T>(code, stdin.clone(), non_determinism);
 
         let padded_height = MasterBaseTable::padded_height(&aet);
         let claim = Claim {
@@ -975,6 +992,8 @@ pub(crate) mod triton_stark_tests {
             program_digest: Tip5::hash(&aet.program),
             output: stdout,
             padded_height,
+            exit_code: aet.exit_code.value(),
+            final_answer: aet.final_answer.map(|answer| answer.value()),
         };
         let log_expansion_factor = 2;
         let security_level = 32;
@@ -997,10 +1016,10 @@ pub(crate) mod triton_stark_tests {
         )
     }
 
-    pub fn parse_simulate_pad_extend(
+    pub fn parse_simulate_pad_extend<T: FromCode>(
         code: &str,
         stdin: Vec<u64>,
-        secret_in: Vec<u64>,
+        non_determinism: Box<dyn NonDeterminism>,
     ) -> (
         StarkParameters,
         Claim,
@@ -1010,10 +1029,9 @@ pub(crate) mod triton_stark_tests {
         Challenges,
     ) {
         let (parameters, claim, unpadded_master_base_table, master_base_table) =
-            parse_simulate_pad(code, stdin, secret_in);
+            parse_simulate_pad::<T>(code, stdin, non_determinism);
 
-        let dummy_challenges =
-            Challenges::placeholder(&claim.public_input(), &claim.public_output());
+        let dummy_challenges = Challenges::placeholder(Some(&claim));
         let master_ext_table =
             master_base_table.extend(&dummy_challenges, parameters.num_randomizer_polynomials);
 
@@ -1039,7 +1057,11 @@ pub(crate) mod triton_stark_tests {
         push  5 read_mem
         halt
         ";
-        let (_, _, master_base_table, _) = parse_simulate_pad(program, vec![], vec![]);
+        let (_, _, master_base_table, _) = parse_simulate_pad::<Program>(
+            program,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+        );
 
         println!();
         println!("Processor Table:");
@@ -1141,8 +1163,11 @@ pub(crate) mod triton_stark_tests {
     pub fn check_io_terminals() {
         let read_nop_code = "read_io read_io read_io nop nop write_io push 17 write_io halt";
         let input_symbols = vec![3, 5, 7];
-        let (_, claim, _, _, master_ext_table, all_challenges) =
-            parse_simulate_pad_extend(read_nop_code, input_symbols, vec![]);
+        let (_, claim, _, _, master_ext_table, all_challenges) = parse_simulate_pad_extend::<Program>(
+            read_nop_code,
+            input_symbols,
+            Box::new(PrecomputedNonDeterminism::default()),
+        );
 
         let processor_table = master_ext_table.table(ProcessorTable);
         let processor_table_last_row = processor_table.slice(s![-1, ..]);
@@ -1170,11 +1195,11 @@ pub(crate) mod triton_stark_tests {
 
         for (code_idx, code_with_input) in code_collection.into_iter().enumerate() {
             println!("Checking Grand Cross-Table Argument for TASM snippet {code_idx}.");
+            let non_determinism = code_with_input.non_determinism();
             let code = code_with_input.source_code;
             let input = code_with_input.input;
-            let secret_input = code_with_input.secret_input.clone();
             let (_, _, _, master_base_table, master_ext_table, all_challenges) =
-                parse_simulate_pad_extend(&code, input, secret_input);
+                parse_simulate_pad_extend::<Program>(&code, input, non_determinism);
 
             let processor_table = master_ext_table.table(ProcessorTable);
             let processor_table_last_row = processor_table.slice(s![-1, ..]);
@@ -1213,7 +1238,7 @@ pub(crate) mod triton_stark_tests {
 
     #[test]
     fn constraint_polynomials_use_right_variable_count_test() {
-        let challenges = Challenges::placeholder(&[], &[]);
+        let challenges = Challenges::placeholder(None);
         let base_row = Array1::<BFieldElement>::zeros(NUM_BASE_COLUMNS);
         let ext_row = Array1::zeros(NUM_EXT_COLUMNS);
 
@@ -1251,9 +1276,25 @@ pub(crate) mod triton_stark_tests {
         ExtHashTable::evaluate_terminal_constraints(br, er, &challenges);
 
         ExtU32Table::evaluate_initial_constraints(br, er, &challenges);
+        ExtZmipsProcessorTable::evaluate_initial_constraints(br, er, &challenges);
+        ExtRegisterTable::evaluate_initial_constraints(br, er, &challenges);
+        ExtTapeTable::evaluate_initial_constraints(br, er, &challenges);
+        ExtMemoryTable::evaluate_initial_constraints(br, er, &challenges);
         ExtU32Table::evaluate_consistency_constraints(br, er, &challenges);
+        ExtZmipsProcessorTable::evaluate_consistency_constraints(br, er, &challenges);
+        ExtRegisterTable::evaluate_consistency_constraints(br, er, &challenges);
+        ExtTapeTable::evaluate_consistency_constraints(br, er, &challenges);
+        ExtMemoryTable::evaluate_consistency_constraints(br, er, &challenges);
         ExtU32Table::evaluate_transition_constraints(br, er, br, er, &challenges);
+        ExtZmipsProcessorTable::evaluate_transition_constraints(br, er, br, er, &challenges);
+        ExtRegisterTable::evaluate_transition_constraints(br, er, br, er, &challenges);
+        ExtTapeTable::evaluate_transition_constraints(br, er, br, er, &challenges);
+        ExtMemoryTable::evaluate_transition_constraints(br, er, br, er, &challenges);
         ExtU32Table::evaluate_terminal_constraints(br, er, &challenges);
+        ExtZmipsProcessorTable::evaluate_terminal_constraints(br, er, &challenges);
+        ExtRegisterTable::evaluate_terminal_constraints(br, er, &challenges);
+        ExtTapeTable::evaluate_terminal_constraints(br, er, &challenges);
+        ExtMemoryTable::evaluate_terminal_constraints(br, er, &challenges);
     }
 
     #[test]
@@ -1268,6 +1309,10 @@ pub(crate) mod triton_stark_tests {
             "cascade table",
             "lookup table",
             "u32 table",
+            "zmips processor table",
+            "register table",
+            "tape table",
+            "memory table",
             "cross-table arg",
         ];
         let all_init = [
@@ -1280,6 +1325,10 @@ pub(crate) mod triton_stark_tests {
             ExtCascadeTable::num_initial_quotients(),
             ExtLookupTable::num_initial_quotients(),
             ExtU32Table::num_initial_quotients(),
+            ExtZmipsProcessorTable::num_initial_quotients(),
+            ExtRegisterTable::num_initial_quotients(),
+            ExtTapeTable::num_initial_quotients(),
+            ExtMemoryTable::num_initial_quotients(),
             GrandCrossTableArg::num_initial_quotients(),
         ];
         let all_cons = [
@@ -1292,6 +1341,10 @@ pub(crate) mod triton_stark_tests {
             ExtCascadeTable::num_consistency_quotients(),
             ExtLookupTable::num_consistency_quotients(),
             ExtU32Table::num_consistency_quotients(),
+            ExtZmipsProcessorTable::num_consistency_quotients(),
+            ExtRegisterTable::num_consistency_quotients(),
+            ExtTapeTable::num_consistency_quotients(),
+            ExtMemoryTable::num_consistency_quotients(),
             GrandCrossTableArg::num_consistency_quotients(),
         ];
         let all_trans = [
@@ -1304,6 +1357,10 @@ pub(crate) mod triton_stark_tests {
             ExtCascadeTable::num_transition_quotients(),
             ExtLookupTable::num_transition_quotients(),
             ExtU32Table::num_transition_quotients(),
+            ExtZmipsProcessorTable::num_transition_quotients(),
+            ExtRegisterTable::num_transition_quotients(),
+            ExtTapeTable::num_transition_quotients(),
+            ExtMemoryTable::num_transition_quotients(),
             GrandCrossTableArg::num_transition_quotients(),
         ];
         let all_term = [
@@ -1316,6 +1373,10 @@ pub(crate) mod triton_stark_tests {
             ExtCascadeTable::num_terminal_quotients(),
             ExtLookupTable::num_terminal_quotients(),
             ExtU32Table::num_terminal_quotients(),
+            ExtZmipsProcessorTable::num_terminal_quotients(),
+            ExtRegisterTable::num_terminal_quotients(),
+            ExtTapeTable::num_terminal_quotients(),
+            ExtMemoryTable::num_terminal_quotients(),
             GrandCrossTableArg::num_terminal_quotients(),
         ];
 
@@ -1348,7 +1409,7 @@ pub(crate) mod triton_stark_tests {
     fn number_of_quotient_degree_bounds_match_number_of_constraints_test() {
         let base_row = Array1::<BFieldElement>::zeros(NUM_BASE_COLUMNS);
         let ext_row = Array1::zeros(NUM_EXT_COLUMNS);
-        let challenges = Challenges::placeholder(&[], &[]);
+        let challenges = Challenges::placeholder(None);
         let padded_height = 2;
         let num_trace_randomizers = 2;
         let interpolant_degree = interpolant_degree(padded_height, num_trace_randomizers);
@@ -1427,10 +1488,42 @@ pub(crate) mod triton_stark_tests {
             ExtU32Table::num_initial_quotients(),
             ExtU32Table::evaluate_initial_constraints(br, er, &challenges).len(),
         );
+        assert_eq!(
+            ExtZmipsProcessorTable::num_initial_quotients(),
+            ExtZmipsProcessorTable::evaluate_initial_constraints(br, er, &challenges).len(),
+        );
+        assert_eq!(
+            ExtRegisterTable::num_initial_quotients(),
+            ExtRegisterTable::evaluate_initial_constraints(br, er, &challenges).len(),
+        );
+        assert_eq!(
+            ExtTapeTable::num_initial_quotients(),
+            ExtTapeTable::evaluate_initial_constraints(br, er, &challenges).len(),
+        );
+        assert_eq!(
+            ExtMemoryTable::num_initial_quotients(),
+            ExtMemoryTable::evaluate_initial_constraints(br, er, &challenges).len(),
+        );
         assert_eq!(
             ExtU32Table::num_initial_quotients(),
             ExtU32Table::initial_quotient_degree_bounds(id).len()
         );
+        assert_eq!(
+            ExtZmipsProcessorTable::num_initial_quotients(),
+            ExtZmipsProcessorTable::initial_quotient_degree_bounds(id).len()
+        );
+        assert_eq!(
+            ExtRegisterTable::num_initial_quotients(),
+            ExtRegisterTable::initial_quotient_degree_bounds(id).len()
+        );
+        assert_eq!(
+            ExtTapeTable::num_initial_quotients(),
+            ExtTapeTable::initial_quotient_degree_bounds(id).len()
+        );
+        assert_eq!(
+            ExtMemoryTable::num_initial_quotients(),
+            ExtMemoryTable::initial_quotient_degree_bounds(id).len()
+        );
         assert_eq!(
             GrandCrossTableArg::num_initial_quotients(),
             GrandCrossTableArg::evaluate_initial_constraints(br, er, &challenges).len(),
@@ -1508,10 +1601,42 @@ pub(crate) mod triton_stark_tests {
             ExtU32Table::num_consistency_quotients(),
             ExtU32Table::evaluate_consistency_constraints(br, er, &challenges).len(),
         );
+        assert_eq!(
+            ExtZmipsProcessorTable::num_consistency_quotients(),
+            ExtZmipsProcessorTable::evaluate_consistency_constraints(br, er, &challenges).len(),
+        );
+        assert_eq!(
+            ExtRegisterTable::num_consistency_quotients(),
+            ExtRegisterTable::evaluate_consistency_constraints(br, er, &challenges).len(),
+        );
+        assert_eq!(
+            ExtTapeTable::num_consistency_quotients(),
+            ExtTapeTable::evaluate_consistency_constraints(br, er, &challenges).len(),
+        );
+        assert_eq!(
+            ExtMemoryTable::num_consistency_quotients(),
+            ExtMemoryTable::evaluate_consistency_constraints(br, er, &challenges).len(),
+        );
         assert_eq!(
             ExtU32Table::num_consistency_quotients(),
             ExtU32Table::consistency_quotient_degree_bounds(id, ph).len()
         );
+        assert_eq!(
+            ExtZmipsProcessorTable::num_consistency_quotients(),
+            ExtZmipsProcessorTable::consistency_quotient_degree_bounds(id, ph).len()
+        );
+        assert_eq!(
+            ExtRegisterTable::num_consistency_quotients(),
+            ExtRegisterTable::consistency_quotient_degree_bounds(id, ph).len()
+        );
+        assert_eq!(
+            ExtTapeTable::num_consistency_quotients(),
+            ExtTapeTable::consistency_quotient_degree_bounds(id, ph).len()
+        );
+        assert_eq!(
+            ExtMemoryTable::num_consistency_quotients(),
+            ExtMemoryTable::consistency_quotient_degree_bounds(id, ph).len()
+        );
         assert_eq!(
             GrandCrossTableArg::num_consistency_quotients(),
             GrandCrossTableArg::evaluate_consistency_constraints(br, er, &challenges).len(),
@@ -1589,10 +1714,43 @@ pub(crate) mod triton_stark_tests {
             ExtU32Table::num_transition_quotients(),
             ExtU32Table::evaluate_transition_constraints(br, er, br, er, &challenges).len(),
         );
+        assert_eq!(
+            ExtZmipsProcessorTable::num_transition_quotients(),
+            ExtZmipsProcessorTable::evaluate_transition_constraints(br, er, br, er, &challenges)
+                .len(),
+        );
+        assert_eq!(
+            ExtRegisterTable::num_transition_quotients(),
+            ExtRegisterTable::evaluate_transition_constraints(br, er, br, er, &challenges).len(),
+        );
+        assert_eq!(
+            ExtTapeTable::num_transition_quotients(),
+            ExtTapeTable::evaluate_transition_constraints(br, er, br, er, &challenges).len(),
+        );
+        assert_eq!(
+            ExtMemoryTable::num_transition_quotients(),
+            ExtMemoryTable::evaluate_transition_constraints(br, er, br, er, &challenges).len(),
+        );
         assert_eq!(
             ExtU32Table::num_transition_quotients(),
             ExtU32Table::transition_quotient_degree_bounds(id, ph).len()
         );
+        assert_eq!(
+            ExtZmipsProcessorTable::num_transition_quotients(),
+            ExtZmipsProcessorTable::transition_quotient_degree_bounds(id, ph).len()
+        );
+        assert_eq!(
+            ExtRegisterTable::num_transition_quotients(),
+            ExtRegisterTable::transition_quotient_degree_bounds(id, ph).len()
+        );
+        assert_eq!(
+            ExtTapeTable::num_transition_quotients(),
+            ExtTapeTable::transition_quotient_degree_bounds(id, ph).len()
+        );
+        assert_eq!(
+            ExtMemoryTable::num_transition_quotients(),
+            ExtMemoryTable::transition_quotient_degree_bounds(id, ph).len()
+        );
         assert_eq!(
             GrandCrossTableArg::num_transition_quotients(),
             GrandCrossTableArg::evaluate_transition_constraints(br, er, br, er, &challenges).len(),
@@ -1670,10 +1828,42 @@ pub(crate) mod triton_stark_tests {
             ExtU32Table::num_terminal_quotients(),
             ExtU32Table::evaluate_terminal_constraints(br, er, &challenges).len(),
         );
+        assert_eq!(
+            ExtZmipsProcessorTable::num_terminal_quotients(),
+            ExtZmipsProcessorTable::evaluate_terminal_constraints(br, er, &challenges).len(),
+        );
+        assert_eq!(
+            ExtRegisterTable::num_terminal_quotients(),
+            ExtRegisterTable::evaluate_terminal_constraints(br, er, &challenges).len(),
+        );
+        assert_eq!(
+            ExtTapeTable::num_terminal_quotients(),
+            ExtTapeTable::evaluate_terminal_constraints(br, er, &challenges).len(),
+        );
+        assert_eq!(
+            ExtMemoryTable::num_terminal_quotients(),
+            ExtMemoryTable::evaluate_terminal_constraints(br, er, &challenges).len(),
+        );
         assert_eq!(
             ExtU32Table::num_terminal_quotients(),
             ExtU32Table::terminal_quotient_degree_bounds(id).len()
         );
+        assert_eq!(
+            ExtZmipsProcessorTable::num_terminal_quotients(),
+            ExtZmipsProcessorTable::terminal_quotient_degree_bounds(id).len()
+        );
+        assert_eq!(
+            ExtRegisterTable::num_terminal_quotients(),
+            ExtRegisterTable::terminal_quotient_degree_bounds(id).len()
+        );
+        assert_eq!(
+            ExtTapeTable::num_terminal_quotients(),
+            ExtTapeTable::terminal_quotient_degree_bounds(id).len()
+        );
+        assert_eq!(
+            ExtMemoryTable::num_terminal_quotients(),
+            ExtMemoryTable::terminal_quotient_degree_bounds(id).len()
+        );
         assert_eq!(
             GrandCrossTableArg::num_terminal_quotients(),
             GrandCrossTableArg::evaluate_terminal_constraints(br, er, &challenges).len(),
@@ -1695,6 +1885,7 @@ pub(crate) mod triton_stark_tests {
             source_code: FIBONACCI_SEQUENCE.to_string(),
             input: vec![100],
             secret_input: vec![],
+            digests: vec![],
         };
         triton_table_constraints_evaluate_to_zero(source_code_and_input);
     }
@@ -1715,13 +1906,47 @@ pub(crate) mod triton_stark_tests {
         }
     }
 
+    #[test]
+    fn zmips_table_constraints_evaluate_to_zero_on_small_programs_test() {
+        for (program_idx, program) in zmips_test_programs().into_iter().enumerate() {
+            println!("Testing program with index {program_idx}.");
+            let claim = zmips_table_constraints_evaluate_to_zero(program);
+            assert_eq!(0, claim.exit_code);
+        }
+    }
+
+    #[test]
+    fn zmips_table_constraints_evaluate_to_zero_on_property_based_programs_test() {
+        for (program_idx, program) in property_based_zmips_test_programs().into_iter().enumerate() {
+            println!("Testing program with index {program_idx}.");
+            let claim = zmips_table_constraints_evaluate_to_zero(program);
+            assert_eq!(0, claim.exit_code);
+        }
+    }
+
     pub fn triton_table_constraints_evaluate_to_zero(source_code_and_input: SourceCodeAndInput) {
+        table_constraints_evaluate_to_zero::<Program>(source_code_and_input);
+    }
+
+    /// Check all constraints of all tables on the trace of the given zMIPS program. Returns the
+    /// claim, for example to check the program's exit code.
+    pub fn zmips_table_constraints_evaluate_to_zero(
+        source_code_and_input: SourceCodeAndInput,
+    ) -> Claim {
+        table_constraints_evaluate_to_zero::<triton_zmips::program::Program>(source_code_and_input)
+    }
+
+    fn table_constraints_evaluate_to_zero<T: FromCode>(
+        source_code_and_input: SourceCodeAndInput,
+    ) -> Claim {
         let zero = XFieldElement::zero();
-        let (_, _, _, master_base_table, master_ext_table, challenges) = parse_simulate_pad_extend(
-            &source_code_and_input.source_code,
-            source_code_and_input.input,
-            source_code_and_input.secret_input,
-        );
+        let non_determinism = source_code_and_input.non_determinism();
+        let (_, claim, _, master_base_table, master_ext_table, challenges) =
+            parse_simulate_pad_extend::<T>(
+                &source_code_and_input.source_code,
+                source_code_and_input.input,
+                non_determinism,
+            );
 
         assert_eq!(
             master_base_table.master_base_matrix.nrows(),
@@ -1797,13 +2022,20 @@ pub(crate) mod triton_stark_tests {
                         ProcessorBaseTableColumn::PreviousInstruction.master_base_table_index();
                     let ci_idx = ProcessorBaseTableColumn::CI.master_base_table_index();
                     let nia_idx = ProcessorBaseTableColumn::NIA.master_base_table_index();
+                    let zmips_ci_idx = ZmipsProcessorBaseTableColumn::CI.master_base_table_index();
                     let pi = base_row[pi_idx].value();
                     let ci = base_row[ci_idx].value();
                     let nia = base_row[nia_idx].value();
-                    let previous_instruction =
-                        AnInstruction::<BFieldElement>::try_from(pi).unwrap();
-                    let current_instruction = AnInstruction::<BFieldElement>::try_from(ci).unwrap();
-                    let next_instruction_str = match AnInstruction::<BFieldElement>::try_from(nia) {
+                    let zmips_ci = base_row[zmips_ci_idx].value();
+                    let instruction_str =
+                        |opcode| match AnInstruction::<BFieldElement>::try_from(opcode) {
+                            Ok(instr) => format!("{instr:?}"),
+                            Err(_) => "not an instruction".to_string(),
+                        };
+                    let previous_instruction = instruction_str(pi);
+                    let current_instruction = instruction_str(ci);
+                    let next_instruction_str = instruction_str(nia);
+                    let zmips_instruction = match ZmipsInstruction::try_from(zmips_ci as u32) {
                         Ok(instr) => format!("{instr:?}"),
                         Err(_) => "not an instruction".to_string(),
                     };
@@ -1815,9 +2047,10 @@ pub(crate) mod triton_stark_tests {
                         Table: {table_name}. Index within table: {table_idx} \
                         Row index: {row_idx}. \
                         Total rows: {num_rows}\n\
-                        Previous Instruction: {previous_instruction:?} – opcode: {pi}\n\
-                        Current Instruction:  {current_instruction:?} – opcode: {ci}\n\
-                        Next Instruction:     {next_instruction_str} – opcode: {nia}\n"
+                        Previous Instruction: {previous_instruction} – opcode: {pi}\n\
+                        Current Instruction:  {current_instruction} – opcode: {ci}\n\
+                        Next Instruction:     {next_instruction_str} – opcode: {nia}\n\
+                        zMIPS Instruction:    {zmips_instruction} – opcode: {zmips_ci}\n"
                     );
                 }
             }
@@ -1840,6 +2073,7 @@ pub(crate) mod triton_stark_tests {
                 Table: {table_name}. Index within table: {table_idx}",
             );
         }
+        claim
     }
 
     #[test]
@@ -1848,7 +2082,7 @@ pub(crate) mod triton_stark_tests {
         let (parameters, claim, proof) = parse_simulate_prove::<Program>(
             &code_with_input.source_code,
             code_with_input.input.clone(),
-            code_with_input.secret_input.clone(),
+            code_with_input.non_determinism(),
             &mut None,
         );
 
@@ -1868,7 +2102,7 @@ pub(crate) mod triton_stark_tests {
         let (parameters, claim, proof) = parse_simulate_prove::<Program>(
             &code_with_input.source_code,
             code_with_input.input.clone(),
-            code_with_input.secret_input.clone(),
+            code_with_input.non_determinism(),
             &mut profiler,
         );
         let mut profiler = profiler.unwrap();
@@ -1887,6 +2121,116 @@ pub(crate) mod triton_stark_tests {
         println!("{report}");
     }
 
+    #[test]
+    fn verify_rejects_non_canonical_exit_code_and_final_answer_test() {
+        let code_with_input = test_halt();
+        let (parameters, claim, proof) = parse_simulate_prove::<Program>(
+            &code_with_input.source_code,
+            code_with_input.input.clone(),
+            code_with_input.non_determinism(),
+            &mut None,
+        );
+
+        let claim_with_non_canonical_exit_code = Claim {
+            exit_code: claim.exit_code + BFieldElement::P,
+            ..claim.clone()
+        };
+        let result = Stark::verify(
+            &parameters,
+            &claim_with_non_canonical_exit_code,
+            &proof,
+            &mut None,
+        );
+        assert!(result.is_err());
+
+        let claim_with_non_canonical_final_answer = Claim {
+            final_answer: Some(BFieldElement::P),
+            ..claim
+        };
+        let result = Stark::verify(
+            &parameters,
+            &claim_with_non_canonical_final_answer,
+            &proof,
+            &mut None,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn zmips_prove_verify_wrapping_seek_test() {
+        let code = "li $t0, 5\n \
+            pubseek $t0, -3\n pubread $t1\n \
+            secseek $t0, -4\n secread $t2\n \
+            addu $t3, $t1, $t2\n print $t3\n exit $zero";
+        let non_determinism =
+            PrecomputedNonDeterminism::from([20, 21].map(BFieldElement::new).to_vec());
+        let (parameters, claim, proof) = parse_simulate_prove::<triton_zmips::program::Program>(
+            code,
+            vec![10, 11, 12],
+            Box::new(non_determinism),
+            &mut None,
+        );
+        assert_eq!(vec![33], claim.output);
+
+        let result = Stark::verify(&parameters, &claim, &proof, &mut None);
+        if let Err(e) = result {
+            panic!("The Verifier is unhappy! {e}");
+        }
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn zmips_prove_verify_memory_test() {
+        let code = "li $t1, 64\n \
+            li $t0, 7\n sw $t0, 0($t1)\n li $t0, 8\n sw $t0, 4($t1)\n \
+            lw $t2, 0($t1)\n lw $t3, 4($t1)\n addu $t4, $t2, $t3\n print $t4\n \
+            li $t0, 9\n sw $t0, 0($t1)\n lw $t2, 0($t1)\n print $t2\n \
+            lw $t5, -4($t1)\n print $t5\n exit $zero";
+        let (parameters, claim, proof) = parse_simulate_prove::<triton_zmips::program::Program>(
+            code,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            &mut None,
+        );
+        assert_eq!(vec![15, 9, 0], claim.output);
+
+        let result = Stark::verify(&parameters, &claim, &proof, &mut None);
+        if let Err(e) = result {
+            panic!("The Verifier is unhappy! {e}");
+        }
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn zmips_prove_verify_data_segment_test() {
+        let code = "
+            .data
+            numbers: .word 7, 8, 9
+            .text
+            la $t0, numbers
+            lw $t1, 0($t0)
+            li $t2, 5
+            sw $t2, 4($t0)
+            lw $t3, 4($t0)
+            addu $t4, $t1, $t3
+            print $t4
+            exit $zero
+        ";
+        let (parameters, claim, proof) = parse_simulate_prove::<triton_zmips::program::Program>(
+            code,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            &mut None,
+        );
+        assert_eq!(vec![12], claim.output);
+
+        let result = Stark::verify(&parameters, &claim, &proof, &mut None);
+        if let Err(e) = result {
+            panic!("The Verifier is unhappy! {e}");
+        }
+        assert!(result.unwrap());
+    }
+
     #[test]
     #[ignore = "used for tracking&debugging deserialization errors"]
     fn triton_prove_halt_save_error_test() {
@@ -1896,7 +2240,7 @@ pub(crate) mod triton_stark_tests {
             let (parameters, claim, proof) = parse_simulate_prove::<Program>(
                 &code_with_input.source_code,
                 code_with_input.input.clone(),
-                code_with_input.secret_input.clone(),
+                code_with_input.non_determinism(),
                 &mut None,
             );
 
@@ -1919,7 +2263,7 @@ pub(crate) mod triton_stark_tests {
         let (parameters, claim, _) = parse_simulate_prove::<Program>(
             &code_with_input.source_code,
             code_with_input.input.clone(),
-            code_with_input.secret_input.clone(),
+            code_with_input.non_determinism(),
             &mut None,
         );
 
@@ -1940,11 +2284,11 @@ pub(crate) mod triton_stark_tests {
     fn prove_verify_fibonacci_100_test() {
         let source_code = FIBONACCI_SEQUENCE;
         let stdin = vec![100];
-        let secret_in = vec![];
+        let non_determinism = Box::new(PrecomputedNonDeterminism::default());
 
         let mut profiler = Some(TritonProfiler::new("Prove Fib 100"));
         let (parameters, claim, proof) =
-            parse_simulate_prove::<Program>(source_code, stdin, secret_in, &mut profiler);
+            parse_simulate_prove::<Program>(source_code, stdin, non_determinism, &mut profiler);
         let mut profiler = profiler.unwrap();
         profiler.finish();
 
@@ -1969,9 +2313,9 @@ pub(crate) mod triton_stark_tests {
 
         for (fib_seq_idx, fib_seq_val) in [(0, 1), (7, 21), (11, 144)] {
             let stdin = vec![fib_seq_idx];
-            let secret_in = vec![];
+            let non_determinism = Box::new(PrecomputedNonDeterminism::default());
             let (parameters, claim, proof) =
-                parse_simulate_prove::<Program>(code, stdin, secret_in, &mut None);
+                parse_simulate_prove::<Program>(code, stdin, non_determinism, &mut None);
             match Stark::verify(&parameters, &claim, &proof, &mut None) {
                 Ok(result) => assert!(result, "The Verifier disagrees!"),
                 Err(err) => panic!("The Verifier is unhappy! {err}"),
@@ -1990,8 +2334,12 @@ pub(crate) mod triton_stark_tests {
     #[test]
     fn triton_prove_verify_many_u32_operations_test() {
         let mut profiler = Some(TritonProfiler::new("Prove Many U32 Ops"));
-        let (parameters, claim, proof) =
-            parse_simulate_prove::<Program>(MANY_U32_INSTRUCTIONS, vec![], vec![], &mut profiler);
+        let (parameters, claim, proof) = parse_simulate_prove::<Program>(
+            MANY_U32_INSTRUCTIONS,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            &mut profiler,
+        );
         let mut profiler = profiler.unwrap();
         profiler.finish();
 
@@ -2017,8 +2365,12 @@ pub(crate) mod triton_stark_tests {
             let stdin = vec![fibonacci_number];
             let fib_test_name = format!("element #{fibonacci_number:>4} from Fibonacci sequence");
             let mut profiler = Some(TritonProfiler::new(&fib_test_name));
-            let (parameters, claim, _) =
-                parse_simulate_prove::<Program>(source_code, stdin, vec![], &mut profiler);
+            let (parameters, claim, _) = parse_simulate_prove::<Program>(
+                source_code,
+                stdin,
+                Box::new(PrecomputedNonDeterminism::default()),
+                &mut profiler,
+            );
             let mut profiler = profiler.unwrap();
             profiler.finish();
 
@@ -2037,8 +2389,12 @@ pub(crate) mod triton_stark_tests {
         let st0 = (rng.next_u32() as u64) << 32;
 
         let source_code = format!("push {st0} log_2_floor halt");
-        let (parameters, claim, proof) =
-            parse_simulate_prove::<Program>(&source_code, vec![], vec![], &mut None);
+        let (parameters, claim, proof) = parse_simulate_prove::<Program>(
+            &source_code,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            &mut None,
+        );
         let result = Stark::verify(&parameters, &claim, &proof, &mut None);
         assert!(result.is_ok());
         assert!(result.unwrap());
@@ -2048,8 +2404,12 @@ pub(crate) mod triton_stark_tests {
     #[should_panic(expected = "The logarithm of 0 does not exist")]
     pub fn negative_log_2_floor_of_0_test() {
         let source_code = "push 0 log_2_floor halt";
-        let (parameters, claim, proof) =
-            parse_simulate_prove::<Program>(source_code, vec![], vec![], &mut None);
+        let (parameters, claim, proof) = parse_simulate_prove::<Program>(
+            source_code,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            &mut None,
+        );
         let result = Stark::verify(&parameters, &claim, &proof, &mut None);
         assert!(result.is_ok());
         assert!(result.unwrap());
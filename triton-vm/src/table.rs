@@ -8,9 +8,13 @@ pub mod hash_table;
 pub mod jump_stack_table;
 pub mod lookup_table;
 pub mod master_table;
+pub mod memory_table;
 pub mod op_stack_table;
 pub mod processor_table;
 pub mod program_table;
 pub mod ram_table;
+pub mod register_table;
 pub mod table_column;
+pub mod tape_table;
 pub mod u32_table;
+pub mod zmips_processor_table;
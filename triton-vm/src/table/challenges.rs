@@ -17,19 +17,26 @@
 //! - **Terminals**. The public input (respectively output) of the program is not stored in any
 //! table. Instead, the terminal of the Evaluation Argument is computed directly from the
 //! public input (respectively output) and the indeterminate.
+//! - **Claimed values**. The exit code and the final answer of a zMIPS program are not
+//! challenges at all, but are taken verbatim from the [`Claim`]. Providing them as challenges
+//! allows the terminal constraints to bind them to the trace.
 
 use std::fmt::Debug;
 use std::hash::Hash;
 
+use num_traits::Zero;
 use strum::EnumCount;
 use strum_macros::Display;
 use strum_macros::EnumCount as EnumCountMacro;
 use strum_macros::EnumIter;
 use twenty_first::shared_math::b_field_element::BFieldElement;
 use twenty_first::shared_math::other::random_elements;
+use twenty_first::shared_math::tip5::Digest;
+use twenty_first::shared_math::tip5::DIGEST_LENGTH;
 use twenty_first::shared_math::tip5::LOOKUP_TABLE;
 use twenty_first::shared_math::x_field_element::XFieldElement;
 
+use crate::proof::Claim;
 use crate::table::challenges::ChallengeId::*;
 use crate::table::cross_table_argument::CrossTableArg;
 use crate::table::cross_table_argument::EvalArg;
@@ -64,16 +71,43 @@ pub enum ChallengeId {
     RamIndeterminate,
     JumpStackIndeterminate,
 
+    /// The indeterminate for the Permutation Argument between the
+    /// [zMIPS Processor Table](crate::table::zmips_processor_table) and the
+    /// [Register Table](crate::table::register_table).
+    RegisterIndeterminate,
+
+    /// The indeterminate for the Lookup Argument between the
+    /// [zMIPS Processor Table](crate::table::zmips_processor_table) and the
+    /// [Tape Table](crate::table::tape_table), guaranteeing that instructions `pubread` and
+    /// `secread` read the symbol under the respective input tape's cursor.
+    TapeIndeterminate,
+
+    /// The indeterminate for the Permutation Argument between the
+    /// [zMIPS Processor Table](crate::table::zmips_processor_table) and the
+    /// [Memory Table](crate::table::memory_table), guaranteeing that instructions `lw` and `sw`
+    /// access memory consistently.
+    MemoryIndeterminate,
+
+    /// The indeterminate for the Permutation Argument between the
+    /// [Program Table](crate::table::program_table) and the
+    /// [Memory Table](crate::table::memory_table), guaranteeing that the words of a zMIPS
+    /// program's data segment are the initial values of their addresses.
+    DataSegmentIndeterminate,
+
     U32Indeterminate,
 
     /// The indeterminate for the Lookup Argument between the Processor Table and all memory-like
     /// tables, _i.e._, the OpStack Table, the Ram Table, and the JumpStack Table, guaranteeing
-    /// that all clock jump differences are directed forward.
+    /// that all clock jump differences are directed forward. Also used for the Lookup Argument
+    /// between the zMIPS Processor Table and the Register Table as well as the Memory Table.
     ClockJumpDifferenceLookupIndeterminate,
 
     /// The indeterminate for the Contiguity Argument within the Ram Table.
     RamTableBezoutRelationIndeterminate,
 
+    /// The indeterminate for the Contiguity Argument within the Memory Table.
+    MemoryTableBezoutRelationIndeterminate,
+
     /// A weight for linearly combining multiple elements. Applies to
     /// - `Address` in the Program Table
     /// - `IP` in the Processor Table
@@ -105,6 +139,23 @@ pub enum ChallengeId {
     JumpStackJsoWeight,
     JumpStackJsdWeight,
 
+    RegisterClkWeight,
+    RegisterIndexWeight,
+    RegisterValueWeight,
+    RegisterIsWriteWeight,
+
+    TapeIsSecretWeight,
+    TapePositionWeight,
+    TapeValueWeight,
+
+    MemoryClkWeight,
+    MemoryAddressWeight,
+    MemoryValueWeight,
+    MemoryIsWriteWeight,
+
+    DataSegmentAddressWeight,
+    DataSegmentValueWeight,
+
     HashCIWeight,
     HashStateWeight0,
     HashStateWeight1,
@@ -171,6 +222,11 @@ pub enum ChallengeId {
     CascadeToLookupWeight,
     ProcessorToU32Weight,
     ClockJumpDifferenceLookupWeight,
+    ZmipsProcessorToRegisterWeight,
+    ZmipsClockJumpDifferenceLookupWeight,
+    ZmipsProcessorToTapeWeight,
+    ZmipsProcessorToMemoryWeight,
+    ProgramToMemoryWeight,
 
     /// The terminal for the Evaluation Argument with standard input.
     StandardInputTerminal,
@@ -181,6 +237,15 @@ pub enum ChallengeId {
     /// The terminal for the Evaluation Argument establishing correctness of the
     /// [Lookup Table](crate::table::lookup_table).
     LookupTablePublicTerminal,
+
+    /// The exit code of the zMIPS program, as claimed in the [`Claim`].
+    ClaimedExitCode,
+
+    /// The final answer of the zMIPS program as claimed in the [`Claim`], or 0 if there is none.
+    ClaimedFinalAnswer,
+
+    /// 1 if the [`Claim`] contains a final answer, else 0.
+    ClaimedHasFinalAnswer,
 }
 
 impl ChallengeId {
@@ -213,27 +278,25 @@ impl Challenges {
     /// indeterminate [`StandardOutputIndeterminate`].
     /// - The [`LookupTablePublicTerminal`] is computed from the publicly known and constant
     /// lookup table and the sampled indeterminate [`LookupTablePublicIndeterminate`].
+    /// - The [`ClaimedExitCode`], [`ClaimedFinalAnswer`], and [`ClaimedHasFinalAnswer`] are
+    /// taken from the [`Claim`].
     pub const fn num_challenges_to_sample() -> usize {
         // When modifying this, be sure to add to the compile-time assertions of the form
         // `const _: () = assert!(…);`
         // at the end of this file.
-        Self::count() - 3
+        Self::count() - 6
     }
 
-    pub fn new(
-        mut challenges: Vec<XFieldElement>,
-        public_input: &[BFieldElement],
-        public_output: &[BFieldElement],
-    ) -> Self {
+    pub fn new(mut challenges: Vec<XFieldElement>, claim: &Claim) -> Self {
         assert_eq!(Self::num_challenges_to_sample(), challenges.len());
 
         let input_terminal = EvalArg::compute_terminal(
-            public_input,
+            &claim.public_input(),
             EvalArg::default_initial(),
             challenges[StandardInputIndeterminate.index()],
         );
         let output_terminal = EvalArg::compute_terminal(
-            public_output,
+            &claim.public_output(),
             EvalArg::default_initial(),
             challenges[StandardOutputIndeterminate.index()],
         );
@@ -246,6 +309,12 @@ impl Challenges {
         challenges.insert(StandardInputTerminal.index(), input_terminal);
         challenges.insert(StandardOutputTerminal.index(), output_terminal);
         challenges.insert(LookupTablePublicTerminal.index(), lookup_terminal);
+        let exit_code = BFieldElement::new(claim.exit_code);
+        let final_answer = BFieldElement::new(claim.final_answer.unwrap_or(0));
+        let has_final_answer = BFieldElement::new(claim.final_answer.is_some() as u64);
+        challenges.insert(ClaimedExitCode.index(), exit_code.lift());
+        challenges.insert(ClaimedFinalAnswer.index(), final_answer.lift());
+        challenges.insert(ClaimedHasFinalAnswer.index(), has_final_answer.lift());
         assert_eq!(Self::count(), challenges.len());
         let challenges = challenges.try_into().unwrap();
 
@@ -253,10 +322,19 @@ impl Challenges {
     }
 
     /// Stand-in challenges. Can be used in tests. For non-interactive STARKs, use the
-    /// Fiat-Shamir heuristic to derive the actual challenges.
-    pub fn placeholder(public_input: &[BFieldElement], public_output: &[BFieldElement]) -> Self {
+    /// Fiat-Shamir heuristic to derive the actual challenges. Without a claim, the public input
+    /// and output are empty, the exit code is 0, and there is no final answer.
+    pub fn placeholder(claim: Option<&Claim>) -> Self {
+        let empty_claim = Claim {
+            input: vec![],
+            program_digest: Digest::new([BFieldElement::zero(); DIGEST_LENGTH]),
+            output: vec![],
+            padded_height: 0,
+            exit_code: 0,
+            final_answer: None,
+        };
         let stand_in_challenges = random_elements(Self::num_challenges_to_sample());
-        Self::new(stand_in_challenges, public_input, public_output)
+        Self::new(stand_in_challenges, claim.unwrap_or(&empty_claim))
     }
 
     #[inline(always)]
@@ -283,3 +361,8 @@ const _: () = assert!(StandardOutputIndeterminate.index() < LookupTablePublicTer
 const _: () = assert!(LookupTablePublicIndeterminate.index() < StandardInputTerminal.index());
 const _: () = assert!(LookupTablePublicIndeterminate.index() < StandardOutputTerminal.index());
 const _: () = assert!(LookupTablePublicIndeterminate.index() < LookupTablePublicTerminal.index());
+
+// The claimed values do not depend on other challenges, but are inserted after all of them.
+const _: () = assert!(LookupTablePublicTerminal.index() < ClaimedExitCode.index());
+const _: () = assert!(ClaimedExitCode.index() < ClaimedFinalAnswer.index());
+const _: () = assert!(ClaimedFinalAnswer.index() < ClaimedHasFinalAnswer.index());
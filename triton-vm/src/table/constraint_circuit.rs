@@ -1675,7 +1675,7 @@ mod constraint_circuit_tests {
 
     #[test]
     fn constant_folding_processor_table_test() {
-        let challenges = Challenges::placeholder(&[], &[]);
+        let challenges = Challenges::placeholder(None);
         let constraint_circuits = ExtProcessorTable::ext_initial_constraints_as_circuits();
         table_constraints_prop(constraint_circuits, &challenges, "processor initial");
         let constraint_circuits = ExtProcessorTable::ext_consistency_constraints_as_circuits();
@@ -1688,7 +1688,7 @@ mod constraint_circuit_tests {
 
     #[test]
     fn constant_folding_program_table_test() {
-        let challenges = Challenges::placeholder(&[], &[]);
+        let challenges = Challenges::placeholder(None);
         let constraint_circuits = ExtProgramTable::ext_initial_constraints_as_circuits();
         table_constraints_prop(constraint_circuits, &challenges, "program initial");
         let constraint_circuits = ExtProgramTable::ext_consistency_constraints_as_circuits();
@@ -1701,7 +1701,7 @@ mod constraint_circuit_tests {
 
     #[test]
     fn constant_folding_jump_stack_table_test() {
-        let challenges = Challenges::placeholder(&[], &[]);
+        let challenges = Challenges::placeholder(None);
         let constraint_circuits = ExtJumpStackTable::ext_initial_constraints_as_circuits();
         table_constraints_prop(constraint_circuits, &challenges, "jump stack initial");
         let constraint_circuits = ExtJumpStackTable::ext_consistency_constraints_as_circuits();
@@ -1714,7 +1714,7 @@ mod constraint_circuit_tests {
 
     #[test]
     fn constant_folding_op_stack_table_test() {
-        let challenges = Challenges::placeholder(&[], &[]);
+        let challenges = Challenges::placeholder(None);
         let constraint_circuits = ExtOpStackTable::ext_initial_constraints_as_circuits();
         table_constraints_prop(constraint_circuits, &challenges, "op stack initial");
         let constraint_circuits = ExtOpStackTable::ext_consistency_constraints_as_circuits();
@@ -1727,7 +1727,7 @@ mod constraint_circuit_tests {
 
     #[test]
     fn constant_folding_ram_table_test() {
-        let challenges = Challenges::placeholder(&[], &[]);
+        let challenges = Challenges::placeholder(None);
         let constraint_circuits = ExtRamTable::ext_initial_constraints_as_circuits();
         table_constraints_prop(constraint_circuits, &challenges, "ram initial");
         let constraint_circuits = ExtRamTable::ext_consistency_constraints_as_circuits();
@@ -1740,7 +1740,7 @@ mod constraint_circuit_tests {
 
     #[test]
     fn constant_folding_hash_table_test() {
-        let challenges = Challenges::placeholder(&[], &[]);
+        let challenges = Challenges::placeholder(None);
         let constraint_circuits = ExtHashTable::ext_initial_constraints_as_circuits();
         table_constraints_prop(constraint_circuits, &challenges, "hash initial");
         let constraint_circuits = ExtHashTable::ext_consistency_constraints_as_circuits();
@@ -1753,7 +1753,7 @@ mod constraint_circuit_tests {
 
     #[test]
     fn constant_folding_u32_table_test() {
-        let challenges = Challenges::placeholder(&[], &[]);
+        let challenges = Challenges::placeholder(None);
         let constraint_circuits = ExtU32Table::ext_initial_constraints_as_circuits();
         table_constraints_prop(constraint_circuits, &challenges, "u32 initial");
         let constraint_circuits = ExtU32Table::ext_consistency_constraints_as_circuits();
@@ -1766,7 +1766,7 @@ mod constraint_circuit_tests {
 
     #[test]
     fn constant_folding_cascade_table_test() {
-        let challenges = Challenges::placeholder(&[], &[]);
+        let challenges = Challenges::placeholder(None);
         let constraint_circuits = ExtCascadeTable::ext_initial_constraints_as_circuits();
         table_constraints_prop(constraint_circuits, &challenges, "cascade initial");
         let constraint_circuits = ExtCascadeTable::ext_consistency_constraints_as_circuits();
@@ -1779,7 +1779,7 @@ mod constraint_circuit_tests {
 
     #[test]
     fn constant_folding_lookup_table_test() {
-        let challenges = Challenges::placeholder(&[], &[]);
+        let challenges = Challenges::placeholder(None);
         let constraint_circuits = ExtLookupTable::ext_initial_constraints_as_circuits();
         table_constraints_prop(constraint_circuits, &challenges, "lookup initial");
         let constraint_circuits = ExtLookupTable::ext_consistency_constraints_as_circuits();
@@ -2,8 +2,12 @@ pub mod cascade_table_constraints;
 pub mod hash_table_constraints;
 pub mod jump_stack_table_constraints;
 pub mod lookup_table_constraints;
+pub mod memory_table_constraints;
 pub mod op_stack_table_constraints;
 pub mod processor_table_constraints;
 pub mod program_table_constraints;
 pub mod ram_table_constraints;
+pub mod register_table_constraints;
+pub mod tape_table_constraints;
 pub mod u32_table_constraints;
+pub mod zmips_processor_table_constraints;
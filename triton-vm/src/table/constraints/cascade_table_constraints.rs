@@ -20,34 +20,34 @@ impl Evaluable<BFieldElement> for ExtCascadeTable {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_29 = (BFieldElement::new(1)) - (base_row[128]);
-        let node_35 = ((challenges.get_challenge(LookupTableInputWeight)) * (base_row[130]))
-            + ((challenges.get_challenge(LookupTableOutputWeight)) * (base_row[132]));
-        let node_38 = ((challenges.get_challenge(LookupTableInputWeight)) * (base_row[129]))
-            + ((challenges.get_challenge(LookupTableOutputWeight)) * (base_row[131]));
+        let node_29 = (BFieldElement::new(1)) - (base_row[130]);
+        let node_35 = ((challenges.get_challenge(LookupTableInputWeight)) * (base_row[132]))
+            + ((challenges.get_challenge(LookupTableOutputWeight)) * (base_row[134]));
+        let node_38 = ((challenges.get_challenge(LookupTableInputWeight)) * (base_row[131]))
+            + ((challenges.get_challenge(LookupTableOutputWeight)) * (base_row[133]));
 
         let base_constraints: [BFieldElement; 0] = [];
         let ext_constraints = [
             ((node_29)
-                * (((ext_row[41])
+                * (((ext_row[42])
                     * ((challenges.get_challenge(HashCascadeLookupIndeterminate))
                         - (((challenges.get_challenge(HashCascadeLookInWeight))
-                            * (((BFieldElement::new(256)) * (base_row[129]))
-                                + (base_row[130])))
+                            * (((BFieldElement::new(256)) * (base_row[131]))
+                                + (base_row[132])))
                             + ((challenges.get_challenge(HashCascadeLookOutWeight))
-                                * (((BFieldElement::new(256)) * (base_row[131]))
-                                    + (base_row[132]))))))
-                    - (base_row[133])))
-                + ((base_row[128]) * (ext_row[41])),
+                                * (((BFieldElement::new(256)) * (base_row[133]))
+                                    + (base_row[134]))))))
+                    - (base_row[135])))
+                + ((base_row[130]) * (ext_row[42])),
             ((node_29)
-                * ((((((ext_row[42])
+                * ((((((ext_row[43])
                     * ((challenges.get_challenge(CascadeLookupIndeterminate)) - (node_35)))
                     * ((challenges.get_challenge(CascadeLookupIndeterminate)) - (node_38)))
                     - ((BFieldElement::new(2))
                         * (challenges.get_challenge(CascadeLookupIndeterminate))))
                     + (node_35))
                     + (node_38)))
-                + ((base_row[128]) * (ext_row[42])),
+                + ((base_row[130]) * (ext_row[43])),
         ];
         base_constraints
             .into_iter()
@@ -63,7 +63,7 @@ impl Evaluable<BFieldElement> for ExtCascadeTable {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let base_constraints = [(base_row[128]) * ((BFieldElement::new(1)) - (base_row[128]))];
+        let base_constraints = [(base_row[130]) * ((BFieldElement::new(1)) - (base_row[130]))];
         let ext_constraints = [];
         base_constraints
             .into_iter()
@@ -81,27 +81,27 @@ impl Evaluable<BFieldElement> for ExtCascadeTable {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_20 = (BFieldElement::new(1)) - (next_base_row[128]);
-        let node_29 = (next_ext_row[41]) - (current_ext_row[41]);
-        let node_42 = (next_ext_row[42]) - (current_ext_row[42]);
-        let node_38 = ((challenges.get_challenge(LookupTableInputWeight)) * (next_base_row[130]))
-            + ((challenges.get_challenge(LookupTableOutputWeight)) * (next_base_row[132]));
-        let node_41 = ((challenges.get_challenge(LookupTableInputWeight)) * (next_base_row[129]))
-            + ((challenges.get_challenge(LookupTableOutputWeight)) * (next_base_row[131]));
+        let node_20 = (BFieldElement::new(1)) - (next_base_row[130]);
+        let node_29 = (next_ext_row[42]) - (current_ext_row[42]);
+        let node_42 = (next_ext_row[43]) - (current_ext_row[43]);
+        let node_38 = ((challenges.get_challenge(LookupTableInputWeight)) * (next_base_row[132]))
+            + ((challenges.get_challenge(LookupTableOutputWeight)) * (next_base_row[134]));
+        let node_41 = ((challenges.get_challenge(LookupTableInputWeight)) * (next_base_row[131]))
+            + ((challenges.get_challenge(LookupTableOutputWeight)) * (next_base_row[133]));
 
-        let base_constraints = [(current_base_row[128]) * (node_20)];
+        let base_constraints = [(current_base_row[130]) * (node_20)];
         let ext_constraints = [
             ((node_20)
                 * (((node_29)
                     * ((challenges.get_challenge(HashCascadeLookupIndeterminate))
                         - (((challenges.get_challenge(HashCascadeLookInWeight))
-                            * (((BFieldElement::new(256)) * (next_base_row[129]))
-                                + (next_base_row[130])))
+                            * (((BFieldElement::new(256)) * (next_base_row[131]))
+                                + (next_base_row[132])))
                             + ((challenges.get_challenge(HashCascadeLookOutWeight))
-                                * (((BFieldElement::new(256)) * (next_base_row[131]))
-                                    + (next_base_row[132]))))))
-                    - (next_base_row[133])))
-                + ((next_base_row[128]) * (node_29)),
+                                * (((BFieldElement::new(256)) * (next_base_row[133]))
+                                    + (next_base_row[134]))))))
+                    - (next_base_row[135])))
+                + ((next_base_row[130]) * (node_29)),
             ((node_20)
                 * ((((((node_42)
                     * ((challenges.get_challenge(CascadeLookupIndeterminate)) - (node_38)))
@@ -110,7 +110,7 @@ impl Evaluable<BFieldElement> for ExtCascadeTable {
                         * (challenges.get_challenge(CascadeLookupIndeterminate))))
                     + (node_38))
                     + (node_41)))
-                + ((next_base_row[128]) * (node_42)),
+                + ((next_base_row[130]) * (node_42)),
         ];
         base_constraints
             .into_iter()
@@ -138,34 +138,34 @@ impl Evaluable<XFieldElement> for ExtCascadeTable {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_29 = (BFieldElement::new(1)) - (base_row[128]);
-        let node_35 = ((challenges.get_challenge(LookupTableInputWeight)) * (base_row[130]))
-            + ((challenges.get_challenge(LookupTableOutputWeight)) * (base_row[132]));
-        let node_38 = ((challenges.get_challenge(LookupTableInputWeight)) * (base_row[129]))
-            + ((challenges.get_challenge(LookupTableOutputWeight)) * (base_row[131]));
+        let node_29 = (BFieldElement::new(1)) - (base_row[130]);
+        let node_35 = ((challenges.get_challenge(LookupTableInputWeight)) * (base_row[132]))
+            + ((challenges.get_challenge(LookupTableOutputWeight)) * (base_row[134]));
+        let node_38 = ((challenges.get_challenge(LookupTableInputWeight)) * (base_row[131]))
+            + ((challenges.get_challenge(LookupTableOutputWeight)) * (base_row[133]));
 
         let base_constraints = [];
         let ext_constraints = [
             ((node_29)
-                * (((ext_row[41])
+                * (((ext_row[42])
                     * ((challenges.get_challenge(HashCascadeLookupIndeterminate))
                         - (((challenges.get_challenge(HashCascadeLookInWeight))
-                            * (((BFieldElement::new(256)) * (base_row[129]))
-                                + (base_row[130])))
+                            * (((BFieldElement::new(256)) * (base_row[131]))
+                                + (base_row[132])))
                             + ((challenges.get_challenge(HashCascadeLookOutWeight))
-                                * (((BFieldElement::new(256)) * (base_row[131]))
-                                    + (base_row[132]))))))
-                    - (base_row[133])))
-                + ((base_row[128]) * (ext_row[41])),
+                                * (((BFieldElement::new(256)) * (base_row[133]))
+                                    + (base_row[134]))))))
+                    - (base_row[135])))
+                + ((base_row[130]) * (ext_row[42])),
             ((node_29)
-                * ((((((ext_row[42])
+                * ((((((ext_row[43])
                     * ((challenges.get_challenge(CascadeLookupIndeterminate)) - (node_35)))
                     * ((challenges.get_challenge(CascadeLookupIndeterminate)) - (node_38)))
                     - ((BFieldElement::new(2))
                         * (challenges.get_challenge(CascadeLookupIndeterminate))))
                     + (node_35))
                     + (node_38)))
-                + ((base_row[128]) * (ext_row[42])),
+                + ((base_row[130]) * (ext_row[43])),
         ];
         base_constraints
             .into_iter()
@@ -180,7 +180,7 @@ impl Evaluable<XFieldElement> for ExtCascadeTable {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let base_constraints = [(base_row[128]) * ((BFieldElement::new(1)) - (base_row[128]))];
+        let base_constraints = [(base_row[130]) * ((BFieldElement::new(1)) - (base_row[130]))];
         let ext_constraints = [];
         base_constraints
             .into_iter()
@@ -197,27 +197,27 @@ impl Evaluable<XFieldElement> for ExtCascadeTable {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_20 = (BFieldElement::new(1)) - (next_base_row[128]);
-        let node_29 = (next_ext_row[41]) - (current_ext_row[41]);
-        let node_42 = (next_ext_row[42]) - (current_ext_row[42]);
-        let node_38 = ((challenges.get_challenge(LookupTableInputWeight)) * (next_base_row[130]))
-            + ((challenges.get_challenge(LookupTableOutputWeight)) * (next_base_row[132]));
-        let node_41 = ((challenges.get_challenge(LookupTableInputWeight)) * (next_base_row[129]))
-            + ((challenges.get_challenge(LookupTableOutputWeight)) * (next_base_row[131]));
+        let node_20 = (BFieldElement::new(1)) - (next_base_row[130]);
+        let node_29 = (next_ext_row[42]) - (current_ext_row[42]);
+        let node_42 = (next_ext_row[43]) - (current_ext_row[43]);
+        let node_38 = ((challenges.get_challenge(LookupTableInputWeight)) * (next_base_row[132]))
+            + ((challenges.get_challenge(LookupTableOutputWeight)) * (next_base_row[134]));
+        let node_41 = ((challenges.get_challenge(LookupTableInputWeight)) * (next_base_row[131]))
+            + ((challenges.get_challenge(LookupTableOutputWeight)) * (next_base_row[133]));
 
-        let base_constraints = [(current_base_row[128]) * (node_20)];
+        let base_constraints = [(current_base_row[130]) * (node_20)];
         let ext_constraints = [
             ((node_20)
                 * (((node_29)
                     * ((challenges.get_challenge(HashCascadeLookupIndeterminate))
                         - (((challenges.get_challenge(HashCascadeLookInWeight))
-                            * (((BFieldElement::new(256)) * (next_base_row[129]))
-                                + (next_base_row[130])))
+                            * (((BFieldElement::new(256)) * (next_base_row[131]))
+                                + (next_base_row[132])))
                             + ((challenges.get_challenge(HashCascadeLookOutWeight))
-                                * (((BFieldElement::new(256)) * (next_base_row[131]))
-                                    + (next_base_row[132]))))))
-                    - (next_base_row[133])))
-                + ((next_base_row[128]) * (node_29)),
+                                * (((BFieldElement::new(256)) * (next_base_row[133]))
+                                    + (next_base_row[134]))))))
+                    - (next_base_row[135])))
+                + ((next_base_row[130]) * (node_29)),
             ((node_20)
                 * ((((((node_42)
                     * ((challenges.get_challenge(CascadeLookupIndeterminate)) - (node_38)))
@@ -226,7 +226,7 @@ impl Evaluable<XFieldElement> for ExtCascadeTable {
                         * (challenges.get_challenge(CascadeLookupIndeterminate))))
                     + (node_38))
                     + (node_41)))
-                + ((next_base_row[128]) * (node_42)),
+                + ((next_base_row[130]) * (node_42)),
         ];
         base_constraints
             .into_iter()
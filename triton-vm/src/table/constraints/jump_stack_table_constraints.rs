@@ -20,12 +20,12 @@ impl Evaluable<BFieldElement> for ExtJumpStackTable {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let base_constraints = [base_row[57], base_row[59], base_row[60], base_row[61]];
+        let base_constraints = [base_row[59], base_row[61], base_row[62], base_row[63]];
         let ext_constraints = [
-            (ext_row[20])
+            (ext_row[21])
                 - ((challenges.get_challenge(JumpStackIndeterminate))
-                    - ((challenges.get_challenge(JumpStackCiWeight)) * (base_row[58]))),
-            ext_row[21],
+                    - ((challenges.get_challenge(JumpStackCiWeight)) * (base_row[60]))),
+            ext_row[22],
         ];
         base_constraints
             .into_iter()
@@ -53,35 +53,35 @@ impl Evaluable<BFieldElement> for ExtJumpStackTable {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_17 = (next_base_row[59]) - (current_base_row[59]);
+        let node_17 = (next_base_row[61]) - (current_base_row[61]);
         let node_18 = (node_17) - (BFieldElement::new(1));
-        let node_20 = (current_base_row[58]) - (BFieldElement::new(24));
+        let node_20 = (current_base_row[60]) - (BFieldElement::new(24));
         let node_21 = (node_18) * (node_20);
-        let node_26 = (next_base_row[57]) - (current_base_row[57]);
-        let node_50 = (next_ext_row[21]) - (current_ext_row[21]);
+        let node_26 = (next_base_row[59]) - (current_base_row[59]);
+        let node_50 = (next_ext_row[22]) - (current_ext_row[22]);
 
         let base_constraints = [
             (node_18) * (node_17),
-            (node_21) * ((next_base_row[60]) - (current_base_row[60])),
-            (node_21) * ((next_base_row[61]) - (current_base_row[61])),
+            (node_21) * ((next_base_row[62]) - (current_base_row[62])),
+            (node_21) * ((next_base_row[63]) - (current_base_row[63])),
             (((node_18) * ((node_26) - (BFieldElement::new(1))))
-                * ((current_base_row[58]) - (BFieldElement::new(25))))
+                * ((current_base_row[60]) - (BFieldElement::new(25))))
                 * (node_20),
         ];
         let ext_constraints = [
-            (next_ext_row[20])
-                - ((current_ext_row[20])
+            (next_ext_row[21])
+                - ((current_ext_row[21])
                     * ((challenges.get_challenge(JumpStackIndeterminate))
                         - ((((((challenges.get_challenge(JumpStackClkWeight))
-                            * (next_base_row[57]))
+                            * (next_base_row[59]))
                             + ((challenges.get_challenge(JumpStackCiWeight))
-                                * (next_base_row[58])))
+                                * (next_base_row[60])))
                             + ((challenges.get_challenge(JumpStackJspWeight))
-                                * (next_base_row[59])))
+                                * (next_base_row[61])))
                             + ((challenges.get_challenge(JumpStackJsoWeight))
-                                * (next_base_row[60])))
+                                * (next_base_row[62])))
                             + ((challenges.get_challenge(JumpStackJsdWeight))
-                                * (next_base_row[61]))))),
+                                * (next_base_row[63]))))),
             ((node_18)
                 * (((node_50)
                     * ((challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate))
@@ -115,12 +115,12 @@ impl Evaluable<XFieldElement> for ExtJumpStackTable {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let base_constraints = [base_row[57], base_row[59], base_row[60], base_row[61]];
+        let base_constraints = [base_row[59], base_row[61], base_row[62], base_row[63]];
         let ext_constraints = [
-            (ext_row[20])
+            (ext_row[21])
                 - ((challenges.get_challenge(JumpStackIndeterminate))
-                    - ((challenges.get_challenge(JumpStackCiWeight)) * (base_row[58]))),
-            ext_row[21],
+                    - ((challenges.get_challenge(JumpStackCiWeight)) * (base_row[60]))),
+            ext_row[22],
         ];
         base_constraints
             .into_iter()
@@ -147,35 +147,35 @@ impl Evaluable<XFieldElement> for ExtJumpStackTable {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_17 = (next_base_row[59]) - (current_base_row[59]);
+        let node_17 = (next_base_row[61]) - (current_base_row[61]);
         let node_18 = (node_17) - (BFieldElement::new(1));
-        let node_20 = (current_base_row[58]) - (BFieldElement::new(24));
+        let node_20 = (current_base_row[60]) - (BFieldElement::new(24));
         let node_21 = (node_18) * (node_20);
-        let node_26 = (next_base_row[57]) - (current_base_row[57]);
-        let node_50 = (next_ext_row[21]) - (current_ext_row[21]);
+        let node_26 = (next_base_row[59]) - (current_base_row[59]);
+        let node_50 = (next_ext_row[22]) - (current_ext_row[22]);
 
         let base_constraints = [
             (node_18) * (node_17),
-            (node_21) * ((next_base_row[60]) - (current_base_row[60])),
-            (node_21) * ((next_base_row[61]) - (current_base_row[61])),
+            (node_21) * ((next_base_row[62]) - (current_base_row[62])),
+            (node_21) * ((next_base_row[63]) - (current_base_row[63])),
             (((node_18) * ((node_26) - (BFieldElement::new(1))))
-                * ((current_base_row[58]) - (BFieldElement::new(25))))
+                * ((current_base_row[60]) - (BFieldElement::new(25))))
                 * (node_20),
         ];
         let ext_constraints = [
-            (next_ext_row[20])
-                - ((current_ext_row[20])
+            (next_ext_row[21])
+                - ((current_ext_row[21])
                     * ((challenges.get_challenge(JumpStackIndeterminate))
                         - ((((((challenges.get_challenge(JumpStackClkWeight))
-                            * (next_base_row[57]))
+                            * (next_base_row[59]))
                             + ((challenges.get_challenge(JumpStackCiWeight))
-                                * (next_base_row[58])))
+                                * (next_base_row[60])))
                             + ((challenges.get_challenge(JumpStackJspWeight))
-                                * (next_base_row[59])))
+                                * (next_base_row[61])))
                             + ((challenges.get_challenge(JumpStackJsoWeight))
-                                * (next_base_row[60])))
+                                * (next_base_row[62])))
                             + ((challenges.get_challenge(JumpStackJsdWeight))
-                                * (next_base_row[61]))))),
+                                * (next_base_row[63]))))),
             ((node_18)
                 * (((node_50)
                     * ((challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate))
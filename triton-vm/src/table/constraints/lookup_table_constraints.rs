@@ -20,14 +20,14 @@ impl Evaluable<BFieldElement> for ExtLookupTable {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let base_constraints = [base_row[135]];
+        let base_constraints = [base_row[137]];
         let ext_constraints = [
-            ((ext_row[43])
+            ((ext_row[44])
                 * ((challenges.get_challenge(CascadeLookupIndeterminate))
-                    - ((base_row[136]) * (challenges.get_challenge(LookupTableOutputWeight)))))
-                - (base_row[137]),
-            ((ext_row[44]) - (challenges.get_challenge(LookupTablePublicIndeterminate)))
-                - (base_row[136]),
+                    - ((base_row[138]) * (challenges.get_challenge(LookupTableOutputWeight)))))
+                - (base_row[139]),
+            ((ext_row[45]) - (challenges.get_challenge(LookupTablePublicIndeterminate)))
+                - (base_row[138]),
         ];
         base_constraints
             .into_iter()
@@ -43,7 +43,7 @@ impl Evaluable<BFieldElement> for ExtLookupTable {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let base_constraints = [(base_row[134]) * ((BFieldElement::new(1)) - (base_row[134]))];
+        let base_constraints = [(base_row[136]) * ((BFieldElement::new(1)) - (base_row[136]))];
         let ext_constraints = [];
         base_constraints
             .into_iter()
@@ -61,31 +61,31 @@ impl Evaluable<BFieldElement> for ExtLookupTable {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_11 = (BFieldElement::new(1)) - (next_base_row[134]);
-        let node_24 = (next_ext_row[43]) - (current_ext_row[43]);
+        let node_11 = (BFieldElement::new(1)) - (next_base_row[136]);
+        let node_24 = (next_ext_row[44]) - (current_ext_row[44]);
 
         let base_constraints = [
-            (current_base_row[134]) * (node_11),
-            ((next_base_row[134]) * (next_base_row[135]))
+            (current_base_row[136]) * (node_11),
+            ((next_base_row[136]) * (next_base_row[137]))
                 + ((node_11)
-                    * (((next_base_row[135]) - (current_base_row[135])) - (BFieldElement::new(1)))),
+                    * (((next_base_row[137]) - (current_base_row[137])) - (BFieldElement::new(1)))),
         ];
         let ext_constraints = [
             ((node_11)
                 * (((node_24)
                     * ((challenges.get_challenge(CascadeLookupIndeterminate))
-                        - (((next_base_row[135])
+                        - (((next_base_row[137])
                             * (challenges.get_challenge(LookupTableInputWeight)))
-                            + ((next_base_row[136])
+                            + ((next_base_row[138])
                                 * (challenges.get_challenge(LookupTableOutputWeight))))))
-                    - (next_base_row[137])))
-                + ((next_base_row[134]) * (node_24)),
+                    - (next_base_row[139])))
+                + ((next_base_row[136]) * (node_24)),
             ((node_11)
-                * (((next_ext_row[44])
-                    - ((current_ext_row[44])
+                * (((next_ext_row[45])
+                    - ((current_ext_row[45])
                         * (challenges.get_challenge(LookupTablePublicIndeterminate))))
-                    - (next_base_row[136])))
-                + ((next_base_row[134]) * ((next_ext_row[44]) - (current_ext_row[44]))),
+                    - (next_base_row[138])))
+                + ((next_base_row[136]) * ((next_ext_row[45]) - (current_ext_row[45]))),
         ];
         base_constraints
             .into_iter()
@@ -103,7 +103,7 @@ impl Evaluable<BFieldElement> for ExtLookupTable {
     ) -> Vec<XFieldElement> {
         let base_constraints: [BFieldElement; 0] = [];
         let ext_constraints =
-            [(ext_row[44]) - (challenges.get_challenge(LookupTablePublicTerminal))];
+            [(ext_row[45]) - (challenges.get_challenge(LookupTablePublicTerminal))];
         base_constraints
             .into_iter()
             .map(|bfe| bfe.lift())
@@ -120,14 +120,14 @@ impl Evaluable<XFieldElement> for ExtLookupTable {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let base_constraints = [base_row[135]];
+        let base_constraints = [base_row[137]];
         let ext_constraints = [
-            ((ext_row[43])
+            ((ext_row[44])
                 * ((challenges.get_challenge(CascadeLookupIndeterminate))
-                    - ((base_row[136]) * (challenges.get_challenge(LookupTableOutputWeight)))))
-                - (base_row[137]),
-            ((ext_row[44]) - (challenges.get_challenge(LookupTablePublicIndeterminate)))
-                - (base_row[136]),
+                    - ((base_row[138]) * (challenges.get_challenge(LookupTableOutputWeight)))))
+                - (base_row[139]),
+            ((ext_row[45]) - (challenges.get_challenge(LookupTablePublicIndeterminate)))
+                - (base_row[138]),
         ];
         base_constraints
             .into_iter()
@@ -142,7 +142,7 @@ impl Evaluable<XFieldElement> for ExtLookupTable {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let base_constraints = [(base_row[134]) * ((BFieldElement::new(1)) - (base_row[134]))];
+        let base_constraints = [(base_row[136]) * ((BFieldElement::new(1)) - (base_row[136]))];
         let ext_constraints = [];
         base_constraints
             .into_iter()
@@ -159,31 +159,31 @@ impl Evaluable<XFieldElement> for ExtLookupTable {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_11 = (BFieldElement::new(1)) - (next_base_row[134]);
-        let node_24 = (next_ext_row[43]) - (current_ext_row[43]);
+        let node_11 = (BFieldElement::new(1)) - (next_base_row[136]);
+        let node_24 = (next_ext_row[44]) - (current_ext_row[44]);
 
         let base_constraints = [
-            (current_base_row[134]) * (node_11),
-            ((next_base_row[134]) * (next_base_row[135]))
+            (current_base_row[136]) * (node_11),
+            ((next_base_row[136]) * (next_base_row[137]))
                 + ((node_11)
-                    * (((next_base_row[135]) - (current_base_row[135])) - (BFieldElement::new(1)))),
+                    * (((next_base_row[137]) - (current_base_row[137])) - (BFieldElement::new(1)))),
         ];
         let ext_constraints = [
             ((node_11)
                 * (((node_24)
                     * ((challenges.get_challenge(CascadeLookupIndeterminate))
-                        - (((next_base_row[135])
+                        - (((next_base_row[137])
                             * (challenges.get_challenge(LookupTableInputWeight)))
-                            + ((next_base_row[136])
+                            + ((next_base_row[138])
                                 * (challenges.get_challenge(LookupTableOutputWeight))))))
-                    - (next_base_row[137])))
-                + ((next_base_row[134]) * (node_24)),
+                    - (next_base_row[139])))
+                + ((next_base_row[136]) * (node_24)),
             ((node_11)
-                * (((next_ext_row[44])
-                    - ((current_ext_row[44])
+                * (((next_ext_row[45])
+                    - ((current_ext_row[45])
                         * (challenges.get_challenge(LookupTablePublicIndeterminate))))
-                    - (next_base_row[136])))
-                + ((next_base_row[134]) * ((next_ext_row[44]) - (current_ext_row[44]))),
+                    - (next_base_row[138])))
+                + ((next_base_row[136]) * ((next_ext_row[45]) - (current_ext_row[45]))),
         ];
         base_constraints
             .into_iter()
@@ -200,7 +200,7 @@ impl Evaluable<XFieldElement> for ExtLookupTable {
     ) -> Vec<XFieldElement> {
         let base_constraints = [];
         let ext_constraints =
-            [(ext_row[44]) - (challenges.get_challenge(LookupTablePublicTerminal))];
+            [(ext_row[45]) - (challenges.get_challenge(LookupTablePublicTerminal))];
         base_constraints
             .into_iter()
             .chain(ext_constraints.into_iter())
@@ -0,0 +1,425 @@
+use ndarray::ArrayView1;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::mpolynomial::Degree;
+use twenty_first::shared_math::x_field_element::XFieldElement;
+
+use crate::table::challenges::ChallengeId::*;
+use crate::table::challenges::Challenges;
+use crate::table::extension_table::Evaluable;
+use crate::table::extension_table::Quotientable;
+use crate::table::memory_table::ExtMemoryTable;
+
+// This file has been auto-generated. Any modifications _will_ be lost.
+// To re-generate, execute:
+// `cargo run --bin constraint-evaluation-generator`
+impl Evaluable<BFieldElement> for ExtMemoryTable {
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_initial_constraints(
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let base_constraints = [
+            ((BFieldElement::new(1)) - (base_row[255]))
+                * ((BFieldElement::new(1)) - (base_row[256])),
+            base_row[263],
+        ];
+        let ext_constraints = [
+            (ext_row[58]) - (BFieldElement::new(1)),
+            ext_row[59],
+            ((base_row[257])
+                * ((ext_row[64])
+                    - ((challenges.get_challenge(DataSegmentIndeterminate))
+                        - (((challenges.get_challenge(DataSegmentAddressWeight))
+                            * (base_row[259]))
+                            + ((challenges.get_challenge(DataSegmentValueWeight))
+                                * (base_row[260]))))))
+                + (((BFieldElement::new(1)) - (base_row[257]))
+                    * ((ext_row[64]) - (BFieldElement::new(1)))),
+            ext_row[62],
+            (ext_row[63]) - (base_row[264]),
+            (ext_row[61]) - (BFieldElement::new(1)),
+            (ext_row[60])
+                - ((challenges.get_challenge(MemoryTableBezoutRelationIndeterminate))
+                    - (base_row[259])),
+        ];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_consistency_constraints(
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let base_constraints = [
+            (base_row[255]) * ((base_row[255]) - (BFieldElement::new(1))),
+            (base_row[256]) * ((base_row[256]) - (BFieldElement::new(1))),
+            (base_row[257]) * ((base_row[257]) - (BFieldElement::new(1))),
+            (base_row[261]) * ((base_row[261]) - (BFieldElement::new(1))),
+            (base_row[257]) * ((BFieldElement::new(1)) - (base_row[256])),
+        ];
+        let ext_constraints = [];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_transition_constraints(
+        current_base_row: ArrayView1<BFieldElement>,
+        current_ext_row: ArrayView1<XFieldElement>,
+        next_base_row: ArrayView1<BFieldElement>,
+        next_ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_9 = (next_base_row[259]) - (current_base_row[259]);
+        let node_10 = (node_9) * (current_base_row[262]);
+        let node_24 = (BFieldElement::new(1)) - (node_10);
+        let node_18 = (BFieldElement::new(1)) - (next_base_row[256]);
+        let node_70 = (node_18) * ((BFieldElement::new(1)) - (next_base_row[255]));
+        let node_11 = (node_10) - (BFieldElement::new(1));
+        let node_35 = (challenges.get_challenge(MemoryTableBezoutRelationIndeterminate))
+            - (next_base_row[259]);
+        let node_71 = (BFieldElement::new(1)) - (node_70);
+        let node_96 = (next_ext_row[59]) - (current_ext_row[59]);
+
+        let base_constraints = [
+            (current_base_row[255]) * ((next_base_row[255]) - (BFieldElement::new(1))),
+            (current_base_row[262]) * (node_11),
+            (node_9) * (node_11),
+            (next_base_row[256]) - (node_10),
+            (((BFieldElement::new(1)) - (next_base_row[261])) * (node_18))
+                * ((next_base_row[260]) - (current_base_row[260])),
+            (node_24) * ((next_base_row[263]) - (current_base_row[263])),
+            (node_24) * ((next_base_row[264]) - (current_base_row[264])),
+        ];
+        let ext_constraints = [
+            ((node_9) * ((next_ext_row[60]) - ((current_ext_row[60]) * (node_35))))
+                + ((node_24) * ((next_ext_row[60]) - (current_ext_row[60]))),
+            ((node_9)
+                * (((next_ext_row[61]) - (current_ext_row[60]))
+                    - ((node_35) * (current_ext_row[61]))))
+                + ((node_24) * ((next_ext_row[61]) - (current_ext_row[61]))),
+            ((node_9)
+                * (((next_ext_row[62])
+                    - ((challenges.get_challenge(MemoryTableBezoutRelationIndeterminate))
+                        * (current_ext_row[62])))
+                    - (next_base_row[263])))
+                + ((node_24) * ((next_ext_row[62]) - (current_ext_row[62]))),
+            ((node_9)
+                * (((next_ext_row[63])
+                    - ((challenges.get_challenge(MemoryTableBezoutRelationIndeterminate))
+                        * (current_ext_row[63])))
+                    - (next_base_row[264])))
+                + ((node_24) * ((next_ext_row[63]) - (current_ext_row[63]))),
+            ((node_70)
+                * ((next_ext_row[58])
+                    - ((current_ext_row[58])
+                        * ((challenges.get_challenge(MemoryIndeterminate))
+                            - (((((challenges.get_challenge(MemoryClkWeight))
+                                * (next_base_row[258]))
+                                + ((challenges.get_challenge(MemoryAddressWeight))
+                                    * (next_base_row[259])))
+                                + ((challenges.get_challenge(MemoryValueWeight))
+                                    * (next_base_row[260])))
+                                + ((challenges.get_challenge(MemoryIsWriteWeight))
+                                    * (next_base_row[261])))))))
+                + ((node_71) * ((next_ext_row[58]) - (current_ext_row[58]))),
+            ((node_70)
+                * (((node_96)
+                    * ((challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate))
+                        - ((next_base_row[258]) - (current_base_row[258]))))
+                    - (BFieldElement::new(1))))
+                + ((node_71) * (node_96)),
+            ((next_base_row[257])
+                * ((next_ext_row[64])
+                    - ((current_ext_row[64])
+                        * ((challenges.get_challenge(DataSegmentIndeterminate))
+                            - (((challenges.get_challenge(DataSegmentAddressWeight))
+                                * (next_base_row[259]))
+                                + ((challenges.get_challenge(DataSegmentValueWeight))
+                                    * (next_base_row[260])))))))
+                + (((BFieldElement::new(1)) - (next_base_row[257]))
+                    * ((next_ext_row[64]) - (current_ext_row[64]))),
+        ];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_terminal_constraints(
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let base_constraints: [BFieldElement; 0] = [];
+        let ext_constraints = [
+            (((ext_row[62]) * (ext_row[60])) + ((ext_row[63]) * (ext_row[61])))
+                - (BFieldElement::new(1)),
+        ];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+}
+
+impl Evaluable<XFieldElement> for ExtMemoryTable {
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_initial_constraints(
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let base_constraints = [
+            ((BFieldElement::new(1)) - (base_row[255]))
+                * ((BFieldElement::new(1)) - (base_row[256])),
+            base_row[263],
+        ];
+        let ext_constraints = [
+            (ext_row[58]) - (BFieldElement::new(1)),
+            ext_row[59],
+            ((base_row[257])
+                * ((ext_row[64])
+                    - ((challenges.get_challenge(DataSegmentIndeterminate))
+                        - (((challenges.get_challenge(DataSegmentAddressWeight))
+                            * (base_row[259]))
+                            + ((challenges.get_challenge(DataSegmentValueWeight))
+                                * (base_row[260]))))))
+                + (((BFieldElement::new(1)) - (base_row[257]))
+                    * ((ext_row[64]) - (BFieldElement::new(1)))),
+            ext_row[62],
+            (ext_row[63]) - (base_row[264]),
+            (ext_row[61]) - (BFieldElement::new(1)),
+            (ext_row[60])
+                - ((challenges.get_challenge(MemoryTableBezoutRelationIndeterminate))
+                    - (base_row[259])),
+        ];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_consistency_constraints(
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let base_constraints = [
+            (base_row[255]) * ((base_row[255]) - (BFieldElement::new(1))),
+            (base_row[256]) * ((base_row[256]) - (BFieldElement::new(1))),
+            (base_row[257]) * ((base_row[257]) - (BFieldElement::new(1))),
+            (base_row[261]) * ((base_row[261]) - (BFieldElement::new(1))),
+            (base_row[257]) * ((BFieldElement::new(1)) - (base_row[256])),
+        ];
+        let ext_constraints = [];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_transition_constraints(
+        current_base_row: ArrayView1<XFieldElement>,
+        current_ext_row: ArrayView1<XFieldElement>,
+        next_base_row: ArrayView1<XFieldElement>,
+        next_ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_9 = (next_base_row[259]) - (current_base_row[259]);
+        let node_10 = (node_9) * (current_base_row[262]);
+        let node_24 = (BFieldElement::new(1)) - (node_10);
+        let node_18 = (BFieldElement::new(1)) - (next_base_row[256]);
+        let node_70 = (node_18) * ((BFieldElement::new(1)) - (next_base_row[255]));
+        let node_11 = (node_10) - (BFieldElement::new(1));
+        let node_35 = (challenges.get_challenge(MemoryTableBezoutRelationIndeterminate))
+            - (next_base_row[259]);
+        let node_71 = (BFieldElement::new(1)) - (node_70);
+        let node_96 = (next_ext_row[59]) - (current_ext_row[59]);
+
+        let base_constraints = [
+            (current_base_row[255]) * ((next_base_row[255]) - (BFieldElement::new(1))),
+            (current_base_row[262]) * (node_11),
+            (node_9) * (node_11),
+            (next_base_row[256]) - (node_10),
+            (((BFieldElement::new(1)) - (next_base_row[261])) * (node_18))
+                * ((next_base_row[260]) - (current_base_row[260])),
+            (node_24) * ((next_base_row[263]) - (current_base_row[263])),
+            (node_24) * ((next_base_row[264]) - (current_base_row[264])),
+        ];
+        let ext_constraints = [
+            ((node_9) * ((next_ext_row[60]) - ((current_ext_row[60]) * (node_35))))
+                + ((node_24) * ((next_ext_row[60]) - (current_ext_row[60]))),
+            ((node_9)
+                * (((next_ext_row[61]) - (current_ext_row[60]))
+                    - ((node_35) * (current_ext_row[61]))))
+                + ((node_24) * ((next_ext_row[61]) - (current_ext_row[61]))),
+            ((node_9)
+                * (((next_ext_row[62])
+                    - ((challenges.get_challenge(MemoryTableBezoutRelationIndeterminate))
+                        * (current_ext_row[62])))
+                    - (next_base_row[263])))
+                + ((node_24) * ((next_ext_row[62]) - (current_ext_row[62]))),
+            ((node_9)
+                * (((next_ext_row[63])
+                    - ((challenges.get_challenge(MemoryTableBezoutRelationIndeterminate))
+                        * (current_ext_row[63])))
+                    - (next_base_row[264])))
+                + ((node_24) * ((next_ext_row[63]) - (current_ext_row[63]))),
+            ((node_70)
+                * ((next_ext_row[58])
+                    - ((current_ext_row[58])
+                        * ((challenges.get_challenge(MemoryIndeterminate))
+                            - (((((challenges.get_challenge(MemoryClkWeight))
+                                * (next_base_row[258]))
+                                + ((challenges.get_challenge(MemoryAddressWeight))
+                                    * (next_base_row[259])))
+                                + ((challenges.get_challenge(MemoryValueWeight))
+                                    * (next_base_row[260])))
+                                + ((challenges.get_challenge(MemoryIsWriteWeight))
+                                    * (next_base_row[261])))))))
+                + ((node_71) * ((next_ext_row[58]) - (current_ext_row[58]))),
+            ((node_70)
+                * (((node_96)
+                    * ((challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate))
+                        - ((next_base_row[258]) - (current_base_row[258]))))
+                    - (BFieldElement::new(1))))
+                + ((node_71) * (node_96)),
+            ((next_base_row[257])
+                * ((next_ext_row[64])
+                    - ((current_ext_row[64])
+                        * ((challenges.get_challenge(DataSegmentIndeterminate))
+                            - (((challenges.get_challenge(DataSegmentAddressWeight))
+                                * (next_base_row[259]))
+                                + ((challenges.get_challenge(DataSegmentValueWeight))
+                                    * (next_base_row[260])))))))
+                + (((BFieldElement::new(1)) - (next_base_row[257]))
+                    * ((next_ext_row[64]) - (current_ext_row[64]))),
+        ];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_terminal_constraints(
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let base_constraints = [];
+        let ext_constraints = [
+            (((ext_row[62]) * (ext_row[60])) + ((ext_row[63]) * (ext_row[61])))
+                - (BFieldElement::new(1)),
+        ];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+}
+
+impl Quotientable for ExtMemoryTable {
+    fn num_initial_quotients() -> usize {
+        9
+    }
+
+    fn num_consistency_quotients() -> usize {
+        5
+    }
+
+    fn num_transition_quotients() -> usize {
+        14
+    }
+
+    fn num_terminal_quotients() -> usize {
+        1
+    }
+
+    #[allow(unused_variables)]
+    fn initial_quotient_degree_bounds(interpolant_degree: Degree) -> Vec<Degree> {
+        let zerofier_degree = 1 as Degree;
+        [
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+        ]
+        .to_vec()
+    }
+
+    #[allow(unused_variables)]
+    fn consistency_quotient_degree_bounds(
+        interpolant_degree: Degree,
+        padded_height: usize,
+    ) -> Vec<Degree> {
+        let zerofier_degree = padded_height as Degree;
+        [
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+        ]
+        .to_vec()
+    }
+
+    #[allow(unused_variables)]
+    fn transition_quotient_degree_bounds(
+        interpolant_degree: Degree,
+        padded_height: usize,
+    ) -> Vec<Degree> {
+        let zerofier_degree = padded_height as Degree - 1;
+        [
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 4 as Degree - zerofier_degree,
+            interpolant_degree * 4 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+        ]
+        .to_vec()
+    }
+
+    #[allow(unused_variables)]
+    fn terminal_quotient_degree_bounds(interpolant_degree: Degree) -> Vec<Degree> {
+        let zerofier_degree = 1 as Degree;
+        [interpolant_degree * 2 as Degree - zerofier_degree].to_vec()
+    }
+}
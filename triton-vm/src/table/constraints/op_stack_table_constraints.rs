@@ -21,17 +21,17 @@ impl Evaluable<BFieldElement> for ExtOpStackTable {
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
         let base_constraints = [
-            base_row[46],
-            base_row[49],
-            (base_row[48]) - (BFieldElement::new(16)),
+            base_row[48],
+            base_row[51],
+            (base_row[50]) - (BFieldElement::new(16)),
         ];
         let ext_constraints = [
-            (ext_row[12])
+            (ext_row[13])
                 - ((challenges.get_challenge(OpStackIndeterminate))
-                    - (((challenges.get_challenge(OpStackIb1Weight)) * (base_row[47]))
+                    - (((challenges.get_challenge(OpStackIb1Weight)) * (base_row[49]))
                         + ((challenges.get_challenge(OpStackOspWeight))
                             * (BFieldElement::new(16))))),
-            ext_row[13],
+            ext_row[14],
         ];
         base_constraints
             .into_iter()
@@ -59,31 +59,31 @@ impl Evaluable<BFieldElement> for ExtOpStackTable {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_13 = (next_base_row[48]) - (current_base_row[48]);
+        let node_13 = (next_base_row[50]) - (current_base_row[50]);
         let node_14 = (node_13) - (BFieldElement::new(1));
-        let node_35 = (next_ext_row[13]) - (current_ext_row[13]);
+        let node_35 = (next_ext_row[14]) - (current_ext_row[14]);
 
         let base_constraints = [
             (node_14) * (node_13),
-            ((node_14) * ((next_base_row[49]) - (current_base_row[49])))
-                * ((BFieldElement::new(1)) - (current_base_row[47])),
+            ((node_14) * ((next_base_row[51]) - (current_base_row[51])))
+                * ((BFieldElement::new(1)) - (current_base_row[49])),
         ];
         let ext_constraints = [
-            (next_ext_row[12])
-                - ((current_ext_row[12])
+            (next_ext_row[13])
+                - ((current_ext_row[13])
                     * ((challenges.get_challenge(OpStackIndeterminate))
                         - (((((challenges.get_challenge(OpStackClkWeight))
-                            * (next_base_row[46]))
+                            * (next_base_row[48]))
                             + ((challenges.get_challenge(OpStackIb1Weight))
-                                * (next_base_row[47])))
+                                * (next_base_row[49])))
                             + ((challenges.get_challenge(OpStackOspWeight))
-                                * (next_base_row[48])))
+                                * (next_base_row[50])))
                             + ((challenges.get_challenge(OpStackOsvWeight))
-                                * (next_base_row[49]))))),
+                                * (next_base_row[51]))))),
             ((node_14)
                 * (((node_35)
                     * ((challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate))
-                        - ((next_base_row[46]) - (current_base_row[46]))))
+                        - ((next_base_row[48]) - (current_base_row[48]))))
                     - (BFieldElement::new(1))))
                 + ((node_13) * (node_35)),
         ];
@@ -114,17 +114,17 @@ impl Evaluable<XFieldElement> for ExtOpStackTable {
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
         let base_constraints = [
-            base_row[46],
-            base_row[49],
-            (base_row[48]) - (BFieldElement::new(16)),
+            base_row[48],
+            base_row[51],
+            (base_row[50]) - (BFieldElement::new(16)),
         ];
         let ext_constraints = [
-            (ext_row[12])
+            (ext_row[13])
                 - ((challenges.get_challenge(OpStackIndeterminate))
-                    - (((challenges.get_challenge(OpStackIb1Weight)) * (base_row[47]))
+                    - (((challenges.get_challenge(OpStackIb1Weight)) * (base_row[49]))
                         + ((challenges.get_challenge(OpStackOspWeight))
                             * (BFieldElement::new(16))))),
-            ext_row[13],
+            ext_row[14],
         ];
         base_constraints
             .into_iter()
@@ -151,31 +151,31 @@ impl Evaluable<XFieldElement> for ExtOpStackTable {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_13 = (next_base_row[48]) - (current_base_row[48]);
+        let node_13 = (next_base_row[50]) - (current_base_row[50]);
         let node_14 = (node_13) - (BFieldElement::new(1));
-        let node_35 = (next_ext_row[13]) - (current_ext_row[13]);
+        let node_35 = (next_ext_row[14]) - (current_ext_row[14]);
 
         let base_constraints = [
             (node_14) * (node_13),
-            ((node_14) * ((next_base_row[49]) - (current_base_row[49])))
-                * ((BFieldElement::new(1)) - (current_base_row[47])),
+            ((node_14) * ((next_base_row[51]) - (current_base_row[51])))
+                * ((BFieldElement::new(1)) - (current_base_row[49])),
         ];
         let ext_constraints = [
-            (next_ext_row[12])
-                - ((current_ext_row[12])
+            (next_ext_row[13])
+                - ((current_ext_row[13])
                     * ((challenges.get_challenge(OpStackIndeterminate))
                         - (((((challenges.get_challenge(OpStackClkWeight))
-                            * (next_base_row[46]))
+                            * (next_base_row[48]))
                             + ((challenges.get_challenge(OpStackIb1Weight))
-                                * (next_base_row[47])))
+                                * (next_base_row[49])))
                             + ((challenges.get_challenge(OpStackOspWeight))
-                                * (next_base_row[48])))
+                                * (next_base_row[50])))
                             + ((challenges.get_challenge(OpStackOsvWeight))
-                                * (next_base_row[49]))))),
+                                * (next_base_row[51]))))),
             ((node_14)
                 * (((node_35)
                     * ((challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate))
-                        - ((next_base_row[46]) - (current_base_row[46]))))
+                        - ((next_base_row[48]) - (current_base_row[48]))))
                     - (BFieldElement::new(1))))
                 + ((node_13) * (node_35)),
         ];
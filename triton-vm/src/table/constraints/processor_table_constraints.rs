@@ -21,10 +21,8 @@ impl Evaluable<BFieldElement> for ExtProcessorTable {
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
         let base_constraints = [
-            base_row[4],
-            base_row[7],
-            base_row[18],
-            base_row[19],
+            base_row[6],
+            base_row[9],
             base_row[20],
             base_row[21],
             base_row[22],
@@ -42,44 +40,50 @@ impl Evaluable<BFieldElement> for ExtProcessorTable {
             base_row[34],
             base_row[35],
             base_row[36],
-            (base_row[37]) - (BFieldElement::new(16)),
+            base_row[37],
             base_row[38],
-            base_row[44],
-            base_row[43],
-            base_row[6],
+            (base_row[39]) - (BFieldElement::new(16)),
+            base_row[40],
+            base_row[46],
+            base_row[45],
+            base_row[8],
         ];
         let ext_constraints = [
-            (ext_row[1]) - (BFieldElement::new(1)),
-            ((ext_row[3])
-                * ((challenges.get_challenge(InstructionLookupIndeterminate))
-                    - (((challenges.get_challenge(ProgramInstructionWeight)) * (base_row[8]))
-                        + ((challenges.get_challenge(ProgramNextInstructionWeight))
-                            * (base_row[9])))))
-                - (BFieldElement::new(1)),
             (ext_row[2]) - (BFieldElement::new(1)),
-            (ext_row[4])
+            (((BFieldElement::new(1)) - (base_row[7]))
+                * (((ext_row[4])
+                    * ((challenges.get_challenge(InstructionLookupIndeterminate))
+                        - (((challenges.get_challenge(ProgramInstructionWeight))
+                            * (base_row[10]))
+                            + ((challenges.get_challenge(ProgramNextInstructionWeight))
+                                * (base_row[11])))))
+                    - (BFieldElement::new(1))))
+                + ((base_row[7]) * (ext_row[4])),
+            (ext_row[3]) - (BFieldElement::new(1)),
+            (ext_row[5])
                 - ((challenges.get_challenge(OpStackIndeterminate))
-                    - (((challenges.get_challenge(OpStackIb1Weight)) * (base_row[11]))
+                    - (((challenges.get_challenge(OpStackIb1Weight)) * (base_row[13]))
                         + ((challenges.get_challenge(OpStackOspWeight))
                             * (BFieldElement::new(16))))),
-            (ext_row[5]) - (challenges.get_challenge(RamIndeterminate)),
-            (ext_row[6])
+            (ext_row[6]) - (challenges.get_challenge(RamIndeterminate)),
+            (ext_row[7])
                 - ((challenges.get_challenge(JumpStackIndeterminate))
-                    - ((challenges.get_challenge(JumpStackCiWeight)) * (base_row[8]))),
-            ext_row[11],
-            (((base_row[8]) - (BFieldElement::new(48))) * ((ext_row[7]) - (BFieldElement::new(1))))
-                + ((((((((((base_row[10]) - (BFieldElement::new(1)))
-                    * ((base_row[11]) - (BFieldElement::new(1))))
-                    * ((base_row[12]) - (BFieldElement::new(1))))
+                    - ((challenges.get_challenge(JumpStackCiWeight)) * (base_row[10]))),
+            ext_row[12],
+            (((base_row[10]) - (BFieldElement::new(48)))
+                * ((ext_row[8]) - (BFieldElement::new(1))))
+                + ((((((((((base_row[12]) - (BFieldElement::new(1)))
                     * ((base_row[13]) - (BFieldElement::new(1))))
-                    * (base_row[14]))
-                    * (base_row[15]))
-                    * ((base_row[16]) - (BFieldElement::new(1))))
-                    * ((base_row[17]) - (BFieldElement::new(1))))
-                    * ((ext_row[7]) - (challenges.get_challenge(HashInputIndeterminate)))),
-            (ext_row[8]) - (BFieldElement::new(1)),
+                    * ((base_row[14]) - (BFieldElement::new(1))))
+                    * ((base_row[15]) - (BFieldElement::new(1))))
+                    * (base_row[16]))
+                    * (base_row[17]))
+                    * ((base_row[18]) - (BFieldElement::new(1))))
+                    * ((base_row[19]) - (BFieldElement::new(1))))
+                    * ((ext_row[8]) - (challenges.get_challenge(HashInputIndeterminate)))),
             (ext_row[9]) - (BFieldElement::new(1)),
-            ext_row[10],
+            (ext_row[10]) - (BFieldElement::new(1)),
+            ext_row[11],
         ];
         base_constraints
             .into_iter()
@@ -96,24 +100,24 @@ impl Evaluable<BFieldElement> for ExtProcessorTable {
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
         let base_constraints = [
-            (base_row[10]) * ((base_row[10]) - (BFieldElement::new(1))),
-            (base_row[11]) * ((base_row[11]) - (BFieldElement::new(1))),
             (base_row[12]) * ((base_row[12]) - (BFieldElement::new(1))),
             (base_row[13]) * ((base_row[13]) - (BFieldElement::new(1))),
             (base_row[14]) * ((base_row[14]) - (BFieldElement::new(1))),
             (base_row[15]) * ((base_row[15]) - (BFieldElement::new(1))),
             (base_row[16]) * ((base_row[16]) - (BFieldElement::new(1))),
             (base_row[17]) * ((base_row[17]) - (BFieldElement::new(1))),
-            (base_row[5]) * ((base_row[5]) - (BFieldElement::new(1))),
-            (base_row[8])
-                - ((((((((base_row[10]) + ((BFieldElement::new(2)) * (base_row[11])))
-                    + ((BFieldElement::new(4)) * (base_row[12])))
-                    + ((BFieldElement::new(8)) * (base_row[13])))
-                    + ((BFieldElement::new(16)) * (base_row[14])))
-                    + ((BFieldElement::new(32)) * (base_row[15])))
-                    + ((BFieldElement::new(64)) * (base_row[16])))
-                    + ((BFieldElement::new(128)) * (base_row[17]))),
-            ((base_row[5]) * ((base_row[4]) - (BFieldElement::new(1)))) * (base_row[45]),
+            (base_row[18]) * ((base_row[18]) - (BFieldElement::new(1))),
+            (base_row[19]) * ((base_row[19]) - (BFieldElement::new(1))),
+            (base_row[7]) * ((base_row[7]) - (BFieldElement::new(1))),
+            (base_row[10])
+                - ((((((((base_row[12]) + ((BFieldElement::new(2)) * (base_row[13])))
+                    + ((BFieldElement::new(4)) * (base_row[14])))
+                    + ((BFieldElement::new(8)) * (base_row[15])))
+                    + ((BFieldElement::new(16)) * (base_row[16])))
+                    + ((BFieldElement::new(32)) * (base_row[17])))
+                    + ((BFieldElement::new(64)) * (base_row[18])))
+                    + ((BFieldElement::new(128)) * (base_row[19]))),
+            ((base_row[7]) * ((base_row[6]) - (BFieldElement::new(1)))) * (base_row[47]),
         ];
         let ext_constraints = [];
         base_constraints
@@ -132,1566 +136,1570 @@ impl Evaluable<BFieldElement> for ExtProcessorTable {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_746 = (current_base_row[17]) - (BFieldElement::new(1));
-        let node_732 = (current_base_row[10]) - (BFieldElement::new(1));
-        let node_736 = (current_base_row[12]) - (BFieldElement::new(1));
-        let node_744 = (current_base_row[16]) - (BFieldElement::new(1));
-        let node_750 = (current_base_row[11]) - (BFieldElement::new(1));
-        let node_742 = (current_base_row[15]) - (BFieldElement::new(1));
-        let node_758 = (node_732) * (node_750);
-        let node_738 = (current_base_row[13]) - (BFieldElement::new(1));
-        let node_740 = (current_base_row[14]) - (BFieldElement::new(1));
-        let node_759 = (node_758) * (node_736);
-        let node_737 = ((node_732) * (current_base_row[11])) * (node_736);
-        let node_776 = (node_759) * (node_738);
-        let node_840 = (node_758) * (current_base_row[12]);
-        let node_761 = (node_759) * (current_base_row[13]);
-        let node_752 = ((current_base_row[10]) * (node_750)) * (node_736);
-        let node_794 = (node_776) * (node_740);
-        let node_739 = (node_737) * (node_738);
-        let node_781 = (node_737) * (current_base_row[13]);
-        let node_841 = (node_840) * (node_738);
-        let node_846 = (node_840) * (current_base_row[13]);
-        let node_762 = (node_761) * (node_740);
-        let node_777 = (node_776) * (current_base_row[14]);
-        let node_753 = (node_752) * (node_738);
-        let node_790 = (node_761) * (current_base_row[14]);
-        let node_803 = (node_794) * (node_742);
-        let node_741 = (node_739) * (node_740);
-        let node_766 = (node_752) * (current_base_row[13]);
-        let node_3965 = (BFieldElement::new(1)) - (next_base_row[5]);
-        let node_775 =
-            ((((node_753) * (current_base_row[14])) * (node_742)) * (node_744)) * (node_746);
-        let node_799 = (node_739) * (current_base_row[14]);
-        let node_782 = (node_781) * (node_740);
-        let node_778 = (node_777) * (node_742);
-        let node_804 = (node_803) * (node_744);
-        let node_842 = (node_841) * (node_740);
-        let node_743 = (node_741) * (node_742);
-        let node_763 = (node_762) * (node_742);
-        let node_796 = (node_794) * (current_base_row[15]);
-        let node_809 = (node_781) * (current_base_row[14]);
-        let node_847 = (node_846) * (node_740);
-        let node_851 = (node_841) * (current_base_row[14]);
-        let node_855 = (node_846) * (current_base_row[14]);
-        let node_806 = (node_762) * (current_base_row[15]);
-        let node_770 = ((((node_766) * (node_740)) * (node_742)) * (node_744)) * (node_746);
-        let node_816 = (node_790) * (current_base_row[15]);
-        let node_813 = (node_777) * (current_base_row[15]);
-        let node_391 = (next_base_row[7]) - (current_base_row[7]);
-        let node_791 = (node_790) * (node_742);
-        let node_393 = (next_base_row[18]) - (current_base_row[18]);
-        let node_394 = (next_base_row[19]) - (current_base_row[19]);
-        let node_395 = (next_base_row[20]) - (current_base_row[20]);
-        let node_418 = (next_base_row[44]) - (current_base_row[44]);
-        let node_419 = (next_base_row[43]) - (current_base_row[43]);
-        let node_445 = (BFieldElement::new(1)) - (current_base_row[39]);
-        let node_392 = (node_391) - (BFieldElement::new(1));
-        let node_440 = (BFieldElement::new(1)) - (current_base_row[42]);
-        let node_441 = (BFieldElement::new(1)) - (current_base_row[41]);
-        let node_443 = (BFieldElement::new(1)) - (current_base_row[40]);
-        let node_821 = ((node_803) * (current_base_row[16])) * (node_746);
-        let node_785 = (((node_782) * (node_742)) * (node_744)) * (node_746);
-        let node_838 = (((node_799) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_802 = (((node_799) * (node_742)) * (node_744)) * (node_746);
-        let node_805 = (node_804) * (node_746);
-        let node_823 = ((node_763) * (current_base_row[16])) * (node_746);
-        let node_825 = ((node_778) * (current_base_row[16])) * (node_746);
-        let node_845 = (((node_842) * (node_742)) * (node_744)) * (node_746);
-        let node_747 = ((node_743) * (node_744)) * (node_746);
-        let node_757 = ((((node_753) * (node_740)) * (node_742)) * (node_744)) * (node_746);
-        let node_780 = ((node_778) * (node_744)) * (node_746);
-        let node_789 =
-            ((((node_766) * (current_base_row[14])) * (node_742)) * (node_744)) * (node_746);
-        let node_798 = ((node_796) * (node_744)) * (node_746);
-        let node_812 = (((node_809) * (node_742)) * (node_744)) * (node_746);
-        let node_830 = (((node_741) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_833 = (((node_782) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_835 = ((node_796) * (current_base_row[16])) * (node_746);
-        let node_850 = (((node_847) * (node_742)) * (node_744)) * (node_746);
-        let node_854 = (((node_851) * (node_742)) * (node_744)) * (node_746);
-        let node_858 = (((node_855) * (node_742)) * (node_744)) * (node_746);
-        let node_861 = (((node_842) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_864 = (((node_847) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_867 = (((node_851) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_870 = (((node_855) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_872 = ((node_806) * (current_base_row[16])) * (node_746);
-        let node_874 = ((node_813) * (current_base_row[16])) * (node_746);
-        let node_876 = ((node_816) * (current_base_row[16])) * (node_746);
-        let node_879 = (((node_809) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_881 = (node_804) * (current_base_row[17]);
-        let node_883 = ((node_743) * (current_base_row[16])) * (node_746);
-        let node_765 = ((node_763) * (node_744)) * (node_746);
-        let node_808 = ((node_806) * (node_744)) * (node_746);
-        let node_793 = ((node_791) * (node_744)) * (node_746);
-        let node_818 = ((node_816) * (node_744)) * (node_746);
-        let node_580 = (next_base_row[32]) - (current_base_row[32]);
-        let node_583 = (next_base_row[33]) - (current_base_row[33]);
-        let node_586 = (next_base_row[34]) - (current_base_row[34]);
-        let node_589 = (next_base_row[35]) - (current_base_row[35]);
-        let node_592 = (next_base_row[36]) - (current_base_row[36]);
-        let node_594 = (next_base_row[38]) - (current_base_row[38]);
-        let node_595 = (next_base_row[37]) - (current_base_row[37]);
-        let node_577 = (next_base_row[31]) - (current_base_row[31]);
-        let node_556 = (next_base_row[24]) - (current_base_row[24]);
-        let node_559 = (next_base_row[25]) - (current_base_row[25]);
-        let node_562 = (next_base_row[26]) - (current_base_row[26]);
-        let node_565 = (next_base_row[27]) - (current_base_row[27]);
-        let node_568 = (next_base_row[28]) - (current_base_row[28]);
-        let node_571 = (next_base_row[29]) - (current_base_row[29]);
-        let node_574 = (next_base_row[30]) - (current_base_row[30]);
-        let node_458 = (node_440) * (current_base_row[41]);
-        let node_473 = (current_base_row[42]) * (node_441);
-        let node_488 = (current_base_row[42]) * (current_base_row[41]);
-        let node_815 = ((node_813) * (node_744)) * (node_746);
-        let node_827 = ((node_791) * (current_base_row[16])) * (node_746);
-        let node_442 = (node_440) * (node_441);
-        let node_553 = (next_base_row[23]) - (current_base_row[23]);
-        let node_550 = (next_base_row[22]) - (current_base_row[22]);
-        let node_399 = (next_base_row[24]) - (current_base_row[25]);
-        let node_400 = (next_base_row[25]) - (current_base_row[26]);
-        let node_401 = (next_base_row[26]) - (current_base_row[27]);
-        let node_402 = (next_base_row[27]) - (current_base_row[28]);
-        let node_403 = (next_base_row[28]) - (current_base_row[29]);
-        let node_404 = (next_base_row[29]) - (current_base_row[30]);
-        let node_405 = (next_base_row[30]) - (current_base_row[31]);
-        let node_406 = (next_base_row[31]) - (current_base_row[32]);
-        let node_407 = (next_base_row[32]) - (current_base_row[33]);
-        let node_408 = (next_base_row[33]) - (current_base_row[34]);
-        let node_409 = (next_base_row[34]) - (current_base_row[35]);
-        let node_410 = (next_base_row[35]) - (current_base_row[36]);
-        let node_411 = (next_base_row[36]) - (current_base_row[38]);
-        let node_413 = (next_base_row[37]) - ((current_base_row[37]) - (BFieldElement::new(1)));
-        let node_417 = (((current_base_row[37]) - (BFieldElement::new(16)))
-            * (current_base_row[42]))
+        let node_1018 = (current_base_row[19]) - (BFieldElement::new(1));
+        let node_1004 = (current_base_row[12]) - (BFieldElement::new(1));
+        let node_1008 = (current_base_row[14]) - (BFieldElement::new(1));
+        let node_1016 = (current_base_row[18]) - (BFieldElement::new(1));
+        let node_1022 = (current_base_row[13]) - (BFieldElement::new(1));
+        let node_1014 = (current_base_row[17]) - (BFieldElement::new(1));
+        let node_1030 = (node_1004) * (node_1022);
+        let node_1010 = (current_base_row[15]) - (BFieldElement::new(1));
+        let node_1012 = (current_base_row[16]) - (BFieldElement::new(1));
+        let node_1031 = (node_1030) * (node_1008);
+        let node_1009 = ((node_1004) * (current_base_row[13])) * (node_1008);
+        let node_1048 = (node_1031) * (node_1010);
+        let node_1112 = (node_1030) * (current_base_row[14]);
+        let node_1033 = (node_1031) * (current_base_row[15]);
+        let node_1024 = ((current_base_row[12]) * (node_1022)) * (node_1008);
+        let node_1066 = (node_1048) * (node_1012);
+        let node_1011 = (node_1009) * (node_1010);
+        let node_1053 = (node_1009) * (current_base_row[15]);
+        let node_1113 = (node_1112) * (node_1010);
+        let node_1118 = (node_1112) * (current_base_row[15]);
+        let node_1034 = (node_1033) * (node_1012);
+        let node_1049 = (node_1048) * (current_base_row[16]);
+        let node_1025 = (node_1024) * (node_1010);
+        let node_1062 = (node_1033) * (current_base_row[16]);
+        let node_1075 = (node_1066) * (node_1014);
+        let node_1013 = (node_1011) * (node_1012);
+        let node_1038 = (node_1024) * (current_base_row[15]);
+        let node_4237 = (BFieldElement::new(1)) - (next_base_row[7]);
+        let node_1047 =
+            ((((node_1025) * (current_base_row[16])) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1071 = (node_1011) * (current_base_row[16]);
+        let node_1054 = (node_1053) * (node_1012);
+        let node_1050 = (node_1049) * (node_1014);
+        let node_1076 = (node_1075) * (node_1016);
+        let node_1114 = (node_1113) * (node_1012);
+        let node_1015 = (node_1013) * (node_1014);
+        let node_1035 = (node_1034) * (node_1014);
+        let node_1068 = (node_1066) * (current_base_row[17]);
+        let node_1081 = (node_1053) * (current_base_row[16]);
+        let node_1119 = (node_1118) * (node_1012);
+        let node_1123 = (node_1113) * (current_base_row[16]);
+        let node_1127 = (node_1118) * (current_base_row[16]);
+        let node_1078 = (node_1034) * (current_base_row[17]);
+        let node_1042 = ((((node_1038) * (node_1012)) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1088 = (node_1062) * (current_base_row[17]);
+        let node_1085 = (node_1049) * (current_base_row[17]);
+        let node_663 = (next_base_row[9]) - (current_base_row[9]);
+        let node_1063 = (node_1062) * (node_1014);
+        let node_665 = (next_base_row[20]) - (current_base_row[20]);
+        let node_666 = (next_base_row[21]) - (current_base_row[21]);
+        let node_667 = (next_base_row[22]) - (current_base_row[22]);
+        let node_690 = (next_base_row[46]) - (current_base_row[46]);
+        let node_691 = (next_base_row[45]) - (current_base_row[45]);
+        let node_717 = (BFieldElement::new(1)) - (current_base_row[41]);
+        let node_664 = (node_663) - (BFieldElement::new(1));
+        let node_712 = (BFieldElement::new(1)) - (current_base_row[44]);
+        let node_713 = (BFieldElement::new(1)) - (current_base_row[43]);
+        let node_715 = (BFieldElement::new(1)) - (current_base_row[42]);
+        let node_1093 = ((node_1075) * (current_base_row[18])) * (node_1018);
+        let node_1057 = (((node_1054) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1110 = (((node_1071) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1074 = (((node_1071) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1077 = (node_1076) * (node_1018);
+        let node_1095 = ((node_1035) * (current_base_row[18])) * (node_1018);
+        let node_1097 = ((node_1050) * (current_base_row[18])) * (node_1018);
+        let node_1117 = (((node_1114) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1019 = ((node_1015) * (node_1016)) * (node_1018);
+        let node_1029 = ((((node_1025) * (node_1012)) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1052 = ((node_1050) * (node_1016)) * (node_1018);
+        let node_1061 =
+            ((((node_1038) * (current_base_row[16])) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1070 = ((node_1068) * (node_1016)) * (node_1018);
+        let node_1084 = (((node_1081) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1102 = (((node_1013) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1105 = (((node_1054) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1107 = ((node_1068) * (current_base_row[18])) * (node_1018);
+        let node_1122 = (((node_1119) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1126 = (((node_1123) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1130 = (((node_1127) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1133 = (((node_1114) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1136 = (((node_1119) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1139 = (((node_1123) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1142 = (((node_1127) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1144 = ((node_1078) * (current_base_row[18])) * (node_1018);
+        let node_1146 = ((node_1085) * (current_base_row[18])) * (node_1018);
+        let node_1148 = ((node_1088) * (current_base_row[18])) * (node_1018);
+        let node_1151 = (((node_1081) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1153 = (node_1076) * (current_base_row[19]);
+        let node_1155 = ((node_1015) * (current_base_row[18])) * (node_1018);
+        let node_1037 = ((node_1035) * (node_1016)) * (node_1018);
+        let node_1080 = ((node_1078) * (node_1016)) * (node_1018);
+        let node_1065 = ((node_1063) * (node_1016)) * (node_1018);
+        let node_1090 = ((node_1088) * (node_1016)) * (node_1018);
+        let node_852 = (next_base_row[34]) - (current_base_row[34]);
+        let node_855 = (next_base_row[35]) - (current_base_row[35]);
+        let node_858 = (next_base_row[36]) - (current_base_row[36]);
+        let node_861 = (next_base_row[37]) - (current_base_row[37]);
+        let node_864 = (next_base_row[38]) - (current_base_row[38]);
+        let node_866 = (next_base_row[40]) - (current_base_row[40]);
+        let node_867 = (next_base_row[39]) - (current_base_row[39]);
+        let node_849 = (next_base_row[33]) - (current_base_row[33]);
+        let node_828 = (next_base_row[26]) - (current_base_row[26]);
+        let node_831 = (next_base_row[27]) - (current_base_row[27]);
+        let node_834 = (next_base_row[28]) - (current_base_row[28]);
+        let node_837 = (next_base_row[29]) - (current_base_row[29]);
+        let node_840 = (next_base_row[30]) - (current_base_row[30]);
+        let node_843 = (next_base_row[31]) - (current_base_row[31]);
+        let node_846 = (next_base_row[32]) - (current_base_row[32]);
+        let node_730 = (node_712) * (current_base_row[43]);
+        let node_745 = (current_base_row[44]) * (node_713);
+        let node_760 = (current_base_row[44]) * (current_base_row[43]);
+        let node_1087 = ((node_1085) * (node_1016)) * (node_1018);
+        let node_1099 = ((node_1063) * (current_base_row[18])) * (node_1018);
+        let node_714 = (node_712) * (node_713);
+        let node_825 = (next_base_row[25]) - (current_base_row[25]);
+        let node_822 = (next_base_row[24]) - (current_base_row[24]);
+        let node_671 = (next_base_row[26]) - (current_base_row[27]);
+        let node_672 = (next_base_row[27]) - (current_base_row[28]);
+        let node_673 = (next_base_row[28]) - (current_base_row[29]);
+        let node_674 = (next_base_row[29]) - (current_base_row[30]);
+        let node_675 = (next_base_row[30]) - (current_base_row[31]);
+        let node_676 = (next_base_row[31]) - (current_base_row[32]);
+        let node_677 = (next_base_row[32]) - (current_base_row[33]);
+        let node_678 = (next_base_row[33]) - (current_base_row[34]);
+        let node_679 = (next_base_row[34]) - (current_base_row[35]);
+        let node_680 = (next_base_row[35]) - (current_base_row[36]);
+        let node_681 = (next_base_row[36]) - (current_base_row[37]);
+        let node_682 = (next_base_row[37]) - (current_base_row[38]);
+        let node_683 = (next_base_row[38]) - (current_base_row[40]);
+        let node_685 = (next_base_row[39]) - ((current_base_row[39]) - (BFieldElement::new(1)));
+        let node_689 = (((current_base_row[39]) - (BFieldElement::new(16)))
+            * (current_base_row[44]))
             - (BFieldElement::new(1));
-        let node_397 = (next_base_row[22]) - (current_base_row[23]);
-        let node_398 = (next_base_row[23]) - (current_base_row[24]);
-        let node_447 = (next_base_row[21]) - (current_base_row[21]);
-        let node_4349 = (next_ext_row[10]) - (current_ext_row[10]);
-        let node_451 = (node_442) * (current_base_row[40]);
-        let node_459 = (node_458) * (node_443);
-        let node_466 = (node_458) * (current_base_row[40]);
-        let node_474 = (node_473) * (node_443);
-        let node_481 = (node_473) * (current_base_row[40]);
-        let node_489 = (node_488) * (node_443);
-        let node_496 = (node_488) * (current_base_row[40]);
-        let node_4327 = (challenges.get_challenge(U32Indeterminate))
-            - ((challenges.get_challenge(U32LhsWeight)) * (current_base_row[21]));
-        let node_396 = (next_base_row[21]) - (current_base_row[22]);
-        let node_444 = (node_442) * (node_443);
-        let node_421 = (next_base_row[22]) - (current_base_row[21]);
-        let node_422 = (next_base_row[23]) - (current_base_row[22]);
-        let node_423 = (next_base_row[24]) - (current_base_row[23]);
-        let node_424 = (next_base_row[25]) - (current_base_row[24]);
-        let node_425 = (next_base_row[26]) - (current_base_row[25]);
-        let node_426 = (next_base_row[27]) - (current_base_row[26]);
-        let node_427 = (next_base_row[28]) - (current_base_row[27]);
-        let node_428 = (next_base_row[29]) - (current_base_row[28]);
-        let node_429 = (next_base_row[30]) - (current_base_row[29]);
-        let node_430 = (next_base_row[31]) - (current_base_row[30]);
-        let node_431 = (next_base_row[32]) - (current_base_row[31]);
-        let node_432 = (next_base_row[33]) - (current_base_row[32]);
-        let node_433 = (next_base_row[34]) - (current_base_row[33]);
-        let node_434 = (next_base_row[35]) - (current_base_row[34]);
-        let node_435 = (next_base_row[36]) - (current_base_row[35]);
-        let node_436 = (next_base_row[38]) - (current_base_row[36]);
-        let node_438 = (next_base_row[37]) - ((current_base_row[37]) + (BFieldElement::new(1)));
-        let node_4324 = (challenges.get_challenge(U32CiWeight)) * (current_base_row[8]);
-        let node_4328 = (challenges.get_challenge(U32RhsWeight)) * (current_base_row[22]);
-        let node_503 = (current_base_row[39]) - (BFieldElement::new(1));
-        let node_4331 = (challenges.get_challenge(U32ResultWeight)) * (next_base_row[21]);
-        let node_504 = (current_base_row[39]) * (node_503);
-        let node_664 = (current_base_row[22]) - (current_base_row[21]);
-        let node_449 = (node_444) * (current_base_row[39]);
-        let node_452 = (node_451) * (node_445);
-        let node_439 = (node_391) - (BFieldElement::new(2));
-        let node_455 = (node_451) * (current_base_row[39]);
-        let node_460 = (node_459) * (node_445);
-        let node_463 = (node_459) * (current_base_row[39]);
-        let node_467 = (node_466) * (node_445);
-        let node_470 = (node_466) * (current_base_row[39]);
-        let node_475 = (node_474) * (node_445);
-        let node_478 = (node_474) * (current_base_row[39]);
-        let node_482 = (node_481) * (node_445);
-        let node_485 = (node_481) * (current_base_row[39]);
-        let node_490 = (node_489) * (node_445);
-        let node_493 = (node_489) * (current_base_row[39]);
-        let node_497 = (node_496) * (node_445);
-        let node_500 = (node_496) * (current_base_row[39]);
-        let node_4264 = ((((((((((challenges.get_challenge(HashStateWeight0))
-            * (next_base_row[21]))
-            + ((challenges.get_challenge(HashStateWeight1)) * (next_base_row[22])))
-            + ((challenges.get_challenge(HashStateWeight2)) * (next_base_row[23])))
-            + ((challenges.get_challenge(HashStateWeight3)) * (next_base_row[24])))
-            + ((challenges.get_challenge(HashStateWeight4)) * (next_base_row[25])))
-            + ((challenges.get_challenge(HashStateWeight5)) * (next_base_row[26])))
-            + ((challenges.get_challenge(HashStateWeight6)) * (next_base_row[27])))
-            + ((challenges.get_challenge(HashStateWeight7)) * (next_base_row[28])))
-            + ((challenges.get_challenge(HashStateWeight8)) * (next_base_row[29])))
-            + ((challenges.get_challenge(HashStateWeight9)) * (next_base_row[30]));
-        let node_4329 = (node_4327) - (node_4328);
-        let node_517 = (BFieldElement::new(2)) * (current_base_row[40]);
-        let node_665 = (current_base_row[39]) * (node_664);
-        let node_4304 = (((next_ext_row[9])
-            - ((challenges.get_challenge(SpongeIndeterminate)) * (current_ext_row[9])))
-            - ((challenges.get_challenge(HashCIWeight)) * (current_base_row[8])))
-            - (node_4264);
-        let node_4321 = (challenges.get_challenge(U32Indeterminate))
-            - ((challenges.get_challenge(U32LhsWeight)) * (next_base_row[21]));
-        let node_4322 = (challenges.get_challenge(U32RhsWeight)) * (next_base_row[22]);
-        let node_4354 =
-            ((node_4349) * (((node_4329) - (node_4324)) - (node_4331))) - (BFieldElement::new(1));
-        let node_446 = (node_444) * (node_445);
-        let node_620 = (next_base_row[8]) - (current_base_row[8]);
-        let node_658 = (current_base_row[21]) + (current_base_row[22]);
-        let node_661 = (next_base_row[21]) - ((current_base_row[21]) * (current_base_row[22]));
-        let node_662 = (next_base_row[21]) * (current_base_row[21]);
-        let node_666 = (node_665) - (BFieldElement::new(1));
-        let node_680 = (current_base_row[22]) * (next_base_row[22]);
-        let node_689 = (current_base_row[21]) * (current_base_row[24]);
-        let node_690 = (current_base_row[23]) * (current_base_row[25]);
-        let node_692 = (current_base_row[22]) * (current_base_row[26]);
-        let node_710 = (current_base_row[23]) * (next_base_row[22]);
-        let node_712 = (current_base_row[22]) * (next_base_row[23]);
-        let node_450 = (node_449) * (node_396);
-        let node_698 = (current_base_row[23]) * (current_base_row[26]);
-        let node_718 = (current_base_row[23]) * (next_base_row[23]);
-        let node_454 = (node_452) * ((next_base_row[21]) - (current_base_row[23]));
-        let node_600 = ((current_base_row[21]) * (current_base_row[41])) - (BFieldElement::new(1));
-        let node_457 = (node_455) * ((next_base_row[21]) - (current_base_row[24]));
-        let node_462 = (node_460) * ((next_base_row[21]) - (current_base_row[25]));
-        let node_465 = (node_463) * ((next_base_row[21]) - (current_base_row[26]));
-        let node_469 = (node_467) * ((next_base_row[21]) - (current_base_row[27]));
-        let node_472 = (node_470) * ((next_base_row[21]) - (current_base_row[28]));
-        let node_477 = (node_475) * ((next_base_row[21]) - (current_base_row[29]));
-        let node_480 = (node_478) * ((next_base_row[21]) - (current_base_row[30]));
-        let node_484 = (node_482) * ((next_base_row[21]) - (current_base_row[31]));
-        let node_487 = (node_485) * ((next_base_row[21]) - (current_base_row[32]));
-        let node_492 = (node_490) * ((next_base_row[21]) - (current_base_row[33]));
-        let node_495 = (node_493) * ((next_base_row[21]) - (current_base_row[34]));
-        let node_499 = (node_497) * ((next_base_row[21]) - (current_base_row[35]));
-        let node_502 = (node_500) * ((next_base_row[21]) - (current_base_row[36]));
-        let node_506 = (current_base_row[40]) * ((current_base_row[40]) - (BFieldElement::new(1)));
-        let node_508 = (current_base_row[41]) * ((current_base_row[41]) - (BFieldElement::new(1)));
-        let node_510 = (current_base_row[42]) * ((current_base_row[42]) - (BFieldElement::new(1)));
-        let node_519 = ((((current_base_row[9])
-            - ((BFieldElement::new(8)) * (current_base_row[42])))
-            - ((BFieldElement::new(4)) * (current_base_row[41])))
-            - (node_517))
-            - (current_base_row[39]);
-        let node_4141 = (next_ext_row[3]) - (current_ext_row[3]);
-        let node_4149 = (next_base_row[10]) - (BFieldElement::new(1));
-        let node_4153 = (next_base_row[12]) - (BFieldElement::new(1));
-        let node_4155 = (next_base_row[13]) - (BFieldElement::new(1));
-        let node_4163 = (next_base_row[17]) - (BFieldElement::new(1));
-        let node_4362 =
-            ((node_4349) * (((node_4327) - (node_4324)) - (node_4331))) - (BFieldElement::new(1));
-        let node_4345 = (((node_4321) - (node_4328))
+        let node_669 = (next_base_row[24]) - (current_base_row[25]);
+        let node_670 = (next_base_row[25]) - (current_base_row[26]);
+        let node_719 = (next_base_row[23]) - (current_base_row[23]);
+        let node_4621 = (next_ext_row[11]) - (current_ext_row[11]);
+        let node_723 = (node_714) * (current_base_row[42]);
+        let node_731 = (node_730) * (node_715);
+        let node_738 = (node_730) * (current_base_row[42]);
+        let node_746 = (node_745) * (node_715);
+        let node_753 = (node_745) * (current_base_row[42]);
+        let node_761 = (node_760) * (node_715);
+        let node_768 = (node_760) * (current_base_row[42]);
+        let node_4599 = (challenges.get_challenge(U32Indeterminate))
+            - ((challenges.get_challenge(U32LhsWeight)) * (current_base_row[23]));
+        let node_668 = (next_base_row[23]) - (current_base_row[24]);
+        let node_716 = (node_714) * (node_715);
+        let node_693 = (next_base_row[24]) - (current_base_row[23]);
+        let node_694 = (next_base_row[25]) - (current_base_row[24]);
+        let node_695 = (next_base_row[26]) - (current_base_row[25]);
+        let node_696 = (next_base_row[27]) - (current_base_row[26]);
+        let node_697 = (next_base_row[28]) - (current_base_row[27]);
+        let node_698 = (next_base_row[29]) - (current_base_row[28]);
+        let node_699 = (next_base_row[30]) - (current_base_row[29]);
+        let node_700 = (next_base_row[31]) - (current_base_row[30]);
+        let node_701 = (next_base_row[32]) - (current_base_row[31]);
+        let node_702 = (next_base_row[33]) - (current_base_row[32]);
+        let node_703 = (next_base_row[34]) - (current_base_row[33]);
+        let node_704 = (next_base_row[35]) - (current_base_row[34]);
+        let node_705 = (next_base_row[36]) - (current_base_row[35]);
+        let node_706 = (next_base_row[37]) - (current_base_row[36]);
+        let node_707 = (next_base_row[38]) - (current_base_row[37]);
+        let node_708 = (next_base_row[40]) - (current_base_row[38]);
+        let node_710 = (next_base_row[39]) - ((current_base_row[39]) + (BFieldElement::new(1)));
+        let node_4596 = (challenges.get_challenge(U32CiWeight)) * (current_base_row[10]);
+        let node_4600 = (challenges.get_challenge(U32RhsWeight)) * (current_base_row[24]);
+        let node_775 = (current_base_row[41]) - (BFieldElement::new(1));
+        let node_4603 = (challenges.get_challenge(U32ResultWeight)) * (next_base_row[23]);
+        let node_776 = (current_base_row[41]) * (node_775);
+        let node_936 = (current_base_row[24]) - (current_base_row[23]);
+        let node_721 = (node_716) * (current_base_row[41]);
+        let node_724 = (node_723) * (node_717);
+        let node_711 = (node_663) - (BFieldElement::new(2));
+        let node_727 = (node_723) * (current_base_row[41]);
+        let node_732 = (node_731) * (node_717);
+        let node_735 = (node_731) * (current_base_row[41]);
+        let node_739 = (node_738) * (node_717);
+        let node_742 = (node_738) * (current_base_row[41]);
+        let node_747 = (node_746) * (node_717);
+        let node_750 = (node_746) * (current_base_row[41]);
+        let node_754 = (node_753) * (node_717);
+        let node_757 = (node_753) * (current_base_row[41]);
+        let node_762 = (node_761) * (node_717);
+        let node_765 = (node_761) * (current_base_row[41]);
+        let node_769 = (node_768) * (node_717);
+        let node_772 = (node_768) * (current_base_row[41]);
+        let node_4536 = ((((((((((challenges.get_challenge(HashStateWeight0))
+            * (next_base_row[23]))
+            + ((challenges.get_challenge(HashStateWeight1)) * (next_base_row[24])))
+            + ((challenges.get_challenge(HashStateWeight2)) * (next_base_row[25])))
+            + ((challenges.get_challenge(HashStateWeight3)) * (next_base_row[26])))
+            + ((challenges.get_challenge(HashStateWeight4)) * (next_base_row[27])))
+            + ((challenges.get_challenge(HashStateWeight5)) * (next_base_row[28])))
+            + ((challenges.get_challenge(HashStateWeight6)) * (next_base_row[29])))
+            + ((challenges.get_challenge(HashStateWeight7)) * (next_base_row[30])))
+            + ((challenges.get_challenge(HashStateWeight8)) * (next_base_row[31])))
+            + ((challenges.get_challenge(HashStateWeight9)) * (next_base_row[32]));
+        let node_4601 = (node_4599) - (node_4600);
+        let node_789 = (BFieldElement::new(2)) * (current_base_row[42]);
+        let node_937 = (current_base_row[41]) * (node_936);
+        let node_4576 = (((next_ext_row[10])
+            - ((challenges.get_challenge(SpongeIndeterminate)) * (current_ext_row[10])))
+            - ((challenges.get_challenge(HashCIWeight)) * (current_base_row[10])))
+            - (node_4536);
+        let node_4593 = (challenges.get_challenge(U32Indeterminate))
+            - ((challenges.get_challenge(U32LhsWeight)) * (next_base_row[23]));
+        let node_4594 = (challenges.get_challenge(U32RhsWeight)) * (next_base_row[24]);
+        let node_4626 =
+            ((node_4621) * (((node_4601) - (node_4596)) - (node_4603))) - (BFieldElement::new(1));
+        let node_718 = (node_716) * (node_717);
+        let node_892 = (next_base_row[10]) - (current_base_row[10]);
+        let node_930 = (current_base_row[23]) + (current_base_row[24]);
+        let node_933 = (next_base_row[23]) - ((current_base_row[23]) * (current_base_row[24]));
+        let node_934 = (next_base_row[23]) * (current_base_row[23]);
+        let node_938 = (node_937) - (BFieldElement::new(1));
+        let node_952 = (current_base_row[24]) * (next_base_row[24]);
+        let node_961 = (current_base_row[23]) * (current_base_row[26]);
+        let node_962 = (current_base_row[25]) * (current_base_row[27]);
+        let node_964 = (current_base_row[24]) * (current_base_row[28]);
+        let node_982 = (current_base_row[25]) * (next_base_row[24]);
+        let node_984 = (current_base_row[24]) * (next_base_row[25]);
+        let node_722 = (node_721) * (node_668);
+        let node_970 = (current_base_row[25]) * (current_base_row[28]);
+        let node_990 = (current_base_row[25]) * (next_base_row[25]);
+        let node_726 = (node_724) * ((next_base_row[23]) - (current_base_row[25]));
+        let node_872 = ((current_base_row[23]) * (current_base_row[43])) - (BFieldElement::new(1));
+        let node_729 = (node_727) * ((next_base_row[23]) - (current_base_row[26]));
+        let node_734 = (node_732) * ((next_base_row[23]) - (current_base_row[27]));
+        let node_737 = (node_735) * ((next_base_row[23]) - (current_base_row[28]));
+        let node_741 = (node_739) * ((next_base_row[23]) - (current_base_row[29]));
+        let node_744 = (node_742) * ((next_base_row[23]) - (current_base_row[30]));
+        let node_749 = (node_747) * ((next_base_row[23]) - (current_base_row[31]));
+        let node_752 = (node_750) * ((next_base_row[23]) - (current_base_row[32]));
+        let node_756 = (node_754) * ((next_base_row[23]) - (current_base_row[33]));
+        let node_759 = (node_757) * ((next_base_row[23]) - (current_base_row[34]));
+        let node_764 = (node_762) * ((next_base_row[23]) - (current_base_row[35]));
+        let node_767 = (node_765) * ((next_base_row[23]) - (current_base_row[36]));
+        let node_771 = (node_769) * ((next_base_row[23]) - (current_base_row[37]));
+        let node_774 = (node_772) * ((next_base_row[23]) - (current_base_row[38]));
+        let node_778 = (current_base_row[42]) * ((current_base_row[42]) - (BFieldElement::new(1)));
+        let node_780 = (current_base_row[43]) * ((current_base_row[43]) - (BFieldElement::new(1)));
+        let node_782 = (current_base_row[44]) * ((current_base_row[44]) - (BFieldElement::new(1)));
+        let node_791 = ((((current_base_row[11])
+            - ((BFieldElement::new(8)) * (current_base_row[44])))
+            - ((BFieldElement::new(4)) * (current_base_row[43])))
+            - (node_789))
+            - (current_base_row[41]);
+        let node_4413 = (next_ext_row[4]) - (current_ext_row[4]);
+        let node_4421 = (next_base_row[12]) - (BFieldElement::new(1));
+        let node_4425 = (next_base_row[14]) - (BFieldElement::new(1));
+        let node_4427 = (next_base_row[15]) - (BFieldElement::new(1));
+        let node_4435 = (next_base_row[19]) - (BFieldElement::new(1));
+        let node_4634 =
+            ((node_4621) * (((node_4599) - (node_4596)) - (node_4603))) - (BFieldElement::new(1));
+        let node_4617 = (((node_4593) - (node_4600))
             - ((challenges.get_challenge(U32CiWeight)) * (BFieldElement::new(12))))
             - (challenges.get_challenge(U32ResultWeight));
-        let node_4348 = ((node_4327) - (node_4322))
+        let node_4620 = ((node_4599) - (node_4594))
             - ((challenges.get_challenge(U32CiWeight)) * (BFieldElement::new(4)));
 
         let base_constraints = [
-            ((next_base_row[4]) - (current_base_row[4])) - (BFieldElement::new(1)),
-            (current_base_row[5]) * ((next_base_row[5]) - (current_base_row[5])),
-            ((next_base_row[6]) - (current_base_row[8])) * (node_3965),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_392))
-                + ((node_757)
-                    * ((next_base_row[21])
-                        - (current_base_row[9]))))
-                + ((node_765) * (node_392)))
-                + ((node_770) * ((node_446) * (node_447))))
-                + ((node_775) * (node_446)))
-                + ((node_780) * (node_392)))
-                + ((node_785)
-                    * ((current_base_row[9])
-                        - ((current_base_row[39]) + (node_517)))))
-                + ((node_789)
-                    * ((next_base_row[18])
-                        - ((current_base_row[18])
+            ((next_base_row[6]) - (current_base_row[6])) - (BFieldElement::new(1)),
+            (current_base_row[7]) * ((next_base_row[7]) - (current_base_row[7])),
+            ((next_base_row[8]) - (current_base_row[10])) * (node_4237),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_664))
+                + ((node_1029)
+                    * ((next_base_row[23])
+                        - (current_base_row[11]))))
+                + ((node_1037) * (node_664)))
+                + ((node_1042) * ((node_718) * (node_719))))
+                + ((node_1047) * (node_718)))
+                + ((node_1052) * (node_664)))
+                + ((node_1057)
+                    * ((current_base_row[11])
+                        - ((current_base_row[41]) + (node_789)))))
+                + ((node_1061)
+                    * ((next_base_row[20])
+                        - ((current_base_row[20])
                             + (BFieldElement::new(1))))))
-                + ((node_793)
-                    * ((next_base_row[18])
-                        - ((current_base_row[18])
+                + ((node_1065)
+                    * ((next_base_row[20])
+                        - ((current_base_row[20])
                             - (BFieldElement::new(1))))))
-                + ((node_798)
-                    * ((next_base_row[7]) - (current_base_row[20]))))
-                + ((node_802)
-                    * ((current_base_row[21])
+                + ((node_1070)
+                    * ((next_base_row[9]) - (current_base_row[22]))))
+                + ((node_1074)
+                    * ((current_base_row[23])
                         - (BFieldElement::new(1)))))
-                + ((node_805) * (node_620)))
-                + ((node_808)
-                    * ((next_base_row[43]) - (current_base_row[21]))))
-                + ((node_812)
-                    * ((next_base_row[43]) - (current_base_row[22]))))
-                + ((node_815) * (node_392)))
-                + ((node_818) * (node_504)))
-                + ((node_821)
-                    * ((current_base_row[26]) - (current_base_row[21]))))
-                + ((node_823) * (node_392)))
-                + ((node_825) * (node_392)))
-                + ((node_827) * (node_392)))
-                + ((node_830) * ((next_base_row[21]) - (node_658))))
-                + ((node_833) * (node_661)))
-                + ((node_835) * ((node_662) - (BFieldElement::new(1)))))
-                + ((node_838) * ((current_base_row[39]) * (node_666))))
-                + ((node_845)
-                    * ((current_base_row[21])
-                        - (((BFieldElement::new(4294967296))
-                            * (next_base_row[22]))
-                            + (next_base_row[21])))))
-                + ((node_850) * (node_392)))
-                + ((node_854) * (node_392)))
-                + ((node_858) * (node_392)))
-                + ((node_861) * (node_392)))
-                + ((node_864) * (node_392)))
-                + ((node_867)
-                    * (((current_base_row[21]) - (node_680)) - (next_base_row[21]))))
-                + ((node_870) * (node_392)))
-                + ((node_872)
-                    * ((next_base_row[21])
-                        - ((current_base_row[21]) + (current_base_row[24])))))
-                + ((node_874)
-                    * ((next_base_row[21]) - (((node_689) - (node_690)) - (node_692)))))
-                + ((node_876)
-                    * ((((node_662) - (node_710)) - (node_712)) - (BFieldElement::new(1)))))
-                + ((node_879) * (node_661)))
-                + ((node_881) * (node_392)))
-                + ((node_883) * (node_392)))
-                * (node_3965))
-                + ((node_391) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_393))
-                + ((node_757) * (node_421)))
-                + ((node_765) * (node_393)))
-                + ((node_770) * (node_450)))
-                + ((node_775) * ((node_449) * (node_421))))
-                + ((node_780) * (node_393)))
-                + ((node_785) * (node_504)))
-                + ((node_789)
-                    * (((next_base_row[19])
-                        - (current_base_row[7]))
-                        - (BFieldElement::new(2)))))
-                + ((node_793)
-                    * ((next_base_row[7]) - (current_base_row[19]))))
-                + ((node_798) * (node_393)))
-                + ((node_802) * (node_392)))
-                + ((node_805) * (node_392)))
-                + ((node_808)
-                    * ((next_base_row[21]) - (next_base_row[44]))))
-                + ((node_812)
-                    * ((next_base_row[44]) - (current_base_row[21]))))
-                + ((node_815) * (node_393)))
-                + ((node_818)
-                    * ((((next_base_row[31]) * (BFieldElement::new(2)))
-                        + (current_base_row[39]))
-                        - (current_base_row[31]))))
-                + ((node_821)
-                    * ((current_base_row[27]) - (current_base_row[22]))))
-                + ((node_823) * (node_393)))
-                + ((node_825) * (node_393)))
-                + ((node_827) * (node_393)))
-                + ((node_830) * (node_392)))
-                + ((node_833) * (node_392)))
-                + ((node_835) * (node_392)))
-                + ((node_838) * ((node_664) * (node_666))))
-                + ((node_845)
-                    * ((next_base_row[21])
-                        * (((current_base_row[39])
-                            * ((next_base_row[22])
-                                - (BFieldElement::new(4294967295))))
-                            - (BFieldElement::new(1))))))
-                + ((node_850) * (node_393)))
-                + ((node_854) * (node_393)))
-                + ((node_858) * (node_393)))
-                + ((node_861) * (node_393)))
-                + ((node_864) * (node_393)))
-                + ((node_867) * (node_553)))
-                + ((node_870) * (node_393)))
-                + ((node_872)
-                    * ((next_base_row[22])
-                        - ((current_base_row[22]) + (current_base_row[25])))))
-                + ((node_874)
-                    * ((next_base_row[22])
-                        - ((((((current_base_row[22]) * (current_base_row[24]))
-                            + ((current_base_row[21]) * (current_base_row[25])))
-                            - (node_698))
-                            + (node_690))
-                            + (node_692)))))
-                + ((node_876)
-                    * ((((((current_base_row[22]) * (next_base_row[21]))
-                        + ((current_base_row[21]) * (next_base_row[22])))
-                        - (node_718))
-                        + (node_710))
-                        + (node_712))))
-                + ((node_879)
-                    * ((next_base_row[22])
-                        - ((current_base_row[21]) * (current_base_row[23])))))
-                + ((node_881) * (node_393)))
-                + ((node_883) * (node_393)))
-                * (node_3965))
-                + ((node_620) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_394))
-                + ((node_757) * (node_422)))
-                + ((node_765) * (node_394)))
-                + ((node_770) * (node_454)))
-                + ((node_775)
-                    * ((node_452)
-                        * ((next_base_row[23])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_394)))
-                + ((node_785)
-                    * ((((node_392) * (current_base_row[21]))
-                        + (((node_439) * (node_600))
-                            * (node_503)))
-                        + ((((node_391)
-                            - (BFieldElement::new(3)))
-                            * (node_600))
-                            * (current_base_row[39])))))
-                + ((node_789)
-                    * ((next_base_row[20])
-                        - (current_base_row[9]))))
-                + ((node_793) * (node_447)))
-                + ((node_798) * (node_394)))
-                + ((node_802) * (node_393)))
-                + ((node_805) * (node_393)))
-                + ((node_808) * (node_392)))
-                + ((node_812) * (node_392)))
-                + ((node_815) * (node_394)))
-                + ((node_818)
-                    * (((node_445)
-                        * ((current_base_row[26]) - (next_base_row[21])))
-                        + ((current_base_row[39])
-                            * ((current_base_row[26])
-                                - (next_base_row[26]))))))
-                + ((node_821)
+                + ((node_1077) * (node_892)))
+                + ((node_1080)
+                    * ((next_base_row[45]) - (current_base_row[23]))))
+                + ((node_1084)
+                    * ((next_base_row[45]) - (current_base_row[24]))))
+                + ((node_1087) * (node_664)))
+                + ((node_1090) * (node_776)))
+                + ((node_1093)
                     * ((current_base_row[28]) - (current_base_row[23]))))
-                + ((node_823) * (node_394)))
-                + ((node_825) * (node_394)))
-                + ((node_827) * (node_394)))
-                + ((node_830) * (node_393)))
-                + ((node_833) * (node_393)))
-                + ((node_835) * (node_393)))
-                + ((node_838)
-                    * ((next_base_row[21])
-                        - ((BFieldElement::new(1)) - (node_665)))))
-                + ((node_845) * (node_422)))
-                + ((node_850) * (node_394)))
-                + ((node_854) * (node_394)))
-                + ((node_858) * (node_394)))
-                + ((node_861) * (node_394)))
-                + ((node_864) * (node_394)))
-                + ((node_867) * (node_392)))
-                + ((node_870) * (node_394)))
-                + ((node_872)
+                + ((node_1095) * (node_664)))
+                + ((node_1097) * (node_664)))
+                + ((node_1099) * (node_664)))
+                + ((node_1102) * ((next_base_row[23]) - (node_930))))
+                + ((node_1105) * (node_933)))
+                + ((node_1107) * ((node_934) - (BFieldElement::new(1)))))
+                + ((node_1110) * ((current_base_row[41]) * (node_938))))
+                + ((node_1117)
+                    * ((current_base_row[23])
+                        - (((BFieldElement::new(4294967296))
+                            * (next_base_row[24]))
+                            + (next_base_row[23])))))
+                + ((node_1122) * (node_664)))
+                + ((node_1126) * (node_664)))
+                + ((node_1130) * (node_664)))
+                + ((node_1133) * (node_664)))
+                + ((node_1136) * (node_664)))
+                + ((node_1139)
+                    * (((current_base_row[23]) - (node_952)) - (next_base_row[23]))))
+                + ((node_1142) * (node_664)))
+                + ((node_1144)
                     * ((next_base_row[23])
                         - ((current_base_row[23]) + (current_base_row[26])))))
-                + ((node_874)
-                    * ((next_base_row[23])
-                        - (((((current_base_row[23]) * (current_base_row[24]))
-                            + ((current_base_row[22]) * (current_base_row[25])))
-                            + ((current_base_row[21]) * (current_base_row[26])))
-                            + (node_698)))))
-                + ((node_876)
-                    * (((((current_base_row[23]) * (next_base_row[21])) + (node_680))
-                        + ((current_base_row[21]) * (next_base_row[23])))
-                        + (node_718))))
-                + ((node_879) * ((next_base_row[23]) - (node_689))))
-                + ((node_881) * (node_394)))
-                + ((node_883) * (node_394)))
-                * (node_3965))
-                + (((next_base_row[9]) - (current_base_row[9])) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_395))
-                + ((node_757) * (node_423)))
-                + ((node_765) * (node_395)))
-                + ((node_770) * (node_457)))
-                + ((node_775)
-                    * ((node_455)
-                        * ((next_base_row[24])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_395)))
-                + ((node_785) * (node_393)))
-                + ((node_789)
-                    * ((next_base_row[7]) - (current_base_row[9]))))
-                + ((node_793) * (node_550)))
-                + ((node_798) * (node_395)))
-                + ((node_802) * (node_394)))
-                + ((node_805) * (node_394)))
-                + ((node_808) * (node_393)))
-                + ((node_812) * (node_393)))
-                + ((node_815) * (node_395)))
-                + ((node_818)
-                    * (((node_445)
-                        * ((current_base_row[27]) - (next_base_row[22])))
-                        + ((current_base_row[39])
-                            * ((current_base_row[27])
-                                - (next_base_row[27]))))))
-                + ((node_821)
+                + ((node_1146)
+                    * ((next_base_row[23]) - (((node_961) - (node_962)) - (node_964)))))
+                + ((node_1148)
+                    * ((((node_934) - (node_982)) - (node_984)) - (BFieldElement::new(1)))))
+                + ((node_1151) * (node_933)))
+                + ((node_1153) * (node_664)))
+                + ((node_1155) * (node_664)))
+                * (node_4237))
+                + ((node_663) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_665))
+                + ((node_1029) * (node_693)))
+                + ((node_1037) * (node_665)))
+                + ((node_1042) * (node_722)))
+                + ((node_1047) * ((node_721) * (node_693))))
+                + ((node_1052) * (node_665)))
+                + ((node_1057) * (node_776)))
+                + ((node_1061)
+                    * (((next_base_row[21])
+                        - (current_base_row[9]))
+                        - (BFieldElement::new(2)))))
+                + ((node_1065)
+                    * ((next_base_row[9]) - (current_base_row[21]))))
+                + ((node_1070) * (node_665)))
+                + ((node_1074) * (node_664)))
+                + ((node_1077) * (node_664)))
+                + ((node_1080)
+                    * ((next_base_row[23]) - (next_base_row[46]))))
+                + ((node_1084)
+                    * ((next_base_row[46]) - (current_base_row[23]))))
+                + ((node_1087) * (node_665)))
+                + ((node_1090)
+                    * ((((next_base_row[33]) * (BFieldElement::new(2)))
+                        + (current_base_row[41]))
+                        - (current_base_row[33]))))
+                + ((node_1093)
                     * ((current_base_row[29]) - (current_base_row[24]))))
-                + ((node_823) * (node_395)))
-                + ((node_825) * (node_395)))
-                + ((node_827) * (node_395)))
-                + ((node_830) * (node_394)))
-                + ((node_833) * (node_394)))
-                + ((node_835) * (node_394)))
-                + ((node_838) * (node_392)))
-                + ((node_845) * (node_423)))
-                + ((node_850) * (node_395)))
-                + ((node_854) * (node_395)))
-                + ((node_858) * (node_395)))
-                + ((node_861) * (node_395)))
-                + ((node_864) * (node_395)))
-                + ((node_867) * (node_393)))
-                + ((node_870) * (node_395)))
-                + ((node_872) * (node_556)))
-                + ((node_874) * (node_556)))
-                + ((node_876) * (node_556)))
-                + ((node_879) * (node_399)))
-                + ((node_881) * (node_395)))
-                + ((node_883) * (node_395)))
-                * (node_3965))
-                + ((node_393) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_396))
-                + ((node_757) * (node_424)))
-                + ((node_765) * (node_421)))
-                + ((node_770) * (node_462)))
-                + ((node_775)
-                    * ((node_460)
+                + ((node_1095) * (node_665)))
+                + ((node_1097) * (node_665)))
+                + ((node_1099) * (node_665)))
+                + ((node_1102) * (node_664)))
+                + ((node_1105) * (node_664)))
+                + ((node_1107) * (node_664)))
+                + ((node_1110) * ((node_936) * (node_938))))
+                + ((node_1117)
+                    * ((next_base_row[23])
+                        * (((current_base_row[41])
+                            * ((next_base_row[24])
+                                - (BFieldElement::new(4294967295))))
+                            - (BFieldElement::new(1))))))
+                + ((node_1122) * (node_665)))
+                + ((node_1126) * (node_665)))
+                + ((node_1130) * (node_665)))
+                + ((node_1133) * (node_665)))
+                + ((node_1136) * (node_665)))
+                + ((node_1139) * (node_825)))
+                + ((node_1142) * (node_665)))
+                + ((node_1144)
+                    * ((next_base_row[24])
+                        - ((current_base_row[24]) + (current_base_row[27])))))
+                + ((node_1146)
+                    * ((next_base_row[24])
+                        - ((((((current_base_row[24]) * (current_base_row[26]))
+                            + ((current_base_row[23]) * (current_base_row[27])))
+                            - (node_970))
+                            + (node_962))
+                            + (node_964)))))
+                + ((node_1148)
+                    * ((((((current_base_row[24]) * (next_base_row[23]))
+                        + ((current_base_row[23]) * (next_base_row[24])))
+                        - (node_990))
+                        + (node_982))
+                        + (node_984))))
+                + ((node_1151)
+                    * ((next_base_row[24])
+                        - ((current_base_row[23]) * (current_base_row[25])))))
+                + ((node_1153) * (node_665)))
+                + ((node_1155) * (node_665)))
+                * (node_4237))
+                + ((node_892) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_666))
+                + ((node_1029) * (node_694)))
+                + ((node_1037) * (node_666)))
+                + ((node_1042) * (node_726)))
+                + ((node_1047)
+                    * ((node_724)
                         * ((next_base_row[25])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_447)))
-                + ((node_785) * (node_394)))
-                + ((node_789) * (node_447)))
-                + ((node_793) * (node_553)))
-                + ((node_798) * (node_447)))
-                + ((node_802) * (node_395)))
-                + ((node_805) * (node_395)))
-                + ((node_808) * (node_394)))
-                + ((node_812) * (node_394)))
-                + ((node_815) * (node_577)))
-                + ((node_818)
-                    * (((node_445)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_666)))
+                + ((node_1057)
+                    * ((((node_664) * (current_base_row[23]))
+                        + (((node_711) * (node_872))
+                            * (node_775)))
+                        + ((((node_663)
+                            - (BFieldElement::new(3)))
+                            * (node_872))
+                            * (current_base_row[41])))))
+                + ((node_1061)
+                    * ((next_base_row[22])
+                        - (current_base_row[11]))))
+                + ((node_1065) * (node_719)))
+                + ((node_1070) * (node_666)))
+                + ((node_1074) * (node_665)))
+                + ((node_1077) * (node_665)))
+                + ((node_1080) * (node_664)))
+                + ((node_1084) * (node_664)))
+                + ((node_1087) * (node_666)))
+                + ((node_1090)
+                    * (((node_717)
                         * ((current_base_row[28]) - (next_base_row[23])))
-                        + ((current_base_row[39])
+                        + ((current_base_row[41])
                             * ((current_base_row[28])
                                 - (next_base_row[28]))))))
-                + ((node_821)
+                + ((node_1093)
                     * ((current_base_row[30]) - (current_base_row[25]))))
-                + ((node_823) * (node_447)))
-                + ((node_825) * (node_447)))
-                + ((node_827) * (node_577)))
-                + ((node_830) * (node_395)))
-                + ((node_833) * (node_395)))
-                + ((node_835) * (node_395)))
-                + ((node_838) * (node_393)))
-                + ((node_845) * (node_424)))
-                + ((node_850) * (node_397)))
-                + ((node_854) * (node_397)))
-                + ((node_858) * (node_397)))
-                + ((node_861) * (node_550)))
-                + ((node_864) * (node_397)))
-                + ((node_867) * (node_394)))
-                + ((node_870) * (node_550)))
-                + ((node_872) * (node_559)))
-                + ((node_874) * (node_559)))
-                + ((node_876) * (node_559)))
-                + ((node_879) * (node_400)))
-                + ((node_881) * (node_421)))
-                + ((node_883) * (node_396)))
-                * (node_3965))
-                + ((node_394) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_397))
-                + ((node_757) * (node_425)))
-                + ((node_765) * (node_422)))
-                + ((node_770) * (node_465)))
-                + ((node_775)
-                    * ((node_463)
+                + ((node_1095) * (node_666)))
+                + ((node_1097) * (node_666)))
+                + ((node_1099) * (node_666)))
+                + ((node_1102) * (node_665)))
+                + ((node_1105) * (node_665)))
+                + ((node_1107) * (node_665)))
+                + ((node_1110)
+                    * ((next_base_row[23])
+                        - ((BFieldElement::new(1)) - (node_937)))))
+                + ((node_1117) * (node_694)))
+                + ((node_1122) * (node_666)))
+                + ((node_1126) * (node_666)))
+                + ((node_1130) * (node_666)))
+                + ((node_1133) * (node_666)))
+                + ((node_1136) * (node_666)))
+                + ((node_1139) * (node_664)))
+                + ((node_1142) * (node_666)))
+                + ((node_1144)
+                    * ((next_base_row[25])
+                        - ((current_base_row[25]) + (current_base_row[28])))))
+                + ((node_1146)
+                    * ((next_base_row[25])
+                        - (((((current_base_row[25]) * (current_base_row[26]))
+                            + ((current_base_row[24]) * (current_base_row[27])))
+                            + ((current_base_row[23]) * (current_base_row[28])))
+                            + (node_970)))))
+                + ((node_1148)
+                    * (((((current_base_row[25]) * (next_base_row[23])) + (node_952))
+                        + ((current_base_row[23]) * (next_base_row[25])))
+                        + (node_990))))
+                + ((node_1151) * ((next_base_row[25]) - (node_961))))
+                + ((node_1153) * (node_666)))
+                + ((node_1155) * (node_666)))
+                * (node_4237))
+                + (((next_base_row[11]) - (current_base_row[11])) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_667))
+                + ((node_1029) * (node_695)))
+                + ((node_1037) * (node_667)))
+                + ((node_1042) * (node_729)))
+                + ((node_1047)
+                    * ((node_727)
                         * ((next_base_row[26])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_550)))
-                + ((node_785) * (node_395)))
-                + ((node_789) * (node_550)))
-                + ((node_793) * (node_556)))
-                + ((node_798) * (node_550)))
-                + ((node_802) * (node_396)))
-                + ((node_805) * (node_447)))
-                + ((node_808) * (node_395)))
-                + ((node_812) * (node_395)))
-                + ((node_815) * (node_580)))
-                + ((node_818)
-                    * (((node_445)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_667)))
+                + ((node_1057) * (node_665)))
+                + ((node_1061)
+                    * ((next_base_row[9])
+                        - (current_base_row[11]))))
+                + ((node_1065) * (node_822)))
+                + ((node_1070) * (node_667)))
+                + ((node_1074) * (node_666)))
+                + ((node_1077) * (node_666)))
+                + ((node_1080) * (node_665)))
+                + ((node_1084) * (node_665)))
+                + ((node_1087) * (node_667)))
+                + ((node_1090)
+                    * (((node_717)
                         * ((current_base_row[29]) - (next_base_row[24])))
-                        + ((current_base_row[39])
+                        + ((current_base_row[41])
                             * ((current_base_row[29])
                                 - (next_base_row[29]))))))
-                + ((node_821) * (node_392)))
-                + ((node_823) * (node_550)))
-                + ((node_825) * (node_550)))
-                + ((node_827) * (node_580)))
-                + ((node_830) * (node_397)))
-                + ((node_833) * (node_397)))
-                + ((node_835) * (node_550)))
-                + ((node_838) * (node_394)))
-                + ((node_845) * (node_425)))
-                + ((node_850) * (node_398)))
-                + ((node_854) * (node_398)))
-                + ((node_858) * (node_398)))
-                + ((node_861) * (node_553)))
-                + ((node_864) * (node_398)))
-                + ((node_867) * (node_395)))
-                + ((node_870) * (node_553)))
-                + ((node_872) * (node_562)))
-                + ((node_874) * (node_562)))
-                + ((node_876) * (node_562)))
-                + ((node_879) * (node_401)))
-                + ((node_881) * (node_422)))
-                + ((node_883) * (node_397)))
-                * (node_3965))
-                + ((node_395) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_398))
-                + ((node_757) * (node_426)))
-                + ((node_765) * (node_423)))
-                + ((node_770) * (node_469)))
-                + ((node_775)
-                    * ((node_467)
+                + ((node_1093)
+                    * ((current_base_row[31]) - (current_base_row[26]))))
+                + ((node_1095) * (node_667)))
+                + ((node_1097) * (node_667)))
+                + ((node_1099) * (node_667)))
+                + ((node_1102) * (node_666)))
+                + ((node_1105) * (node_666)))
+                + ((node_1107) * (node_666)))
+                + ((node_1110) * (node_664)))
+                + ((node_1117) * (node_695)))
+                + ((node_1122) * (node_667)))
+                + ((node_1126) * (node_667)))
+                + ((node_1130) * (node_667)))
+                + ((node_1133) * (node_667)))
+                + ((node_1136) * (node_667)))
+                + ((node_1139) * (node_665)))
+                + ((node_1142) * (node_667)))
+                + ((node_1144) * (node_828)))
+                + ((node_1146) * (node_828)))
+                + ((node_1148) * (node_828)))
+                + ((node_1151) * (node_671)))
+                + ((node_1153) * (node_667)))
+                + ((node_1155) * (node_667)))
+                * (node_4237))
+                + ((node_665) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_668))
+                + ((node_1029) * (node_696)))
+                + ((node_1037) * (node_693)))
+                + ((node_1042) * (node_734)))
+                + ((node_1047)
+                    * ((node_732)
                         * ((next_base_row[27])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_553)))
-                + ((node_785) * (node_396)))
-                + ((node_789) * (node_553)))
-                + ((node_793) * (node_559)))
-                + ((node_798) * (node_553)))
-                + ((node_802) * (node_397)))
-                + ((node_805) * (node_550)))
-                + ((node_808) * (node_421)))
-                + ((node_812) * (node_396)))
-                + ((node_815) * (node_583)))
-                + ((node_818)
-                    * (((node_445)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_719)))
+                + ((node_1057) * (node_666)))
+                + ((node_1061) * (node_719)))
+                + ((node_1065) * (node_825)))
+                + ((node_1070) * (node_719)))
+                + ((node_1074) * (node_667)))
+                + ((node_1077) * (node_667)))
+                + ((node_1080) * (node_666)))
+                + ((node_1084) * (node_666)))
+                + ((node_1087) * (node_849)))
+                + ((node_1090)
+                    * (((node_717)
                         * ((current_base_row[30]) - (next_base_row[25])))
-                        + ((current_base_row[39])
+                        + ((current_base_row[41])
                             * ((current_base_row[30])
                                 - (next_base_row[30]))))))
-                + ((node_821) * (node_393)))
-                + ((node_823) * (node_553)))
-                + ((node_825) * (node_553)))
-                + ((node_827) * (node_583)))
-                + ((node_830) * (node_398)))
-                + ((node_833) * (node_398)))
-                + ((node_835) * (node_553)))
-                + ((node_838) * (node_395)))
-                + ((node_845) * (node_426)))
-                + ((node_850) * (node_399)))
-                + ((node_854) * (node_399)))
-                + ((node_858) * (node_399)))
-                + ((node_861) * (node_556)))
-                + ((node_864) * (node_399)))
-                + ((node_867) * (node_556)))
-                + ((node_870) * (node_556)))
-                + ((node_872) * (node_565)))
-                + ((node_874) * (node_565)))
-                + ((node_876) * (node_565)))
-                + ((node_879) * (node_402)))
-                + ((node_881) * (node_423)))
-                + ((node_883) * (node_398)))
-                * (node_3965))
-                + ((node_447) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_399))
-                + ((node_757) * (node_427)))
-                + ((node_765) * (node_424)))
-                + ((node_770) * (node_472)))
-                + ((node_775)
-                    * ((node_470)
+                + ((node_1093)
+                    * ((current_base_row[32]) - (current_base_row[27]))))
+                + ((node_1095) * (node_719)))
+                + ((node_1097) * (node_719)))
+                + ((node_1099) * (node_849)))
+                + ((node_1102) * (node_667)))
+                + ((node_1105) * (node_667)))
+                + ((node_1107) * (node_667)))
+                + ((node_1110) * (node_665)))
+                + ((node_1117) * (node_696)))
+                + ((node_1122) * (node_669)))
+                + ((node_1126) * (node_669)))
+                + ((node_1130) * (node_669)))
+                + ((node_1133) * (node_822)))
+                + ((node_1136) * (node_669)))
+                + ((node_1139) * (node_666)))
+                + ((node_1142) * (node_822)))
+                + ((node_1144) * (node_831)))
+                + ((node_1146) * (node_831)))
+                + ((node_1148) * (node_831)))
+                + ((node_1151) * (node_672)))
+                + ((node_1153) * (node_693)))
+                + ((node_1155) * (node_668)))
+                * (node_4237))
+                + ((node_666) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_669))
+                + ((node_1029) * (node_697)))
+                + ((node_1037) * (node_694)))
+                + ((node_1042) * (node_737)))
+                + ((node_1047)
+                    * ((node_735)
                         * ((next_base_row[28])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_556)))
-                + ((node_785) * (node_397)))
-                + ((node_789) * (node_556)))
-                + ((node_793) * (node_562)))
-                + ((node_798) * (node_556)))
-                + ((node_802) * (node_398)))
-                + ((node_805) * (node_553)))
-                + ((node_808) * (node_422)))
-                + ((node_812) * (node_397)))
-                + ((node_815) * (node_586)))
-                + ((node_818) * (node_580)))
-                + ((node_821) * (node_394)))
-                + ((node_823) * (node_556)))
-                + ((node_825) * (node_556)))
-                + ((node_827) * (node_586)))
-                + ((node_830) * (node_399)))
-                + ((node_833) * (node_399)))
-                + ((node_835) * (node_556)))
-                + ((node_838) * (node_397)))
-                + ((node_845) * (node_427)))
-                + ((node_850) * (node_400)))
-                + ((node_854) * (node_400)))
-                + ((node_858) * (node_400)))
-                + ((node_861) * (node_559)))
-                + ((node_864) * (node_400)))
-                + ((node_867) * (node_559)))
-                + ((node_870) * (node_559)))
-                + ((node_872) * (node_568)))
-                + ((node_874) * (node_568)))
-                + ((node_876) * (node_568)))
-                + ((node_879) * (node_403)))
-                + ((node_881) * (node_424)))
-                + ((node_883) * (node_399)))
-                * (node_3965))
-                + ((node_550) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_400))
-                + ((node_757) * (node_428)))
-                + ((node_765) * (node_425)))
-                + ((node_770) * (node_477)))
-                + ((node_775)
-                    * ((node_475)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_822)))
+                + ((node_1057) * (node_667)))
+                + ((node_1061) * (node_822)))
+                + ((node_1065) * (node_828)))
+                + ((node_1070) * (node_822)))
+                + ((node_1074) * (node_668)))
+                + ((node_1077) * (node_719)))
+                + ((node_1080) * (node_667)))
+                + ((node_1084) * (node_667)))
+                + ((node_1087) * (node_852)))
+                + ((node_1090)
+                    * (((node_717)
+                        * ((current_base_row[31]) - (next_base_row[26])))
+                        + ((current_base_row[41])
+                            * ((current_base_row[31])
+                                - (next_base_row[31]))))))
+                + ((node_1093) * (node_664)))
+                + ((node_1095) * (node_822)))
+                + ((node_1097) * (node_822)))
+                + ((node_1099) * (node_852)))
+                + ((node_1102) * (node_669)))
+                + ((node_1105) * (node_669)))
+                + ((node_1107) * (node_822)))
+                + ((node_1110) * (node_666)))
+                + ((node_1117) * (node_697)))
+                + ((node_1122) * (node_670)))
+                + ((node_1126) * (node_670)))
+                + ((node_1130) * (node_670)))
+                + ((node_1133) * (node_825)))
+                + ((node_1136) * (node_670)))
+                + ((node_1139) * (node_667)))
+                + ((node_1142) * (node_825)))
+                + ((node_1144) * (node_834)))
+                + ((node_1146) * (node_834)))
+                + ((node_1148) * (node_834)))
+                + ((node_1151) * (node_673)))
+                + ((node_1153) * (node_694)))
+                + ((node_1155) * (node_669)))
+                * (node_4237))
+                + ((node_667) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_670))
+                + ((node_1029) * (node_698)))
+                + ((node_1037) * (node_695)))
+                + ((node_1042) * (node_741)))
+                + ((node_1047)
+                    * ((node_739)
                         * ((next_base_row[29])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_559)))
-                + ((node_785) * (node_398)))
-                + ((node_789) * (node_559)))
-                + ((node_793) * (node_565)))
-                + ((node_798) * (node_559)))
-                + ((node_802) * (node_399)))
-                + ((node_805) * (node_556)))
-                + ((node_808) * (node_423)))
-                + ((node_812) * (node_398)))
-                + ((node_815) * (node_589)))
-                + ((node_818) * (node_583)))
-                + ((node_821) * (node_395)))
-                + ((node_823) * (node_559)))
-                + ((node_825) * (node_559)))
-                + ((node_827) * (node_589)))
-                + ((node_830) * (node_400)))
-                + ((node_833) * (node_400)))
-                + ((node_835) * (node_559)))
-                + ((node_838) * (node_398)))
-                + ((node_845) * (node_428)))
-                + ((node_850) * (node_401)))
-                + ((node_854) * (node_401)))
-                + ((node_858) * (node_401)))
-                + ((node_861) * (node_562)))
-                + ((node_864) * (node_401)))
-                + ((node_867) * (node_562)))
-                + ((node_870) * (node_562)))
-                + ((node_872) * (node_571)))
-                + ((node_874) * (node_571)))
-                + ((node_876) * (node_571)))
-                + ((node_879) * (node_404)))
-                + ((node_881) * (node_425)))
-                + ((node_883) * (node_400)))
-                * (node_3965))
-                + ((node_553) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_401))
-                + ((node_757) * (node_429)))
-                + ((node_765) * (node_426)))
-                + ((node_770) * (node_480)))
-                + ((node_775)
-                    * ((node_478)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_825)))
+                + ((node_1057) * (node_668)))
+                + ((node_1061) * (node_825)))
+                + ((node_1065) * (node_831)))
+                + ((node_1070) * (node_825)))
+                + ((node_1074) * (node_669)))
+                + ((node_1077) * (node_822)))
+                + ((node_1080) * (node_693)))
+                + ((node_1084) * (node_668)))
+                + ((node_1087) * (node_855)))
+                + ((node_1090)
+                    * (((node_717)
+                        * ((current_base_row[32]) - (next_base_row[27])))
+                        + ((current_base_row[41])
+                            * ((current_base_row[32])
+                                - (next_base_row[32]))))))
+                + ((node_1093) * (node_665)))
+                + ((node_1095) * (node_825)))
+                + ((node_1097) * (node_825)))
+                + ((node_1099) * (node_855)))
+                + ((node_1102) * (node_670)))
+                + ((node_1105) * (node_670)))
+                + ((node_1107) * (node_825)))
+                + ((node_1110) * (node_667)))
+                + ((node_1117) * (node_698)))
+                + ((node_1122) * (node_671)))
+                + ((node_1126) * (node_671)))
+                + ((node_1130) * (node_671)))
+                + ((node_1133) * (node_828)))
+                + ((node_1136) * (node_671)))
+                + ((node_1139) * (node_828)))
+                + ((node_1142) * (node_828)))
+                + ((node_1144) * (node_837)))
+                + ((node_1146) * (node_837)))
+                + ((node_1148) * (node_837)))
+                + ((node_1151) * (node_674)))
+                + ((node_1153) * (node_695)))
+                + ((node_1155) * (node_670)))
+                * (node_4237))
+                + ((node_719) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_671))
+                + ((node_1029) * (node_699)))
+                + ((node_1037) * (node_696)))
+                + ((node_1042) * (node_744)))
+                + ((node_1047)
+                    * ((node_742)
                         * ((next_base_row[30])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_562)))
-                + ((node_785) * (node_399)))
-                + ((node_789) * (node_562)))
-                + ((node_793) * (node_568)))
-                + ((node_798) * (node_562)))
-                + ((node_802) * (node_400)))
-                + ((node_805) * (node_559)))
-                + ((node_808) * (node_424)))
-                + ((node_812) * (node_399)))
-                + ((node_815) * (node_592)))
-                + ((node_818) * (node_586)))
-                + ((node_821) * (node_447)))
-                + ((node_823) * (node_562)))
-                + ((node_825) * (node_562)))
-                + ((node_827) * (node_592)))
-                + ((node_830) * (node_401)))
-                + ((node_833) * (node_401)))
-                + ((node_835) * (node_562)))
-                + ((node_838) * (node_399)))
-                + ((node_845) * (node_429)))
-                + ((node_850) * (node_402)))
-                + ((node_854) * (node_402)))
-                + ((node_858) * (node_402)))
-                + ((node_861) * (node_565)))
-                + ((node_864) * (node_402)))
-                + ((node_867) * (node_565)))
-                + ((node_870) * (node_565)))
-                + ((node_872) * (node_574)))
-                + ((node_874) * (node_574)))
-                + ((node_876) * (node_574)))
-                + ((node_879) * (node_405)))
-                + ((node_881) * (node_426)))
-                + ((node_883) * (node_401)))
-                * (node_3965))
-                + ((node_556) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_402))
-                + ((node_757) * (node_430)))
-                + ((node_765) * (node_427)))
-                + ((node_770) * (node_484)))
-                + ((node_775)
-                    * ((node_482)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_828)))
+                + ((node_1057) * (node_669)))
+                + ((node_1061) * (node_828)))
+                + ((node_1065) * (node_834)))
+                + ((node_1070) * (node_828)))
+                + ((node_1074) * (node_670)))
+                + ((node_1077) * (node_825)))
+                + ((node_1080) * (node_694)))
+                + ((node_1084) * (node_669)))
+                + ((node_1087) * (node_858)))
+                + ((node_1090) * (node_852)))
+                + ((node_1093) * (node_666)))
+                + ((node_1095) * (node_828)))
+                + ((node_1097) * (node_828)))
+                + ((node_1099) * (node_858)))
+                + ((node_1102) * (node_671)))
+                + ((node_1105) * (node_671)))
+                + ((node_1107) * (node_828)))
+                + ((node_1110) * (node_669)))
+                + ((node_1117) * (node_699)))
+                + ((node_1122) * (node_672)))
+                + ((node_1126) * (node_672)))
+                + ((node_1130) * (node_672)))
+                + ((node_1133) * (node_831)))
+                + ((node_1136) * (node_672)))
+                + ((node_1139) * (node_831)))
+                + ((node_1142) * (node_831)))
+                + ((node_1144) * (node_840)))
+                + ((node_1146) * (node_840)))
+                + ((node_1148) * (node_840)))
+                + ((node_1151) * (node_675)))
+                + ((node_1153) * (node_696)))
+                + ((node_1155) * (node_671)))
+                * (node_4237))
+                + ((node_822) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_672))
+                + ((node_1029) * (node_700)))
+                + ((node_1037) * (node_697)))
+                + ((node_1042) * (node_749)))
+                + ((node_1047)
+                    * ((node_747)
                         * ((next_base_row[31])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_565)))
-                + ((node_785) * (node_400)))
-                + ((node_789) * (node_565)))
-                + ((node_793) * (node_571)))
-                + ((node_798) * (node_565)))
-                + ((node_802) * (node_401)))
-                + ((node_805) * (node_562)))
-                + ((node_808) * (node_425)))
-                + ((node_812) * (node_400)))
-                + ((node_815) * (node_594)))
-                + ((node_818) * (node_589)))
-                + ((node_821) * (node_550)))
-                + ((node_823) * (node_565)))
-                + ((node_825) * (node_565)))
-                + ((node_827) * (node_594)))
-                + ((node_830) * (node_402)))
-                + ((node_833) * (node_402)))
-                + ((node_835) * (node_565)))
-                + ((node_838) * (node_400)))
-                + ((node_845) * (node_430)))
-                + ((node_850) * (node_403)))
-                + ((node_854) * (node_403)))
-                + ((node_858) * (node_403)))
-                + ((node_861) * (node_568)))
-                + ((node_864) * (node_403)))
-                + ((node_867) * (node_568)))
-                + ((node_870) * (node_568)))
-                + ((node_872) * (node_577)))
-                + ((node_874) * (node_577)))
-                + ((node_876) * (node_577)))
-                + ((node_879) * (node_406)))
-                + ((node_881) * (node_427)))
-                + ((node_883) * (node_402)))
-                * (node_3965))
-                + ((node_559) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_403))
-                + ((node_757) * (node_431)))
-                + ((node_765) * (node_428)))
-                + ((node_770) * (node_487)))
-                + ((node_775)
-                    * ((node_485)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_831)))
+                + ((node_1057) * (node_670)))
+                + ((node_1061) * (node_831)))
+                + ((node_1065) * (node_837)))
+                + ((node_1070) * (node_831)))
+                + ((node_1074) * (node_671)))
+                + ((node_1077) * (node_828)))
+                + ((node_1080) * (node_695)))
+                + ((node_1084) * (node_670)))
+                + ((node_1087) * (node_861)))
+                + ((node_1090) * (node_855)))
+                + ((node_1093) * (node_667)))
+                + ((node_1095) * (node_831)))
+                + ((node_1097) * (node_831)))
+                + ((node_1099) * (node_861)))
+                + ((node_1102) * (node_672)))
+                + ((node_1105) * (node_672)))
+                + ((node_1107) * (node_831)))
+                + ((node_1110) * (node_670)))
+                + ((node_1117) * (node_700)))
+                + ((node_1122) * (node_673)))
+                + ((node_1126) * (node_673)))
+                + ((node_1130) * (node_673)))
+                + ((node_1133) * (node_834)))
+                + ((node_1136) * (node_673)))
+                + ((node_1139) * (node_834)))
+                + ((node_1142) * (node_834)))
+                + ((node_1144) * (node_843)))
+                + ((node_1146) * (node_843)))
+                + ((node_1148) * (node_843)))
+                + ((node_1151) * (node_676)))
+                + ((node_1153) * (node_697)))
+                + ((node_1155) * (node_672)))
+                * (node_4237))
+                + ((node_825) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_673))
+                + ((node_1029) * (node_701)))
+                + ((node_1037) * (node_698)))
+                + ((node_1042) * (node_752)))
+                + ((node_1047)
+                    * ((node_750)
                         * ((next_base_row[32])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_568)))
-                + ((node_785) * (node_401)))
-                + ((node_789) * (node_568)))
-                + ((node_793) * (node_574)))
-                + ((node_798) * (node_568)))
-                + ((node_802) * (node_402)))
-                + ((node_805) * (node_565)))
-                + ((node_808) * (node_426)))
-                + ((node_812) * (node_401)))
-                + ((node_815) * (node_595)))
-                + ((node_818) * (node_592)))
-                + ((node_821) * (node_553)))
-                + ((node_823) * (node_568)))
-                + ((node_825) * (node_568)))
-                + ((node_827) * (node_595)))
-                + ((node_830) * (node_403)))
-                + ((node_833) * (node_403)))
-                + ((node_835) * (node_568)))
-                + ((node_838) * (node_401)))
-                + ((node_845) * (node_431)))
-                + ((node_850) * (node_404)))
-                + ((node_854) * (node_404)))
-                + ((node_858) * (node_404)))
-                + ((node_861) * (node_571)))
-                + ((node_864) * (node_404)))
-                + ((node_867) * (node_571)))
-                + ((node_870) * (node_571)))
-                + ((node_872) * (node_580)))
-                + ((node_874) * (node_580)))
-                + ((node_876) * (node_580)))
-                + ((node_879) * (node_407)))
-                + ((node_881) * (node_428)))
-                + ((node_883) * (node_403)))
-                * (node_3965))
-                + ((node_562) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_404))
-                + ((node_757) * (node_432)))
-                + ((node_765) * (node_429)))
-                + ((node_770) * (node_492)))
-                + ((node_775)
-                    * ((node_490)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_834)))
+                + ((node_1057) * (node_671)))
+                + ((node_1061) * (node_834)))
+                + ((node_1065) * (node_840)))
+                + ((node_1070) * (node_834)))
+                + ((node_1074) * (node_672)))
+                + ((node_1077) * (node_831)))
+                + ((node_1080) * (node_696)))
+                + ((node_1084) * (node_671)))
+                + ((node_1087) * (node_864)))
+                + ((node_1090) * (node_858)))
+                + ((node_1093) * (node_719)))
+                + ((node_1095) * (node_834)))
+                + ((node_1097) * (node_834)))
+                + ((node_1099) * (node_864)))
+                + ((node_1102) * (node_673)))
+                + ((node_1105) * (node_673)))
+                + ((node_1107) * (node_834)))
+                + ((node_1110) * (node_671)))
+                + ((node_1117) * (node_701)))
+                + ((node_1122) * (node_674)))
+                + ((node_1126) * (node_674)))
+                + ((node_1130) * (node_674)))
+                + ((node_1133) * (node_837)))
+                + ((node_1136) * (node_674)))
+                + ((node_1139) * (node_837)))
+                + ((node_1142) * (node_837)))
+                + ((node_1144) * (node_846)))
+                + ((node_1146) * (node_846)))
+                + ((node_1148) * (node_846)))
+                + ((node_1151) * (node_677)))
+                + ((node_1153) * (node_698)))
+                + ((node_1155) * (node_673)))
+                * (node_4237))
+                + ((node_828) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_674))
+                + ((node_1029) * (node_702)))
+                + ((node_1037) * (node_699)))
+                + ((node_1042) * (node_756)))
+                + ((node_1047)
+                    * ((node_754)
                         * ((next_base_row[33])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_571)))
-                + ((node_785) * (node_402)))
-                + ((node_789) * (node_571)))
-                + ((node_793) * (node_577)))
-                + ((node_798) * (node_571)))
-                + ((node_802) * (node_403)))
-                + ((node_805) * (node_568)))
-                + ((node_808) * (node_427)))
-                + ((node_812) * (node_402)))
-                + ((node_815) * (node_418)))
-                + ((node_818) * (node_594)))
-                + ((node_821) * (node_556)))
-                + ((node_823) * (node_571)))
-                + ((node_825) * (node_571)))
-                + ((node_827) * (node_418)))
-                + ((node_830) * (node_404)))
-                + ((node_833) * (node_404)))
-                + ((node_835) * (node_571)))
-                + ((node_838) * (node_402)))
-                + ((node_845) * (node_432)))
-                + ((node_850) * (node_405)))
-                + ((node_854) * (node_405)))
-                + ((node_858) * (node_405)))
-                + ((node_861) * (node_574)))
-                + ((node_864) * (node_405)))
-                + ((node_867) * (node_574)))
-                + ((node_870) * (node_574)))
-                + ((node_872) * (node_583)))
-                + ((node_874) * (node_583)))
-                + ((node_876) * (node_583)))
-                + ((node_879) * (node_408)))
-                + ((node_881) * (node_429)))
-                + ((node_883) * (node_404)))
-                * (node_3965))
-                + ((node_565) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_405))
-                + ((node_757) * (node_433)))
-                + ((node_765) * (node_430)))
-                + ((node_770) * (node_495)))
-                + ((node_775)
-                    * ((node_493)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_837)))
+                + ((node_1057) * (node_672)))
+                + ((node_1061) * (node_837)))
+                + ((node_1065) * (node_843)))
+                + ((node_1070) * (node_837)))
+                + ((node_1074) * (node_673)))
+                + ((node_1077) * (node_834)))
+                + ((node_1080) * (node_697)))
+                + ((node_1084) * (node_672)))
+                + ((node_1087) * (node_866)))
+                + ((node_1090) * (node_861)))
+                + ((node_1093) * (node_822)))
+                + ((node_1095) * (node_837)))
+                + ((node_1097) * (node_837)))
+                + ((node_1099) * (node_866)))
+                + ((node_1102) * (node_674)))
+                + ((node_1105) * (node_674)))
+                + ((node_1107) * (node_837)))
+                + ((node_1110) * (node_672)))
+                + ((node_1117) * (node_702)))
+                + ((node_1122) * (node_675)))
+                + ((node_1126) * (node_675)))
+                + ((node_1130) * (node_675)))
+                + ((node_1133) * (node_840)))
+                + ((node_1136) * (node_675)))
+                + ((node_1139) * (node_840)))
+                + ((node_1142) * (node_840)))
+                + ((node_1144) * (node_849)))
+                + ((node_1146) * (node_849)))
+                + ((node_1148) * (node_849)))
+                + ((node_1151) * (node_678)))
+                + ((node_1153) * (node_699)))
+                + ((node_1155) * (node_674)))
+                * (node_4237))
+                + ((node_831) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_675))
+                + ((node_1029) * (node_703)))
+                + ((node_1037) * (node_700)))
+                + ((node_1042) * (node_759)))
+                + ((node_1047)
+                    * ((node_757)
                         * ((next_base_row[34])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_574)))
-                + ((node_785) * (node_403)))
-                + ((node_789) * (node_574)))
-                + ((node_793) * (node_580)))
-                + ((node_798) * (node_574)))
-                + ((node_802) * (node_404)))
-                + ((node_805) * (node_571)))
-                + ((node_808) * (node_428)))
-                + ((node_812) * (node_403)))
-                + ((node_815) * (node_419)))
-                + ((node_818) * (node_595)))
-                + ((node_821) * (node_559)))
-                + ((node_823) * (node_574)))
-                + ((node_825) * (node_574)))
-                + ((node_827) * (node_419)))
-                + ((node_830) * (node_405)))
-                + ((node_833) * (node_405)))
-                + ((node_835) * (node_574)))
-                + ((node_838) * (node_403)))
-                + ((node_845) * (node_433)))
-                + ((node_850) * (node_406)))
-                + ((node_854) * (node_406)))
-                + ((node_858) * (node_406)))
-                + ((node_861) * (node_577)))
-                + ((node_864) * (node_406)))
-                + ((node_867) * (node_577)))
-                + ((node_870) * (node_577)))
-                + ((node_872) * (node_586)))
-                + ((node_874) * (node_586)))
-                + ((node_876) * (node_586)))
-                + ((node_879) * (node_409)))
-                + ((node_881) * (node_430)))
-                + ((node_883) * (node_405)))
-                * (node_3965))
-                + ((node_568) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_406))
-                + ((node_757) * (node_434)))
-                + ((node_765) * (node_431)))
-                + ((node_770) * (node_499)))
-                + ((node_775)
-                    * ((node_497)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_840)))
+                + ((node_1057) * (node_673)))
+                + ((node_1061) * (node_840)))
+                + ((node_1065) * (node_846)))
+                + ((node_1070) * (node_840)))
+                + ((node_1074) * (node_674)))
+                + ((node_1077) * (node_837)))
+                + ((node_1080) * (node_698)))
+                + ((node_1084) * (node_673)))
+                + ((node_1087) * (node_867)))
+                + ((node_1090) * (node_864)))
+                + ((node_1093) * (node_825)))
+                + ((node_1095) * (node_840)))
+                + ((node_1097) * (node_840)))
+                + ((node_1099) * (node_867)))
+                + ((node_1102) * (node_675)))
+                + ((node_1105) * (node_675)))
+                + ((node_1107) * (node_840)))
+                + ((node_1110) * (node_673)))
+                + ((node_1117) * (node_703)))
+                + ((node_1122) * (node_676)))
+                + ((node_1126) * (node_676)))
+                + ((node_1130) * (node_676)))
+                + ((node_1133) * (node_843)))
+                + ((node_1136) * (node_676)))
+                + ((node_1139) * (node_843)))
+                + ((node_1142) * (node_843)))
+                + ((node_1144) * (node_852)))
+                + ((node_1146) * (node_852)))
+                + ((node_1148) * (node_852)))
+                + ((node_1151) * (node_679)))
+                + ((node_1153) * (node_700)))
+                + ((node_1155) * (node_675)))
+                * (node_4237))
+                + ((node_834) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_676))
+                + ((node_1029) * (node_704)))
+                + ((node_1037) * (node_701)))
+                + ((node_1042) * (node_764)))
+                + ((node_1047)
+                    * ((node_762)
                         * ((next_base_row[35])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_577)))
-                + ((node_785) * (node_404)))
-                + ((node_789) * (node_577)))
-                + ((node_793) * (node_583)))
-                + ((node_798) * (node_577)))
-                + ((node_802) * (node_405)))
-                + ((node_805) * (node_574)))
-                + ((node_808) * (node_429)))
-                + ((node_812) * (node_404)))
-                + ((node_818) * (node_392)))
-                + ((node_821) * (node_562)))
-                + ((node_823) * (node_577)))
-                + ((node_825) * (node_577)))
-                + ((node_830) * (node_406)))
-                + ((node_833) * (node_406)))
-                + ((node_835) * (node_577)))
-                + ((node_838) * (node_404)))
-                + ((node_845) * (node_434)))
-                + ((node_850) * (node_407)))
-                + ((node_854) * (node_407)))
-                + ((node_858) * (node_407)))
-                + ((node_861) * (node_580)))
-                + ((node_864) * (node_407)))
-                + ((node_867) * (node_580)))
-                + ((node_870) * (node_580)))
-                + ((node_872) * (node_589)))
-                + ((node_874) * (node_589)))
-                + ((node_876) * (node_589)))
-                + ((node_879) * (node_410)))
-                + ((node_881) * (node_431)))
-                + ((node_883) * (node_406)))
-                * (node_3965))
-                + ((node_571) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_407))
-                + ((node_757) * (node_435)))
-                + ((node_765) * (node_432)))
-                + ((node_770) * (node_502)))
-                + ((node_775)
-                    * ((node_500)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_843)))
+                + ((node_1057) * (node_674)))
+                + ((node_1061) * (node_843)))
+                + ((node_1065) * (node_849)))
+                + ((node_1070) * (node_843)))
+                + ((node_1074) * (node_675)))
+                + ((node_1077) * (node_840)))
+                + ((node_1080) * (node_699)))
+                + ((node_1084) * (node_674)))
+                + ((node_1087) * (node_690)))
+                + ((node_1090) * (node_866)))
+                + ((node_1093) * (node_828)))
+                + ((node_1095) * (node_843)))
+                + ((node_1097) * (node_843)))
+                + ((node_1099) * (node_690)))
+                + ((node_1102) * (node_676)))
+                + ((node_1105) * (node_676)))
+                + ((node_1107) * (node_843)))
+                + ((node_1110) * (node_674)))
+                + ((node_1117) * (node_704)))
+                + ((node_1122) * (node_677)))
+                + ((node_1126) * (node_677)))
+                + ((node_1130) * (node_677)))
+                + ((node_1133) * (node_846)))
+                + ((node_1136) * (node_677)))
+                + ((node_1139) * (node_846)))
+                + ((node_1142) * (node_846)))
+                + ((node_1144) * (node_855)))
+                + ((node_1146) * (node_855)))
+                + ((node_1148) * (node_855)))
+                + ((node_1151) * (node_680)))
+                + ((node_1153) * (node_701)))
+                + ((node_1155) * (node_676)))
+                * (node_4237))
+                + ((node_837) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_677))
+                + ((node_1029) * (node_705)))
+                + ((node_1037) * (node_702)))
+                + ((node_1042) * (node_767)))
+                + ((node_1047)
+                    * ((node_765)
                         * ((next_base_row[36])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_580)))
-                + ((node_785) * (node_405)))
-                + ((node_789) * (node_580)))
-                + ((node_793) * (node_586)))
-                + ((node_798) * (node_580)))
-                + ((node_802) * (node_406)))
-                + ((node_805) * (node_577)))
-                + ((node_808) * (node_430)))
-                + ((node_812) * (node_405)))
-                + ((node_818) * (node_393)))
-                + ((node_821) * (node_565)))
-                + ((node_823) * (node_580)))
-                + ((node_825) * (node_580)))
-                + ((node_830) * (node_407)))
-                + ((node_833) * (node_407)))
-                + ((node_835) * (node_580)))
-                + ((node_838) * (node_405)))
-                + ((node_845) * (node_435)))
-                + ((node_850) * (node_408)))
-                + ((node_854) * (node_408)))
-                + ((node_858) * (node_408)))
-                + ((node_861) * (node_583)))
-                + ((node_864) * (node_408)))
-                + ((node_867) * (node_583)))
-                + ((node_870) * (node_583)))
-                + ((node_872) * (node_592)))
-                + ((node_874) * (node_592)))
-                + ((node_876) * (node_592)))
-                + ((node_879) * (node_411)))
-                + ((node_881) * (node_432)))
-                + ((node_883) * (node_407)))
-                * (node_3965))
-                + ((node_574) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_408))
-                + ((node_757) * (node_436)))
-                + ((node_765) * (node_433)))
-                + ((node_770) * (node_504)))
-                + ((node_775) * (node_450)))
-                + ((node_780) * (node_583)))
-                + ((node_785) * (node_406)))
-                + ((node_789) * (node_583)))
-                + ((node_793) * (node_589)))
-                + ((node_798) * (node_583)))
-                + ((node_802) * (node_407)))
-                + ((node_805) * (node_580)))
-                + ((node_808) * (node_431)))
-                + ((node_812) * (node_406)))
-                + ((node_818) * (node_394)))
-                + ((node_821) * (node_568)))
-                + ((node_823) * (node_583)))
-                + ((node_825) * (node_583)))
-                + ((node_830) * (node_408)))
-                + ((node_833) * (node_408)))
-                + ((node_835) * (node_583)))
-                + ((node_838) * (node_406)))
-                + ((node_845) * (node_436)))
-                + ((node_850) * (node_409)))
-                + ((node_854) * (node_409)))
-                + ((node_858) * (node_409)))
-                + ((node_861) * (node_586)))
-                + ((node_864) * (node_409)))
-                + ((node_867) * (node_586)))
-                + ((node_870) * (node_586)))
-                + ((node_872) * (node_594)))
-                + ((node_874) * (node_594)))
-                + ((node_876) * (node_594)))
-                + ((node_879) * (node_413)))
-                + ((node_881) * (node_433)))
-                + ((node_883) * (node_408)))
-                * (node_3965))
-                + ((node_577) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_409))
-                + ((node_757) * (node_438)))
-                + ((node_765) * (node_434)))
-                + ((node_770) * (node_506)))
-                + ((node_775) * (node_454)))
-                + ((node_780) * (node_586)))
-                + ((node_785) * (node_407)))
-                + ((node_789) * (node_586)))
-                + ((node_793) * (node_592)))
-                + ((node_798) * (node_586)))
-                + ((node_802) * (node_408)))
-                + ((node_805) * (node_583)))
-                + ((node_808) * (node_432)))
-                + ((node_812) * (node_407)))
-                + ((node_818) * (node_395)))
-                + ((node_821) * (node_571)))
-                + ((node_823) * (node_586)))
-                + ((node_825) * (node_586)))
-                + ((node_830) * (node_409)))
-                + ((node_833) * (node_409)))
-                + ((node_835) * (node_586)))
-                + ((node_838) * (node_407)))
-                + ((node_845) * (node_438)))
-                + ((node_850) * (node_410)))
-                + ((node_854) * (node_410)))
-                + ((node_858) * (node_410)))
-                + ((node_861) * (node_589)))
-                + ((node_864) * (node_410)))
-                + ((node_867) * (node_589)))
-                + ((node_870) * (node_589)))
-                + ((node_872) * (node_595)))
-                + ((node_874) * (node_595)))
-                + ((node_876) * (node_595)))
-                + ((node_879) * (node_417)))
-                + ((node_881) * (node_434)))
-                + ((node_883) * (node_409)))
-                * (node_3965))
-                + ((node_580) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_410))
-                + ((node_757) * (node_439)))
-                + ((node_765) * (node_435)))
-                + ((node_770) * (node_508)))
-                + ((node_775) * (node_457)))
-                + ((node_780) * (node_589)))
-                + ((node_785) * (node_408)))
-                + ((node_789) * (node_589)))
-                + ((node_793) * (node_594)))
-                + ((node_798) * (node_589)))
-                + ((node_802) * (node_409)))
-                + ((node_805) * (node_586)))
-                + ((node_808) * (node_433)))
-                + ((node_812) * (node_408)))
-                + ((node_818) * (node_418)))
-                + ((node_821) * (node_574)))
-                + ((node_823) * (node_589)))
-                + ((node_825) * (node_589)))
-                + ((node_830) * (node_410)))
-                + ((node_833) * (node_410)))
-                + ((node_835) * (node_589)))
-                + ((node_838) * (node_408)))
-                + ((node_845) * (node_392)))
-                + ((node_850) * (node_411)))
-                + ((node_854) * (node_411)))
-                + ((node_858) * (node_411)))
-                + ((node_861) * (node_592)))
-                + ((node_864) * (node_411)))
-                + ((node_867) * (node_592)))
-                + ((node_870) * (node_592)))
-                + ((node_872) * (node_392)))
-                + ((node_874) * (node_392)))
-                + ((node_876) * (node_392)))
-                + ((node_879) * (node_392)))
-                + ((node_881) * (node_435)))
-                + ((node_883) * (node_410)))
-                * (node_3965))
-                + ((node_583) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_411))
-                + ((node_757) * (node_393)))
-                + ((node_765) * (node_436)))
-                + ((node_770) * (node_510)))
-                + ((node_775) * (node_462)))
-                + ((node_780) * (node_592)))
-                + ((node_785) * (node_409)))
-                + ((node_789) * (node_592)))
-                + ((node_793) * (node_595)))
-                + ((node_798) * (node_592)))
-                + ((node_802) * (node_410)))
-                + ((node_805) * (node_589)))
-                + ((node_808) * (node_434)))
-                + ((node_812) * (node_409)))
-                + ((node_818) * (node_419)))
-                + ((node_821) * (node_577)))
-                + ((node_823) * (node_592)))
-                + ((node_825) * (node_592)))
-                + ((node_830) * (node_411)))
-                + ((node_833) * (node_411)))
-                + ((node_835) * (node_592)))
-                + ((node_838) * (node_409)))
-                + ((node_845) * (node_393)))
-                + ((node_850) * (node_413)))
-                + ((node_854) * (node_413)))
-                + ((node_858) * (node_413)))
-                + ((node_861) * (node_594)))
-                + ((node_864) * (node_413)))
-                + ((node_867) * (node_594)))
-                + ((node_870) * (node_594)))
-                + ((node_872) * (node_393)))
-                + ((node_874) * (node_393)))
-                + ((node_876) * (node_393)))
-                + ((node_879) * (node_393)))
-                + ((node_881) * (node_436)))
-                + ((node_883) * (node_411)))
-                * (node_3965))
-                + ((node_586) * (next_base_row[5])),
-            (((((((((((((((((((((((((((((((((((((node_747)
-                * (node_413))
-                + ((node_757) * (node_394)))
-                + ((node_765) * (node_438)))
-                + ((node_770) * (node_519)))
-                + ((node_775) * (node_465)))
-                + ((node_780) * (node_594)))
-                + ((node_785) * (node_410)))
-                + ((node_789) * (node_594)))
-                + ((node_793) * (node_418)))
-                + ((node_798) * (node_594)))
-                + ((node_802) * (node_411)))
-                + ((node_805) * (node_592)))
-                + ((node_808) * (node_435)))
-                + ((node_812) * (node_410)))
-                + ((node_821) * (node_580)))
-                + ((node_823) * (node_594)))
-                + ((node_825) * (node_594)))
-                + ((node_830) * (node_413)))
-                + ((node_833) * (node_413)))
-                + ((node_835) * (node_594)))
-                + ((node_838) * (node_410)))
-                + ((node_845) * (node_394)))
-                + ((node_850) * (node_417)))
-                + ((node_854) * (node_417)))
-                + ((node_858) * (node_417)))
-                + ((node_861) * (node_595)))
-                + ((node_864) * (node_417)))
-                + ((node_867) * (node_595)))
-                + ((node_870) * (node_595)))
-                + ((node_872) * (node_394)))
-                + ((node_874) * (node_394)))
-                + ((node_876) * (node_394)))
-                + ((node_879) * (node_394)))
-                + ((node_881) * (node_438)))
-                + ((node_883) * (node_413)))
-                * (node_3965))
-                + ((node_589) * (next_base_row[5])),
-            (((((((((((((((((((((((((((((((((((((node_747)
-                * (node_417))
-                + ((node_757) * (node_395)))
-                + ((node_765) * (node_418)))
-                + ((node_770) * (node_439)))
-                + ((node_775) * (node_469)))
-                + ((node_780) * (node_595)))
-                + ((node_785) * (node_411)))
-                + ((node_789) * (node_595)))
-                + ((node_793) * (node_419)))
-                + ((node_798) * (node_595)))
-                + ((node_802) * (node_413)))
-                + ((node_805) * (node_594)))
-                + ((node_808) * (node_436)))
-                + ((node_812) * (node_411)))
-                + ((node_821) * (node_583)))
-                + ((node_823) * (node_595)))
-                + ((node_825) * (node_595)))
-                + ((node_830) * (node_417)))
-                + ((node_833) * (node_417)))
-                + ((node_835) * (node_595)))
-                + ((node_838) * (node_411)))
-                + ((node_845) * (node_395)))
-                + ((node_850) * (node_418)))
-                + ((node_854) * (node_418)))
-                + ((node_858) * (node_418)))
-                + ((node_861) * (node_418)))
-                + ((node_864) * (node_418)))
-                + ((node_867) * (node_418)))
-                + ((node_870) * (node_418)))
-                + ((node_872) * (node_395)))
-                + ((node_874) * (node_395)))
-                + ((node_876) * (node_395)))
-                + ((node_879) * (node_395)))
-                + ((node_881) * (node_418)))
-                + ((node_883) * (node_417)))
-                * (node_3965))
-                + ((node_592) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((node_747)
-                * (node_418))
-                + ((node_757) * (node_418)))
-                + ((node_765) * (node_419)))
-                + ((node_770) * (node_393)))
-                + ((node_775) * (node_472)))
-                + ((node_780) * (node_418)))
-                + ((node_785) * (node_413)))
-                + ((node_789) * (node_418)))
-                + ((node_798) * (node_418)))
-                + ((node_802) * (node_417)))
-                + ((node_805) * (node_595)))
-                + ((node_808) * (node_438)))
-                + ((node_812) * (node_413)))
-                + ((node_821) * (node_586)))
-                + ((node_823) * (node_418)))
-                + ((node_825) * (node_418)))
-                + ((node_830) * (node_418)))
-                + ((node_833) * (node_418)))
-                + ((node_835) * (node_418)))
-                + ((node_838) * (node_413)))
-                + ((node_845) * (node_418)))
-                + ((node_850) * (node_419)))
-                + ((node_854) * (node_419)))
-                + ((node_858) * (node_419)))
-                + ((node_861) * (node_419)))
-                + ((node_864) * (node_419)))
-                + ((node_867) * (node_419)))
-                + ((node_870) * (node_419)))
-                + ((node_872) * (node_418)))
-                + ((node_874) * (node_418)))
-                + ((node_876) * (node_418)))
-                + ((node_879) * (node_418)))
-                + ((node_881) * (node_419)))
-                + ((node_883) * (node_418)))
-                * (node_3965))
-                + ((node_594) * (next_base_row[5])),
-            ((((((((((((((((((((((((((node_747) * (node_419))
-                + ((node_757) * (node_419)))
-                + ((node_770) * (node_394)))
-                + ((node_775) * (node_477)))
-                + ((node_780) * (node_419)))
-                + ((node_785) * (node_417)))
-                + ((node_789) * (node_419)))
-                + ((node_798) * (node_419)))
-                + ((node_802) * (node_418)))
-                + ((node_805) * (node_418)))
-                + ((node_812) * (node_417)))
-                + ((node_821) * (node_589)))
-                + ((node_823) * (node_419)))
-                + ((node_825) * (node_419)))
-                + ((node_830) * (node_419)))
-                + ((node_833) * (node_419)))
-                + ((node_835) * (node_419)))
-                + ((node_838) * (node_417)))
-                + ((node_845) * (node_419)))
-                + ((node_872) * (node_419)))
-                + ((node_874) * (node_419)))
-                + ((node_876) * (node_419)))
-                + ((node_879) * (node_419)))
-                + ((node_883) * (node_419)))
-                * (node_3965))
-                + ((node_595) * (next_base_row[5])),
-            (((((((((node_770) * (node_395)) + ((node_775) * (node_480)))
-                + ((node_785) * (node_418)))
-                + ((node_802) * (node_419)))
-                + ((node_805) * (node_419)))
-                + ((node_821) * (node_592)))
-                + ((node_838) * (node_418)))
-                * (node_3965))
-                + ((node_418) * (next_base_row[5])),
-            (((((((node_770) * (node_421)) + ((node_775) * (node_484)))
-                + ((node_785) * (node_419)))
-                + ((node_821) * (node_594)))
-                + ((node_838) * (node_419)))
-                * (node_3965))
-                + ((node_419) * (next_base_row[5])),
-            ((((node_770) * (node_422)) + ((node_775) * (node_487))) + ((node_821) * (node_595)))
-                * (node_3965),
-            ((((node_770) * (node_423)) + ((node_775) * (node_492))) + ((node_821) * (node_418)))
-                * (node_3965),
-            ((((node_770) * (node_424)) + ((node_775) * (node_495))) + ((node_821) * (node_419)))
-                * (node_3965),
-            (((node_770) * (node_425)) + ((node_775) * (node_499))) * (node_3965),
-            (((node_770) * (node_426)) + ((node_775) * (node_502))) * (node_3965),
-            (((node_770) * (node_427))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_449)) * (node_550))))
-                * (node_3965),
-            (((node_770) * (node_428))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_452)) * (node_553))))
-                * (node_3965),
-            (((node_770) * (node_429))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_455)) * (node_556))))
-                * (node_3965),
-            (((node_770) * (node_430))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_460)) * (node_559))))
-                * (node_3965),
-            (((node_770) * (node_431))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_463)) * (node_562))))
-                * (node_3965),
-            (((node_770) * (node_432))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_467)) * (node_565))))
-                * (node_3965),
-            (((node_770) * (node_433))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_470)) * (node_568))))
-                * (node_3965),
-            (((node_770) * (node_434))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_475)) * (node_571))))
-                * (node_3965),
-            (((node_770) * (node_435))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_478)) * (node_574))))
-                * (node_3965),
-            (((node_770) * (node_436))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_482)) * (node_577))))
-                * (node_3965),
-            (((node_770) * (node_438))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_485)) * (node_580))))
-                * (node_3965),
-            (((node_770) * (node_418))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_490)) * (node_583))))
-                * (node_3965),
-            (((node_770) * (node_419))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_493)) * (node_586))))
-                * (node_3965),
-            ((node_775) * (((BFieldElement::new(1)) - (node_497)) * (node_589))) * (node_3965),
-            ((node_775) * (((BFieldElement::new(1)) - (node_500)) * (node_592))) * (node_3965),
-            ((node_775) * (node_594)) * (node_3965),
-            ((node_775) * (node_595)) * (node_3965),
-            ((node_775) * (node_504)) * (node_3965),
-            ((node_775) * (node_506)) * (node_3965),
-            ((node_775) * (node_508)) * (node_3965),
-            ((node_775) * (node_510)) * (node_3965),
-            ((node_775) * (node_519)) * (node_3965),
-            ((node_775) * (node_439)) * (node_3965),
-            ((node_775) * (node_393)) * (node_3965),
-            ((node_775) * (node_394)) * (node_3965),
-            ((node_775) * (node_395)) * (node_3965),
-            ((node_775) * (node_418)) * (node_3965),
-            ((node_775) * (node_419)) * (node_3965),
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_846)))
+                + ((node_1057) * (node_675)))
+                + ((node_1061) * (node_846)))
+                + ((node_1065) * (node_852)))
+                + ((node_1070) * (node_846)))
+                + ((node_1074) * (node_676)))
+                + ((node_1077) * (node_843)))
+                + ((node_1080) * (node_700)))
+                + ((node_1084) * (node_675)))
+                + ((node_1087) * (node_691)))
+                + ((node_1090) * (node_867)))
+                + ((node_1093) * (node_831)))
+                + ((node_1095) * (node_846)))
+                + ((node_1097) * (node_846)))
+                + ((node_1099) * (node_691)))
+                + ((node_1102) * (node_677)))
+                + ((node_1105) * (node_677)))
+                + ((node_1107) * (node_846)))
+                + ((node_1110) * (node_675)))
+                + ((node_1117) * (node_705)))
+                + ((node_1122) * (node_678)))
+                + ((node_1126) * (node_678)))
+                + ((node_1130) * (node_678)))
+                + ((node_1133) * (node_849)))
+                + ((node_1136) * (node_678)))
+                + ((node_1139) * (node_849)))
+                + ((node_1142) * (node_849)))
+                + ((node_1144) * (node_858)))
+                + ((node_1146) * (node_858)))
+                + ((node_1148) * (node_858)))
+                + ((node_1151) * (node_681)))
+                + ((node_1153) * (node_702)))
+                + ((node_1155) * (node_677)))
+                * (node_4237))
+                + ((node_840) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_678))
+                + ((node_1029) * (node_706)))
+                + ((node_1037) * (node_703)))
+                + ((node_1042) * (node_771)))
+                + ((node_1047)
+                    * ((node_769)
+                        * ((next_base_row[37])
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_849)))
+                + ((node_1057) * (node_676)))
+                + ((node_1061) * (node_849)))
+                + ((node_1065) * (node_855)))
+                + ((node_1070) * (node_849)))
+                + ((node_1074) * (node_677)))
+                + ((node_1077) * (node_846)))
+                + ((node_1080) * (node_701)))
+                + ((node_1084) * (node_676)))
+                + ((node_1090) * (node_664)))
+                + ((node_1093) * (node_834)))
+                + ((node_1095) * (node_849)))
+                + ((node_1097) * (node_849)))
+                + ((node_1102) * (node_678)))
+                + ((node_1105) * (node_678)))
+                + ((node_1107) * (node_849)))
+                + ((node_1110) * (node_676)))
+                + ((node_1117) * (node_706)))
+                + ((node_1122) * (node_679)))
+                + ((node_1126) * (node_679)))
+                + ((node_1130) * (node_679)))
+                + ((node_1133) * (node_852)))
+                + ((node_1136) * (node_679)))
+                + ((node_1139) * (node_852)))
+                + ((node_1142) * (node_852)))
+                + ((node_1144) * (node_861)))
+                + ((node_1146) * (node_861)))
+                + ((node_1148) * (node_861)))
+                + ((node_1151) * (node_682)))
+                + ((node_1153) * (node_703)))
+                + ((node_1155) * (node_678)))
+                * (node_4237))
+                + ((node_843) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_679))
+                + ((node_1029) * (node_707)))
+                + ((node_1037) * (node_704)))
+                + ((node_1042) * (node_774)))
+                + ((node_1047)
+                    * ((node_772)
+                        * ((next_base_row[38])
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_852)))
+                + ((node_1057) * (node_677)))
+                + ((node_1061) * (node_852)))
+                + ((node_1065) * (node_858)))
+                + ((node_1070) * (node_852)))
+                + ((node_1074) * (node_678)))
+                + ((node_1077) * (node_849)))
+                + ((node_1080) * (node_702)))
+                + ((node_1084) * (node_677)))
+                + ((node_1090) * (node_665)))
+                + ((node_1093) * (node_837)))
+                + ((node_1095) * (node_852)))
+                + ((node_1097) * (node_852)))
+                + ((node_1102) * (node_679)))
+                + ((node_1105) * (node_679)))
+                + ((node_1107) * (node_852)))
+                + ((node_1110) * (node_677)))
+                + ((node_1117) * (node_707)))
+                + ((node_1122) * (node_680)))
+                + ((node_1126) * (node_680)))
+                + ((node_1130) * (node_680)))
+                + ((node_1133) * (node_855)))
+                + ((node_1136) * (node_680)))
+                + ((node_1139) * (node_855)))
+                + ((node_1142) * (node_855)))
+                + ((node_1144) * (node_864)))
+                + ((node_1146) * (node_864)))
+                + ((node_1148) * (node_864)))
+                + ((node_1151) * (node_683)))
+                + ((node_1153) * (node_704)))
+                + ((node_1155) * (node_679)))
+                * (node_4237))
+                + ((node_846) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_680))
+                + ((node_1029) * (node_708)))
+                + ((node_1037) * (node_705)))
+                + ((node_1042) * (node_776)))
+                + ((node_1047) * (node_722)))
+                + ((node_1052) * (node_855)))
+                + ((node_1057) * (node_678)))
+                + ((node_1061) * (node_855)))
+                + ((node_1065) * (node_861)))
+                + ((node_1070) * (node_855)))
+                + ((node_1074) * (node_679)))
+                + ((node_1077) * (node_852)))
+                + ((node_1080) * (node_703)))
+                + ((node_1084) * (node_678)))
+                + ((node_1090) * (node_666)))
+                + ((node_1093) * (node_840)))
+                + ((node_1095) * (node_855)))
+                + ((node_1097) * (node_855)))
+                + ((node_1102) * (node_680)))
+                + ((node_1105) * (node_680)))
+                + ((node_1107) * (node_855)))
+                + ((node_1110) * (node_678)))
+                + ((node_1117) * (node_708)))
+                + ((node_1122) * (node_681)))
+                + ((node_1126) * (node_681)))
+                + ((node_1130) * (node_681)))
+                + ((node_1133) * (node_858)))
+                + ((node_1136) * (node_681)))
+                + ((node_1139) * (node_858)))
+                + ((node_1142) * (node_858)))
+                + ((node_1144) * (node_866)))
+                + ((node_1146) * (node_866)))
+                + ((node_1148) * (node_866)))
+                + ((node_1151) * (node_685)))
+                + ((node_1153) * (node_705)))
+                + ((node_1155) * (node_680)))
+                * (node_4237))
+                + ((node_849) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_681))
+                + ((node_1029) * (node_710)))
+                + ((node_1037) * (node_706)))
+                + ((node_1042) * (node_778)))
+                + ((node_1047) * (node_726)))
+                + ((node_1052) * (node_858)))
+                + ((node_1057) * (node_679)))
+                + ((node_1061) * (node_858)))
+                + ((node_1065) * (node_864)))
+                + ((node_1070) * (node_858)))
+                + ((node_1074) * (node_680)))
+                + ((node_1077) * (node_855)))
+                + ((node_1080) * (node_704)))
+                + ((node_1084) * (node_679)))
+                + ((node_1090) * (node_667)))
+                + ((node_1093) * (node_843)))
+                + ((node_1095) * (node_858)))
+                + ((node_1097) * (node_858)))
+                + ((node_1102) * (node_681)))
+                + ((node_1105) * (node_681)))
+                + ((node_1107) * (node_858)))
+                + ((node_1110) * (node_679)))
+                + ((node_1117) * (node_710)))
+                + ((node_1122) * (node_682)))
+                + ((node_1126) * (node_682)))
+                + ((node_1130) * (node_682)))
+                + ((node_1133) * (node_861)))
+                + ((node_1136) * (node_682)))
+                + ((node_1139) * (node_861)))
+                + ((node_1142) * (node_861)))
+                + ((node_1144) * (node_867)))
+                + ((node_1146) * (node_867)))
+                + ((node_1148) * (node_867)))
+                + ((node_1151) * (node_689)))
+                + ((node_1153) * (node_706)))
+                + ((node_1155) * (node_681)))
+                * (node_4237))
+                + ((node_852) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_682))
+                + ((node_1029) * (node_711)))
+                + ((node_1037) * (node_707)))
+                + ((node_1042) * (node_780)))
+                + ((node_1047) * (node_729)))
+                + ((node_1052) * (node_861)))
+                + ((node_1057) * (node_680)))
+                + ((node_1061) * (node_861)))
+                + ((node_1065) * (node_866)))
+                + ((node_1070) * (node_861)))
+                + ((node_1074) * (node_681)))
+                + ((node_1077) * (node_858)))
+                + ((node_1080) * (node_705)))
+                + ((node_1084) * (node_680)))
+                + ((node_1090) * (node_690)))
+                + ((node_1093) * (node_846)))
+                + ((node_1095) * (node_861)))
+                + ((node_1097) * (node_861)))
+                + ((node_1102) * (node_682)))
+                + ((node_1105) * (node_682)))
+                + ((node_1107) * (node_861)))
+                + ((node_1110) * (node_680)))
+                + ((node_1117) * (node_664)))
+                + ((node_1122) * (node_683)))
+                + ((node_1126) * (node_683)))
+                + ((node_1130) * (node_683)))
+                + ((node_1133) * (node_864)))
+                + ((node_1136) * (node_683)))
+                + ((node_1139) * (node_864)))
+                + ((node_1142) * (node_864)))
+                + ((node_1144) * (node_664)))
+                + ((node_1146) * (node_664)))
+                + ((node_1148) * (node_664)))
+                + ((node_1151) * (node_664)))
+                + ((node_1153) * (node_707)))
+                + ((node_1155) * (node_682)))
+                * (node_4237))
+                + ((node_855) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_683))
+                + ((node_1029) * (node_665)))
+                + ((node_1037) * (node_708)))
+                + ((node_1042) * (node_782)))
+                + ((node_1047) * (node_734)))
+                + ((node_1052) * (node_864)))
+                + ((node_1057) * (node_681)))
+                + ((node_1061) * (node_864)))
+                + ((node_1065) * (node_867)))
+                + ((node_1070) * (node_864)))
+                + ((node_1074) * (node_682)))
+                + ((node_1077) * (node_861)))
+                + ((node_1080) * (node_706)))
+                + ((node_1084) * (node_681)))
+                + ((node_1090) * (node_691)))
+                + ((node_1093) * (node_849)))
+                + ((node_1095) * (node_864)))
+                + ((node_1097) * (node_864)))
+                + ((node_1102) * (node_683)))
+                + ((node_1105) * (node_683)))
+                + ((node_1107) * (node_864)))
+                + ((node_1110) * (node_681)))
+                + ((node_1117) * (node_665)))
+                + ((node_1122) * (node_685)))
+                + ((node_1126) * (node_685)))
+                + ((node_1130) * (node_685)))
+                + ((node_1133) * (node_866)))
+                + ((node_1136) * (node_685)))
+                + ((node_1139) * (node_866)))
+                + ((node_1142) * (node_866)))
+                + ((node_1144) * (node_665)))
+                + ((node_1146) * (node_665)))
+                + ((node_1148) * (node_665)))
+                + ((node_1151) * (node_665)))
+                + ((node_1153) * (node_708)))
+                + ((node_1155) * (node_683)))
+                * (node_4237))
+                + ((node_858) * (next_base_row[7])),
+            (((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_685))
+                + ((node_1029) * (node_666)))
+                + ((node_1037) * (node_710)))
+                + ((node_1042) * (node_791)))
+                + ((node_1047) * (node_737)))
+                + ((node_1052) * (node_866)))
+                + ((node_1057) * (node_682)))
+                + ((node_1061) * (node_866)))
+                + ((node_1065) * (node_690)))
+                + ((node_1070) * (node_866)))
+                + ((node_1074) * (node_683)))
+                + ((node_1077) * (node_864)))
+                + ((node_1080) * (node_707)))
+                + ((node_1084) * (node_682)))
+                + ((node_1093) * (node_852)))
+                + ((node_1095) * (node_866)))
+                + ((node_1097) * (node_866)))
+                + ((node_1102) * (node_685)))
+                + ((node_1105) * (node_685)))
+                + ((node_1107) * (node_866)))
+                + ((node_1110) * (node_682)))
+                + ((node_1117) * (node_666)))
+                + ((node_1122) * (node_689)))
+                + ((node_1126) * (node_689)))
+                + ((node_1130) * (node_689)))
+                + ((node_1133) * (node_867)))
+                + ((node_1136) * (node_689)))
+                + ((node_1139) * (node_867)))
+                + ((node_1142) * (node_867)))
+                + ((node_1144) * (node_666)))
+                + ((node_1146) * (node_666)))
+                + ((node_1148) * (node_666)))
+                + ((node_1151) * (node_666)))
+                + ((node_1153) * (node_710)))
+                + ((node_1155) * (node_685)))
+                * (node_4237))
+                + ((node_861) * (next_base_row[7])),
+            (((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_689))
+                + ((node_1029) * (node_667)))
+                + ((node_1037) * (node_690)))
+                + ((node_1042) * (node_711)))
+                + ((node_1047) * (node_741)))
+                + ((node_1052) * (node_867)))
+                + ((node_1057) * (node_683)))
+                + ((node_1061) * (node_867)))
+                + ((node_1065) * (node_691)))
+                + ((node_1070) * (node_867)))
+                + ((node_1074) * (node_685)))
+                + ((node_1077) * (node_866)))
+                + ((node_1080) * (node_708)))
+                + ((node_1084) * (node_683)))
+                + ((node_1093) * (node_855)))
+                + ((node_1095) * (node_867)))
+                + ((node_1097) * (node_867)))
+                + ((node_1102) * (node_689)))
+                + ((node_1105) * (node_689)))
+                + ((node_1107) * (node_867)))
+                + ((node_1110) * (node_683)))
+                + ((node_1117) * (node_667)))
+                + ((node_1122) * (node_690)))
+                + ((node_1126) * (node_690)))
+                + ((node_1130) * (node_690)))
+                + ((node_1133) * (node_690)))
+                + ((node_1136) * (node_690)))
+                + ((node_1139) * (node_690)))
+                + ((node_1142) * (node_690)))
+                + ((node_1144) * (node_667)))
+                + ((node_1146) * (node_667)))
+                + ((node_1148) * (node_667)))
+                + ((node_1151) * (node_667)))
+                + ((node_1153) * (node_690)))
+                + ((node_1155) * (node_689)))
+                * (node_4237))
+                + ((node_864) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_690))
+                + ((node_1029) * (node_690)))
+                + ((node_1037) * (node_691)))
+                + ((node_1042) * (node_665)))
+                + ((node_1047) * (node_744)))
+                + ((node_1052) * (node_690)))
+                + ((node_1057) * (node_685)))
+                + ((node_1061) * (node_690)))
+                + ((node_1070) * (node_690)))
+                + ((node_1074) * (node_689)))
+                + ((node_1077) * (node_867)))
+                + ((node_1080) * (node_710)))
+                + ((node_1084) * (node_685)))
+                + ((node_1093) * (node_858)))
+                + ((node_1095) * (node_690)))
+                + ((node_1097) * (node_690)))
+                + ((node_1102) * (node_690)))
+                + ((node_1105) * (node_690)))
+                + ((node_1107) * (node_690)))
+                + ((node_1110) * (node_685)))
+                + ((node_1117) * (node_690)))
+                + ((node_1122) * (node_691)))
+                + ((node_1126) * (node_691)))
+                + ((node_1130) * (node_691)))
+                + ((node_1133) * (node_691)))
+                + ((node_1136) * (node_691)))
+                + ((node_1139) * (node_691)))
+                + ((node_1142) * (node_691)))
+                + ((node_1144) * (node_690)))
+                + ((node_1146) * (node_690)))
+                + ((node_1148) * (node_690)))
+                + ((node_1151) * (node_690)))
+                + ((node_1153) * (node_691)))
+                + ((node_1155) * (node_690)))
+                * (node_4237))
+                + ((node_866) * (next_base_row[7])),
+            ((((((((((((((((((((((((((node_1019) * (node_691))
+                + ((node_1029) * (node_691)))
+                + ((node_1042) * (node_666)))
+                + ((node_1047) * (node_749)))
+                + ((node_1052) * (node_691)))
+                + ((node_1057) * (node_689)))
+                + ((node_1061) * (node_691)))
+                + ((node_1070) * (node_691)))
+                + ((node_1074) * (node_690)))
+                + ((node_1077) * (node_690)))
+                + ((node_1084) * (node_689)))
+                + ((node_1093) * (node_861)))
+                + ((node_1095) * (node_691)))
+                + ((node_1097) * (node_691)))
+                + ((node_1102) * (node_691)))
+                + ((node_1105) * (node_691)))
+                + ((node_1107) * (node_691)))
+                + ((node_1110) * (node_689)))
+                + ((node_1117) * (node_691)))
+                + ((node_1144) * (node_691)))
+                + ((node_1146) * (node_691)))
+                + ((node_1148) * (node_691)))
+                + ((node_1151) * (node_691)))
+                + ((node_1155) * (node_691)))
+                * (node_4237))
+                + ((node_867) * (next_base_row[7])),
+            (((((((((node_1042) * (node_667)) + ((node_1047) * (node_752)))
+                + ((node_1057) * (node_690)))
+                + ((node_1074) * (node_691)))
+                + ((node_1077) * (node_691)))
+                + ((node_1093) * (node_864)))
+                + ((node_1110) * (node_690)))
+                * (node_4237))
+                + ((node_690) * (next_base_row[7])),
+            (((((((node_1042) * (node_693)) + ((node_1047) * (node_756)))
+                + ((node_1057) * (node_691)))
+                + ((node_1093) * (node_866)))
+                + ((node_1110) * (node_691)))
+                * (node_4237))
+                + ((node_691) * (next_base_row[7])),
+            ((((node_1042) * (node_694)) + ((node_1047) * (node_759)))
+                + ((node_1093) * (node_867)))
+                * (node_4237),
+            ((((node_1042) * (node_695)) + ((node_1047) * (node_764)))
+                + ((node_1093) * (node_690)))
+                * (node_4237),
+            ((((node_1042) * (node_696)) + ((node_1047) * (node_767)))
+                + ((node_1093) * (node_691)))
+                * (node_4237),
+            (((node_1042) * (node_697)) + ((node_1047) * (node_771))) * (node_4237),
+            (((node_1042) * (node_698)) + ((node_1047) * (node_774))) * (node_4237),
+            (((node_1042) * (node_699))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_721)) * (node_822))))
+                * (node_4237),
+            (((node_1042) * (node_700))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_724)) * (node_825))))
+                * (node_4237),
+            (((node_1042) * (node_701))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_727)) * (node_828))))
+                * (node_4237),
+            (((node_1042) * (node_702))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_732)) * (node_831))))
+                * (node_4237),
+            (((node_1042) * (node_703))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_735)) * (node_834))))
+                * (node_4237),
+            (((node_1042) * (node_704))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_739)) * (node_837))))
+                * (node_4237),
+            (((node_1042) * (node_705))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_742)) * (node_840))))
+                * (node_4237),
+            (((node_1042) * (node_706))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_747)) * (node_843))))
+                * (node_4237),
+            (((node_1042) * (node_707))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_750)) * (node_846))))
+                * (node_4237),
+            (((node_1042) * (node_708))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_754)) * (node_849))))
+                * (node_4237),
+            (((node_1042) * (node_710))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_757)) * (node_852))))
+                * (node_4237),
+            (((node_1042) * (node_690))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_762)) * (node_855))))
+                * (node_4237),
+            (((node_1042) * (node_691))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_765)) * (node_858))))
+                * (node_4237),
+            ((node_1047) * (((BFieldElement::new(1)) - (node_769)) * (node_861))) * (node_4237),
+            ((node_1047) * (((BFieldElement::new(1)) - (node_772)) * (node_864))) * (node_4237),
+            ((node_1047) * (node_866)) * (node_4237),
+            ((node_1047) * (node_867)) * (node_4237),
+            ((node_1047) * (node_776)) * (node_4237),
+            ((node_1047) * (node_778)) * (node_4237),
+            ((node_1047) * (node_780)) * (node_4237),
+            ((node_1047) * (node_782)) * (node_4237),
+            ((node_1047) * (node_791)) * (node_4237),
+            ((node_1047) * (node_711)) * (node_4237),
+            ((node_1047) * (node_665)) * (node_4237),
+            ((node_1047) * (node_666)) * (node_4237),
+            ((node_1047) * (node_667)) * (node_4237),
+            ((node_1047) * (node_690)) * (node_4237),
+            ((node_1047) * (node_691)) * (node_4237),
         ];
         let ext_constraints = [
-            (((next_ext_row[11]) - (current_ext_row[11]))
+            (((next_ext_row[12]) - (current_ext_row[12]))
                 * ((challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate))
-                    - (next_base_row[4])))
-                - (next_base_row[45]),
-            (((current_base_row[8]) - (BFieldElement::new(128)))
-                * ((next_ext_row[1]) - (current_ext_row[1])))
-                + ((node_881)
-                    * (((next_ext_row[1])
+                    - (next_base_row[6])))
+                - (next_base_row[47]),
+            (((current_base_row[10]) - (BFieldElement::new(128)))
+                * ((next_ext_row[2]) - (current_ext_row[2])))
+                + ((node_1153)
+                    * (((next_ext_row[2])
                         - ((challenges.get_challenge(StandardInputIndeterminate))
-                            * (current_ext_row[1])))
-                        - (next_base_row[21]))),
-            ((node_3965)
-                * (((node_4141)
+                            * (current_ext_row[2])))
+                        - (next_base_row[23]))),
+            ((node_4237)
+                * (((node_4413)
                     * ((challenges.get_challenge(InstructionLookupIndeterminate))
                         - ((((challenges.get_challenge(ProgramAddressWeight))
-                            * (next_base_row[7]))
+                            * (next_base_row[9]))
                             + ((challenges.get_challenge(ProgramInstructionWeight))
-                                * (next_base_row[8])))
+                                * (next_base_row[10])))
                             + ((challenges.get_challenge(ProgramNextInstructionWeight))
-                                * (next_base_row[9])))))
+                                * (next_base_row[11])))))
                     - (BFieldElement::new(1))))
-                + ((next_base_row[5]) * (node_4141)),
-            (((next_base_row[8]) - (BFieldElement::new(66)))
-                * ((next_ext_row[2]) - (current_ext_row[2])))
-                + (((((((((node_4149) * (next_base_row[11])) * (node_4153)) * (node_4155))
-                    * ((next_base_row[14]) - (BFieldElement::new(1))))
-                    * ((next_base_row[15]) - (BFieldElement::new(1))))
-                    * (next_base_row[16]))
-                    * (node_4163))
-                    * (((next_ext_row[2])
+                + ((next_base_row[7]) * (node_4413)),
+            (((next_base_row[10]) - (BFieldElement::new(66)))
+                * ((next_ext_row[3]) - (current_ext_row[3])))
+                + (((((((((node_4421) * (next_base_row[13])) * (node_4425)) * (node_4427))
+                    * ((next_base_row[16]) - (BFieldElement::new(1))))
+                    * ((next_base_row[17]) - (BFieldElement::new(1))))
+                    * (next_base_row[18]))
+                    * (node_4435))
+                    * (((next_ext_row[3])
                         - ((challenges.get_challenge(StandardOutputIndeterminate))
-                            * (current_ext_row[2])))
-                        - (next_base_row[21]))),
-            (next_ext_row[4])
-                - ((current_ext_row[4])
+                            * (current_ext_row[3])))
+                        - (next_base_row[23]))),
+            (next_ext_row[5])
+                - ((current_ext_row[5])
                     * ((challenges.get_challenge(OpStackIndeterminate))
                         - (((((challenges.get_challenge(OpStackClkWeight))
-                            * (next_base_row[4]))
+                            * (next_base_row[6]))
                             + ((challenges.get_challenge(OpStackIb1Weight))
-                                * (next_base_row[11])))
+                                * (next_base_row[13])))
                             + ((challenges.get_challenge(OpStackOspWeight))
-                                * (next_base_row[37])))
+                                * (next_base_row[39])))
                             + ((challenges.get_challenge(OpStackOsvWeight))
-                                * (next_base_row[38]))))),
-            (next_ext_row[5])
-                - ((current_ext_row[5])
+                                * (next_base_row[40]))))),
+            (next_ext_row[6])
+                - ((current_ext_row[6])
                     * ((challenges.get_challenge(RamIndeterminate))
-                        - (((((challenges.get_challenge(RamClkWeight)) * (next_base_row[4]))
+                        - (((((challenges.get_challenge(RamClkWeight)) * (next_base_row[6]))
                             + ((challenges.get_challenge(RamRampWeight))
-                                * (next_base_row[43])))
-                            + ((challenges.get_challenge(RamRamvWeight)) * (next_base_row[44])))
+                                * (next_base_row[45])))
+                            + ((challenges.get_challenge(RamRamvWeight)) * (next_base_row[46])))
                             + ((challenges.get_challenge(RamPreviousInstructionWeight))
-                                * (next_base_row[6]))))),
-            (next_ext_row[6])
-                - ((current_ext_row[6])
+                                * (next_base_row[8]))))),
+            (next_ext_row[7])
+                - ((current_ext_row[7])
                     * ((challenges.get_challenge(JumpStackIndeterminate))
                         - ((((((challenges.get_challenge(JumpStackClkWeight))
-                            * (next_base_row[4]))
+                            * (next_base_row[6]))
                             + ((challenges.get_challenge(JumpStackCiWeight))
-                                * (next_base_row[8])))
+                                * (next_base_row[10])))
                             + ((challenges.get_challenge(JumpStackJspWeight))
-                                * (next_base_row[18])))
+                                * (next_base_row[20])))
                             + ((challenges.get_challenge(JumpStackJsoWeight))
-                                * (next_base_row[19])))
+                                * (next_base_row[21])))
                             + ((challenges.get_challenge(JumpStackJsdWeight))
-                                * (next_base_row[20]))))),
-            (((next_base_row[8]) - (BFieldElement::new(48)))
-                * ((next_ext_row[7]) - (current_ext_row[7])))
-                + (((((((((node_4149) * ((next_base_row[11]) - (BFieldElement::new(1))))
-                    * (node_4153))
-                    * (node_4155))
-                    * (next_base_row[14]))
-                    * (next_base_row[15]))
-                    * ((next_base_row[16]) - (BFieldElement::new(1))))
-                    * (node_4163))
-                    * (((next_ext_row[7])
-                        - ((challenges.get_challenge(HashInputIndeterminate))
-                            * (current_ext_row[7])))
-                        - (node_4264))),
-            (((current_base_row[8]) - (BFieldElement::new(48)))
+                                * (next_base_row[22]))))),
+            (((next_base_row[10]) - (BFieldElement::new(48)))
                 * ((next_ext_row[8]) - (current_ext_row[8])))
-                + ((node_815)
+                + (((((((((node_4421) * ((next_base_row[13]) - (BFieldElement::new(1))))
+                    * (node_4425))
+                    * (node_4427))
+                    * (next_base_row[16]))
+                    * (next_base_row[17]))
+                    * ((next_base_row[18]) - (BFieldElement::new(1))))
+                    * (node_4435))
                     * (((next_ext_row[8])
-                        - ((challenges.get_challenge(HashDigestIndeterminate))
+                        - ((challenges.get_challenge(HashInputIndeterminate))
                             * (current_ext_row[8])))
+                        - (node_4536))),
+            (((current_base_row[10]) - (BFieldElement::new(48)))
+                * ((next_ext_row[9]) - (current_ext_row[9])))
+                + ((node_1087)
+                    * (((next_ext_row[9])
+                        - ((challenges.get_challenge(HashDigestIndeterminate))
+                            * (current_ext_row[9])))
                         - ((((((challenges.get_challenge(HashStateWeight0))
-                            * (next_base_row[26]))
+                            * (next_base_row[28]))
                             + ((challenges.get_challenge(HashStateWeight1))
-                                * (next_base_row[27])))
+                                * (next_base_row[29])))
                             + ((challenges.get_challenge(HashStateWeight2))
-                                * (next_base_row[28])))
+                                * (next_base_row[30])))
                             + ((challenges.get_challenge(HashStateWeight3))
-                                * (next_base_row[29])))
+                                * (next_base_row[31])))
                             + ((challenges.get_challenge(HashStateWeight4))
-                                * (next_base_row[30]))))),
-            (((((((current_base_row[8]) - (BFieldElement::new(72)))
-                * ((current_base_row[8]) - (BFieldElement::new(80))))
-                * ((current_base_row[8]) - (BFieldElement::new(88))))
-                * ((next_ext_row[9]) - (current_ext_row[9])))
-                + ((node_823) * (node_4304)))
-                + ((node_825) * (node_4304)))
-                + ((node_827) * (node_4304)),
-            (((((((((node_845)
-                * (((node_4349) * (((node_4321) - (node_4322)) - (node_4324)))
+                                * (next_base_row[32]))))),
+            (((((((current_base_row[10]) - (BFieldElement::new(72)))
+                * ((current_base_row[10]) - (BFieldElement::new(80))))
+                * ((current_base_row[10]) - (BFieldElement::new(88))))
+                * ((next_ext_row[10]) - (current_ext_row[10])))
+                + ((node_1095) * (node_4576)))
+                + ((node_1097) * (node_4576)))
+                + ((node_1099) * (node_4576)),
+            (((((((((node_1117)
+                * (((node_4621) * (((node_4593) - (node_4594)) - (node_4596)))
                     - (BFieldElement::new(1))))
-                + ((node_850) * (node_4354)))
-                + ((node_854) * (node_4354)))
-                + ((node_858)
-                    * (((node_4349)
-                        * (((node_4329)
+                + ((node_1122) * (node_4626)))
+                + ((node_1126) * (node_4626)))
+                + ((node_1130)
+                    * (((node_4621)
+                        * (((node_4601)
                             - ((challenges.get_challenge(U32CiWeight))
                                 * (BFieldElement::new(20))))
                             - (((challenges.get_challenge(U32ResultWeight))
-                                * ((node_658) - (next_base_row[21])))
+                                * ((node_930) - (next_base_row[23])))
                                 * (BFieldElement::new(9223372034707292161)))))
                         - (BFieldElement::new(1)))))
-                + ((node_864) * (node_4354)))
-                + ((node_861) * (node_4362)))
-                + ((node_867)
-                    * (((((node_4349) * (node_4345)) * (node_4348)) - (node_4345))
-                        - (node_4348))))
-                + ((node_870) * (node_4362)))
-                + (((BFieldElement::new(1)) - (current_base_row[12])) * (node_4349)),
+                + ((node_1136) * (node_4626)))
+                + ((node_1133) * (node_4634)))
+                + ((node_1139)
+                    * (((((node_4621) * (node_4617)) * (node_4620)) - (node_4617))
+                        - (node_4620))))
+                + ((node_1142) * (node_4634)))
+                + (((BFieldElement::new(1)) - (current_base_row[14])) * (node_4621)),
         ];
         base_constraints
             .into_iter()
@@ -1707,7 +1715,7 @@ impl Evaluable<BFieldElement> for ExtProcessorTable {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let base_constraints = [base_row[8]];
+        let base_constraints = [base_row[10]];
         let ext_constraints = [];
         base_constraints
             .into_iter()
@@ -1726,10 +1734,8 @@ impl Evaluable<XFieldElement> for ExtProcessorTable {
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
         let base_constraints = [
-            base_row[4],
-            base_row[7],
-            base_row[18],
-            base_row[19],
+            base_row[6],
+            base_row[9],
             base_row[20],
             base_row[21],
             base_row[22],
@@ -1747,44 +1753,50 @@ impl Evaluable<XFieldElement> for ExtProcessorTable {
             base_row[34],
             base_row[35],
             base_row[36],
-            (base_row[37]) - (BFieldElement::new(16)),
+            base_row[37],
             base_row[38],
-            base_row[44],
-            base_row[43],
-            base_row[6],
+            (base_row[39]) - (BFieldElement::new(16)),
+            base_row[40],
+            base_row[46],
+            base_row[45],
+            base_row[8],
         ];
         let ext_constraints = [
-            (ext_row[1]) - (BFieldElement::new(1)),
-            ((ext_row[3])
-                * ((challenges.get_challenge(InstructionLookupIndeterminate))
-                    - (((challenges.get_challenge(ProgramInstructionWeight)) * (base_row[8]))
-                        + ((challenges.get_challenge(ProgramNextInstructionWeight))
-                            * (base_row[9])))))
-                - (BFieldElement::new(1)),
             (ext_row[2]) - (BFieldElement::new(1)),
-            (ext_row[4])
+            (((BFieldElement::new(1)) - (base_row[7]))
+                * (((ext_row[4])
+                    * ((challenges.get_challenge(InstructionLookupIndeterminate))
+                        - (((challenges.get_challenge(ProgramInstructionWeight))
+                            * (base_row[10]))
+                            + ((challenges.get_challenge(ProgramNextInstructionWeight))
+                                * (base_row[11])))))
+                    - (BFieldElement::new(1))))
+                + ((base_row[7]) * (ext_row[4])),
+            (ext_row[3]) - (BFieldElement::new(1)),
+            (ext_row[5])
                 - ((challenges.get_challenge(OpStackIndeterminate))
-                    - (((challenges.get_challenge(OpStackIb1Weight)) * (base_row[11]))
+                    - (((challenges.get_challenge(OpStackIb1Weight)) * (base_row[13]))
                         + ((challenges.get_challenge(OpStackOspWeight))
                             * (BFieldElement::new(16))))),
-            (ext_row[5]) - (challenges.get_challenge(RamIndeterminate)),
-            (ext_row[6])
+            (ext_row[6]) - (challenges.get_challenge(RamIndeterminate)),
+            (ext_row[7])
                 - ((challenges.get_challenge(JumpStackIndeterminate))
-                    - ((challenges.get_challenge(JumpStackCiWeight)) * (base_row[8]))),
-            ext_row[11],
-            (((base_row[8]) - (BFieldElement::new(48))) * ((ext_row[7]) - (BFieldElement::new(1))))
-                + ((((((((((base_row[10]) - (BFieldElement::new(1)))
-                    * ((base_row[11]) - (BFieldElement::new(1))))
-                    * ((base_row[12]) - (BFieldElement::new(1))))
+                    - ((challenges.get_challenge(JumpStackCiWeight)) * (base_row[10]))),
+            ext_row[12],
+            (((base_row[10]) - (BFieldElement::new(48)))
+                * ((ext_row[8]) - (BFieldElement::new(1))))
+                + ((((((((((base_row[12]) - (BFieldElement::new(1)))
                     * ((base_row[13]) - (BFieldElement::new(1))))
-                    * (base_row[14]))
-                    * (base_row[15]))
-                    * ((base_row[16]) - (BFieldElement::new(1))))
-                    * ((base_row[17]) - (BFieldElement::new(1))))
-                    * ((ext_row[7]) - (challenges.get_challenge(HashInputIndeterminate)))),
-            (ext_row[8]) - (BFieldElement::new(1)),
+                    * ((base_row[14]) - (BFieldElement::new(1))))
+                    * ((base_row[15]) - (BFieldElement::new(1))))
+                    * (base_row[16]))
+                    * (base_row[17]))
+                    * ((base_row[18]) - (BFieldElement::new(1))))
+                    * ((base_row[19]) - (BFieldElement::new(1))))
+                    * ((ext_row[8]) - (challenges.get_challenge(HashInputIndeterminate)))),
             (ext_row[9]) - (BFieldElement::new(1)),
-            ext_row[10],
+            (ext_row[10]) - (BFieldElement::new(1)),
+            ext_row[11],
         ];
         base_constraints
             .into_iter()
@@ -1800,24 +1812,24 @@ impl Evaluable<XFieldElement> for ExtProcessorTable {
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
         let base_constraints = [
-            (base_row[10]) * ((base_row[10]) - (BFieldElement::new(1))),
-            (base_row[11]) * ((base_row[11]) - (BFieldElement::new(1))),
             (base_row[12]) * ((base_row[12]) - (BFieldElement::new(1))),
             (base_row[13]) * ((base_row[13]) - (BFieldElement::new(1))),
             (base_row[14]) * ((base_row[14]) - (BFieldElement::new(1))),
             (base_row[15]) * ((base_row[15]) - (BFieldElement::new(1))),
             (base_row[16]) * ((base_row[16]) - (BFieldElement::new(1))),
             (base_row[17]) * ((base_row[17]) - (BFieldElement::new(1))),
-            (base_row[5]) * ((base_row[5]) - (BFieldElement::new(1))),
-            (base_row[8])
-                - ((((((((base_row[10]) + ((BFieldElement::new(2)) * (base_row[11])))
-                    + ((BFieldElement::new(4)) * (base_row[12])))
-                    + ((BFieldElement::new(8)) * (base_row[13])))
-                    + ((BFieldElement::new(16)) * (base_row[14])))
-                    + ((BFieldElement::new(32)) * (base_row[15])))
-                    + ((BFieldElement::new(64)) * (base_row[16])))
-                    + ((BFieldElement::new(128)) * (base_row[17]))),
-            ((base_row[5]) * ((base_row[4]) - (BFieldElement::new(1)))) * (base_row[45]),
+            (base_row[18]) * ((base_row[18]) - (BFieldElement::new(1))),
+            (base_row[19]) * ((base_row[19]) - (BFieldElement::new(1))),
+            (base_row[7]) * ((base_row[7]) - (BFieldElement::new(1))),
+            (base_row[10])
+                - ((((((((base_row[12]) + ((BFieldElement::new(2)) * (base_row[13])))
+                    + ((BFieldElement::new(4)) * (base_row[14])))
+                    + ((BFieldElement::new(8)) * (base_row[15])))
+                    + ((BFieldElement::new(16)) * (base_row[16])))
+                    + ((BFieldElement::new(32)) * (base_row[17])))
+                    + ((BFieldElement::new(64)) * (base_row[18])))
+                    + ((BFieldElement::new(128)) * (base_row[19]))),
+            ((base_row[7]) * ((base_row[6]) - (BFieldElement::new(1)))) * (base_row[47]),
         ];
         let ext_constraints = [];
         base_constraints
@@ -1835,1566 +1847,1570 @@ impl Evaluable<XFieldElement> for ExtProcessorTable {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_746 = (current_base_row[17]) - (BFieldElement::new(1));
-        let node_732 = (current_base_row[10]) - (BFieldElement::new(1));
-        let node_736 = (current_base_row[12]) - (BFieldElement::new(1));
-        let node_744 = (current_base_row[16]) - (BFieldElement::new(1));
-        let node_750 = (current_base_row[11]) - (BFieldElement::new(1));
-        let node_742 = (current_base_row[15]) - (BFieldElement::new(1));
-        let node_758 = (node_732) * (node_750);
-        let node_738 = (current_base_row[13]) - (BFieldElement::new(1));
-        let node_740 = (current_base_row[14]) - (BFieldElement::new(1));
-        let node_759 = (node_758) * (node_736);
-        let node_737 = ((node_732) * (current_base_row[11])) * (node_736);
-        let node_776 = (node_759) * (node_738);
-        let node_840 = (node_758) * (current_base_row[12]);
-        let node_761 = (node_759) * (current_base_row[13]);
-        let node_752 = ((current_base_row[10]) * (node_750)) * (node_736);
-        let node_794 = (node_776) * (node_740);
-        let node_739 = (node_737) * (node_738);
-        let node_781 = (node_737) * (current_base_row[13]);
-        let node_841 = (node_840) * (node_738);
-        let node_846 = (node_840) * (current_base_row[13]);
-        let node_762 = (node_761) * (node_740);
-        let node_777 = (node_776) * (current_base_row[14]);
-        let node_753 = (node_752) * (node_738);
-        let node_790 = (node_761) * (current_base_row[14]);
-        let node_803 = (node_794) * (node_742);
-        let node_741 = (node_739) * (node_740);
-        let node_766 = (node_752) * (current_base_row[13]);
-        let node_3965 = (BFieldElement::new(1)) - (next_base_row[5]);
-        let node_775 =
-            ((((node_753) * (current_base_row[14])) * (node_742)) * (node_744)) * (node_746);
-        let node_799 = (node_739) * (current_base_row[14]);
-        let node_782 = (node_781) * (node_740);
-        let node_778 = (node_777) * (node_742);
-        let node_804 = (node_803) * (node_744);
-        let node_842 = (node_841) * (node_740);
-        let node_743 = (node_741) * (node_742);
-        let node_763 = (node_762) * (node_742);
-        let node_796 = (node_794) * (current_base_row[15]);
-        let node_809 = (node_781) * (current_base_row[14]);
-        let node_847 = (node_846) * (node_740);
-        let node_851 = (node_841) * (current_base_row[14]);
-        let node_855 = (node_846) * (current_base_row[14]);
-        let node_806 = (node_762) * (current_base_row[15]);
-        let node_770 = ((((node_766) * (node_740)) * (node_742)) * (node_744)) * (node_746);
-        let node_816 = (node_790) * (current_base_row[15]);
-        let node_813 = (node_777) * (current_base_row[15]);
-        let node_391 = (next_base_row[7]) - (current_base_row[7]);
-        let node_791 = (node_790) * (node_742);
-        let node_393 = (next_base_row[18]) - (current_base_row[18]);
-        let node_394 = (next_base_row[19]) - (current_base_row[19]);
-        let node_395 = (next_base_row[20]) - (current_base_row[20]);
-        let node_418 = (next_base_row[44]) - (current_base_row[44]);
-        let node_419 = (next_base_row[43]) - (current_base_row[43]);
-        let node_445 = (BFieldElement::new(1)) - (current_base_row[39]);
-        let node_392 = (node_391) - (BFieldElement::new(1));
-        let node_440 = (BFieldElement::new(1)) - (current_base_row[42]);
-        let node_441 = (BFieldElement::new(1)) - (current_base_row[41]);
-        let node_443 = (BFieldElement::new(1)) - (current_base_row[40]);
-        let node_821 = ((node_803) * (current_base_row[16])) * (node_746);
-        let node_785 = (((node_782) * (node_742)) * (node_744)) * (node_746);
-        let node_838 = (((node_799) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_802 = (((node_799) * (node_742)) * (node_744)) * (node_746);
-        let node_805 = (node_804) * (node_746);
-        let node_823 = ((node_763) * (current_base_row[16])) * (node_746);
-        let node_825 = ((node_778) * (current_base_row[16])) * (node_746);
-        let node_845 = (((node_842) * (node_742)) * (node_744)) * (node_746);
-        let node_747 = ((node_743) * (node_744)) * (node_746);
-        let node_757 = ((((node_753) * (node_740)) * (node_742)) * (node_744)) * (node_746);
-        let node_780 = ((node_778) * (node_744)) * (node_746);
-        let node_789 =
-            ((((node_766) * (current_base_row[14])) * (node_742)) * (node_744)) * (node_746);
-        let node_798 = ((node_796) * (node_744)) * (node_746);
-        let node_812 = (((node_809) * (node_742)) * (node_744)) * (node_746);
-        let node_830 = (((node_741) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_833 = (((node_782) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_835 = ((node_796) * (current_base_row[16])) * (node_746);
-        let node_850 = (((node_847) * (node_742)) * (node_744)) * (node_746);
-        let node_854 = (((node_851) * (node_742)) * (node_744)) * (node_746);
-        let node_858 = (((node_855) * (node_742)) * (node_744)) * (node_746);
-        let node_861 = (((node_842) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_864 = (((node_847) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_867 = (((node_851) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_870 = (((node_855) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_872 = ((node_806) * (current_base_row[16])) * (node_746);
-        let node_874 = ((node_813) * (current_base_row[16])) * (node_746);
-        let node_876 = ((node_816) * (current_base_row[16])) * (node_746);
-        let node_879 = (((node_809) * (current_base_row[15])) * (node_744)) * (node_746);
-        let node_881 = (node_804) * (current_base_row[17]);
-        let node_883 = ((node_743) * (current_base_row[16])) * (node_746);
-        let node_765 = ((node_763) * (node_744)) * (node_746);
-        let node_808 = ((node_806) * (node_744)) * (node_746);
-        let node_793 = ((node_791) * (node_744)) * (node_746);
-        let node_818 = ((node_816) * (node_744)) * (node_746);
-        let node_580 = (next_base_row[32]) - (current_base_row[32]);
-        let node_583 = (next_base_row[33]) - (current_base_row[33]);
-        let node_586 = (next_base_row[34]) - (current_base_row[34]);
-        let node_589 = (next_base_row[35]) - (current_base_row[35]);
-        let node_592 = (next_base_row[36]) - (current_base_row[36]);
-        let node_594 = (next_base_row[38]) - (current_base_row[38]);
-        let node_595 = (next_base_row[37]) - (current_base_row[37]);
-        let node_577 = (next_base_row[31]) - (current_base_row[31]);
-        let node_556 = (next_base_row[24]) - (current_base_row[24]);
-        let node_559 = (next_base_row[25]) - (current_base_row[25]);
-        let node_562 = (next_base_row[26]) - (current_base_row[26]);
-        let node_565 = (next_base_row[27]) - (current_base_row[27]);
-        let node_568 = (next_base_row[28]) - (current_base_row[28]);
-        let node_571 = (next_base_row[29]) - (current_base_row[29]);
-        let node_574 = (next_base_row[30]) - (current_base_row[30]);
-        let node_458 = (node_440) * (current_base_row[41]);
-        let node_473 = (current_base_row[42]) * (node_441);
-        let node_488 = (current_base_row[42]) * (current_base_row[41]);
-        let node_815 = ((node_813) * (node_744)) * (node_746);
-        let node_827 = ((node_791) * (current_base_row[16])) * (node_746);
-        let node_442 = (node_440) * (node_441);
-        let node_553 = (next_base_row[23]) - (current_base_row[23]);
-        let node_550 = (next_base_row[22]) - (current_base_row[22]);
-        let node_399 = (next_base_row[24]) - (current_base_row[25]);
-        let node_400 = (next_base_row[25]) - (current_base_row[26]);
-        let node_401 = (next_base_row[26]) - (current_base_row[27]);
-        let node_402 = (next_base_row[27]) - (current_base_row[28]);
-        let node_403 = (next_base_row[28]) - (current_base_row[29]);
-        let node_404 = (next_base_row[29]) - (current_base_row[30]);
-        let node_405 = (next_base_row[30]) - (current_base_row[31]);
-        let node_406 = (next_base_row[31]) - (current_base_row[32]);
-        let node_407 = (next_base_row[32]) - (current_base_row[33]);
-        let node_408 = (next_base_row[33]) - (current_base_row[34]);
-        let node_409 = (next_base_row[34]) - (current_base_row[35]);
-        let node_410 = (next_base_row[35]) - (current_base_row[36]);
-        let node_411 = (next_base_row[36]) - (current_base_row[38]);
-        let node_413 = (next_base_row[37]) - ((current_base_row[37]) - (BFieldElement::new(1)));
-        let node_417 = (((current_base_row[37]) - (BFieldElement::new(16)))
-            * (current_base_row[42]))
+        let node_1018 = (current_base_row[19]) - (BFieldElement::new(1));
+        let node_1004 = (current_base_row[12]) - (BFieldElement::new(1));
+        let node_1008 = (current_base_row[14]) - (BFieldElement::new(1));
+        let node_1016 = (current_base_row[18]) - (BFieldElement::new(1));
+        let node_1022 = (current_base_row[13]) - (BFieldElement::new(1));
+        let node_1014 = (current_base_row[17]) - (BFieldElement::new(1));
+        let node_1030 = (node_1004) * (node_1022);
+        let node_1010 = (current_base_row[15]) - (BFieldElement::new(1));
+        let node_1012 = (current_base_row[16]) - (BFieldElement::new(1));
+        let node_1031 = (node_1030) * (node_1008);
+        let node_1009 = ((node_1004) * (current_base_row[13])) * (node_1008);
+        let node_1048 = (node_1031) * (node_1010);
+        let node_1112 = (node_1030) * (current_base_row[14]);
+        let node_1033 = (node_1031) * (current_base_row[15]);
+        let node_1024 = ((current_base_row[12]) * (node_1022)) * (node_1008);
+        let node_1066 = (node_1048) * (node_1012);
+        let node_1011 = (node_1009) * (node_1010);
+        let node_1053 = (node_1009) * (current_base_row[15]);
+        let node_1113 = (node_1112) * (node_1010);
+        let node_1118 = (node_1112) * (current_base_row[15]);
+        let node_1034 = (node_1033) * (node_1012);
+        let node_1049 = (node_1048) * (current_base_row[16]);
+        let node_1025 = (node_1024) * (node_1010);
+        let node_1062 = (node_1033) * (current_base_row[16]);
+        let node_1075 = (node_1066) * (node_1014);
+        let node_1013 = (node_1011) * (node_1012);
+        let node_1038 = (node_1024) * (current_base_row[15]);
+        let node_4237 = (BFieldElement::new(1)) - (next_base_row[7]);
+        let node_1047 =
+            ((((node_1025) * (current_base_row[16])) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1071 = (node_1011) * (current_base_row[16]);
+        let node_1054 = (node_1053) * (node_1012);
+        let node_1050 = (node_1049) * (node_1014);
+        let node_1076 = (node_1075) * (node_1016);
+        let node_1114 = (node_1113) * (node_1012);
+        let node_1015 = (node_1013) * (node_1014);
+        let node_1035 = (node_1034) * (node_1014);
+        let node_1068 = (node_1066) * (current_base_row[17]);
+        let node_1081 = (node_1053) * (current_base_row[16]);
+        let node_1119 = (node_1118) * (node_1012);
+        let node_1123 = (node_1113) * (current_base_row[16]);
+        let node_1127 = (node_1118) * (current_base_row[16]);
+        let node_1078 = (node_1034) * (current_base_row[17]);
+        let node_1042 = ((((node_1038) * (node_1012)) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1088 = (node_1062) * (current_base_row[17]);
+        let node_1085 = (node_1049) * (current_base_row[17]);
+        let node_663 = (next_base_row[9]) - (current_base_row[9]);
+        let node_1063 = (node_1062) * (node_1014);
+        let node_665 = (next_base_row[20]) - (current_base_row[20]);
+        let node_666 = (next_base_row[21]) - (current_base_row[21]);
+        let node_667 = (next_base_row[22]) - (current_base_row[22]);
+        let node_690 = (next_base_row[46]) - (current_base_row[46]);
+        let node_691 = (next_base_row[45]) - (current_base_row[45]);
+        let node_717 = (BFieldElement::new(1)) - (current_base_row[41]);
+        let node_664 = (node_663) - (BFieldElement::new(1));
+        let node_712 = (BFieldElement::new(1)) - (current_base_row[44]);
+        let node_713 = (BFieldElement::new(1)) - (current_base_row[43]);
+        let node_715 = (BFieldElement::new(1)) - (current_base_row[42]);
+        let node_1093 = ((node_1075) * (current_base_row[18])) * (node_1018);
+        let node_1057 = (((node_1054) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1110 = (((node_1071) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1074 = (((node_1071) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1077 = (node_1076) * (node_1018);
+        let node_1095 = ((node_1035) * (current_base_row[18])) * (node_1018);
+        let node_1097 = ((node_1050) * (current_base_row[18])) * (node_1018);
+        let node_1117 = (((node_1114) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1019 = ((node_1015) * (node_1016)) * (node_1018);
+        let node_1029 = ((((node_1025) * (node_1012)) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1052 = ((node_1050) * (node_1016)) * (node_1018);
+        let node_1061 =
+            ((((node_1038) * (current_base_row[16])) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1070 = ((node_1068) * (node_1016)) * (node_1018);
+        let node_1084 = (((node_1081) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1102 = (((node_1013) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1105 = (((node_1054) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1107 = ((node_1068) * (current_base_row[18])) * (node_1018);
+        let node_1122 = (((node_1119) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1126 = (((node_1123) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1130 = (((node_1127) * (node_1014)) * (node_1016)) * (node_1018);
+        let node_1133 = (((node_1114) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1136 = (((node_1119) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1139 = (((node_1123) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1142 = (((node_1127) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1144 = ((node_1078) * (current_base_row[18])) * (node_1018);
+        let node_1146 = ((node_1085) * (current_base_row[18])) * (node_1018);
+        let node_1148 = ((node_1088) * (current_base_row[18])) * (node_1018);
+        let node_1151 = (((node_1081) * (current_base_row[17])) * (node_1016)) * (node_1018);
+        let node_1153 = (node_1076) * (current_base_row[19]);
+        let node_1155 = ((node_1015) * (current_base_row[18])) * (node_1018);
+        let node_1037 = ((node_1035) * (node_1016)) * (node_1018);
+        let node_1080 = ((node_1078) * (node_1016)) * (node_1018);
+        let node_1065 = ((node_1063) * (node_1016)) * (node_1018);
+        let node_1090 = ((node_1088) * (node_1016)) * (node_1018);
+        let node_852 = (next_base_row[34]) - (current_base_row[34]);
+        let node_855 = (next_base_row[35]) - (current_base_row[35]);
+        let node_858 = (next_base_row[36]) - (current_base_row[36]);
+        let node_861 = (next_base_row[37]) - (current_base_row[37]);
+        let node_864 = (next_base_row[38]) - (current_base_row[38]);
+        let node_866 = (next_base_row[40]) - (current_base_row[40]);
+        let node_867 = (next_base_row[39]) - (current_base_row[39]);
+        let node_849 = (next_base_row[33]) - (current_base_row[33]);
+        let node_828 = (next_base_row[26]) - (current_base_row[26]);
+        let node_831 = (next_base_row[27]) - (current_base_row[27]);
+        let node_834 = (next_base_row[28]) - (current_base_row[28]);
+        let node_837 = (next_base_row[29]) - (current_base_row[29]);
+        let node_840 = (next_base_row[30]) - (current_base_row[30]);
+        let node_843 = (next_base_row[31]) - (current_base_row[31]);
+        let node_846 = (next_base_row[32]) - (current_base_row[32]);
+        let node_730 = (node_712) * (current_base_row[43]);
+        let node_745 = (current_base_row[44]) * (node_713);
+        let node_760 = (current_base_row[44]) * (current_base_row[43]);
+        let node_1087 = ((node_1085) * (node_1016)) * (node_1018);
+        let node_1099 = ((node_1063) * (current_base_row[18])) * (node_1018);
+        let node_714 = (node_712) * (node_713);
+        let node_825 = (next_base_row[25]) - (current_base_row[25]);
+        let node_822 = (next_base_row[24]) - (current_base_row[24]);
+        let node_671 = (next_base_row[26]) - (current_base_row[27]);
+        let node_672 = (next_base_row[27]) - (current_base_row[28]);
+        let node_673 = (next_base_row[28]) - (current_base_row[29]);
+        let node_674 = (next_base_row[29]) - (current_base_row[30]);
+        let node_675 = (next_base_row[30]) - (current_base_row[31]);
+        let node_676 = (next_base_row[31]) - (current_base_row[32]);
+        let node_677 = (next_base_row[32]) - (current_base_row[33]);
+        let node_678 = (next_base_row[33]) - (current_base_row[34]);
+        let node_679 = (next_base_row[34]) - (current_base_row[35]);
+        let node_680 = (next_base_row[35]) - (current_base_row[36]);
+        let node_681 = (next_base_row[36]) - (current_base_row[37]);
+        let node_682 = (next_base_row[37]) - (current_base_row[38]);
+        let node_683 = (next_base_row[38]) - (current_base_row[40]);
+        let node_685 = (next_base_row[39]) - ((current_base_row[39]) - (BFieldElement::new(1)));
+        let node_689 = (((current_base_row[39]) - (BFieldElement::new(16)))
+            * (current_base_row[44]))
             - (BFieldElement::new(1));
-        let node_397 = (next_base_row[22]) - (current_base_row[23]);
-        let node_398 = (next_base_row[23]) - (current_base_row[24]);
-        let node_447 = (next_base_row[21]) - (current_base_row[21]);
-        let node_4349 = (next_ext_row[10]) - (current_ext_row[10]);
-        let node_451 = (node_442) * (current_base_row[40]);
-        let node_459 = (node_458) * (node_443);
-        let node_466 = (node_458) * (current_base_row[40]);
-        let node_474 = (node_473) * (node_443);
-        let node_481 = (node_473) * (current_base_row[40]);
-        let node_489 = (node_488) * (node_443);
-        let node_496 = (node_488) * (current_base_row[40]);
-        let node_4327 = (challenges.get_challenge(U32Indeterminate))
-            - ((challenges.get_challenge(U32LhsWeight)) * (current_base_row[21]));
-        let node_396 = (next_base_row[21]) - (current_base_row[22]);
-        let node_444 = (node_442) * (node_443);
-        let node_421 = (next_base_row[22]) - (current_base_row[21]);
-        let node_422 = (next_base_row[23]) - (current_base_row[22]);
-        let node_423 = (next_base_row[24]) - (current_base_row[23]);
-        let node_424 = (next_base_row[25]) - (current_base_row[24]);
-        let node_425 = (next_base_row[26]) - (current_base_row[25]);
-        let node_426 = (next_base_row[27]) - (current_base_row[26]);
-        let node_427 = (next_base_row[28]) - (current_base_row[27]);
-        let node_428 = (next_base_row[29]) - (current_base_row[28]);
-        let node_429 = (next_base_row[30]) - (current_base_row[29]);
-        let node_430 = (next_base_row[31]) - (current_base_row[30]);
-        let node_431 = (next_base_row[32]) - (current_base_row[31]);
-        let node_432 = (next_base_row[33]) - (current_base_row[32]);
-        let node_433 = (next_base_row[34]) - (current_base_row[33]);
-        let node_434 = (next_base_row[35]) - (current_base_row[34]);
-        let node_435 = (next_base_row[36]) - (current_base_row[35]);
-        let node_436 = (next_base_row[38]) - (current_base_row[36]);
-        let node_438 = (next_base_row[37]) - ((current_base_row[37]) + (BFieldElement::new(1)));
-        let node_4324 = (challenges.get_challenge(U32CiWeight)) * (current_base_row[8]);
-        let node_4328 = (challenges.get_challenge(U32RhsWeight)) * (current_base_row[22]);
-        let node_503 = (current_base_row[39]) - (BFieldElement::new(1));
-        let node_4331 = (challenges.get_challenge(U32ResultWeight)) * (next_base_row[21]);
-        let node_504 = (current_base_row[39]) * (node_503);
-        let node_664 = (current_base_row[22]) - (current_base_row[21]);
-        let node_449 = (node_444) * (current_base_row[39]);
-        let node_452 = (node_451) * (node_445);
-        let node_439 = (node_391) - (BFieldElement::new(2));
-        let node_455 = (node_451) * (current_base_row[39]);
-        let node_460 = (node_459) * (node_445);
-        let node_463 = (node_459) * (current_base_row[39]);
-        let node_467 = (node_466) * (node_445);
-        let node_470 = (node_466) * (current_base_row[39]);
-        let node_475 = (node_474) * (node_445);
-        let node_478 = (node_474) * (current_base_row[39]);
-        let node_482 = (node_481) * (node_445);
-        let node_485 = (node_481) * (current_base_row[39]);
-        let node_490 = (node_489) * (node_445);
-        let node_493 = (node_489) * (current_base_row[39]);
-        let node_497 = (node_496) * (node_445);
-        let node_500 = (node_496) * (current_base_row[39]);
-        let node_4264 = ((((((((((challenges.get_challenge(HashStateWeight0))
-            * (next_base_row[21]))
-            + ((challenges.get_challenge(HashStateWeight1)) * (next_base_row[22])))
-            + ((challenges.get_challenge(HashStateWeight2)) * (next_base_row[23])))
-            + ((challenges.get_challenge(HashStateWeight3)) * (next_base_row[24])))
-            + ((challenges.get_challenge(HashStateWeight4)) * (next_base_row[25])))
-            + ((challenges.get_challenge(HashStateWeight5)) * (next_base_row[26])))
-            + ((challenges.get_challenge(HashStateWeight6)) * (next_base_row[27])))
-            + ((challenges.get_challenge(HashStateWeight7)) * (next_base_row[28])))
-            + ((challenges.get_challenge(HashStateWeight8)) * (next_base_row[29])))
-            + ((challenges.get_challenge(HashStateWeight9)) * (next_base_row[30]));
-        let node_4329 = (node_4327) - (node_4328);
-        let node_517 = (BFieldElement::new(2)) * (current_base_row[40]);
-        let node_665 = (current_base_row[39]) * (node_664);
-        let node_4304 = (((next_ext_row[9])
-            - ((challenges.get_challenge(SpongeIndeterminate)) * (current_ext_row[9])))
-            - ((challenges.get_challenge(HashCIWeight)) * (current_base_row[8])))
-            - (node_4264);
-        let node_4321 = (challenges.get_challenge(U32Indeterminate))
-            - ((challenges.get_challenge(U32LhsWeight)) * (next_base_row[21]));
-        let node_4322 = (challenges.get_challenge(U32RhsWeight)) * (next_base_row[22]);
-        let node_4354 =
-            ((node_4349) * (((node_4329) - (node_4324)) - (node_4331))) - (BFieldElement::new(1));
-        let node_446 = (node_444) * (node_445);
-        let node_620 = (next_base_row[8]) - (current_base_row[8]);
-        let node_658 = (current_base_row[21]) + (current_base_row[22]);
-        let node_661 = (next_base_row[21]) - ((current_base_row[21]) * (current_base_row[22]));
-        let node_662 = (next_base_row[21]) * (current_base_row[21]);
-        let node_666 = (node_665) - (BFieldElement::new(1));
-        let node_680 = (current_base_row[22]) * (next_base_row[22]);
-        let node_689 = (current_base_row[21]) * (current_base_row[24]);
-        let node_690 = (current_base_row[23]) * (current_base_row[25]);
-        let node_692 = (current_base_row[22]) * (current_base_row[26]);
-        let node_710 = (current_base_row[23]) * (next_base_row[22]);
-        let node_712 = (current_base_row[22]) * (next_base_row[23]);
-        let node_450 = (node_449) * (node_396);
-        let node_698 = (current_base_row[23]) * (current_base_row[26]);
-        let node_718 = (current_base_row[23]) * (next_base_row[23]);
-        let node_454 = (node_452) * ((next_base_row[21]) - (current_base_row[23]));
-        let node_600 = ((current_base_row[21]) * (current_base_row[41])) - (BFieldElement::new(1));
-        let node_457 = (node_455) * ((next_base_row[21]) - (current_base_row[24]));
-        let node_462 = (node_460) * ((next_base_row[21]) - (current_base_row[25]));
-        let node_465 = (node_463) * ((next_base_row[21]) - (current_base_row[26]));
-        let node_469 = (node_467) * ((next_base_row[21]) - (current_base_row[27]));
-        let node_472 = (node_470) * ((next_base_row[21]) - (current_base_row[28]));
-        let node_477 = (node_475) * ((next_base_row[21]) - (current_base_row[29]));
-        let node_480 = (node_478) * ((next_base_row[21]) - (current_base_row[30]));
-        let node_484 = (node_482) * ((next_base_row[21]) - (current_base_row[31]));
-        let node_487 = (node_485) * ((next_base_row[21]) - (current_base_row[32]));
-        let node_492 = (node_490) * ((next_base_row[21]) - (current_base_row[33]));
-        let node_495 = (node_493) * ((next_base_row[21]) - (current_base_row[34]));
-        let node_499 = (node_497) * ((next_base_row[21]) - (current_base_row[35]));
-        let node_502 = (node_500) * ((next_base_row[21]) - (current_base_row[36]));
-        let node_506 = (current_base_row[40]) * ((current_base_row[40]) - (BFieldElement::new(1)));
-        let node_508 = (current_base_row[41]) * ((current_base_row[41]) - (BFieldElement::new(1)));
-        let node_510 = (current_base_row[42]) * ((current_base_row[42]) - (BFieldElement::new(1)));
-        let node_519 = ((((current_base_row[9])
-            - ((BFieldElement::new(8)) * (current_base_row[42])))
-            - ((BFieldElement::new(4)) * (current_base_row[41])))
-            - (node_517))
-            - (current_base_row[39]);
-        let node_4141 = (next_ext_row[3]) - (current_ext_row[3]);
-        let node_4149 = (next_base_row[10]) - (BFieldElement::new(1));
-        let node_4153 = (next_base_row[12]) - (BFieldElement::new(1));
-        let node_4155 = (next_base_row[13]) - (BFieldElement::new(1));
-        let node_4163 = (next_base_row[17]) - (BFieldElement::new(1));
-        let node_4362 =
-            ((node_4349) * (((node_4327) - (node_4324)) - (node_4331))) - (BFieldElement::new(1));
-        let node_4345 = (((node_4321) - (node_4328))
+        let node_669 = (next_base_row[24]) - (current_base_row[25]);
+        let node_670 = (next_base_row[25]) - (current_base_row[26]);
+        let node_719 = (next_base_row[23]) - (current_base_row[23]);
+        let node_4621 = (next_ext_row[11]) - (current_ext_row[11]);
+        let node_723 = (node_714) * (current_base_row[42]);
+        let node_731 = (node_730) * (node_715);
+        let node_738 = (node_730) * (current_base_row[42]);
+        let node_746 = (node_745) * (node_715);
+        let node_753 = (node_745) * (current_base_row[42]);
+        let node_761 = (node_760) * (node_715);
+        let node_768 = (node_760) * (current_base_row[42]);
+        let node_4599 = (challenges.get_challenge(U32Indeterminate))
+            - ((challenges.get_challenge(U32LhsWeight)) * (current_base_row[23]));
+        let node_668 = (next_base_row[23]) - (current_base_row[24]);
+        let node_716 = (node_714) * (node_715);
+        let node_693 = (next_base_row[24]) - (current_base_row[23]);
+        let node_694 = (next_base_row[25]) - (current_base_row[24]);
+        let node_695 = (next_base_row[26]) - (current_base_row[25]);
+        let node_696 = (next_base_row[27]) - (current_base_row[26]);
+        let node_697 = (next_base_row[28]) - (current_base_row[27]);
+        let node_698 = (next_base_row[29]) - (current_base_row[28]);
+        let node_699 = (next_base_row[30]) - (current_base_row[29]);
+        let node_700 = (next_base_row[31]) - (current_base_row[30]);
+        let node_701 = (next_base_row[32]) - (current_base_row[31]);
+        let node_702 = (next_base_row[33]) - (current_base_row[32]);
+        let node_703 = (next_base_row[34]) - (current_base_row[33]);
+        let node_704 = (next_base_row[35]) - (current_base_row[34]);
+        let node_705 = (next_base_row[36]) - (current_base_row[35]);
+        let node_706 = (next_base_row[37]) - (current_base_row[36]);
+        let node_707 = (next_base_row[38]) - (current_base_row[37]);
+        let node_708 = (next_base_row[40]) - (current_base_row[38]);
+        let node_710 = (next_base_row[39]) - ((current_base_row[39]) + (BFieldElement::new(1)));
+        let node_4596 = (challenges.get_challenge(U32CiWeight)) * (current_base_row[10]);
+        let node_4600 = (challenges.get_challenge(U32RhsWeight)) * (current_base_row[24]);
+        let node_775 = (current_base_row[41]) - (BFieldElement::new(1));
+        let node_4603 = (challenges.get_challenge(U32ResultWeight)) * (next_base_row[23]);
+        let node_776 = (current_base_row[41]) * (node_775);
+        let node_936 = (current_base_row[24]) - (current_base_row[23]);
+        let node_721 = (node_716) * (current_base_row[41]);
+        let node_724 = (node_723) * (node_717);
+        let node_711 = (node_663) - (BFieldElement::new(2));
+        let node_727 = (node_723) * (current_base_row[41]);
+        let node_732 = (node_731) * (node_717);
+        let node_735 = (node_731) * (current_base_row[41]);
+        let node_739 = (node_738) * (node_717);
+        let node_742 = (node_738) * (current_base_row[41]);
+        let node_747 = (node_746) * (node_717);
+        let node_750 = (node_746) * (current_base_row[41]);
+        let node_754 = (node_753) * (node_717);
+        let node_757 = (node_753) * (current_base_row[41]);
+        let node_762 = (node_761) * (node_717);
+        let node_765 = (node_761) * (current_base_row[41]);
+        let node_769 = (node_768) * (node_717);
+        let node_772 = (node_768) * (current_base_row[41]);
+        let node_4536 = ((((((((((challenges.get_challenge(HashStateWeight0))
+            * (next_base_row[23]))
+            + ((challenges.get_challenge(HashStateWeight1)) * (next_base_row[24])))
+            + ((challenges.get_challenge(HashStateWeight2)) * (next_base_row[25])))
+            + ((challenges.get_challenge(HashStateWeight3)) * (next_base_row[26])))
+            + ((challenges.get_challenge(HashStateWeight4)) * (next_base_row[27])))
+            + ((challenges.get_challenge(HashStateWeight5)) * (next_base_row[28])))
+            + ((challenges.get_challenge(HashStateWeight6)) * (next_base_row[29])))
+            + ((challenges.get_challenge(HashStateWeight7)) * (next_base_row[30])))
+            + ((challenges.get_challenge(HashStateWeight8)) * (next_base_row[31])))
+            + ((challenges.get_challenge(HashStateWeight9)) * (next_base_row[32]));
+        let node_4601 = (node_4599) - (node_4600);
+        let node_789 = (BFieldElement::new(2)) * (current_base_row[42]);
+        let node_937 = (current_base_row[41]) * (node_936);
+        let node_4576 = (((next_ext_row[10])
+            - ((challenges.get_challenge(SpongeIndeterminate)) * (current_ext_row[10])))
+            - ((challenges.get_challenge(HashCIWeight)) * (current_base_row[10])))
+            - (node_4536);
+        let node_4593 = (challenges.get_challenge(U32Indeterminate))
+            - ((challenges.get_challenge(U32LhsWeight)) * (next_base_row[23]));
+        let node_4594 = (challenges.get_challenge(U32RhsWeight)) * (next_base_row[24]);
+        let node_4626 =
+            ((node_4621) * (((node_4601) - (node_4596)) - (node_4603))) - (BFieldElement::new(1));
+        let node_718 = (node_716) * (node_717);
+        let node_892 = (next_base_row[10]) - (current_base_row[10]);
+        let node_930 = (current_base_row[23]) + (current_base_row[24]);
+        let node_933 = (next_base_row[23]) - ((current_base_row[23]) * (current_base_row[24]));
+        let node_934 = (next_base_row[23]) * (current_base_row[23]);
+        let node_938 = (node_937) - (BFieldElement::new(1));
+        let node_952 = (current_base_row[24]) * (next_base_row[24]);
+        let node_961 = (current_base_row[23]) * (current_base_row[26]);
+        let node_962 = (current_base_row[25]) * (current_base_row[27]);
+        let node_964 = (current_base_row[24]) * (current_base_row[28]);
+        let node_982 = (current_base_row[25]) * (next_base_row[24]);
+        let node_984 = (current_base_row[24]) * (next_base_row[25]);
+        let node_722 = (node_721) * (node_668);
+        let node_970 = (current_base_row[25]) * (current_base_row[28]);
+        let node_990 = (current_base_row[25]) * (next_base_row[25]);
+        let node_726 = (node_724) * ((next_base_row[23]) - (current_base_row[25]));
+        let node_872 = ((current_base_row[23]) * (current_base_row[43])) - (BFieldElement::new(1));
+        let node_729 = (node_727) * ((next_base_row[23]) - (current_base_row[26]));
+        let node_734 = (node_732) * ((next_base_row[23]) - (current_base_row[27]));
+        let node_737 = (node_735) * ((next_base_row[23]) - (current_base_row[28]));
+        let node_741 = (node_739) * ((next_base_row[23]) - (current_base_row[29]));
+        let node_744 = (node_742) * ((next_base_row[23]) - (current_base_row[30]));
+        let node_749 = (node_747) * ((next_base_row[23]) - (current_base_row[31]));
+        let node_752 = (node_750) * ((next_base_row[23]) - (current_base_row[32]));
+        let node_756 = (node_754) * ((next_base_row[23]) - (current_base_row[33]));
+        let node_759 = (node_757) * ((next_base_row[23]) - (current_base_row[34]));
+        let node_764 = (node_762) * ((next_base_row[23]) - (current_base_row[35]));
+        let node_767 = (node_765) * ((next_base_row[23]) - (current_base_row[36]));
+        let node_771 = (node_769) * ((next_base_row[23]) - (current_base_row[37]));
+        let node_774 = (node_772) * ((next_base_row[23]) - (current_base_row[38]));
+        let node_778 = (current_base_row[42]) * ((current_base_row[42]) - (BFieldElement::new(1)));
+        let node_780 = (current_base_row[43]) * ((current_base_row[43]) - (BFieldElement::new(1)));
+        let node_782 = (current_base_row[44]) * ((current_base_row[44]) - (BFieldElement::new(1)));
+        let node_791 = ((((current_base_row[11])
+            - ((BFieldElement::new(8)) * (current_base_row[44])))
+            - ((BFieldElement::new(4)) * (current_base_row[43])))
+            - (node_789))
+            - (current_base_row[41]);
+        let node_4413 = (next_ext_row[4]) - (current_ext_row[4]);
+        let node_4421 = (next_base_row[12]) - (BFieldElement::new(1));
+        let node_4425 = (next_base_row[14]) - (BFieldElement::new(1));
+        let node_4427 = (next_base_row[15]) - (BFieldElement::new(1));
+        let node_4435 = (next_base_row[19]) - (BFieldElement::new(1));
+        let node_4634 =
+            ((node_4621) * (((node_4599) - (node_4596)) - (node_4603))) - (BFieldElement::new(1));
+        let node_4617 = (((node_4593) - (node_4600))
             - ((challenges.get_challenge(U32CiWeight)) * (BFieldElement::new(12))))
             - (challenges.get_challenge(U32ResultWeight));
-        let node_4348 = ((node_4327) - (node_4322))
+        let node_4620 = ((node_4599) - (node_4594))
             - ((challenges.get_challenge(U32CiWeight)) * (BFieldElement::new(4)));
 
         let base_constraints = [
-            ((next_base_row[4]) - (current_base_row[4])) - (BFieldElement::new(1)),
-            (current_base_row[5]) * ((next_base_row[5]) - (current_base_row[5])),
-            ((next_base_row[6]) - (current_base_row[8])) * (node_3965),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_392))
-                + ((node_757)
-                    * ((next_base_row[21])
-                        - (current_base_row[9]))))
-                + ((node_765) * (node_392)))
-                + ((node_770) * ((node_446) * (node_447))))
-                + ((node_775) * (node_446)))
-                + ((node_780) * (node_392)))
-                + ((node_785)
-                    * ((current_base_row[9])
-                        - ((current_base_row[39]) + (node_517)))))
-                + ((node_789)
-                    * ((next_base_row[18])
-                        - ((current_base_row[18])
+            ((next_base_row[6]) - (current_base_row[6])) - (BFieldElement::new(1)),
+            (current_base_row[7]) * ((next_base_row[7]) - (current_base_row[7])),
+            ((next_base_row[8]) - (current_base_row[10])) * (node_4237),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_664))
+                + ((node_1029)
+                    * ((next_base_row[23])
+                        - (current_base_row[11]))))
+                + ((node_1037) * (node_664)))
+                + ((node_1042) * ((node_718) * (node_719))))
+                + ((node_1047) * (node_718)))
+                + ((node_1052) * (node_664)))
+                + ((node_1057)
+                    * ((current_base_row[11])
+                        - ((current_base_row[41]) + (node_789)))))
+                + ((node_1061)
+                    * ((next_base_row[20])
+                        - ((current_base_row[20])
                             + (BFieldElement::new(1))))))
-                + ((node_793)
-                    * ((next_base_row[18])
-                        - ((current_base_row[18])
+                + ((node_1065)
+                    * ((next_base_row[20])
+                        - ((current_base_row[20])
                             - (BFieldElement::new(1))))))
-                + ((node_798)
-                    * ((next_base_row[7]) - (current_base_row[20]))))
-                + ((node_802)
-                    * ((current_base_row[21])
+                + ((node_1070)
+                    * ((next_base_row[9]) - (current_base_row[22]))))
+                + ((node_1074)
+                    * ((current_base_row[23])
                         - (BFieldElement::new(1)))))
-                + ((node_805) * (node_620)))
-                + ((node_808)
-                    * ((next_base_row[43]) - (current_base_row[21]))))
-                + ((node_812)
-                    * ((next_base_row[43]) - (current_base_row[22]))))
-                + ((node_815) * (node_392)))
-                + ((node_818) * (node_504)))
-                + ((node_821)
-                    * ((current_base_row[26]) - (current_base_row[21]))))
-                + ((node_823) * (node_392)))
-                + ((node_825) * (node_392)))
-                + ((node_827) * (node_392)))
-                + ((node_830) * ((next_base_row[21]) - (node_658))))
-                + ((node_833) * (node_661)))
-                + ((node_835) * ((node_662) - (BFieldElement::new(1)))))
-                + ((node_838) * ((current_base_row[39]) * (node_666))))
-                + ((node_845)
-                    * ((current_base_row[21])
-                        - (((BFieldElement::new(4294967296))
-                            * (next_base_row[22]))
-                            + (next_base_row[21])))))
-                + ((node_850) * (node_392)))
-                + ((node_854) * (node_392)))
-                + ((node_858) * (node_392)))
-                + ((node_861) * (node_392)))
-                + ((node_864) * (node_392)))
-                + ((node_867)
-                    * (((current_base_row[21]) - (node_680)) - (next_base_row[21]))))
-                + ((node_870) * (node_392)))
-                + ((node_872)
-                    * ((next_base_row[21])
-                        - ((current_base_row[21]) + (current_base_row[24])))))
-                + ((node_874)
-                    * ((next_base_row[21]) - (((node_689) - (node_690)) - (node_692)))))
-                + ((node_876)
-                    * ((((node_662) - (node_710)) - (node_712)) - (BFieldElement::new(1)))))
-                + ((node_879) * (node_661)))
-                + ((node_881) * (node_392)))
-                + ((node_883) * (node_392)))
-                * (node_3965))
-                + ((node_391) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_393))
-                + ((node_757) * (node_421)))
-                + ((node_765) * (node_393)))
-                + ((node_770) * (node_450)))
-                + ((node_775) * ((node_449) * (node_421))))
-                + ((node_780) * (node_393)))
-                + ((node_785) * (node_504)))
-                + ((node_789)
-                    * (((next_base_row[19])
-                        - (current_base_row[7]))
-                        - (BFieldElement::new(2)))))
-                + ((node_793)
-                    * ((next_base_row[7]) - (current_base_row[19]))))
-                + ((node_798) * (node_393)))
-                + ((node_802) * (node_392)))
-                + ((node_805) * (node_392)))
-                + ((node_808)
-                    * ((next_base_row[21]) - (next_base_row[44]))))
-                + ((node_812)
-                    * ((next_base_row[44]) - (current_base_row[21]))))
-                + ((node_815) * (node_393)))
-                + ((node_818)
-                    * ((((next_base_row[31]) * (BFieldElement::new(2)))
-                        + (current_base_row[39]))
-                        - (current_base_row[31]))))
-                + ((node_821)
-                    * ((current_base_row[27]) - (current_base_row[22]))))
-                + ((node_823) * (node_393)))
-                + ((node_825) * (node_393)))
-                + ((node_827) * (node_393)))
-                + ((node_830) * (node_392)))
-                + ((node_833) * (node_392)))
-                + ((node_835) * (node_392)))
-                + ((node_838) * ((node_664) * (node_666))))
-                + ((node_845)
-                    * ((next_base_row[21])
-                        * (((current_base_row[39])
-                            * ((next_base_row[22])
-                                - (BFieldElement::new(4294967295))))
-                            - (BFieldElement::new(1))))))
-                + ((node_850) * (node_393)))
-                + ((node_854) * (node_393)))
-                + ((node_858) * (node_393)))
-                + ((node_861) * (node_393)))
-                + ((node_864) * (node_393)))
-                + ((node_867) * (node_553)))
-                + ((node_870) * (node_393)))
-                + ((node_872)
-                    * ((next_base_row[22])
-                        - ((current_base_row[22]) + (current_base_row[25])))))
-                + ((node_874)
-                    * ((next_base_row[22])
-                        - ((((((current_base_row[22]) * (current_base_row[24]))
-                            + ((current_base_row[21]) * (current_base_row[25])))
-                            - (node_698))
-                            + (node_690))
-                            + (node_692)))))
-                + ((node_876)
-                    * ((((((current_base_row[22]) * (next_base_row[21]))
-                        + ((current_base_row[21]) * (next_base_row[22])))
-                        - (node_718))
-                        + (node_710))
-                        + (node_712))))
-                + ((node_879)
-                    * ((next_base_row[22])
-                        - ((current_base_row[21]) * (current_base_row[23])))))
-                + ((node_881) * (node_393)))
-                + ((node_883) * (node_393)))
-                * (node_3965))
-                + ((node_620) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_394))
-                + ((node_757) * (node_422)))
-                + ((node_765) * (node_394)))
-                + ((node_770) * (node_454)))
-                + ((node_775)
-                    * ((node_452)
-                        * ((next_base_row[23])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_394)))
-                + ((node_785)
-                    * ((((node_392) * (current_base_row[21]))
-                        + (((node_439) * (node_600))
-                            * (node_503)))
-                        + ((((node_391)
-                            - (BFieldElement::new(3)))
-                            * (node_600))
-                            * (current_base_row[39])))))
-                + ((node_789)
-                    * ((next_base_row[20])
-                        - (current_base_row[9]))))
-                + ((node_793) * (node_447)))
-                + ((node_798) * (node_394)))
-                + ((node_802) * (node_393)))
-                + ((node_805) * (node_393)))
-                + ((node_808) * (node_392)))
-                + ((node_812) * (node_392)))
-                + ((node_815) * (node_394)))
-                + ((node_818)
-                    * (((node_445)
-                        * ((current_base_row[26]) - (next_base_row[21])))
-                        + ((current_base_row[39])
-                            * ((current_base_row[26])
-                                - (next_base_row[26]))))))
-                + ((node_821)
+                + ((node_1077) * (node_892)))
+                + ((node_1080)
+                    * ((next_base_row[45]) - (current_base_row[23]))))
+                + ((node_1084)
+                    * ((next_base_row[45]) - (current_base_row[24]))))
+                + ((node_1087) * (node_664)))
+                + ((node_1090) * (node_776)))
+                + ((node_1093)
                     * ((current_base_row[28]) - (current_base_row[23]))))
-                + ((node_823) * (node_394)))
-                + ((node_825) * (node_394)))
-                + ((node_827) * (node_394)))
-                + ((node_830) * (node_393)))
-                + ((node_833) * (node_393)))
-                + ((node_835) * (node_393)))
-                + ((node_838)
-                    * ((next_base_row[21])
-                        - ((BFieldElement::new(1)) - (node_665)))))
-                + ((node_845) * (node_422)))
-                + ((node_850) * (node_394)))
-                + ((node_854) * (node_394)))
-                + ((node_858) * (node_394)))
-                + ((node_861) * (node_394)))
-                + ((node_864) * (node_394)))
-                + ((node_867) * (node_392)))
-                + ((node_870) * (node_394)))
-                + ((node_872)
+                + ((node_1095) * (node_664)))
+                + ((node_1097) * (node_664)))
+                + ((node_1099) * (node_664)))
+                + ((node_1102) * ((next_base_row[23]) - (node_930))))
+                + ((node_1105) * (node_933)))
+                + ((node_1107) * ((node_934) - (BFieldElement::new(1)))))
+                + ((node_1110) * ((current_base_row[41]) * (node_938))))
+                + ((node_1117)
+                    * ((current_base_row[23])
+                        - (((BFieldElement::new(4294967296))
+                            * (next_base_row[24]))
+                            + (next_base_row[23])))))
+                + ((node_1122) * (node_664)))
+                + ((node_1126) * (node_664)))
+                + ((node_1130) * (node_664)))
+                + ((node_1133) * (node_664)))
+                + ((node_1136) * (node_664)))
+                + ((node_1139)
+                    * (((current_base_row[23]) - (node_952)) - (next_base_row[23]))))
+                + ((node_1142) * (node_664)))
+                + ((node_1144)
                     * ((next_base_row[23])
                         - ((current_base_row[23]) + (current_base_row[26])))))
-                + ((node_874)
-                    * ((next_base_row[23])
-                        - (((((current_base_row[23]) * (current_base_row[24]))
-                            + ((current_base_row[22]) * (current_base_row[25])))
-                            + ((current_base_row[21]) * (current_base_row[26])))
-                            + (node_698)))))
-                + ((node_876)
-                    * (((((current_base_row[23]) * (next_base_row[21])) + (node_680))
-                        + ((current_base_row[21]) * (next_base_row[23])))
-                        + (node_718))))
-                + ((node_879) * ((next_base_row[23]) - (node_689))))
-                + ((node_881) * (node_394)))
-                + ((node_883) * (node_394)))
-                * (node_3965))
-                + (((next_base_row[9]) - (current_base_row[9])) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_395))
-                + ((node_757) * (node_423)))
-                + ((node_765) * (node_395)))
-                + ((node_770) * (node_457)))
-                + ((node_775)
-                    * ((node_455)
-                        * ((next_base_row[24])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_395)))
-                + ((node_785) * (node_393)))
-                + ((node_789)
-                    * ((next_base_row[7]) - (current_base_row[9]))))
-                + ((node_793) * (node_550)))
-                + ((node_798) * (node_395)))
-                + ((node_802) * (node_394)))
-                + ((node_805) * (node_394)))
-                + ((node_808) * (node_393)))
-                + ((node_812) * (node_393)))
-                + ((node_815) * (node_395)))
-                + ((node_818)
-                    * (((node_445)
-                        * ((current_base_row[27]) - (next_base_row[22])))
-                        + ((current_base_row[39])
-                            * ((current_base_row[27])
-                                - (next_base_row[27]))))))
-                + ((node_821)
+                + ((node_1146)
+                    * ((next_base_row[23]) - (((node_961) - (node_962)) - (node_964)))))
+                + ((node_1148)
+                    * ((((node_934) - (node_982)) - (node_984)) - (BFieldElement::new(1)))))
+                + ((node_1151) * (node_933)))
+                + ((node_1153) * (node_664)))
+                + ((node_1155) * (node_664)))
+                * (node_4237))
+                + ((node_663) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_665))
+                + ((node_1029) * (node_693)))
+                + ((node_1037) * (node_665)))
+                + ((node_1042) * (node_722)))
+                + ((node_1047) * ((node_721) * (node_693))))
+                + ((node_1052) * (node_665)))
+                + ((node_1057) * (node_776)))
+                + ((node_1061)
+                    * (((next_base_row[21])
+                        - (current_base_row[9]))
+                        - (BFieldElement::new(2)))))
+                + ((node_1065)
+                    * ((next_base_row[9]) - (current_base_row[21]))))
+                + ((node_1070) * (node_665)))
+                + ((node_1074) * (node_664)))
+                + ((node_1077) * (node_664)))
+                + ((node_1080)
+                    * ((next_base_row[23]) - (next_base_row[46]))))
+                + ((node_1084)
+                    * ((next_base_row[46]) - (current_base_row[23]))))
+                + ((node_1087) * (node_665)))
+                + ((node_1090)
+                    * ((((next_base_row[33]) * (BFieldElement::new(2)))
+                        + (current_base_row[41]))
+                        - (current_base_row[33]))))
+                + ((node_1093)
                     * ((current_base_row[29]) - (current_base_row[24]))))
-                + ((node_823) * (node_395)))
-                + ((node_825) * (node_395)))
-                + ((node_827) * (node_395)))
-                + ((node_830) * (node_394)))
-                + ((node_833) * (node_394)))
-                + ((node_835) * (node_394)))
-                + ((node_838) * (node_392)))
-                + ((node_845) * (node_423)))
-                + ((node_850) * (node_395)))
-                + ((node_854) * (node_395)))
-                + ((node_858) * (node_395)))
-                + ((node_861) * (node_395)))
-                + ((node_864) * (node_395)))
-                + ((node_867) * (node_393)))
-                + ((node_870) * (node_395)))
-                + ((node_872) * (node_556)))
-                + ((node_874) * (node_556)))
-                + ((node_876) * (node_556)))
-                + ((node_879) * (node_399)))
-                + ((node_881) * (node_395)))
-                + ((node_883) * (node_395)))
-                * (node_3965))
-                + ((node_393) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_396))
-                + ((node_757) * (node_424)))
-                + ((node_765) * (node_421)))
-                + ((node_770) * (node_462)))
-                + ((node_775)
-                    * ((node_460)
+                + ((node_1095) * (node_665)))
+                + ((node_1097) * (node_665)))
+                + ((node_1099) * (node_665)))
+                + ((node_1102) * (node_664)))
+                + ((node_1105) * (node_664)))
+                + ((node_1107) * (node_664)))
+                + ((node_1110) * ((node_936) * (node_938))))
+                + ((node_1117)
+                    * ((next_base_row[23])
+                        * (((current_base_row[41])
+                            * ((next_base_row[24])
+                                - (BFieldElement::new(4294967295))))
+                            - (BFieldElement::new(1))))))
+                + ((node_1122) * (node_665)))
+                + ((node_1126) * (node_665)))
+                + ((node_1130) * (node_665)))
+                + ((node_1133) * (node_665)))
+                + ((node_1136) * (node_665)))
+                + ((node_1139) * (node_825)))
+                + ((node_1142) * (node_665)))
+                + ((node_1144)
+                    * ((next_base_row[24])
+                        - ((current_base_row[24]) + (current_base_row[27])))))
+                + ((node_1146)
+                    * ((next_base_row[24])
+                        - ((((((current_base_row[24]) * (current_base_row[26]))
+                            + ((current_base_row[23]) * (current_base_row[27])))
+                            - (node_970))
+                            + (node_962))
+                            + (node_964)))))
+                + ((node_1148)
+                    * ((((((current_base_row[24]) * (next_base_row[23]))
+                        + ((current_base_row[23]) * (next_base_row[24])))
+                        - (node_990))
+                        + (node_982))
+                        + (node_984))))
+                + ((node_1151)
+                    * ((next_base_row[24])
+                        - ((current_base_row[23]) * (current_base_row[25])))))
+                + ((node_1153) * (node_665)))
+                + ((node_1155) * (node_665)))
+                * (node_4237))
+                + ((node_892) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_666))
+                + ((node_1029) * (node_694)))
+                + ((node_1037) * (node_666)))
+                + ((node_1042) * (node_726)))
+                + ((node_1047)
+                    * ((node_724)
                         * ((next_base_row[25])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_447)))
-                + ((node_785) * (node_394)))
-                + ((node_789) * (node_447)))
-                + ((node_793) * (node_553)))
-                + ((node_798) * (node_447)))
-                + ((node_802) * (node_395)))
-                + ((node_805) * (node_395)))
-                + ((node_808) * (node_394)))
-                + ((node_812) * (node_394)))
-                + ((node_815) * (node_577)))
-                + ((node_818)
-                    * (((node_445)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_666)))
+                + ((node_1057)
+                    * ((((node_664) * (current_base_row[23]))
+                        + (((node_711) * (node_872))
+                            * (node_775)))
+                        + ((((node_663)
+                            - (BFieldElement::new(3)))
+                            * (node_872))
+                            * (current_base_row[41])))))
+                + ((node_1061)
+                    * ((next_base_row[22])
+                        - (current_base_row[11]))))
+                + ((node_1065) * (node_719)))
+                + ((node_1070) * (node_666)))
+                + ((node_1074) * (node_665)))
+                + ((node_1077) * (node_665)))
+                + ((node_1080) * (node_664)))
+                + ((node_1084) * (node_664)))
+                + ((node_1087) * (node_666)))
+                + ((node_1090)
+                    * (((node_717)
                         * ((current_base_row[28]) - (next_base_row[23])))
-                        + ((current_base_row[39])
+                        + ((current_base_row[41])
                             * ((current_base_row[28])
                                 - (next_base_row[28]))))))
-                + ((node_821)
+                + ((node_1093)
                     * ((current_base_row[30]) - (current_base_row[25]))))
-                + ((node_823) * (node_447)))
-                + ((node_825) * (node_447)))
-                + ((node_827) * (node_577)))
-                + ((node_830) * (node_395)))
-                + ((node_833) * (node_395)))
-                + ((node_835) * (node_395)))
-                + ((node_838) * (node_393)))
-                + ((node_845) * (node_424)))
-                + ((node_850) * (node_397)))
-                + ((node_854) * (node_397)))
-                + ((node_858) * (node_397)))
-                + ((node_861) * (node_550)))
-                + ((node_864) * (node_397)))
-                + ((node_867) * (node_394)))
-                + ((node_870) * (node_550)))
-                + ((node_872) * (node_559)))
-                + ((node_874) * (node_559)))
-                + ((node_876) * (node_559)))
-                + ((node_879) * (node_400)))
-                + ((node_881) * (node_421)))
-                + ((node_883) * (node_396)))
-                * (node_3965))
-                + ((node_394) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_397))
-                + ((node_757) * (node_425)))
-                + ((node_765) * (node_422)))
-                + ((node_770) * (node_465)))
-                + ((node_775)
-                    * ((node_463)
+                + ((node_1095) * (node_666)))
+                + ((node_1097) * (node_666)))
+                + ((node_1099) * (node_666)))
+                + ((node_1102) * (node_665)))
+                + ((node_1105) * (node_665)))
+                + ((node_1107) * (node_665)))
+                + ((node_1110)
+                    * ((next_base_row[23])
+                        - ((BFieldElement::new(1)) - (node_937)))))
+                + ((node_1117) * (node_694)))
+                + ((node_1122) * (node_666)))
+                + ((node_1126) * (node_666)))
+                + ((node_1130) * (node_666)))
+                + ((node_1133) * (node_666)))
+                + ((node_1136) * (node_666)))
+                + ((node_1139) * (node_664)))
+                + ((node_1142) * (node_666)))
+                + ((node_1144)
+                    * ((next_base_row[25])
+                        - ((current_base_row[25]) + (current_base_row[28])))))
+                + ((node_1146)
+                    * ((next_base_row[25])
+                        - (((((current_base_row[25]) * (current_base_row[26]))
+                            + ((current_base_row[24]) * (current_base_row[27])))
+                            + ((current_base_row[23]) * (current_base_row[28])))
+                            + (node_970)))))
+                + ((node_1148)
+                    * (((((current_base_row[25]) * (next_base_row[23])) + (node_952))
+                        + ((current_base_row[23]) * (next_base_row[25])))
+                        + (node_990))))
+                + ((node_1151) * ((next_base_row[25]) - (node_961))))
+                + ((node_1153) * (node_666)))
+                + ((node_1155) * (node_666)))
+                * (node_4237))
+                + (((next_base_row[11]) - (current_base_row[11])) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_667))
+                + ((node_1029) * (node_695)))
+                + ((node_1037) * (node_667)))
+                + ((node_1042) * (node_729)))
+                + ((node_1047)
+                    * ((node_727)
                         * ((next_base_row[26])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_550)))
-                + ((node_785) * (node_395)))
-                + ((node_789) * (node_550)))
-                + ((node_793) * (node_556)))
-                + ((node_798) * (node_550)))
-                + ((node_802) * (node_396)))
-                + ((node_805) * (node_447)))
-                + ((node_808) * (node_395)))
-                + ((node_812) * (node_395)))
-                + ((node_815) * (node_580)))
-                + ((node_818)
-                    * (((node_445)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_667)))
+                + ((node_1057) * (node_665)))
+                + ((node_1061)
+                    * ((next_base_row[9])
+                        - (current_base_row[11]))))
+                + ((node_1065) * (node_822)))
+                + ((node_1070) * (node_667)))
+                + ((node_1074) * (node_666)))
+                + ((node_1077) * (node_666)))
+                + ((node_1080) * (node_665)))
+                + ((node_1084) * (node_665)))
+                + ((node_1087) * (node_667)))
+                + ((node_1090)
+                    * (((node_717)
                         * ((current_base_row[29]) - (next_base_row[24])))
-                        + ((current_base_row[39])
+                        + ((current_base_row[41])
                             * ((current_base_row[29])
                                 - (next_base_row[29]))))))
-                + ((node_821) * (node_392)))
-                + ((node_823) * (node_550)))
-                + ((node_825) * (node_550)))
-                + ((node_827) * (node_580)))
-                + ((node_830) * (node_397)))
-                + ((node_833) * (node_397)))
-                + ((node_835) * (node_550)))
-                + ((node_838) * (node_394)))
-                + ((node_845) * (node_425)))
-                + ((node_850) * (node_398)))
-                + ((node_854) * (node_398)))
-                + ((node_858) * (node_398)))
-                + ((node_861) * (node_553)))
-                + ((node_864) * (node_398)))
-                + ((node_867) * (node_395)))
-                + ((node_870) * (node_553)))
-                + ((node_872) * (node_562)))
-                + ((node_874) * (node_562)))
-                + ((node_876) * (node_562)))
-                + ((node_879) * (node_401)))
-                + ((node_881) * (node_422)))
-                + ((node_883) * (node_397)))
-                * (node_3965))
-                + ((node_395) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_398))
-                + ((node_757) * (node_426)))
-                + ((node_765) * (node_423)))
-                + ((node_770) * (node_469)))
-                + ((node_775)
-                    * ((node_467)
+                + ((node_1093)
+                    * ((current_base_row[31]) - (current_base_row[26]))))
+                + ((node_1095) * (node_667)))
+                + ((node_1097) * (node_667)))
+                + ((node_1099) * (node_667)))
+                + ((node_1102) * (node_666)))
+                + ((node_1105) * (node_666)))
+                + ((node_1107) * (node_666)))
+                + ((node_1110) * (node_664)))
+                + ((node_1117) * (node_695)))
+                + ((node_1122) * (node_667)))
+                + ((node_1126) * (node_667)))
+                + ((node_1130) * (node_667)))
+                + ((node_1133) * (node_667)))
+                + ((node_1136) * (node_667)))
+                + ((node_1139) * (node_665)))
+                + ((node_1142) * (node_667)))
+                + ((node_1144) * (node_828)))
+                + ((node_1146) * (node_828)))
+                + ((node_1148) * (node_828)))
+                + ((node_1151) * (node_671)))
+                + ((node_1153) * (node_667)))
+                + ((node_1155) * (node_667)))
+                * (node_4237))
+                + ((node_665) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_668))
+                + ((node_1029) * (node_696)))
+                + ((node_1037) * (node_693)))
+                + ((node_1042) * (node_734)))
+                + ((node_1047)
+                    * ((node_732)
                         * ((next_base_row[27])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_553)))
-                + ((node_785) * (node_396)))
-                + ((node_789) * (node_553)))
-                + ((node_793) * (node_559)))
-                + ((node_798) * (node_553)))
-                + ((node_802) * (node_397)))
-                + ((node_805) * (node_550)))
-                + ((node_808) * (node_421)))
-                + ((node_812) * (node_396)))
-                + ((node_815) * (node_583)))
-                + ((node_818)
-                    * (((node_445)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_719)))
+                + ((node_1057) * (node_666)))
+                + ((node_1061) * (node_719)))
+                + ((node_1065) * (node_825)))
+                + ((node_1070) * (node_719)))
+                + ((node_1074) * (node_667)))
+                + ((node_1077) * (node_667)))
+                + ((node_1080) * (node_666)))
+                + ((node_1084) * (node_666)))
+                + ((node_1087) * (node_849)))
+                + ((node_1090)
+                    * (((node_717)
                         * ((current_base_row[30]) - (next_base_row[25])))
-                        + ((current_base_row[39])
+                        + ((current_base_row[41])
                             * ((current_base_row[30])
                                 - (next_base_row[30]))))))
-                + ((node_821) * (node_393)))
-                + ((node_823) * (node_553)))
-                + ((node_825) * (node_553)))
-                + ((node_827) * (node_583)))
-                + ((node_830) * (node_398)))
-                + ((node_833) * (node_398)))
-                + ((node_835) * (node_553)))
-                + ((node_838) * (node_395)))
-                + ((node_845) * (node_426)))
-                + ((node_850) * (node_399)))
-                + ((node_854) * (node_399)))
-                + ((node_858) * (node_399)))
-                + ((node_861) * (node_556)))
-                + ((node_864) * (node_399)))
-                + ((node_867) * (node_556)))
-                + ((node_870) * (node_556)))
-                + ((node_872) * (node_565)))
-                + ((node_874) * (node_565)))
-                + ((node_876) * (node_565)))
-                + ((node_879) * (node_402)))
-                + ((node_881) * (node_423)))
-                + ((node_883) * (node_398)))
-                * (node_3965))
-                + ((node_447) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_399))
-                + ((node_757) * (node_427)))
-                + ((node_765) * (node_424)))
-                + ((node_770) * (node_472)))
-                + ((node_775)
-                    * ((node_470)
+                + ((node_1093)
+                    * ((current_base_row[32]) - (current_base_row[27]))))
+                + ((node_1095) * (node_719)))
+                + ((node_1097) * (node_719)))
+                + ((node_1099) * (node_849)))
+                + ((node_1102) * (node_667)))
+                + ((node_1105) * (node_667)))
+                + ((node_1107) * (node_667)))
+                + ((node_1110) * (node_665)))
+                + ((node_1117) * (node_696)))
+                + ((node_1122) * (node_669)))
+                + ((node_1126) * (node_669)))
+                + ((node_1130) * (node_669)))
+                + ((node_1133) * (node_822)))
+                + ((node_1136) * (node_669)))
+                + ((node_1139) * (node_666)))
+                + ((node_1142) * (node_822)))
+                + ((node_1144) * (node_831)))
+                + ((node_1146) * (node_831)))
+                + ((node_1148) * (node_831)))
+                + ((node_1151) * (node_672)))
+                + ((node_1153) * (node_693)))
+                + ((node_1155) * (node_668)))
+                * (node_4237))
+                + ((node_666) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_669))
+                + ((node_1029) * (node_697)))
+                + ((node_1037) * (node_694)))
+                + ((node_1042) * (node_737)))
+                + ((node_1047)
+                    * ((node_735)
                         * ((next_base_row[28])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_556)))
-                + ((node_785) * (node_397)))
-                + ((node_789) * (node_556)))
-                + ((node_793) * (node_562)))
-                + ((node_798) * (node_556)))
-                + ((node_802) * (node_398)))
-                + ((node_805) * (node_553)))
-                + ((node_808) * (node_422)))
-                + ((node_812) * (node_397)))
-                + ((node_815) * (node_586)))
-                + ((node_818) * (node_580)))
-                + ((node_821) * (node_394)))
-                + ((node_823) * (node_556)))
-                + ((node_825) * (node_556)))
-                + ((node_827) * (node_586)))
-                + ((node_830) * (node_399)))
-                + ((node_833) * (node_399)))
-                + ((node_835) * (node_556)))
-                + ((node_838) * (node_397)))
-                + ((node_845) * (node_427)))
-                + ((node_850) * (node_400)))
-                + ((node_854) * (node_400)))
-                + ((node_858) * (node_400)))
-                + ((node_861) * (node_559)))
-                + ((node_864) * (node_400)))
-                + ((node_867) * (node_559)))
-                + ((node_870) * (node_559)))
-                + ((node_872) * (node_568)))
-                + ((node_874) * (node_568)))
-                + ((node_876) * (node_568)))
-                + ((node_879) * (node_403)))
-                + ((node_881) * (node_424)))
-                + ((node_883) * (node_399)))
-                * (node_3965))
-                + ((node_550) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_400))
-                + ((node_757) * (node_428)))
-                + ((node_765) * (node_425)))
-                + ((node_770) * (node_477)))
-                + ((node_775)
-                    * ((node_475)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_822)))
+                + ((node_1057) * (node_667)))
+                + ((node_1061) * (node_822)))
+                + ((node_1065) * (node_828)))
+                + ((node_1070) * (node_822)))
+                + ((node_1074) * (node_668)))
+                + ((node_1077) * (node_719)))
+                + ((node_1080) * (node_667)))
+                + ((node_1084) * (node_667)))
+                + ((node_1087) * (node_852)))
+                + ((node_1090)
+                    * (((node_717)
+                        * ((current_base_row[31]) - (next_base_row[26])))
+                        + ((current_base_row[41])
+                            * ((current_base_row[31])
+                                - (next_base_row[31]))))))
+                + ((node_1093) * (node_664)))
+                + ((node_1095) * (node_822)))
+                + ((node_1097) * (node_822)))
+                + ((node_1099) * (node_852)))
+                + ((node_1102) * (node_669)))
+                + ((node_1105) * (node_669)))
+                + ((node_1107) * (node_822)))
+                + ((node_1110) * (node_666)))
+                + ((node_1117) * (node_697)))
+                + ((node_1122) * (node_670)))
+                + ((node_1126) * (node_670)))
+                + ((node_1130) * (node_670)))
+                + ((node_1133) * (node_825)))
+                + ((node_1136) * (node_670)))
+                + ((node_1139) * (node_667)))
+                + ((node_1142) * (node_825)))
+                + ((node_1144) * (node_834)))
+                + ((node_1146) * (node_834)))
+                + ((node_1148) * (node_834)))
+                + ((node_1151) * (node_673)))
+                + ((node_1153) * (node_694)))
+                + ((node_1155) * (node_669)))
+                * (node_4237))
+                + ((node_667) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_670))
+                + ((node_1029) * (node_698)))
+                + ((node_1037) * (node_695)))
+                + ((node_1042) * (node_741)))
+                + ((node_1047)
+                    * ((node_739)
                         * ((next_base_row[29])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_559)))
-                + ((node_785) * (node_398)))
-                + ((node_789) * (node_559)))
-                + ((node_793) * (node_565)))
-                + ((node_798) * (node_559)))
-                + ((node_802) * (node_399)))
-                + ((node_805) * (node_556)))
-                + ((node_808) * (node_423)))
-                + ((node_812) * (node_398)))
-                + ((node_815) * (node_589)))
-                + ((node_818) * (node_583)))
-                + ((node_821) * (node_395)))
-                + ((node_823) * (node_559)))
-                + ((node_825) * (node_559)))
-                + ((node_827) * (node_589)))
-                + ((node_830) * (node_400)))
-                + ((node_833) * (node_400)))
-                + ((node_835) * (node_559)))
-                + ((node_838) * (node_398)))
-                + ((node_845) * (node_428)))
-                + ((node_850) * (node_401)))
-                + ((node_854) * (node_401)))
-                + ((node_858) * (node_401)))
-                + ((node_861) * (node_562)))
-                + ((node_864) * (node_401)))
-                + ((node_867) * (node_562)))
-                + ((node_870) * (node_562)))
-                + ((node_872) * (node_571)))
-                + ((node_874) * (node_571)))
-                + ((node_876) * (node_571)))
-                + ((node_879) * (node_404)))
-                + ((node_881) * (node_425)))
-                + ((node_883) * (node_400)))
-                * (node_3965))
-                + ((node_553) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_401))
-                + ((node_757) * (node_429)))
-                + ((node_765) * (node_426)))
-                + ((node_770) * (node_480)))
-                + ((node_775)
-                    * ((node_478)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_825)))
+                + ((node_1057) * (node_668)))
+                + ((node_1061) * (node_825)))
+                + ((node_1065) * (node_831)))
+                + ((node_1070) * (node_825)))
+                + ((node_1074) * (node_669)))
+                + ((node_1077) * (node_822)))
+                + ((node_1080) * (node_693)))
+                + ((node_1084) * (node_668)))
+                + ((node_1087) * (node_855)))
+                + ((node_1090)
+                    * (((node_717)
+                        * ((current_base_row[32]) - (next_base_row[27])))
+                        + ((current_base_row[41])
+                            * ((current_base_row[32])
+                                - (next_base_row[32]))))))
+                + ((node_1093) * (node_665)))
+                + ((node_1095) * (node_825)))
+                + ((node_1097) * (node_825)))
+                + ((node_1099) * (node_855)))
+                + ((node_1102) * (node_670)))
+                + ((node_1105) * (node_670)))
+                + ((node_1107) * (node_825)))
+                + ((node_1110) * (node_667)))
+                + ((node_1117) * (node_698)))
+                + ((node_1122) * (node_671)))
+                + ((node_1126) * (node_671)))
+                + ((node_1130) * (node_671)))
+                + ((node_1133) * (node_828)))
+                + ((node_1136) * (node_671)))
+                + ((node_1139) * (node_828)))
+                + ((node_1142) * (node_828)))
+                + ((node_1144) * (node_837)))
+                + ((node_1146) * (node_837)))
+                + ((node_1148) * (node_837)))
+                + ((node_1151) * (node_674)))
+                + ((node_1153) * (node_695)))
+                + ((node_1155) * (node_670)))
+                * (node_4237))
+                + ((node_719) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_671))
+                + ((node_1029) * (node_699)))
+                + ((node_1037) * (node_696)))
+                + ((node_1042) * (node_744)))
+                + ((node_1047)
+                    * ((node_742)
                         * ((next_base_row[30])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_562)))
-                + ((node_785) * (node_399)))
-                + ((node_789) * (node_562)))
-                + ((node_793) * (node_568)))
-                + ((node_798) * (node_562)))
-                + ((node_802) * (node_400)))
-                + ((node_805) * (node_559)))
-                + ((node_808) * (node_424)))
-                + ((node_812) * (node_399)))
-                + ((node_815) * (node_592)))
-                + ((node_818) * (node_586)))
-                + ((node_821) * (node_447)))
-                + ((node_823) * (node_562)))
-                + ((node_825) * (node_562)))
-                + ((node_827) * (node_592)))
-                + ((node_830) * (node_401)))
-                + ((node_833) * (node_401)))
-                + ((node_835) * (node_562)))
-                + ((node_838) * (node_399)))
-                + ((node_845) * (node_429)))
-                + ((node_850) * (node_402)))
-                + ((node_854) * (node_402)))
-                + ((node_858) * (node_402)))
-                + ((node_861) * (node_565)))
-                + ((node_864) * (node_402)))
-                + ((node_867) * (node_565)))
-                + ((node_870) * (node_565)))
-                + ((node_872) * (node_574)))
-                + ((node_874) * (node_574)))
-                + ((node_876) * (node_574)))
-                + ((node_879) * (node_405)))
-                + ((node_881) * (node_426)))
-                + ((node_883) * (node_401)))
-                * (node_3965))
-                + ((node_556) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_402))
-                + ((node_757) * (node_430)))
-                + ((node_765) * (node_427)))
-                + ((node_770) * (node_484)))
-                + ((node_775)
-                    * ((node_482)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_828)))
+                + ((node_1057) * (node_669)))
+                + ((node_1061) * (node_828)))
+                + ((node_1065) * (node_834)))
+                + ((node_1070) * (node_828)))
+                + ((node_1074) * (node_670)))
+                + ((node_1077) * (node_825)))
+                + ((node_1080) * (node_694)))
+                + ((node_1084) * (node_669)))
+                + ((node_1087) * (node_858)))
+                + ((node_1090) * (node_852)))
+                + ((node_1093) * (node_666)))
+                + ((node_1095) * (node_828)))
+                + ((node_1097) * (node_828)))
+                + ((node_1099) * (node_858)))
+                + ((node_1102) * (node_671)))
+                + ((node_1105) * (node_671)))
+                + ((node_1107) * (node_828)))
+                + ((node_1110) * (node_669)))
+                + ((node_1117) * (node_699)))
+                + ((node_1122) * (node_672)))
+                + ((node_1126) * (node_672)))
+                + ((node_1130) * (node_672)))
+                + ((node_1133) * (node_831)))
+                + ((node_1136) * (node_672)))
+                + ((node_1139) * (node_831)))
+                + ((node_1142) * (node_831)))
+                + ((node_1144) * (node_840)))
+                + ((node_1146) * (node_840)))
+                + ((node_1148) * (node_840)))
+                + ((node_1151) * (node_675)))
+                + ((node_1153) * (node_696)))
+                + ((node_1155) * (node_671)))
+                * (node_4237))
+                + ((node_822) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_672))
+                + ((node_1029) * (node_700)))
+                + ((node_1037) * (node_697)))
+                + ((node_1042) * (node_749)))
+                + ((node_1047)
+                    * ((node_747)
                         * ((next_base_row[31])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_565)))
-                + ((node_785) * (node_400)))
-                + ((node_789) * (node_565)))
-                + ((node_793) * (node_571)))
-                + ((node_798) * (node_565)))
-                + ((node_802) * (node_401)))
-                + ((node_805) * (node_562)))
-                + ((node_808) * (node_425)))
-                + ((node_812) * (node_400)))
-                + ((node_815) * (node_594)))
-                + ((node_818) * (node_589)))
-                + ((node_821) * (node_550)))
-                + ((node_823) * (node_565)))
-                + ((node_825) * (node_565)))
-                + ((node_827) * (node_594)))
-                + ((node_830) * (node_402)))
-                + ((node_833) * (node_402)))
-                + ((node_835) * (node_565)))
-                + ((node_838) * (node_400)))
-                + ((node_845) * (node_430)))
-                + ((node_850) * (node_403)))
-                + ((node_854) * (node_403)))
-                + ((node_858) * (node_403)))
-                + ((node_861) * (node_568)))
-                + ((node_864) * (node_403)))
-                + ((node_867) * (node_568)))
-                + ((node_870) * (node_568)))
-                + ((node_872) * (node_577)))
-                + ((node_874) * (node_577)))
-                + ((node_876) * (node_577)))
-                + ((node_879) * (node_406)))
-                + ((node_881) * (node_427)))
-                + ((node_883) * (node_402)))
-                * (node_3965))
-                + ((node_559) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_403))
-                + ((node_757) * (node_431)))
-                + ((node_765) * (node_428)))
-                + ((node_770) * (node_487)))
-                + ((node_775)
-                    * ((node_485)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_831)))
+                + ((node_1057) * (node_670)))
+                + ((node_1061) * (node_831)))
+                + ((node_1065) * (node_837)))
+                + ((node_1070) * (node_831)))
+                + ((node_1074) * (node_671)))
+                + ((node_1077) * (node_828)))
+                + ((node_1080) * (node_695)))
+                + ((node_1084) * (node_670)))
+                + ((node_1087) * (node_861)))
+                + ((node_1090) * (node_855)))
+                + ((node_1093) * (node_667)))
+                + ((node_1095) * (node_831)))
+                + ((node_1097) * (node_831)))
+                + ((node_1099) * (node_861)))
+                + ((node_1102) * (node_672)))
+                + ((node_1105) * (node_672)))
+                + ((node_1107) * (node_831)))
+                + ((node_1110) * (node_670)))
+                + ((node_1117) * (node_700)))
+                + ((node_1122) * (node_673)))
+                + ((node_1126) * (node_673)))
+                + ((node_1130) * (node_673)))
+                + ((node_1133) * (node_834)))
+                + ((node_1136) * (node_673)))
+                + ((node_1139) * (node_834)))
+                + ((node_1142) * (node_834)))
+                + ((node_1144) * (node_843)))
+                + ((node_1146) * (node_843)))
+                + ((node_1148) * (node_843)))
+                + ((node_1151) * (node_676)))
+                + ((node_1153) * (node_697)))
+                + ((node_1155) * (node_672)))
+                * (node_4237))
+                + ((node_825) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_673))
+                + ((node_1029) * (node_701)))
+                + ((node_1037) * (node_698)))
+                + ((node_1042) * (node_752)))
+                + ((node_1047)
+                    * ((node_750)
                         * ((next_base_row[32])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_568)))
-                + ((node_785) * (node_401)))
-                + ((node_789) * (node_568)))
-                + ((node_793) * (node_574)))
-                + ((node_798) * (node_568)))
-                + ((node_802) * (node_402)))
-                + ((node_805) * (node_565)))
-                + ((node_808) * (node_426)))
-                + ((node_812) * (node_401)))
-                + ((node_815) * (node_595)))
-                + ((node_818) * (node_592)))
-                + ((node_821) * (node_553)))
-                + ((node_823) * (node_568)))
-                + ((node_825) * (node_568)))
-                + ((node_827) * (node_595)))
-                + ((node_830) * (node_403)))
-                + ((node_833) * (node_403)))
-                + ((node_835) * (node_568)))
-                + ((node_838) * (node_401)))
-                + ((node_845) * (node_431)))
-                + ((node_850) * (node_404)))
-                + ((node_854) * (node_404)))
-                + ((node_858) * (node_404)))
-                + ((node_861) * (node_571)))
-                + ((node_864) * (node_404)))
-                + ((node_867) * (node_571)))
-                + ((node_870) * (node_571)))
-                + ((node_872) * (node_580)))
-                + ((node_874) * (node_580)))
-                + ((node_876) * (node_580)))
-                + ((node_879) * (node_407)))
-                + ((node_881) * (node_428)))
-                + ((node_883) * (node_403)))
-                * (node_3965))
-                + ((node_562) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_404))
-                + ((node_757) * (node_432)))
-                + ((node_765) * (node_429)))
-                + ((node_770) * (node_492)))
-                + ((node_775)
-                    * ((node_490)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_834)))
+                + ((node_1057) * (node_671)))
+                + ((node_1061) * (node_834)))
+                + ((node_1065) * (node_840)))
+                + ((node_1070) * (node_834)))
+                + ((node_1074) * (node_672)))
+                + ((node_1077) * (node_831)))
+                + ((node_1080) * (node_696)))
+                + ((node_1084) * (node_671)))
+                + ((node_1087) * (node_864)))
+                + ((node_1090) * (node_858)))
+                + ((node_1093) * (node_719)))
+                + ((node_1095) * (node_834)))
+                + ((node_1097) * (node_834)))
+                + ((node_1099) * (node_864)))
+                + ((node_1102) * (node_673)))
+                + ((node_1105) * (node_673)))
+                + ((node_1107) * (node_834)))
+                + ((node_1110) * (node_671)))
+                + ((node_1117) * (node_701)))
+                + ((node_1122) * (node_674)))
+                + ((node_1126) * (node_674)))
+                + ((node_1130) * (node_674)))
+                + ((node_1133) * (node_837)))
+                + ((node_1136) * (node_674)))
+                + ((node_1139) * (node_837)))
+                + ((node_1142) * (node_837)))
+                + ((node_1144) * (node_846)))
+                + ((node_1146) * (node_846)))
+                + ((node_1148) * (node_846)))
+                + ((node_1151) * (node_677)))
+                + ((node_1153) * (node_698)))
+                + ((node_1155) * (node_673)))
+                * (node_4237))
+                + ((node_828) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_674))
+                + ((node_1029) * (node_702)))
+                + ((node_1037) * (node_699)))
+                + ((node_1042) * (node_756)))
+                + ((node_1047)
+                    * ((node_754)
                         * ((next_base_row[33])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_571)))
-                + ((node_785) * (node_402)))
-                + ((node_789) * (node_571)))
-                + ((node_793) * (node_577)))
-                + ((node_798) * (node_571)))
-                + ((node_802) * (node_403)))
-                + ((node_805) * (node_568)))
-                + ((node_808) * (node_427)))
-                + ((node_812) * (node_402)))
-                + ((node_815) * (node_418)))
-                + ((node_818) * (node_594)))
-                + ((node_821) * (node_556)))
-                + ((node_823) * (node_571)))
-                + ((node_825) * (node_571)))
-                + ((node_827) * (node_418)))
-                + ((node_830) * (node_404)))
-                + ((node_833) * (node_404)))
-                + ((node_835) * (node_571)))
-                + ((node_838) * (node_402)))
-                + ((node_845) * (node_432)))
-                + ((node_850) * (node_405)))
-                + ((node_854) * (node_405)))
-                + ((node_858) * (node_405)))
-                + ((node_861) * (node_574)))
-                + ((node_864) * (node_405)))
-                + ((node_867) * (node_574)))
-                + ((node_870) * (node_574)))
-                + ((node_872) * (node_583)))
-                + ((node_874) * (node_583)))
-                + ((node_876) * (node_583)))
-                + ((node_879) * (node_408)))
-                + ((node_881) * (node_429)))
-                + ((node_883) * (node_404)))
-                * (node_3965))
-                + ((node_565) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_405))
-                + ((node_757) * (node_433)))
-                + ((node_765) * (node_430)))
-                + ((node_770) * (node_495)))
-                + ((node_775)
-                    * ((node_493)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_837)))
+                + ((node_1057) * (node_672)))
+                + ((node_1061) * (node_837)))
+                + ((node_1065) * (node_843)))
+                + ((node_1070) * (node_837)))
+                + ((node_1074) * (node_673)))
+                + ((node_1077) * (node_834)))
+                + ((node_1080) * (node_697)))
+                + ((node_1084) * (node_672)))
+                + ((node_1087) * (node_866)))
+                + ((node_1090) * (node_861)))
+                + ((node_1093) * (node_822)))
+                + ((node_1095) * (node_837)))
+                + ((node_1097) * (node_837)))
+                + ((node_1099) * (node_866)))
+                + ((node_1102) * (node_674)))
+                + ((node_1105) * (node_674)))
+                + ((node_1107) * (node_837)))
+                + ((node_1110) * (node_672)))
+                + ((node_1117) * (node_702)))
+                + ((node_1122) * (node_675)))
+                + ((node_1126) * (node_675)))
+                + ((node_1130) * (node_675)))
+                + ((node_1133) * (node_840)))
+                + ((node_1136) * (node_675)))
+                + ((node_1139) * (node_840)))
+                + ((node_1142) * (node_840)))
+                + ((node_1144) * (node_849)))
+                + ((node_1146) * (node_849)))
+                + ((node_1148) * (node_849)))
+                + ((node_1151) * (node_678)))
+                + ((node_1153) * (node_699)))
+                + ((node_1155) * (node_674)))
+                * (node_4237))
+                + ((node_831) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_675))
+                + ((node_1029) * (node_703)))
+                + ((node_1037) * (node_700)))
+                + ((node_1042) * (node_759)))
+                + ((node_1047)
+                    * ((node_757)
                         * ((next_base_row[34])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_574)))
-                + ((node_785) * (node_403)))
-                + ((node_789) * (node_574)))
-                + ((node_793) * (node_580)))
-                + ((node_798) * (node_574)))
-                + ((node_802) * (node_404)))
-                + ((node_805) * (node_571)))
-                + ((node_808) * (node_428)))
-                + ((node_812) * (node_403)))
-                + ((node_815) * (node_419)))
-                + ((node_818) * (node_595)))
-                + ((node_821) * (node_559)))
-                + ((node_823) * (node_574)))
-                + ((node_825) * (node_574)))
-                + ((node_827) * (node_419)))
-                + ((node_830) * (node_405)))
-                + ((node_833) * (node_405)))
-                + ((node_835) * (node_574)))
-                + ((node_838) * (node_403)))
-                + ((node_845) * (node_433)))
-                + ((node_850) * (node_406)))
-                + ((node_854) * (node_406)))
-                + ((node_858) * (node_406)))
-                + ((node_861) * (node_577)))
-                + ((node_864) * (node_406)))
-                + ((node_867) * (node_577)))
-                + ((node_870) * (node_577)))
-                + ((node_872) * (node_586)))
-                + ((node_874) * (node_586)))
-                + ((node_876) * (node_586)))
-                + ((node_879) * (node_409)))
-                + ((node_881) * (node_430)))
-                + ((node_883) * (node_405)))
-                * (node_3965))
-                + ((node_568) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_406))
-                + ((node_757) * (node_434)))
-                + ((node_765) * (node_431)))
-                + ((node_770) * (node_499)))
-                + ((node_775)
-                    * ((node_497)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_840)))
+                + ((node_1057) * (node_673)))
+                + ((node_1061) * (node_840)))
+                + ((node_1065) * (node_846)))
+                + ((node_1070) * (node_840)))
+                + ((node_1074) * (node_674)))
+                + ((node_1077) * (node_837)))
+                + ((node_1080) * (node_698)))
+                + ((node_1084) * (node_673)))
+                + ((node_1087) * (node_867)))
+                + ((node_1090) * (node_864)))
+                + ((node_1093) * (node_825)))
+                + ((node_1095) * (node_840)))
+                + ((node_1097) * (node_840)))
+                + ((node_1099) * (node_867)))
+                + ((node_1102) * (node_675)))
+                + ((node_1105) * (node_675)))
+                + ((node_1107) * (node_840)))
+                + ((node_1110) * (node_673)))
+                + ((node_1117) * (node_703)))
+                + ((node_1122) * (node_676)))
+                + ((node_1126) * (node_676)))
+                + ((node_1130) * (node_676)))
+                + ((node_1133) * (node_843)))
+                + ((node_1136) * (node_676)))
+                + ((node_1139) * (node_843)))
+                + ((node_1142) * (node_843)))
+                + ((node_1144) * (node_852)))
+                + ((node_1146) * (node_852)))
+                + ((node_1148) * (node_852)))
+                + ((node_1151) * (node_679)))
+                + ((node_1153) * (node_700)))
+                + ((node_1155) * (node_675)))
+                * (node_4237))
+                + ((node_834) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_676))
+                + ((node_1029) * (node_704)))
+                + ((node_1037) * (node_701)))
+                + ((node_1042) * (node_764)))
+                + ((node_1047)
+                    * ((node_762)
                         * ((next_base_row[35])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_577)))
-                + ((node_785) * (node_404)))
-                + ((node_789) * (node_577)))
-                + ((node_793) * (node_583)))
-                + ((node_798) * (node_577)))
-                + ((node_802) * (node_405)))
-                + ((node_805) * (node_574)))
-                + ((node_808) * (node_429)))
-                + ((node_812) * (node_404)))
-                + ((node_818) * (node_392)))
-                + ((node_821) * (node_562)))
-                + ((node_823) * (node_577)))
-                + ((node_825) * (node_577)))
-                + ((node_830) * (node_406)))
-                + ((node_833) * (node_406)))
-                + ((node_835) * (node_577)))
-                + ((node_838) * (node_404)))
-                + ((node_845) * (node_434)))
-                + ((node_850) * (node_407)))
-                + ((node_854) * (node_407)))
-                + ((node_858) * (node_407)))
-                + ((node_861) * (node_580)))
-                + ((node_864) * (node_407)))
-                + ((node_867) * (node_580)))
-                + ((node_870) * (node_580)))
-                + ((node_872) * (node_589)))
-                + ((node_874) * (node_589)))
-                + ((node_876) * (node_589)))
-                + ((node_879) * (node_410)))
-                + ((node_881) * (node_431)))
-                + ((node_883) * (node_406)))
-                * (node_3965))
-                + ((node_571) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_407))
-                + ((node_757) * (node_435)))
-                + ((node_765) * (node_432)))
-                + ((node_770) * (node_502)))
-                + ((node_775)
-                    * ((node_500)
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_843)))
+                + ((node_1057) * (node_674)))
+                + ((node_1061) * (node_843)))
+                + ((node_1065) * (node_849)))
+                + ((node_1070) * (node_843)))
+                + ((node_1074) * (node_675)))
+                + ((node_1077) * (node_840)))
+                + ((node_1080) * (node_699)))
+                + ((node_1084) * (node_674)))
+                + ((node_1087) * (node_690)))
+                + ((node_1090) * (node_866)))
+                + ((node_1093) * (node_828)))
+                + ((node_1095) * (node_843)))
+                + ((node_1097) * (node_843)))
+                + ((node_1099) * (node_690)))
+                + ((node_1102) * (node_676)))
+                + ((node_1105) * (node_676)))
+                + ((node_1107) * (node_843)))
+                + ((node_1110) * (node_674)))
+                + ((node_1117) * (node_704)))
+                + ((node_1122) * (node_677)))
+                + ((node_1126) * (node_677)))
+                + ((node_1130) * (node_677)))
+                + ((node_1133) * (node_846)))
+                + ((node_1136) * (node_677)))
+                + ((node_1139) * (node_846)))
+                + ((node_1142) * (node_846)))
+                + ((node_1144) * (node_855)))
+                + ((node_1146) * (node_855)))
+                + ((node_1148) * (node_855)))
+                + ((node_1151) * (node_680)))
+                + ((node_1153) * (node_701)))
+                + ((node_1155) * (node_676)))
+                * (node_4237))
+                + ((node_837) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_677))
+                + ((node_1029) * (node_705)))
+                + ((node_1037) * (node_702)))
+                + ((node_1042) * (node_767)))
+                + ((node_1047)
+                    * ((node_765)
                         * ((next_base_row[36])
-                            - (current_base_row[21])))))
-                + ((node_780) * (node_580)))
-                + ((node_785) * (node_405)))
-                + ((node_789) * (node_580)))
-                + ((node_793) * (node_586)))
-                + ((node_798) * (node_580)))
-                + ((node_802) * (node_406)))
-                + ((node_805) * (node_577)))
-                + ((node_808) * (node_430)))
-                + ((node_812) * (node_405)))
-                + ((node_818) * (node_393)))
-                + ((node_821) * (node_565)))
-                + ((node_823) * (node_580)))
-                + ((node_825) * (node_580)))
-                + ((node_830) * (node_407)))
-                + ((node_833) * (node_407)))
-                + ((node_835) * (node_580)))
-                + ((node_838) * (node_405)))
-                + ((node_845) * (node_435)))
-                + ((node_850) * (node_408)))
-                + ((node_854) * (node_408)))
-                + ((node_858) * (node_408)))
-                + ((node_861) * (node_583)))
-                + ((node_864) * (node_408)))
-                + ((node_867) * (node_583)))
-                + ((node_870) * (node_583)))
-                + ((node_872) * (node_592)))
-                + ((node_874) * (node_592)))
-                + ((node_876) * (node_592)))
-                + ((node_879) * (node_411)))
-                + ((node_881) * (node_432)))
-                + ((node_883) * (node_407)))
-                * (node_3965))
-                + ((node_574) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_408))
-                + ((node_757) * (node_436)))
-                + ((node_765) * (node_433)))
-                + ((node_770) * (node_504)))
-                + ((node_775) * (node_450)))
-                + ((node_780) * (node_583)))
-                + ((node_785) * (node_406)))
-                + ((node_789) * (node_583)))
-                + ((node_793) * (node_589)))
-                + ((node_798) * (node_583)))
-                + ((node_802) * (node_407)))
-                + ((node_805) * (node_580)))
-                + ((node_808) * (node_431)))
-                + ((node_812) * (node_406)))
-                + ((node_818) * (node_394)))
-                + ((node_821) * (node_568)))
-                + ((node_823) * (node_583)))
-                + ((node_825) * (node_583)))
-                + ((node_830) * (node_408)))
-                + ((node_833) * (node_408)))
-                + ((node_835) * (node_583)))
-                + ((node_838) * (node_406)))
-                + ((node_845) * (node_436)))
-                + ((node_850) * (node_409)))
-                + ((node_854) * (node_409)))
-                + ((node_858) * (node_409)))
-                + ((node_861) * (node_586)))
-                + ((node_864) * (node_409)))
-                + ((node_867) * (node_586)))
-                + ((node_870) * (node_586)))
-                + ((node_872) * (node_594)))
-                + ((node_874) * (node_594)))
-                + ((node_876) * (node_594)))
-                + ((node_879) * (node_413)))
-                + ((node_881) * (node_433)))
-                + ((node_883) * (node_408)))
-                * (node_3965))
-                + ((node_577) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_409))
-                + ((node_757) * (node_438)))
-                + ((node_765) * (node_434)))
-                + ((node_770) * (node_506)))
-                + ((node_775) * (node_454)))
-                + ((node_780) * (node_586)))
-                + ((node_785) * (node_407)))
-                + ((node_789) * (node_586)))
-                + ((node_793) * (node_592)))
-                + ((node_798) * (node_586)))
-                + ((node_802) * (node_408)))
-                + ((node_805) * (node_583)))
-                + ((node_808) * (node_432)))
-                + ((node_812) * (node_407)))
-                + ((node_818) * (node_395)))
-                + ((node_821) * (node_571)))
-                + ((node_823) * (node_586)))
-                + ((node_825) * (node_586)))
-                + ((node_830) * (node_409)))
-                + ((node_833) * (node_409)))
-                + ((node_835) * (node_586)))
-                + ((node_838) * (node_407)))
-                + ((node_845) * (node_438)))
-                + ((node_850) * (node_410)))
-                + ((node_854) * (node_410)))
-                + ((node_858) * (node_410)))
-                + ((node_861) * (node_589)))
-                + ((node_864) * (node_410)))
-                + ((node_867) * (node_589)))
-                + ((node_870) * (node_589)))
-                + ((node_872) * (node_595)))
-                + ((node_874) * (node_595)))
-                + ((node_876) * (node_595)))
-                + ((node_879) * (node_417)))
-                + ((node_881) * (node_434)))
-                + ((node_883) * (node_409)))
-                * (node_3965))
-                + ((node_580) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_410))
-                + ((node_757) * (node_439)))
-                + ((node_765) * (node_435)))
-                + ((node_770) * (node_508)))
-                + ((node_775) * (node_457)))
-                + ((node_780) * (node_589)))
-                + ((node_785) * (node_408)))
-                + ((node_789) * (node_589)))
-                + ((node_793) * (node_594)))
-                + ((node_798) * (node_589)))
-                + ((node_802) * (node_409)))
-                + ((node_805) * (node_586)))
-                + ((node_808) * (node_433)))
-                + ((node_812) * (node_408)))
-                + ((node_818) * (node_418)))
-                + ((node_821) * (node_574)))
-                + ((node_823) * (node_589)))
-                + ((node_825) * (node_589)))
-                + ((node_830) * (node_410)))
-                + ((node_833) * (node_410)))
-                + ((node_835) * (node_589)))
-                + ((node_838) * (node_408)))
-                + ((node_845) * (node_392)))
-                + ((node_850) * (node_411)))
-                + ((node_854) * (node_411)))
-                + ((node_858) * (node_411)))
-                + ((node_861) * (node_592)))
-                + ((node_864) * (node_411)))
-                + ((node_867) * (node_592)))
-                + ((node_870) * (node_592)))
-                + ((node_872) * (node_392)))
-                + ((node_874) * (node_392)))
-                + ((node_876) * (node_392)))
-                + ((node_879) * (node_392)))
-                + ((node_881) * (node_435)))
-                + ((node_883) * (node_410)))
-                * (node_3965))
-                + ((node_583) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((((node_747)
-                * (node_411))
-                + ((node_757) * (node_393)))
-                + ((node_765) * (node_436)))
-                + ((node_770) * (node_510)))
-                + ((node_775) * (node_462)))
-                + ((node_780) * (node_592)))
-                + ((node_785) * (node_409)))
-                + ((node_789) * (node_592)))
-                + ((node_793) * (node_595)))
-                + ((node_798) * (node_592)))
-                + ((node_802) * (node_410)))
-                + ((node_805) * (node_589)))
-                + ((node_808) * (node_434)))
-                + ((node_812) * (node_409)))
-                + ((node_818) * (node_419)))
-                + ((node_821) * (node_577)))
-                + ((node_823) * (node_592)))
-                + ((node_825) * (node_592)))
-                + ((node_830) * (node_411)))
-                + ((node_833) * (node_411)))
-                + ((node_835) * (node_592)))
-                + ((node_838) * (node_409)))
-                + ((node_845) * (node_393)))
-                + ((node_850) * (node_413)))
-                + ((node_854) * (node_413)))
-                + ((node_858) * (node_413)))
-                + ((node_861) * (node_594)))
-                + ((node_864) * (node_413)))
-                + ((node_867) * (node_594)))
-                + ((node_870) * (node_594)))
-                + ((node_872) * (node_393)))
-                + ((node_874) * (node_393)))
-                + ((node_876) * (node_393)))
-                + ((node_879) * (node_393)))
-                + ((node_881) * (node_436)))
-                + ((node_883) * (node_411)))
-                * (node_3965))
-                + ((node_586) * (next_base_row[5])),
-            (((((((((((((((((((((((((((((((((((((node_747)
-                * (node_413))
-                + ((node_757) * (node_394)))
-                + ((node_765) * (node_438)))
-                + ((node_770) * (node_519)))
-                + ((node_775) * (node_465)))
-                + ((node_780) * (node_594)))
-                + ((node_785) * (node_410)))
-                + ((node_789) * (node_594)))
-                + ((node_793) * (node_418)))
-                + ((node_798) * (node_594)))
-                + ((node_802) * (node_411)))
-                + ((node_805) * (node_592)))
-                + ((node_808) * (node_435)))
-                + ((node_812) * (node_410)))
-                + ((node_821) * (node_580)))
-                + ((node_823) * (node_594)))
-                + ((node_825) * (node_594)))
-                + ((node_830) * (node_413)))
-                + ((node_833) * (node_413)))
-                + ((node_835) * (node_594)))
-                + ((node_838) * (node_410)))
-                + ((node_845) * (node_394)))
-                + ((node_850) * (node_417)))
-                + ((node_854) * (node_417)))
-                + ((node_858) * (node_417)))
-                + ((node_861) * (node_595)))
-                + ((node_864) * (node_417)))
-                + ((node_867) * (node_595)))
-                + ((node_870) * (node_595)))
-                + ((node_872) * (node_394)))
-                + ((node_874) * (node_394)))
-                + ((node_876) * (node_394)))
-                + ((node_879) * (node_394)))
-                + ((node_881) * (node_438)))
-                + ((node_883) * (node_413)))
-                * (node_3965))
-                + ((node_589) * (next_base_row[5])),
-            (((((((((((((((((((((((((((((((((((((node_747)
-                * (node_417))
-                + ((node_757) * (node_395)))
-                + ((node_765) * (node_418)))
-                + ((node_770) * (node_439)))
-                + ((node_775) * (node_469)))
-                + ((node_780) * (node_595)))
-                + ((node_785) * (node_411)))
-                + ((node_789) * (node_595)))
-                + ((node_793) * (node_419)))
-                + ((node_798) * (node_595)))
-                + ((node_802) * (node_413)))
-                + ((node_805) * (node_594)))
-                + ((node_808) * (node_436)))
-                + ((node_812) * (node_411)))
-                + ((node_821) * (node_583)))
-                + ((node_823) * (node_595)))
-                + ((node_825) * (node_595)))
-                + ((node_830) * (node_417)))
-                + ((node_833) * (node_417)))
-                + ((node_835) * (node_595)))
-                + ((node_838) * (node_411)))
-                + ((node_845) * (node_395)))
-                + ((node_850) * (node_418)))
-                + ((node_854) * (node_418)))
-                + ((node_858) * (node_418)))
-                + ((node_861) * (node_418)))
-                + ((node_864) * (node_418)))
-                + ((node_867) * (node_418)))
-                + ((node_870) * (node_418)))
-                + ((node_872) * (node_395)))
-                + ((node_874) * (node_395)))
-                + ((node_876) * (node_395)))
-                + ((node_879) * (node_395)))
-                + ((node_881) * (node_418)))
-                + ((node_883) * (node_417)))
-                * (node_3965))
-                + ((node_592) * (next_base_row[5])),
-            ((((((((((((((((((((((((((((((((((((node_747)
-                * (node_418))
-                + ((node_757) * (node_418)))
-                + ((node_765) * (node_419)))
-                + ((node_770) * (node_393)))
-                + ((node_775) * (node_472)))
-                + ((node_780) * (node_418)))
-                + ((node_785) * (node_413)))
-                + ((node_789) * (node_418)))
-                + ((node_798) * (node_418)))
-                + ((node_802) * (node_417)))
-                + ((node_805) * (node_595)))
-                + ((node_808) * (node_438)))
-                + ((node_812) * (node_413)))
-                + ((node_821) * (node_586)))
-                + ((node_823) * (node_418)))
-                + ((node_825) * (node_418)))
-                + ((node_830) * (node_418)))
-                + ((node_833) * (node_418)))
-                + ((node_835) * (node_418)))
-                + ((node_838) * (node_413)))
-                + ((node_845) * (node_418)))
-                + ((node_850) * (node_419)))
-                + ((node_854) * (node_419)))
-                + ((node_858) * (node_419)))
-                + ((node_861) * (node_419)))
-                + ((node_864) * (node_419)))
-                + ((node_867) * (node_419)))
-                + ((node_870) * (node_419)))
-                + ((node_872) * (node_418)))
-                + ((node_874) * (node_418)))
-                + ((node_876) * (node_418)))
-                + ((node_879) * (node_418)))
-                + ((node_881) * (node_419)))
-                + ((node_883) * (node_418)))
-                * (node_3965))
-                + ((node_594) * (next_base_row[5])),
-            ((((((((((((((((((((((((((node_747) * (node_419))
-                + ((node_757) * (node_419)))
-                + ((node_770) * (node_394)))
-                + ((node_775) * (node_477)))
-                + ((node_780) * (node_419)))
-                + ((node_785) * (node_417)))
-                + ((node_789) * (node_419)))
-                + ((node_798) * (node_419)))
-                + ((node_802) * (node_418)))
-                + ((node_805) * (node_418)))
-                + ((node_812) * (node_417)))
-                + ((node_821) * (node_589)))
-                + ((node_823) * (node_419)))
-                + ((node_825) * (node_419)))
-                + ((node_830) * (node_419)))
-                + ((node_833) * (node_419)))
-                + ((node_835) * (node_419)))
-                + ((node_838) * (node_417)))
-                + ((node_845) * (node_419)))
-                + ((node_872) * (node_419)))
-                + ((node_874) * (node_419)))
-                + ((node_876) * (node_419)))
-                + ((node_879) * (node_419)))
-                + ((node_883) * (node_419)))
-                * (node_3965))
-                + ((node_595) * (next_base_row[5])),
-            (((((((((node_770) * (node_395)) + ((node_775) * (node_480)))
-                + ((node_785) * (node_418)))
-                + ((node_802) * (node_419)))
-                + ((node_805) * (node_419)))
-                + ((node_821) * (node_592)))
-                + ((node_838) * (node_418)))
-                * (node_3965))
-                + ((node_418) * (next_base_row[5])),
-            (((((((node_770) * (node_421)) + ((node_775) * (node_484)))
-                + ((node_785) * (node_419)))
-                + ((node_821) * (node_594)))
-                + ((node_838) * (node_419)))
-                * (node_3965))
-                + ((node_419) * (next_base_row[5])),
-            ((((node_770) * (node_422)) + ((node_775) * (node_487))) + ((node_821) * (node_595)))
-                * (node_3965),
-            ((((node_770) * (node_423)) + ((node_775) * (node_492))) + ((node_821) * (node_418)))
-                * (node_3965),
-            ((((node_770) * (node_424)) + ((node_775) * (node_495))) + ((node_821) * (node_419)))
-                * (node_3965),
-            (((node_770) * (node_425)) + ((node_775) * (node_499))) * (node_3965),
-            (((node_770) * (node_426)) + ((node_775) * (node_502))) * (node_3965),
-            (((node_770) * (node_427))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_449)) * (node_550))))
-                * (node_3965),
-            (((node_770) * (node_428))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_452)) * (node_553))))
-                * (node_3965),
-            (((node_770) * (node_429))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_455)) * (node_556))))
-                * (node_3965),
-            (((node_770) * (node_430))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_460)) * (node_559))))
-                * (node_3965),
-            (((node_770) * (node_431))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_463)) * (node_562))))
-                * (node_3965),
-            (((node_770) * (node_432))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_467)) * (node_565))))
-                * (node_3965),
-            (((node_770) * (node_433))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_470)) * (node_568))))
-                * (node_3965),
-            (((node_770) * (node_434))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_475)) * (node_571))))
-                * (node_3965),
-            (((node_770) * (node_435))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_478)) * (node_574))))
-                * (node_3965),
-            (((node_770) * (node_436))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_482)) * (node_577))))
-                * (node_3965),
-            (((node_770) * (node_438))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_485)) * (node_580))))
-                * (node_3965),
-            (((node_770) * (node_418))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_490)) * (node_583))))
-                * (node_3965),
-            (((node_770) * (node_419))
-                + ((node_775) * (((BFieldElement::new(1)) - (node_493)) * (node_586))))
-                * (node_3965),
-            ((node_775) * (((BFieldElement::new(1)) - (node_497)) * (node_589))) * (node_3965),
-            ((node_775) * (((BFieldElement::new(1)) - (node_500)) * (node_592))) * (node_3965),
-            ((node_775) * (node_594)) * (node_3965),
-            ((node_775) * (node_595)) * (node_3965),
-            ((node_775) * (node_504)) * (node_3965),
-            ((node_775) * (node_506)) * (node_3965),
-            ((node_775) * (node_508)) * (node_3965),
-            ((node_775) * (node_510)) * (node_3965),
-            ((node_775) * (node_519)) * (node_3965),
-            ((node_775) * (node_439)) * (node_3965),
-            ((node_775) * (node_393)) * (node_3965),
-            ((node_775) * (node_394)) * (node_3965),
-            ((node_775) * (node_395)) * (node_3965),
-            ((node_775) * (node_418)) * (node_3965),
-            ((node_775) * (node_419)) * (node_3965),
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_846)))
+                + ((node_1057) * (node_675)))
+                + ((node_1061) * (node_846)))
+                + ((node_1065) * (node_852)))
+                + ((node_1070) * (node_846)))
+                + ((node_1074) * (node_676)))
+                + ((node_1077) * (node_843)))
+                + ((node_1080) * (node_700)))
+                + ((node_1084) * (node_675)))
+                + ((node_1087) * (node_691)))
+                + ((node_1090) * (node_867)))
+                + ((node_1093) * (node_831)))
+                + ((node_1095) * (node_846)))
+                + ((node_1097) * (node_846)))
+                + ((node_1099) * (node_691)))
+                + ((node_1102) * (node_677)))
+                + ((node_1105) * (node_677)))
+                + ((node_1107) * (node_846)))
+                + ((node_1110) * (node_675)))
+                + ((node_1117) * (node_705)))
+                + ((node_1122) * (node_678)))
+                + ((node_1126) * (node_678)))
+                + ((node_1130) * (node_678)))
+                + ((node_1133) * (node_849)))
+                + ((node_1136) * (node_678)))
+                + ((node_1139) * (node_849)))
+                + ((node_1142) * (node_849)))
+                + ((node_1144) * (node_858)))
+                + ((node_1146) * (node_858)))
+                + ((node_1148) * (node_858)))
+                + ((node_1151) * (node_681)))
+                + ((node_1153) * (node_702)))
+                + ((node_1155) * (node_677)))
+                * (node_4237))
+                + ((node_840) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_678))
+                + ((node_1029) * (node_706)))
+                + ((node_1037) * (node_703)))
+                + ((node_1042) * (node_771)))
+                + ((node_1047)
+                    * ((node_769)
+                        * ((next_base_row[37])
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_849)))
+                + ((node_1057) * (node_676)))
+                + ((node_1061) * (node_849)))
+                + ((node_1065) * (node_855)))
+                + ((node_1070) * (node_849)))
+                + ((node_1074) * (node_677)))
+                + ((node_1077) * (node_846)))
+                + ((node_1080) * (node_701)))
+                + ((node_1084) * (node_676)))
+                + ((node_1090) * (node_664)))
+                + ((node_1093) * (node_834)))
+                + ((node_1095) * (node_849)))
+                + ((node_1097) * (node_849)))
+                + ((node_1102) * (node_678)))
+                + ((node_1105) * (node_678)))
+                + ((node_1107) * (node_849)))
+                + ((node_1110) * (node_676)))
+                + ((node_1117) * (node_706)))
+                + ((node_1122) * (node_679)))
+                + ((node_1126) * (node_679)))
+                + ((node_1130) * (node_679)))
+                + ((node_1133) * (node_852)))
+                + ((node_1136) * (node_679)))
+                + ((node_1139) * (node_852)))
+                + ((node_1142) * (node_852)))
+                + ((node_1144) * (node_861)))
+                + ((node_1146) * (node_861)))
+                + ((node_1148) * (node_861)))
+                + ((node_1151) * (node_682)))
+                + ((node_1153) * (node_703)))
+                + ((node_1155) * (node_678)))
+                * (node_4237))
+                + ((node_843) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_679))
+                + ((node_1029) * (node_707)))
+                + ((node_1037) * (node_704)))
+                + ((node_1042) * (node_774)))
+                + ((node_1047)
+                    * ((node_772)
+                        * ((next_base_row[38])
+                            - (current_base_row[23])))))
+                + ((node_1052) * (node_852)))
+                + ((node_1057) * (node_677)))
+                + ((node_1061) * (node_852)))
+                + ((node_1065) * (node_858)))
+                + ((node_1070) * (node_852)))
+                + ((node_1074) * (node_678)))
+                + ((node_1077) * (node_849)))
+                + ((node_1080) * (node_702)))
+                + ((node_1084) * (node_677)))
+                + ((node_1090) * (node_665)))
+                + ((node_1093) * (node_837)))
+                + ((node_1095) * (node_852)))
+                + ((node_1097) * (node_852)))
+                + ((node_1102) * (node_679)))
+                + ((node_1105) * (node_679)))
+                + ((node_1107) * (node_852)))
+                + ((node_1110) * (node_677)))
+                + ((node_1117) * (node_707)))
+                + ((node_1122) * (node_680)))
+                + ((node_1126) * (node_680)))
+                + ((node_1130) * (node_680)))
+                + ((node_1133) * (node_855)))
+                + ((node_1136) * (node_680)))
+                + ((node_1139) * (node_855)))
+                + ((node_1142) * (node_855)))
+                + ((node_1144) * (node_864)))
+                + ((node_1146) * (node_864)))
+                + ((node_1148) * (node_864)))
+                + ((node_1151) * (node_683)))
+                + ((node_1153) * (node_704)))
+                + ((node_1155) * (node_679)))
+                * (node_4237))
+                + ((node_846) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_680))
+                + ((node_1029) * (node_708)))
+                + ((node_1037) * (node_705)))
+                + ((node_1042) * (node_776)))
+                + ((node_1047) * (node_722)))
+                + ((node_1052) * (node_855)))
+                + ((node_1057) * (node_678)))
+                + ((node_1061) * (node_855)))
+                + ((node_1065) * (node_861)))
+                + ((node_1070) * (node_855)))
+                + ((node_1074) * (node_679)))
+                + ((node_1077) * (node_852)))
+                + ((node_1080) * (node_703)))
+                + ((node_1084) * (node_678)))
+                + ((node_1090) * (node_666)))
+                + ((node_1093) * (node_840)))
+                + ((node_1095) * (node_855)))
+                + ((node_1097) * (node_855)))
+                + ((node_1102) * (node_680)))
+                + ((node_1105) * (node_680)))
+                + ((node_1107) * (node_855)))
+                + ((node_1110) * (node_678)))
+                + ((node_1117) * (node_708)))
+                + ((node_1122) * (node_681)))
+                + ((node_1126) * (node_681)))
+                + ((node_1130) * (node_681)))
+                + ((node_1133) * (node_858)))
+                + ((node_1136) * (node_681)))
+                + ((node_1139) * (node_858)))
+                + ((node_1142) * (node_858)))
+                + ((node_1144) * (node_866)))
+                + ((node_1146) * (node_866)))
+                + ((node_1148) * (node_866)))
+                + ((node_1151) * (node_685)))
+                + ((node_1153) * (node_705)))
+                + ((node_1155) * (node_680)))
+                * (node_4237))
+                + ((node_849) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_681))
+                + ((node_1029) * (node_710)))
+                + ((node_1037) * (node_706)))
+                + ((node_1042) * (node_778)))
+                + ((node_1047) * (node_726)))
+                + ((node_1052) * (node_858)))
+                + ((node_1057) * (node_679)))
+                + ((node_1061) * (node_858)))
+                + ((node_1065) * (node_864)))
+                + ((node_1070) * (node_858)))
+                + ((node_1074) * (node_680)))
+                + ((node_1077) * (node_855)))
+                + ((node_1080) * (node_704)))
+                + ((node_1084) * (node_679)))
+                + ((node_1090) * (node_667)))
+                + ((node_1093) * (node_843)))
+                + ((node_1095) * (node_858)))
+                + ((node_1097) * (node_858)))
+                + ((node_1102) * (node_681)))
+                + ((node_1105) * (node_681)))
+                + ((node_1107) * (node_858)))
+                + ((node_1110) * (node_679)))
+                + ((node_1117) * (node_710)))
+                + ((node_1122) * (node_682)))
+                + ((node_1126) * (node_682)))
+                + ((node_1130) * (node_682)))
+                + ((node_1133) * (node_861)))
+                + ((node_1136) * (node_682)))
+                + ((node_1139) * (node_861)))
+                + ((node_1142) * (node_861)))
+                + ((node_1144) * (node_867)))
+                + ((node_1146) * (node_867)))
+                + ((node_1148) * (node_867)))
+                + ((node_1151) * (node_689)))
+                + ((node_1153) * (node_706)))
+                + ((node_1155) * (node_681)))
+                * (node_4237))
+                + ((node_852) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_682))
+                + ((node_1029) * (node_711)))
+                + ((node_1037) * (node_707)))
+                + ((node_1042) * (node_780)))
+                + ((node_1047) * (node_729)))
+                + ((node_1052) * (node_861)))
+                + ((node_1057) * (node_680)))
+                + ((node_1061) * (node_861)))
+                + ((node_1065) * (node_866)))
+                + ((node_1070) * (node_861)))
+                + ((node_1074) * (node_681)))
+                + ((node_1077) * (node_858)))
+                + ((node_1080) * (node_705)))
+                + ((node_1084) * (node_680)))
+                + ((node_1090) * (node_690)))
+                + ((node_1093) * (node_846)))
+                + ((node_1095) * (node_861)))
+                + ((node_1097) * (node_861)))
+                + ((node_1102) * (node_682)))
+                + ((node_1105) * (node_682)))
+                + ((node_1107) * (node_861)))
+                + ((node_1110) * (node_680)))
+                + ((node_1117) * (node_664)))
+                + ((node_1122) * (node_683)))
+                + ((node_1126) * (node_683)))
+                + ((node_1130) * (node_683)))
+                + ((node_1133) * (node_864)))
+                + ((node_1136) * (node_683)))
+                + ((node_1139) * (node_864)))
+                + ((node_1142) * (node_864)))
+                + ((node_1144) * (node_664)))
+                + ((node_1146) * (node_664)))
+                + ((node_1148) * (node_664)))
+                + ((node_1151) * (node_664)))
+                + ((node_1153) * (node_707)))
+                + ((node_1155) * (node_682)))
+                * (node_4237))
+                + ((node_855) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_683))
+                + ((node_1029) * (node_665)))
+                + ((node_1037) * (node_708)))
+                + ((node_1042) * (node_782)))
+                + ((node_1047) * (node_734)))
+                + ((node_1052) * (node_864)))
+                + ((node_1057) * (node_681)))
+                + ((node_1061) * (node_864)))
+                + ((node_1065) * (node_867)))
+                + ((node_1070) * (node_864)))
+                + ((node_1074) * (node_682)))
+                + ((node_1077) * (node_861)))
+                + ((node_1080) * (node_706)))
+                + ((node_1084) * (node_681)))
+                + ((node_1090) * (node_691)))
+                + ((node_1093) * (node_849)))
+                + ((node_1095) * (node_864)))
+                + ((node_1097) * (node_864)))
+                + ((node_1102) * (node_683)))
+                + ((node_1105) * (node_683)))
+                + ((node_1107) * (node_864)))
+                + ((node_1110) * (node_681)))
+                + ((node_1117) * (node_665)))
+                + ((node_1122) * (node_685)))
+                + ((node_1126) * (node_685)))
+                + ((node_1130) * (node_685)))
+                + ((node_1133) * (node_866)))
+                + ((node_1136) * (node_685)))
+                + ((node_1139) * (node_866)))
+                + ((node_1142) * (node_866)))
+                + ((node_1144) * (node_665)))
+                + ((node_1146) * (node_665)))
+                + ((node_1148) * (node_665)))
+                + ((node_1151) * (node_665)))
+                + ((node_1153) * (node_708)))
+                + ((node_1155) * (node_683)))
+                * (node_4237))
+                + ((node_858) * (next_base_row[7])),
+            (((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_685))
+                + ((node_1029) * (node_666)))
+                + ((node_1037) * (node_710)))
+                + ((node_1042) * (node_791)))
+                + ((node_1047) * (node_737)))
+                + ((node_1052) * (node_866)))
+                + ((node_1057) * (node_682)))
+                + ((node_1061) * (node_866)))
+                + ((node_1065) * (node_690)))
+                + ((node_1070) * (node_866)))
+                + ((node_1074) * (node_683)))
+                + ((node_1077) * (node_864)))
+                + ((node_1080) * (node_707)))
+                + ((node_1084) * (node_682)))
+                + ((node_1093) * (node_852)))
+                + ((node_1095) * (node_866)))
+                + ((node_1097) * (node_866)))
+                + ((node_1102) * (node_685)))
+                + ((node_1105) * (node_685)))
+                + ((node_1107) * (node_866)))
+                + ((node_1110) * (node_682)))
+                + ((node_1117) * (node_666)))
+                + ((node_1122) * (node_689)))
+                + ((node_1126) * (node_689)))
+                + ((node_1130) * (node_689)))
+                + ((node_1133) * (node_867)))
+                + ((node_1136) * (node_689)))
+                + ((node_1139) * (node_867)))
+                + ((node_1142) * (node_867)))
+                + ((node_1144) * (node_666)))
+                + ((node_1146) * (node_666)))
+                + ((node_1148) * (node_666)))
+                + ((node_1151) * (node_666)))
+                + ((node_1153) * (node_710)))
+                + ((node_1155) * (node_685)))
+                * (node_4237))
+                + ((node_861) * (next_base_row[7])),
+            (((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_689))
+                + ((node_1029) * (node_667)))
+                + ((node_1037) * (node_690)))
+                + ((node_1042) * (node_711)))
+                + ((node_1047) * (node_741)))
+                + ((node_1052) * (node_867)))
+                + ((node_1057) * (node_683)))
+                + ((node_1061) * (node_867)))
+                + ((node_1065) * (node_691)))
+                + ((node_1070) * (node_867)))
+                + ((node_1074) * (node_685)))
+                + ((node_1077) * (node_866)))
+                + ((node_1080) * (node_708)))
+                + ((node_1084) * (node_683)))
+                + ((node_1093) * (node_855)))
+                + ((node_1095) * (node_867)))
+                + ((node_1097) * (node_867)))
+                + ((node_1102) * (node_689)))
+                + ((node_1105) * (node_689)))
+                + ((node_1107) * (node_867)))
+                + ((node_1110) * (node_683)))
+                + ((node_1117) * (node_667)))
+                + ((node_1122) * (node_690)))
+                + ((node_1126) * (node_690)))
+                + ((node_1130) * (node_690)))
+                + ((node_1133) * (node_690)))
+                + ((node_1136) * (node_690)))
+                + ((node_1139) * (node_690)))
+                + ((node_1142) * (node_690)))
+                + ((node_1144) * (node_667)))
+                + ((node_1146) * (node_667)))
+                + ((node_1148) * (node_667)))
+                + ((node_1151) * (node_667)))
+                + ((node_1153) * (node_690)))
+                + ((node_1155) * (node_689)))
+                * (node_4237))
+                + ((node_864) * (next_base_row[7])),
+            ((((((((((((((((((((((((((((((((((((node_1019)
+                * (node_690))
+                + ((node_1029) * (node_690)))
+                + ((node_1037) * (node_691)))
+                + ((node_1042) * (node_665)))
+                + ((node_1047) * (node_744)))
+                + ((node_1052) * (node_690)))
+                + ((node_1057) * (node_685)))
+                + ((node_1061) * (node_690)))
+                + ((node_1070) * (node_690)))
+                + ((node_1074) * (node_689)))
+                + ((node_1077) * (node_867)))
+                + ((node_1080) * (node_710)))
+                + ((node_1084) * (node_685)))
+                + ((node_1093) * (node_858)))
+                + ((node_1095) * (node_690)))
+                + ((node_1097) * (node_690)))
+                + ((node_1102) * (node_690)))
+                + ((node_1105) * (node_690)))
+                + ((node_1107) * (node_690)))
+                + ((node_1110) * (node_685)))
+                + ((node_1117) * (node_690)))
+                + ((node_1122) * (node_691)))
+                + ((node_1126) * (node_691)))
+                + ((node_1130) * (node_691)))
+                + ((node_1133) * (node_691)))
+                + ((node_1136) * (node_691)))
+                + ((node_1139) * (node_691)))
+                + ((node_1142) * (node_691)))
+                + ((node_1144) * (node_690)))
+                + ((node_1146) * (node_690)))
+                + ((node_1148) * (node_690)))
+                + ((node_1151) * (node_690)))
+                + ((node_1153) * (node_691)))
+                + ((node_1155) * (node_690)))
+                * (node_4237))
+                + ((node_866) * (next_base_row[7])),
+            ((((((((((((((((((((((((((node_1019) * (node_691))
+                + ((node_1029) * (node_691)))
+                + ((node_1042) * (node_666)))
+                + ((node_1047) * (node_749)))
+                + ((node_1052) * (node_691)))
+                + ((node_1057) * (node_689)))
+                + ((node_1061) * (node_691)))
+                + ((node_1070) * (node_691)))
+                + ((node_1074) * (node_690)))
+                + ((node_1077) * (node_690)))
+                + ((node_1084) * (node_689)))
+                + ((node_1093) * (node_861)))
+                + ((node_1095) * (node_691)))
+                + ((node_1097) * (node_691)))
+                + ((node_1102) * (node_691)))
+                + ((node_1105) * (node_691)))
+                + ((node_1107) * (node_691)))
+                + ((node_1110) * (node_689)))
+                + ((node_1117) * (node_691)))
+                + ((node_1144) * (node_691)))
+                + ((node_1146) * (node_691)))
+                + ((node_1148) * (node_691)))
+                + ((node_1151) * (node_691)))
+                + ((node_1155) * (node_691)))
+                * (node_4237))
+                + ((node_867) * (next_base_row[7])),
+            (((((((((node_1042) * (node_667)) + ((node_1047) * (node_752)))
+                + ((node_1057) * (node_690)))
+                + ((node_1074) * (node_691)))
+                + ((node_1077) * (node_691)))
+                + ((node_1093) * (node_864)))
+                + ((node_1110) * (node_690)))
+                * (node_4237))
+                + ((node_690) * (next_base_row[7])),
+            (((((((node_1042) * (node_693)) + ((node_1047) * (node_756)))
+                + ((node_1057) * (node_691)))
+                + ((node_1093) * (node_866)))
+                + ((node_1110) * (node_691)))
+                * (node_4237))
+                + ((node_691) * (next_base_row[7])),
+            ((((node_1042) * (node_694)) + ((node_1047) * (node_759)))
+                + ((node_1093) * (node_867)))
+                * (node_4237),
+            ((((node_1042) * (node_695)) + ((node_1047) * (node_764)))
+                + ((node_1093) * (node_690)))
+                * (node_4237),
+            ((((node_1042) * (node_696)) + ((node_1047) * (node_767)))
+                + ((node_1093) * (node_691)))
+                * (node_4237),
+            (((node_1042) * (node_697)) + ((node_1047) * (node_771))) * (node_4237),
+            (((node_1042) * (node_698)) + ((node_1047) * (node_774))) * (node_4237),
+            (((node_1042) * (node_699))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_721)) * (node_822))))
+                * (node_4237),
+            (((node_1042) * (node_700))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_724)) * (node_825))))
+                * (node_4237),
+            (((node_1042) * (node_701))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_727)) * (node_828))))
+                * (node_4237),
+            (((node_1042) * (node_702))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_732)) * (node_831))))
+                * (node_4237),
+            (((node_1042) * (node_703))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_735)) * (node_834))))
+                * (node_4237),
+            (((node_1042) * (node_704))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_739)) * (node_837))))
+                * (node_4237),
+            (((node_1042) * (node_705))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_742)) * (node_840))))
+                * (node_4237),
+            (((node_1042) * (node_706))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_747)) * (node_843))))
+                * (node_4237),
+            (((node_1042) * (node_707))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_750)) * (node_846))))
+                * (node_4237),
+            (((node_1042) * (node_708))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_754)) * (node_849))))
+                * (node_4237),
+            (((node_1042) * (node_710))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_757)) * (node_852))))
+                * (node_4237),
+            (((node_1042) * (node_690))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_762)) * (node_855))))
+                * (node_4237),
+            (((node_1042) * (node_691))
+                + ((node_1047) * (((BFieldElement::new(1)) - (node_765)) * (node_858))))
+                * (node_4237),
+            ((node_1047) * (((BFieldElement::new(1)) - (node_769)) * (node_861))) * (node_4237),
+            ((node_1047) * (((BFieldElement::new(1)) - (node_772)) * (node_864))) * (node_4237),
+            ((node_1047) * (node_866)) * (node_4237),
+            ((node_1047) * (node_867)) * (node_4237),
+            ((node_1047) * (node_776)) * (node_4237),
+            ((node_1047) * (node_778)) * (node_4237),
+            ((node_1047) * (node_780)) * (node_4237),
+            ((node_1047) * (node_782)) * (node_4237),
+            ((node_1047) * (node_791)) * (node_4237),
+            ((node_1047) * (node_711)) * (node_4237),
+            ((node_1047) * (node_665)) * (node_4237),
+            ((node_1047) * (node_666)) * (node_4237),
+            ((node_1047) * (node_667)) * (node_4237),
+            ((node_1047) * (node_690)) * (node_4237),
+            ((node_1047) * (node_691)) * (node_4237),
         ];
         let ext_constraints = [
-            (((next_ext_row[11]) - (current_ext_row[11]))
+            (((next_ext_row[12]) - (current_ext_row[12]))
                 * ((challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate))
-                    - (next_base_row[4])))
-                - (next_base_row[45]),
-            (((current_base_row[8]) - (BFieldElement::new(128)))
-                * ((next_ext_row[1]) - (current_ext_row[1])))
-                + ((node_881)
-                    * (((next_ext_row[1])
+                    - (next_base_row[6])))
+                - (next_base_row[47]),
+            (((current_base_row[10]) - (BFieldElement::new(128)))
+                * ((next_ext_row[2]) - (current_ext_row[2])))
+                + ((node_1153)
+                    * (((next_ext_row[2])
                         - ((challenges.get_challenge(StandardInputIndeterminate))
-                            * (current_ext_row[1])))
-                        - (next_base_row[21]))),
-            ((node_3965)
-                * (((node_4141)
+                            * (current_ext_row[2])))
+                        - (next_base_row[23]))),
+            ((node_4237)
+                * (((node_4413)
                     * ((challenges.get_challenge(InstructionLookupIndeterminate))
                         - ((((challenges.get_challenge(ProgramAddressWeight))
-                            * (next_base_row[7]))
+                            * (next_base_row[9]))
                             + ((challenges.get_challenge(ProgramInstructionWeight))
-                                * (next_base_row[8])))
+                                * (next_base_row[10])))
                             + ((challenges.get_challenge(ProgramNextInstructionWeight))
-                                * (next_base_row[9])))))
+                                * (next_base_row[11])))))
                     - (BFieldElement::new(1))))
-                + ((next_base_row[5]) * (node_4141)),
-            (((next_base_row[8]) - (BFieldElement::new(66)))
-                * ((next_ext_row[2]) - (current_ext_row[2])))
-                + (((((((((node_4149) * (next_base_row[11])) * (node_4153)) * (node_4155))
-                    * ((next_base_row[14]) - (BFieldElement::new(1))))
-                    * ((next_base_row[15]) - (BFieldElement::new(1))))
-                    * (next_base_row[16]))
-                    * (node_4163))
-                    * (((next_ext_row[2])
+                + ((next_base_row[7]) * (node_4413)),
+            (((next_base_row[10]) - (BFieldElement::new(66)))
+                * ((next_ext_row[3]) - (current_ext_row[3])))
+                + (((((((((node_4421) * (next_base_row[13])) * (node_4425)) * (node_4427))
+                    * ((next_base_row[16]) - (BFieldElement::new(1))))
+                    * ((next_base_row[17]) - (BFieldElement::new(1))))
+                    * (next_base_row[18]))
+                    * (node_4435))
+                    * (((next_ext_row[3])
                         - ((challenges.get_challenge(StandardOutputIndeterminate))
-                            * (current_ext_row[2])))
-                        - (next_base_row[21]))),
-            (next_ext_row[4])
-                - ((current_ext_row[4])
+                            * (current_ext_row[3])))
+                        - (next_base_row[23]))),
+            (next_ext_row[5])
+                - ((current_ext_row[5])
                     * ((challenges.get_challenge(OpStackIndeterminate))
                         - (((((challenges.get_challenge(OpStackClkWeight))
-                            * (next_base_row[4]))
+                            * (next_base_row[6]))
                             + ((challenges.get_challenge(OpStackIb1Weight))
-                                * (next_base_row[11])))
+                                * (next_base_row[13])))
                             + ((challenges.get_challenge(OpStackOspWeight))
-                                * (next_base_row[37])))
+                                * (next_base_row[39])))
                             + ((challenges.get_challenge(OpStackOsvWeight))
-                                * (next_base_row[38]))))),
-            (next_ext_row[5])
-                - ((current_ext_row[5])
+                                * (next_base_row[40]))))),
+            (next_ext_row[6])
+                - ((current_ext_row[6])
                     * ((challenges.get_challenge(RamIndeterminate))
-                        - (((((challenges.get_challenge(RamClkWeight)) * (next_base_row[4]))
+                        - (((((challenges.get_challenge(RamClkWeight)) * (next_base_row[6]))
                             + ((challenges.get_challenge(RamRampWeight))
-                                * (next_base_row[43])))
-                            + ((challenges.get_challenge(RamRamvWeight)) * (next_base_row[44])))
+                                * (next_base_row[45])))
+                            + ((challenges.get_challenge(RamRamvWeight)) * (next_base_row[46])))
                             + ((challenges.get_challenge(RamPreviousInstructionWeight))
-                                * (next_base_row[6]))))),
-            (next_ext_row[6])
-                - ((current_ext_row[6])
+                                * (next_base_row[8]))))),
+            (next_ext_row[7])
+                - ((current_ext_row[7])
                     * ((challenges.get_challenge(JumpStackIndeterminate))
                         - ((((((challenges.get_challenge(JumpStackClkWeight))
-                            * (next_base_row[4]))
+                            * (next_base_row[6]))
                             + ((challenges.get_challenge(JumpStackCiWeight))
-                                * (next_base_row[8])))
+                                * (next_base_row[10])))
                             + ((challenges.get_challenge(JumpStackJspWeight))
-                                * (next_base_row[18])))
+                                * (next_base_row[20])))
                             + ((challenges.get_challenge(JumpStackJsoWeight))
-                                * (next_base_row[19])))
+                                * (next_base_row[21])))
                             + ((challenges.get_challenge(JumpStackJsdWeight))
-                                * (next_base_row[20]))))),
-            (((next_base_row[8]) - (BFieldElement::new(48)))
-                * ((next_ext_row[7]) - (current_ext_row[7])))
-                + (((((((((node_4149) * ((next_base_row[11]) - (BFieldElement::new(1))))
-                    * (node_4153))
-                    * (node_4155))
-                    * (next_base_row[14]))
-                    * (next_base_row[15]))
-                    * ((next_base_row[16]) - (BFieldElement::new(1))))
-                    * (node_4163))
-                    * (((next_ext_row[7])
-                        - ((challenges.get_challenge(HashInputIndeterminate))
-                            * (current_ext_row[7])))
-                        - (node_4264))),
-            (((current_base_row[8]) - (BFieldElement::new(48)))
+                                * (next_base_row[22]))))),
+            (((next_base_row[10]) - (BFieldElement::new(48)))
                 * ((next_ext_row[8]) - (current_ext_row[8])))
-                + ((node_815)
+                + (((((((((node_4421) * ((next_base_row[13]) - (BFieldElement::new(1))))
+                    * (node_4425))
+                    * (node_4427))
+                    * (next_base_row[16]))
+                    * (next_base_row[17]))
+                    * ((next_base_row[18]) - (BFieldElement::new(1))))
+                    * (node_4435))
                     * (((next_ext_row[8])
-                        - ((challenges.get_challenge(HashDigestIndeterminate))
+                        - ((challenges.get_challenge(HashInputIndeterminate))
                             * (current_ext_row[8])))
+                        - (node_4536))),
+            (((current_base_row[10]) - (BFieldElement::new(48)))
+                * ((next_ext_row[9]) - (current_ext_row[9])))
+                + ((node_1087)
+                    * (((next_ext_row[9])
+                        - ((challenges.get_challenge(HashDigestIndeterminate))
+                            * (current_ext_row[9])))
                         - ((((((challenges.get_challenge(HashStateWeight0))
-                            * (next_base_row[26]))
+                            * (next_base_row[28]))
                             + ((challenges.get_challenge(HashStateWeight1))
-                                * (next_base_row[27])))
+                                * (next_base_row[29])))
                             + ((challenges.get_challenge(HashStateWeight2))
-                                * (next_base_row[28])))
+                                * (next_base_row[30])))
                             + ((challenges.get_challenge(HashStateWeight3))
-                                * (next_base_row[29])))
+                                * (next_base_row[31])))
                             + ((challenges.get_challenge(HashStateWeight4))
-                                * (next_base_row[30]))))),
-            (((((((current_base_row[8]) - (BFieldElement::new(72)))
-                * ((current_base_row[8]) - (BFieldElement::new(80))))
-                * ((current_base_row[8]) - (BFieldElement::new(88))))
-                * ((next_ext_row[9]) - (current_ext_row[9])))
-                + ((node_823) * (node_4304)))
-                + ((node_825) * (node_4304)))
-                + ((node_827) * (node_4304)),
-            (((((((((node_845)
-                * (((node_4349) * (((node_4321) - (node_4322)) - (node_4324)))
+                                * (next_base_row[32]))))),
+            (((((((current_base_row[10]) - (BFieldElement::new(72)))
+                * ((current_base_row[10]) - (BFieldElement::new(80))))
+                * ((current_base_row[10]) - (BFieldElement::new(88))))
+                * ((next_ext_row[10]) - (current_ext_row[10])))
+                + ((node_1095) * (node_4576)))
+                + ((node_1097) * (node_4576)))
+                + ((node_1099) * (node_4576)),
+            (((((((((node_1117)
+                * (((node_4621) * (((node_4593) - (node_4594)) - (node_4596)))
                     - (BFieldElement::new(1))))
-                + ((node_850) * (node_4354)))
-                + ((node_854) * (node_4354)))
-                + ((node_858)
-                    * (((node_4349)
-                        * (((node_4329)
+                + ((node_1122) * (node_4626)))
+                + ((node_1126) * (node_4626)))
+                + ((node_1130)
+                    * (((node_4621)
+                        * (((node_4601)
                             - ((challenges.get_challenge(U32CiWeight))
                                 * (BFieldElement::new(20))))
                             - (((challenges.get_challenge(U32ResultWeight))
-                                * ((node_658) - (next_base_row[21])))
+                                * ((node_930) - (next_base_row[23])))
                                 * (BFieldElement::new(9223372034707292161)))))
                         - (BFieldElement::new(1)))))
-                + ((node_864) * (node_4354)))
-                + ((node_861) * (node_4362)))
-                + ((node_867)
-                    * (((((node_4349) * (node_4345)) * (node_4348)) - (node_4345))
-                        - (node_4348))))
-                + ((node_870) * (node_4362)))
-                + (((BFieldElement::new(1)) - (current_base_row[12])) * (node_4349)),
+                + ((node_1136) * (node_4626)))
+                + ((node_1133) * (node_4634)))
+                + ((node_1139)
+                    * (((((node_4621) * (node_4617)) * (node_4620)) - (node_4617))
+                        - (node_4620))))
+                + ((node_1142) * (node_4634)))
+                + (((BFieldElement::new(1)) - (current_base_row[14])) * (node_4621)),
         ];
         base_constraints
             .into_iter()
@@ -3409,7 +3425,7 @@ impl Evaluable<XFieldElement> for ExtProcessorTable {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let base_constraints = [base_row[8]];
+        let base_constraints = [base_row[10]];
         let ext_constraints = [];
         base_constraints
             .into_iter()
@@ -3466,7 +3482,7 @@ impl Quotientable for ExtProcessorTable {
             interpolant_degree * 1 as Degree - zerofier_degree,
             interpolant_degree * 1 as Degree - zerofier_degree,
             interpolant_degree * 1 as Degree - zerofier_degree,
-            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
             interpolant_degree * 1 as Degree - zerofier_degree,
             interpolant_degree * 1 as Degree - zerofier_degree,
             interpolant_degree * 1 as Degree - zerofier_degree,
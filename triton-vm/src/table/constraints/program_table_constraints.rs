@@ -21,7 +21,18 @@ impl Evaluable<BFieldElement> for ExtProgramTable {
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
         let base_constraints = [base_row[0]];
-        let ext_constraints = [ext_row[0]];
+        let ext_constraints = [
+            ext_row[0],
+            ((base_row[4])
+                * ((ext_row[1])
+                    - ((challenges.get_challenge(DataSegmentIndeterminate))
+                        - (((challenges.get_challenge(DataSegmentAddressWeight))
+                            * (BFieldElement::new(268500992)))
+                            + ((challenges.get_challenge(DataSegmentValueWeight))
+                                * (base_row[5]))))))
+                + (((BFieldElement::new(1)) - (base_row[4]))
+                    * ((ext_row[1]) - (BFieldElement::new(1)))),
+        ];
         base_constraints
             .into_iter()
             .map(|bfe| bfe.lift())
@@ -36,7 +47,10 @@ impl Evaluable<BFieldElement> for ExtProgramTable {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let base_constraints = [(base_row[3]) * ((base_row[3]) - (BFieldElement::new(1)))];
+        let base_constraints = [
+            (base_row[3]) * ((base_row[3]) - (BFieldElement::new(1))),
+            (base_row[4]) * ((base_row[4]) - (BFieldElement::new(1))),
+        ];
         let ext_constraints = [];
         base_constraints
             .into_iter()
@@ -54,23 +68,37 @@ impl Evaluable<BFieldElement> for ExtProgramTable {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_14 = (next_ext_row[0]) - (current_ext_row[0]);
+        let node_28 = (next_ext_row[0]) - (current_ext_row[0]);
 
         let base_constraints = [
             (next_base_row[0]) - ((current_base_row[0]) + (BFieldElement::new(1))),
             (current_base_row[3]) * ((next_base_row[3]) - (current_base_row[3])),
+            ((BFieldElement::new(1)) - (current_base_row[4])) * (next_base_row[4]),
+        ];
+        let ext_constraints = [
+            (((BFieldElement::new(1)) - (current_base_row[3]))
+                * (((node_28)
+                    * ((challenges.get_challenge(InstructionLookupIndeterminate))
+                        - ((((challenges.get_challenge(ProgramAddressWeight))
+                            * (current_base_row[0]))
+                            + ((challenges.get_challenge(ProgramInstructionWeight))
+                                * (current_base_row[1])))
+                            + ((challenges.get_challenge(ProgramNextInstructionWeight))
+                                * (next_base_row[1])))))
+                    - (current_base_row[2])))
+                + ((current_base_row[3]) * (node_28)),
+            ((next_base_row[4])
+                * ((next_ext_row[1])
+                    - ((current_ext_row[1])
+                        * ((challenges.get_challenge(DataSegmentIndeterminate))
+                            - (((challenges.get_challenge(DataSegmentAddressWeight))
+                                * ((BFieldElement::new(268500992))
+                                    + ((BFieldElement::new(4)) * (next_base_row[0]))))
+                                + ((challenges.get_challenge(DataSegmentValueWeight))
+                                    * (next_base_row[5])))))))
+                + (((BFieldElement::new(1)) - (next_base_row[4]))
+                    * ((next_ext_row[1]) - (current_ext_row[1]))),
         ];
-        let ext_constraints = [(((BFieldElement::new(1)) - (current_base_row[3]))
-            * (((node_14)
-                * ((challenges.get_challenge(InstructionLookupIndeterminate))
-                    - ((((challenges.get_challenge(ProgramAddressWeight))
-                        * (current_base_row[0]))
-                        + ((challenges.get_challenge(ProgramInstructionWeight))
-                            * (current_base_row[1])))
-                        + ((challenges.get_challenge(ProgramNextInstructionWeight))
-                            * (next_base_row[1])))))
-                - (current_base_row[2])))
-            + ((current_base_row[3]) * (node_14))];
         base_constraints
             .into_iter()
             .map(|bfe| bfe.lift())
@@ -98,7 +126,18 @@ impl Evaluable<XFieldElement> for ExtProgramTable {
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
         let base_constraints = [base_row[0]];
-        let ext_constraints = [ext_row[0]];
+        let ext_constraints = [
+            ext_row[0],
+            ((base_row[4])
+                * ((ext_row[1])
+                    - ((challenges.get_challenge(DataSegmentIndeterminate))
+                        - (((challenges.get_challenge(DataSegmentAddressWeight))
+                            * (BFieldElement::new(268500992)))
+                            + ((challenges.get_challenge(DataSegmentValueWeight))
+                                * (base_row[5]))))))
+                + (((BFieldElement::new(1)) - (base_row[4]))
+                    * ((ext_row[1]) - (BFieldElement::new(1)))),
+        ];
         base_constraints
             .into_iter()
             .chain(ext_constraints.into_iter())
@@ -112,7 +151,10 @@ impl Evaluable<XFieldElement> for ExtProgramTable {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let base_constraints = [(base_row[3]) * ((base_row[3]) - (BFieldElement::new(1)))];
+        let base_constraints = [
+            (base_row[3]) * ((base_row[3]) - (BFieldElement::new(1))),
+            (base_row[4]) * ((base_row[4]) - (BFieldElement::new(1))),
+        ];
         let ext_constraints = [];
         base_constraints
             .into_iter()
@@ -129,23 +171,37 @@ impl Evaluable<XFieldElement> for ExtProgramTable {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_14 = (next_ext_row[0]) - (current_ext_row[0]);
+        let node_28 = (next_ext_row[0]) - (current_ext_row[0]);
 
         let base_constraints = [
             (next_base_row[0]) - ((current_base_row[0]) + (BFieldElement::new(1))),
             (current_base_row[3]) * ((next_base_row[3]) - (current_base_row[3])),
+            ((BFieldElement::new(1)) - (current_base_row[4])) * (next_base_row[4]),
+        ];
+        let ext_constraints = [
+            (((BFieldElement::new(1)) - (current_base_row[3]))
+                * (((node_28)
+                    * ((challenges.get_challenge(InstructionLookupIndeterminate))
+                        - ((((challenges.get_challenge(ProgramAddressWeight))
+                            * (current_base_row[0]))
+                            + ((challenges.get_challenge(ProgramInstructionWeight))
+                                * (current_base_row[1])))
+                            + ((challenges.get_challenge(ProgramNextInstructionWeight))
+                                * (next_base_row[1])))))
+                    - (current_base_row[2])))
+                + ((current_base_row[3]) * (node_28)),
+            ((next_base_row[4])
+                * ((next_ext_row[1])
+                    - ((current_ext_row[1])
+                        * ((challenges.get_challenge(DataSegmentIndeterminate))
+                            - (((challenges.get_challenge(DataSegmentAddressWeight))
+                                * ((BFieldElement::new(268500992))
+                                    + ((BFieldElement::new(4)) * (next_base_row[0]))))
+                                + ((challenges.get_challenge(DataSegmentValueWeight))
+                                    * (next_base_row[5])))))))
+                + (((BFieldElement::new(1)) - (next_base_row[4]))
+                    * ((next_ext_row[1]) - (current_ext_row[1]))),
         ];
-        let ext_constraints = [(((BFieldElement::new(1)) - (current_base_row[3]))
-            * (((node_14)
-                * ((challenges.get_challenge(InstructionLookupIndeterminate))
-                    - ((((challenges.get_challenge(ProgramAddressWeight))
-                        * (current_base_row[0]))
-                        + ((challenges.get_challenge(ProgramInstructionWeight))
-                            * (current_base_row[1])))
-                        + ((challenges.get_challenge(ProgramNextInstructionWeight))
-                            * (next_base_row[1])))))
-                - (current_base_row[2])))
-            + ((current_base_row[3]) * (node_14))];
         base_constraints
             .into_iter()
             .chain(ext_constraints.into_iter())
@@ -165,15 +221,15 @@ impl Evaluable<XFieldElement> for ExtProgramTable {
 
 impl Quotientable for ExtProgramTable {
     fn num_initial_quotients() -> usize {
-        2
+        3
     }
 
     fn num_consistency_quotients() -> usize {
-        1
+        2
     }
 
     fn num_transition_quotients() -> usize {
-        3
+        5
     }
 
     fn num_terminal_quotients() -> usize {
@@ -186,6 +242,7 @@ impl Quotientable for ExtProgramTable {
         [
             interpolant_degree * 1 as Degree - zerofier_degree,
             interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
         ]
         .to_vec()
     }
@@ -196,7 +253,11 @@ impl Quotientable for ExtProgramTable {
         padded_height: usize,
     ) -> Vec<Degree> {
         let zerofier_degree = padded_height as Degree;
-        [interpolant_degree * 2 as Degree - zerofier_degree].to_vec()
+        [
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+        ]
+        .to_vec()
     }
 
     #[allow(unused_variables)]
@@ -209,6 +270,8 @@ impl Quotientable for ExtProgramTable {
             interpolant_degree * 1 as Degree - zerofier_degree,
             interpolant_degree * 2 as Degree - zerofier_degree,
             interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
         ]
         .to_vec()
     }
@@ -21,24 +21,24 @@ impl Evaluable<BFieldElement> for ExtRamTable {
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
         let base_constraints = [
-            (base_row[53]) * ((BFieldElement::new(26)) - (base_row[51])),
-            base_row[55],
+            (base_row[55]) * ((BFieldElement::new(26)) - (base_row[53])),
+            base_row[57],
         ];
         let ext_constraints = [
-            ext_row[16],
-            (ext_row[17]) - (base_row[56]),
-            (ext_row[14])
+            ext_row[17],
+            (ext_row[18]) - (base_row[58]),
+            (ext_row[15])
                 - ((challenges.get_challenge(RamTableBezoutRelationIndeterminate))
-                    - (base_row[52])),
-            (ext_row[15]) - (BFieldElement::new(1)),
-            (ext_row[18])
+                    - (base_row[54])),
+            (ext_row[16]) - (BFieldElement::new(1)),
+            (ext_row[19])
                 - ((challenges.get_challenge(RamIndeterminate))
-                    - (((((base_row[50]) * (challenges.get_challenge(RamClkWeight)))
-                        + ((base_row[52]) * (challenges.get_challenge(RamRampWeight))))
-                        + ((base_row[53]) * (challenges.get_challenge(RamRamvWeight))))
-                        + ((base_row[51])
+                    - (((((base_row[52]) * (challenges.get_challenge(RamClkWeight)))
+                        + ((base_row[54]) * (challenges.get_challenge(RamRampWeight))))
+                        + ((base_row[55]) * (challenges.get_challenge(RamRamvWeight))))
+                        + ((base_row[53])
                             * (challenges.get_challenge(RamPreviousInstructionWeight))))),
-            ext_row[19],
+            ext_row[20],
         ];
         base_constraints
             .into_iter()
@@ -66,55 +66,55 @@ impl Evaluable<BFieldElement> for ExtRamTable {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_31 = (next_base_row[52]) - (current_base_row[52]);
-        let node_32 = (node_31) * (current_base_row[54]);
+        let node_31 = (next_base_row[54]) - (current_base_row[54]);
+        let node_32 = (node_31) * (current_base_row[56]);
         let node_37 = (BFieldElement::new(1)) - (node_32);
         let node_33 = (node_32) - (BFieldElement::new(1));
-        let node_38 = (BFieldElement::new(26)) - (next_base_row[51]);
+        let node_38 = (BFieldElement::new(26)) - (next_base_row[53]);
         let node_48 =
-            (challenges.get_challenge(RamTableBezoutRelationIndeterminate)) - (next_base_row[52]);
-        let node_86 = (next_ext_row[19]) - (current_ext_row[19]);
+            (challenges.get_challenge(RamTableBezoutRelationIndeterminate)) - (next_base_row[54]);
+        let node_86 = (next_ext_row[20]) - (current_ext_row[20]);
 
         let base_constraints = [
-            (current_base_row[54]) * (node_33),
+            (current_base_row[56]) * (node_33),
             (node_31) * (node_33),
-            ((node_37) * (node_38)) * ((next_base_row[53]) - (current_base_row[53])),
-            ((node_31) * (node_38)) * (next_base_row[53]),
-            (node_37) * ((next_base_row[55]) - (current_base_row[55])),
-            (node_37) * ((next_base_row[56]) - (current_base_row[56])),
+            ((node_37) * (node_38)) * ((next_base_row[55]) - (current_base_row[55])),
+            ((node_31) * (node_38)) * (next_base_row[55]),
+            (node_37) * ((next_base_row[57]) - (current_base_row[57])),
+            (node_37) * ((next_base_row[58]) - (current_base_row[58])),
         ];
         let ext_constraints = [
-            ((node_31) * ((next_ext_row[14]) - ((current_ext_row[14]) * (node_48))))
-                + ((node_37) * ((next_ext_row[14]) - (current_ext_row[14]))),
-            ((node_31)
-                * (((next_ext_row[15]) - (current_ext_row[14]))
-                    - ((node_48) * (current_ext_row[15]))))
+            ((node_31) * ((next_ext_row[15]) - ((current_ext_row[15]) * (node_48))))
                 + ((node_37) * ((next_ext_row[15]) - (current_ext_row[15]))),
             ((node_31)
-                * (((next_ext_row[16])
-                    - ((challenges.get_challenge(RamTableBezoutRelationIndeterminate))
-                        * (current_ext_row[16])))
-                    - (next_base_row[55])))
+                * (((next_ext_row[16]) - (current_ext_row[15]))
+                    - ((node_48) * (current_ext_row[16]))))
                 + ((node_37) * ((next_ext_row[16]) - (current_ext_row[16]))),
             ((node_31)
                 * (((next_ext_row[17])
                     - ((challenges.get_challenge(RamTableBezoutRelationIndeterminate))
                         * (current_ext_row[17])))
-                    - (next_base_row[56])))
+                    - (next_base_row[57])))
                 + ((node_37) * ((next_ext_row[17]) - (current_ext_row[17]))),
-            (next_ext_row[18])
-                - ((current_ext_row[18])
+            ((node_31)
+                * (((next_ext_row[18])
+                    - ((challenges.get_challenge(RamTableBezoutRelationIndeterminate))
+                        * (current_ext_row[18])))
+                    - (next_base_row[58])))
+                + ((node_37) * ((next_ext_row[18]) - (current_ext_row[18]))),
+            (next_ext_row[19])
+                - ((current_ext_row[19])
                     * ((challenges.get_challenge(RamIndeterminate))
-                        - (((((next_base_row[50]) * (challenges.get_challenge(RamClkWeight)))
-                            + ((next_base_row[52])
+                        - (((((next_base_row[52]) * (challenges.get_challenge(RamClkWeight)))
+                            + ((next_base_row[54])
                                 * (challenges.get_challenge(RamRampWeight))))
-                            + ((next_base_row[53]) * (challenges.get_challenge(RamRamvWeight))))
-                            + ((next_base_row[51])
+                            + ((next_base_row[55]) * (challenges.get_challenge(RamRamvWeight))))
+                            + ((next_base_row[53])
                                 * (challenges.get_challenge(RamPreviousInstructionWeight)))))),
             ((node_37)
                 * (((node_86)
                     * ((challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate))
-                        - ((next_base_row[50]) - (current_base_row[50]))))
+                        - ((next_base_row[52]) - (current_base_row[52]))))
                     - (BFieldElement::new(1))))
                 + ((node_31) * (node_86)),
         ];
@@ -134,7 +134,7 @@ impl Evaluable<BFieldElement> for ExtRamTable {
     ) -> Vec<XFieldElement> {
         let base_constraints: [BFieldElement; 0] = [];
         let ext_constraints = [
-            (((ext_row[16]) * (ext_row[14])) + ((ext_row[17]) * (ext_row[15])))
+            (((ext_row[17]) * (ext_row[15])) + ((ext_row[18]) * (ext_row[16])))
                 - (BFieldElement::new(1)),
         ];
         base_constraints
@@ -154,24 +154,24 @@ impl Evaluable<XFieldElement> for ExtRamTable {
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
         let base_constraints = [
-            (base_row[53]) * ((BFieldElement::new(26)) - (base_row[51])),
-            base_row[55],
+            (base_row[55]) * ((BFieldElement::new(26)) - (base_row[53])),
+            base_row[57],
         ];
         let ext_constraints = [
-            ext_row[16],
-            (ext_row[17]) - (base_row[56]),
-            (ext_row[14])
+            ext_row[17],
+            (ext_row[18]) - (base_row[58]),
+            (ext_row[15])
                 - ((challenges.get_challenge(RamTableBezoutRelationIndeterminate))
-                    - (base_row[52])),
-            (ext_row[15]) - (BFieldElement::new(1)),
-            (ext_row[18])
+                    - (base_row[54])),
+            (ext_row[16]) - (BFieldElement::new(1)),
+            (ext_row[19])
                 - ((challenges.get_challenge(RamIndeterminate))
-                    - (((((base_row[50]) * (challenges.get_challenge(RamClkWeight)))
-                        + ((base_row[52]) * (challenges.get_challenge(RamRampWeight))))
-                        + ((base_row[53]) * (challenges.get_challenge(RamRamvWeight))))
-                        + ((base_row[51])
+                    - (((((base_row[52]) * (challenges.get_challenge(RamClkWeight)))
+                        + ((base_row[54]) * (challenges.get_challenge(RamRampWeight))))
+                        + ((base_row[55]) * (challenges.get_challenge(RamRamvWeight))))
+                        + ((base_row[53])
                             * (challenges.get_challenge(RamPreviousInstructionWeight))))),
-            ext_row[19],
+            ext_row[20],
         ];
         base_constraints
             .into_iter()
@@ -198,55 +198,55 @@ impl Evaluable<XFieldElement> for ExtRamTable {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_31 = (next_base_row[52]) - (current_base_row[52]);
-        let node_32 = (node_31) * (current_base_row[54]);
+        let node_31 = (next_base_row[54]) - (current_base_row[54]);
+        let node_32 = (node_31) * (current_base_row[56]);
         let node_37 = (BFieldElement::new(1)) - (node_32);
         let node_33 = (node_32) - (BFieldElement::new(1));
-        let node_38 = (BFieldElement::new(26)) - (next_base_row[51]);
+        let node_38 = (BFieldElement::new(26)) - (next_base_row[53]);
         let node_48 =
-            (challenges.get_challenge(RamTableBezoutRelationIndeterminate)) - (next_base_row[52]);
-        let node_86 = (next_ext_row[19]) - (current_ext_row[19]);
+            (challenges.get_challenge(RamTableBezoutRelationIndeterminate)) - (next_base_row[54]);
+        let node_86 = (next_ext_row[20]) - (current_ext_row[20]);
 
         let base_constraints = [
-            (current_base_row[54]) * (node_33),
+            (current_base_row[56]) * (node_33),
             (node_31) * (node_33),
-            ((node_37) * (node_38)) * ((next_base_row[53]) - (current_base_row[53])),
-            ((node_31) * (node_38)) * (next_base_row[53]),
-            (node_37) * ((next_base_row[55]) - (current_base_row[55])),
-            (node_37) * ((next_base_row[56]) - (current_base_row[56])),
+            ((node_37) * (node_38)) * ((next_base_row[55]) - (current_base_row[55])),
+            ((node_31) * (node_38)) * (next_base_row[55]),
+            (node_37) * ((next_base_row[57]) - (current_base_row[57])),
+            (node_37) * ((next_base_row[58]) - (current_base_row[58])),
         ];
         let ext_constraints = [
-            ((node_31) * ((next_ext_row[14]) - ((current_ext_row[14]) * (node_48))))
-                + ((node_37) * ((next_ext_row[14]) - (current_ext_row[14]))),
-            ((node_31)
-                * (((next_ext_row[15]) - (current_ext_row[14]))
-                    - ((node_48) * (current_ext_row[15]))))
+            ((node_31) * ((next_ext_row[15]) - ((current_ext_row[15]) * (node_48))))
                 + ((node_37) * ((next_ext_row[15]) - (current_ext_row[15]))),
             ((node_31)
-                * (((next_ext_row[16])
-                    - ((challenges.get_challenge(RamTableBezoutRelationIndeterminate))
-                        * (current_ext_row[16])))
-                    - (next_base_row[55])))
+                * (((next_ext_row[16]) - (current_ext_row[15]))
+                    - ((node_48) * (current_ext_row[16]))))
                 + ((node_37) * ((next_ext_row[16]) - (current_ext_row[16]))),
             ((node_31)
                 * (((next_ext_row[17])
                     - ((challenges.get_challenge(RamTableBezoutRelationIndeterminate))
                         * (current_ext_row[17])))
-                    - (next_base_row[56])))
+                    - (next_base_row[57])))
                 + ((node_37) * ((next_ext_row[17]) - (current_ext_row[17]))),
-            (next_ext_row[18])
-                - ((current_ext_row[18])
+            ((node_31)
+                * (((next_ext_row[18])
+                    - ((challenges.get_challenge(RamTableBezoutRelationIndeterminate))
+                        * (current_ext_row[18])))
+                    - (next_base_row[58])))
+                + ((node_37) * ((next_ext_row[18]) - (current_ext_row[18]))),
+            (next_ext_row[19])
+                - ((current_ext_row[19])
                     * ((challenges.get_challenge(RamIndeterminate))
-                        - (((((next_base_row[50]) * (challenges.get_challenge(RamClkWeight)))
-                            + ((next_base_row[52])
+                        - (((((next_base_row[52]) * (challenges.get_challenge(RamClkWeight)))
+                            + ((next_base_row[54])
                                 * (challenges.get_challenge(RamRampWeight))))
-                            + ((next_base_row[53]) * (challenges.get_challenge(RamRamvWeight))))
-                            + ((next_base_row[51])
+                            + ((next_base_row[55]) * (challenges.get_challenge(RamRamvWeight))))
+                            + ((next_base_row[53])
                                 * (challenges.get_challenge(RamPreviousInstructionWeight)))))),
             ((node_37)
                 * (((node_86)
                     * ((challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate))
-                        - ((next_base_row[50]) - (current_base_row[50]))))
+                        - ((next_base_row[52]) - (current_base_row[52]))))
                     - (BFieldElement::new(1))))
                 + ((node_31) * (node_86)),
         ];
@@ -265,7 +265,7 @@ impl Evaluable<XFieldElement> for ExtRamTable {
     ) -> Vec<XFieldElement> {
         let base_constraints = [];
         let ext_constraints = [
-            (((ext_row[16]) * (ext_row[14])) + ((ext_row[17]) * (ext_row[15])))
+            (((ext_row[17]) * (ext_row[15])) + ((ext_row[18]) * (ext_row[16])))
                 - (BFieldElement::new(1)),
         ];
         base_constraints
@@ -0,0 +1,306 @@
+use ndarray::ArrayView1;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::mpolynomial::Degree;
+use twenty_first::shared_math::x_field_element::XFieldElement;
+
+use crate::table::challenges::ChallengeId::*;
+use crate::table::challenges::Challenges;
+use crate::table::extension_table::Evaluable;
+use crate::table::extension_table::Quotientable;
+use crate::table::register_table::ExtRegisterTable;
+
+// This file has been auto-generated. Any modifications _will_ be lost.
+// To re-generate, execute:
+// `cargo run --bin constraint-evaluation-generator`
+impl Evaluable<BFieldElement> for ExtRegisterTable {
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_initial_constraints(
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let base_constraints = [
+            base_row[244],
+            (base_row[245]) - (BFieldElement::new(1)),
+            base_row[246],
+        ];
+        let ext_constraints = [(ext_row[54]) - (BFieldElement::new(1)), ext_row[55]];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_consistency_constraints(
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_15 = (BFieldElement::new(1)) - ((base_row[246]) * (base_row[249]));
+
+        let base_constraints = [
+            (base_row[244]) * ((base_row[244]) - (BFieldElement::new(1))),
+            (base_row[245]) * ((base_row[245]) - (BFieldElement::new(1))),
+            (base_row[248]) * ((base_row[248]) - (BFieldElement::new(1))),
+            (base_row[245]) * (base_row[247]),
+            (base_row[246]) * (node_15),
+            (base_row[249]) * (node_15),
+            (node_15) * (base_row[247]),
+        ];
+        let ext_constraints = [];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_transition_constraints(
+        current_base_row: ArrayView1<BFieldElement>,
+        current_ext_row: ArrayView1<XFieldElement>,
+        next_base_row: ArrayView1<BFieldElement>,
+        next_ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_12 = (BFieldElement::new(1)) - (next_base_row[245]);
+        let node_19 = (node_12) * ((BFieldElement::new(1)) - (next_base_row[244]));
+        let node_20 = (BFieldElement::new(1)) - (node_19);
+        let node_45 = (next_ext_row[55]) - (current_ext_row[55]);
+
+        let base_constraints = [
+            (current_base_row[244]) * ((next_base_row[244]) - (BFieldElement::new(1))),
+            ((next_base_row[246]) - (current_base_row[246])) - (next_base_row[245]),
+            (((BFieldElement::new(1)) - (next_base_row[248])) * (node_12))
+                * ((next_base_row[247]) - (current_base_row[247])),
+        ];
+        let ext_constraints = [
+            ((node_19)
+                * ((next_ext_row[54])
+                    - ((current_ext_row[54])
+                        * ((challenges.get_challenge(RegisterIndeterminate))
+                            - (((((challenges.get_challenge(RegisterClkWeight))
+                                * (next_base_row[243]))
+                                + ((challenges.get_challenge(RegisterIndexWeight))
+                                    * (next_base_row[246])))
+                                + ((challenges.get_challenge(RegisterValueWeight))
+                                    * (next_base_row[247])))
+                                + ((challenges.get_challenge(RegisterIsWriteWeight))
+                                    * (next_base_row[248])))))))
+                + ((node_20) * ((next_ext_row[54]) - (current_ext_row[54]))),
+            ((node_19)
+                * (((node_45)
+                    * ((challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate))
+                        - (((next_base_row[243]) - (current_base_row[243]))
+                            - (current_base_row[248]))))
+                    - (BFieldElement::new(1))))
+                + ((node_20) * (node_45)),
+        ];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_terminal_constraints(
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let base_constraints = [(base_row[246]) - (BFieldElement::new(31))];
+        let ext_constraints = [];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+}
+
+impl Evaluable<XFieldElement> for ExtRegisterTable {
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_initial_constraints(
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let base_constraints = [
+            base_row[244],
+            (base_row[245]) - (BFieldElement::new(1)),
+            base_row[246],
+        ];
+        let ext_constraints = [(ext_row[54]) - (BFieldElement::new(1)), ext_row[55]];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_consistency_constraints(
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_15 = (BFieldElement::new(1)) - ((base_row[246]) * (base_row[249]));
+
+        let base_constraints = [
+            (base_row[244]) * ((base_row[244]) - (BFieldElement::new(1))),
+            (base_row[245]) * ((base_row[245]) - (BFieldElement::new(1))),
+            (base_row[248]) * ((base_row[248]) - (BFieldElement::new(1))),
+            (base_row[245]) * (base_row[247]),
+            (base_row[246]) * (node_15),
+            (base_row[249]) * (node_15),
+            (node_15) * (base_row[247]),
+        ];
+        let ext_constraints = [];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_transition_constraints(
+        current_base_row: ArrayView1<XFieldElement>,
+        current_ext_row: ArrayView1<XFieldElement>,
+        next_base_row: ArrayView1<XFieldElement>,
+        next_ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_12 = (BFieldElement::new(1)) - (next_base_row[245]);
+        let node_19 = (node_12) * ((BFieldElement::new(1)) - (next_base_row[244]));
+        let node_20 = (BFieldElement::new(1)) - (node_19);
+        let node_45 = (next_ext_row[55]) - (current_ext_row[55]);
+
+        let base_constraints = [
+            (current_base_row[244]) * ((next_base_row[244]) - (BFieldElement::new(1))),
+            ((next_base_row[246]) - (current_base_row[246])) - (next_base_row[245]),
+            (((BFieldElement::new(1)) - (next_base_row[248])) * (node_12))
+                * ((next_base_row[247]) - (current_base_row[247])),
+        ];
+        let ext_constraints = [
+            ((node_19)
+                * ((next_ext_row[54])
+                    - ((current_ext_row[54])
+                        * ((challenges.get_challenge(RegisterIndeterminate))
+                            - (((((challenges.get_challenge(RegisterClkWeight))
+                                * (next_base_row[243]))
+                                + ((challenges.get_challenge(RegisterIndexWeight))
+                                    * (next_base_row[246])))
+                                + ((challenges.get_challenge(RegisterValueWeight))
+                                    * (next_base_row[247])))
+                                + ((challenges.get_challenge(RegisterIsWriteWeight))
+                                    * (next_base_row[248])))))))
+                + ((node_20) * ((next_ext_row[54]) - (current_ext_row[54]))),
+            ((node_19)
+                * (((node_45)
+                    * ((challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate))
+                        - (((next_base_row[243]) - (current_base_row[243]))
+                            - (current_base_row[248]))))
+                    - (BFieldElement::new(1))))
+                + ((node_20) * (node_45)),
+        ];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_terminal_constraints(
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let base_constraints = [(base_row[246]) - (BFieldElement::new(31))];
+        let ext_constraints = [];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+}
+
+impl Quotientable for ExtRegisterTable {
+    fn num_initial_quotients() -> usize {
+        5
+    }
+
+    fn num_consistency_quotients() -> usize {
+        7
+    }
+
+    fn num_transition_quotients() -> usize {
+        5
+    }
+
+    fn num_terminal_quotients() -> usize {
+        1
+    }
+
+    #[allow(unused_variables)]
+    fn initial_quotient_degree_bounds(interpolant_degree: Degree) -> Vec<Degree> {
+        let zerofier_degree = 1 as Degree;
+        [
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+        ]
+        .to_vec()
+    }
+
+    #[allow(unused_variables)]
+    fn consistency_quotient_degree_bounds(
+        interpolant_degree: Degree,
+        padded_height: usize,
+    ) -> Vec<Degree> {
+        let zerofier_degree = padded_height as Degree;
+        [
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+        ]
+        .to_vec()
+    }
+
+    #[allow(unused_variables)]
+    fn transition_quotient_degree_bounds(
+        interpolant_degree: Degree,
+        padded_height: usize,
+    ) -> Vec<Degree> {
+        let zerofier_degree = padded_height as Degree - 1;
+        [
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 4 as Degree - zerofier_degree,
+            interpolant_degree * 4 as Degree - zerofier_degree,
+        ]
+        .to_vec()
+    }
+
+    #[allow(unused_variables)]
+    fn terminal_quotient_degree_bounds(interpolant_degree: Degree) -> Vec<Degree> {
+        let zerofier_degree = 1 as Degree;
+        [interpolant_degree * 1 as Degree - zerofier_degree].to_vec()
+    }
+}
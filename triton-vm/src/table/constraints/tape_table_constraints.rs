@@ -0,0 +1,286 @@
+use ndarray::ArrayView1;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::mpolynomial::Degree;
+use twenty_first::shared_math::x_field_element::XFieldElement;
+
+use crate::table::challenges::ChallengeId::*;
+use crate::table::challenges::Challenges;
+use crate::table::extension_table::Evaluable;
+use crate::table::extension_table::Quotientable;
+use crate::table::tape_table::ExtTapeTable;
+
+// This file has been auto-generated. Any modifications _will_ be lost.
+// To re-generate, execute:
+// `cargo run --bin constraint-evaluation-generator`
+impl Evaluable<BFieldElement> for ExtTapeTable {
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_initial_constraints(
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_4 = (BFieldElement::new(1)) - (base_row[250]);
+        let node_8 = (node_4) * ((BFieldElement::new(1)) - (base_row[251]));
+
+        let base_constraints = [base_row[252], (base_row[151]) * (node_4)];
+        let ext_constraints = [
+            ((node_8)
+                * (((ext_row[56]) - (challenges.get_challenge(StandardInputIndeterminate)))
+                    - (base_row[253])))
+                + (((BFieldElement::new(1)) - (node_8))
+                    * ((ext_row[56]) - (BFieldElement::new(1)))),
+            ((ext_row[57])
+                * ((challenges.get_challenge(TapeIndeterminate))
+                    - (((challenges.get_challenge(TapeIsSecretWeight)) * (base_row[251]))
+                        + ((challenges.get_challenge(TapeValueWeight)) * (base_row[253])))))
+                - (base_row[254]),
+        ];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_consistency_constraints(
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let base_constraints = [
+            (base_row[250]) * ((base_row[250]) - (BFieldElement::new(1))),
+            (base_row[251]) * ((base_row[251]) - (BFieldElement::new(1))),
+            (base_row[250]) * (base_row[254]),
+        ];
+        let ext_constraints = [];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_transition_constraints(
+        current_base_row: ArrayView1<BFieldElement>,
+        current_ext_row: ArrayView1<XFieldElement>,
+        next_base_row: ArrayView1<BFieldElement>,
+        next_ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_10 = (BFieldElement::new(1)) - (next_base_row[250]);
+        let node_19 = (node_10) * ((BFieldElement::new(1)) - (next_base_row[251]));
+
+        let base_constraints = [
+            (current_base_row[250]) * ((next_base_row[250]) - (BFieldElement::new(1))),
+            (current_base_row[251]) * ((next_base_row[251]) - (BFieldElement::new(1))),
+            (node_10)
+                * ((next_base_row[252])
+                    - (((BFieldElement::new(1))
+                        - ((next_base_row[251]) - (current_base_row[251])))
+                        * ((current_base_row[252]) + (BFieldElement::new(1))))),
+        ];
+        let ext_constraints = [
+            ((next_ext_row[56])
+                - ((node_19)
+                    * (((current_ext_row[56])
+                        * (challenges.get_challenge(StandardInputIndeterminate)))
+                        + (next_base_row[253]))))
+                - (((BFieldElement::new(1)) - (node_19)) * (current_ext_row[56])),
+            (((next_ext_row[57]) - (current_ext_row[57]))
+                * ((challenges.get_challenge(TapeIndeterminate))
+                    - ((((challenges.get_challenge(TapeIsSecretWeight)) * (next_base_row[251]))
+                        + ((challenges.get_challenge(TapePositionWeight))
+                            * (next_base_row[252])))
+                        + ((challenges.get_challenge(TapeValueWeight)) * (next_base_row[253])))))
+                - (next_base_row[254]),
+        ];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_terminal_constraints(
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        vec![]
+    }
+}
+
+impl Evaluable<XFieldElement> for ExtTapeTable {
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_initial_constraints(
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_4 = (BFieldElement::new(1)) - (base_row[250]);
+        let node_8 = (node_4) * ((BFieldElement::new(1)) - (base_row[251]));
+
+        let base_constraints = [base_row[252], (base_row[151]) * (node_4)];
+        let ext_constraints = [
+            ((node_8)
+                * (((ext_row[56]) - (challenges.get_challenge(StandardInputIndeterminate)))
+                    - (base_row[253])))
+                + (((BFieldElement::new(1)) - (node_8))
+                    * ((ext_row[56]) - (BFieldElement::new(1)))),
+            ((ext_row[57])
+                * ((challenges.get_challenge(TapeIndeterminate))
+                    - (((challenges.get_challenge(TapeIsSecretWeight)) * (base_row[251]))
+                        + ((challenges.get_challenge(TapeValueWeight)) * (base_row[253])))))
+                - (base_row[254]),
+        ];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_consistency_constraints(
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let base_constraints = [
+            (base_row[250]) * ((base_row[250]) - (BFieldElement::new(1))),
+            (base_row[251]) * ((base_row[251]) - (BFieldElement::new(1))),
+            (base_row[250]) * (base_row[254]),
+        ];
+        let ext_constraints = [];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_transition_constraints(
+        current_base_row: ArrayView1<XFieldElement>,
+        current_ext_row: ArrayView1<XFieldElement>,
+        next_base_row: ArrayView1<XFieldElement>,
+        next_ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_10 = (BFieldElement::new(1)) - (next_base_row[250]);
+        let node_19 = (node_10) * ((BFieldElement::new(1)) - (next_base_row[251]));
+
+        let base_constraints = [
+            (current_base_row[250]) * ((next_base_row[250]) - (BFieldElement::new(1))),
+            (current_base_row[251]) * ((next_base_row[251]) - (BFieldElement::new(1))),
+            (node_10)
+                * ((next_base_row[252])
+                    - (((BFieldElement::new(1))
+                        - ((next_base_row[251]) - (current_base_row[251])))
+                        * ((current_base_row[252]) + (BFieldElement::new(1))))),
+        ];
+        let ext_constraints = [
+            ((next_ext_row[56])
+                - ((node_19)
+                    * (((current_ext_row[56])
+                        * (challenges.get_challenge(StandardInputIndeterminate)))
+                        + (next_base_row[253]))))
+                - (((BFieldElement::new(1)) - (node_19)) * (current_ext_row[56])),
+            (((next_ext_row[57]) - (current_ext_row[57]))
+                * ((challenges.get_challenge(TapeIndeterminate))
+                    - ((((challenges.get_challenge(TapeIsSecretWeight)) * (next_base_row[251]))
+                        + ((challenges.get_challenge(TapePositionWeight))
+                            * (next_base_row[252])))
+                        + ((challenges.get_challenge(TapeValueWeight)) * (next_base_row[253])))))
+                - (next_base_row[254]),
+        ];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_terminal_constraints(
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        vec![]
+    }
+}
+
+impl Quotientable for ExtTapeTable {
+    fn num_initial_quotients() -> usize {
+        4
+    }
+
+    fn num_consistency_quotients() -> usize {
+        3
+    }
+
+    fn num_transition_quotients() -> usize {
+        5
+    }
+
+    fn num_terminal_quotients() -> usize {
+        0
+    }
+
+    #[allow(unused_variables)]
+    fn initial_quotient_degree_bounds(interpolant_degree: Degree) -> Vec<Degree> {
+        let zerofier_degree = 1 as Degree;
+        [
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+        ]
+        .to_vec()
+    }
+
+    #[allow(unused_variables)]
+    fn consistency_quotient_degree_bounds(
+        interpolant_degree: Degree,
+        padded_height: usize,
+    ) -> Vec<Degree> {
+        let zerofier_degree = padded_height as Degree;
+        [
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+        ]
+        .to_vec()
+    }
+
+    #[allow(unused_variables)]
+    fn transition_quotient_degree_bounds(
+        interpolant_degree: Degree,
+        padded_height: usize,
+    ) -> Vec<Degree> {
+        let zerofier_degree = padded_height as Degree - 1;
+        [
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+        ]
+        .to_vec()
+    }
+
+    #[allow(unused_variables)]
+    fn terminal_quotient_degree_bounds(interpolant_degree: Degree) -> Vec<Degree> {
+        let zerofier_degree = 1 as Degree;
+        [].to_vec()
+    }
+}
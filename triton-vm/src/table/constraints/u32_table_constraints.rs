@@ -22,18 +22,18 @@ impl Evaluable<BFieldElement> for ExtU32Table {
     ) -> Vec<XFieldElement> {
         let base_constraints: [BFieldElement; 0] = [];
         let ext_constraints = [
-            (((base_row[138]) - (BFieldElement::new(1))) * (ext_row[45]))
-                + ((base_row[138])
-                    * (((ext_row[45])
+            (((base_row[140]) - (BFieldElement::new(1))) * (ext_row[46]))
+                + ((base_row[140])
+                    * (((ext_row[46])
                         * ((challenges.get_challenge(U32Indeterminate))
                             - (((((challenges.get_challenge(U32LhsWeight))
-                                * (base_row[142]))
+                                * (base_row[144]))
                                 + ((challenges.get_challenge(U32RhsWeight))
-                                    * (base_row[144])))
-                                + ((challenges.get_challenge(U32CiWeight)) * (base_row[141])))
+                                    * (base_row[146])))
+                                + ((challenges.get_challenge(U32CiWeight)) * (base_row[143])))
                                 + ((challenges.get_challenge(U32ResultWeight))
-                                    * (base_row[146])))))
-                        - (base_row[147]))),
+                                    * (base_row[148])))))
+                        - (base_row[149]))),
         ];
         base_constraints
             .into_iter()
@@ -49,39 +49,39 @@ impl Evaluable<BFieldElement> for ExtU32Table {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_20 = (BFieldElement::new(1)) - ((base_row[142]) * (base_row[143]));
-        let node_28 = (base_row[141]) - (BFieldElement::new(4));
-        let node_24 = (BFieldElement::new(1)) - ((base_row[144]) * (base_row[145]));
-        let node_31 = (base_row[141]) - (BFieldElement::new(20));
-        let node_37 = (base_row[141]) - (BFieldElement::new(44));
-        let node_40 = (base_row[141]) - (BFieldElement::new(60));
-        let node_34 = (base_row[141]) - (BFieldElement::new(36));
-        let node_54 = (node_28) * ((base_row[141]) - (BFieldElement::new(12)));
+        let node_20 = (BFieldElement::new(1)) - ((base_row[144]) * (base_row[145]));
+        let node_28 = (base_row[143]) - (BFieldElement::new(4));
+        let node_24 = (BFieldElement::new(1)) - ((base_row[146]) * (base_row[147]));
+        let node_31 = (base_row[143]) - (BFieldElement::new(20));
+        let node_37 = (base_row[143]) - (BFieldElement::new(44));
+        let node_40 = (base_row[143]) - (BFieldElement::new(60));
+        let node_34 = (base_row[143]) - (BFieldElement::new(36));
+        let node_54 = (node_28) * ((base_row[143]) - (BFieldElement::new(12)));
         let node_61 = (node_54) * (node_31);
-        let node_42 = (base_row[138]) - (BFieldElement::new(1));
+        let node_42 = (base_row[140]) - (BFieldElement::new(1));
         let node_41 = ((((node_28) * (node_31)) * (node_34)) * (node_37)) * (node_40);
         let node_62 = (node_61) * (node_34);
         let node_68 = ((node_61) * (node_37)) * (node_40);
 
         let base_constraints = [
-            (base_row[138]) * ((BFieldElement::new(1)) - (base_row[138])),
-            (base_row[138]) * (base_row[139]),
+            (base_row[140]) * ((BFieldElement::new(1)) - (base_row[140])),
+            (base_row[140]) * (base_row[141]),
             (BFieldElement::new(1))
-                - ((base_row[140]) * ((base_row[139]) - (BFieldElement::new(33)))),
-            (base_row[143]) * (node_20),
-            (base_row[142]) * (node_20),
-            (base_row[145]) * (node_24),
-            (base_row[144]) * (node_24),
+                - ((base_row[142]) * ((base_row[141]) - (BFieldElement::new(33)))),
+            (base_row[145]) * (node_20),
+            (base_row[144]) * (node_20),
+            (base_row[147]) * (node_24),
+            (base_row[146]) * (node_24),
             ((((node_41) * (node_42)) * (node_20)) * (node_24))
-                * ((base_row[146]) - (BFieldElement::new(2))),
-            ((((node_41) * (base_row[138])) * (node_20)) * (node_24)) * (base_row[146]),
+                * ((base_row[148]) - (BFieldElement::new(2))),
+            ((((node_41) * (base_row[140])) * (node_20)) * (node_24)) * (base_row[148]),
             ((((((node_54) * (node_34)) * (node_37)) * (node_40)) * (node_20)) * (node_24))
-                * (base_row[146]),
-            (((node_62) * (node_40)) * (node_24)) * ((base_row[146]) - (BFieldElement::new(1))),
-            (((node_68) * (node_42)) * (node_20)) * ((base_row[146]) + (BFieldElement::new(1))),
-            (((node_62) * (node_37)) * (node_20)) * (base_row[146]),
-            ((node_68) * (base_row[138])) * (node_20),
-            (node_42) * (base_row[147]),
+                * (base_row[148]),
+            (((node_62) * (node_40)) * (node_24)) * ((base_row[148]) - (BFieldElement::new(1))),
+            (((node_68) * (node_42)) * (node_20)) * ((base_row[148]) + (BFieldElement::new(1))),
+            (((node_62) * (node_37)) * (node_20)) * (base_row[148]),
+            ((node_68) * (base_row[140])) * (node_20),
+            (node_42) * (base_row[149]),
         ];
         let ext_constraints = [];
         base_constraints
@@ -100,78 +100,78 @@ impl Evaluable<BFieldElement> for ExtU32Table {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_27 = (next_base_row[138]) - (BFieldElement::new(1));
-        let node_45 = (next_base_row[141]) - (BFieldElement::new(4));
-        let node_48 = (next_base_row[141]) - (BFieldElement::new(20));
-        let node_56 = (next_base_row[141]) - (BFieldElement::new(60));
-        let node_23 = (current_base_row[144]) - ((BFieldElement::new(2)) * (next_base_row[144]));
-        let node_51 = (next_base_row[141]) - (BFieldElement::new(36));
-        let node_21 = (current_base_row[142]) - ((BFieldElement::new(2)) * (next_base_row[142]));
-        let node_53 = (next_base_row[141]) - (BFieldElement::new(44));
-        let node_89 = (node_45) * ((next_base_row[141]) - (BFieldElement::new(12)));
+        let node_27 = (next_base_row[140]) - (BFieldElement::new(1));
+        let node_45 = (next_base_row[143]) - (BFieldElement::new(4));
+        let node_48 = (next_base_row[143]) - (BFieldElement::new(20));
+        let node_56 = (next_base_row[143]) - (BFieldElement::new(60));
+        let node_23 = (current_base_row[146]) - ((BFieldElement::new(2)) * (next_base_row[146]));
+        let node_51 = (next_base_row[143]) - (BFieldElement::new(36));
+        let node_21 = (current_base_row[144]) - ((BFieldElement::new(2)) * (next_base_row[144]));
+        let node_53 = (next_base_row[143]) - (BFieldElement::new(44));
+        let node_89 = (node_45) * ((next_base_row[143]) - (BFieldElement::new(12)));
         let node_58 = (node_27) * (((((node_45) * (node_48)) * (node_51)) * (node_53)) * (node_56));
         let node_99 = (node_89) * (node_48);
-        let node_59 = (next_base_row[146]) - (BFieldElement::new(1));
-        let node_64 = (node_58) * (next_base_row[146]);
+        let node_59 = (next_base_row[148]) - (BFieldElement::new(1));
+        let node_64 = (node_58) * (next_base_row[148]);
         let node_68 = (node_64) * (node_59);
         let node_112 = (node_99) * (node_51);
-        let node_19 = (current_base_row[141]) - (BFieldElement::new(44));
+        let node_19 = (current_base_row[143]) - (BFieldElement::new(44));
         let node_42 = (node_23) - (BFieldElement::new(1));
         let node_114 = (node_27) * ((node_112) * (node_56));
-        let node_33 = ((next_base_row[139]) - (current_base_row[139])) - (BFieldElement::new(1));
+        let node_33 = ((next_base_row[141]) - (current_base_row[141])) - (BFieldElement::new(1));
         let node_39 = (node_21) - (BFieldElement::new(1));
-        let node_61 = (next_base_row[146]) - (BFieldElement::new(2));
-        let node_66 = (current_base_row[146]) - (BFieldElement::new(1));
+        let node_61 = (next_base_row[148]) - (BFieldElement::new(2));
+        let node_66 = (current_base_row[148]) - (BFieldElement::new(1));
         let node_80 = (node_68)
             * ((((BFieldElement::new(1)) - (node_21)) - (node_23))
                 + (((BFieldElement::new(2)) * (node_21)) * (node_23)));
         let node_102 = (node_27) * (((node_99) * (node_53)) * (node_56));
-        let node_118 = (next_base_row[146]) * (next_base_row[146]);
-        let node_130 = (next_ext_row[45]) - (current_ext_row[45]);
+        let node_118 = (next_base_row[148]) * (next_base_row[148]);
+        let node_130 = (next_ext_row[46]) - (current_ext_row[46]);
 
         let base_constraints = [
-            ((next_base_row[138]) * (current_base_row[142])) * (node_19),
-            (next_base_row[138]) * (current_base_row[144]),
-            (node_27) * ((next_base_row[141]) - (current_base_row[141])),
-            (((node_27) * (current_base_row[142])) * (node_19)) * (node_33),
-            ((node_27) * (current_base_row[144])) * (node_33),
+            ((next_base_row[140]) * (current_base_row[144])) * (node_19),
+            (next_base_row[140]) * (current_base_row[146]),
+            (node_27) * ((next_base_row[143]) - (current_base_row[143])),
+            (((node_27) * (current_base_row[144])) * (node_19)) * (node_33),
+            ((node_27) * (current_base_row[146])) * (node_33),
             (((node_27) * (node_19)) * (node_21)) * (node_39),
             ((node_27) * (node_23)) * (node_42),
-            (((node_58) * (node_59)) * (node_61)) * (current_base_row[146]),
+            (((node_58) * (node_59)) * (node_61)) * (current_base_row[148]),
             ((node_64) * (node_61)) * (node_66),
             (((node_68) * (node_39)) * (node_23)) * (node_66),
-            (((node_68) * (node_21)) * (node_42)) * (current_base_row[146]),
-            ((node_80) * ((current_base_row[138]) - (BFieldElement::new(1))))
-                * ((current_base_row[146]) - (BFieldElement::new(2))),
-            ((node_80) * (current_base_row[138])) * (current_base_row[146]),
+            (((node_68) * (node_21)) * (node_42)) * (current_base_row[148]),
+            ((node_80) * ((current_base_row[140]) - (BFieldElement::new(1))))
+                * ((current_base_row[148]) - (BFieldElement::new(2))),
+            ((node_80) * (current_base_row[140])) * (current_base_row[148]),
             ((node_27) * ((((node_89) * (node_51)) * (node_53)) * (node_56)))
-                * (((current_base_row[146]) - ((BFieldElement::new(2)) * (next_base_row[146])))
+                * (((current_base_row[148]) - ((BFieldElement::new(2)) * (next_base_row[148])))
                     - ((node_21) * (node_23))),
             (((node_102)
-                * ((BFieldElement::new(1)) - ((next_base_row[142]) * (next_base_row[143]))))
-                * (current_base_row[142]))
-                * ((current_base_row[146]) - (current_base_row[139])),
-            ((node_102) * (next_base_row[142])) * ((next_base_row[146]) - (current_base_row[146])),
-            (node_114) * ((next_base_row[142]) - (current_base_row[142])),
-            ((node_114) * (node_42)) * ((current_base_row[146]) - (node_118)),
+                * ((BFieldElement::new(1)) - ((next_base_row[144]) * (next_base_row[145]))))
+                * (current_base_row[144]))
+                * ((current_base_row[148]) - (current_base_row[141])),
+            ((node_102) * (next_base_row[144])) * ((next_base_row[148]) - (current_base_row[148])),
+            (node_114) * ((next_base_row[144]) - (current_base_row[144])),
+            ((node_114) * (node_42)) * ((current_base_row[148]) - (node_118)),
             ((node_114) * (node_23))
-                * ((current_base_row[146]) - ((node_118) * (current_base_row[142]))),
+                * ((current_base_row[148]) - ((node_118) * (current_base_row[144]))),
             ((node_27) * ((node_112) * (node_53)))
-                * (((current_base_row[146]) - (next_base_row[146])) - (node_21)),
+                * (((current_base_row[148]) - (next_base_row[148])) - (node_21)),
         ];
         let ext_constraints = [
             (node_27) * (node_130),
-            (next_base_row[138])
+            (next_base_row[140])
                 * (((node_130)
                     * ((challenges.get_challenge(U32Indeterminate))
-                        - (((((challenges.get_challenge(U32CiWeight)) * (next_base_row[141]))
+                        - (((((challenges.get_challenge(U32CiWeight)) * (next_base_row[143]))
                             + ((challenges.get_challenge(U32LhsWeight))
-                                * (next_base_row[142])))
-                            + ((challenges.get_challenge(U32RhsWeight))
                                 * (next_base_row[144])))
+                            + ((challenges.get_challenge(U32RhsWeight))
+                                * (next_base_row[146])))
                             + ((challenges.get_challenge(U32ResultWeight))
-                                * (next_base_row[146])))))
-                    - (next_base_row[147])),
+                                * (next_base_row[148])))))
+                    - (next_base_row[149])),
         ];
         base_constraints
             .into_iter()
@@ -188,8 +188,8 @@ impl Evaluable<BFieldElement> for ExtU32Table {
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
         let base_constraints = [
-            (base_row[142]) * ((base_row[141]) - (BFieldElement::new(44))),
-            base_row[144],
+            (base_row[144]) * ((base_row[143]) - (BFieldElement::new(44))),
+            base_row[146],
         ];
         let ext_constraints = [];
         base_constraints
@@ -210,18 +210,18 @@ impl Evaluable<XFieldElement> for ExtU32Table {
     ) -> Vec<XFieldElement> {
         let base_constraints = [];
         let ext_constraints = [
-            (((base_row[138]) - (BFieldElement::new(1))) * (ext_row[45]))
-                + ((base_row[138])
-                    * (((ext_row[45])
+            (((base_row[140]) - (BFieldElement::new(1))) * (ext_row[46]))
+                + ((base_row[140])
+                    * (((ext_row[46])
                         * ((challenges.get_challenge(U32Indeterminate))
                             - (((((challenges.get_challenge(U32LhsWeight))
-                                * (base_row[142]))
+                                * (base_row[144]))
                                 + ((challenges.get_challenge(U32RhsWeight))
-                                    * (base_row[144])))
-                                + ((challenges.get_challenge(U32CiWeight)) * (base_row[141])))
+                                    * (base_row[146])))
+                                + ((challenges.get_challenge(U32CiWeight)) * (base_row[143])))
                                 + ((challenges.get_challenge(U32ResultWeight))
-                                    * (base_row[146])))))
-                        - (base_row[147]))),
+                                    * (base_row[148])))))
+                        - (base_row[149]))),
         ];
         base_constraints
             .into_iter()
@@ -236,39 +236,39 @@ impl Evaluable<XFieldElement> for ExtU32Table {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_20 = (BFieldElement::new(1)) - ((base_row[142]) * (base_row[143]));
-        let node_28 = (base_row[141]) - (BFieldElement::new(4));
-        let node_24 = (BFieldElement::new(1)) - ((base_row[144]) * (base_row[145]));
-        let node_31 = (base_row[141]) - (BFieldElement::new(20));
-        let node_37 = (base_row[141]) - (BFieldElement::new(44));
-        let node_40 = (base_row[141]) - (BFieldElement::new(60));
-        let node_34 = (base_row[141]) - (BFieldElement::new(36));
-        let node_54 = (node_28) * ((base_row[141]) - (BFieldElement::new(12)));
+        let node_20 = (BFieldElement::new(1)) - ((base_row[144]) * (base_row[145]));
+        let node_28 = (base_row[143]) - (BFieldElement::new(4));
+        let node_24 = (BFieldElement::new(1)) - ((base_row[146]) * (base_row[147]));
+        let node_31 = (base_row[143]) - (BFieldElement::new(20));
+        let node_37 = (base_row[143]) - (BFieldElement::new(44));
+        let node_40 = (base_row[143]) - (BFieldElement::new(60));
+        let node_34 = (base_row[143]) - (BFieldElement::new(36));
+        let node_54 = (node_28) * ((base_row[143]) - (BFieldElement::new(12)));
         let node_61 = (node_54) * (node_31);
-        let node_42 = (base_row[138]) - (BFieldElement::new(1));
+        let node_42 = (base_row[140]) - (BFieldElement::new(1));
         let node_41 = ((((node_28) * (node_31)) * (node_34)) * (node_37)) * (node_40);
         let node_62 = (node_61) * (node_34);
         let node_68 = ((node_61) * (node_37)) * (node_40);
 
         let base_constraints = [
-            (base_row[138]) * ((BFieldElement::new(1)) - (base_row[138])),
-            (base_row[138]) * (base_row[139]),
+            (base_row[140]) * ((BFieldElement::new(1)) - (base_row[140])),
+            (base_row[140]) * (base_row[141]),
             (BFieldElement::new(1))
-                - ((base_row[140]) * ((base_row[139]) - (BFieldElement::new(33)))),
-            (base_row[143]) * (node_20),
-            (base_row[142]) * (node_20),
-            (base_row[145]) * (node_24),
-            (base_row[144]) * (node_24),
+                - ((base_row[142]) * ((base_row[141]) - (BFieldElement::new(33)))),
+            (base_row[145]) * (node_20),
+            (base_row[144]) * (node_20),
+            (base_row[147]) * (node_24),
+            (base_row[146]) * (node_24),
             ((((node_41) * (node_42)) * (node_20)) * (node_24))
-                * ((base_row[146]) - (BFieldElement::new(2))),
-            ((((node_41) * (base_row[138])) * (node_20)) * (node_24)) * (base_row[146]),
+                * ((base_row[148]) - (BFieldElement::new(2))),
+            ((((node_41) * (base_row[140])) * (node_20)) * (node_24)) * (base_row[148]),
             ((((((node_54) * (node_34)) * (node_37)) * (node_40)) * (node_20)) * (node_24))
-                * (base_row[146]),
-            (((node_62) * (node_40)) * (node_24)) * ((base_row[146]) - (BFieldElement::new(1))),
-            (((node_68) * (node_42)) * (node_20)) * ((base_row[146]) + (BFieldElement::new(1))),
-            (((node_62) * (node_37)) * (node_20)) * (base_row[146]),
-            ((node_68) * (base_row[138])) * (node_20),
-            (node_42) * (base_row[147]),
+                * (base_row[148]),
+            (((node_62) * (node_40)) * (node_24)) * ((base_row[148]) - (BFieldElement::new(1))),
+            (((node_68) * (node_42)) * (node_20)) * ((base_row[148]) + (BFieldElement::new(1))),
+            (((node_62) * (node_37)) * (node_20)) * (base_row[148]),
+            ((node_68) * (base_row[140])) * (node_20),
+            (node_42) * (base_row[149]),
         ];
         let ext_constraints = [];
         base_constraints
@@ -286,78 +286,78 @@ impl Evaluable<XFieldElement> for ExtU32Table {
         next_ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        let node_27 = (next_base_row[138]) - (BFieldElement::new(1));
-        let node_45 = (next_base_row[141]) - (BFieldElement::new(4));
-        let node_48 = (next_base_row[141]) - (BFieldElement::new(20));
-        let node_56 = (next_base_row[141]) - (BFieldElement::new(60));
-        let node_23 = (current_base_row[144]) - ((BFieldElement::new(2)) * (next_base_row[144]));
-        let node_51 = (next_base_row[141]) - (BFieldElement::new(36));
-        let node_21 = (current_base_row[142]) - ((BFieldElement::new(2)) * (next_base_row[142]));
-        let node_53 = (next_base_row[141]) - (BFieldElement::new(44));
-        let node_89 = (node_45) * ((next_base_row[141]) - (BFieldElement::new(12)));
+        let node_27 = (next_base_row[140]) - (BFieldElement::new(1));
+        let node_45 = (next_base_row[143]) - (BFieldElement::new(4));
+        let node_48 = (next_base_row[143]) - (BFieldElement::new(20));
+        let node_56 = (next_base_row[143]) - (BFieldElement::new(60));
+        let node_23 = (current_base_row[146]) - ((BFieldElement::new(2)) * (next_base_row[146]));
+        let node_51 = (next_base_row[143]) - (BFieldElement::new(36));
+        let node_21 = (current_base_row[144]) - ((BFieldElement::new(2)) * (next_base_row[144]));
+        let node_53 = (next_base_row[143]) - (BFieldElement::new(44));
+        let node_89 = (node_45) * ((next_base_row[143]) - (BFieldElement::new(12)));
         let node_58 = (node_27) * (((((node_45) * (node_48)) * (node_51)) * (node_53)) * (node_56));
         let node_99 = (node_89) * (node_48);
-        let node_59 = (next_base_row[146]) - (BFieldElement::new(1));
-        let node_64 = (node_58) * (next_base_row[146]);
+        let node_59 = (next_base_row[148]) - (BFieldElement::new(1));
+        let node_64 = (node_58) * (next_base_row[148]);
         let node_68 = (node_64) * (node_59);
         let node_112 = (node_99) * (node_51);
-        let node_19 = (current_base_row[141]) - (BFieldElement::new(44));
+        let node_19 = (current_base_row[143]) - (BFieldElement::new(44));
         let node_42 = (node_23) - (BFieldElement::new(1));
         let node_114 = (node_27) * ((node_112) * (node_56));
-        let node_33 = ((next_base_row[139]) - (current_base_row[139])) - (BFieldElement::new(1));
+        let node_33 = ((next_base_row[141]) - (current_base_row[141])) - (BFieldElement::new(1));
         let node_39 = (node_21) - (BFieldElement::new(1));
-        let node_61 = (next_base_row[146]) - (BFieldElement::new(2));
-        let node_66 = (current_base_row[146]) - (BFieldElement::new(1));
+        let node_61 = (next_base_row[148]) - (BFieldElement::new(2));
+        let node_66 = (current_base_row[148]) - (BFieldElement::new(1));
         let node_80 = (node_68)
             * ((((BFieldElement::new(1)) - (node_21)) - (node_23))
                 + (((BFieldElement::new(2)) * (node_21)) * (node_23)));
         let node_102 = (node_27) * (((node_99) * (node_53)) * (node_56));
-        let node_118 = (next_base_row[146]) * (next_base_row[146]);
-        let node_130 = (next_ext_row[45]) - (current_ext_row[45]);
+        let node_118 = (next_base_row[148]) * (next_base_row[148]);
+        let node_130 = (next_ext_row[46]) - (current_ext_row[46]);
 
         let base_constraints = [
-            ((next_base_row[138]) * (current_base_row[142])) * (node_19),
-            (next_base_row[138]) * (current_base_row[144]),
-            (node_27) * ((next_base_row[141]) - (current_base_row[141])),
-            (((node_27) * (current_base_row[142])) * (node_19)) * (node_33),
-            ((node_27) * (current_base_row[144])) * (node_33),
+            ((next_base_row[140]) * (current_base_row[144])) * (node_19),
+            (next_base_row[140]) * (current_base_row[146]),
+            (node_27) * ((next_base_row[143]) - (current_base_row[143])),
+            (((node_27) * (current_base_row[144])) * (node_19)) * (node_33),
+            ((node_27) * (current_base_row[146])) * (node_33),
             (((node_27) * (node_19)) * (node_21)) * (node_39),
             ((node_27) * (node_23)) * (node_42),
-            (((node_58) * (node_59)) * (node_61)) * (current_base_row[146]),
+            (((node_58) * (node_59)) * (node_61)) * (current_base_row[148]),
             ((node_64) * (node_61)) * (node_66),
             (((node_68) * (node_39)) * (node_23)) * (node_66),
-            (((node_68) * (node_21)) * (node_42)) * (current_base_row[146]),
-            ((node_80) * ((current_base_row[138]) - (BFieldElement::new(1))))
-                * ((current_base_row[146]) - (BFieldElement::new(2))),
-            ((node_80) * (current_base_row[138])) * (current_base_row[146]),
+            (((node_68) * (node_21)) * (node_42)) * (current_base_row[148]),
+            ((node_80) * ((current_base_row[140]) - (BFieldElement::new(1))))
+                * ((current_base_row[148]) - (BFieldElement::new(2))),
+            ((node_80) * (current_base_row[140])) * (current_base_row[148]),
             ((node_27) * ((((node_89) * (node_51)) * (node_53)) * (node_56)))
-                * (((current_base_row[146]) - ((BFieldElement::new(2)) * (next_base_row[146])))
+                * (((current_base_row[148]) - ((BFieldElement::new(2)) * (next_base_row[148])))
                     - ((node_21) * (node_23))),
             (((node_102)
-                * ((BFieldElement::new(1)) - ((next_base_row[142]) * (next_base_row[143]))))
-                * (current_base_row[142]))
-                * ((current_base_row[146]) - (current_base_row[139])),
-            ((node_102) * (next_base_row[142])) * ((next_base_row[146]) - (current_base_row[146])),
-            (node_114) * ((next_base_row[142]) - (current_base_row[142])),
-            ((node_114) * (node_42)) * ((current_base_row[146]) - (node_118)),
+                * ((BFieldElement::new(1)) - ((next_base_row[144]) * (next_base_row[145]))))
+                * (current_base_row[144]))
+                * ((current_base_row[148]) - (current_base_row[141])),
+            ((node_102) * (next_base_row[144])) * ((next_base_row[148]) - (current_base_row[148])),
+            (node_114) * ((next_base_row[144]) - (current_base_row[144])),
+            ((node_114) * (node_42)) * ((current_base_row[148]) - (node_118)),
             ((node_114) * (node_23))
-                * ((current_base_row[146]) - ((node_118) * (current_base_row[142]))),
+                * ((current_base_row[148]) - ((node_118) * (current_base_row[144]))),
             ((node_27) * ((node_112) * (node_53)))
-                * (((current_base_row[146]) - (next_base_row[146])) - (node_21)),
+                * (((current_base_row[148]) - (next_base_row[148])) - (node_21)),
         ];
         let ext_constraints = [
             (node_27) * (node_130),
-            (next_base_row[138])
+            (next_base_row[140])
                 * (((node_130)
                     * ((challenges.get_challenge(U32Indeterminate))
-                        - (((((challenges.get_challenge(U32CiWeight)) * (next_base_row[141]))
+                        - (((((challenges.get_challenge(U32CiWeight)) * (next_base_row[143]))
                             + ((challenges.get_challenge(U32LhsWeight))
-                                * (next_base_row[142])))
-                            + ((challenges.get_challenge(U32RhsWeight))
                                 * (next_base_row[144])))
+                            + ((challenges.get_challenge(U32RhsWeight))
+                                * (next_base_row[146])))
                             + ((challenges.get_challenge(U32ResultWeight))
-                                * (next_base_row[146])))))
-                    - (next_base_row[147])),
+                                * (next_base_row[148])))))
+                    - (next_base_row[149])),
         ];
         base_constraints
             .into_iter()
@@ -373,8 +373,8 @@ impl Evaluable<XFieldElement> for ExtU32Table {
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
         let base_constraints = [
-            (base_row[142]) * ((base_row[141]) - (BFieldElement::new(44))),
-            base_row[144],
+            (base_row[144]) * ((base_row[143]) - (BFieldElement::new(44))),
+            base_row[146],
         ];
         let ext_constraints = [];
         base_constraints
@@ -0,0 +1,2997 @@
+use ndarray::ArrayView1;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::mpolynomial::Degree;
+use twenty_first::shared_math::x_field_element::XFieldElement;
+
+use crate::table::challenges::ChallengeId::*;
+use crate::table::challenges::Challenges;
+use crate::table::extension_table::Evaluable;
+use crate::table::extension_table::Quotientable;
+use crate::table::zmips_processor_table::ExtZmipsProcessorTable;
+
+// This file has been auto-generated. Any modifications _will_ be lost.
+// To re-generate, execute:
+// `cargo run --bin constraint-evaluation-generator`
+impl Evaluable<BFieldElement> for ExtZmipsProcessorTable {
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_initial_constraints(
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_80 = (BFieldElement::new(1)) - (base_row[151]);
+        let node_220 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (base_row[227]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (base_row[228])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (base_row[229])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (base_row[230])));
+        let node_232 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (base_row[231]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (base_row[232])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (base_row[233])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (base_row[234])));
+        let node_244 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (base_row[235]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (base_row[236])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (base_row[237])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (base_row[238])));
+        let node_256 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (base_row[239]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (base_row[240])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (base_row[241])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (base_row[242])));
+        let node_26 = (base_row[198]) + (base_row[220]);
+        let node_262 = (node_220) * (node_232);
+        let node_27 = ((base_row[197]) + (base_row[217])) + (node_26);
+        let node_54 = (base_row[201]) + (base_row[216]);
+        let node_178 = (base_row[183]) + (base_row[184]);
+        let node_265 = (node_262) * (node_244);
+
+        let base_constraints = [
+            base_row[150],
+            base_row[152],
+            ((base_row[151]) + (base_row[7])) - (BFieldElement::new(1)),
+            base_row[223],
+            base_row[224],
+            (base_row[225])
+                - (((base_row[203]) * (base_row[161])) + ((base_row[221]) * (base_row[162]))),
+            (base_row[226]) - ((base_row[203]) + (base_row[221])),
+        ];
+        let ext_constraints = [
+            ((node_27)
+                * (((ext_row[47])
+                    * ((challenges.get_challenge(TapeIndeterminate))
+                        - (((challenges.get_challenge(TapeIsSecretWeight)) * (node_26))
+                            + ((challenges.get_challenge(TapeValueWeight)) * (base_row[163])))))
+                    - (BFieldElement::new(1))))
+                + (((BFieldElement::new(1)) - (node_27)) * (ext_row[47])),
+            ((node_54)
+                * (((ext_row[48]) - (challenges.get_challenge(StandardOutputIndeterminate)))
+                    - (((base_row[201]) * (base_row[161])) + ((base_row[216]) * (base_row[162])))))
+                + (((BFieldElement::new(1)) - (node_54))
+                    * ((ext_row[48]) - (BFieldElement::new(1)))),
+            ((node_80)
+                * (((ext_row[49])
+                    * ((challenges.get_challenge(InstructionLookupIndeterminate))
+                        - (((challenges.get_challenge(ProgramInstructionWeight))
+                            * (base_row[154]))
+                            + ((challenges.get_challenge(ProgramNextInstructionWeight))
+                                * (base_row[155])))))
+                    - (BFieldElement::new(1))))
+                + ((base_row[151]) * (ext_row[49])),
+            ((node_80)
+                * ((ext_row[50])
+                    - ((((challenges.get_challenge(RegisterIndeterminate))
+                        - (((challenges.get_challenge(RegisterIndexWeight))
+                            * (base_row[156]))
+                            + ((challenges.get_challenge(RegisterValueWeight))
+                                * (base_row[161]))))
+                        * ((challenges.get_challenge(RegisterIndeterminate))
+                            - (((challenges.get_challenge(RegisterIndexWeight))
+                                * (base_row[157]))
+                                + ((challenges.get_challenge(RegisterValueWeight))
+                                    * (base_row[162])))))
+                        * ((challenges.get_challenge(RegisterIndeterminate))
+                            - ((((challenges.get_challenge(RegisterIndexWeight))
+                                * (base_row[158]))
+                                + ((challenges.get_challenge(RegisterValueWeight))
+                                    * (base_row[163])))
+                                + ((challenges.get_challenge(RegisterIsWriteWeight))
+                                    * ((((((((((((((((((((((((((((base_row[177])
+                                        + (base_row[178]))
+                                        + (base_row[179]))
+                                        + (base_row[210]))
+                                        + (base_row[180]))
+                                        + (base_row[211]))
+                                        + (base_row[212]))
+                                        + (base_row[183]))
+                                        + (base_row[185]))
+                                        + (base_row[206]))
+                                        + (base_row[186]))
+                                        + (base_row[207]))
+                                        + (base_row[187]))
+                                        + (base_row[188]))
+                                        + (base_row[208]))
+                                        + (base_row[189]))
+                                        + (base_row[190]))
+                                        + (base_row[191]))
+                                        + (base_row[192]))
+                                        + (base_row[193]))
+                                        + (base_row[204]))
+                                        + (base_row[194]))
+                                        + (base_row[195]))
+                                        + (base_row[196]))
+                                        + (base_row[209]))
+                                        + (base_row[197]))
+                                        + (base_row[198]))
+                                        + (base_row[215]))))))))
+                + ((base_row[151]) * ((ext_row[50]) - (BFieldElement::new(1)))),
+            ((node_80)
+                * ((ext_row[51])
+                    - (((node_178)
+                        * ((challenges.get_challenge(MemoryIndeterminate))
+                            - ((((challenges.get_challenge(MemoryAddressWeight))
+                                * (base_row[165]))
+                                + ((challenges.get_challenge(MemoryValueWeight))
+                                    * (base_row[166])))
+                                + ((challenges.get_challenge(MemoryIsWriteWeight))
+                                    * (base_row[184])))))
+                        + ((BFieldElement::new(1)) - (node_178)))))
+                + ((base_row[151]) * ((ext_row[51]) - (BFieldElement::new(1)))),
+            ((node_80)
+                * (((ext_row[52]) * ((node_265) * (node_256)))
+                    - ((((((node_232) * (node_244)) * (node_256))
+                        + (((node_220) * (node_244)) * (node_256)))
+                        + ((node_262) * (node_256)))
+                        + (node_265))))
+                + ((base_row[151]) * (ext_row[52])),
+            ((ext_row[53]) * (challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate)))
+                - (base_row[222]),
+        ];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_consistency_constraints(
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_1255 = (base_row[235]) - (BFieldElement::new(4));
+        let node_1606 = (base_row[239]) - (BFieldElement::new(4));
+        let node_905 = (base_row[231]) - (BFieldElement::new(4));
+        let node_552 = (base_row[227]) - (BFieldElement::new(4));
+        let node_378 = (base_row[168]) * (BFieldElement::new(4294967296));
+        let node_380 = (base_row[169]) * (BFieldElement::new(4294967296));
+        let node_438 = (BFieldElement::new(2)) * (base_row[164]);
+        let node_434 = (BFieldElement::new(2)) * (base_row[161]);
+        let node_556 = (base_row[227]) - (BFieldElement::new(12));
+        let node_539 = (node_434) - (node_378);
+        let node_540 = (node_438) - (node_380);
+        let node_749 = (base_row[229]) - (base_row[164]);
+        let node_997 = (base_row[232]) - (node_539);
+        let node_1089 = (base_row[233]) - (node_540);
+        let node_364 = (base_row[161]) - (base_row[164]);
+        let node_609 = (base_row[227]) - (BFieldElement::new(20));
+        let node_653 = (base_row[228]) - (base_row[161]);
+        let node_676 = (base_row[228]) - (base_row[163]);
+        let node_536 = ((base_row[161]) + (BFieldElement::new(2147483648))) - (node_378);
+        let node_538 = ((base_row[164]) + (BFieldElement::new(2147483648))) - (node_380);
+        let node_1388 = (base_row[236]) - (base_row[171]);
+        let node_383 = (base_row[170]) * (BFieldElement::new(4294967296));
+        let node_387 = (base_row[161]) + (base_row[164]);
+        let node_422 = (base_row[163]) - (base_row[171]);
+        let node_368 = (BFieldElement::new(1)) - (base_row[163]);
+        let node_419 = (base_row[163]) - (base_row[172]);
+        let node_441 =
+            (base_row[164]) + ((base_row[169]) * ((BFieldElement::new(4294967296)) - (node_438)));
+        let node_448 = ((BFieldElement::new(2)) * (base_row[168])) * (base_row[169]);
+        let node_473 = (base_row[163]) - (base_row[161]);
+        let node_648 = (base_row[228]) - (node_538);
+        let node_679 = (base_row[228]) - (base_row[165]);
+        let node_694 = (base_row[228]) - (base_row[172]);
+        let node_713 = (base_row[228]) - (base_row[164]);
+        let node_744 = (base_row[229]) - (node_536);
+        let node_807 = (base_row[229]) - (BFieldElement::new(31));
+        let node_838 = (base_row[230]) - (base_row[163]);
+        let node_868 = (base_row[230]) - (BFieldElement::new(1));
+        let node_969 = (base_row[231]) - (BFieldElement::new(44));
+        let node_1061 = (base_row[232]) - (BFieldElement::new(2));
+        let node_1495 = (base_row[237]) - (base_row[172]);
+        let node_370 = (node_364) * (base_row[167]);
+        let node_379 = (base_row[161]) - (node_378);
+        let node_381 = (base_row[164]) - (node_380);
+        let node_384 = (base_row[163]) - (node_383);
+        let node_388 = (node_387) - (base_row[163]);
+        let node_395 = (node_364) - (base_row[163]);
+        let node_404 = ((base_row[171]) * (BFieldElement::new(4294967296))) + (base_row[172]);
+        let node_400 = ((base_row[195]) + (base_row[196])) + (base_row[209]);
+        let node_451 = (BFieldElement::new(1)) - ((base_row[171]) * (base_row[167]));
+        let node_449 = ((base_row[168]) + (base_row[169])) - (node_448);
+        let node_453 = (BFieldElement::new(1)) - ((base_row[172]) * (base_row[167]));
+        let node_466 = (BFieldElement::new(2)) * (base_row[172]);
+        let node_474 = (node_473) - (base_row[164]);
+        let node_495 = (node_387) - (base_row[165]);
+        let node_645 = (base_row[228]) - (node_536);
+        let node_682 = (base_row[228]) - (node_539);
+        let node_741 = (base_row[229]) - (node_538);
+        let node_776 = (base_row[229]) - (node_540);
+        let node_788 = (base_row[229]) - (node_441);
+        let node_881 = (base_row[230]) - (base_row[172]);
+        let node_1031 =
+            (base_row[232]) - (((BFieldElement::new(2)) * (base_row[163])) - (node_383));
+        let node_1153 = (base_row[233]) - ((BFieldElement::new(32)) - (base_row[230]));
+
+        let base_constraints =
+            [
+                (base_row[151]) * ((base_row[151]) - (BFieldElement::new(1))),
+                (base_row[173]) * ((base_row[173]) - (BFieldElement::new(1))),
+                (base_row[174]) * ((base_row[174]) - (BFieldElement::new(1))),
+                (base_row[175]) * ((base_row[175]) - (BFieldElement::new(1))),
+                (base_row[176]) * ((base_row[176]) - (BFieldElement::new(1))),
+                (base_row[205]) * ((base_row[205]) - (BFieldElement::new(1))),
+                (base_row[213]) * ((base_row[213]) - (BFieldElement::new(1))),
+                (base_row[214]) * ((base_row[214]) - (BFieldElement::new(1))),
+                (base_row[177]) * ((base_row[177]) - (BFieldElement::new(1))),
+                (base_row[178]) * ((base_row[178]) - (BFieldElement::new(1))),
+                (base_row[179]) * ((base_row[179]) - (BFieldElement::new(1))),
+                (base_row[210]) * ((base_row[210]) - (BFieldElement::new(1))),
+                (base_row[180]) * ((base_row[180]) - (BFieldElement::new(1))),
+                (base_row[181]) * ((base_row[181]) - (BFieldElement::new(1))),
+                (base_row[182]) * ((base_row[182]) - (BFieldElement::new(1))),
+                (base_row[211]) * ((base_row[211]) - (BFieldElement::new(1))),
+                (base_row[212]) * ((base_row[212]) - (BFieldElement::new(1))),
+                (base_row[183]) * ((base_row[183]) - (BFieldElement::new(1))),
+                (base_row[184]) * ((base_row[184]) - (BFieldElement::new(1))),
+                (base_row[185]) * ((base_row[185]) - (BFieldElement::new(1))),
+                (base_row[206]) * ((base_row[206]) - (BFieldElement::new(1))),
+                (base_row[186]) * ((base_row[186]) - (BFieldElement::new(1))),
+                (base_row[207]) * ((base_row[207]) - (BFieldElement::new(1))),
+                (base_row[187]) * ((base_row[187]) - (BFieldElement::new(1))),
+                (base_row[188]) * ((base_row[188]) - (BFieldElement::new(1))),
+                (base_row[208]) * ((base_row[208]) - (BFieldElement::new(1))),
+                (base_row[189]) * ((base_row[189]) - (BFieldElement::new(1))),
+                (base_row[190]) * ((base_row[190]) - (BFieldElement::new(1))),
+                (base_row[191]) * ((base_row[191]) - (BFieldElement::new(1))),
+                (base_row[192]) * ((base_row[192]) - (BFieldElement::new(1))),
+                (base_row[193]) * ((base_row[193]) - (BFieldElement::new(1))),
+                (base_row[204]) * ((base_row[204]) - (BFieldElement::new(1))),
+                (base_row[194]) * ((base_row[194]) - (BFieldElement::new(1))),
+                (base_row[195]) * ((base_row[195]) - (BFieldElement::new(1))),
+                (base_row[196]) * ((base_row[196]) - (BFieldElement::new(1))),
+                (base_row[209]) * ((base_row[209]) - (BFieldElement::new(1))),
+                (base_row[197]) * ((base_row[197]) - (BFieldElement::new(1))),
+                (base_row[198]) * ((base_row[198]) - (BFieldElement::new(1))),
+                (base_row[199]) * ((base_row[199]) - (BFieldElement::new(1))),
+                (base_row[200]) * ((base_row[200]) - (BFieldElement::new(1))),
+                (base_row[201]) * ((base_row[201]) - (BFieldElement::new(1))),
+                (base_row[202]) * ((base_row[202]) - (BFieldElement::new(1))),
+                (base_row[203]) * ((base_row[203]) - (BFieldElement::new(1))),
+                (base_row[215]) * ((base_row[215]) - (BFieldElement::new(1))),
+                (base_row[168]) * ((base_row[168]) - (BFieldElement::new(1))),
+                (base_row[169]) * ((base_row[169]) - (BFieldElement::new(1))),
+                (base_row[170]) * ((base_row[170]) - (BFieldElement::new(1))),
+                (base_row[216]) * ((base_row[216]) - (BFieldElement::new(1))),
+                (base_row[217]) * ((base_row[217]) - (BFieldElement::new(1))),
+                (base_row[218]) * ((base_row[218]) - (BFieldElement::new(1))),
+                (base_row[219]) * ((base_row[219]) - (BFieldElement::new(1))),
+                (base_row[220]) * ((base_row[220]) - (BFieldElement::new(1))),
+                (base_row[221]) * ((base_row[221]) - (BFieldElement::new(1))),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) + (base_row[174]))
+                    + (base_row[175]))
+                    + (base_row[176]))
+                    + (base_row[205]))
+                    + (base_row[213]))
+                    + (base_row[214]))
+                    + (base_row[177]))
+                    + (base_row[178]))
+                    + (base_row[179]))
+                    + (base_row[210]))
+                    + (base_row[180]))
+                    + (base_row[181]))
+                    + (base_row[182]))
+                    + (base_row[211]))
+                    + (base_row[212]))
+                    + (base_row[183]))
+                    + (base_row[184]))
+                    + (base_row[185]))
+                    + (base_row[206]))
+                    + (base_row[186]))
+                    + (base_row[207]))
+                    + (base_row[187]))
+                    + (base_row[188]))
+                    + (base_row[208]))
+                    + (base_row[189]))
+                    + (base_row[190]))
+                    + (base_row[191]))
+                    + (base_row[192]))
+                    + (base_row[193]))
+                    + (base_row[204]))
+                    + (base_row[194]))
+                    + (base_row[195]))
+                    + (base_row[196]))
+                    + (base_row[209]))
+                    + (base_row[197]))
+                    + (base_row[198]))
+                    + (base_row[199]))
+                    + (base_row[200]))
+                    + (base_row[201]))
+                    + (base_row[202]))
+                    + (base_row[203]))
+                    + (base_row[215]))
+                    - (BFieldElement::new(1)),
+                (base_row[153])
+                    - ((((((((((((((((((((((((((((((((((((((((((base_row[174])
+                        + ((BFieldElement::new(2)) * (base_row[175])))
+                        + ((BFieldElement::new(3)) * (base_row[176])))
+                        + ((BFieldElement::new(32)) * (base_row[205])))
+                        + ((BFieldElement::new(40))
+                            * (base_row[213])))
+                        + ((BFieldElement::new(41))
+                            * (base_row[214])))
+                        + ((BFieldElement::new(4))
+                            * (base_row[177])))
+                        + ((BFieldElement::new(5))
+                            * (base_row[178])))
+                        + ((BFieldElement::new(6))
+                            * (base_row[179])))
+                        + ((BFieldElement::new(37))
+                            * (base_row[210])))
+                        + ((BFieldElement::new(7))
+                            * (base_row[180])))
+                        + ((BFieldElement::new(8))
+                            * (base_row[181])))
+                        + ((BFieldElement::new(9)) * (base_row[182])))
+                        + ((BFieldElement::new(38)) * (base_row[211])))
+                        + ((BFieldElement::new(39)) * (base_row[212])))
+                        + ((BFieldElement::new(10)) * (base_row[183])))
+                        + ((BFieldElement::new(11)) * (base_row[184])))
+                        + ((BFieldElement::new(12)) * (base_row[185])))
+                        + ((BFieldElement::new(33)) * (base_row[206])))
+                        + ((BFieldElement::new(13)) * (base_row[186])))
+                        + ((BFieldElement::new(34)) * (base_row[207])))
+                        + ((BFieldElement::new(14)) * (base_row[187])))
+                        + ((BFieldElement::new(15)) * (base_row[188])))
+                        + ((BFieldElement::new(35)) * (base_row[208])))
+                        + ((BFieldElement::new(16)) * (base_row[189])))
+                        + ((BFieldElement::new(17)) * (base_row[190])))
+                        + ((BFieldElement::new(18)) * (base_row[191])))
+                        + ((BFieldElement::new(19)) * (base_row[192])))
+                        + ((BFieldElement::new(20)) * (base_row[193])))
+                        + ((BFieldElement::new(31)) * (base_row[204])))
+                        + ((BFieldElement::new(21)) * (base_row[194])))
+                        + ((BFieldElement::new(22)) * (base_row[195])))
+                        + ((BFieldElement::new(23)) * (base_row[196])))
+                        + ((BFieldElement::new(36)) * (base_row[209])))
+                        + ((BFieldElement::new(24)) * (base_row[197])))
+                        + ((BFieldElement::new(25)) * (base_row[198])))
+                        + ((BFieldElement::new(26)) * (base_row[199])))
+                        + ((BFieldElement::new(27)) * (base_row[200])))
+                        + ((BFieldElement::new(28)) * (base_row[201])))
+                        + ((BFieldElement::new(29)) * (base_row[202])))
+                        + ((BFieldElement::new(30)) * (base_row[203])))
+                        + ((BFieldElement::new(42)) * (base_row[215]))),
+                (base_row[159]) * ((base_row[159]) - (BFieldElement::new(1))),
+                (base_row[154])
+                    - ((((((base_row[153]) + ((BFieldElement::new(256)) * (base_row[156])))
+                        + ((BFieldElement::new(8192)) * (base_row[157])))
+                        + ((BFieldElement::new(262144)) * (base_row[158])))
+                        + ((BFieldElement::new(8388608)) * (base_row[159])))
+                        + ((BFieldElement::new(16777216)) * (base_row[160]))),
+                (base_row[164])
+                    - (((base_row[159]) * (base_row[160]))
+                        + (((BFieldElement::new(1)) - (base_row[159])) * (base_row[162]))),
+                ((base_row[177]) * (node_364)) * (base_row[163]),
+                (base_row[177]) * ((node_368) - (node_370)),
+                ((base_row[178]) * (node_364)) * (node_368),
+                (base_row[178]) * ((base_row[163]) - (node_370)),
+                (base_row[185]) * (((node_379) + (node_381)) - (node_384)),
+                ((base_row[206]) * (node_388)) * ((node_388) - (BFieldElement::new(4294967296))),
+                (base_row[186]) * (((node_379) - (node_381)) - (node_384)),
+                ((base_row[207]) * (node_395)) * ((node_395) + (BFieldElement::new(4294967296))),
+                (base_row[187]) * (((base_row[161]) * (base_row[164])) - (node_404)),
+                (node_400) * (((base_row[161]) * (base_row[234])) - (node_404)),
+                (((base_row[187]) + (node_400)) * (base_row[172]))
+                    * ((BFieldElement::new(1))
+                        - (((base_row[171]) - (BFieldElement::new(4294967295))) * (base_row[167]))),
+                (base_row[187]) * (node_419),
+                (base_row[195]) * (node_419),
+                (base_row[196]) * (node_422),
+                (base_row[209])
+                    * ((node_422)
+                        - ((base_row[168]) * ((BFieldElement::new(4294967296)) - (base_row[234])))),
+                (base_row[208])
+                    * (((base_row[161]) - ((base_row[171]) * (base_row[164]))) - (base_row[172])),
+                (base_row[208]) * (node_422),
+                ((base_row[188]) + (base_row[189]))
+                    * ((((base_row[161])
+                        + ((base_row[168]) * ((BFieldElement::new(4294967296)) - (node_434))))
+                        - ((base_row[171]) * (node_441)))
+                        - (base_row[172])),
+                ((base_row[188]) * (base_row[171])) * (node_451),
+                (base_row[188])
+                    * (((node_422)
+                        - ((node_449)
+                            * ((BFieldElement::new(4294967296))
+                                - ((BFieldElement::new(2)) * (base_row[171])))))
+                        + (((node_449) * (BFieldElement::new(4294967296))) * (node_451))),
+                ((base_row[189]) * (base_row[172])) * (node_453),
+                (base_row[189])
+                    * (((node_419)
+                        - ((base_row[168]) * ((BFieldElement::new(4294967296)) - (node_466))))
+                        + ((node_378) * (node_453))),
+                (base_row[204]) * ((node_474) + (base_row[172])),
+                (base_row[193]) * ((node_474) + (node_466)),
+                (base_row[194])
+                    * (((base_row[163]) + (base_row[164])) - (BFieldElement::new(4294967295))),
+                (base_row[190]) * ((base_row[163]) - (base_row[164])),
+                (base_row[191]) * ((base_row[163]) - (base_row[160])),
+                ((base_row[211]) + (base_row[212]))
+                    * (((base_row[163]) - (base_row[152])) - (BFieldElement::new(1))),
+                (((((base_row[183]) + (base_row[184])) + (base_row[199])) + (base_row[200]))
+                    * (node_495))
+                    * ((node_495) - (BFieldElement::new(4294967296))),
+                (base_row[183]) * ((base_row[163]) - (base_row[166])),
+                (base_row[184]) * ((base_row[166]) - (base_row[163])),
+                ((((((base_row[216]) + (base_row[217])) + (base_row[218])) + (base_row[219]))
+                    + (base_row[220]))
+                    + (base_row[221]))
+                    - (base_row[215]),
+                ((((((base_row[216]) * ((base_row[161]) - (BFieldElement::new(1))))
+                    + ((base_row[217]) * ((base_row[161]) - (BFieldElement::new(5)))))
+                    + ((base_row[218]) * ((base_row[161]) - (BFieldElement::new(10)))))
+                    + ((base_row[219]) * ((base_row[161]) - (BFieldElement::new(17)))))
+                    + ((base_row[220]) * ((base_row[161]) - (BFieldElement::new(100)))))
+                    + ((base_row[221]) * ((base_row[161]) - (BFieldElement::new(101)))),
+                (((base_row[215]) - (base_row[217])) - (base_row[220])) * (node_473),
+                (base_row[151]) * (base_row[222]),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (node_552))
+                    + ((base_row[174]) * (node_552)))
+                    + ((base_row[175]) * (node_556)))
+                    + ((base_row[176]) * (node_556)))
+                    + ((base_row[205]) * (node_556)))
+                    + ((base_row[213]) * (node_556)))
+                    + ((base_row[214]) * (node_556)))
+                    + ((base_row[177]) * (node_552)))
+                    + ((base_row[178]) * (node_552)))
+                    + ((base_row[179]) * (node_556)))
+                    + ((base_row[210]) * (node_556)))
+                    + ((base_row[180]) * (node_556)))
+                    + ((base_row[181]) * (node_552)))
+                    + ((base_row[182]) * (node_552)))
+                    + ((base_row[211]) * (node_552)))
+                    + ((base_row[212]) * (node_552)))
+                    + ((base_row[183]) * (node_552)))
+                    + ((base_row[184]) * (node_552)))
+                    + ((base_row[185]) * (node_552)))
+                    + ((base_row[206]) * (node_552)))
+                    + ((base_row[186]) * (node_552)))
+                    + ((base_row[207]) * (node_552)))
+                    + ((base_row[187]) * (node_552)))
+                    + ((base_row[188]) * (node_556)))
+                    + ((base_row[208]) * (node_556)))
+                    + ((base_row[189]) * (node_556)))
+                    + ((base_row[190]) * (node_552)))
+                    + ((base_row[191]) * (node_552)))
+                    + ((base_row[192]) * (node_609)))
+                    + ((base_row[193]) * (node_609)))
+                    + ((base_row[204]) * (node_609)))
+                    + ((base_row[194]) * (node_552)))
+                    + ((base_row[195]) * (node_609)))
+                    + ((base_row[196]) * (node_609)))
+                    + ((base_row[209]) * (node_609)))
+                    + ((base_row[197]) * (node_552)))
+                    + ((base_row[198]) * (node_552)))
+                    + ((base_row[199]) * (node_552)))
+                    + ((base_row[200]) * (node_552)))
+                    + ((base_row[201]) * (node_552)))
+                    + ((base_row[202]) * (node_552)))
+                    + ((base_row[203]) * (node_552)))
+                    + ((base_row[215]) * (node_552)),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[228]))
+                    + ((base_row[174]) * (base_row[228])))
+                    + ((base_row[175]) * (node_645)))
+                    + ((base_row[176]) * (node_648)))
+                    + ((base_row[205]) * (node_648)))
+                    + ((base_row[213]) * (node_653)))
+                    + ((base_row[214]) * (node_653)))
+                    + ((base_row[177]) * (base_row[228])))
+                    + ((base_row[178]) * (base_row[228])))
+                    + ((base_row[179]) * (node_645)))
+                    + ((base_row[210]) * (node_653)))
+                    + ((base_row[180]) * (node_648)))
+                    + ((base_row[181]) * (base_row[228])))
+                    + ((base_row[182]) * (base_row[228])))
+                    + ((base_row[211]) * (base_row[228])))
+                    + ((base_row[212]) * (base_row[228])))
+                    + ((base_row[183]) * (node_676)))
+                    + ((base_row[184]) * (node_679)))
+                    + ((base_row[185]) * (node_682)))
+                    + ((base_row[206]) * (node_676)))
+                    + ((base_row[186]) * (node_682)))
+                    + ((base_row[207]) * (node_676)))
+                    + ((base_row[187]) * ((base_row[228]) - (base_row[171]))))
+                    + ((base_row[188]) * (node_694)))
+                    + ((base_row[208]) * (node_694)))
+                    + ((base_row[189]) * (node_694)))
+                    + ((base_row[190]) * (base_row[228])))
+                    + ((base_row[191]) * (base_row[228])))
+                    + ((base_row[192]) * (node_653)))
+                    + ((base_row[193]) * (node_653)))
+                    + ((base_row[204]) * (node_653)))
+                    + ((base_row[194]) * (base_row[228])))
+                    + ((base_row[195]) * (node_713)))
+                    + ((base_row[196]) * (node_713)))
+                    + ((base_row[209]) * (node_713)))
+                    + ((base_row[197]) * (node_676)))
+                    + ((base_row[198]) * (node_676)))
+                    + ((base_row[199]) * (node_679)))
+                    + ((base_row[200]) * (node_679)))
+                    + ((base_row[201]) * (base_row[228])))
+                    + ((base_row[202]) * (base_row[228])))
+                    + ((base_row[203]) * (base_row[228])))
+                    + ((base_row[215]) * (node_676)),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[229]))
+                    + ((base_row[174]) * (base_row[229])))
+                    + ((base_row[175]) * (node_741)))
+                    + ((base_row[176]) * (node_744)))
+                    + ((base_row[205]) * (node_744)))
+                    + ((base_row[213]) * (node_749)))
+                    + ((base_row[214]) * (node_749)))
+                    + ((base_row[177]) * (base_row[229])))
+                    + ((base_row[178]) * (base_row[229])))
+                    + ((base_row[179]) * (node_741)))
+                    + ((base_row[210]) * (node_749)))
+                    + ((base_row[180]) * (node_744)))
+                    + ((base_row[181]) * (base_row[229])))
+                    + ((base_row[182]) * (base_row[229])))
+                    + ((base_row[211]) * (base_row[229])))
+                    + ((base_row[212]) * (base_row[229])))
+                    + ((base_row[183]) * (base_row[229])))
+                    + ((base_row[184]) * (base_row[229])))
+                    + ((base_row[185]) * (node_776)))
+                    + ((base_row[206]) * (base_row[229])))
+                    + ((base_row[186]) * (node_776)))
+                    + ((base_row[207]) * (base_row[229])))
+                    + ((base_row[187]) * ((base_row[229]) - (base_row[172]))))
+                    + ((base_row[188]) * (node_788)))
+                    + ((base_row[208]) * (node_749)))
+                    + ((base_row[189]) * (node_788)))
+                    + ((base_row[190]) * (base_row[229])))
+                    + ((base_row[191]) * (base_row[229])))
+                    + ((base_row[192]) * (node_749)))
+                    + ((base_row[193]) * (node_749)))
+                    + ((base_row[204]) * (node_749)))
+                    + ((base_row[194]) * (base_row[229])))
+                    + ((base_row[195]) * (node_807)))
+                    + ((base_row[196]) * (node_807)))
+                    + ((base_row[209]) * (node_807)))
+                    + ((base_row[197]) * (base_row[229])))
+                    + ((base_row[198]) * (base_row[229])))
+                    + ((base_row[199]) * (base_row[229])))
+                    + ((base_row[200]) * (base_row[229])))
+                    + ((base_row[201]) * (base_row[229])))
+                    + ((base_row[202]) * (base_row[229])))
+                    + ((base_row[203]) * (base_row[229])))
+                    + ((base_row[215]) * (base_row[229])),
+                (((((((((((((((((((((((((((((((((((base_row[173])
+                    * (base_row[230]))
+                    + ((base_row[174]) * (base_row[230])))
+                    + ((base_row[177]) * (base_row[230])))
+                    + ((base_row[178]) * (base_row[230])))
+                    + ((base_row[179]) * (node_838)))
+                    + ((base_row[210]) * (node_838)))
+                    + ((base_row[180])
+                        * ((base_row[230]) - (node_368))))
+                    + ((base_row[181]) * (base_row[230])))
+                    + ((base_row[182]) * (base_row[230])))
+                    + ((base_row[211]) * (base_row[230])))
+                    + ((base_row[212]) * (base_row[230])))
+                    + ((base_row[183]) * (base_row[230])))
+                    + ((base_row[184]) * (base_row[230])))
+                    + ((base_row[185]) * (base_row[230])))
+                    + ((base_row[206]) * (base_row[230])))
+                    + ((base_row[186]) * (base_row[230])))
+                    + ((base_row[207]) * (base_row[230])))
+                    + ((base_row[187]) * (base_row[230])))
+                    + ((base_row[188]) * (node_868)))
+                    + ((base_row[208]) * (node_868)))
+                    + ((base_row[189]) * (node_868)))
+                    + ((base_row[190]) * (base_row[230])))
+                    + ((base_row[191]) * (base_row[230])))
+                    + ((base_row[192]) * (node_838)))
+                    + ((base_row[193]) * (node_881)))
+                    + ((base_row[204]) * (node_881)))
+                    + ((base_row[194]) * (base_row[230])))
+                    + ((base_row[197]) * (base_row[230])))
+                    + ((base_row[198]) * (base_row[230])))
+                    + ((base_row[199]) * (base_row[230])))
+                    + ((base_row[200]) * (base_row[230])))
+                    + ((base_row[201]) * (base_row[230])))
+                    + ((base_row[202]) * (base_row[230])))
+                    + ((base_row[203]) * (base_row[230])))
+                    + ((base_row[215]) * (base_row[230])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (node_905))
+                    + ((base_row[174]) * (node_905)))
+                    + ((base_row[175]) * (node_905)))
+                    + ((base_row[176]) * (node_905)))
+                    + ((base_row[205]) * (node_905)))
+                    + ((base_row[213]) * (node_905)))
+                    + ((base_row[214]) * (node_905)))
+                    + ((base_row[177]) * (node_905)))
+                    + ((base_row[178]) * (node_905)))
+                    + ((base_row[179]) * (node_905)))
+                    + ((base_row[210]) * (node_905)))
+                    + ((base_row[180]) * (node_905)))
+                    + ((base_row[181]) * (node_905)))
+                    + ((base_row[182]) * (node_905)))
+                    + ((base_row[211]) * (node_905)))
+                    + ((base_row[212]) * (node_905)))
+                    + ((base_row[183]) * (node_905)))
+                    + ((base_row[184]) * (node_905)))
+                    + ((base_row[185]) * (node_905)))
+                    + ((base_row[206]) * (node_905)))
+                    + ((base_row[186]) * (node_905)))
+                    + ((base_row[207]) * (node_905)))
+                    + ((base_row[187]) * (node_905)))
+                    + ((base_row[188]) * (node_905)))
+                    + ((base_row[208]) * (node_905)))
+                    + ((base_row[189]) * (node_905)))
+                    + ((base_row[190]) * (node_905)))
+                    + ((base_row[191]) * (node_905)))
+                    + ((base_row[192]) * (node_905)))
+                    + ((base_row[193]) * (node_905)))
+                    + ((base_row[204]) * (node_905)))
+                    + ((base_row[194]) * (node_905)))
+                    + ((base_row[195]) * (node_969)))
+                    + ((base_row[196]) * (node_969)))
+                    + ((base_row[209]) * (node_969)))
+                    + ((base_row[197]) * (node_905)))
+                    + ((base_row[198]) * (node_905)))
+                    + ((base_row[199]) * (node_905)))
+                    + ((base_row[200]) * (node_905)))
+                    + ((base_row[201]) * (node_905)))
+                    + ((base_row[202]) * (node_905)))
+                    + ((base_row[203]) * (node_905)))
+                    + ((base_row[215]) * (node_905)),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[232]))
+                    + ((base_row[174]) * (base_row[232])))
+                    + ((base_row[175]) * (node_997)))
+                    + ((base_row[176]) * (node_997)))
+                    + ((base_row[205]) * (node_997)))
+                    + ((base_row[213]) * (base_row[232])))
+                    + ((base_row[214]) * (base_row[232])))
+                    + ((base_row[177]) * (base_row[232])))
+                    + ((base_row[178]) * (base_row[232])))
+                    + ((base_row[179]) * (node_997)))
+                    + ((base_row[210]) * (base_row[232])))
+                    + ((base_row[180]) * (node_997)))
+                    + ((base_row[181]) * (base_row[232])))
+                    + ((base_row[182]) * (base_row[232])))
+                    + ((base_row[211]) * (base_row[232])))
+                    + ((base_row[212]) * (base_row[232])))
+                    + ((base_row[183])
+                        * ((base_row[232]) - (base_row[165]))))
+                    + ((base_row[184]) * (base_row[232])))
+                    + ((base_row[185]) * (node_1031)))
+                    + ((base_row[206]) * (base_row[232])))
+                    + ((base_row[186]) * (node_1031)))
+                    + ((base_row[207]) * (base_row[232])))
+                    + ((base_row[187]) * (base_row[232])))
+                    + ((base_row[188]) * (node_997)))
+                    + ((base_row[208]) * ((base_row[232]) - (base_row[171]))))
+                    + ((base_row[189]) * (node_997)))
+                    + ((base_row[190]) * (base_row[232])))
+                    + ((base_row[191]) * (base_row[232])))
+                    + ((base_row[192]) * (base_row[232])))
+                    + ((base_row[193]) * (base_row[232])))
+                    + ((base_row[204]) * (base_row[232])))
+                    + ((base_row[194]) * (base_row[232])))
+                    + ((base_row[195]) * (node_1061)))
+                    + ((base_row[196]) * (node_1061)))
+                    + ((base_row[209]) * (node_1061)))
+                    + ((base_row[197]) * (base_row[232])))
+                    + ((base_row[198]) * (base_row[232])))
+                    + ((base_row[199]) * (base_row[232])))
+                    + ((base_row[200]) * (base_row[232])))
+                    + ((base_row[201]) * (base_row[232])))
+                    + ((base_row[202]) * (base_row[232])))
+                    + ((base_row[203]) * (base_row[232])))
+                    + ((base_row[215]) * (base_row[232])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[233]))
+                    + ((base_row[174]) * (base_row[233])))
+                    + ((base_row[175]) * (node_1089)))
+                    + ((base_row[176]) * (node_1089)))
+                    + ((base_row[205]) * (node_1089)))
+                    + ((base_row[213]) * (base_row[233])))
+                    + ((base_row[214]) * (base_row[233])))
+                    + ((base_row[177]) * (base_row[233])))
+                    + ((base_row[178]) * (base_row[233])))
+                    + ((base_row[179]) * (node_1089)))
+                    + ((base_row[210]) * (base_row[233])))
+                    + ((base_row[180]) * (node_1089)))
+                    + ((base_row[181]) * (base_row[233])))
+                    + ((base_row[182]) * (base_row[233])))
+                    + ((base_row[211]) * (base_row[233])))
+                    + ((base_row[212]) * (base_row[233])))
+                    + ((base_row[183]) * (base_row[233])))
+                    + ((base_row[184]) * (base_row[233])))
+                    + ((base_row[185]) * (base_row[233])))
+                    + ((base_row[206]) * (base_row[233])))
+                    + ((base_row[186]) * (base_row[233])))
+                    + ((base_row[207]) * (base_row[233])))
+                    + ((base_row[187]) * (base_row[233])))
+                    + ((base_row[188]) * (node_1089)))
+                    + ((base_row[208]) * (base_row[233])))
+                    + ((base_row[189]) * (node_1089)))
+                    + ((base_row[190]) * (base_row[233])))
+                    + ((base_row[191]) * (base_row[233])))
+                    + ((base_row[192]) * (base_row[233])))
+                    + ((base_row[193]) * (base_row[233])))
+                    + ((base_row[204]) * (base_row[233])))
+                    + ((base_row[194]) * (base_row[233])))
+                    + ((base_row[195]) * ((base_row[233]) - (base_row[230]))))
+                    + ((base_row[196]) * (node_1153)))
+                    + ((base_row[209]) * (node_1153)))
+                    + ((base_row[197]) * (base_row[233])))
+                    + ((base_row[198]) * (base_row[233])))
+                    + ((base_row[199]) * (base_row[233])))
+                    + ((base_row[200]) * (base_row[233])))
+                    + ((base_row[201]) * (base_row[233])))
+                    + ((base_row[202]) * (base_row[233])))
+                    + ((base_row[203]) * (base_row[233])))
+                    + ((base_row[215]) * (base_row[233])),
+                ((((((((((((((((((((((((((((((((((((((((base_row[173])
+                    * (base_row[234]))
+                    + ((base_row[174]) * (base_row[234])))
+                    + ((base_row[175]) * (base_row[234])))
+                    + ((base_row[176]) * (base_row[234])))
+                    + ((base_row[205]) * (base_row[234])))
+                    + ((base_row[213]) * (base_row[234])))
+                    + ((base_row[214]) * (base_row[234])))
+                    + ((base_row[177]) * (base_row[234])))
+                    + ((base_row[178]) * (base_row[234])))
+                    + ((base_row[179]) * (base_row[234])))
+                    + ((base_row[210]) * (base_row[234])))
+                    + ((base_row[180]) * (base_row[234])))
+                    + ((base_row[181]) * (base_row[234])))
+                    + ((base_row[182]) * (base_row[234])))
+                    + ((base_row[211]) * (base_row[234])))
+                    + ((base_row[212]) * (base_row[234])))
+                    + ((base_row[183]) * (base_row[234])))
+                    + ((base_row[184]) * (base_row[234])))
+                    + ((base_row[185]) * (base_row[234])))
+                    + ((base_row[206]) * (base_row[234])))
+                    + ((base_row[186]) * (base_row[234])))
+                    + ((base_row[207]) * (base_row[234])))
+                    + ((base_row[187]) * (base_row[234])))
+                    + ((base_row[188]) * (base_row[234])))
+                    + ((base_row[208]) * (base_row[234])))
+                    + ((base_row[189]) * (base_row[234])))
+                    + ((base_row[190]) * (base_row[234])))
+                    + ((base_row[191]) * (base_row[234])))
+                    + ((base_row[192]) * (base_row[234])))
+                    + ((base_row[193]) * (base_row[234])))
+                    + ((base_row[204]) * (base_row[234])))
+                    + ((base_row[194]) * (base_row[234])))
+                    + ((base_row[197]) * (base_row[234])))
+                    + ((base_row[198]) * (base_row[234])))
+                    + ((base_row[199]) * (base_row[234])))
+                    + ((base_row[200]) * (base_row[234])))
+                    + ((base_row[201]) * (base_row[234])))
+                    + ((base_row[202]) * (base_row[234])))
+                    + ((base_row[203]) * (base_row[234])))
+                    + ((base_row[215]) * (base_row[234])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (node_1255))
+                    + ((base_row[174]) * (node_1255)))
+                    + ((base_row[175]) * (node_1255)))
+                    + ((base_row[176]) * (node_1255)))
+                    + ((base_row[205]) * (node_1255)))
+                    + ((base_row[213]) * (node_1255)))
+                    + ((base_row[214]) * (node_1255)))
+                    + ((base_row[177]) * (node_1255)))
+                    + ((base_row[178]) * (node_1255)))
+                    + ((base_row[179]) * (node_1255)))
+                    + ((base_row[210]) * (node_1255)))
+                    + ((base_row[180]) * (node_1255)))
+                    + ((base_row[181]) * (node_1255)))
+                    + ((base_row[182]) * (node_1255)))
+                    + ((base_row[211]) * (node_1255)))
+                    + ((base_row[212]) * (node_1255)))
+                    + ((base_row[183]) * (node_1255)))
+                    + ((base_row[184]) * (node_1255)))
+                    + ((base_row[185]) * (node_1255)))
+                    + ((base_row[206]) * (node_1255)))
+                    + ((base_row[186]) * (node_1255)))
+                    + ((base_row[207]) * (node_1255)))
+                    + ((base_row[187]) * (node_1255)))
+                    + ((base_row[188]) * (node_1255)))
+                    + ((base_row[208]) * (node_1255)))
+                    + ((base_row[189]) * (node_1255)))
+                    + ((base_row[190]) * (node_1255)))
+                    + ((base_row[191]) * (node_1255)))
+                    + ((base_row[192]) * (node_1255)))
+                    + ((base_row[193]) * (node_1255)))
+                    + ((base_row[204]) * (node_1255)))
+                    + ((base_row[194]) * (node_1255)))
+                    + ((base_row[195]) * (node_1255)))
+                    + ((base_row[196]) * (node_1255)))
+                    + ((base_row[209]) * (node_1255)))
+                    + ((base_row[197]) * (node_1255)))
+                    + ((base_row[198]) * (node_1255)))
+                    + ((base_row[199]) * (node_1255)))
+                    + ((base_row[200]) * (node_1255)))
+                    + ((base_row[201]) * (node_1255)))
+                    + ((base_row[202]) * (node_1255)))
+                    + ((base_row[203]) * (node_1255)))
+                    + ((base_row[215]) * (node_1255)),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[236]))
+                    + ((base_row[174]) * (base_row[236])))
+                    + ((base_row[175]) * (base_row[236])))
+                    + ((base_row[176]) * (base_row[236])))
+                    + ((base_row[205]) * (base_row[236])))
+                    + ((base_row[213]) * (base_row[236])))
+                    + ((base_row[214]) * (base_row[236])))
+                    + ((base_row[177]) * (base_row[236])))
+                    + ((base_row[178]) * (base_row[236])))
+                    + ((base_row[179]) * (base_row[236])))
+                    + ((base_row[210]) * (base_row[236])))
+                    + ((base_row[180]) * (base_row[236])))
+                    + ((base_row[181]) * (base_row[236])))
+                    + ((base_row[182]) * (base_row[236])))
+                    + ((base_row[211]) * (base_row[236])))
+                    + ((base_row[212]) * (base_row[236])))
+                    + ((base_row[183]) * (base_row[236])))
+                    + ((base_row[184]) * (base_row[236])))
+                    + ((base_row[185]) * (base_row[236])))
+                    + ((base_row[206]) * (base_row[236])))
+                    + ((base_row[186]) * (base_row[236])))
+                    + ((base_row[207]) * (base_row[236])))
+                    + ((base_row[187]) * (base_row[236])))
+                    + ((base_row[188]) * (node_1388)))
+                    + ((base_row[208]) * (base_row[236])))
+                    + ((base_row[189]) * (node_1388)))
+                    + ((base_row[190]) * (base_row[236])))
+                    + ((base_row[191]) * (base_row[236])))
+                    + ((base_row[192]) * (base_row[236])))
+                    + ((base_row[193]) * (base_row[236])))
+                    + ((base_row[204]) * (base_row[236])))
+                    + ((base_row[194]) * (base_row[236])))
+                    + ((base_row[195]) * (node_1388)))
+                    + ((base_row[196]) * (node_1388)))
+                    + ((base_row[209]) * (node_1388)))
+                    + ((base_row[197]) * (base_row[236])))
+                    + ((base_row[198]) * (base_row[236])))
+                    + ((base_row[199]) * (base_row[236])))
+                    + ((base_row[200]) * (base_row[236])))
+                    + ((base_row[201]) * (base_row[236])))
+                    + ((base_row[202]) * (base_row[236])))
+                    + ((base_row[203]) * (base_row[236])))
+                    + ((base_row[215]) * (base_row[236])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[237]))
+                    + ((base_row[174]) * (base_row[237])))
+                    + ((base_row[175]) * (base_row[237])))
+                    + ((base_row[176]) * (base_row[237])))
+                    + ((base_row[205]) * (base_row[237])))
+                    + ((base_row[213]) * (base_row[237])))
+                    + ((base_row[214]) * (base_row[237])))
+                    + ((base_row[177]) * (base_row[237])))
+                    + ((base_row[178]) * (base_row[237])))
+                    + ((base_row[179]) * (base_row[237])))
+                    + ((base_row[210]) * (base_row[237])))
+                    + ((base_row[180]) * (base_row[237])))
+                    + ((base_row[181]) * (base_row[237])))
+                    + ((base_row[182]) * (base_row[237])))
+                    + ((base_row[211]) * (base_row[237])))
+                    + ((base_row[212]) * (base_row[237])))
+                    + ((base_row[183]) * (base_row[237])))
+                    + ((base_row[184]) * (base_row[237])))
+                    + ((base_row[185]) * (base_row[237])))
+                    + ((base_row[206]) * (base_row[237])))
+                    + ((base_row[186]) * (base_row[237])))
+                    + ((base_row[207]) * (base_row[237])))
+                    + ((base_row[187]) * (base_row[237])))
+                    + ((base_row[188])
+                        * ((base_row[237])
+                            - (((((BFieldElement::new(2147483647))
+                                + (base_row[168]))
+                                + (base_row[169]))
+                                - (node_448))
+                                - (base_row[171])))))
+                    + ((base_row[208]) * (base_row[237])))
+                    + ((base_row[189]) * (base_row[237])))
+                    + ((base_row[190]) * (base_row[237])))
+                    + ((base_row[191]) * (base_row[237])))
+                    + ((base_row[192]) * (base_row[237])))
+                    + ((base_row[193]) * (base_row[237])))
+                    + ((base_row[204]) * (base_row[237])))
+                    + ((base_row[194]) * (base_row[237])))
+                    + ((base_row[195]) * (node_1495)))
+                    + ((base_row[196]) * (node_1495)))
+                    + ((base_row[209]) * (node_1495)))
+                    + ((base_row[197]) * (base_row[237])))
+                    + ((base_row[198]) * (base_row[237])))
+                    + ((base_row[199]) * (base_row[237])))
+                    + ((base_row[200]) * (base_row[237])))
+                    + ((base_row[201]) * (base_row[237])))
+                    + ((base_row[202]) * (base_row[237])))
+                    + ((base_row[203]) * (base_row[237])))
+                    + ((base_row[215]) * (base_row[237])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[238]))
+                    + ((base_row[174]) * (base_row[238])))
+                    + ((base_row[175]) * (base_row[238])))
+                    + ((base_row[176]) * (base_row[238])))
+                    + ((base_row[205]) * (base_row[238])))
+                    + ((base_row[213]) * (base_row[238])))
+                    + ((base_row[214]) * (base_row[238])))
+                    + ((base_row[177]) * (base_row[238])))
+                    + ((base_row[178]) * (base_row[238])))
+                    + ((base_row[179]) * (base_row[238])))
+                    + ((base_row[210]) * (base_row[238])))
+                    + ((base_row[180]) * (base_row[238])))
+                    + ((base_row[181]) * (base_row[238])))
+                    + ((base_row[182]) * (base_row[238])))
+                    + ((base_row[211]) * (base_row[238])))
+                    + ((base_row[212]) * (base_row[238])))
+                    + ((base_row[183]) * (base_row[238])))
+                    + ((base_row[184]) * (base_row[238])))
+                    + ((base_row[185]) * (base_row[238])))
+                    + ((base_row[206]) * (base_row[238])))
+                    + ((base_row[186]) * (base_row[238])))
+                    + ((base_row[207]) * (base_row[238])))
+                    + ((base_row[187]) * (base_row[238])))
+                    + ((base_row[188]) * (base_row[238])))
+                    + ((base_row[208]) * (base_row[238])))
+                    + ((base_row[189]) * (base_row[238])))
+                    + ((base_row[190]) * (base_row[238])))
+                    + ((base_row[191]) * (base_row[238])))
+                    + ((base_row[192]) * (base_row[238])))
+                    + ((base_row[193]) * (base_row[238])))
+                    + ((base_row[204]) * (base_row[238])))
+                    + ((base_row[194]) * (base_row[238])))
+                    + ((base_row[195]) * (base_row[238])))
+                    + ((base_row[196]) * (base_row[238])))
+                    + ((base_row[209]) * (base_row[238])))
+                    + ((base_row[197]) * (base_row[238])))
+                    + ((base_row[198]) * (base_row[238])))
+                    + ((base_row[199]) * (base_row[238])))
+                    + ((base_row[200]) * (base_row[238])))
+                    + ((base_row[201]) * (base_row[238])))
+                    + ((base_row[202]) * (base_row[238])))
+                    + ((base_row[203]) * (base_row[238])))
+                    + ((base_row[215]) * (base_row[238])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (node_1606))
+                    + ((base_row[174]) * (node_1606)))
+                    + ((base_row[175]) * (node_1606)))
+                    + ((base_row[176]) * (node_1606)))
+                    + ((base_row[205]) * (node_1606)))
+                    + ((base_row[213]) * (node_1606)))
+                    + ((base_row[214]) * (node_1606)))
+                    + ((base_row[177]) * (node_1606)))
+                    + ((base_row[178]) * (node_1606)))
+                    + ((base_row[179]) * (node_1606)))
+                    + ((base_row[210]) * (node_1606)))
+                    + ((base_row[180]) * (node_1606)))
+                    + ((base_row[181]) * (node_1606)))
+                    + ((base_row[182]) * (node_1606)))
+                    + ((base_row[211]) * (node_1606)))
+                    + ((base_row[212]) * (node_1606)))
+                    + ((base_row[183]) * (node_1606)))
+                    + ((base_row[184]) * (node_1606)))
+                    + ((base_row[185]) * (node_1606)))
+                    + ((base_row[206]) * (node_1606)))
+                    + ((base_row[186]) * (node_1606)))
+                    + ((base_row[207]) * (node_1606)))
+                    + ((base_row[187]) * (node_1606)))
+                    + ((base_row[188]) * (node_1606)))
+                    + ((base_row[208]) * (node_1606)))
+                    + ((base_row[189]) * (node_1606)))
+                    + ((base_row[190]) * (node_1606)))
+                    + ((base_row[191]) * (node_1606)))
+                    + ((base_row[192]) * (node_1606)))
+                    + ((base_row[193]) * (node_1606)))
+                    + ((base_row[204]) * (node_1606)))
+                    + ((base_row[194]) * (node_1606)))
+                    + ((base_row[195]) * (node_1606)))
+                    + ((base_row[196]) * (node_1606)))
+                    + ((base_row[209]) * (node_1606)))
+                    + ((base_row[197]) * (node_1606)))
+                    + ((base_row[198]) * (node_1606)))
+                    + ((base_row[199]) * (node_1606)))
+                    + ((base_row[200]) * (node_1606)))
+                    + ((base_row[201]) * (node_1606)))
+                    + ((base_row[202]) * (node_1606)))
+                    + ((base_row[203]) * (node_1606)))
+                    + ((base_row[215]) * (node_1606)),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[240]))
+                    + ((base_row[174]) * (base_row[240])))
+                    + ((base_row[175]) * (base_row[240])))
+                    + ((base_row[176]) * (base_row[240])))
+                    + ((base_row[205]) * (base_row[240])))
+                    + ((base_row[213]) * (base_row[240])))
+                    + ((base_row[214]) * (base_row[240])))
+                    + ((base_row[177]) * (base_row[240])))
+                    + ((base_row[178]) * (base_row[240])))
+                    + ((base_row[179]) * (base_row[240])))
+                    + ((base_row[210]) * (base_row[240])))
+                    + ((base_row[180]) * (base_row[240])))
+                    + ((base_row[181]) * (base_row[240])))
+                    + ((base_row[182]) * (base_row[240])))
+                    + ((base_row[211]) * (base_row[240])))
+                    + ((base_row[212]) * (base_row[240])))
+                    + ((base_row[183]) * (base_row[240])))
+                    + ((base_row[184]) * (base_row[240])))
+                    + ((base_row[185]) * (base_row[240])))
+                    + ((base_row[206]) * (base_row[240])))
+                    + ((base_row[186]) * (base_row[240])))
+                    + ((base_row[207]) * (base_row[240])))
+                    + ((base_row[187]) * (base_row[240])))
+                    + ((base_row[188]) * (base_row[240])))
+                    + ((base_row[208]) * (base_row[240])))
+                    + ((base_row[189]) * (base_row[240])))
+                    + ((base_row[190]) * (base_row[240])))
+                    + ((base_row[191]) * (base_row[240])))
+                    + ((base_row[192]) * (base_row[240])))
+                    + ((base_row[193]) * (base_row[240])))
+                    + ((base_row[204]) * (base_row[240])))
+                    + ((base_row[194]) * (base_row[240])))
+                    + ((base_row[195]) * (base_row[240])))
+                    + ((base_row[196]) * (base_row[240])))
+                    + ((base_row[209]) * ((base_row[240]) - (node_539))))
+                    + ((base_row[197]) * (base_row[240])))
+                    + ((base_row[198]) * (base_row[240])))
+                    + ((base_row[199]) * (base_row[240])))
+                    + ((base_row[200]) * (base_row[240])))
+                    + ((base_row[201]) * (base_row[240])))
+                    + ((base_row[202]) * (base_row[240])))
+                    + ((base_row[203]) * (base_row[240])))
+                    + ((base_row[215]) * (base_row[240])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[241]))
+                    + ((base_row[174]) * (base_row[241])))
+                    + ((base_row[175]) * (base_row[241])))
+                    + ((base_row[176]) * (base_row[241])))
+                    + ((base_row[205]) * (base_row[241])))
+                    + ((base_row[213]) * (base_row[241])))
+                    + ((base_row[214]) * (base_row[241])))
+                    + ((base_row[177]) * (base_row[241])))
+                    + ((base_row[178]) * (base_row[241])))
+                    + ((base_row[179]) * (base_row[241])))
+                    + ((base_row[210]) * (base_row[241])))
+                    + ((base_row[180]) * (base_row[241])))
+                    + ((base_row[181]) * (base_row[241])))
+                    + ((base_row[182]) * (base_row[241])))
+                    + ((base_row[211]) * (base_row[241])))
+                    + ((base_row[212]) * (base_row[241])))
+                    + ((base_row[183]) * (base_row[241])))
+                    + ((base_row[184]) * (base_row[241])))
+                    + ((base_row[185]) * (base_row[241])))
+                    + ((base_row[206]) * (base_row[241])))
+                    + ((base_row[186]) * (base_row[241])))
+                    + ((base_row[207]) * (base_row[241])))
+                    + ((base_row[187]) * (base_row[241])))
+                    + ((base_row[188]) * (base_row[241])))
+                    + ((base_row[208]) * (base_row[241])))
+                    + ((base_row[189]) * (base_row[241])))
+                    + ((base_row[190]) * (base_row[241])))
+                    + ((base_row[191]) * (base_row[241])))
+                    + ((base_row[192]) * (base_row[241])))
+                    + ((base_row[193]) * (base_row[241])))
+                    + ((base_row[204]) * (base_row[241])))
+                    + ((base_row[194]) * (base_row[241])))
+                    + ((base_row[195]) * (base_row[241])))
+                    + ((base_row[196]) * (base_row[241])))
+                    + ((base_row[209]) * (base_row[241])))
+                    + ((base_row[197]) * (base_row[241])))
+                    + ((base_row[198]) * (base_row[241])))
+                    + ((base_row[199]) * (base_row[241])))
+                    + ((base_row[200]) * (base_row[241])))
+                    + ((base_row[201]) * (base_row[241])))
+                    + ((base_row[202]) * (base_row[241])))
+                    + ((base_row[203]) * (base_row[241])))
+                    + ((base_row[215]) * (base_row[241])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[242]))
+                    + ((base_row[174]) * (base_row[242])))
+                    + ((base_row[175]) * (base_row[242])))
+                    + ((base_row[176]) * (base_row[242])))
+                    + ((base_row[205]) * (base_row[242])))
+                    + ((base_row[213]) * (base_row[242])))
+                    + ((base_row[214]) * (base_row[242])))
+                    + ((base_row[177]) * (base_row[242])))
+                    + ((base_row[178]) * (base_row[242])))
+                    + ((base_row[179]) * (base_row[242])))
+                    + ((base_row[210]) * (base_row[242])))
+                    + ((base_row[180]) * (base_row[242])))
+                    + ((base_row[181]) * (base_row[242])))
+                    + ((base_row[182]) * (base_row[242])))
+                    + ((base_row[211]) * (base_row[242])))
+                    + ((base_row[212]) * (base_row[242])))
+                    + ((base_row[183]) * (base_row[242])))
+                    + ((base_row[184]) * (base_row[242])))
+                    + ((base_row[185]) * (base_row[242])))
+                    + ((base_row[206]) * (base_row[242])))
+                    + ((base_row[186]) * (base_row[242])))
+                    + ((base_row[207]) * (base_row[242])))
+                    + ((base_row[187]) * (base_row[242])))
+                    + ((base_row[188]) * (base_row[242])))
+                    + ((base_row[208]) * (base_row[242])))
+                    + ((base_row[189]) * (base_row[242])))
+                    + ((base_row[190]) * (base_row[242])))
+                    + ((base_row[191]) * (base_row[242])))
+                    + ((base_row[192]) * (base_row[242])))
+                    + ((base_row[193]) * (base_row[242])))
+                    + ((base_row[204]) * (base_row[242])))
+                    + ((base_row[194]) * (base_row[242])))
+                    + ((base_row[195]) * (base_row[242])))
+                    + ((base_row[196]) * (base_row[242])))
+                    + ((base_row[209]) * (base_row[242])))
+                    + ((base_row[197]) * (base_row[242])))
+                    + ((base_row[198]) * (base_row[242])))
+                    + ((base_row[199]) * (base_row[242])))
+                    + ((base_row[200]) * (base_row[242])))
+                    + ((base_row[201]) * (base_row[242])))
+                    + ((base_row[202]) * (base_row[242])))
+                    + ((base_row[203]) * (base_row[242])))
+                    + ((base_row[215]) * (base_row[242])),
+            ];
+        let ext_constraints = [];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_transition_constraints(
+        current_base_row: ArrayView1<BFieldElement>,
+        current_ext_row: ArrayView1<XFieldElement>,
+        next_base_row: ArrayView1<BFieldElement>,
+        next_ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_11 = (next_base_row[152]) - (current_base_row[152]);
+        let node_22 = (BFieldElement::new(1)) - (next_base_row[151]);
+        let node_24 = (node_11) - (BFieldElement::new(1));
+        let node_26 = (next_base_row[152]) - (current_base_row[160]);
+        let node_106 = (current_base_row[161]) - (current_base_row[162]);
+        let node_185 = (next_base_row[198]) + (next_base_row[220]);
+        let node_382 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (next_base_row[227]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (next_base_row[228])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (next_base_row[229])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (next_base_row[230])));
+        let node_394 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (next_base_row[231]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (next_base_row[232])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (next_base_row[233])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (next_base_row[234])));
+        let node_406 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (next_base_row[235]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (next_base_row[236])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (next_base_row[237])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (next_base_row[238])));
+        let node_418 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (next_base_row[239]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (next_base_row[240])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (next_base_row[241])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (next_base_row[242])));
+        let node_181 = (next_base_row[197]) + (next_base_row[217]);
+        let node_298 = (challenges.get_challenge(RegisterClkWeight)) * (next_base_row[150]);
+        let node_424 = (node_382) * (node_394);
+        let node_109 = (BFieldElement::new(1)) - ((node_106) * (current_base_row[167]));
+        let node_126 =
+            ((current_base_row[175]) + (current_base_row[205])) + (current_base_row[213]);
+        let node_130 = (current_base_row[176]) + (current_base_row[214]);
+        let node_121 = (BFieldElement::new(1)) - (current_base_row[230]);
+        let node_157 = (next_base_row[203]) + (next_base_row[221]);
+        let node_200 = (node_181) + (node_185);
+        let node_177 = (next_ext_row[47]) - (current_ext_row[47]);
+        let node_207 = (next_base_row[201]) + (next_base_row[216]);
+        let node_223 = (next_ext_row[49]) - (current_ext_row[49]);
+        let node_338 = (next_base_row[183]) + (next_base_row[184]);
+        let node_365 = (next_ext_row[52]) - (current_ext_row[52]);
+        let node_427 = (node_424) * (node_406);
+
+        let base_constraints = [
+            ((next_base_row[150]) - (current_base_row[150])) - (BFieldElement::new(1)),
+            (current_base_row[151]) * ((next_base_row[151]) - (BFieldElement::new(1))),
+            (next_base_row[151]) * (node_11),
+            (next_base_row[151]) * ((next_base_row[153]) - (current_base_row[153])),
+            (((current_base_row[202]) + (current_base_row[218])) + (current_base_row[219]))
+                * (node_22),
+            ((((((((((((((((((((((((((((((((((current_base_row
+                [177])
+                + (current_base_row[178]))
+                + (current_base_row[179]))
+                + (current_base_row[210]))
+                + (current_base_row[180]))
+                + (current_base_row[183]))
+                + (current_base_row[184]))
+                + (current_base_row[185]))
+                + (current_base_row[206]))
+                + (current_base_row[186]))
+                + (current_base_row[207]))
+                + (current_base_row[187]))
+                + (current_base_row[188]))
+                + (current_base_row[208]))
+                + (current_base_row[189]))
+                + (current_base_row[190]))
+                + (current_base_row[191]))
+                + (current_base_row[192]))
+                + (current_base_row[193]))
+                + (current_base_row[204]))
+                + (current_base_row[194]))
+                + (current_base_row[195]))
+                + (current_base_row[196]))
+                + (current_base_row[209]))
+                + (current_base_row[197]))
+                + (current_base_row[198]))
+                + (current_base_row[199]))
+                + (current_base_row[200]))
+                + (current_base_row[201]))
+                + (current_base_row[203]))
+                + (current_base_row[216]))
+                + (current_base_row[217]))
+                + (current_base_row[220]))
+                + (current_base_row[221]))
+                * (node_24),
+            ((current_base_row[181]) + (current_base_row[211])) * (node_26),
+            ((current_base_row[182]) + (current_base_row[212]))
+                * ((next_base_row[152]) - (current_base_row[161])),
+            ((current_base_row[173]) * (node_106)) * (node_24),
+            ((current_base_row[173]) * (node_109)) * (node_26),
+            ((current_base_row[174]) * (node_106)) * (node_26),
+            ((current_base_row[174]) * (node_109)) * (node_24),
+            (((node_126) * (current_base_row[230])) + ((node_130) * (node_121))) * (node_26),
+            (((node_126) * (node_121)) + ((node_130) * (current_base_row[230]))) * (node_24),
+            (((next_base_row[223]) - (current_base_row[223]))
+                - ((current_base_row[197]) + (current_base_row[217])))
+                - ((current_base_row[199]) * ((current_base_row[165]) - (current_base_row[223]))),
+            (((next_base_row[224]) - (current_base_row[224]))
+                - ((current_base_row[198]) + (current_base_row[220])))
+                - ((current_base_row[200]) * ((current_base_row[165]) - (current_base_row[224]))),
+            ((next_base_row[225])
+                - (((next_base_row[203]) * (next_base_row[161]))
+                    + ((next_base_row[221]) * (next_base_row[162]))))
+                - (((BFieldElement::new(1)) - (node_157)) * (current_base_row[225])),
+            ((next_base_row[226]) - (current_base_row[226]))
+                - ((node_157) * ((BFieldElement::new(1)) - (current_base_row[226]))),
+        ];
+        let ext_constraints = [
+            ((node_200)
+                * (((node_177)
+                    * ((challenges.get_challenge(TapeIndeterminate))
+                        - ((((challenges.get_challenge(TapeIsSecretWeight)) * (node_185))
+                            + ((challenges.get_challenge(TapePositionWeight))
+                                * (((node_181) * (next_base_row[223]))
+                                    + ((node_185) * (next_base_row[224])))))
+                            + ((challenges.get_challenge(TapeValueWeight))
+                                * (next_base_row[163])))))
+                    - (BFieldElement::new(1))))
+                + (((BFieldElement::new(1)) - (node_200)) * (node_177)),
+            ((next_ext_row[48])
+                - ((node_207)
+                    * (((current_ext_row[48])
+                        * (challenges.get_challenge(StandardOutputIndeterminate)))
+                        + (((next_base_row[201]) * (next_base_row[161]))
+                            + ((next_base_row[216]) * (next_base_row[162]))))))
+                - (((BFieldElement::new(1)) - (node_207)) * (current_ext_row[48])),
+            ((node_22)
+                * (((node_223)
+                    * ((challenges.get_challenge(InstructionLookupIndeterminate))
+                        - ((((challenges.get_challenge(ProgramAddressWeight))
+                            * (next_base_row[152]))
+                            + ((challenges.get_challenge(ProgramInstructionWeight))
+                                * (next_base_row[154])))
+                            + ((challenges.get_challenge(ProgramNextInstructionWeight))
+                                * (next_base_row[155])))))
+                    - (BFieldElement::new(1))))
+                + ((next_base_row[151]) * (node_223)),
+            ((node_22)
+                * ((next_ext_row[50])
+                    - ((current_ext_row[50])
+                        * ((((challenges.get_challenge(RegisterIndeterminate))
+                            - (((node_298)
+                                + ((challenges.get_challenge(RegisterIndexWeight))
+                                    * (next_base_row[156])))
+                                + ((challenges.get_challenge(RegisterValueWeight))
+                                    * (next_base_row[161]))))
+                            * ((challenges.get_challenge(RegisterIndeterminate))
+                                - (((node_298)
+                                    + ((challenges.get_challenge(RegisterIndexWeight))
+                                        * (next_base_row[157])))
+                                    + ((challenges.get_challenge(RegisterValueWeight))
+                                        * (next_base_row[162])))))
+                            * ((challenges.get_challenge(RegisterIndeterminate))
+                                - ((((node_298)
+                                    + ((challenges.get_challenge(RegisterIndexWeight))
+                                        * (next_base_row[158])))
+                                    + ((challenges.get_challenge(RegisterValueWeight))
+                                        * (next_base_row[163])))
+                                    + ((challenges.get_challenge(RegisterIsWriteWeight))
+                                        * ((((((((((((((((((((((((((((next_base_row[177])
+                                            + (next_base_row[178]))
+                                            + (next_base_row[179]))
+                                            + (next_base_row[210]))
+                                            + (next_base_row[180]))
+                                            + (next_base_row[211]))
+                                            + (next_base_row[212]))
+                                            + (next_base_row[183]))
+                                            + (next_base_row[185]))
+                                            + (next_base_row[206]))
+                                            + (next_base_row[186]))
+                                            + (next_base_row[207]))
+                                            + (next_base_row[187]))
+                                            + (next_base_row[188]))
+                                            + (next_base_row[208]))
+                                            + (next_base_row[189]))
+                                            + (next_base_row[190]))
+                                            + (next_base_row[191]))
+                                            + (next_base_row[192]))
+                                            + (next_base_row[193]))
+                                            + (next_base_row[204]))
+                                            + (next_base_row[194]))
+                                            + (next_base_row[195]))
+                                            + (next_base_row[196]))
+                                            + (next_base_row[209]))
+                                            + (next_base_row[197]))
+                                            + (next_base_row[198]))
+                                            + (next_base_row[215])))))))))
+                + ((next_base_row[151]) * ((next_ext_row[50]) - (current_ext_row[50]))),
+            ((node_22)
+                * ((next_ext_row[51])
+                    - ((current_ext_row[51])
+                        * (((node_338)
+                            * ((challenges.get_challenge(MemoryIndeterminate))
+                                - (((((challenges.get_challenge(MemoryClkWeight))
+                                    * (next_base_row[150]))
+                                    + ((challenges.get_challenge(MemoryAddressWeight))
+                                        * (next_base_row[165])))
+                                    + ((challenges.get_challenge(MemoryValueWeight))
+                                        * (next_base_row[166])))
+                                    + ((challenges.get_challenge(MemoryIsWriteWeight))
+                                        * (next_base_row[184])))))
+                            + ((BFieldElement::new(1)) - (node_338))))))
+                + ((next_base_row[151]) * ((next_ext_row[51]) - (current_ext_row[51]))),
+            ((node_22)
+                * (((node_365) * ((node_427) * (node_418)))
+                    - ((((((node_394) * (node_406)) * (node_418))
+                        + (((node_382) * (node_406)) * (node_418)))
+                        + ((node_424) * (node_418)))
+                        + (node_427))))
+                + ((next_base_row[151]) * (node_365)),
+            (((next_ext_row[53]) - (current_ext_row[53]))
+                * ((challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate))
+                    - (next_base_row[150])))
+                - (next_base_row[222]),
+        ];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_terminal_constraints(
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let base_constraints =
+            [(BFieldElement::new(1)) - (((base_row[202]) + (base_row[218])) + (base_row[219]))];
+        let ext_constraints = [
+            (((base_row[202]) * (base_row[161])) + ((base_row[219]) * (base_row[162])))
+                - (challenges.get_challenge(ClaimedExitCode)),
+            (base_row[225]) - (challenges.get_challenge(ClaimedFinalAnswer)),
+            (base_row[226]) - (challenges.get_challenge(ClaimedHasFinalAnswer)),
+        ];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+}
+
+impl Evaluable<XFieldElement> for ExtZmipsProcessorTable {
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_initial_constraints(
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_80 = (BFieldElement::new(1)) - (base_row[151]);
+        let node_220 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (base_row[227]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (base_row[228])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (base_row[229])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (base_row[230])));
+        let node_232 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (base_row[231]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (base_row[232])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (base_row[233])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (base_row[234])));
+        let node_244 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (base_row[235]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (base_row[236])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (base_row[237])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (base_row[238])));
+        let node_256 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (base_row[239]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (base_row[240])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (base_row[241])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (base_row[242])));
+        let node_26 = (base_row[198]) + (base_row[220]);
+        let node_262 = (node_220) * (node_232);
+        let node_27 = ((base_row[197]) + (base_row[217])) + (node_26);
+        let node_54 = (base_row[201]) + (base_row[216]);
+        let node_178 = (base_row[183]) + (base_row[184]);
+        let node_265 = (node_262) * (node_244);
+
+        let base_constraints = [
+            base_row[150],
+            base_row[152],
+            ((base_row[151]) + (base_row[7])) - (BFieldElement::new(1)),
+            base_row[223],
+            base_row[224],
+            (base_row[225])
+                - (((base_row[203]) * (base_row[161])) + ((base_row[221]) * (base_row[162]))),
+            (base_row[226]) - ((base_row[203]) + (base_row[221])),
+        ];
+        let ext_constraints = [
+            ((node_27)
+                * (((ext_row[47])
+                    * ((challenges.get_challenge(TapeIndeterminate))
+                        - (((challenges.get_challenge(TapeIsSecretWeight)) * (node_26))
+                            + ((challenges.get_challenge(TapeValueWeight)) * (base_row[163])))))
+                    - (BFieldElement::new(1))))
+                + (((BFieldElement::new(1)) - (node_27)) * (ext_row[47])),
+            ((node_54)
+                * (((ext_row[48]) - (challenges.get_challenge(StandardOutputIndeterminate)))
+                    - (((base_row[201]) * (base_row[161])) + ((base_row[216]) * (base_row[162])))))
+                + (((BFieldElement::new(1)) - (node_54))
+                    * ((ext_row[48]) - (BFieldElement::new(1)))),
+            ((node_80)
+                * (((ext_row[49])
+                    * ((challenges.get_challenge(InstructionLookupIndeterminate))
+                        - (((challenges.get_challenge(ProgramInstructionWeight))
+                            * (base_row[154]))
+                            + ((challenges.get_challenge(ProgramNextInstructionWeight))
+                                * (base_row[155])))))
+                    - (BFieldElement::new(1))))
+                + ((base_row[151]) * (ext_row[49])),
+            ((node_80)
+                * ((ext_row[50])
+                    - ((((challenges.get_challenge(RegisterIndeterminate))
+                        - (((challenges.get_challenge(RegisterIndexWeight))
+                            * (base_row[156]))
+                            + ((challenges.get_challenge(RegisterValueWeight))
+                                * (base_row[161]))))
+                        * ((challenges.get_challenge(RegisterIndeterminate))
+                            - (((challenges.get_challenge(RegisterIndexWeight))
+                                * (base_row[157]))
+                                + ((challenges.get_challenge(RegisterValueWeight))
+                                    * (base_row[162])))))
+                        * ((challenges.get_challenge(RegisterIndeterminate))
+                            - ((((challenges.get_challenge(RegisterIndexWeight))
+                                * (base_row[158]))
+                                + ((challenges.get_challenge(RegisterValueWeight))
+                                    * (base_row[163])))
+                                + ((challenges.get_challenge(RegisterIsWriteWeight))
+                                    * ((((((((((((((((((((((((((((base_row[177])
+                                        + (base_row[178]))
+                                        + (base_row[179]))
+                                        + (base_row[210]))
+                                        + (base_row[180]))
+                                        + (base_row[211]))
+                                        + (base_row[212]))
+                                        + (base_row[183]))
+                                        + (base_row[185]))
+                                        + (base_row[206]))
+                                        + (base_row[186]))
+                                        + (base_row[207]))
+                                        + (base_row[187]))
+                                        + (base_row[188]))
+                                        + (base_row[208]))
+                                        + (base_row[189]))
+                                        + (base_row[190]))
+                                        + (base_row[191]))
+                                        + (base_row[192]))
+                                        + (base_row[193]))
+                                        + (base_row[204]))
+                                        + (base_row[194]))
+                                        + (base_row[195]))
+                                        + (base_row[196]))
+                                        + (base_row[209]))
+                                        + (base_row[197]))
+                                        + (base_row[198]))
+                                        + (base_row[215]))))))))
+                + ((base_row[151]) * ((ext_row[50]) - (BFieldElement::new(1)))),
+            ((node_80)
+                * ((ext_row[51])
+                    - (((node_178)
+                        * ((challenges.get_challenge(MemoryIndeterminate))
+                            - ((((challenges.get_challenge(MemoryAddressWeight))
+                                * (base_row[165]))
+                                + ((challenges.get_challenge(MemoryValueWeight))
+                                    * (base_row[166])))
+                                + ((challenges.get_challenge(MemoryIsWriteWeight))
+                                    * (base_row[184])))))
+                        + ((BFieldElement::new(1)) - (node_178)))))
+                + ((base_row[151]) * ((ext_row[51]) - (BFieldElement::new(1)))),
+            ((node_80)
+                * (((ext_row[52]) * ((node_265) * (node_256)))
+                    - ((((((node_232) * (node_244)) * (node_256))
+                        + (((node_220) * (node_244)) * (node_256)))
+                        + ((node_262) * (node_256)))
+                        + (node_265))))
+                + ((base_row[151]) * (ext_row[52])),
+            ((ext_row[53]) * (challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate)))
+                - (base_row[222]),
+        ];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_consistency_constraints(
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_1255 = (base_row[235]) - (BFieldElement::new(4));
+        let node_1606 = (base_row[239]) - (BFieldElement::new(4));
+        let node_905 = (base_row[231]) - (BFieldElement::new(4));
+        let node_552 = (base_row[227]) - (BFieldElement::new(4));
+        let node_378 = (base_row[168]) * (BFieldElement::new(4294967296));
+        let node_380 = (base_row[169]) * (BFieldElement::new(4294967296));
+        let node_438 = (BFieldElement::new(2)) * (base_row[164]);
+        let node_434 = (BFieldElement::new(2)) * (base_row[161]);
+        let node_556 = (base_row[227]) - (BFieldElement::new(12));
+        let node_539 = (node_434) - (node_378);
+        let node_540 = (node_438) - (node_380);
+        let node_749 = (base_row[229]) - (base_row[164]);
+        let node_997 = (base_row[232]) - (node_539);
+        let node_1089 = (base_row[233]) - (node_540);
+        let node_364 = (base_row[161]) - (base_row[164]);
+        let node_609 = (base_row[227]) - (BFieldElement::new(20));
+        let node_653 = (base_row[228]) - (base_row[161]);
+        let node_676 = (base_row[228]) - (base_row[163]);
+        let node_536 = ((base_row[161]) + (BFieldElement::new(2147483648))) - (node_378);
+        let node_538 = ((base_row[164]) + (BFieldElement::new(2147483648))) - (node_380);
+        let node_1388 = (base_row[236]) - (base_row[171]);
+        let node_383 = (base_row[170]) * (BFieldElement::new(4294967296));
+        let node_387 = (base_row[161]) + (base_row[164]);
+        let node_422 = (base_row[163]) - (base_row[171]);
+        let node_368 = (BFieldElement::new(1)) - (base_row[163]);
+        let node_419 = (base_row[163]) - (base_row[172]);
+        let node_441 =
+            (base_row[164]) + ((base_row[169]) * ((BFieldElement::new(4294967296)) - (node_438)));
+        let node_448 = ((BFieldElement::new(2)) * (base_row[168])) * (base_row[169]);
+        let node_473 = (base_row[163]) - (base_row[161]);
+        let node_648 = (base_row[228]) - (node_538);
+        let node_679 = (base_row[228]) - (base_row[165]);
+        let node_694 = (base_row[228]) - (base_row[172]);
+        let node_713 = (base_row[228]) - (base_row[164]);
+        let node_744 = (base_row[229]) - (node_536);
+        let node_807 = (base_row[229]) - (BFieldElement::new(31));
+        let node_838 = (base_row[230]) - (base_row[163]);
+        let node_868 = (base_row[230]) - (BFieldElement::new(1));
+        let node_969 = (base_row[231]) - (BFieldElement::new(44));
+        let node_1061 = (base_row[232]) - (BFieldElement::new(2));
+        let node_1495 = (base_row[237]) - (base_row[172]);
+        let node_370 = (node_364) * (base_row[167]);
+        let node_379 = (base_row[161]) - (node_378);
+        let node_381 = (base_row[164]) - (node_380);
+        let node_384 = (base_row[163]) - (node_383);
+        let node_388 = (node_387) - (base_row[163]);
+        let node_395 = (node_364) - (base_row[163]);
+        let node_404 = ((base_row[171]) * (BFieldElement::new(4294967296))) + (base_row[172]);
+        let node_400 = ((base_row[195]) + (base_row[196])) + (base_row[209]);
+        let node_451 = (BFieldElement::new(1)) - ((base_row[171]) * (base_row[167]));
+        let node_449 = ((base_row[168]) + (base_row[169])) - (node_448);
+        let node_453 = (BFieldElement::new(1)) - ((base_row[172]) * (base_row[167]));
+        let node_466 = (BFieldElement::new(2)) * (base_row[172]);
+        let node_474 = (node_473) - (base_row[164]);
+        let node_495 = (node_387) - (base_row[165]);
+        let node_645 = (base_row[228]) - (node_536);
+        let node_682 = (base_row[228]) - (node_539);
+        let node_741 = (base_row[229]) - (node_538);
+        let node_776 = (base_row[229]) - (node_540);
+        let node_788 = (base_row[229]) - (node_441);
+        let node_881 = (base_row[230]) - (base_row[172]);
+        let node_1031 =
+            (base_row[232]) - (((BFieldElement::new(2)) * (base_row[163])) - (node_383));
+        let node_1153 = (base_row[233]) - ((BFieldElement::new(32)) - (base_row[230]));
+
+        let base_constraints =
+            [
+                (base_row[151]) * ((base_row[151]) - (BFieldElement::new(1))),
+                (base_row[173]) * ((base_row[173]) - (BFieldElement::new(1))),
+                (base_row[174]) * ((base_row[174]) - (BFieldElement::new(1))),
+                (base_row[175]) * ((base_row[175]) - (BFieldElement::new(1))),
+                (base_row[176]) * ((base_row[176]) - (BFieldElement::new(1))),
+                (base_row[205]) * ((base_row[205]) - (BFieldElement::new(1))),
+                (base_row[213]) * ((base_row[213]) - (BFieldElement::new(1))),
+                (base_row[214]) * ((base_row[214]) - (BFieldElement::new(1))),
+                (base_row[177]) * ((base_row[177]) - (BFieldElement::new(1))),
+                (base_row[178]) * ((base_row[178]) - (BFieldElement::new(1))),
+                (base_row[179]) * ((base_row[179]) - (BFieldElement::new(1))),
+                (base_row[210]) * ((base_row[210]) - (BFieldElement::new(1))),
+                (base_row[180]) * ((base_row[180]) - (BFieldElement::new(1))),
+                (base_row[181]) * ((base_row[181]) - (BFieldElement::new(1))),
+                (base_row[182]) * ((base_row[182]) - (BFieldElement::new(1))),
+                (base_row[211]) * ((base_row[211]) - (BFieldElement::new(1))),
+                (base_row[212]) * ((base_row[212]) - (BFieldElement::new(1))),
+                (base_row[183]) * ((base_row[183]) - (BFieldElement::new(1))),
+                (base_row[184]) * ((base_row[184]) - (BFieldElement::new(1))),
+                (base_row[185]) * ((base_row[185]) - (BFieldElement::new(1))),
+                (base_row[206]) * ((base_row[206]) - (BFieldElement::new(1))),
+                (base_row[186]) * ((base_row[186]) - (BFieldElement::new(1))),
+                (base_row[207]) * ((base_row[207]) - (BFieldElement::new(1))),
+                (base_row[187]) * ((base_row[187]) - (BFieldElement::new(1))),
+                (base_row[188]) * ((base_row[188]) - (BFieldElement::new(1))),
+                (base_row[208]) * ((base_row[208]) - (BFieldElement::new(1))),
+                (base_row[189]) * ((base_row[189]) - (BFieldElement::new(1))),
+                (base_row[190]) * ((base_row[190]) - (BFieldElement::new(1))),
+                (base_row[191]) * ((base_row[191]) - (BFieldElement::new(1))),
+                (base_row[192]) * ((base_row[192]) - (BFieldElement::new(1))),
+                (base_row[193]) * ((base_row[193]) - (BFieldElement::new(1))),
+                (base_row[204]) * ((base_row[204]) - (BFieldElement::new(1))),
+                (base_row[194]) * ((base_row[194]) - (BFieldElement::new(1))),
+                (base_row[195]) * ((base_row[195]) - (BFieldElement::new(1))),
+                (base_row[196]) * ((base_row[196]) - (BFieldElement::new(1))),
+                (base_row[209]) * ((base_row[209]) - (BFieldElement::new(1))),
+                (base_row[197]) * ((base_row[197]) - (BFieldElement::new(1))),
+                (base_row[198]) * ((base_row[198]) - (BFieldElement::new(1))),
+                (base_row[199]) * ((base_row[199]) - (BFieldElement::new(1))),
+                (base_row[200]) * ((base_row[200]) - (BFieldElement::new(1))),
+                (base_row[201]) * ((base_row[201]) - (BFieldElement::new(1))),
+                (base_row[202]) * ((base_row[202]) - (BFieldElement::new(1))),
+                (base_row[203]) * ((base_row[203]) - (BFieldElement::new(1))),
+                (base_row[215]) * ((base_row[215]) - (BFieldElement::new(1))),
+                (base_row[168]) * ((base_row[168]) - (BFieldElement::new(1))),
+                (base_row[169]) * ((base_row[169]) - (BFieldElement::new(1))),
+                (base_row[170]) * ((base_row[170]) - (BFieldElement::new(1))),
+                (base_row[216]) * ((base_row[216]) - (BFieldElement::new(1))),
+                (base_row[217]) * ((base_row[217]) - (BFieldElement::new(1))),
+                (base_row[218]) * ((base_row[218]) - (BFieldElement::new(1))),
+                (base_row[219]) * ((base_row[219]) - (BFieldElement::new(1))),
+                (base_row[220]) * ((base_row[220]) - (BFieldElement::new(1))),
+                (base_row[221]) * ((base_row[221]) - (BFieldElement::new(1))),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) + (base_row[174]))
+                    + (base_row[175]))
+                    + (base_row[176]))
+                    + (base_row[205]))
+                    + (base_row[213]))
+                    + (base_row[214]))
+                    + (base_row[177]))
+                    + (base_row[178]))
+                    + (base_row[179]))
+                    + (base_row[210]))
+                    + (base_row[180]))
+                    + (base_row[181]))
+                    + (base_row[182]))
+                    + (base_row[211]))
+                    + (base_row[212]))
+                    + (base_row[183]))
+                    + (base_row[184]))
+                    + (base_row[185]))
+                    + (base_row[206]))
+                    + (base_row[186]))
+                    + (base_row[207]))
+                    + (base_row[187]))
+                    + (base_row[188]))
+                    + (base_row[208]))
+                    + (base_row[189]))
+                    + (base_row[190]))
+                    + (base_row[191]))
+                    + (base_row[192]))
+                    + (base_row[193]))
+                    + (base_row[204]))
+                    + (base_row[194]))
+                    + (base_row[195]))
+                    + (base_row[196]))
+                    + (base_row[209]))
+                    + (base_row[197]))
+                    + (base_row[198]))
+                    + (base_row[199]))
+                    + (base_row[200]))
+                    + (base_row[201]))
+                    + (base_row[202]))
+                    + (base_row[203]))
+                    + (base_row[215]))
+                    - (BFieldElement::new(1)),
+                (base_row[153])
+                    - ((((((((((((((((((((((((((((((((((((((((((base_row[174])
+                        + ((BFieldElement::new(2)) * (base_row[175])))
+                        + ((BFieldElement::new(3)) * (base_row[176])))
+                        + ((BFieldElement::new(32)) * (base_row[205])))
+                        + ((BFieldElement::new(40))
+                            * (base_row[213])))
+                        + ((BFieldElement::new(41))
+                            * (base_row[214])))
+                        + ((BFieldElement::new(4))
+                            * (base_row[177])))
+                        + ((BFieldElement::new(5))
+                            * (base_row[178])))
+                        + ((BFieldElement::new(6))
+                            * (base_row[179])))
+                        + ((BFieldElement::new(37))
+                            * (base_row[210])))
+                        + ((BFieldElement::new(7))
+                            * (base_row[180])))
+                        + ((BFieldElement::new(8))
+                            * (base_row[181])))
+                        + ((BFieldElement::new(9)) * (base_row[182])))
+                        + ((BFieldElement::new(38)) * (base_row[211])))
+                        + ((BFieldElement::new(39)) * (base_row[212])))
+                        + ((BFieldElement::new(10)) * (base_row[183])))
+                        + ((BFieldElement::new(11)) * (base_row[184])))
+                        + ((BFieldElement::new(12)) * (base_row[185])))
+                        + ((BFieldElement::new(33)) * (base_row[206])))
+                        + ((BFieldElement::new(13)) * (base_row[186])))
+                        + ((BFieldElement::new(34)) * (base_row[207])))
+                        + ((BFieldElement::new(14)) * (base_row[187])))
+                        + ((BFieldElement::new(15)) * (base_row[188])))
+                        + ((BFieldElement::new(35)) * (base_row[208])))
+                        + ((BFieldElement::new(16)) * (base_row[189])))
+                        + ((BFieldElement::new(17)) * (base_row[190])))
+                        + ((BFieldElement::new(18)) * (base_row[191])))
+                        + ((BFieldElement::new(19)) * (base_row[192])))
+                        + ((BFieldElement::new(20)) * (base_row[193])))
+                        + ((BFieldElement::new(31)) * (base_row[204])))
+                        + ((BFieldElement::new(21)) * (base_row[194])))
+                        + ((BFieldElement::new(22)) * (base_row[195])))
+                        + ((BFieldElement::new(23)) * (base_row[196])))
+                        + ((BFieldElement::new(36)) * (base_row[209])))
+                        + ((BFieldElement::new(24)) * (base_row[197])))
+                        + ((BFieldElement::new(25)) * (base_row[198])))
+                        + ((BFieldElement::new(26)) * (base_row[199])))
+                        + ((BFieldElement::new(27)) * (base_row[200])))
+                        + ((BFieldElement::new(28)) * (base_row[201])))
+                        + ((BFieldElement::new(29)) * (base_row[202])))
+                        + ((BFieldElement::new(30)) * (base_row[203])))
+                        + ((BFieldElement::new(42)) * (base_row[215]))),
+                (base_row[159]) * ((base_row[159]) - (BFieldElement::new(1))),
+                (base_row[154])
+                    - ((((((base_row[153]) + ((BFieldElement::new(256)) * (base_row[156])))
+                        + ((BFieldElement::new(8192)) * (base_row[157])))
+                        + ((BFieldElement::new(262144)) * (base_row[158])))
+                        + ((BFieldElement::new(8388608)) * (base_row[159])))
+                        + ((BFieldElement::new(16777216)) * (base_row[160]))),
+                (base_row[164])
+                    - (((base_row[159]) * (base_row[160]))
+                        + (((BFieldElement::new(1)) - (base_row[159])) * (base_row[162]))),
+                ((base_row[177]) * (node_364)) * (base_row[163]),
+                (base_row[177]) * ((node_368) - (node_370)),
+                ((base_row[178]) * (node_364)) * (node_368),
+                (base_row[178]) * ((base_row[163]) - (node_370)),
+                (base_row[185]) * (((node_379) + (node_381)) - (node_384)),
+                ((base_row[206]) * (node_388)) * ((node_388) - (BFieldElement::new(4294967296))),
+                (base_row[186]) * (((node_379) - (node_381)) - (node_384)),
+                ((base_row[207]) * (node_395)) * ((node_395) + (BFieldElement::new(4294967296))),
+                (base_row[187]) * (((base_row[161]) * (base_row[164])) - (node_404)),
+                (node_400) * (((base_row[161]) * (base_row[234])) - (node_404)),
+                (((base_row[187]) + (node_400)) * (base_row[172]))
+                    * ((BFieldElement::new(1))
+                        - (((base_row[171]) - (BFieldElement::new(4294967295))) * (base_row[167]))),
+                (base_row[187]) * (node_419),
+                (base_row[195]) * (node_419),
+                (base_row[196]) * (node_422),
+                (base_row[209])
+                    * ((node_422)
+                        - ((base_row[168]) * ((BFieldElement::new(4294967296)) - (base_row[234])))),
+                (base_row[208])
+                    * (((base_row[161]) - ((base_row[171]) * (base_row[164]))) - (base_row[172])),
+                (base_row[208]) * (node_422),
+                ((base_row[188]) + (base_row[189]))
+                    * ((((base_row[161])
+                        + ((base_row[168]) * ((BFieldElement::new(4294967296)) - (node_434))))
+                        - ((base_row[171]) * (node_441)))
+                        - (base_row[172])),
+                ((base_row[188]) * (base_row[171])) * (node_451),
+                (base_row[188])
+                    * (((node_422)
+                        - ((node_449)
+                            * ((BFieldElement::new(4294967296))
+                                - ((BFieldElement::new(2)) * (base_row[171])))))
+                        + (((node_449) * (BFieldElement::new(4294967296))) * (node_451))),
+                ((base_row[189]) * (base_row[172])) * (node_453),
+                (base_row[189])
+                    * (((node_419)
+                        - ((base_row[168]) * ((BFieldElement::new(4294967296)) - (node_466))))
+                        + ((node_378) * (node_453))),
+                (base_row[204]) * ((node_474) + (base_row[172])),
+                (base_row[193]) * ((node_474) + (node_466)),
+                (base_row[194])
+                    * (((base_row[163]) + (base_row[164])) - (BFieldElement::new(4294967295))),
+                (base_row[190]) * ((base_row[163]) - (base_row[164])),
+                (base_row[191]) * ((base_row[163]) - (base_row[160])),
+                ((base_row[211]) + (base_row[212]))
+                    * (((base_row[163]) - (base_row[152])) - (BFieldElement::new(1))),
+                (((((base_row[183]) + (base_row[184])) + (base_row[199])) + (base_row[200]))
+                    * (node_495))
+                    * ((node_495) - (BFieldElement::new(4294967296))),
+                (base_row[183]) * ((base_row[163]) - (base_row[166])),
+                (base_row[184]) * ((base_row[166]) - (base_row[163])),
+                ((((((base_row[216]) + (base_row[217])) + (base_row[218])) + (base_row[219]))
+                    + (base_row[220]))
+                    + (base_row[221]))
+                    - (base_row[215]),
+                ((((((base_row[216]) * ((base_row[161]) - (BFieldElement::new(1))))
+                    + ((base_row[217]) * ((base_row[161]) - (BFieldElement::new(5)))))
+                    + ((base_row[218]) * ((base_row[161]) - (BFieldElement::new(10)))))
+                    + ((base_row[219]) * ((base_row[161]) - (BFieldElement::new(17)))))
+                    + ((base_row[220]) * ((base_row[161]) - (BFieldElement::new(100)))))
+                    + ((base_row[221]) * ((base_row[161]) - (BFieldElement::new(101)))),
+                (((base_row[215]) - (base_row[217])) - (base_row[220])) * (node_473),
+                (base_row[151]) * (base_row[222]),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (node_552))
+                    + ((base_row[174]) * (node_552)))
+                    + ((base_row[175]) * (node_556)))
+                    + ((base_row[176]) * (node_556)))
+                    + ((base_row[205]) * (node_556)))
+                    + ((base_row[213]) * (node_556)))
+                    + ((base_row[214]) * (node_556)))
+                    + ((base_row[177]) * (node_552)))
+                    + ((base_row[178]) * (node_552)))
+                    + ((base_row[179]) * (node_556)))
+                    + ((base_row[210]) * (node_556)))
+                    + ((base_row[180]) * (node_556)))
+                    + ((base_row[181]) * (node_552)))
+                    + ((base_row[182]) * (node_552)))
+                    + ((base_row[211]) * (node_552)))
+                    + ((base_row[212]) * (node_552)))
+                    + ((base_row[183]) * (node_552)))
+                    + ((base_row[184]) * (node_552)))
+                    + ((base_row[185]) * (node_552)))
+                    + ((base_row[206]) * (node_552)))
+                    + ((base_row[186]) * (node_552)))
+                    + ((base_row[207]) * (node_552)))
+                    + ((base_row[187]) * (node_552)))
+                    + ((base_row[188]) * (node_556)))
+                    + ((base_row[208]) * (node_556)))
+                    + ((base_row[189]) * (node_556)))
+                    + ((base_row[190]) * (node_552)))
+                    + ((base_row[191]) * (node_552)))
+                    + ((base_row[192]) * (node_609)))
+                    + ((base_row[193]) * (node_609)))
+                    + ((base_row[204]) * (node_609)))
+                    + ((base_row[194]) * (node_552)))
+                    + ((base_row[195]) * (node_609)))
+                    + ((base_row[196]) * (node_609)))
+                    + ((base_row[209]) * (node_609)))
+                    + ((base_row[197]) * (node_552)))
+                    + ((base_row[198]) * (node_552)))
+                    + ((base_row[199]) * (node_552)))
+                    + ((base_row[200]) * (node_552)))
+                    + ((base_row[201]) * (node_552)))
+                    + ((base_row[202]) * (node_552)))
+                    + ((base_row[203]) * (node_552)))
+                    + ((base_row[215]) * (node_552)),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[228]))
+                    + ((base_row[174]) * (base_row[228])))
+                    + ((base_row[175]) * (node_645)))
+                    + ((base_row[176]) * (node_648)))
+                    + ((base_row[205]) * (node_648)))
+                    + ((base_row[213]) * (node_653)))
+                    + ((base_row[214]) * (node_653)))
+                    + ((base_row[177]) * (base_row[228])))
+                    + ((base_row[178]) * (base_row[228])))
+                    + ((base_row[179]) * (node_645)))
+                    + ((base_row[210]) * (node_653)))
+                    + ((base_row[180]) * (node_648)))
+                    + ((base_row[181]) * (base_row[228])))
+                    + ((base_row[182]) * (base_row[228])))
+                    + ((base_row[211]) * (base_row[228])))
+                    + ((base_row[212]) * (base_row[228])))
+                    + ((base_row[183]) * (node_676)))
+                    + ((base_row[184]) * (node_679)))
+                    + ((base_row[185]) * (node_682)))
+                    + ((base_row[206]) * (node_676)))
+                    + ((base_row[186]) * (node_682)))
+                    + ((base_row[207]) * (node_676)))
+                    + ((base_row[187]) * ((base_row[228]) - (base_row[171]))))
+                    + ((base_row[188]) * (node_694)))
+                    + ((base_row[208]) * (node_694)))
+                    + ((base_row[189]) * (node_694)))
+                    + ((base_row[190]) * (base_row[228])))
+                    + ((base_row[191]) * (base_row[228])))
+                    + ((base_row[192]) * (node_653)))
+                    + ((base_row[193]) * (node_653)))
+                    + ((base_row[204]) * (node_653)))
+                    + ((base_row[194]) * (base_row[228])))
+                    + ((base_row[195]) * (node_713)))
+                    + ((base_row[196]) * (node_713)))
+                    + ((base_row[209]) * (node_713)))
+                    + ((base_row[197]) * (node_676)))
+                    + ((base_row[198]) * (node_676)))
+                    + ((base_row[199]) * (node_679)))
+                    + ((base_row[200]) * (node_679)))
+                    + ((base_row[201]) * (base_row[228])))
+                    + ((base_row[202]) * (base_row[228])))
+                    + ((base_row[203]) * (base_row[228])))
+                    + ((base_row[215]) * (node_676)),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[229]))
+                    + ((base_row[174]) * (base_row[229])))
+                    + ((base_row[175]) * (node_741)))
+                    + ((base_row[176]) * (node_744)))
+                    + ((base_row[205]) * (node_744)))
+                    + ((base_row[213]) * (node_749)))
+                    + ((base_row[214]) * (node_749)))
+                    + ((base_row[177]) * (base_row[229])))
+                    + ((base_row[178]) * (base_row[229])))
+                    + ((base_row[179]) * (node_741)))
+                    + ((base_row[210]) * (node_749)))
+                    + ((base_row[180]) * (node_744)))
+                    + ((base_row[181]) * (base_row[229])))
+                    + ((base_row[182]) * (base_row[229])))
+                    + ((base_row[211]) * (base_row[229])))
+                    + ((base_row[212]) * (base_row[229])))
+                    + ((base_row[183]) * (base_row[229])))
+                    + ((base_row[184]) * (base_row[229])))
+                    + ((base_row[185]) * (node_776)))
+                    + ((base_row[206]) * (base_row[229])))
+                    + ((base_row[186]) * (node_776)))
+                    + ((base_row[207]) * (base_row[229])))
+                    + ((base_row[187]) * ((base_row[229]) - (base_row[172]))))
+                    + ((base_row[188]) * (node_788)))
+                    + ((base_row[208]) * (node_749)))
+                    + ((base_row[189]) * (node_788)))
+                    + ((base_row[190]) * (base_row[229])))
+                    + ((base_row[191]) * (base_row[229])))
+                    + ((base_row[192]) * (node_749)))
+                    + ((base_row[193]) * (node_749)))
+                    + ((base_row[204]) * (node_749)))
+                    + ((base_row[194]) * (base_row[229])))
+                    + ((base_row[195]) * (node_807)))
+                    + ((base_row[196]) * (node_807)))
+                    + ((base_row[209]) * (node_807)))
+                    + ((base_row[197]) * (base_row[229])))
+                    + ((base_row[198]) * (base_row[229])))
+                    + ((base_row[199]) * (base_row[229])))
+                    + ((base_row[200]) * (base_row[229])))
+                    + ((base_row[201]) * (base_row[229])))
+                    + ((base_row[202]) * (base_row[229])))
+                    + ((base_row[203]) * (base_row[229])))
+                    + ((base_row[215]) * (base_row[229])),
+                (((((((((((((((((((((((((((((((((((base_row[173])
+                    * (base_row[230]))
+                    + ((base_row[174]) * (base_row[230])))
+                    + ((base_row[177]) * (base_row[230])))
+                    + ((base_row[178]) * (base_row[230])))
+                    + ((base_row[179]) * (node_838)))
+                    + ((base_row[210]) * (node_838)))
+                    + ((base_row[180])
+                        * ((base_row[230]) - (node_368))))
+                    + ((base_row[181]) * (base_row[230])))
+                    + ((base_row[182]) * (base_row[230])))
+                    + ((base_row[211]) * (base_row[230])))
+                    + ((base_row[212]) * (base_row[230])))
+                    + ((base_row[183]) * (base_row[230])))
+                    + ((base_row[184]) * (base_row[230])))
+                    + ((base_row[185]) * (base_row[230])))
+                    + ((base_row[206]) * (base_row[230])))
+                    + ((base_row[186]) * (base_row[230])))
+                    + ((base_row[207]) * (base_row[230])))
+                    + ((base_row[187]) * (base_row[230])))
+                    + ((base_row[188]) * (node_868)))
+                    + ((base_row[208]) * (node_868)))
+                    + ((base_row[189]) * (node_868)))
+                    + ((base_row[190]) * (base_row[230])))
+                    + ((base_row[191]) * (base_row[230])))
+                    + ((base_row[192]) * (node_838)))
+                    + ((base_row[193]) * (node_881)))
+                    + ((base_row[204]) * (node_881)))
+                    + ((base_row[194]) * (base_row[230])))
+                    + ((base_row[197]) * (base_row[230])))
+                    + ((base_row[198]) * (base_row[230])))
+                    + ((base_row[199]) * (base_row[230])))
+                    + ((base_row[200]) * (base_row[230])))
+                    + ((base_row[201]) * (base_row[230])))
+                    + ((base_row[202]) * (base_row[230])))
+                    + ((base_row[203]) * (base_row[230])))
+                    + ((base_row[215]) * (base_row[230])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (node_905))
+                    + ((base_row[174]) * (node_905)))
+                    + ((base_row[175]) * (node_905)))
+                    + ((base_row[176]) * (node_905)))
+                    + ((base_row[205]) * (node_905)))
+                    + ((base_row[213]) * (node_905)))
+                    + ((base_row[214]) * (node_905)))
+                    + ((base_row[177]) * (node_905)))
+                    + ((base_row[178]) * (node_905)))
+                    + ((base_row[179]) * (node_905)))
+                    + ((base_row[210]) * (node_905)))
+                    + ((base_row[180]) * (node_905)))
+                    + ((base_row[181]) * (node_905)))
+                    + ((base_row[182]) * (node_905)))
+                    + ((base_row[211]) * (node_905)))
+                    + ((base_row[212]) * (node_905)))
+                    + ((base_row[183]) * (node_905)))
+                    + ((base_row[184]) * (node_905)))
+                    + ((base_row[185]) * (node_905)))
+                    + ((base_row[206]) * (node_905)))
+                    + ((base_row[186]) * (node_905)))
+                    + ((base_row[207]) * (node_905)))
+                    + ((base_row[187]) * (node_905)))
+                    + ((base_row[188]) * (node_905)))
+                    + ((base_row[208]) * (node_905)))
+                    + ((base_row[189]) * (node_905)))
+                    + ((base_row[190]) * (node_905)))
+                    + ((base_row[191]) * (node_905)))
+                    + ((base_row[192]) * (node_905)))
+                    + ((base_row[193]) * (node_905)))
+                    + ((base_row[204]) * (node_905)))
+                    + ((base_row[194]) * (node_905)))
+                    + ((base_row[195]) * (node_969)))
+                    + ((base_row[196]) * (node_969)))
+                    + ((base_row[209]) * (node_969)))
+                    + ((base_row[197]) * (node_905)))
+                    + ((base_row[198]) * (node_905)))
+                    + ((base_row[199]) * (node_905)))
+                    + ((base_row[200]) * (node_905)))
+                    + ((base_row[201]) * (node_905)))
+                    + ((base_row[202]) * (node_905)))
+                    + ((base_row[203]) * (node_905)))
+                    + ((base_row[215]) * (node_905)),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[232]))
+                    + ((base_row[174]) * (base_row[232])))
+                    + ((base_row[175]) * (node_997)))
+                    + ((base_row[176]) * (node_997)))
+                    + ((base_row[205]) * (node_997)))
+                    + ((base_row[213]) * (base_row[232])))
+                    + ((base_row[214]) * (base_row[232])))
+                    + ((base_row[177]) * (base_row[232])))
+                    + ((base_row[178]) * (base_row[232])))
+                    + ((base_row[179]) * (node_997)))
+                    + ((base_row[210]) * (base_row[232])))
+                    + ((base_row[180]) * (node_997)))
+                    + ((base_row[181]) * (base_row[232])))
+                    + ((base_row[182]) * (base_row[232])))
+                    + ((base_row[211]) * (base_row[232])))
+                    + ((base_row[212]) * (base_row[232])))
+                    + ((base_row[183])
+                        * ((base_row[232]) - (base_row[165]))))
+                    + ((base_row[184]) * (base_row[232])))
+                    + ((base_row[185]) * (node_1031)))
+                    + ((base_row[206]) * (base_row[232])))
+                    + ((base_row[186]) * (node_1031)))
+                    + ((base_row[207]) * (base_row[232])))
+                    + ((base_row[187]) * (base_row[232])))
+                    + ((base_row[188]) * (node_997)))
+                    + ((base_row[208]) * ((base_row[232]) - (base_row[171]))))
+                    + ((base_row[189]) * (node_997)))
+                    + ((base_row[190]) * (base_row[232])))
+                    + ((base_row[191]) * (base_row[232])))
+                    + ((base_row[192]) * (base_row[232])))
+                    + ((base_row[193]) * (base_row[232])))
+                    + ((base_row[204]) * (base_row[232])))
+                    + ((base_row[194]) * (base_row[232])))
+                    + ((base_row[195]) * (node_1061)))
+                    + ((base_row[196]) * (node_1061)))
+                    + ((base_row[209]) * (node_1061)))
+                    + ((base_row[197]) * (base_row[232])))
+                    + ((base_row[198]) * (base_row[232])))
+                    + ((base_row[199]) * (base_row[232])))
+                    + ((base_row[200]) * (base_row[232])))
+                    + ((base_row[201]) * (base_row[232])))
+                    + ((base_row[202]) * (base_row[232])))
+                    + ((base_row[203]) * (base_row[232])))
+                    + ((base_row[215]) * (base_row[232])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[233]))
+                    + ((base_row[174]) * (base_row[233])))
+                    + ((base_row[175]) * (node_1089)))
+                    + ((base_row[176]) * (node_1089)))
+                    + ((base_row[205]) * (node_1089)))
+                    + ((base_row[213]) * (base_row[233])))
+                    + ((base_row[214]) * (base_row[233])))
+                    + ((base_row[177]) * (base_row[233])))
+                    + ((base_row[178]) * (base_row[233])))
+                    + ((base_row[179]) * (node_1089)))
+                    + ((base_row[210]) * (base_row[233])))
+                    + ((base_row[180]) * (node_1089)))
+                    + ((base_row[181]) * (base_row[233])))
+                    + ((base_row[182]) * (base_row[233])))
+                    + ((base_row[211]) * (base_row[233])))
+                    + ((base_row[212]) * (base_row[233])))
+                    + ((base_row[183]) * (base_row[233])))
+                    + ((base_row[184]) * (base_row[233])))
+                    + ((base_row[185]) * (base_row[233])))
+                    + ((base_row[206]) * (base_row[233])))
+                    + ((base_row[186]) * (base_row[233])))
+                    + ((base_row[207]) * (base_row[233])))
+                    + ((base_row[187]) * (base_row[233])))
+                    + ((base_row[188]) * (node_1089)))
+                    + ((base_row[208]) * (base_row[233])))
+                    + ((base_row[189]) * (node_1089)))
+                    + ((base_row[190]) * (base_row[233])))
+                    + ((base_row[191]) * (base_row[233])))
+                    + ((base_row[192]) * (base_row[233])))
+                    + ((base_row[193]) * (base_row[233])))
+                    + ((base_row[204]) * (base_row[233])))
+                    + ((base_row[194]) * (base_row[233])))
+                    + ((base_row[195]) * ((base_row[233]) - (base_row[230]))))
+                    + ((base_row[196]) * (node_1153)))
+                    + ((base_row[209]) * (node_1153)))
+                    + ((base_row[197]) * (base_row[233])))
+                    + ((base_row[198]) * (base_row[233])))
+                    + ((base_row[199]) * (base_row[233])))
+                    + ((base_row[200]) * (base_row[233])))
+                    + ((base_row[201]) * (base_row[233])))
+                    + ((base_row[202]) * (base_row[233])))
+                    + ((base_row[203]) * (base_row[233])))
+                    + ((base_row[215]) * (base_row[233])),
+                ((((((((((((((((((((((((((((((((((((((((base_row[173])
+                    * (base_row[234]))
+                    + ((base_row[174]) * (base_row[234])))
+                    + ((base_row[175]) * (base_row[234])))
+                    + ((base_row[176]) * (base_row[234])))
+                    + ((base_row[205]) * (base_row[234])))
+                    + ((base_row[213]) * (base_row[234])))
+                    + ((base_row[214]) * (base_row[234])))
+                    + ((base_row[177]) * (base_row[234])))
+                    + ((base_row[178]) * (base_row[234])))
+                    + ((base_row[179]) * (base_row[234])))
+                    + ((base_row[210]) * (base_row[234])))
+                    + ((base_row[180]) * (base_row[234])))
+                    + ((base_row[181]) * (base_row[234])))
+                    + ((base_row[182]) * (base_row[234])))
+                    + ((base_row[211]) * (base_row[234])))
+                    + ((base_row[212]) * (base_row[234])))
+                    + ((base_row[183]) * (base_row[234])))
+                    + ((base_row[184]) * (base_row[234])))
+                    + ((base_row[185]) * (base_row[234])))
+                    + ((base_row[206]) * (base_row[234])))
+                    + ((base_row[186]) * (base_row[234])))
+                    + ((base_row[207]) * (base_row[234])))
+                    + ((base_row[187]) * (base_row[234])))
+                    + ((base_row[188]) * (base_row[234])))
+                    + ((base_row[208]) * (base_row[234])))
+                    + ((base_row[189]) * (base_row[234])))
+                    + ((base_row[190]) * (base_row[234])))
+                    + ((base_row[191]) * (base_row[234])))
+                    + ((base_row[192]) * (base_row[234])))
+                    + ((base_row[193]) * (base_row[234])))
+                    + ((base_row[204]) * (base_row[234])))
+                    + ((base_row[194]) * (base_row[234])))
+                    + ((base_row[197]) * (base_row[234])))
+                    + ((base_row[198]) * (base_row[234])))
+                    + ((base_row[199]) * (base_row[234])))
+                    + ((base_row[200]) * (base_row[234])))
+                    + ((base_row[201]) * (base_row[234])))
+                    + ((base_row[202]) * (base_row[234])))
+                    + ((base_row[203]) * (base_row[234])))
+                    + ((base_row[215]) * (base_row[234])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (node_1255))
+                    + ((base_row[174]) * (node_1255)))
+                    + ((base_row[175]) * (node_1255)))
+                    + ((base_row[176]) * (node_1255)))
+                    + ((base_row[205]) * (node_1255)))
+                    + ((base_row[213]) * (node_1255)))
+                    + ((base_row[214]) * (node_1255)))
+                    + ((base_row[177]) * (node_1255)))
+                    + ((base_row[178]) * (node_1255)))
+                    + ((base_row[179]) * (node_1255)))
+                    + ((base_row[210]) * (node_1255)))
+                    + ((base_row[180]) * (node_1255)))
+                    + ((base_row[181]) * (node_1255)))
+                    + ((base_row[182]) * (node_1255)))
+                    + ((base_row[211]) * (node_1255)))
+                    + ((base_row[212]) * (node_1255)))
+                    + ((base_row[183]) * (node_1255)))
+                    + ((base_row[184]) * (node_1255)))
+                    + ((base_row[185]) * (node_1255)))
+                    + ((base_row[206]) * (node_1255)))
+                    + ((base_row[186]) * (node_1255)))
+                    + ((base_row[207]) * (node_1255)))
+                    + ((base_row[187]) * (node_1255)))
+                    + ((base_row[188]) * (node_1255)))
+                    + ((base_row[208]) * (node_1255)))
+                    + ((base_row[189]) * (node_1255)))
+                    + ((base_row[190]) * (node_1255)))
+                    + ((base_row[191]) * (node_1255)))
+                    + ((base_row[192]) * (node_1255)))
+                    + ((base_row[193]) * (node_1255)))
+                    + ((base_row[204]) * (node_1255)))
+                    + ((base_row[194]) * (node_1255)))
+                    + ((base_row[195]) * (node_1255)))
+                    + ((base_row[196]) * (node_1255)))
+                    + ((base_row[209]) * (node_1255)))
+                    + ((base_row[197]) * (node_1255)))
+                    + ((base_row[198]) * (node_1255)))
+                    + ((base_row[199]) * (node_1255)))
+                    + ((base_row[200]) * (node_1255)))
+                    + ((base_row[201]) * (node_1255)))
+                    + ((base_row[202]) * (node_1255)))
+                    + ((base_row[203]) * (node_1255)))
+                    + ((base_row[215]) * (node_1255)),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[236]))
+                    + ((base_row[174]) * (base_row[236])))
+                    + ((base_row[175]) * (base_row[236])))
+                    + ((base_row[176]) * (base_row[236])))
+                    + ((base_row[205]) * (base_row[236])))
+                    + ((base_row[213]) * (base_row[236])))
+                    + ((base_row[214]) * (base_row[236])))
+                    + ((base_row[177]) * (base_row[236])))
+                    + ((base_row[178]) * (base_row[236])))
+                    + ((base_row[179]) * (base_row[236])))
+                    + ((base_row[210]) * (base_row[236])))
+                    + ((base_row[180]) * (base_row[236])))
+                    + ((base_row[181]) * (base_row[236])))
+                    + ((base_row[182]) * (base_row[236])))
+                    + ((base_row[211]) * (base_row[236])))
+                    + ((base_row[212]) * (base_row[236])))
+                    + ((base_row[183]) * (base_row[236])))
+                    + ((base_row[184]) * (base_row[236])))
+                    + ((base_row[185]) * (base_row[236])))
+                    + ((base_row[206]) * (base_row[236])))
+                    + ((base_row[186]) * (base_row[236])))
+                    + ((base_row[207]) * (base_row[236])))
+                    + ((base_row[187]) * (base_row[236])))
+                    + ((base_row[188]) * (node_1388)))
+                    + ((base_row[208]) * (base_row[236])))
+                    + ((base_row[189]) * (node_1388)))
+                    + ((base_row[190]) * (base_row[236])))
+                    + ((base_row[191]) * (base_row[236])))
+                    + ((base_row[192]) * (base_row[236])))
+                    + ((base_row[193]) * (base_row[236])))
+                    + ((base_row[204]) * (base_row[236])))
+                    + ((base_row[194]) * (base_row[236])))
+                    + ((base_row[195]) * (node_1388)))
+                    + ((base_row[196]) * (node_1388)))
+                    + ((base_row[209]) * (node_1388)))
+                    + ((base_row[197]) * (base_row[236])))
+                    + ((base_row[198]) * (base_row[236])))
+                    + ((base_row[199]) * (base_row[236])))
+                    + ((base_row[200]) * (base_row[236])))
+                    + ((base_row[201]) * (base_row[236])))
+                    + ((base_row[202]) * (base_row[236])))
+                    + ((base_row[203]) * (base_row[236])))
+                    + ((base_row[215]) * (base_row[236])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[237]))
+                    + ((base_row[174]) * (base_row[237])))
+                    + ((base_row[175]) * (base_row[237])))
+                    + ((base_row[176]) * (base_row[237])))
+                    + ((base_row[205]) * (base_row[237])))
+                    + ((base_row[213]) * (base_row[237])))
+                    + ((base_row[214]) * (base_row[237])))
+                    + ((base_row[177]) * (base_row[237])))
+                    + ((base_row[178]) * (base_row[237])))
+                    + ((base_row[179]) * (base_row[237])))
+                    + ((base_row[210]) * (base_row[237])))
+                    + ((base_row[180]) * (base_row[237])))
+                    + ((base_row[181]) * (base_row[237])))
+                    + ((base_row[182]) * (base_row[237])))
+                    + ((base_row[211]) * (base_row[237])))
+                    + ((base_row[212]) * (base_row[237])))
+                    + ((base_row[183]) * (base_row[237])))
+                    + ((base_row[184]) * (base_row[237])))
+                    + ((base_row[185]) * (base_row[237])))
+                    + ((base_row[206]) * (base_row[237])))
+                    + ((base_row[186]) * (base_row[237])))
+                    + ((base_row[207]) * (base_row[237])))
+                    + ((base_row[187]) * (base_row[237])))
+                    + ((base_row[188])
+                        * ((base_row[237])
+                            - (((((BFieldElement::new(2147483647))
+                                + (base_row[168]))
+                                + (base_row[169]))
+                                - (node_448))
+                                - (base_row[171])))))
+                    + ((base_row[208]) * (base_row[237])))
+                    + ((base_row[189]) * (base_row[237])))
+                    + ((base_row[190]) * (base_row[237])))
+                    + ((base_row[191]) * (base_row[237])))
+                    + ((base_row[192]) * (base_row[237])))
+                    + ((base_row[193]) * (base_row[237])))
+                    + ((base_row[204]) * (base_row[237])))
+                    + ((base_row[194]) * (base_row[237])))
+                    + ((base_row[195]) * (node_1495)))
+                    + ((base_row[196]) * (node_1495)))
+                    + ((base_row[209]) * (node_1495)))
+                    + ((base_row[197]) * (base_row[237])))
+                    + ((base_row[198]) * (base_row[237])))
+                    + ((base_row[199]) * (base_row[237])))
+                    + ((base_row[200]) * (base_row[237])))
+                    + ((base_row[201]) * (base_row[237])))
+                    + ((base_row[202]) * (base_row[237])))
+                    + ((base_row[203]) * (base_row[237])))
+                    + ((base_row[215]) * (base_row[237])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[238]))
+                    + ((base_row[174]) * (base_row[238])))
+                    + ((base_row[175]) * (base_row[238])))
+                    + ((base_row[176]) * (base_row[238])))
+                    + ((base_row[205]) * (base_row[238])))
+                    + ((base_row[213]) * (base_row[238])))
+                    + ((base_row[214]) * (base_row[238])))
+                    + ((base_row[177]) * (base_row[238])))
+                    + ((base_row[178]) * (base_row[238])))
+                    + ((base_row[179]) * (base_row[238])))
+                    + ((base_row[210]) * (base_row[238])))
+                    + ((base_row[180]) * (base_row[238])))
+                    + ((base_row[181]) * (base_row[238])))
+                    + ((base_row[182]) * (base_row[238])))
+                    + ((base_row[211]) * (base_row[238])))
+                    + ((base_row[212]) * (base_row[238])))
+                    + ((base_row[183]) * (base_row[238])))
+                    + ((base_row[184]) * (base_row[238])))
+                    + ((base_row[185]) * (base_row[238])))
+                    + ((base_row[206]) * (base_row[238])))
+                    + ((base_row[186]) * (base_row[238])))
+                    + ((base_row[207]) * (base_row[238])))
+                    + ((base_row[187]) * (base_row[238])))
+                    + ((base_row[188]) * (base_row[238])))
+                    + ((base_row[208]) * (base_row[238])))
+                    + ((base_row[189]) * (base_row[238])))
+                    + ((base_row[190]) * (base_row[238])))
+                    + ((base_row[191]) * (base_row[238])))
+                    + ((base_row[192]) * (base_row[238])))
+                    + ((base_row[193]) * (base_row[238])))
+                    + ((base_row[204]) * (base_row[238])))
+                    + ((base_row[194]) * (base_row[238])))
+                    + ((base_row[195]) * (base_row[238])))
+                    + ((base_row[196]) * (base_row[238])))
+                    + ((base_row[209]) * (base_row[238])))
+                    + ((base_row[197]) * (base_row[238])))
+                    + ((base_row[198]) * (base_row[238])))
+                    + ((base_row[199]) * (base_row[238])))
+                    + ((base_row[200]) * (base_row[238])))
+                    + ((base_row[201]) * (base_row[238])))
+                    + ((base_row[202]) * (base_row[238])))
+                    + ((base_row[203]) * (base_row[238])))
+                    + ((base_row[215]) * (base_row[238])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (node_1606))
+                    + ((base_row[174]) * (node_1606)))
+                    + ((base_row[175]) * (node_1606)))
+                    + ((base_row[176]) * (node_1606)))
+                    + ((base_row[205]) * (node_1606)))
+                    + ((base_row[213]) * (node_1606)))
+                    + ((base_row[214]) * (node_1606)))
+                    + ((base_row[177]) * (node_1606)))
+                    + ((base_row[178]) * (node_1606)))
+                    + ((base_row[179]) * (node_1606)))
+                    + ((base_row[210]) * (node_1606)))
+                    + ((base_row[180]) * (node_1606)))
+                    + ((base_row[181]) * (node_1606)))
+                    + ((base_row[182]) * (node_1606)))
+                    + ((base_row[211]) * (node_1606)))
+                    + ((base_row[212]) * (node_1606)))
+                    + ((base_row[183]) * (node_1606)))
+                    + ((base_row[184]) * (node_1606)))
+                    + ((base_row[185]) * (node_1606)))
+                    + ((base_row[206]) * (node_1606)))
+                    + ((base_row[186]) * (node_1606)))
+                    + ((base_row[207]) * (node_1606)))
+                    + ((base_row[187]) * (node_1606)))
+                    + ((base_row[188]) * (node_1606)))
+                    + ((base_row[208]) * (node_1606)))
+                    + ((base_row[189]) * (node_1606)))
+                    + ((base_row[190]) * (node_1606)))
+                    + ((base_row[191]) * (node_1606)))
+                    + ((base_row[192]) * (node_1606)))
+                    + ((base_row[193]) * (node_1606)))
+                    + ((base_row[204]) * (node_1606)))
+                    + ((base_row[194]) * (node_1606)))
+                    + ((base_row[195]) * (node_1606)))
+                    + ((base_row[196]) * (node_1606)))
+                    + ((base_row[209]) * (node_1606)))
+                    + ((base_row[197]) * (node_1606)))
+                    + ((base_row[198]) * (node_1606)))
+                    + ((base_row[199]) * (node_1606)))
+                    + ((base_row[200]) * (node_1606)))
+                    + ((base_row[201]) * (node_1606)))
+                    + ((base_row[202]) * (node_1606)))
+                    + ((base_row[203]) * (node_1606)))
+                    + ((base_row[215]) * (node_1606)),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[240]))
+                    + ((base_row[174]) * (base_row[240])))
+                    + ((base_row[175]) * (base_row[240])))
+                    + ((base_row[176]) * (base_row[240])))
+                    + ((base_row[205]) * (base_row[240])))
+                    + ((base_row[213]) * (base_row[240])))
+                    + ((base_row[214]) * (base_row[240])))
+                    + ((base_row[177]) * (base_row[240])))
+                    + ((base_row[178]) * (base_row[240])))
+                    + ((base_row[179]) * (base_row[240])))
+                    + ((base_row[210]) * (base_row[240])))
+                    + ((base_row[180]) * (base_row[240])))
+                    + ((base_row[181]) * (base_row[240])))
+                    + ((base_row[182]) * (base_row[240])))
+                    + ((base_row[211]) * (base_row[240])))
+                    + ((base_row[212]) * (base_row[240])))
+                    + ((base_row[183]) * (base_row[240])))
+                    + ((base_row[184]) * (base_row[240])))
+                    + ((base_row[185]) * (base_row[240])))
+                    + ((base_row[206]) * (base_row[240])))
+                    + ((base_row[186]) * (base_row[240])))
+                    + ((base_row[207]) * (base_row[240])))
+                    + ((base_row[187]) * (base_row[240])))
+                    + ((base_row[188]) * (base_row[240])))
+                    + ((base_row[208]) * (base_row[240])))
+                    + ((base_row[189]) * (base_row[240])))
+                    + ((base_row[190]) * (base_row[240])))
+                    + ((base_row[191]) * (base_row[240])))
+                    + ((base_row[192]) * (base_row[240])))
+                    + ((base_row[193]) * (base_row[240])))
+                    + ((base_row[204]) * (base_row[240])))
+                    + ((base_row[194]) * (base_row[240])))
+                    + ((base_row[195]) * (base_row[240])))
+                    + ((base_row[196]) * (base_row[240])))
+                    + ((base_row[209]) * ((base_row[240]) - (node_539))))
+                    + ((base_row[197]) * (base_row[240])))
+                    + ((base_row[198]) * (base_row[240])))
+                    + ((base_row[199]) * (base_row[240])))
+                    + ((base_row[200]) * (base_row[240])))
+                    + ((base_row[201]) * (base_row[240])))
+                    + ((base_row[202]) * (base_row[240])))
+                    + ((base_row[203]) * (base_row[240])))
+                    + ((base_row[215]) * (base_row[240])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[241]))
+                    + ((base_row[174]) * (base_row[241])))
+                    + ((base_row[175]) * (base_row[241])))
+                    + ((base_row[176]) * (base_row[241])))
+                    + ((base_row[205]) * (base_row[241])))
+                    + ((base_row[213]) * (base_row[241])))
+                    + ((base_row[214]) * (base_row[241])))
+                    + ((base_row[177]) * (base_row[241])))
+                    + ((base_row[178]) * (base_row[241])))
+                    + ((base_row[179]) * (base_row[241])))
+                    + ((base_row[210]) * (base_row[241])))
+                    + ((base_row[180]) * (base_row[241])))
+                    + ((base_row[181]) * (base_row[241])))
+                    + ((base_row[182]) * (base_row[241])))
+                    + ((base_row[211]) * (base_row[241])))
+                    + ((base_row[212]) * (base_row[241])))
+                    + ((base_row[183]) * (base_row[241])))
+                    + ((base_row[184]) * (base_row[241])))
+                    + ((base_row[185]) * (base_row[241])))
+                    + ((base_row[206]) * (base_row[241])))
+                    + ((base_row[186]) * (base_row[241])))
+                    + ((base_row[207]) * (base_row[241])))
+                    + ((base_row[187]) * (base_row[241])))
+                    + ((base_row[188]) * (base_row[241])))
+                    + ((base_row[208]) * (base_row[241])))
+                    + ((base_row[189]) * (base_row[241])))
+                    + ((base_row[190]) * (base_row[241])))
+                    + ((base_row[191]) * (base_row[241])))
+                    + ((base_row[192]) * (base_row[241])))
+                    + ((base_row[193]) * (base_row[241])))
+                    + ((base_row[204]) * (base_row[241])))
+                    + ((base_row[194]) * (base_row[241])))
+                    + ((base_row[195]) * (base_row[241])))
+                    + ((base_row[196]) * (base_row[241])))
+                    + ((base_row[209]) * (base_row[241])))
+                    + ((base_row[197]) * (base_row[241])))
+                    + ((base_row[198]) * (base_row[241])))
+                    + ((base_row[199]) * (base_row[241])))
+                    + ((base_row[200]) * (base_row[241])))
+                    + ((base_row[201]) * (base_row[241])))
+                    + ((base_row[202]) * (base_row[241])))
+                    + ((base_row[203]) * (base_row[241])))
+                    + ((base_row[215]) * (base_row[241])),
+                (((((((((((((((((((((((((((((((((((((((((((base_row[173]) * (base_row[242]))
+                    + ((base_row[174]) * (base_row[242])))
+                    + ((base_row[175]) * (base_row[242])))
+                    + ((base_row[176]) * (base_row[242])))
+                    + ((base_row[205]) * (base_row[242])))
+                    + ((base_row[213]) * (base_row[242])))
+                    + ((base_row[214]) * (base_row[242])))
+                    + ((base_row[177]) * (base_row[242])))
+                    + ((base_row[178]) * (base_row[242])))
+                    + ((base_row[179]) * (base_row[242])))
+                    + ((base_row[210]) * (base_row[242])))
+                    + ((base_row[180]) * (base_row[242])))
+                    + ((base_row[181]) * (base_row[242])))
+                    + ((base_row[182]) * (base_row[242])))
+                    + ((base_row[211]) * (base_row[242])))
+                    + ((base_row[212]) * (base_row[242])))
+                    + ((base_row[183]) * (base_row[242])))
+                    + ((base_row[184]) * (base_row[242])))
+                    + ((base_row[185]) * (base_row[242])))
+                    + ((base_row[206]) * (base_row[242])))
+                    + ((base_row[186]) * (base_row[242])))
+                    + ((base_row[207]) * (base_row[242])))
+                    + ((base_row[187]) * (base_row[242])))
+                    + ((base_row[188]) * (base_row[242])))
+                    + ((base_row[208]) * (base_row[242])))
+                    + ((base_row[189]) * (base_row[242])))
+                    + ((base_row[190]) * (base_row[242])))
+                    + ((base_row[191]) * (base_row[242])))
+                    + ((base_row[192]) * (base_row[242])))
+                    + ((base_row[193]) * (base_row[242])))
+                    + ((base_row[204]) * (base_row[242])))
+                    + ((base_row[194]) * (base_row[242])))
+                    + ((base_row[195]) * (base_row[242])))
+                    + ((base_row[196]) * (base_row[242])))
+                    + ((base_row[209]) * (base_row[242])))
+                    + ((base_row[197]) * (base_row[242])))
+                    + ((base_row[198]) * (base_row[242])))
+                    + ((base_row[199]) * (base_row[242])))
+                    + ((base_row[200]) * (base_row[242])))
+                    + ((base_row[201]) * (base_row[242])))
+                    + ((base_row[202]) * (base_row[242])))
+                    + ((base_row[203]) * (base_row[242])))
+                    + ((base_row[215]) * (base_row[242])),
+            ];
+        let ext_constraints = [];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_transition_constraints(
+        current_base_row: ArrayView1<XFieldElement>,
+        current_ext_row: ArrayView1<XFieldElement>,
+        next_base_row: ArrayView1<XFieldElement>,
+        next_ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let node_11 = (next_base_row[152]) - (current_base_row[152]);
+        let node_22 = (BFieldElement::new(1)) - (next_base_row[151]);
+        let node_24 = (node_11) - (BFieldElement::new(1));
+        let node_26 = (next_base_row[152]) - (current_base_row[160]);
+        let node_106 = (current_base_row[161]) - (current_base_row[162]);
+        let node_185 = (next_base_row[198]) + (next_base_row[220]);
+        let node_382 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (next_base_row[227]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (next_base_row[228])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (next_base_row[229])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (next_base_row[230])));
+        let node_394 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (next_base_row[231]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (next_base_row[232])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (next_base_row[233])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (next_base_row[234])));
+        let node_406 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (next_base_row[235]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (next_base_row[236])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (next_base_row[237])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (next_base_row[238])));
+        let node_418 = (challenges.get_challenge(U32Indeterminate))
+            - (((((challenges.get_challenge(U32CiWeight)) * (next_base_row[239]))
+                + ((challenges.get_challenge(U32LhsWeight)) * (next_base_row[240])))
+                + ((challenges.get_challenge(U32RhsWeight)) * (next_base_row[241])))
+                + ((challenges.get_challenge(U32ResultWeight)) * (next_base_row[242])));
+        let node_181 = (next_base_row[197]) + (next_base_row[217]);
+        let node_298 = (challenges.get_challenge(RegisterClkWeight)) * (next_base_row[150]);
+        let node_424 = (node_382) * (node_394);
+        let node_109 = (BFieldElement::new(1)) - ((node_106) * (current_base_row[167]));
+        let node_126 =
+            ((current_base_row[175]) + (current_base_row[205])) + (current_base_row[213]);
+        let node_130 = (current_base_row[176]) + (current_base_row[214]);
+        let node_121 = (BFieldElement::new(1)) - (current_base_row[230]);
+        let node_157 = (next_base_row[203]) + (next_base_row[221]);
+        let node_200 = (node_181) + (node_185);
+        let node_177 = (next_ext_row[47]) - (current_ext_row[47]);
+        let node_207 = (next_base_row[201]) + (next_base_row[216]);
+        let node_223 = (next_ext_row[49]) - (current_ext_row[49]);
+        let node_338 = (next_base_row[183]) + (next_base_row[184]);
+        let node_365 = (next_ext_row[52]) - (current_ext_row[52]);
+        let node_427 = (node_424) * (node_406);
+
+        let base_constraints = [
+            ((next_base_row[150]) - (current_base_row[150])) - (BFieldElement::new(1)),
+            (current_base_row[151]) * ((next_base_row[151]) - (BFieldElement::new(1))),
+            (next_base_row[151]) * (node_11),
+            (next_base_row[151]) * ((next_base_row[153]) - (current_base_row[153])),
+            (((current_base_row[202]) + (current_base_row[218])) + (current_base_row[219]))
+                * (node_22),
+            ((((((((((((((((((((((((((((((((((current_base_row
+                [177])
+                + (current_base_row[178]))
+                + (current_base_row[179]))
+                + (current_base_row[210]))
+                + (current_base_row[180]))
+                + (current_base_row[183]))
+                + (current_base_row[184]))
+                + (current_base_row[185]))
+                + (current_base_row[206]))
+                + (current_base_row[186]))
+                + (current_base_row[207]))
+                + (current_base_row[187]))
+                + (current_base_row[188]))
+                + (current_base_row[208]))
+                + (current_base_row[189]))
+                + (current_base_row[190]))
+                + (current_base_row[191]))
+                + (current_base_row[192]))
+                + (current_base_row[193]))
+                + (current_base_row[204]))
+                + (current_base_row[194]))
+                + (current_base_row[195]))
+                + (current_base_row[196]))
+                + (current_base_row[209]))
+                + (current_base_row[197]))
+                + (current_base_row[198]))
+                + (current_base_row[199]))
+                + (current_base_row[200]))
+                + (current_base_row[201]))
+                + (current_base_row[203]))
+                + (current_base_row[216]))
+                + (current_base_row[217]))
+                + (current_base_row[220]))
+                + (current_base_row[221]))
+                * (node_24),
+            ((current_base_row[181]) + (current_base_row[211])) * (node_26),
+            ((current_base_row[182]) + (current_base_row[212]))
+                * ((next_base_row[152]) - (current_base_row[161])),
+            ((current_base_row[173]) * (node_106)) * (node_24),
+            ((current_base_row[173]) * (node_109)) * (node_26),
+            ((current_base_row[174]) * (node_106)) * (node_26),
+            ((current_base_row[174]) * (node_109)) * (node_24),
+            (((node_126) * (current_base_row[230])) + ((node_130) * (node_121))) * (node_26),
+            (((node_126) * (node_121)) + ((node_130) * (current_base_row[230]))) * (node_24),
+            (((next_base_row[223]) - (current_base_row[223]))
+                - ((current_base_row[197]) + (current_base_row[217])))
+                - ((current_base_row[199]) * ((current_base_row[165]) - (current_base_row[223]))),
+            (((next_base_row[224]) - (current_base_row[224]))
+                - ((current_base_row[198]) + (current_base_row[220])))
+                - ((current_base_row[200]) * ((current_base_row[165]) - (current_base_row[224]))),
+            ((next_base_row[225])
+                - (((next_base_row[203]) * (next_base_row[161]))
+                    + ((next_base_row[221]) * (next_base_row[162]))))
+                - (((BFieldElement::new(1)) - (node_157)) * (current_base_row[225])),
+            ((next_base_row[226]) - (current_base_row[226]))
+                - ((node_157) * ((BFieldElement::new(1)) - (current_base_row[226]))),
+        ];
+        let ext_constraints = [
+            ((node_200)
+                * (((node_177)
+                    * ((challenges.get_challenge(TapeIndeterminate))
+                        - ((((challenges.get_challenge(TapeIsSecretWeight)) * (node_185))
+                            + ((challenges.get_challenge(TapePositionWeight))
+                                * (((node_181) * (next_base_row[223]))
+                                    + ((node_185) * (next_base_row[224])))))
+                            + ((challenges.get_challenge(TapeValueWeight))
+                                * (next_base_row[163])))))
+                    - (BFieldElement::new(1))))
+                + (((BFieldElement::new(1)) - (node_200)) * (node_177)),
+            ((next_ext_row[48])
+                - ((node_207)
+                    * (((current_ext_row[48])
+                        * (challenges.get_challenge(StandardOutputIndeterminate)))
+                        + (((next_base_row[201]) * (next_base_row[161]))
+                            + ((next_base_row[216]) * (next_base_row[162]))))))
+                - (((BFieldElement::new(1)) - (node_207)) * (current_ext_row[48])),
+            ((node_22)
+                * (((node_223)
+                    * ((challenges.get_challenge(InstructionLookupIndeterminate))
+                        - ((((challenges.get_challenge(ProgramAddressWeight))
+                            * (next_base_row[152]))
+                            + ((challenges.get_challenge(ProgramInstructionWeight))
+                                * (next_base_row[154])))
+                            + ((challenges.get_challenge(ProgramNextInstructionWeight))
+                                * (next_base_row[155])))))
+                    - (BFieldElement::new(1))))
+                + ((next_base_row[151]) * (node_223)),
+            ((node_22)
+                * ((next_ext_row[50])
+                    - ((current_ext_row[50])
+                        * ((((challenges.get_challenge(RegisterIndeterminate))
+                            - (((node_298)
+                                + ((challenges.get_challenge(RegisterIndexWeight))
+                                    * (next_base_row[156])))
+                                + ((challenges.get_challenge(RegisterValueWeight))
+                                    * (next_base_row[161]))))
+                            * ((challenges.get_challenge(RegisterIndeterminate))
+                                - (((node_298)
+                                    + ((challenges.get_challenge(RegisterIndexWeight))
+                                        * (next_base_row[157])))
+                                    + ((challenges.get_challenge(RegisterValueWeight))
+                                        * (next_base_row[162])))))
+                            * ((challenges.get_challenge(RegisterIndeterminate))
+                                - ((((node_298)
+                                    + ((challenges.get_challenge(RegisterIndexWeight))
+                                        * (next_base_row[158])))
+                                    + ((challenges.get_challenge(RegisterValueWeight))
+                                        * (next_base_row[163])))
+                                    + ((challenges.get_challenge(RegisterIsWriteWeight))
+                                        * ((((((((((((((((((((((((((((next_base_row[177])
+                                            + (next_base_row[178]))
+                                            + (next_base_row[179]))
+                                            + (next_base_row[210]))
+                                            + (next_base_row[180]))
+                                            + (next_base_row[211]))
+                                            + (next_base_row[212]))
+                                            + (next_base_row[183]))
+                                            + (next_base_row[185]))
+                                            + (next_base_row[206]))
+                                            + (next_base_row[186]))
+                                            + (next_base_row[207]))
+                                            + (next_base_row[187]))
+                                            + (next_base_row[188]))
+                                            + (next_base_row[208]))
+                                            + (next_base_row[189]))
+                                            + (next_base_row[190]))
+                                            + (next_base_row[191]))
+                                            + (next_base_row[192]))
+                                            + (next_base_row[193]))
+                                            + (next_base_row[204]))
+                                            + (next_base_row[194]))
+                                            + (next_base_row[195]))
+                                            + (next_base_row[196]))
+                                            + (next_base_row[209]))
+                                            + (next_base_row[197]))
+                                            + (next_base_row[198]))
+                                            + (next_base_row[215])))))))))
+                + ((next_base_row[151]) * ((next_ext_row[50]) - (current_ext_row[50]))),
+            ((node_22)
+                * ((next_ext_row[51])
+                    - ((current_ext_row[51])
+                        * (((node_338)
+                            * ((challenges.get_challenge(MemoryIndeterminate))
+                                - (((((challenges.get_challenge(MemoryClkWeight))
+                                    * (next_base_row[150]))
+                                    + ((challenges.get_challenge(MemoryAddressWeight))
+                                        * (next_base_row[165])))
+                                    + ((challenges.get_challenge(MemoryValueWeight))
+                                        * (next_base_row[166])))
+                                    + ((challenges.get_challenge(MemoryIsWriteWeight))
+                                        * (next_base_row[184])))))
+                            + ((BFieldElement::new(1)) - (node_338))))))
+                + ((next_base_row[151]) * ((next_ext_row[51]) - (current_ext_row[51]))),
+            ((node_22)
+                * (((node_365) * ((node_427) * (node_418)))
+                    - ((((((node_394) * (node_406)) * (node_418))
+                        + (((node_382) * (node_406)) * (node_418)))
+                        + ((node_424) * (node_418)))
+                        + (node_427))))
+                + ((next_base_row[151]) * (node_365)),
+            (((next_ext_row[53]) - (current_ext_row[53]))
+                * ((challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate))
+                    - (next_base_row[150])))
+                - (next_base_row[222]),
+        ];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn evaluate_terminal_constraints(
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
+    ) -> Vec<XFieldElement> {
+        let base_constraints =
+            [(BFieldElement::new(1)) - (((base_row[202]) + (base_row[218])) + (base_row[219]))];
+        let ext_constraints = [
+            (((base_row[202]) * (base_row[161])) + ((base_row[219]) * (base_row[162])))
+                - (challenges.get_challenge(ClaimedExitCode)),
+            (base_row[225]) - (challenges.get_challenge(ClaimedFinalAnswer)),
+            (base_row[226]) - (challenges.get_challenge(ClaimedHasFinalAnswer)),
+        ];
+        base_constraints
+            .into_iter()
+            .chain(ext_constraints.into_iter())
+            .collect()
+    }
+}
+
+impl Quotientable for ExtZmipsProcessorTable {
+    fn num_initial_quotients() -> usize {
+        14
+    }
+
+    fn num_consistency_quotients() -> usize {
+        109
+    }
+
+    fn num_transition_quotients() -> usize {
+        25
+    }
+
+    fn num_terminal_quotients() -> usize {
+        4
+    }
+
+    #[allow(unused_variables)]
+    fn initial_quotient_degree_bounds(interpolant_degree: Degree) -> Vec<Degree> {
+        let zerofier_degree = 1 as Degree;
+        [
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 4 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 6 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+        ]
+        .to_vec()
+    }
+
+    #[allow(unused_variables)]
+    fn consistency_quotient_degree_bounds(
+        interpolant_degree: Degree,
+        padded_height: usize,
+    ) -> Vec<Degree> {
+        let zerofier_degree = padded_height as Degree;
+        [
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 4 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 4 as Degree - zerofier_degree,
+            interpolant_degree * 4 as Degree - zerofier_degree,
+            interpolant_degree * 5 as Degree - zerofier_degree,
+            interpolant_degree * 4 as Degree - zerofier_degree,
+            interpolant_degree * 4 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+        ]
+        .to_vec()
+    }
+
+    #[allow(unused_variables)]
+    fn transition_quotient_degree_bounds(
+        interpolant_degree: Degree,
+        padded_height: usize,
+    ) -> Vec<Degree> {
+        let zerofier_degree = padded_height as Degree - 1;
+        [
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 4 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 4 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 4 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 3 as Degree - zerofier_degree,
+            interpolant_degree * 5 as Degree - zerofier_degree,
+            interpolant_degree * 4 as Degree - zerofier_degree,
+            interpolant_degree * 6 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+        ]
+        .to_vec()
+    }
+
+    #[allow(unused_variables)]
+    fn terminal_quotient_degree_bounds(interpolant_degree: Degree) -> Vec<Degree> {
+        let zerofier_degree = 1 as Degree;
+        [
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 2 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+            interpolant_degree * 1 as Degree - zerofier_degree,
+        ]
+        .to_vec()
+    }
+}
@@ -20,11 +20,15 @@ use crate::table::table_column::HashExtTableColumn;
 use crate::table::table_column::JumpStackExtTableColumn;
 use crate::table::table_column::LookupExtTableColumn;
 use crate::table::table_column::MasterExtTableColumn;
+use crate::table::table_column::MemoryExtTableColumn;
 use crate::table::table_column::OpStackExtTableColumn;
 use crate::table::table_column::ProcessorExtTableColumn;
 use crate::table::table_column::ProgramExtTableColumn;
 use crate::table::table_column::RamExtTableColumn;
+use crate::table::table_column::RegisterExtTableColumn;
+use crate::table::table_column::TapeExtTableColumn;
 use crate::table::table_column::U32ExtTableColumn;
+use crate::table::table_column::ZmipsProcessorExtTableColumn;
 
 pub trait CrossTableArg {
     fn default_initial() -> XFieldElement
@@ -159,15 +163,25 @@ impl<FF: FiniteField> Evaluable<FF> for GrandCrossTableArg {
         ext_row: ArrayView1<XFieldElement>,
         challenges: &Challenges,
     ) -> Vec<XFieldElement> {
+        // Exactly one of the two processors executes the program. The other one never reads or
+        // writes, leaving its evaluation arguments at the default initial, which is 1. The zMIPS
+        // processor reads its public input from the Tape Table, which holds the public input
+        // tape if and only if the zMIPS processor executes the program.
         let input_to_processor = challenges.get_challenge(StandardInputTerminal)
-            - ext_row[ProcessorExtTableColumn::InputTableEvalArg.master_ext_table_index()];
+            - ext_row[ProcessorExtTableColumn::InputTableEvalArg.master_ext_table_index()]
+            - ext_row[TapeExtTableColumn::PublicInputEvalArg.master_ext_table_index()]
+            + XFieldElement::one();
         let processor_to_output = ext_row
             [ProcessorExtTableColumn::OutputTableEvalArg.master_ext_table_index()]
+            + ext_row[ZmipsProcessorExtTableColumn::OutputTableEvalArg.master_ext_table_index()]
+            - XFieldElement::one()
             - challenges.get_challenge(StandardOutputTerminal);
 
         let instruction_lookup = ext_row
             [ProcessorExtTableColumn::InstructionLookupClientLogDerivative
                 .master_ext_table_index()]
+            + ext_row[ZmipsProcessorExtTableColumn::InstructionLookupClientLogDerivative
+                .master_ext_table_index()]
             - ext_row[ProgramExtTableColumn::InstructionLookupServerLogDerivative
                 .master_ext_table_index()];
         let processor_to_op_stack = ext_row
@@ -227,6 +241,8 @@ impl<FF: FiniteField> Evaluable<FF> for GrandCrossTableArg {
                 [LookupExtTableColumn::CascadeTableServerLogDerivative.master_ext_table_index()];
         let processor_to_u32 = ext_row
             [ProcessorExtTableColumn::U32LookupClientLogDerivative.master_ext_table_index()]
+            + ext_row[ZmipsProcessorExtTableColumn::U32LookupClientLogDerivative
+                .master_ext_table_index()]
             - ext_row[U32ExtTableColumn::LookupServerLogDerivative.master_ext_table_index()];
         let clock_jump_difference_lookup = ext_row
             [ProcessorExtTableColumn::ClockJumpDifferenceLookupServerLogDerivative
@@ -237,6 +253,25 @@ impl<FF: FiniteField> Evaluable<FF> for GrandCrossTableArg {
                 .master_ext_table_index()]
             - ext_row[JumpStackExtTableColumn::ClockJumpDifferenceLookupClientLogDerivative
                 .master_ext_table_index()];
+        let zmips_processor_to_register = ext_row
+            [ZmipsProcessorExtTableColumn::RegisterTablePermArg.master_ext_table_index()]
+            - ext_row[RegisterExtTableColumn::RunningProductPermArg.master_ext_table_index()];
+        let zmips_clock_jump_difference_lookup = ext_row
+            [ZmipsProcessorExtTableColumn::ClockJumpDifferenceLookupServerLogDerivative
+                .master_ext_table_index()]
+            - ext_row[RegisterExtTableColumn::ClockJumpDifferenceLookupClientLogDerivative
+                .master_ext_table_index()]
+            - ext_row[MemoryExtTableColumn::ClockJumpDifferenceLookupClientLogDerivative
+                .master_ext_table_index()];
+        let zmips_processor_to_tape = ext_row
+            [ZmipsProcessorExtTableColumn::TapeLookupClientLogDerivative.master_ext_table_index()]
+            - ext_row[TapeExtTableColumn::LookupServerLogDerivative.master_ext_table_index()];
+        let zmips_processor_to_memory = ext_row
+            [ZmipsProcessorExtTableColumn::MemoryTablePermArg.master_ext_table_index()]
+            - ext_row[MemoryExtTableColumn::RunningProductPermArg.master_ext_table_index()];
+        let program_to_memory = ext_row
+            [ProgramExtTableColumn::DataSegmentPermArg.master_ext_table_index()]
+            - ext_row[MemoryExtTableColumn::DataSegmentPermArg.master_ext_table_index()];
 
         let linear_sum = challenges.get_challenge(ProcessorToProgramWeight) * instruction_lookup
             + challenges.get_challenge(InputToProcessorWeight) * input_to_processor
@@ -251,7 +286,14 @@ impl<FF: FiniteField> Evaluable<FF> for GrandCrossTableArg {
             + challenges.get_challenge(CascadeToLookupWeight) * cascade_to_lookup
             + challenges.get_challenge(ProcessorToU32Weight) * processor_to_u32
             + challenges.get_challenge(ClockJumpDifferenceLookupWeight)
-                * clock_jump_difference_lookup;
+                * clock_jump_difference_lookup
+            + challenges.get_challenge(ZmipsProcessorToRegisterWeight)
+                * zmips_processor_to_register
+            + challenges.get_challenge(ZmipsClockJumpDifferenceLookupWeight)
+                * zmips_clock_jump_difference_lookup
+            + challenges.get_challenge(ZmipsProcessorToTapeWeight) * zmips_processor_to_tape
+            + challenges.get_challenge(ZmipsProcessorToMemoryWeight) * zmips_processor_to_memory
+            + challenges.get_challenge(ProgramToMemoryWeight) * program_to_memory;
         vec![linear_sum]
     }
 }
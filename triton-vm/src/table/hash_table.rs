@@ -1695,6 +1695,8 @@ impl HashTable {
 #[cfg(test)]
 mod constraint_tests {
     use num_traits::Zero;
+    use triton_opcodes::program::Program;
+    use triton_program::PrecomputedNonDeterminism;
 
     use crate::stark::triton_stark_tests::parse_simulate_pad_extend;
     use crate::table::extension_table::Evaluable;
@@ -1706,7 +1708,11 @@ mod constraint_tests {
     fn hash_table_satisfies_constraints_test() {
         let source_code = "hash hash hash halt";
         let (_, _, _, master_base_table, master_ext_table, challenges) =
-            parse_simulate_pad_extend(source_code, vec![], vec![]);
+            parse_simulate_pad_extend::<Program>(
+                source_code,
+                vec![],
+                Box::new(PrecomputedNonDeterminism::default()),
+            );
         assert_eq!(
             master_base_table.master_base_matrix.nrows(),
             master_ext_table.master_ext_matrix.nrows()
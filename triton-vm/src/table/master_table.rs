@@ -53,6 +53,8 @@ use crate::table::jump_stack_table::ExtJumpStackTable;
 use crate::table::jump_stack_table::JumpStackTable;
 use crate::table::lookup_table::ExtLookupTable;
 use crate::table::lookup_table::LookupTable;
+use crate::table::memory_table::ExtMemoryTable;
+use crate::table::memory_table::MemoryTable;
 use crate::table::op_stack_table::ExtOpStackTable;
 use crate::table::op_stack_table::OpStackTable;
 use crate::table::processor_table::ExtProcessorTable;
@@ -61,8 +63,14 @@ use crate::table::program_table::ExtProgramTable;
 use crate::table::program_table::ProgramTable;
 use crate::table::ram_table::ExtRamTable;
 use crate::table::ram_table::RamTable;
+use crate::table::register_table::ExtRegisterTable;
+use crate::table::register_table::RegisterTable;
+use crate::table::tape_table::ExtTapeTable;
+use crate::table::tape_table::TapeTable;
 use crate::table::u32_table::ExtU32Table;
 use crate::table::u32_table::U32Table;
+use crate::table::zmips_processor_table::ExtZmipsProcessorTable;
+use crate::table::zmips_processor_table::ZmipsProcessorTable;
 use crate::table::*;
 use crate::vm::AlgebraicExecutionTrace;
 
@@ -76,7 +84,11 @@ pub const NUM_BASE_COLUMNS: usize = program_table::BASE_WIDTH
     + hash_table::BASE_WIDTH
     + cascade_table::BASE_WIDTH
     + lookup_table::BASE_WIDTH
-    + u32_table::BASE_WIDTH;
+    + u32_table::BASE_WIDTH
+    + zmips_processor_table::BASE_WIDTH
+    + register_table::BASE_WIDTH
+    + tape_table::BASE_WIDTH
+    + memory_table::BASE_WIDTH;
 pub const NUM_EXT_COLUMNS: usize = program_table::EXT_WIDTH
     + processor_table::EXT_WIDTH
     + op_stack_table::EXT_WIDTH
@@ -85,7 +97,11 @@ pub const NUM_EXT_COLUMNS: usize = program_table::EXT_WIDTH
     + hash_table::EXT_WIDTH
     + cascade_table::EXT_WIDTH
     + lookup_table::EXT_WIDTH
-    + u32_table::EXT_WIDTH;
+    + u32_table::EXT_WIDTH
+    + zmips_processor_table::EXT_WIDTH
+    + register_table::EXT_WIDTH
+    + tape_table::EXT_WIDTH
+    + memory_table::EXT_WIDTH;
 pub const NUM_COLUMNS: usize = NUM_BASE_COLUMNS + NUM_EXT_COLUMNS;
 
 pub const PROGRAM_TABLE_START: usize = 0;
@@ -106,6 +122,15 @@ pub const LOOKUP_TABLE_START: usize = CASCADE_TABLE_END;
 pub const LOOKUP_TABLE_END: usize = LOOKUP_TABLE_START + lookup_table::BASE_WIDTH;
 pub const U32_TABLE_START: usize = LOOKUP_TABLE_END;
 pub const U32_TABLE_END: usize = U32_TABLE_START + u32_table::BASE_WIDTH;
+pub const ZMIPS_PROCESSOR_TABLE_START: usize = U32_TABLE_END;
+pub const ZMIPS_PROCESSOR_TABLE_END: usize =
+    ZMIPS_PROCESSOR_TABLE_START + zmips_processor_table::BASE_WIDTH;
+pub const REGISTER_TABLE_START: usize = ZMIPS_PROCESSOR_TABLE_END;
+pub const REGISTER_TABLE_END: usize = REGISTER_TABLE_START + register_table::BASE_WIDTH;
+pub const TAPE_TABLE_START: usize = REGISTER_TABLE_END;
+pub const TAPE_TABLE_END: usize = TAPE_TABLE_START + tape_table::BASE_WIDTH;
+pub const MEMORY_TABLE_START: usize = TAPE_TABLE_END;
+pub const MEMORY_TABLE_END: usize = MEMORY_TABLE_START + memory_table::BASE_WIDTH;
 
 pub const EXT_PROGRAM_TABLE_START: usize = 0;
 pub const EXT_PROGRAM_TABLE_END: usize = EXT_PROGRAM_TABLE_START + program_table::EXT_WIDTH;
@@ -126,6 +151,15 @@ pub const EXT_LOOKUP_TABLE_START: usize = EXT_CASCADE_TABLE_END;
 pub const EXT_LOOKUP_TABLE_END: usize = EXT_LOOKUP_TABLE_START + lookup_table::EXT_WIDTH;
 pub const EXT_U32_TABLE_START: usize = EXT_LOOKUP_TABLE_END;
 pub const EXT_U32_TABLE_END: usize = EXT_U32_TABLE_START + u32_table::EXT_WIDTH;
+pub const EXT_ZMIPS_PROCESSOR_TABLE_START: usize = EXT_U32_TABLE_END;
+pub const EXT_ZMIPS_PROCESSOR_TABLE_END: usize =
+    EXT_ZMIPS_PROCESSOR_TABLE_START + zmips_processor_table::EXT_WIDTH;
+pub const EXT_REGISTER_TABLE_START: usize = EXT_ZMIPS_PROCESSOR_TABLE_END;
+pub const EXT_REGISTER_TABLE_END: usize = EXT_REGISTER_TABLE_START + register_table::EXT_WIDTH;
+pub const EXT_TAPE_TABLE_START: usize = EXT_REGISTER_TABLE_END;
+pub const EXT_TAPE_TABLE_END: usize = EXT_TAPE_TABLE_START + tape_table::EXT_WIDTH;
+pub const EXT_MEMORY_TABLE_START: usize = EXT_TAPE_TABLE_END;
+pub const EXT_MEMORY_TABLE_END: usize = EXT_MEMORY_TABLE_START + memory_table::EXT_WIDTH;
 
 /// A `TableId` uniquely determines one of Triton VM's tables.
 #[derive(Debug, Copy, Clone, Display, EnumCountMacro, EnumIter, PartialEq, Eq, Hash)]
@@ -139,6 +173,10 @@ pub enum TableId {
     CascadeTable,
     LookupTable,
     U32Table,
+    ZmipsProcessorTable,
+    RegisterTable,
+    TapeTable,
+    MemoryTable,
 }
 
 /// A Master Table is, in some sense, a top-level table of Triton VM. It contains all the data
@@ -256,6 +294,10 @@ pub struct MasterBaseTable {
     pub hash_coprocessor_execution_len: usize,
     pub cascade_table_len: usize,
     pub u32_coprocesor_execution_len: usize,
+    pub zmips_execution_len: usize,
+    pub register_table_len: usize,
+    pub tape_table_len: usize,
+    pub memory_table_len: usize,
 
     pub randomized_padded_trace_len: usize,
 
@@ -347,6 +389,10 @@ impl MasterBaseTable {
             Self::cascade_table_length(aet),
             Self::lookup_table_length(),
             Self::u32_table_length(aet),
+            Self::zmips_processor_table_length(aet),
+            Self::register_table_length(aet),
+            Self::tape_table_length(aet),
+            Self::memory_table_length(aet),
         ]
         .iter()
         .max()
@@ -363,6 +409,25 @@ impl MasterBaseTable {
         aet.processor_trace.nrows()
     }
 
+    pub fn zmips_processor_table_length(aet: &AlgebraicExecutionTrace) -> usize {
+        aet.zmips_processor_trace.nrows()
+    }
+
+    pub fn register_table_length(aet: &AlgebraicExecutionTrace) -> usize {
+        // one initialization row per register, and one row per register access
+        register_table::NUM_REGISTERS
+            + register_table::NUM_REGISTER_ACCESSES_PER_INSTRUCTION
+                * aet.zmips_processor_trace.nrows()
+    }
+
+    pub fn tape_table_length(aet: &AlgebraicExecutionTrace) -> usize {
+        aet.public_input_tape.len() + aet.secret_input_tape.len()
+    }
+
+    pub fn memory_table_length(aet: &AlgebraicExecutionTrace) -> usize {
+        MemoryTable::table_length(aet)
+    }
+
     pub fn hash_table_length(aet: &AlgebraicExecutionTrace) -> usize {
         aet.sponge_trace.nrows() + aet.hash_trace.nrows()
     }
@@ -412,6 +477,10 @@ impl MasterBaseTable {
             hash_coprocessor_execution_len: Self::hash_table_length(aet),
             cascade_table_len: Self::cascade_table_length(aet),
             u32_coprocesor_execution_len: Self::u32_table_length(aet),
+            zmips_execution_len: Self::zmips_processor_table_length(aet),
+            register_table_len: Self::register_table_length(aet),
+            tape_table_len: Self::tape_table_length(aet),
+            memory_table_len: Self::memory_table_length(aet),
             randomized_padded_trace_len,
             rand_trace_to_padded_trace_unit_distance: unit_distance,
             fri_domain,
@@ -434,6 +503,19 @@ impl MasterBaseTable {
         LookupTable::fill_trace(lookup_table, aet);
         let u32_table = &mut master_base_table.table_mut(TableId::U32Table);
         U32Table::fill_trace(u32_table, aet);
+        let register_table = &mut master_base_table.table_mut(TableId::RegisterTable);
+        let clk_jump_diffs_register = RegisterTable::fill_trace(register_table, aet);
+        let memory_table = &mut master_base_table.table_mut(TableId::MemoryTable);
+        let clk_jump_diffs_memory = MemoryTable::fill_trace(memory_table, aet);
+        let zmips_processor_table = &mut master_base_table.table_mut(TableId::ZmipsProcessorTable);
+        ZmipsProcessorTable::fill_trace(
+            zmips_processor_table,
+            aet,
+            &clk_jump_diffs_register,
+            &clk_jump_diffs_memory,
+        );
+        let tape_table = &mut master_base_table.table_mut(TableId::TapeTable);
+        TapeTable::fill_trace(tape_table, aet);
 
         // memory-like tables must be filled in before clock jump differences are known, hence
         // the break from the usual order
@@ -455,6 +537,10 @@ impl MasterBaseTable {
         let hash_coprocessor_execution_len = self.hash_coprocessor_execution_len;
         let cascade_table_len = self.cascade_table_len;
         let u32_table_len = self.u32_coprocesor_execution_len;
+        let zmips_execution_len = self.zmips_execution_len;
+        let register_table_len = self.register_table_len;
+        let tape_table_len = self.tape_table_len;
+        let memory_table_len = self.memory_table_len;
 
         let program_table = &mut self.table_mut(TableId::ProgramTable);
         ProgramTable::pad_trace(program_table, program_len);
@@ -474,6 +560,14 @@ impl MasterBaseTable {
         LookupTable::pad_trace(lookup_table);
         let u32_table = &mut self.table_mut(TableId::U32Table);
         U32Table::pad_trace(u32_table, u32_table_len);
+        let zmips_processor_table = &mut self.table_mut(TableId::ZmipsProcessorTable);
+        ZmipsProcessorTable::pad_trace(zmips_processor_table, zmips_execution_len);
+        let register_table = &mut self.table_mut(TableId::RegisterTable);
+        RegisterTable::pad_trace(register_table, register_table_len);
+        let tape_table = &mut self.table_mut(TableId::TapeTable);
+        TapeTable::pad_trace(tape_table, tape_table_len);
+        let memory_table = &mut self.table_mut(TableId::MemoryTable);
+        MemoryTable::pad_trace(memory_table, memory_table_len);
     }
 
     /// Returns the low-degree extended columns as well as the columns' interpolation polynomials.
@@ -578,6 +672,26 @@ impl MasterBaseTable {
             master_ext_table.table_mut(TableId::U32Table),
             challenges,
         );
+        ZmipsProcessorTable::extend(
+            self.table(TableId::ZmipsProcessorTable),
+            master_ext_table.table_mut(TableId::ZmipsProcessorTable),
+            challenges,
+        );
+        RegisterTable::extend(
+            self.table(TableId::RegisterTable),
+            master_ext_table.table_mut(TableId::RegisterTable),
+            challenges,
+        );
+        TapeTable::extend(
+            self.table(TableId::TapeTable),
+            master_ext_table.table_mut(TableId::TapeTable),
+            challenges,
+        );
+        MemoryTable::extend(
+            self.table(TableId::MemoryTable),
+            master_ext_table.table_mut(TableId::MemoryTable),
+            challenges,
+        );
 
         master_ext_table
     }
@@ -594,6 +708,10 @@ impl MasterBaseTable {
             CascadeTable => (CASCADE_TABLE_START, CASCADE_TABLE_END),
             LookupTable => (LOOKUP_TABLE_START, LOOKUP_TABLE_END),
             U32Table => (U32_TABLE_START, U32_TABLE_END),
+            ZmipsProcessorTable => (ZMIPS_PROCESSOR_TABLE_START, ZMIPS_PROCESSOR_TABLE_END),
+            RegisterTable => (REGISTER_TABLE_START, REGISTER_TABLE_END),
+            TapeTable => (TAPE_TABLE_START, TAPE_TABLE_END),
+            MemoryTable => (MEMORY_TABLE_START, MEMORY_TABLE_END),
         }
     }
 
@@ -672,6 +790,13 @@ impl MasterExtTable {
             CascadeTable => (EXT_CASCADE_TABLE_START, EXT_CASCADE_TABLE_END),
             LookupTable => (EXT_LOOKUP_TABLE_START, EXT_LOOKUP_TABLE_END),
             U32Table => (EXT_U32_TABLE_START, EXT_U32_TABLE_END),
+            ZmipsProcessorTable => (
+                EXT_ZMIPS_PROCESSOR_TABLE_START,
+                EXT_ZMIPS_PROCESSOR_TABLE_END,
+            ),
+            RegisterTable => (EXT_REGISTER_TABLE_START, EXT_REGISTER_TABLE_END),
+            TapeTable => (EXT_TAPE_TABLE_START, EXT_TAPE_TABLE_END),
+            MemoryTable => (EXT_MEMORY_TABLE_START, EXT_MEMORY_TABLE_END),
         }
     }
 
@@ -706,6 +831,10 @@ pub fn all_degrees_with_origin(
         ExtCascadeTable::all_degrees_with_origin("cascade table", id, ph),
         ExtLookupTable::all_degrees_with_origin("lookup table", id, ph),
         ExtU32Table::all_degrees_with_origin("u32 table", id, ph),
+        ExtZmipsProcessorTable::all_degrees_with_origin("zmips processor table", id, ph),
+        ExtRegisterTable::all_degrees_with_origin("register table", id, ph),
+        ExtTapeTable::all_degrees_with_origin("tape table", id, ph),
+        ExtMemoryTable::all_degrees_with_origin("memory table", id, ph),
     ]
     .concat()
 }
@@ -737,6 +866,10 @@ pub fn num_all_initial_quotients() -> usize {
         + ExtCascadeTable::num_initial_quotients()
         + ExtLookupTable::num_initial_quotients()
         + ExtU32Table::num_initial_quotients()
+        + ExtZmipsProcessorTable::num_initial_quotients()
+        + ExtRegisterTable::num_initial_quotients()
+        + ExtTapeTable::num_initial_quotients()
+        + ExtMemoryTable::num_initial_quotients()
 }
 
 pub fn num_all_consistency_quotients() -> usize {
@@ -749,6 +882,10 @@ pub fn num_all_consistency_quotients() -> usize {
         + ExtCascadeTable::num_consistency_quotients()
         + ExtLookupTable::num_consistency_quotients()
         + ExtU32Table::num_consistency_quotients()
+        + ExtZmipsProcessorTable::num_consistency_quotients()
+        + ExtRegisterTable::num_consistency_quotients()
+        + ExtTapeTable::num_consistency_quotients()
+        + ExtMemoryTable::num_consistency_quotients()
 }
 
 pub fn num_all_transition_quotients() -> usize {
@@ -761,6 +898,10 @@ pub fn num_all_transition_quotients() -> usize {
         + ExtCascadeTable::num_transition_quotients()
         + ExtLookupTable::num_transition_quotients()
         + ExtU32Table::num_transition_quotients()
+        + ExtZmipsProcessorTable::num_transition_quotients()
+        + ExtRegisterTable::num_transition_quotients()
+        + ExtTapeTable::num_transition_quotients()
+        + ExtMemoryTable::num_transition_quotients()
 }
 
 pub fn num_all_terminal_quotients() -> usize {
@@ -773,6 +914,10 @@ pub fn num_all_terminal_quotients() -> usize {
         + ExtCascadeTable::num_terminal_quotients()
         + ExtLookupTable::num_terminal_quotients()
         + ExtU32Table::num_terminal_quotients()
+        + ExtZmipsProcessorTable::num_terminal_quotients()
+        + ExtRegisterTable::num_terminal_quotients()
+        + ExtTapeTable::num_terminal_quotients()
+        + ExtMemoryTable::num_terminal_quotients()
         + GrandCrossTableArg::num_terminal_quotients()
 }
 
@@ -787,6 +932,10 @@ pub fn all_initial_quotient_degree_bounds(interpolant_degree: Degree) -> Vec<Deg
         ExtCascadeTable::initial_quotient_degree_bounds(interpolant_degree),
         ExtLookupTable::initial_quotient_degree_bounds(interpolant_degree),
         ExtU32Table::initial_quotient_degree_bounds(interpolant_degree),
+        ExtZmipsProcessorTable::initial_quotient_degree_bounds(interpolant_degree),
+        ExtRegisterTable::initial_quotient_degree_bounds(interpolant_degree),
+        ExtTapeTable::initial_quotient_degree_bounds(interpolant_degree),
+        ExtMemoryTable::initial_quotient_degree_bounds(interpolant_degree),
     ]
     .concat()
 }
@@ -805,6 +954,13 @@ pub fn all_consistency_quotient_degree_bounds(
         ExtCascadeTable::consistency_quotient_degree_bounds(interpolant_degree, padded_height),
         ExtLookupTable::consistency_quotient_degree_bounds(interpolant_degree, padded_height),
         ExtU32Table::consistency_quotient_degree_bounds(interpolant_degree, padded_height),
+        ExtZmipsProcessorTable::consistency_quotient_degree_bounds(
+            interpolant_degree,
+            padded_height,
+        ),
+        ExtRegisterTable::consistency_quotient_degree_bounds(interpolant_degree, padded_height),
+        ExtTapeTable::consistency_quotient_degree_bounds(interpolant_degree, padded_height),
+        ExtMemoryTable::consistency_quotient_degree_bounds(interpolant_degree, padded_height),
     ]
     .concat()
 }
@@ -823,6 +979,13 @@ pub fn all_transition_quotient_degree_bounds(
         ExtCascadeTable::transition_quotient_degree_bounds(interpolant_degree, padded_height),
         ExtLookupTable::transition_quotient_degree_bounds(interpolant_degree, padded_height),
         ExtU32Table::transition_quotient_degree_bounds(interpolant_degree, padded_height),
+        ExtZmipsProcessorTable::transition_quotient_degree_bounds(
+            interpolant_degree,
+            padded_height,
+        ),
+        ExtRegisterTable::transition_quotient_degree_bounds(interpolant_degree, padded_height),
+        ExtTapeTable::transition_quotient_degree_bounds(interpolant_degree, padded_height),
+        ExtMemoryTable::transition_quotient_degree_bounds(interpolant_degree, padded_height),
     ]
     .concat()
 }
@@ -838,6 +1001,10 @@ pub fn all_terminal_quotient_degree_bounds(interpolant_degree: Degree) -> Vec<De
         ExtCascadeTable::terminal_quotient_degree_bounds(interpolant_degree),
         ExtLookupTable::terminal_quotient_degree_bounds(interpolant_degree),
         ExtU32Table::terminal_quotient_degree_bounds(interpolant_degree),
+        ExtZmipsProcessorTable::terminal_quotient_degree_bounds(interpolant_degree),
+        ExtRegisterTable::terminal_quotient_degree_bounds(interpolant_degree),
+        ExtTapeTable::terminal_quotient_degree_bounds(interpolant_degree),
+        ExtMemoryTable::terminal_quotient_degree_bounds(interpolant_degree),
         GrandCrossTableArg::terminal_quotient_degree_bounds(interpolant_degree),
     ]
     .concat()
@@ -943,6 +1110,15 @@ pub fn fill_all_initial_quotients(
     let lookup_section_end = lookup_section_start + ExtLookupTable::num_initial_quotients();
     let u32_section_start = lookup_section_end;
     let u32_section_end = u32_section_start + ExtU32Table::num_initial_quotients();
+    let zmips_processor_section_start = u32_section_end;
+    let zmips_processor_section_end =
+        zmips_processor_section_start + ExtZmipsProcessorTable::num_initial_quotients();
+    let register_section_start = zmips_processor_section_end;
+    let register_section_end = register_section_start + ExtRegisterTable::num_initial_quotients();
+    let tape_section_start = register_section_end;
+    let tape_section_end = tape_section_start + ExtTapeTable::num_initial_quotients();
+    let memory_section_start = tape_section_end;
+    let memory_section_end = memory_section_start + ExtMemoryTable::num_initial_quotients();
 
     let mut program_quot_table =
         quot_table.slice_mut(s![.., program_section_start..program_section_end]);
@@ -1022,6 +1198,43 @@ pub fn fill_all_initial_quotients(
         zerofier_inverse,
         challenges,
     );
+    let mut zmips_processor_quot_table = quot_table.slice_mut(s![
+        ..,
+        zmips_processor_section_start..zmips_processor_section_end
+    ]);
+    ExtZmipsProcessorTable::fill_initial_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut zmips_processor_quot_table,
+        zerofier_inverse,
+        challenges,
+    );
+    let mut register_quot_table =
+        quot_table.slice_mut(s![.., register_section_start..register_section_end]);
+    ExtRegisterTable::fill_initial_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut register_quot_table,
+        zerofier_inverse,
+        challenges,
+    );
+    let mut tape_quot_table = quot_table.slice_mut(s![.., tape_section_start..tape_section_end]);
+    ExtTapeTable::fill_initial_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut tape_quot_table,
+        zerofier_inverse,
+        challenges,
+    );
+    let mut memory_quot_table =
+        quot_table.slice_mut(s![.., memory_section_start..memory_section_end]);
+    ExtMemoryTable::fill_initial_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut memory_quot_table,
+        zerofier_inverse,
+        challenges,
+    );
 }
 
 pub fn fill_all_consistency_quotients(
@@ -1054,6 +1267,16 @@ pub fn fill_all_consistency_quotients(
     let lookup_section_end = lookup_section_start + ExtLookupTable::num_consistency_quotients();
     let u32_section_start = lookup_section_end;
     let u32_section_end = u32_section_start + ExtU32Table::num_consistency_quotients();
+    let zmips_processor_section_start = u32_section_end;
+    let zmips_processor_section_end =
+        zmips_processor_section_start + ExtZmipsProcessorTable::num_consistency_quotients();
+    let register_section_start = zmips_processor_section_end;
+    let register_section_end =
+        register_section_start + ExtRegisterTable::num_consistency_quotients();
+    let tape_section_start = register_section_end;
+    let tape_section_end = tape_section_start + ExtTapeTable::num_consistency_quotients();
+    let memory_section_start = tape_section_end;
+    let memory_section_end = memory_section_start + ExtMemoryTable::num_consistency_quotients();
 
     let mut program_quot_table =
         quot_table.slice_mut(s![.., program_section_start..program_section_end]);
@@ -1133,6 +1356,43 @@ pub fn fill_all_consistency_quotients(
         zerofier_inverse,
         challenges,
     );
+    let mut zmips_processor_quot_table = quot_table.slice_mut(s![
+        ..,
+        zmips_processor_section_start..zmips_processor_section_end
+    ]);
+    ExtZmipsProcessorTable::fill_consistency_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut zmips_processor_quot_table,
+        zerofier_inverse,
+        challenges,
+    );
+    let mut register_quot_table =
+        quot_table.slice_mut(s![.., register_section_start..register_section_end]);
+    ExtRegisterTable::fill_consistency_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut register_quot_table,
+        zerofier_inverse,
+        challenges,
+    );
+    let mut tape_quot_table = quot_table.slice_mut(s![.., tape_section_start..tape_section_end]);
+    ExtTapeTable::fill_consistency_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut tape_quot_table,
+        zerofier_inverse,
+        challenges,
+    );
+    let mut memory_quot_table =
+        quot_table.slice_mut(s![.., memory_section_start..memory_section_end]);
+    ExtMemoryTable::fill_consistency_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut memory_quot_table,
+        zerofier_inverse,
+        challenges,
+    );
 }
 
 pub fn fill_all_transition_quotients(
@@ -1166,6 +1426,16 @@ pub fn fill_all_transition_quotients(
     let lookup_section_end = lookup_section_start + ExtLookupTable::num_transition_quotients();
     let u32_section_start = lookup_section_end;
     let u32_section_end = u32_section_start + ExtU32Table::num_transition_quotients();
+    let zmips_processor_section_start = u32_section_end;
+    let zmips_processor_section_end =
+        zmips_processor_section_start + ExtZmipsProcessorTable::num_transition_quotients();
+    let register_section_start = zmips_processor_section_end;
+    let register_section_end =
+        register_section_start + ExtRegisterTable::num_transition_quotients();
+    let tape_section_start = register_section_end;
+    let tape_section_end = tape_section_start + ExtTapeTable::num_transition_quotients();
+    let memory_section_start = tape_section_end;
+    let memory_section_end = memory_section_start + ExtMemoryTable::num_transition_quotients();
 
     let mut program_quot_table =
         quot_table.slice_mut(s![.., program_section_start..program_section_end]);
@@ -1263,6 +1533,51 @@ pub fn fill_all_transition_quotients(
         trace_domain,
         quotient_domain,
     );
+    let mut zmips_processor_quot_table = quot_table.slice_mut(s![
+        ..,
+        zmips_processor_section_start..zmips_processor_section_end
+    ]);
+    ExtZmipsProcessorTable::fill_transition_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut zmips_processor_quot_table,
+        zerofier_inverse,
+        challenges,
+        trace_domain,
+        quotient_domain,
+    );
+    let mut register_quot_table =
+        quot_table.slice_mut(s![.., register_section_start..register_section_end]);
+    ExtRegisterTable::fill_transition_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut register_quot_table,
+        zerofier_inverse,
+        challenges,
+        trace_domain,
+        quotient_domain,
+    );
+    let mut tape_quot_table = quot_table.slice_mut(s![.., tape_section_start..tape_section_end]);
+    ExtTapeTable::fill_transition_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut tape_quot_table,
+        zerofier_inverse,
+        challenges,
+        trace_domain,
+        quotient_domain,
+    );
+    let mut memory_quot_table =
+        quot_table.slice_mut(s![.., memory_section_start..memory_section_end]);
+    ExtMemoryTable::fill_transition_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut memory_quot_table,
+        zerofier_inverse,
+        challenges,
+        trace_domain,
+        quotient_domain,
+    );
 }
 
 pub fn fill_all_terminal_quotients(
@@ -1294,7 +1609,16 @@ pub fn fill_all_terminal_quotients(
     let lookup_section_end = lookup_section_start + ExtLookupTable::num_terminal_quotients();
     let u32_section_start = lookup_section_end;
     let u32_section_end = u32_section_start + ExtU32Table::num_terminal_quotients();
-    let cross_table_section_start = u32_section_end;
+    let zmips_processor_section_start = u32_section_end;
+    let zmips_processor_section_end =
+        zmips_processor_section_start + ExtZmipsProcessorTable::num_terminal_quotients();
+    let register_section_start = zmips_processor_section_end;
+    let register_section_end = register_section_start + ExtRegisterTable::num_terminal_quotients();
+    let tape_section_start = register_section_end;
+    let tape_section_end = tape_section_start + ExtTapeTable::num_terminal_quotients();
+    let memory_section_start = tape_section_end;
+    let memory_section_end = memory_section_start + ExtMemoryTable::num_terminal_quotients();
+    let cross_table_section_start = memory_section_end;
     let cross_table_section_end =
         cross_table_section_start + GrandCrossTableArg::num_terminal_quotients();
 
@@ -1376,6 +1700,43 @@ pub fn fill_all_terminal_quotients(
         zerofier_inverse,
         challenges,
     );
+    let mut zmips_processor_quot_table = quot_table.slice_mut(s![
+        ..,
+        zmips_processor_section_start..zmips_processor_section_end
+    ]);
+    ExtZmipsProcessorTable::fill_terminal_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut zmips_processor_quot_table,
+        zerofier_inverse,
+        challenges,
+    );
+    let mut register_quot_table =
+        quot_table.slice_mut(s![.., register_section_start..register_section_end]);
+    ExtRegisterTable::fill_terminal_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut register_quot_table,
+        zerofier_inverse,
+        challenges,
+    );
+    let mut tape_quot_table = quot_table.slice_mut(s![.., tape_section_start..tape_section_end]);
+    ExtTapeTable::fill_terminal_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut tape_quot_table,
+        zerofier_inverse,
+        challenges,
+    );
+    let mut memory_quot_table =
+        quot_table.slice_mut(s![.., memory_section_start..memory_section_end]);
+    ExtMemoryTable::fill_terminal_quotients(
+        master_base_table,
+        master_ext_table,
+        &mut memory_quot_table,
+        zerofier_inverse,
+        challenges,
+    );
     let mut cross_table_argument_quot_table =
         quot_table.slice_mut(s![.., cross_table_section_start..cross_table_section_end]);
     GrandCrossTableArg::fill_terminal_quotients(
@@ -1513,6 +1874,10 @@ pub fn evaluate_all_initial_constraints(
         ExtCascadeTable::evaluate_initial_constraints(base_row, ext_row, challenges),
         ExtLookupTable::evaluate_initial_constraints(base_row, ext_row, challenges),
         ExtU32Table::evaluate_initial_constraints(base_row, ext_row, challenges),
+        ExtZmipsProcessorTable::evaluate_initial_constraints(base_row, ext_row, challenges),
+        ExtRegisterTable::evaluate_initial_constraints(base_row, ext_row, challenges),
+        ExtTapeTable::evaluate_initial_constraints(base_row, ext_row, challenges),
+        ExtMemoryTable::evaluate_initial_constraints(base_row, ext_row, challenges),
     ]
     .concat()
 }
@@ -1532,6 +1897,10 @@ pub fn evaluate_all_consistency_constraints(
         ExtCascadeTable::evaluate_consistency_constraints(base_row, ext_row, challenges),
         ExtLookupTable::evaluate_consistency_constraints(base_row, ext_row, challenges),
         ExtU32Table::evaluate_consistency_constraints(base_row, ext_row, challenges),
+        ExtZmipsProcessorTable::evaluate_consistency_constraints(base_row, ext_row, challenges),
+        ExtRegisterTable::evaluate_consistency_constraints(base_row, ext_row, challenges),
+        ExtTapeTable::evaluate_consistency_constraints(base_row, ext_row, challenges),
+        ExtMemoryTable::evaluate_consistency_constraints(base_row, ext_row, challenges),
     ]
     .concat()
 }
@@ -1557,6 +1926,10 @@ pub fn evaluate_all_transition_constraints(
         ExtCascadeTable::evaluate_transition_constraints(cbr, cer, nbr, ner, challenges),
         ExtLookupTable::evaluate_transition_constraints(cbr, cer, nbr, ner, challenges),
         ExtU32Table::evaluate_transition_constraints(cbr, cer, nbr, ner, challenges),
+        ExtZmipsProcessorTable::evaluate_transition_constraints(cbr, cer, nbr, ner, challenges),
+        ExtRegisterTable::evaluate_transition_constraints(cbr, cer, nbr, ner, challenges),
+        ExtTapeTable::evaluate_transition_constraints(cbr, cer, nbr, ner, challenges),
+        ExtMemoryTable::evaluate_transition_constraints(cbr, cer, nbr, ner, challenges),
     ]
     .concat()
 }
@@ -1576,6 +1949,10 @@ pub fn evaluate_all_terminal_constraints(
         ExtCascadeTable::evaluate_terminal_constraints(base_row, ext_row, challenges),
         ExtLookupTable::evaluate_terminal_constraints(base_row, ext_row, challenges),
         ExtU32Table::evaluate_terminal_constraints(base_row, ext_row, challenges),
+        ExtZmipsProcessorTable::evaluate_terminal_constraints(base_row, ext_row, challenges),
+        ExtRegisterTable::evaluate_terminal_constraints(base_row, ext_row, challenges),
+        ExtTapeTable::evaluate_terminal_constraints(base_row, ext_row, challenges),
+        ExtMemoryTable::evaluate_terminal_constraints(base_row, ext_row, challenges),
         GrandCrossTableArg::evaluate_terminal_constraints(base_row, ext_row, challenges),
     ]
     .concat()
@@ -1684,7 +2061,16 @@ pub fn initial_constraint_table_idx_and_name(constraint_idx: usize) -> (usize, &
     let lookup_end = lookup_start + ExtLookupTable::num_initial_quotients();
     let u32_start = lookup_end;
     let u32_end = u32_start + ExtU32Table::num_initial_quotients();
-    assert_eq!(num_all_initial_quotients(), u32_end);
+    let zmips_processor_start = u32_end;
+    let zmips_processor_end =
+        zmips_processor_start + ExtZmipsProcessorTable::num_initial_quotients();
+    let register_start = zmips_processor_end;
+    let register_end = register_start + ExtRegisterTable::num_initial_quotients();
+    let tape_start = register_end;
+    let tape_end = tape_start + ExtTapeTable::num_initial_quotients();
+    let memory_start = tape_end;
+    let memory_end = memory_start + ExtMemoryTable::num_initial_quotients();
+    assert_eq!(num_all_initial_quotients(), memory_end);
     match constraint_idx {
         i if program_start <= i && i < program_end => (i - program_start, "Program"),
         i if processor_start <= i && i < processor_end => (i - processor_start, "Processor"),
@@ -1695,6 +2081,12 @@ pub fn initial_constraint_table_idx_and_name(constraint_idx: usize) -> (usize, &
         i if cascade_start <= i && i < cascade_end => (i - cascade_start, "Cascade"),
         i if lookup_start <= i && i < lookup_end => (i - lookup_start, "Lookup"),
         i if u32_start <= i && i < u32_end => (i - u32_start, "U32"),
+        i if zmips_processor_start <= i && i < zmips_processor_end => {
+            (i - zmips_processor_start, "ZmipsProcessor")
+        }
+        i if register_start <= i && i < register_end => (i - register_start, "Register"),
+        i if tape_start <= i && i < tape_end => (i - tape_start, "Tape"),
+        i if memory_start <= i && i < memory_end => (i - memory_start, "Memory"),
         _ => (0, "Unknown"),
     }
 }
@@ -1722,7 +2114,16 @@ pub fn consistency_constraint_table_idx_and_name(constraint_idx: usize) -> (usiz
     let lookup_end = lookup_start + ExtLookupTable::num_consistency_quotients();
     let u32_start = lookup_end;
     let u32_end = u32_start + ExtU32Table::num_consistency_quotients();
-    assert_eq!(num_all_consistency_quotients(), u32_end);
+    let zmips_processor_start = u32_end;
+    let zmips_processor_end =
+        zmips_processor_start + ExtZmipsProcessorTable::num_consistency_quotients();
+    let register_start = zmips_processor_end;
+    let register_end = register_start + ExtRegisterTable::num_consistency_quotients();
+    let tape_start = register_end;
+    let tape_end = tape_start + ExtTapeTable::num_consistency_quotients();
+    let memory_start = tape_end;
+    let memory_end = memory_start + ExtMemoryTable::num_consistency_quotients();
+    assert_eq!(num_all_consistency_quotients(), memory_end);
     match constraint_idx {
         i if program_start <= i && i < program_end => (i - program_start, "Program"),
         i if processor_start <= i && i < processor_end => (i - processor_start, "Processor"),
@@ -1733,6 +2134,12 @@ pub fn consistency_constraint_table_idx_and_name(constraint_idx: usize) -> (usiz
         i if cascade_start <= i && i < cascade_end => (i - cascade_start, "Cascade"),
         i if lookup_start <= i && i < lookup_end => (i - lookup_start, "Lookup"),
         i if u32_start <= i && i < u32_end => (i - u32_start, "U32"),
+        i if zmips_processor_start <= i && i < zmips_processor_end => {
+            (i - zmips_processor_start, "ZmipsProcessor")
+        }
+        i if register_start <= i && i < register_end => (i - register_start, "Register"),
+        i if tape_start <= i && i < tape_end => (i - tape_start, "Tape"),
+        i if memory_start <= i && i < memory_end => (i - memory_start, "Memory"),
         _ => (0, "Unknown"),
     }
 }
@@ -1760,7 +2167,16 @@ pub fn transition_constraint_table_idx_and_name(constraint_idx: usize) -> (usize
     let lookup_end = lookup_start + ExtLookupTable::num_transition_quotients();
     let u32_start = lookup_end;
     let u32_end = u32_start + ExtU32Table::num_transition_quotients();
-    assert_eq!(num_all_transition_quotients(), u32_end);
+    let zmips_processor_start = u32_end;
+    let zmips_processor_end =
+        zmips_processor_start + ExtZmipsProcessorTable::num_transition_quotients();
+    let register_start = zmips_processor_end;
+    let register_end = register_start + ExtRegisterTable::num_transition_quotients();
+    let tape_start = register_end;
+    let tape_end = tape_start + ExtTapeTable::num_transition_quotients();
+    let memory_start = tape_end;
+    let memory_end = memory_start + ExtMemoryTable::num_transition_quotients();
+    assert_eq!(num_all_transition_quotients(), memory_end);
     match constraint_idx {
         i if program_start <= i && i < program_end => (i - program_start, "Program"),
         i if processor_start <= i && i < processor_end => (i - processor_start, "Processor"),
@@ -1771,6 +2187,12 @@ pub fn transition_constraint_table_idx_and_name(constraint_idx: usize) -> (usize
         i if cascade_start <= i && i < cascade_end => (i - cascade_start, "Cascade"),
         i if lookup_start <= i && i < lookup_end => (i - lookup_start, "Lookup"),
         i if u32_start <= i && i < u32_end => (i - u32_start, "U32"),
+        i if zmips_processor_start <= i && i < zmips_processor_end => {
+            (i - zmips_processor_start, "ZmipsProcessor")
+        }
+        i if register_start <= i && i < register_end => (i - register_start, "Register"),
+        i if tape_start <= i && i < tape_end => (i - tape_start, "Tape"),
+        i if memory_start <= i && i < memory_end => (i - memory_start, "Memory"),
         _ => (0, "Unknown"),
     }
 }
@@ -1798,7 +2220,16 @@ pub fn terminal_constraint_table_idx_and_name(constraint_idx: usize) -> (usize,
     let lookup_end = lookup_start + ExtLookupTable::num_terminal_quotients();
     let u32_start = lookup_end;
     let u32_end = u32_start + ExtU32Table::num_terminal_quotients();
-    let cross_table_start = u32_end;
+    let zmips_processor_start = u32_end;
+    let zmips_processor_end =
+        zmips_processor_start + ExtZmipsProcessorTable::num_terminal_quotients();
+    let register_start = zmips_processor_end;
+    let register_end = register_start + ExtRegisterTable::num_terminal_quotients();
+    let tape_start = register_end;
+    let tape_end = tape_start + ExtTapeTable::num_terminal_quotients();
+    let memory_start = tape_end;
+    let memory_end = memory_start + ExtMemoryTable::num_terminal_quotients();
+    let cross_table_start = memory_end;
     let cross_table_end = cross_table_start + GrandCrossTableArg::num_terminal_quotients();
     assert_eq!(num_all_terminal_quotients(), cross_table_end);
     match constraint_idx {
@@ -1811,6 +2242,12 @@ pub fn terminal_constraint_table_idx_and_name(constraint_idx: usize) -> (usize,
         i if cascade_start <= i && i < cascade_end => (i - cascade_start, "Cascade"),
         i if lookup_start <= i && i < lookup_end => (i - lookup_start, "Lookup"),
         i if u32_start <= i && i < u32_end => (i - u32_start, "U32"),
+        i if zmips_processor_start <= i && i < zmips_processor_end => {
+            (i - zmips_processor_start, "ZmipsProcessor")
+        }
+        i if register_start <= i && i < register_end => (i - register_start, "Register"),
+        i if tape_start <= i && i < tape_end => (i - tape_start, "Tape"),
+        i if memory_start <= i && i < memory_end => (i - memory_start, "Memory"),
         i if cross_table_start <= i && i < cross_table_end => {
             (i - cross_table_start, "GrandCrossTableArgument")
         }
@@ -1823,6 +2260,8 @@ mod master_table_tests {
     use ndarray::s;
     use num_traits::Zero;
     use strum::IntoEnumIterator;
+    use triton_opcodes::program::Program;
+    use triton_program::PrecomputedNonDeterminism;
     use twenty_first::shared_math::b_field_element::BFieldElement;
     use twenty_first::shared_math::traits::FiniteField;
 
@@ -1838,14 +2277,16 @@ mod master_table_tests {
     use crate::table::master_table::terminal_quotient_zerofier_inverse;
     use crate::table::master_table::transition_quotient_zerofier_inverse;
     use crate::table::master_table::TableId::*;
-    use crate::table::master_table::EXT_U32_TABLE_END;
+    use crate::table::master_table::EXT_MEMORY_TABLE_END;
     use crate::table::master_table::NUM_BASE_COLUMNS;
     use crate::table::master_table::NUM_COLUMNS;
     use crate::table::master_table::NUM_EXT_COLUMNS;
+    use crate::table::memory_table;
     use crate::table::op_stack_table;
     use crate::table::processor_table;
     use crate::table::program_table;
     use crate::table::ram_table;
+    use crate::table::register_table;
     use crate::table::table_column::CascadeBaseTableColumn;
     use crate::table::table_column::CascadeExtTableColumn;
     use crate::table::table_column::HashBaseTableColumn;
@@ -1856,6 +2297,8 @@ mod master_table_tests {
     use crate::table::table_column::LookupExtTableColumn;
     use crate::table::table_column::MasterBaseTableColumn;
     use crate::table::table_column::MasterExtTableColumn;
+    use crate::table::table_column::MemoryBaseTableColumn;
+    use crate::table::table_column::MemoryExtTableColumn;
     use crate::table::table_column::OpStackBaseTableColumn;
     use crate::table::table_column::OpStackExtTableColumn;
     use crate::table::table_column::ProcessorBaseTableColumn;
@@ -1864,13 +2307,25 @@ mod master_table_tests {
     use crate::table::table_column::ProgramExtTableColumn;
     use crate::table::table_column::RamBaseTableColumn;
     use crate::table::table_column::RamExtTableColumn;
+    use crate::table::table_column::RegisterBaseTableColumn;
+    use crate::table::table_column::RegisterExtTableColumn;
+    use crate::table::table_column::TapeBaseTableColumn;
+    use crate::table::table_column::TapeExtTableColumn;
     use crate::table::table_column::U32BaseTableColumn;
     use crate::table::table_column::U32ExtTableColumn;
+    use crate::table::table_column::ZmipsProcessorBaseTableColumn;
+    use crate::table::table_column::ZmipsProcessorExtTableColumn;
+    use crate::table::tape_table;
     use crate::table::u32_table;
+    use crate::table::zmips_processor_table;
 
     #[test]
     fn base_table_width_is_correct() {
-        let (_, _, _, master_base_table) = parse_simulate_pad("halt", vec![], vec![]);
+        let (_, _, _, master_base_table) = parse_simulate_pad::<Program>(
+            "halt",
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+        );
 
         assert_eq!(
             program_table::BASE_WIDTH,
@@ -1908,12 +2363,31 @@ mod master_table_tests {
             u32_table::BASE_WIDTH,
             master_base_table.table(U32Table).ncols()
         );
+        assert_eq!(
+            zmips_processor_table::BASE_WIDTH,
+            master_base_table.table(ZmipsProcessorTable).ncols()
+        );
+        assert_eq!(
+            register_table::BASE_WIDTH,
+            master_base_table.table(RegisterTable).ncols()
+        );
+        assert_eq!(
+            tape_table::BASE_WIDTH,
+            master_base_table.table(TapeTable).ncols()
+        );
+        assert_eq!(
+            memory_table::BASE_WIDTH,
+            master_base_table.table(MemoryTable).ncols()
+        );
     }
 
     #[test]
     fn ext_table_width_is_correct() {
-        let (parameters, _, _, _, master_ext_table, _) =
-            parse_simulate_pad_extend("halt", vec![], vec![]);
+        let (parameters, _, _, _, master_ext_table, _) = parse_simulate_pad_extend::<Program>(
+            "halt",
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+        );
 
         assert_eq!(
             program_table::EXT_WIDTH,
@@ -1951,12 +2425,28 @@ mod master_table_tests {
             u32_table::EXT_WIDTH,
             master_ext_table.table(U32Table).ncols()
         );
+        assert_eq!(
+            zmips_processor_table::EXT_WIDTH,
+            master_ext_table.table(ZmipsProcessorTable).ncols()
+        );
+        assert_eq!(
+            register_table::EXT_WIDTH,
+            master_ext_table.table(RegisterTable).ncols()
+        );
+        assert_eq!(
+            tape_table::EXT_WIDTH,
+            master_ext_table.table(TapeTable).ncols()
+        );
+        assert_eq!(
+            memory_table::EXT_WIDTH,
+            master_ext_table.table(MemoryTable).ncols()
+        );
         // use some domain-specific knowledge to also check for the randomizer columns
         assert_eq!(
             parameters.num_randomizer_polynomials,
             master_ext_table
                 .master_ext_matrix
-                .slice(s![.., EXT_U32_TABLE_END..])
+                .slice(s![.., EXT_MEMORY_TABLE_END..])
                 .ncols()
         );
     }
@@ -2083,6 +2573,34 @@ mod master_table_tests {
             u32_table::EXT_WIDTH,
             u32_table::FULL_WIDTH
         );
+        println!(
+            "| {:<18} | {:>10} | {:>9} | {:>10} |",
+            "ZmipsProcessorTable",
+            zmips_processor_table::BASE_WIDTH,
+            zmips_processor_table::EXT_WIDTH,
+            zmips_processor_table::FULL_WIDTH
+        );
+        println!(
+            "| {:<18} | {:>10} | {:>9} | {:>10} |",
+            "RegisterTable",
+            register_table::BASE_WIDTH,
+            register_table::EXT_WIDTH,
+            register_table::FULL_WIDTH
+        );
+        println!(
+            "| {:<18} | {:>10} | {:>9} | {:>10} |",
+            "TapeTable",
+            tape_table::BASE_WIDTH,
+            tape_table::EXT_WIDTH,
+            tape_table::FULL_WIDTH
+        );
+        println!(
+            "| {:<18} | {:>10} | {:>9} | {:>10} |",
+            "MemoryTable",
+            memory_table::BASE_WIDTH,
+            memory_table::EXT_WIDTH,
+            memory_table::FULL_WIDTH
+        );
         println!("|                    |            |           |            |");
         println!(
             "| Sum                | {NUM_BASE_COLUMNS:>10} \
@@ -2150,6 +2668,30 @@ mod master_table_tests {
                 column.master_base_table_index()
             );
         }
+        for column in ZmipsProcessorBaseTableColumn::iter() {
+            println!(
+                "{:>3} | zmips proc. | {column}",
+                column.master_base_table_index()
+            );
+        }
+        for column in RegisterBaseTableColumn::iter() {
+            println!(
+                "{:>3} | register    | {column}",
+                column.master_base_table_index()
+            );
+        }
+        for column in TapeBaseTableColumn::iter() {
+            println!(
+                "{:>3} | tape        | {column}",
+                column.master_base_table_index()
+            );
+        }
+        for column in MemoryBaseTableColumn::iter() {
+            println!(
+                "{:>3} | memory      | {column}",
+                column.master_base_table_index()
+            );
+        }
         println!();
         println!("idx | table       | extension column");
         println!("---:|:------------|:----------------");
@@ -2207,5 +2749,29 @@ mod master_table_tests {
                 column.master_ext_table_index()
             );
         }
+        for column in ZmipsProcessorExtTableColumn::iter() {
+            println!(
+                "{:>3} | zmips proc. | {column}",
+                column.master_ext_table_index()
+            );
+        }
+        for column in RegisterExtTableColumn::iter() {
+            println!(
+                "{:>3} | register    | {column}",
+                column.master_ext_table_index()
+            );
+        }
+        for column in TapeExtTableColumn::iter() {
+            println!(
+                "{:>3} | tape        | {column}",
+                column.master_ext_table_index()
+            );
+        }
+        for column in MemoryExtTableColumn::iter() {
+            println!(
+                "{:>3} | memory      | {column}",
+                column.master_ext_table_index()
+            );
+        }
     }
 }
@@ -0,0 +1,569 @@
+use std::collections::BTreeMap;
+
+use ndarray::parallel::prelude::*;
+use ndarray::s;
+use ndarray::ArrayView1;
+use ndarray::ArrayView2;
+use ndarray::ArrayViewMut2;
+use ndarray::Axis;
+use num_traits::One;
+use num_traits::Zero;
+use strum::EnumCount;
+use triton_zmips::data_segment::DATA_SEGMENT_START;
+use triton_zmips::data_segment::WORD_SIZE;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::polynomial::Polynomial;
+use twenty_first::shared_math::traits::Inverse;
+use twenty_first::shared_math::x_field_element::XFieldElement;
+
+use crate::table::challenges::ChallengeId::*;
+use crate::table::challenges::Challenges;
+use crate::table::constraint_circuit::ConstraintCircuit;
+use crate::table::constraint_circuit::ConstraintCircuitBuilder;
+use crate::table::constraint_circuit::ConstraintCircuitMonad;
+use crate::table::constraint_circuit::DualRowIndicator;
+use crate::table::constraint_circuit::DualRowIndicator::*;
+use crate::table::constraint_circuit::InputIndicator;
+use crate::table::constraint_circuit::SingleRowIndicator;
+use crate::table::constraint_circuit::SingleRowIndicator::*;
+use crate::table::cross_table_argument::CrossTableArg;
+use crate::table::cross_table_argument::LookupArg;
+use crate::table::cross_table_argument::PermArg;
+use crate::table::table_column::MasterBaseTableColumn;
+use crate::table::table_column::MasterExtTableColumn;
+use crate::table::table_column::MemoryBaseTableColumn;
+use crate::table::table_column::MemoryBaseTableColumn::*;
+use crate::table::table_column::MemoryExtTableColumn;
+use crate::table::table_column::MemoryExtTableColumn::*;
+use crate::table::zmips_processor_table::ZmipsProcessorTable;
+use crate::vm::AlgebraicExecutionTrace;
+
+pub const BASE_WIDTH: usize = MemoryBaseTableColumn::COUNT;
+pub const EXT_WIDTH: usize = MemoryExtTableColumn::COUNT;
+pub const FULL_WIDTH: usize = BASE_WIDTH + EXT_WIDTH;
+
+/// The memory address of word `index` of a program's data segment.
+pub fn data_segment_address(index: usize) -> BFieldElement {
+    BFieldElement::new(DATA_SEGMENT_START as u64 + WORD_SIZE as u64 * index as u64)
+}
+
+/// The sections of the Memory Table, one per memory address, as a map from address to the
+/// address's word in the data segment, if any, and the accesses to the address as
+/// `[clk, value, is write]`, sorted by clock cycle.
+type Sections = BTreeMap<u64, (Option<BFieldElement>, Vec<[BFieldElement; 3]>)>;
+
+/// The Memory Table records every access of the zMIPS Processor Table to memory, _i.e._, every
+/// `lw` and `sw`. It consists of one section per memory address, holding an initialization row
+/// followed by all accesses to that address, sorted by clock cycle. Reading an address does not
+/// change its value.
+///
+/// The Permutation Argument with the zMIPS Processor Table establishes that the Memory Table
+/// contains exactly the processor's memory accesses. The clock jump difference Lookup Argument
+/// with the zMIPS Processor Table establishes that the accesses of every section are sorted by
+/// clock cycle. The address changes if and only if a new section starts, and a Bézout relation,
+/// like the one of the Ram Table, establishes that the addresses of all sections are distinct.
+/// Hence, every `lw` reads the value most recently written to its address, or the address's
+/// initial value.
+///
+/// The initial values of all words of the program's data segment are established by a
+/// Permutation Argument with the Program Table, which holds the data segment. Since every address
+/// has exactly one section, the initial value of a data segment address cannot differ from the
+/// program's. All other addresses are initialized non-deterministically.
+#[derive(Debug, Clone)]
+pub struct MemoryTable {}
+
+#[derive(Debug, Clone)]
+pub struct ExtMemoryTable {}
+
+impl MemoryTable {
+    /// Groups the words of the data segment and the zMIPS processor's memory accesses by address.
+    fn sections(aet: &AlgebraicExecutionTrace) -> Sections {
+        let mut sections = Sections::new();
+        for (index, word) in aet.program.data_bwords().into_iter().enumerate() {
+            let address = data_segment_address(index).value();
+            sections.entry(address).or_default().0 = Some(word);
+        }
+
+        // Preserves the order of the processor's rows, which are sorted by CLK.
+        for processor_row in aet.zmips_processor_trace.rows() {
+            if let Some([clk, address, value, is_write]) =
+                ZmipsProcessorTable::memory_access(processor_row)
+            {
+                let access = [clk, value, is_write];
+                sections.entry(address.value()).or_default().1.push(access);
+            }
+        }
+        sections
+    }
+
+    /// The number of rows of the Memory Table before padding: one initialization row per
+    /// address, and one row per memory access.
+    pub fn table_length(aet: &AlgebraicExecutionTrace) -> usize {
+        Self::sections(aet)
+            .values()
+            .map(|(_, accesses)| 1 + accesses.len())
+            .sum()
+    }
+
+    /// Fills the trace table in-place and returns all clock jump differences.
+    pub fn fill_trace(
+        memory_table: &mut ArrayViewMut2<BFieldElement>,
+        aet: &AlgebraicExecutionTrace,
+    ) -> Vec<BFieldElement> {
+        let sections = Self::sections(aet);
+
+        // Without any section, the Bézout relation covers the padding rows' address 0.
+        if sections.is_empty() {
+            memory_table[[
+                0,
+                BezoutCoefficientPolynomialCoefficient1.base_table_index(),
+            ]] = BFieldElement::one();
+            return vec![];
+        }
+
+        // Compute Bézout coefficient polynomials.
+        let num_addresses = sections.len();
+        let polynomial_with_addresses_as_roots = sections.keys().fold(
+            Polynomial::from_constant(BFieldElement::one()),
+            |acc, &address| {
+                acc * Polynomial::new(vec![-BFieldElement::new(address), BFieldElement::one()])
+            },
+        );
+        let formal_derivative = polynomial_with_addresses_as_roots.formal_derivative();
+        let (gcd, bezout_0, bezout_1) =
+            Polynomial::xgcd(polynomial_with_addresses_as_roots, formal_derivative);
+        assert!(gcd.is_one(), "Each address must occur at most once.");
+        assert!(
+            bezout_0.degree() < num_addresses as isize,
+            "The Bézout coefficient 0 must be of degree at most {}.",
+            num_addresses - 1
+        );
+        assert!(
+            bezout_1.degree() < num_addresses as isize,
+            "The Bézout coefficient 1 must be of degree at most {}.",
+            num_addresses - 1
+        );
+        let mut bezout_coefficient_polynomial_coefficients_0 = bezout_0.coefficients;
+        let mut bezout_coefficient_polynomial_coefficients_1 = bezout_1.coefficients;
+        bezout_coefficient_polynomial_coefficients_0.resize(num_addresses, BFieldElement::zero());
+        bezout_coefficient_polynomial_coefficients_1.resize(num_addresses, BFieldElement::zero());
+
+        // The clock jump difference of an access is relative to the previous row of its section.
+        // The initialization row has clock cycle 0.
+        let mut clock_jump_differences = vec![];
+        let mut row_idx = 0;
+        for (address, (data_word, accesses)) in sections {
+            // Only a read observes the initial value of an address outside the data segment.
+            let initial_value = match (data_word, accesses.first()) {
+                (Some(data_word), _) => data_word,
+                (None, Some(&[_, value, is_write])) if is_write.is_zero() => value,
+                _ => BFieldElement::zero(),
+            };
+            let address = BFieldElement::new(address);
+            let bcpc_0 = bezout_coefficient_polynomial_coefficients_0.pop().unwrap();
+            let bcpc_1 = bezout_coefficient_polynomial_coefficients_1.pop().unwrap();
+
+            let mut initialization_row = memory_table.row_mut(row_idx);
+            initialization_row[IsInitialization.base_table_index()] = BFieldElement::one();
+            initialization_row[IsData.base_table_index()] =
+                BFieldElement::new(data_word.is_some() as u64);
+            initialization_row[Address.base_table_index()] = address;
+            initialization_row[Value.base_table_index()] = initial_value;
+            initialization_row[BezoutCoefficientPolynomialCoefficient0.base_table_index()] = bcpc_0;
+            initialization_row[BezoutCoefficientPolynomialCoefficient1.base_table_index()] = bcpc_1;
+            row_idx += 1;
+
+            let mut previous_clk = BFieldElement::zero();
+            for [clk, value, is_write] in accesses {
+                let mut memory_table_row = memory_table.row_mut(row_idx);
+                memory_table_row[CLK.base_table_index()] = clk;
+                memory_table_row[Address.base_table_index()] = address;
+                memory_table_row[Value.base_table_index()] = value;
+                memory_table_row[IsWrite.base_table_index()] = is_write;
+                memory_table_row[BezoutCoefficientPolynomialCoefficient0.base_table_index()] =
+                    bcpc_0;
+                memory_table_row[BezoutCoefficientPolynomialCoefficient1.base_table_index()] =
+                    bcpc_1;
+                clock_jump_differences.push(clk - previous_clk);
+                previous_clk = clk;
+                row_idx += 1;
+            }
+        }
+        assert_eq!(0, bezout_coefficient_polynomial_coefficients_0.len());
+        assert_eq!(0, bezout_coefficient_polynomial_coefficients_1.len());
+
+        // The inverse of the address difference is 0 in the last row, since the padding rows
+        // keep the last address.
+        for curr_row_idx in 0..row_idx - 1 {
+            let address = memory_table[[curr_row_idx, Address.base_table_index()]];
+            let next_address = memory_table[[curr_row_idx + 1, Address.base_table_index()]];
+            memory_table[[curr_row_idx, InverseOfAddressDifference.base_table_index()]] =
+                (next_address - address).inverse_or_zero();
+        }
+
+        clock_jump_differences
+    }
+
+    pub fn pad_trace(memory_table: &mut ArrayViewMut2<BFieldElement>, memory_table_len: usize) {
+        let last_row_idx = memory_table_len.saturating_sub(1);
+        let mut padding_template = memory_table.row(last_row_idx).to_owned();
+        padding_template[IsPadding.base_table_index()] = BFieldElement::one();
+        padding_template[IsInitialization.base_table_index()] = BFieldElement::zero();
+        padding_template[IsData.base_table_index()] = BFieldElement::zero();
+        padding_template[IsWrite.base_table_index()] = BFieldElement::zero();
+        memory_table
+            .slice_mut(s![memory_table_len.., ..])
+            .axis_iter_mut(Axis(0))
+            .into_par_iter()
+            .for_each(|mut row| row.assign(&padding_template));
+    }
+
+    pub fn extend(
+        base_table: ArrayView2<BFieldElement>,
+        mut ext_table: ArrayViewMut2<XFieldElement>,
+        challenges: &Challenges,
+    ) {
+        assert_eq!(BASE_WIDTH, base_table.ncols());
+        assert_eq!(EXT_WIDTH, ext_table.ncols());
+        assert_eq!(base_table.nrows(), ext_table.nrows());
+
+        let clk_weight = challenges.get_challenge(MemoryClkWeight);
+        let address_weight = challenges.get_challenge(MemoryAddressWeight);
+        let value_weight = challenges.get_challenge(MemoryValueWeight);
+        let is_write_weight = challenges.get_challenge(MemoryIsWriteWeight);
+        let processor_perm_indeterminate = challenges.get_challenge(MemoryIndeterminate);
+        let data_segment_address_weight = challenges.get_challenge(DataSegmentAddressWeight);
+        let data_segment_value_weight = challenges.get_challenge(DataSegmentValueWeight);
+        let data_segment_perm_indeterminate = challenges.get_challenge(DataSegmentIndeterminate);
+        let bezout_relation_indeterminate =
+            challenges.get_challenge(MemoryTableBezoutRelationIndeterminate);
+        let clock_jump_difference_lookup_indeterminate =
+            challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate);
+
+        let mut running_product_for_perm_arg = PermArg::default_initial();
+        let mut running_product_for_data_segment_perm_arg = PermArg::default_initial();
+        let mut clock_jump_diff_lookup_log_derivative = LookupArg::default_initial();
+
+        // initialize columns establishing Bézout relation
+        let mut running_product_of_address =
+            bezout_relation_indeterminate - base_table.row(0)[Address.base_table_index()];
+        let mut formal_derivative = XFieldElement::one();
+        let mut bezout_coefficient_0 =
+            base_table.row(0)[BezoutCoefficientPolynomialCoefficient0.base_table_index()].lift();
+        let mut bezout_coefficient_1 =
+            base_table.row(0)[BezoutCoefficientPolynomialCoefficient1.base_table_index()].lift();
+
+        let mut previous_row: Option<ArrayView1<BFieldElement>> = None;
+        for row_idx in 0..base_table.nrows() {
+            let current_row = base_table.row(row_idx);
+            let clk = current_row[CLK.base_table_index()];
+            let address = current_row[Address.base_table_index()];
+            let value = current_row[Value.base_table_index()];
+            let is_write = current_row[IsWrite.base_table_index()];
+            let is_memory_access = current_row[IsInitialization.base_table_index()].is_zero()
+                && current_row[IsPadding.base_table_index()].is_zero();
+
+            if let Some(prev_row) = previous_row {
+                if prev_row[Address.base_table_index()] != address {
+                    // accumulate coefficient for Bézout relation, proving new address is unique
+                    let bcpc0 =
+                        current_row[BezoutCoefficientPolynomialCoefficient0.base_table_index()];
+                    let bcpc1 =
+                        current_row[BezoutCoefficientPolynomialCoefficient1.base_table_index()];
+
+                    formal_derivative = (bezout_relation_indeterminate - address)
+                        * formal_derivative
+                        + running_product_of_address;
+                    running_product_of_address *= bezout_relation_indeterminate - address;
+                    bezout_coefficient_0 =
+                        bezout_coefficient_0 * bezout_relation_indeterminate + bcpc0;
+                    bezout_coefficient_1 =
+                        bezout_coefficient_1 * bezout_relation_indeterminate + bcpc1;
+                }
+            }
+
+            // permutation argument to Program Table, establishing the data segment
+            if current_row[IsData.base_table_index()].is_one() {
+                let compressed_row_for_data_segment =
+                    address * data_segment_address_weight + value * data_segment_value_weight;
+                running_product_for_data_segment_perm_arg *=
+                    data_segment_perm_indeterminate - compressed_row_for_data_segment;
+            }
+
+            if is_memory_access {
+                // permutation argument to zMIPS Processor Table
+                let compressed_row_for_permutation_argument = clk * clk_weight
+                    + address * address_weight
+                    + value * value_weight
+                    + is_write * is_write_weight;
+                running_product_for_perm_arg *=
+                    processor_perm_indeterminate - compressed_row_for_permutation_argument;
+
+                // prove that clock jump is directed forward
+                if let Some(prev_row) = previous_row {
+                    let clock_jump_difference = clk - prev_row[CLK.base_table_index()];
+                    clock_jump_diff_lookup_log_derivative +=
+                        (clock_jump_difference_lookup_indeterminate - clock_jump_difference)
+                            .inverse();
+                }
+            }
+
+            let mut extension_row = ext_table.row_mut(row_idx);
+            extension_row[RunningProductPermArg.ext_table_index()] = running_product_for_perm_arg;
+            extension_row[DataSegmentPermArg.ext_table_index()] =
+                running_product_for_data_segment_perm_arg;
+            extension_row[ClockJumpDifferenceLookupClientLogDerivative.ext_table_index()] =
+                clock_jump_diff_lookup_log_derivative;
+            extension_row[RunningProductOfAddress.ext_table_index()] = running_product_of_address;
+            extension_row[FormalDerivative.ext_table_index()] = formal_derivative;
+            extension_row[BezoutCoefficient0.ext_table_index()] = bezout_coefficient_0;
+            extension_row[BezoutCoefficient1.ext_table_index()] = bezout_coefficient_1;
+            previous_row = Some(current_row);
+        }
+    }
+}
+
+impl ExtMemoryTable {
+    /// The compressed row for the Permutation Argument with the Program Table. Only meaningful if
+    /// the row initializes an address of the data segment.
+    fn compressed_row_for_data_segment<II: InputIndicator>(
+        circuit_builder: &ConstraintCircuitBuilder<II>,
+        row: impl Fn(MemoryBaseTableColumn) -> ConstraintCircuitMonad<II>,
+    ) -> ConstraintCircuitMonad<II> {
+        circuit_builder.challenge(DataSegmentAddressWeight) * row(Address)
+            + circuit_builder.challenge(DataSegmentValueWeight) * row(Value)
+    }
+
+    pub fn ext_initial_constraints_as_circuits() -> Vec<ConstraintCircuit<SingleRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let one = circuit_builder.b_constant(1_u32.into());
+        let base_row = |col: MemoryBaseTableColumn| {
+            circuit_builder.input(BaseRow(col.master_base_table_index()))
+        };
+        let ext_row =
+            |col: MemoryExtTableColumn| circuit_builder.input(ExtRow(col.master_ext_table_index()));
+
+        let first_row_is_padding_or_initializes_an_address =
+            (one.clone() - base_row(IsPadding)) * (one.clone() - base_row(IsInitialization));
+
+        // The initialization row is not part of either argument with the zMIPS Processor Table.
+        let running_product_permutation_argument_is_initialized_correctly =
+            ext_row(RunningProductPermArg) - circuit_builder.x_constant(PermArg::default_initial());
+        let clock_jump_diff_log_derivative_is_initialized_correctly =
+            ext_row(ClockJumpDifferenceLookupClientLogDerivative)
+                - circuit_builder.x_constant(LookupArg::default_initial());
+
+        let data_segment_running_product_initial =
+            circuit_builder.x_constant(PermArg::default_initial());
+        let data_segment_running_product_has_accumulated_first_row = ext_row(DataSegmentPermArg)
+            - data_segment_running_product_initial.clone()
+                * (circuit_builder.challenge(DataSegmentIndeterminate)
+                    - Self::compressed_row_for_data_segment(&circuit_builder, base_row));
+        let data_segment_running_product_is_default_initial =
+            ext_row(DataSegmentPermArg) - data_segment_running_product_initial;
+        let data_segment_running_product_is_initialized_correctly = base_row(IsData)
+            * data_segment_running_product_has_accumulated_first_row
+            + (one.clone() - base_row(IsData)) * data_segment_running_product_is_default_initial;
+
+        let bezout_coefficient_polynomial_coefficient_0_is_0 =
+            base_row(BezoutCoefficientPolynomialCoefficient0);
+        let bezout_coefficient_0_is_0 = ext_row(BezoutCoefficient0);
+        let bezout_coefficient_1_is_bezout_coefficient_polynomial_coefficient_1 =
+            ext_row(BezoutCoefficient1) - base_row(BezoutCoefficientPolynomialCoefficient1);
+        let formal_derivative_is_1 = ext_row(FormalDerivative) - one;
+        let running_product_polynomial_is_initialized_correctly = ext_row(RunningProductOfAddress)
+            - (circuit_builder.challenge(MemoryTableBezoutRelationIndeterminate)
+                - base_row(Address));
+
+        let mut constraints = [
+            first_row_is_padding_or_initializes_an_address,
+            running_product_permutation_argument_is_initialized_correctly,
+            clock_jump_diff_log_derivative_is_initialized_correctly,
+            data_segment_running_product_is_initialized_correctly,
+            bezout_coefficient_polynomial_coefficient_0_is_0,
+            bezout_coefficient_0_is_0,
+            bezout_coefficient_1_is_bezout_coefficient_polynomial_coefficient_1,
+            formal_derivative_is_1,
+            running_product_polynomial_is_initialized_correctly,
+        ];
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints.map(|circuit| circuit.consume()).to_vec()
+    }
+
+    pub fn ext_consistency_constraints_as_circuits() -> Vec<ConstraintCircuit<SingleRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let one = circuit_builder.b_constant(1_u32.into());
+        let base_row = |col: MemoryBaseTableColumn| {
+            circuit_builder.input(BaseRow(col.master_base_table_index()))
+        };
+        let is_bit = |col| base_row(col) * (base_row(col) - one.clone());
+
+        let is_padding_is_bit = is_bit(IsPadding);
+        let is_initialization_is_bit = is_bit(IsInitialization);
+        let is_data_is_bit = is_bit(IsData);
+        let is_write_is_bit = is_bit(IsWrite);
+        let only_initialization_rows_belong_to_the_data_segment =
+            base_row(IsData) * (one.clone() - base_row(IsInitialization));
+
+        let mut constraints = [
+            is_padding_is_bit,
+            is_initialization_is_bit,
+            is_data_is_bit,
+            is_write_is_bit,
+            only_initialization_rows_belong_to_the_data_segment,
+        ];
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints.map(|circuit| circuit.consume()).to_vec()
+    }
+
+    pub fn ext_transition_constraints_as_circuits() -> Vec<ConstraintCircuit<DualRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let one = circuit_builder.b_constant(1_u32.into());
+        let curr_base_row = |col: MemoryBaseTableColumn| {
+            circuit_builder.input(CurrentBaseRow(col.master_base_table_index()))
+        };
+        let next_base_row = |col: MemoryBaseTableColumn| {
+            circuit_builder.input(NextBaseRow(col.master_base_table_index()))
+        };
+        let curr_ext_row = |col: MemoryExtTableColumn| {
+            circuit_builder.input(CurrentExtRow(col.master_ext_table_index()))
+        };
+        let next_ext_row = |col: MemoryExtTableColumn| {
+            circuit_builder.input(NextExtRow(col.master_ext_table_index()))
+        };
+        let bezout_challenge = circuit_builder.challenge(MemoryTableBezoutRelationIndeterminate);
+
+        let is_padding_is_0_or_remains_1 =
+            curr_base_row(IsPadding) * (next_base_row(IsPadding) - one.clone());
+
+        let iord = curr_base_row(InverseOfAddressDifference);
+        let address_diff = next_base_row(Address) - curr_base_row(Address);
+        let address_changes = address_diff.clone() * iord.clone();
+
+        // iord is 0 or iord is the inverse of (address' - address)
+        let iord_is_0_or_iord_is_inverse_of_address_diff =
+            iord * (address_changes.clone() - one.clone());
+
+        // (address' - address) is zero or iord is the inverse of (address' - address)
+        let address_diff_is_0_or_iord_is_inverse_of_address_diff =
+            address_diff.clone() * (address_changes.clone() - one.clone());
+
+        // Every section consists of exactly one address, and starts with an initialization row.
+        let address_changes_if_and_only_if_next_row_initializes =
+            next_base_row(IsInitialization) - address_changes.clone();
+
+        let value_remains_unless_address_is_written_to_or_initialized = (one.clone()
+            - next_base_row(IsWrite))
+            * (one.clone() - next_base_row(IsInitialization))
+            * (next_base_row(Value) - curr_base_row(Value));
+
+        let address_remains = one.clone() - address_changes;
+        let bcpc0_only_changes_if_address_changes = address_remains.clone()
+            * (next_base_row(BezoutCoefficientPolynomialCoefficient0)
+                - curr_base_row(BezoutCoefficientPolynomialCoefficient0));
+        let bcpc1_only_changes_if_address_changes = address_remains.clone()
+            * (next_base_row(BezoutCoefficientPolynomialCoefficient1)
+                - curr_base_row(BezoutCoefficientPolynomialCoefficient1));
+
+        let running_product_of_address_updates_correctly = address_diff.clone()
+            * (next_ext_row(RunningProductOfAddress)
+                - curr_ext_row(RunningProductOfAddress)
+                    * (bezout_challenge.clone() - next_base_row(Address)))
+            + address_remains.clone()
+                * (next_ext_row(RunningProductOfAddress) - curr_ext_row(RunningProductOfAddress));
+        let formal_derivative_updates_correctly = address_diff.clone()
+            * (next_ext_row(FormalDerivative)
+                - curr_ext_row(RunningProductOfAddress)
+                - (bezout_challenge.clone() - next_base_row(Address))
+                    * curr_ext_row(FormalDerivative))
+            + address_remains.clone()
+                * (next_ext_row(FormalDerivative) - curr_ext_row(FormalDerivative));
+        let bezout_coefficient_0_is_constructed_correctly = address_diff.clone()
+            * (next_ext_row(BezoutCoefficient0)
+                - bezout_challenge.clone() * curr_ext_row(BezoutCoefficient0)
+                - next_base_row(BezoutCoefficientPolynomialCoefficient0))
+            + address_remains.clone()
+                * (next_ext_row(BezoutCoefficient0) - curr_ext_row(BezoutCoefficient0));
+        let bezout_coefficient_1_is_constructed_correctly = address_diff
+            * (next_ext_row(BezoutCoefficient1)
+                - bezout_challenge * curr_ext_row(BezoutCoefficient1)
+                - next_base_row(BezoutCoefficientPolynomialCoefficient1))
+            + address_remains
+                * (next_ext_row(BezoutCoefficient1) - curr_ext_row(BezoutCoefficient1));
+
+        // Both arguments with the zMIPS Processor Table accumulate a summand if and only if the
+        // next row is a memory access, i.e., neither an initialization row nor a padding row.
+        let next_row_is_memory_access = (one.clone() - next_base_row(IsInitialization))
+            * (one.clone() - next_base_row(IsPadding));
+        let next_row_is_no_memory_access = one.clone() - next_row_is_memory_access.clone();
+
+        let compressed_row_for_permutation_argument = circuit_builder.challenge(MemoryClkWeight)
+            * next_base_row(CLK)
+            + circuit_builder.challenge(MemoryAddressWeight) * next_base_row(Address)
+            + circuit_builder.challenge(MemoryValueWeight) * next_base_row(Value)
+            + circuit_builder.challenge(MemoryIsWriteWeight) * next_base_row(IsWrite);
+        let rppa_accumulates = next_ext_row(RunningProductPermArg)
+            - curr_ext_row(RunningProductPermArg)
+                * (circuit_builder.challenge(MemoryIndeterminate)
+                    - compressed_row_for_permutation_argument);
+        let rppa_remains =
+            next_ext_row(RunningProductPermArg) - curr_ext_row(RunningProductPermArg);
+        let rppa_updates_correctly = next_row_is_memory_access.clone() * rppa_accumulates
+            + next_row_is_no_memory_access.clone() * rppa_remains;
+
+        let log_derivative_remains = next_ext_row(ClockJumpDifferenceLookupClientLogDerivative)
+            - curr_ext_row(ClockJumpDifferenceLookupClientLogDerivative);
+        let clk_diff = next_base_row(CLK) - curr_base_row(CLK);
+        let log_derivative_accumulates = log_derivative_remains.clone()
+            * (circuit_builder.challenge(ClockJumpDifferenceLookupIndeterminate) - clk_diff)
+            - one.clone();
+        let log_derivative_updates_correctly = next_row_is_memory_access
+            * log_derivative_accumulates
+            + next_row_is_no_memory_access * log_derivative_remains;
+
+        let data_segment_running_product_accumulates = next_ext_row(DataSegmentPermArg)
+            - curr_ext_row(DataSegmentPermArg)
+                * (circuit_builder.challenge(DataSegmentIndeterminate)
+                    - Self::compressed_row_for_data_segment(&circuit_builder, next_base_row));
+        let data_segment_running_product_remains =
+            next_ext_row(DataSegmentPermArg) - curr_ext_row(DataSegmentPermArg);
+        let data_segment_running_product_updates_correctly = next_base_row(IsData)
+            * data_segment_running_product_accumulates
+            + (one - next_base_row(IsData)) * data_segment_running_product_remains;
+
+        let mut constraints = [
+            is_padding_is_0_or_remains_1,
+            iord_is_0_or_iord_is_inverse_of_address_diff,
+            address_diff_is_0_or_iord_is_inverse_of_address_diff,
+            address_changes_if_and_only_if_next_row_initializes,
+            value_remains_unless_address_is_written_to_or_initialized,
+            bcpc0_only_changes_if_address_changes,
+            bcpc1_only_changes_if_address_changes,
+            running_product_of_address_updates_correctly,
+            formal_derivative_updates_correctly,
+            bezout_coefficient_0_is_constructed_correctly,
+            bezout_coefficient_1_is_constructed_correctly,
+            rppa_updates_correctly,
+            log_derivative_updates_correctly,
+            data_segment_running_product_updates_correctly,
+        ];
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints.map(|circuit| circuit.consume()).to_vec()
+    }
+
+    pub fn ext_terminal_constraints_as_circuits() -> Vec<ConstraintCircuit<SingleRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let one = circuit_builder.b_constant(1_u32.into());
+        let ext_row =
+            |col: MemoryExtTableColumn| circuit_builder.input(ExtRow(col.master_ext_table_index()));
+
+        // All addresses are distinct, i.e., every address has exactly one section.
+        let bezout_relation_holds = ext_row(BezoutCoefficient0) * ext_row(RunningProductOfAddress)
+            + ext_row(BezoutCoefficient1) * ext_row(FormalDerivative)
+            - one;
+
+        let mut constraints = [bezout_relation_holds];
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints.map(|circuit| circuit.consume()).to_vec()
+    }
+}
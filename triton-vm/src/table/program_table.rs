@@ -5,6 +5,8 @@ use ndarray::ArrayViewMut2;
 use num_traits::One;
 use num_traits::Zero;
 use strum::EnumCount;
+use triton_zmips::data_segment::DATA_SEGMENT_START;
+use triton_zmips::data_segment::WORD_SIZE;
 use twenty_first::shared_math::b_field_element::BFieldElement;
 use twenty_first::shared_math::traits::Inverse;
 use twenty_first::shared_math::x_field_element::XFieldElement;
@@ -19,6 +21,7 @@ use crate::table::constraint_circuit::SingleRowIndicator;
 use crate::table::constraint_circuit::SingleRowIndicator::*;
 use crate::table::cross_table_argument::CrossTableArg;
 use crate::table::cross_table_argument::LookupArg;
+use crate::table::cross_table_argument::PermArg;
 use crate::table::table_column::MasterBaseTableColumn;
 use crate::table::table_column::MasterExtTableColumn;
 use crate::table::table_column::ProgramBaseTableColumn;
@@ -33,6 +36,9 @@ pub const BASE_WIDTH: usize = ProgramBaseTableColumn::COUNT;
 pub const EXT_WIDTH: usize = ProgramExtTableColumn::COUNT;
 pub const FULL_WIDTH: usize = BASE_WIDTH + EXT_WIDTH;
 
+/// The Program Table holds the program's instructions and, for zMIPS programs, the words of the
+/// data segment. The rows holding the data segment are established as the initial values of
+/// their addresses by a Permutation Argument with the Memory Table.
 #[derive(Debug, Clone)]
 pub struct ProgramTable {}
 
@@ -42,11 +48,16 @@ pub struct ExtProgramTable {}
 impl ExtProgramTable {
     pub fn ext_initial_constraints_as_circuits() -> Vec<ConstraintCircuit<SingleRowIndicator>> {
         let circuit_builder = ConstraintCircuitBuilder::new();
+        let one = circuit_builder.b_constant(1_u32.into());
 
         let address = circuit_builder.input(BaseRow(Address.master_base_table_index()));
+        let is_data = circuit_builder.input(BaseRow(IsData.master_base_table_index()));
+        let data_word = circuit_builder.input(BaseRow(DataWord.master_base_table_index()));
         let instruction_lookup_log_derivative = circuit_builder.input(ExtRow(
             InstructionLookupServerLogDerivative.master_ext_table_index(),
         ));
+        let data_segment_running_product =
+            circuit_builder.input(ExtRow(DataSegmentPermArg.master_ext_table_index()));
 
         let first_address_is_zero = address;
 
@@ -54,9 +65,25 @@ impl ExtProgramTable {
             instruction_lookup_log_derivative
                 - circuit_builder.x_constant(LookupArg::default_initial());
 
+        // The first address being 0, the first data word is stored at the data segment's start.
+        let compressed_row_for_data_segment = circuit_builder.challenge(DataSegmentAddressWeight)
+            * circuit_builder.b_constant(DATA_SEGMENT_START.into())
+            + circuit_builder.challenge(DataSegmentValueWeight) * data_word;
+        let data_segment_running_product_has_accumulated_first_row = data_segment_running_product
+            .clone()
+            - circuit_builder.x_constant(PermArg::default_initial())
+                * (circuit_builder.challenge(DataSegmentIndeterminate)
+                    - compressed_row_for_data_segment);
+        let data_segment_running_product_is_default_initial =
+            data_segment_running_product - circuit_builder.x_constant(PermArg::default_initial());
+        let data_segment_running_product_is_initialized_correctly = is_data.clone()
+            * data_segment_running_product_has_accumulated_first_row
+            + (one - is_data) * data_segment_running_product_is_default_initial;
+
         let mut constraints = [
             first_address_is_zero,
             instruction_lookup_log_derivative_is_initialized_correctly,
+            data_segment_running_product_is_initialized_correctly,
         ];
 
         ConstraintCircuitMonad::constant_folding(&mut constraints);
@@ -68,9 +95,12 @@ impl ExtProgramTable {
         let one = circuit_builder.b_constant(1_u32.into());
 
         let is_padding = circuit_builder.input(BaseRow(IsPadding.master_base_table_index()));
-        let is_padding_is_bit = is_padding.clone() * (is_padding - one);
+        let is_padding_is_bit = is_padding.clone() * (is_padding - one.clone());
 
-        let mut constraints = [is_padding_is_bit];
+        let is_data = circuit_builder.input(BaseRow(IsData.master_base_table_index()));
+        let is_data_is_bit = is_data.clone() * (is_data - one);
+
+        let mut constraints = [is_padding_is_bit, is_data_is_bit];
         ConstraintCircuitMonad::constant_folding(&mut constraints);
         constraints.map(|circuit| circuit.consume()).to_vec()
     }
@@ -95,6 +125,21 @@ impl ExtProgramTable {
         let log_derivative_next = circuit_builder.input(NextExtRow(
             InstructionLookupServerLogDerivative.master_ext_table_index(),
         ));
+        let is_data = circuit_builder.input(CurrentBaseRow(IsData.master_base_table_index()));
+        let is_data_next = circuit_builder.input(NextBaseRow(IsData.master_base_table_index()));
+        let data_word_next = circuit_builder.input(NextBaseRow(DataWord.master_base_table_index()));
+        let data_segment_running_product =
+            circuit_builder.input(CurrentExtRow(DataSegmentPermArg.master_ext_table_index()));
+        let data_segment_running_product_next =
+            circuit_builder.input(NextExtRow(DataSegmentPermArg.master_ext_table_index()));
+
+        // The data segment's word with index `address` is stored at its start plus `address`
+        // words.
+        let data_segment_start = circuit_builder.b_constant(DATA_SEGMENT_START.into());
+        let word_size = circuit_builder.b_constant(WORD_SIZE.into());
+        let compressed_row_for_data_segment = circuit_builder.challenge(DataSegmentAddressWeight)
+            * (data_segment_start + word_size * address_next.clone())
+            + circuit_builder.challenge(DataSegmentValueWeight) * data_word_next;
 
         let address_increases_by_one = address_next - (address.clone() + one.clone());
         let is_padding_is_0_or_remains_unchanged =
@@ -110,14 +155,28 @@ impl ExtProgramTable {
         let log_derivative_updates = (log_derivative_next - log_derivative)
             * (indeterminate - compressed_row)
             - lookup_multiplicity;
-        let log_derivative_updates_if_and_only_if_not_a_padding_row = (one - is_padding.clone())
-            * log_derivative_updates
-            + is_padding * log_derivative_remains;
+        let log_derivative_updates_if_and_only_if_not_a_padding_row =
+            (one.clone() - is_padding.clone()) * log_derivative_updates
+                + is_padding * log_derivative_remains;
+
+        let data_rows_form_a_prefix = (one.clone() - is_data) * is_data_next.clone();
+
+        let data_segment_running_product_accumulates = data_segment_running_product_next.clone()
+            - data_segment_running_product.clone()
+                * (circuit_builder.challenge(DataSegmentIndeterminate)
+                    - compressed_row_for_data_segment);
+        let data_segment_running_product_remains =
+            data_segment_running_product_next - data_segment_running_product;
+        let data_segment_running_product_updates_correctly = is_data_next.clone()
+            * data_segment_running_product_accumulates
+            + (one - is_data_next) * data_segment_running_product_remains;
 
         let mut constraints = [
             address_increases_by_one,
             is_padding_is_0_or_remains_unchanged,
             log_derivative_updates_if_and_only_if_not_a_padding_row,
+            data_rows_form_a_prefix,
+            data_segment_running_product_updates_correctly,
         ];
         ConstraintCircuitMonad::constant_folding(&mut constraints);
         constraints.map(|circuit| circuit.consume()).to_vec()
@@ -152,6 +211,13 @@ impl ProgramTable {
         let multiplicities_column =
             program_table.slice_mut(s![..program_len, LookupMultiplicity.base_table_index()]);
         multiplicities.move_into(multiplicities_column);
+
+        let data = Array1::from(aet.program.data_bwords());
+        let data_len = data.len();
+        data.move_into(program_table.slice_mut(s![..data_len, DataWord.base_table_index()]));
+        program_table
+            .slice_mut(s![..data_len, IsData.base_table_index()])
+            .fill(BFieldElement::one());
     }
 
     pub fn pad_trace(program_table: &mut ArrayViewMut2<BFieldElement>, program_len: usize) {
@@ -179,6 +245,23 @@ impl ProgramTable {
         let next_instruction_weight = challenges.get_challenge(ProgramNextInstructionWeight);
         let instruction_lookup_indeterminate =
             challenges.get_challenge(InstructionLookupIndeterminate);
+        let data_segment_address_weight = challenges.get_challenge(DataSegmentAddressWeight);
+        let data_segment_value_weight = challenges.get_challenge(DataSegmentValueWeight);
+        let data_segment_perm_indeterminate = challenges.get_challenge(DataSegmentIndeterminate);
+
+        // permutation argument to Memory Table, establishing the data segment
+        let mut data_segment_running_product = PermArg::default_initial();
+        for (row, mut extension_row) in base_table.rows().into_iter().zip(ext_table.rows_mut()) {
+            if row[IsData.base_table_index()].is_one() {
+                let address = BFieldElement::new(DATA_SEGMENT_START.into())
+                    + BFieldElement::new(WORD_SIZE.into()) * row[Address.base_table_index()];
+                let compressed_row_for_data_segment = address * data_segment_address_weight
+                    + row[DataWord.base_table_index()] * data_segment_value_weight;
+                data_segment_running_product *=
+                    data_segment_perm_indeterminate - compressed_row_for_data_segment;
+            }
+            extension_row[DataSegmentPermArg.ext_table_index()] = data_segment_running_product;
+        }
 
         let mut instruction_lookup_log_derivative = LookupArg::default_initial();
 
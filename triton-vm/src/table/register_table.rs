@@ -0,0 +1,350 @@
+use ndarray::parallel::prelude::*;
+use ndarray::s;
+use ndarray::ArrayView1;
+use ndarray::ArrayView2;
+use ndarray::ArrayViewMut2;
+use ndarray::Axis;
+use num_traits::One;
+use num_traits::Zero;
+use strum::EnumCount;
+use triton_zmips::regs::REGS;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::traits::Inverse;
+use twenty_first::shared_math::x_field_element::XFieldElement;
+
+use crate::table::challenges::ChallengeId::*;
+use crate::table::challenges::Challenges;
+use crate::table::constraint_circuit::ConstraintCircuit;
+use crate::table::constraint_circuit::ConstraintCircuitBuilder;
+use crate::table::constraint_circuit::ConstraintCircuitMonad;
+use crate::table::constraint_circuit::DualRowIndicator;
+use crate::table::constraint_circuit::DualRowIndicator::*;
+use crate::table::constraint_circuit::SingleRowIndicator;
+use crate::table::constraint_circuit::SingleRowIndicator::*;
+use crate::table::cross_table_argument::CrossTableArg;
+use crate::table::cross_table_argument::LookupArg;
+use crate::table::cross_table_argument::PermArg;
+use crate::table::table_column::MasterBaseTableColumn;
+use crate::table::table_column::MasterExtTableColumn;
+use crate::table::table_column::RegisterBaseTableColumn;
+use crate::table::table_column::RegisterBaseTableColumn::*;
+use crate::table::table_column::RegisterExtTableColumn;
+use crate::table::table_column::RegisterExtTableColumn::*;
+use crate::table::table_column::ZmipsProcessorBaseTableColumn;
+use crate::table::zmips_processor_table::ZmipsProcessorTable;
+use crate::vm::AlgebraicExecutionTrace;
+
+pub const BASE_WIDTH: usize = RegisterBaseTableColumn::COUNT;
+pub const EXT_WIDTH: usize = RegisterExtTableColumn::COUNT;
+pub const FULL_WIDTH: usize = BASE_WIDTH + EXT_WIDTH;
+
+/// The number of registers of the zMIPS register machine.
+pub const NUM_REGISTERS: usize = REGS.len();
+
+/// Every instruction reads registers `rs` and `rt`, and either reads or writes register `rd`.
+pub const NUM_REGISTER_ACCESSES_PER_INSTRUCTION: usize = 3;
+
+/// The Register Table records every access of the zMIPS Processor Table to the register file.
+/// It is sorted by register index, then by clock cycle. The section of every register starts with
+/// an initialization row, setting the register to 0, followed by all accesses to that register.
+/// Reading a register does not change its value. Like in MIPS, register `$zero` is hardwired to
+/// 0: its section holds no value other than 0.
+///
+/// The Permutation Argument with the zMIPS Processor Table establishes that the Register Table
+/// contains exactly the processor's register accesses. The clock jump difference Lookup Argument
+/// with the zMIPS Processor Table establishes that the accesses are sorted by clock cycle, and
+/// that reading a register in some cycle happens before writing to it in that same cycle.
+#[derive(Debug, Clone)]
+pub struct RegisterTable {}
+
+#[derive(Debug, Clone)]
+pub struct ExtRegisterTable {}
+
+impl RegisterTable {
+    /// Fills the trace table in-place and returns all clock jump differences.
+    pub fn fill_trace(
+        register_table: &mut ArrayViewMut2<BFieldElement>,
+        aet: &AlgebraicExecutionTrace,
+    ) -> Vec<BFieldElement> {
+        // Group the register accesses by register index. Preserves the order of the processor's
+        // rows, which are sorted by CLK. Within one row, the source registers are read before the
+        // destination register is accessed.
+        let mut accesses_per_register = vec![vec![]; NUM_REGISTERS];
+        for processor_row in aet.zmips_processor_trace.rows() {
+            let clk = processor_row[ZmipsProcessorBaseTableColumn::CLK.base_table_index()];
+            for [register, value, is_write] in ZmipsProcessorTable::register_accesses(processor_row)
+            {
+                accesses_per_register[register.value() as usize].push((clk, value, is_write));
+            }
+        }
+
+        // The clock jump difference of a row following a write is reduced by 1, guaranteeing that
+        // a register is written to at most once per clock cycle, and only after it was read.
+        let mut clock_jump_differences = vec![];
+        let mut row_idx = 0;
+        for (register, accesses) in accesses_per_register.into_iter().enumerate() {
+            let register = BFieldElement::new(register as u64);
+            let register_index_inverse = register.inverse_or_zero();
+            let mut initialization_row = register_table.row_mut(row_idx);
+            initialization_row[IsInitialization.base_table_index()] = BFieldElement::one();
+            initialization_row[RegisterIndex.base_table_index()] = register;
+            initialization_row[InverseOfRegisterIndex.base_table_index()] = register_index_inverse;
+            row_idx += 1;
+
+            let mut previous_clk = BFieldElement::zero();
+            let mut previous_is_write = BFieldElement::zero();
+            for (clk, value, is_write) in accesses {
+                let mut register_table_row = register_table.row_mut(row_idx);
+                register_table_row[CLK.base_table_index()] = clk;
+                register_table_row[RegisterIndex.base_table_index()] = register;
+                register_table_row[RegisterValue.base_table_index()] = value;
+                register_table_row[IsWrite.base_table_index()] = is_write;
+                register_table_row[InverseOfRegisterIndex.base_table_index()] =
+                    register_index_inverse;
+                clock_jump_differences.push(clk - previous_clk - previous_is_write);
+                previous_clk = clk;
+                previous_is_write = is_write;
+                row_idx += 1;
+            }
+        }
+        assert_eq!(
+            NUM_REGISTERS
+                + NUM_REGISTER_ACCESSES_PER_INSTRUCTION * aet.zmips_processor_trace.nrows(),
+            row_idx
+        );
+
+        clock_jump_differences
+    }
+
+    pub fn pad_trace(register_table: &mut ArrayViewMut2<BFieldElement>, register_table_len: usize) {
+        let mut padding_template = register_table.row(register_table_len - 1).to_owned();
+        padding_template[IsPadding.base_table_index()] = BFieldElement::one();
+        padding_template[IsInitialization.base_table_index()] = BFieldElement::zero();
+        padding_template[IsWrite.base_table_index()] = BFieldElement::zero();
+        register_table
+            .slice_mut(s![register_table_len.., ..])
+            .axis_iter_mut(Axis(0))
+            .into_par_iter()
+            .for_each(|mut row| row.assign(&padding_template));
+    }
+
+    pub fn extend(
+        base_table: ArrayView2<BFieldElement>,
+        mut ext_table: ArrayViewMut2<XFieldElement>,
+        challenges: &Challenges,
+    ) {
+        assert_eq!(BASE_WIDTH, base_table.ncols());
+        assert_eq!(EXT_WIDTH, ext_table.ncols());
+        assert_eq!(base_table.nrows(), ext_table.nrows());
+
+        let clk_weight = challenges.get_challenge(RegisterClkWeight);
+        let index_weight = challenges.get_challenge(RegisterIndexWeight);
+        let value_weight = challenges.get_challenge(RegisterValueWeight);
+        let is_write_weight = challenges.get_challenge(RegisterIsWriteWeight);
+        let processor_perm_indeterminate = challenges.get_challenge(RegisterIndeterminate);
+        let clock_jump_difference_lookup_indeterminate =
+            challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate);
+
+        let mut running_product_for_perm_arg = PermArg::default_initial();
+        let mut clock_jump_diff_lookup_log_derivative = LookupArg::default_initial();
+
+        let mut previous_row: Option<ArrayView1<BFieldElement>> = None;
+        for row_idx in 0..base_table.nrows() {
+            let current_row = base_table.row(row_idx);
+            let is_register_access = current_row[IsInitialization.base_table_index()].is_zero()
+                && current_row[IsPadding.base_table_index()].is_zero();
+
+            if is_register_access {
+                let clk = current_row[CLK.base_table_index()];
+                let register_index = current_row[RegisterIndex.base_table_index()];
+                let register_value = current_row[RegisterValue.base_table_index()];
+                let is_write = current_row[IsWrite.base_table_index()];
+
+                // permutation argument to zMIPS Processor Table
+                let compressed_row_for_permutation_argument = clk * clk_weight
+                    + register_index * index_weight
+                    + register_value * value_weight
+                    + is_write * is_write_weight;
+                running_product_for_perm_arg *=
+                    processor_perm_indeterminate - compressed_row_for_permutation_argument;
+
+                // prove that clock jump is directed forward
+                if let Some(prev_row) = previous_row {
+                    let clock_jump_difference = clk
+                        - prev_row[CLK.base_table_index()]
+                        - prev_row[IsWrite.base_table_index()];
+                    clock_jump_diff_lookup_log_derivative +=
+                        (clock_jump_difference_lookup_indeterminate - clock_jump_difference)
+                            .inverse();
+                }
+            }
+
+            let mut extension_row = ext_table.row_mut(row_idx);
+            extension_row[RunningProductPermArg.ext_table_index()] = running_product_for_perm_arg;
+            extension_row[ClockJumpDifferenceLookupClientLogDerivative.ext_table_index()] =
+                clock_jump_diff_lookup_log_derivative;
+            previous_row = Some(current_row);
+        }
+    }
+}
+
+impl ExtRegisterTable {
+    pub fn ext_initial_constraints_as_circuits() -> Vec<ConstraintCircuit<SingleRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let one = circuit_builder.b_constant(1_u32.into());
+        let base_row = |col: RegisterBaseTableColumn| {
+            circuit_builder.input(BaseRow(col.master_base_table_index()))
+        };
+        let ext_row = |col: RegisterExtTableColumn| {
+            circuit_builder.input(ExtRow(col.master_ext_table_index()))
+        };
+
+        let first_row_is_not_padding = base_row(IsPadding);
+        let first_row_initializes_a_register = base_row(IsInitialization) - one;
+        let first_register_index_is_0 = base_row(RegisterIndex);
+
+        // The initialization row is not part of either argument.
+        let running_product_permutation_argument_is_initialized_correctly =
+            ext_row(RunningProductPermArg) - circuit_builder.x_constant(PermArg::default_initial());
+        let clock_jump_diff_log_derivative_is_initialized_correctly =
+            ext_row(ClockJumpDifferenceLookupClientLogDerivative)
+                - circuit_builder.x_constant(LookupArg::default_initial());
+
+        let mut constraints = [
+            first_row_is_not_padding,
+            first_row_initializes_a_register,
+            first_register_index_is_0,
+            running_product_permutation_argument_is_initialized_correctly,
+            clock_jump_diff_log_derivative_is_initialized_correctly,
+        ];
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints.map(|circuit| circuit.consume()).to_vec()
+    }
+
+    pub fn ext_consistency_constraints_as_circuits() -> Vec<ConstraintCircuit<SingleRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let one = circuit_builder.b_constant(1_u32.into());
+        let base_row = |col: RegisterBaseTableColumn| {
+            circuit_builder.input(BaseRow(col.master_base_table_index()))
+        };
+        let is_bit = |col| base_row(col) * (base_row(col) - one.clone());
+
+        let is_padding_is_bit = is_bit(IsPadding);
+        let is_initialization_is_bit = is_bit(IsInitialization);
+        let is_write_is_bit = is_bit(IsWrite);
+        let initialization_sets_register_to_0 =
+            base_row(IsInitialization) * base_row(RegisterValue);
+
+        // 1 if and only if the row belongs to register `$zero`, else 0.
+        let is_zero_register =
+            one.clone() - base_row(RegisterIndex) * base_row(InverseOfRegisterIndex);
+        let register_index_is_0_or_inverse_is_correct =
+            base_row(RegisterIndex) * is_zero_register.clone();
+        let inverse_is_0_or_inverse_is_correct =
+            base_row(InverseOfRegisterIndex) * is_zero_register.clone();
+        let zero_register_holds_0 = is_zero_register * base_row(RegisterValue);
+
+        let mut constraints = [
+            is_padding_is_bit,
+            is_initialization_is_bit,
+            is_write_is_bit,
+            initialization_sets_register_to_0,
+            register_index_is_0_or_inverse_is_correct,
+            inverse_is_0_or_inverse_is_correct,
+            zero_register_holds_0,
+        ];
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints.map(|circuit| circuit.consume()).to_vec()
+    }
+
+    pub fn ext_transition_constraints_as_circuits() -> Vec<ConstraintCircuit<DualRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let one = circuit_builder.b_constant(1_u32.into());
+        let curr_base_row = |col: RegisterBaseTableColumn| {
+            circuit_builder.input(CurrentBaseRow(col.master_base_table_index()))
+        };
+        let next_base_row = |col: RegisterBaseTableColumn| {
+            circuit_builder.input(NextBaseRow(col.master_base_table_index()))
+        };
+        let curr_ext_row = |col: RegisterExtTableColumn| {
+            circuit_builder.input(CurrentExtRow(col.master_ext_table_index()))
+        };
+        let next_ext_row = |col: RegisterExtTableColumn| {
+            circuit_builder.input(NextExtRow(col.master_ext_table_index()))
+        };
+
+        let is_padding_is_0_or_remains_1 =
+            curr_base_row(IsPadding) * (next_base_row(IsPadding) - one.clone());
+
+        // The register index increases by 1 exactly if the next row is an initialization row.
+        // Together with the initial and terminal constraints, every register has exactly one
+        // contiguous section, and the register indices are in the range [0, 32).
+        let register_index_increases_by_1_if_and_only_if_next_row_initializes =
+            next_base_row(RegisterIndex)
+                - curr_base_row(RegisterIndex)
+                - next_base_row(IsInitialization);
+
+        let value_remains_unless_register_is_written_to_or_initialized = (one.clone()
+            - next_base_row(IsWrite))
+            * (one.clone() - next_base_row(IsInitialization))
+            * (next_base_row(RegisterValue) - curr_base_row(RegisterValue));
+
+        // Both arguments accumulate a summand if and only if the next row is a register access,
+        // i.e., neither an initialization row nor a padding row.
+        let next_row_is_register_access = (one.clone() - next_base_row(IsInitialization))
+            * (one.clone() - next_base_row(IsPadding));
+        let next_row_is_no_register_access = one.clone() - next_row_is_register_access.clone();
+
+        let compressed_row_for_permutation_argument = circuit_builder.challenge(RegisterClkWeight)
+            * next_base_row(CLK)
+            + circuit_builder.challenge(RegisterIndexWeight) * next_base_row(RegisterIndex)
+            + circuit_builder.challenge(RegisterValueWeight) * next_base_row(RegisterValue)
+            + circuit_builder.challenge(RegisterIsWriteWeight) * next_base_row(IsWrite);
+        let rppa_accumulates = next_ext_row(RunningProductPermArg)
+            - curr_ext_row(RunningProductPermArg)
+                * (circuit_builder.challenge(RegisterIndeterminate)
+                    - compressed_row_for_permutation_argument);
+        let rppa_remains =
+            next_ext_row(RunningProductPermArg) - curr_ext_row(RunningProductPermArg);
+        let rppa_updates_correctly = next_row_is_register_access.clone() * rppa_accumulates
+            + next_row_is_no_register_access.clone() * rppa_remains;
+
+        let log_derivative_remains = next_ext_row(ClockJumpDifferenceLookupClientLogDerivative)
+            - curr_ext_row(ClockJumpDifferenceLookupClientLogDerivative);
+        // One instruction accesses a register up to three times in the same cycle. Subtracting
+        // `IsWrite` forces the access after a write into a later cycle, so the write is the last
+        // access of its cycle.
+        let clk_diff = next_base_row(CLK) - curr_base_row(CLK) - curr_base_row(IsWrite);
+        let log_derivative_accumulates = log_derivative_remains.clone()
+            * (circuit_builder.challenge(ClockJumpDifferenceLookupIndeterminate) - clk_diff)
+            - one;
+        let log_derivative_updates_correctly = next_row_is_register_access
+            * log_derivative_accumulates
+            + next_row_is_no_register_access * log_derivative_remains;
+
+        let mut constraints = [
+            is_padding_is_0_or_remains_1,
+            register_index_increases_by_1_if_and_only_if_next_row_initializes,
+            value_remains_unless_register_is_written_to_or_initialized,
+            rppa_updates_correctly,
+            log_derivative_updates_correctly,
+        ];
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints.map(|circuit| circuit.consume()).to_vec()
+    }
+
+    pub fn ext_terminal_constraints_as_circuits() -> Vec<ConstraintCircuit<SingleRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let register_index =
+            circuit_builder.input(BaseRow(RegisterIndex.master_base_table_index()));
+        let last_register_index = BFieldElement::new(NUM_REGISTERS as u64 - 1);
+
+        // Every register has been initialized.
+        let last_register_index_is_max =
+            register_index - circuit_builder.b_constant(last_register_index);
+
+        let mut constraints = [last_register_index_is_max];
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints.map(|circuit| circuit.consume()).to_vec()
+    }
+}
@@ -12,19 +12,27 @@ use crate::table::master_table::EXT_CASCADE_TABLE_START;
 use crate::table::master_table::EXT_HASH_TABLE_START;
 use crate::table::master_table::EXT_JUMP_STACK_TABLE_START;
 use crate::table::master_table::EXT_LOOKUP_TABLE_START;
+use crate::table::master_table::EXT_MEMORY_TABLE_START;
 use crate::table::master_table::EXT_OP_STACK_TABLE_START;
 use crate::table::master_table::EXT_PROCESSOR_TABLE_START;
 use crate::table::master_table::EXT_PROGRAM_TABLE_START;
 use crate::table::master_table::EXT_RAM_TABLE_START;
+use crate::table::master_table::EXT_REGISTER_TABLE_START;
+use crate::table::master_table::EXT_TAPE_TABLE_START;
 use crate::table::master_table::EXT_U32_TABLE_START;
+use crate::table::master_table::EXT_ZMIPS_PROCESSOR_TABLE_START;
 use crate::table::master_table::HASH_TABLE_START;
 use crate::table::master_table::JUMP_STACK_TABLE_START;
 use crate::table::master_table::LOOKUP_TABLE_START;
+use crate::table::master_table::MEMORY_TABLE_START;
 use crate::table::master_table::OP_STACK_TABLE_START;
 use crate::table::master_table::PROCESSOR_TABLE_START;
 use crate::table::master_table::PROGRAM_TABLE_START;
 use crate::table::master_table::RAM_TABLE_START;
+use crate::table::master_table::REGISTER_TABLE_START;
+use crate::table::master_table::TAPE_TABLE_START;
 use crate::table::master_table::U32_TABLE_START;
+use crate::table::master_table::ZMIPS_PROCESSOR_TABLE_START;
 
 // -------- Program Table --------
 
@@ -35,12 +43,19 @@ pub enum ProgramBaseTableColumn {
     Instruction,
     LookupMultiplicity,
     IsPadding,
+    /// Marks the rows holding the words of a zMIPS program's data segment, one word per row.
+    IsData,
+    /// Word number `Address` of the data segment if the row is marked by `IsData`.
+    DataWord,
 }
 
 #[repr(usize)]
 #[derive(Display, Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCountMacro, Hash)]
 pub enum ProgramExtTableColumn {
     InstructionLookupServerLogDerivative,
+    /// The running product for the Permutation Argument with the Memory Table, accumulating the
+    /// words of the data segment.
+    DataSegmentPermArg,
 }
 
 // -------- Processor Table --------
@@ -411,6 +426,252 @@ pub enum U32ExtTableColumn {
     LookupServerLogDerivative,
 }
 
+// -------- zMIPS Processor Table --------
+
+#[repr(usize)]
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCountMacro, Hash)]
+pub enum ZmipsProcessorBaseTableColumn {
+    CLK,
+    IsPadding,
+    IP,
+    /// The opcode of the current instruction.
+    CI,
+    /// The current instruction, including all its operands, packed into one word.
+    IW,
+    /// The word following the current instruction in program memory.
+    NIW,
+    /// Index of the first source register.
+    RS,
+    /// Index of the second source register.
+    RT,
+    /// Index of the destination register.
+    RD,
+    /// Whether the second source operand is the immediate or the content of register `rt`.
+    IsImm,
+    Imm,
+    RsVal,
+    RtVal,
+    RdVal,
+    /// The second source operand: either `Imm` or `RtVal`, depending on `IsImm`.
+    Operand,
+    /// The memory address accessed by `lw` and `sw`, or the tape position targeted by `pubseek`
+    /// and `secseek`: `RsVal + Operand` modulo 2^32.
+    RAMP,
+    /// The memory value read by `lw` or written by `sw`.
+    RAMV,
+    /// Helper variable, used for inverses.
+    HV,
+    /// The sign bit of `RsVal`, _i.e._, bit 31.
+    SignRs,
+    /// The sign bit of `Operand`, _i.e._, bit 31.
+    SignOp,
+    /// The sign bit of `RdVal`, _i.e._, bit 31.
+    SignRd,
+    /// Helper variable for the high word of products, the quotient of divisions, and shifts.
+    Hi,
+    /// Helper variable for the low word of products, the remainder of divisions, shifts, and
+    /// the bitwise `and` underlying `or` and `xor`.
+    Lo,
+
+    // One selector per instruction, in the order of the opcodes. Exactly one is set in every row.
+    IsBeq,
+    IsBne,
+    IsBlt,
+    IsBle,
+    IsSeq,
+    IsSne,
+    IsSlt,
+    IsSle,
+    IsJ,
+    IsJr,
+    IsLw,
+    IsSw,
+    IsAdd,
+    IsSub,
+    IsMult,
+    IsDiv,
+    IsMod,
+    IsMove,
+    IsLa,
+    IsAnd,
+    IsXor,
+    IsNot,
+    IsSll,
+    IsSrl,
+    IsPubread,
+    IsSecread,
+    IsPubseek,
+    IsSecseek,
+    IsPrint,
+    IsExit,
+    IsAnswer,
+    IsOr,
+    IsBgt,
+    IsAddu,
+    IsSubu,
+    IsDivu,
+    IsSra,
+    IsSltu,
+    IsJal,
+    IsJalr,
+    IsBltu,
+    IsBgeu,
+    IsSyscall,
+
+    // Exactly one of the following is set if and only if the current instruction is `syscall`,
+    // indicating the service requested through register `$v0`.
+    SyscallPrintInt,
+    SyscallReadInt,
+    SyscallExit,
+    SyscallExit2,
+    SyscallReadSecretInt,
+    SyscallAnswer,
+
+    /// The number of clock jump differences of magnitude `CLK` in the Register Table and the
+    /// Memory Table.
+    ClockJumpDifferenceLookupMultiplicity,
+
+    /// The position of the public input tape's cursor, _i.e._, the position instruction
+    /// `pubread` reads from.
+    PubCursor,
+    /// The position of the secret input tape's cursor, _i.e._, the position instruction
+    /// `secread` reads from.
+    SecCursor,
+
+    /// The most recently reported final answer, _i.e._, the argument of the last `answer` up to
+    /// and including the current row. 0 if no answer has been reported yet.
+    FinalAnswer,
+    /// 1 if any row up to and including the current one reported a final answer, else 0.
+    HasFinalAnswer,
+
+    // Every row looks up exactly four entries in the U32 Table. Unused slots look up
+    // `(split, 0, 0, 0)`.
+    U32LookupCi0,
+    U32LookupLhs0,
+    U32LookupRhs0,
+    U32LookupResult0,
+    U32LookupCi1,
+    U32LookupLhs1,
+    U32LookupRhs1,
+    U32LookupResult1,
+    U32LookupCi2,
+    U32LookupLhs2,
+    U32LookupRhs2,
+    U32LookupResult2,
+    U32LookupCi3,
+    U32LookupLhs3,
+    U32LookupRhs3,
+    U32LookupResult3,
+}
+
+#[repr(usize)]
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCountMacro, Hash)]
+pub enum ZmipsProcessorExtTableColumn {
+    /// The (running sum of the) logarithmic derivative for the Lookup Argument with the Tape
+    /// Table, accumulating the symbols read by instructions `pubread` and `secread`.
+    TapeLookupClientLogDerivative,
+    OutputTableEvalArg,
+    InstructionLookupClientLogDerivative,
+    /// The running product for the Permutation Argument with the Register Table, accumulating
+    /// the accesses to registers `rs`, `rt`, and `rd`.
+    RegisterTablePermArg,
+    /// The running product for the Permutation Argument with the Memory Table, accumulating
+    /// the memory accesses of instructions `lw` and `sw`.
+    MemoryTablePermArg,
+    /// The (running sum of the) logarithmic derivative for the Lookup Argument with the U32
+    /// Table, accumulating the four lookups of every row.
+    U32LookupClientLogDerivative,
+    /// The (running sum of the) logarithmic derivative for the clock jump difference Lookup
+    /// Argument with the Register Table and the Memory Table.
+    ClockJumpDifferenceLookupServerLogDerivative,
+}
+
+// -------- Register Table --------
+
+#[repr(usize)]
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCountMacro, Hash)]
+pub enum RegisterBaseTableColumn {
+    CLK,
+    IsPadding,
+    /// Marks the first row of every register's section, setting the register to 0.
+    IsInitialization,
+    RegisterIndex,
+    RegisterValue,
+    /// Whether the zMIPS processor wrote `RegisterValue` to the register, or read it.
+    IsWrite,
+    /// The inverse of `RegisterIndex`, or 0 for register `$zero`. Identifies the rows of register
+    /// `$zero`, which is hardwired to 0.
+    InverseOfRegisterIndex,
+}
+
+#[repr(usize)]
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCountMacro, Hash)]
+pub enum RegisterExtTableColumn {
+    RunningProductPermArg,
+    /// The (running sum of the) logarithmic derivative for the clock jump difference Lookup
+    /// Argument with the zMIPS Processor Table.
+    ClockJumpDifferenceLookupClientLogDerivative,
+}
+
+// -------- Tape Table --------
+
+#[repr(usize)]
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCountMacro, Hash)]
+pub enum TapeBaseTableColumn {
+    IsPadding,
+    /// Whether the row belongs to the secret input tape or to the public input tape.
+    IsSecret,
+    Position,
+    Value,
+    /// The number of times the zMIPS processor read the symbol.
+    LookupMultiplicity,
+}
+
+#[repr(usize)]
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCountMacro, Hash)]
+pub enum TapeExtTableColumn {
+    /// The running evaluation over the public input tape, establishing that the tape equals the
+    /// public input.
+    PublicInputEvalArg,
+    LookupServerLogDerivative,
+}
+
+// -------- Memory Table --------
+
+#[repr(usize)]
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCountMacro, Hash)]
+pub enum MemoryBaseTableColumn {
+    IsPadding,
+    /// Marks the first row of every address's section, holding the address's initial value.
+    IsInitialization,
+    /// Marks the initialization rows of all addresses of the data segment.
+    IsData,
+    CLK,
+    Address,
+    Value,
+    /// Whether the zMIPS processor wrote `Value` to the address using `sw`, or read it using `lw`.
+    IsWrite,
+    InverseOfAddressDifference,
+    BezoutCoefficientPolynomialCoefficient0,
+    BezoutCoefficientPolynomialCoefficient1,
+}
+
+#[repr(usize)]
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCountMacro, Hash)]
+pub enum MemoryExtTableColumn {
+    RunningProductPermArg,
+    /// The (running sum of the) logarithmic derivative for the clock jump difference Lookup
+    /// Argument with the zMIPS Processor Table.
+    ClockJumpDifferenceLookupClientLogDerivative,
+    RunningProductOfAddress,
+    FormalDerivative,
+    BezoutCoefficient0,
+    BezoutCoefficient1,
+    /// The running product for the Permutation Argument with the Program Table, accumulating the
+    /// initialization rows of the data segment's addresses.
+    DataSegmentPermArg,
+}
+
 // --------------------------------------------------------------------
 
 /// A trait for the columns of the master base table. This trait is implemented for all enums
@@ -534,6 +795,54 @@ impl MasterBaseTableColumn for U32BaseTableColumn {
     }
 }
 
+impl MasterBaseTableColumn for ZmipsProcessorBaseTableColumn {
+    #[inline]
+    fn base_table_index(&self) -> usize {
+        (*self) as usize
+    }
+
+    #[inline]
+    fn master_base_table_index(&self) -> usize {
+        ZMIPS_PROCESSOR_TABLE_START + self.base_table_index()
+    }
+}
+
+impl MasterBaseTableColumn for RegisterBaseTableColumn {
+    #[inline]
+    fn base_table_index(&self) -> usize {
+        (*self) as usize
+    }
+
+    #[inline]
+    fn master_base_table_index(&self) -> usize {
+        REGISTER_TABLE_START + self.base_table_index()
+    }
+}
+
+impl MasterBaseTableColumn for TapeBaseTableColumn {
+    #[inline]
+    fn base_table_index(&self) -> usize {
+        (*self) as usize
+    }
+
+    #[inline]
+    fn master_base_table_index(&self) -> usize {
+        TAPE_TABLE_START + self.base_table_index()
+    }
+}
+
+impl MasterBaseTableColumn for MemoryBaseTableColumn {
+    #[inline]
+    fn base_table_index(&self) -> usize {
+        (*self) as usize
+    }
+
+    #[inline]
+    fn master_base_table_index(&self) -> usize {
+        MEMORY_TABLE_START + self.base_table_index()
+    }
+}
+
 // --------------------------------------------------------------------
 
 /// A trait for the columns in the master extension table. This trait is implemented for all enums
@@ -658,6 +967,54 @@ impl MasterExtTableColumn for U32ExtTableColumn {
     }
 }
 
+impl MasterExtTableColumn for ZmipsProcessorExtTableColumn {
+    #[inline]
+    fn ext_table_index(&self) -> usize {
+        (*self) as usize
+    }
+
+    #[inline]
+    fn master_ext_table_index(&self) -> usize {
+        EXT_ZMIPS_PROCESSOR_TABLE_START + self.ext_table_index()
+    }
+}
+
+impl MasterExtTableColumn for RegisterExtTableColumn {
+    #[inline]
+    fn ext_table_index(&self) -> usize {
+        (*self) as usize
+    }
+
+    #[inline]
+    fn master_ext_table_index(&self) -> usize {
+        EXT_REGISTER_TABLE_START + self.ext_table_index()
+    }
+}
+
+impl MasterExtTableColumn for TapeExtTableColumn {
+    #[inline]
+    fn ext_table_index(&self) -> usize {
+        (*self) as usize
+    }
+
+    #[inline]
+    fn master_ext_table_index(&self) -> usize {
+        EXT_TAPE_TABLE_START + self.ext_table_index()
+    }
+}
+
+impl MasterExtTableColumn for MemoryExtTableColumn {
+    #[inline]
+    fn ext_table_index(&self) -> usize {
+        (*self) as usize
+    }
+
+    #[inline]
+    fn master_ext_table_index(&self) -> usize {
+        EXT_MEMORY_TABLE_START + self.ext_table_index()
+    }
+}
+
 // --------------------------------------------------------------------
 
 #[cfg(test)]
@@ -668,11 +1025,15 @@ mod table_column_tests {
     use crate::table::hash_table;
     use crate::table::jump_stack_table;
     use crate::table::lookup_table;
+    use crate::table::memory_table;
     use crate::table::op_stack_table;
     use crate::table::processor_table;
     use crate::table::program_table;
     use crate::table::ram_table;
+    use crate::table::register_table;
+    use crate::table::tape_table;
     use crate::table::u32_table;
+    use crate::table::zmips_processor_table;
 
     use super::*;
 
@@ -759,6 +1120,42 @@ mod table_column_tests {
                 + 1,
             "U32Table's BASE_WIDTH is 1 + its max column index",
         );
+        assert_eq!(
+            zmips_processor_table::BASE_WIDTH,
+            ZmipsProcessorBaseTableColumn::iter()
+                .last()
+                .unwrap()
+                .base_table_index()
+                + 1,
+            "ZmipsProcessorTable's BASE_WIDTH is 1 + its max column index",
+        );
+        assert_eq!(
+            register_table::BASE_WIDTH,
+            RegisterBaseTableColumn::iter()
+                .last()
+                .unwrap()
+                .base_table_index()
+                + 1,
+            "RegisterTable's BASE_WIDTH is 1 + its max column index",
+        );
+        assert_eq!(
+            tape_table::BASE_WIDTH,
+            TapeBaseTableColumn::iter()
+                .last()
+                .unwrap()
+                .base_table_index()
+                + 1,
+            "TapeTable's BASE_WIDTH is 1 + its max column index",
+        );
+        assert_eq!(
+            memory_table::BASE_WIDTH,
+            MemoryBaseTableColumn::iter()
+                .last()
+                .unwrap()
+                .base_table_index()
+                + 1,
+            "MemoryTable's BASE_WIDTH is 1 + its max column index",
+        );
 
         assert_eq!(
             program_table::EXT_WIDTH,
@@ -829,6 +1226,38 @@ mod table_column_tests {
             U32ExtTableColumn::iter().last().unwrap().ext_table_index() + 1,
             "U32Table's EXT_WIDTH is 1 + its max column index",
         );
+        assert_eq!(
+            zmips_processor_table::EXT_WIDTH,
+            ZmipsProcessorExtTableColumn::iter()
+                .last()
+                .unwrap()
+                .ext_table_index()
+                + 1,
+            "ZmipsProcessorTable's EXT_WIDTH is 1 + its max column index",
+        );
+        assert_eq!(
+            register_table::EXT_WIDTH,
+            RegisterExtTableColumn::iter()
+                .last()
+                .unwrap()
+                .ext_table_index()
+                + 1,
+            "RegisterTable's EXT_WIDTH is 1 + its max column index",
+        );
+        assert_eq!(
+            tape_table::EXT_WIDTH,
+            TapeExtTableColumn::iter().last().unwrap().ext_table_index() + 1,
+            "TapeTable's EXT_WIDTH is 1 + its max column index",
+        );
+        assert_eq!(
+            memory_table::EXT_WIDTH,
+            MemoryExtTableColumn::iter()
+                .last()
+                .unwrap()
+                .ext_table_index()
+                + 1,
+            "MemoryTable's EXT_WIDTH is 1 + its max column index",
+        );
     }
 
     #[test]
@@ -870,6 +1299,22 @@ mod table_column_tests {
             assert_eq!(expected_column_index, column.master_base_table_index());
             expected_column_index += 1;
         }
+        for column in ZmipsProcessorBaseTableColumn::iter() {
+            assert_eq!(expected_column_index, column.master_base_table_index());
+            expected_column_index += 1;
+        }
+        for column in RegisterBaseTableColumn::iter() {
+            assert_eq!(expected_column_index, column.master_base_table_index());
+            expected_column_index += 1;
+        }
+        for column in TapeBaseTableColumn::iter() {
+            assert_eq!(expected_column_index, column.master_base_table_index());
+            expected_column_index += 1;
+        }
+        for column in MemoryBaseTableColumn::iter() {
+            assert_eq!(expected_column_index, column.master_base_table_index());
+            expected_column_index += 1;
+        }
     }
 
     #[test]
@@ -911,5 +1356,21 @@ mod table_column_tests {
             assert_eq!(expected_column_index, column.master_ext_table_index());
             expected_column_index += 1;
         }
+        for column in ZmipsProcessorExtTableColumn::iter() {
+            assert_eq!(expected_column_index, column.master_ext_table_index());
+            expected_column_index += 1;
+        }
+        for column in RegisterExtTableColumn::iter() {
+            assert_eq!(expected_column_index, column.master_ext_table_index());
+            expected_column_index += 1;
+        }
+        for column in TapeExtTableColumn::iter() {
+            assert_eq!(expected_column_index, column.master_ext_table_index());
+            expected_column_index += 1;
+        }
+        for column in MemoryExtTableColumn::iter() {
+            assert_eq!(expected_column_index, column.master_ext_table_index());
+            expected_column_index += 1;
+        }
     }
 }
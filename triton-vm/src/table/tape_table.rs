@@ -0,0 +1,319 @@
+use ndarray::parallel::prelude::*;
+use ndarray::s;
+use ndarray::Array1;
+use ndarray::ArrayView2;
+use ndarray::ArrayViewMut2;
+use ndarray::Axis;
+use num_traits::One;
+use num_traits::Zero;
+use strum::EnumCount;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::b_field_element::BFIELD_ONE;
+use twenty_first::shared_math::traits::Inverse;
+use twenty_first::shared_math::x_field_element::XFieldElement;
+
+use crate::table::challenges::ChallengeId::*;
+use crate::table::challenges::Challenges;
+use crate::table::constraint_circuit::ConstraintCircuit;
+use crate::table::constraint_circuit::ConstraintCircuitBuilder;
+use crate::table::constraint_circuit::ConstraintCircuitMonad;
+use crate::table::constraint_circuit::DualRowIndicator;
+use crate::table::constraint_circuit::DualRowIndicator::*;
+use crate::table::constraint_circuit::SingleRowIndicator;
+use crate::table::constraint_circuit::SingleRowIndicator::*;
+use crate::table::cross_table_argument::CrossTableArg;
+use crate::table::cross_table_argument::EvalArg;
+use crate::table::cross_table_argument::LookupArg;
+use crate::table::table_column::MasterBaseTableColumn;
+use crate::table::table_column::MasterExtTableColumn;
+use crate::table::table_column::TapeBaseTableColumn;
+use crate::table::table_column::TapeBaseTableColumn::*;
+use crate::table::table_column::TapeExtTableColumn;
+use crate::table::table_column::TapeExtTableColumn::*;
+use crate::table::table_column::ZmipsProcessorBaseTableColumn;
+use crate::vm::AlgebraicExecutionTrace;
+
+pub const BASE_WIDTH: usize = TapeBaseTableColumn::COUNT;
+pub const EXT_WIDTH: usize = TapeExtTableColumn::COUNT;
+pub const FULL_WIDTH: usize = BASE_WIDTH + EXT_WIDTH;
+
+/// The Tape Table holds the input tapes of the zMIPS register machine: first the public input
+/// tape, then the secret input tape. Every symbol is listed together with its position on the
+/// tape and the number of times the zMIPS Processor Table read it.
+///
+/// Reading a symbol is a lookup into the Tape Table, which allows moving the input tapes' cursors
+/// freely using instructions `pubseek` and `secseek`. The reading services of instruction
+/// `syscall` read from the same cursors as `pubread` and `secread`. An Evaluation Argument over
+/// the public input tape establishes that it equals the public input. The secret input tape is
+/// not constrained beyond every position holding exactly one symbol.
+#[derive(Debug, Clone)]
+pub struct TapeTable {}
+
+#[derive(Debug, Clone)]
+pub struct ExtTapeTable {}
+
+impl TapeTable {
+    pub fn fill_trace(
+        tape_table: &mut ArrayViewMut2<BFieldElement>,
+        aet: &AlgebraicExecutionTrace,
+    ) {
+        let public_tape_len = aet.public_input_tape.len();
+        let secret_tape_len = aet.secret_input_tape.len();
+
+        // compute the lookup multiplicities of the tapes' symbols
+        let mut public_multiplicities = Array1::zeros([public_tape_len]);
+        let mut secret_multiplicities = Array1::zeros([secret_tape_len]);
+        for row in aet.zmips_processor_trace.rows() {
+            let is_public_read = row[ZmipsProcessorBaseTableColumn::IsPubread.base_table_index()]
+                + row[ZmipsProcessorBaseTableColumn::SyscallReadInt.base_table_index()];
+            let is_secret_read = row[ZmipsProcessorBaseTableColumn::IsSecread.base_table_index()]
+                + row[ZmipsProcessorBaseTableColumn::SyscallReadSecretInt.base_table_index()];
+            if is_public_read.is_one() {
+                let position = row[ZmipsProcessorBaseTableColumn::PubCursor.base_table_index()];
+                let position = position.value() as usize;
+                match position < public_tape_len {
+                    true => public_multiplicities[position] += BFIELD_ONE,
+                    false => panic!(
+                        "Public input tape: position {position} must be less than {public_tape_len}."
+                    ),
+                }
+            }
+            if is_secret_read.is_one() {
+                let position = row[ZmipsProcessorBaseTableColumn::SecCursor.base_table_index()];
+                let position = position.value() as usize;
+                match position < secret_tape_len {
+                    true => secret_multiplicities[position] += BFIELD_ONE,
+                    false => panic!(
+                        "Secret input tape: position {position} must be less than {secret_tape_len}."
+                    ),
+                }
+            }
+        }
+
+        let public_section = s![0..public_tape_len, ..];
+        let mut public_tape_section = tape_table.slice_mut(public_section);
+        Self::fill_section(
+            &mut public_tape_section,
+            &aet.public_input_tape,
+            public_multiplicities,
+        );
+
+        let secret_section = s![public_tape_len..public_tape_len + secret_tape_len, ..];
+        let mut secret_tape_section = tape_table.slice_mut(secret_section);
+        secret_tape_section
+            .column_mut(IsSecret.base_table_index())
+            .fill(BFieldElement::one());
+        Self::fill_section(
+            &mut secret_tape_section,
+            &aet.secret_input_tape,
+            secret_multiplicities,
+        );
+    }
+
+    fn fill_section(
+        section: &mut ArrayViewMut2<BFieldElement>,
+        tape: &[BFieldElement],
+        lookup_multiplicities: Array1<BFieldElement>,
+    ) {
+        let positions = Array1::from_iter((0..tape.len()).map(|i| BFieldElement::new(i as u64)));
+        positions.move_into(section.column_mut(Position.base_table_index()));
+        Array1::from_vec(tape.to_vec()).move_into(section.column_mut(Value.base_table_index()));
+        lookup_multiplicities.move_into(section.column_mut(LookupMultiplicity.base_table_index()));
+    }
+
+    pub fn pad_trace(tape_table: &mut ArrayViewMut2<BFieldElement>, tape_table_len: usize) {
+        let mut padding_template = match tape_table_len {
+            0 => Array1::zeros(BASE_WIDTH),
+            _ => tape_table.row(tape_table_len - 1).to_owned(),
+        };
+        padding_template[IsPadding.base_table_index()] = BFieldElement::one();
+        padding_template[LookupMultiplicity.base_table_index()] = BFieldElement::zero();
+        tape_table
+            .slice_mut(s![tape_table_len.., ..])
+            .axis_iter_mut(Axis(0))
+            .into_par_iter()
+            .for_each(|mut row| row.assign(&padding_template));
+    }
+
+    pub fn extend(
+        base_table: ArrayView2<BFieldElement>,
+        mut ext_table: ArrayViewMut2<XFieldElement>,
+        challenges: &Challenges,
+    ) {
+        assert_eq!(BASE_WIDTH, base_table.ncols());
+        assert_eq!(EXT_WIDTH, ext_table.ncols());
+        assert_eq!(base_table.nrows(), ext_table.nrows());
+
+        let is_secret_weight = challenges.get_challenge(TapeIsSecretWeight);
+        let position_weight = challenges.get_challenge(TapePositionWeight);
+        let value_weight = challenges.get_challenge(TapeValueWeight);
+        let lookup_indeterminate = challenges.get_challenge(TapeIndeterminate);
+        let public_input_indeterminate = challenges.get_challenge(StandardInputIndeterminate);
+
+        let mut public_input_running_evaluation = EvalArg::default_initial();
+        let mut lookup_log_derivative = LookupArg::default_initial();
+
+        for row_idx in 0..base_table.nrows() {
+            let current_row = base_table.row(row_idx);
+
+            if current_row[IsPadding.base_table_index()].is_zero() {
+                let is_secret = current_row[IsSecret.base_table_index()];
+                let position = current_row[Position.base_table_index()];
+                let value = current_row[Value.base_table_index()];
+
+                // evaluation argument with the public input
+                if is_secret.is_zero() {
+                    public_input_running_evaluation =
+                        public_input_running_evaluation * public_input_indeterminate + value;
+                }
+
+                // lookup argument with the zMIPS Processor Table
+                let lookup_multiplicity = current_row[LookupMultiplicity.base_table_index()];
+                let compressed_row = is_secret * is_secret_weight
+                    + position * position_weight
+                    + value * value_weight;
+                lookup_log_derivative +=
+                    (lookup_indeterminate - compressed_row).inverse() * lookup_multiplicity;
+            }
+
+            let mut extension_row = ext_table.row_mut(row_idx);
+            extension_row[PublicInputEvalArg.ext_table_index()] = public_input_running_evaluation;
+            extension_row[LookupServerLogDerivative.ext_table_index()] = lookup_log_derivative;
+        }
+    }
+}
+
+impl ExtTapeTable {
+    pub fn ext_initial_constraints_as_circuits() -> Vec<ConstraintCircuit<SingleRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let one = circuit_builder.b_constant(1_u32.into());
+        let base_row = |col: TapeBaseTableColumn| {
+            circuit_builder.input(BaseRow(col.master_base_table_index()))
+        };
+        let ext_row =
+            |col: TapeExtTableColumn| circuit_builder.input(ExtRow(col.master_ext_table_index()));
+
+        let first_position_is_0 = base_row(Position);
+
+        // If the zMIPS processor does not execute the program, the Tape Table only consists of
+        // padding. In particular, it does not contribute to the public input.
+        let zmips_processor_is_padding = circuit_builder.input(BaseRow(
+            ZmipsProcessorBaseTableColumn::IsPadding.master_base_table_index(),
+        ));
+        let tape_is_padding_if_zmips_processor_is_padding =
+            zmips_processor_is_padding * (one.clone() - base_row(IsPadding));
+
+        let row_is_public =
+            (one.clone() - base_row(IsPadding)) * (one.clone() - base_row(IsSecret));
+        let public_input_eval_default_initial =
+            circuit_builder.x_constant(EvalArg::default_initial());
+        let public_input_running_evaluation_is_initialized_correctly = row_is_public.clone()
+            * (ext_row(PublicInputEvalArg)
+                - circuit_builder.challenge(StandardInputIndeterminate)
+                    * public_input_eval_default_initial.clone()
+                - base_row(Value))
+            + (one - row_is_public)
+                * (ext_row(PublicInputEvalArg) - public_input_eval_default_initial);
+
+        // note: the multiplicity is 0 in padding rows, and `position` is already constrained to
+        // be 0
+        let compressed_row = circuit_builder.challenge(TapeIsSecretWeight) * base_row(IsSecret)
+            + circuit_builder.challenge(TapeValueWeight) * base_row(Value);
+        let lookup_log_derivative_is_initialized_correctly = ext_row(LookupServerLogDerivative)
+            * (circuit_builder.challenge(TapeIndeterminate) - compressed_row)
+            - base_row(LookupMultiplicity);
+
+        let mut constraints = [
+            first_position_is_0,
+            tape_is_padding_if_zmips_processor_is_padding,
+            public_input_running_evaluation_is_initialized_correctly,
+            lookup_log_derivative_is_initialized_correctly,
+        ];
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints.map(|circuit| circuit.consume()).to_vec()
+    }
+
+    pub fn ext_consistency_constraints_as_circuits() -> Vec<ConstraintCircuit<SingleRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let one = circuit_builder.b_constant(1_u32.into());
+        let base_row = |col: TapeBaseTableColumn| {
+            circuit_builder.input(BaseRow(col.master_base_table_index()))
+        };
+        let is_bit = |col| base_row(col) * (base_row(col) - one.clone());
+
+        let is_padding_is_bit = is_bit(IsPadding);
+        let is_secret_is_bit = is_bit(IsSecret);
+        let lookup_multiplicity_is_0_in_padding_section =
+            base_row(IsPadding) * base_row(LookupMultiplicity);
+
+        let mut constraints = [
+            is_padding_is_bit,
+            is_secret_is_bit,
+            lookup_multiplicity_is_0_in_padding_section,
+        ];
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints.map(|circuit| circuit.consume()).to_vec()
+    }
+
+    pub fn ext_transition_constraints_as_circuits() -> Vec<ConstraintCircuit<DualRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let one = circuit_builder.b_constant(1_u32.into());
+        let curr_base_row = |col: TapeBaseTableColumn| {
+            circuit_builder.input(CurrentBaseRow(col.master_base_table_index()))
+        };
+        let next_base_row = |col: TapeBaseTableColumn| {
+            circuit_builder.input(NextBaseRow(col.master_base_table_index()))
+        };
+        let curr_ext_row = |col: TapeExtTableColumn| {
+            circuit_builder.input(CurrentExtRow(col.master_ext_table_index()))
+        };
+        let next_ext_row = |col: TapeExtTableColumn| {
+            circuit_builder.input(NextExtRow(col.master_ext_table_index()))
+        };
+
+        let is_padding_is_0_or_remains_1 =
+            curr_base_row(IsPadding) * (next_base_row(IsPadding) - one.clone());
+        let is_secret_is_0_or_remains_1 =
+            curr_base_row(IsSecret) * (next_base_row(IsSecret) - one.clone());
+
+        // Outside of the padding section, the position increases by 1, except when switching
+        // from the public to the secret input tape, where it is reset to 0.
+        let tape_switches = next_base_row(IsSecret) - curr_base_row(IsSecret);
+        let position_increments_or_resets = (one.clone() - next_base_row(IsPadding))
+            * (next_base_row(Position)
+                - (one.clone() - tape_switches) * (curr_base_row(Position) + one.clone()));
+
+        let next_row_is_public =
+            (one.clone() - next_base_row(IsPadding)) * (one.clone() - next_base_row(IsSecret));
+        let public_input_running_evaluation_updates_correctly = next_ext_row(PublicInputEvalArg)
+            - next_row_is_public.clone()
+                * (curr_ext_row(PublicInputEvalArg)
+                    * circuit_builder.challenge(StandardInputIndeterminate)
+                    + next_base_row(Value))
+            - (one - next_row_is_public) * curr_ext_row(PublicInputEvalArg);
+
+        // note: the multiplicity is 0 in padding rows
+        let compressed_row = circuit_builder.challenge(TapeIsSecretWeight)
+            * next_base_row(IsSecret)
+            + circuit_builder.challenge(TapePositionWeight) * next_base_row(Position)
+            + circuit_builder.challenge(TapeValueWeight) * next_base_row(Value);
+        let lookup_log_derivative_updates_correctly = (next_ext_row(LookupServerLogDerivative)
+            - curr_ext_row(LookupServerLogDerivative))
+            * (circuit_builder.challenge(TapeIndeterminate) - compressed_row)
+            - next_base_row(LookupMultiplicity);
+
+        let mut constraints = [
+            is_padding_is_0_or_remains_1,
+            is_secret_is_0_or_remains_1,
+            position_increments_or_resets,
+            public_input_running_evaluation_updates_correctly,
+            lookup_log_derivative_updates_correctly,
+        ];
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints.map(|circuit| circuit.consume()).to_vec()
+    }
+
+    pub fn ext_terminal_constraints_as_circuits() -> Vec<ConstraintCircuit<SingleRowIndicator>> {
+        vec![]
+    }
+}
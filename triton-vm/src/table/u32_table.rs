@@ -448,7 +448,11 @@ impl U32Table {
         {
             section[[row_idx, Result.base_table_index()]] = match current_instruction {
                 Instruction::Split => zero,
-                Instruction::Lt => two,
+                // `0 < 0` is false, the result is only unknown below the section's first row
+                Instruction::Lt => match section[[row_idx, CopyFlag.base_table_index()]].is_one() {
+                    true => zero,
+                    false => two,
+                },
                 Instruction::And => zero,
                 Instruction::Log2Floor => -one,
                 Instruction::Pow => one,
@@ -540,6 +544,13 @@ impl U32Table {
             padding_row[[LHS.base_table_index()]] = last_row[LHS.base_table_index()];
             padding_row[[LhsInv.base_table_index()]] = last_row[LhsInv.base_table_index()];
             padding_row[[Result.base_table_index()]] = last_row[Result.base_table_index()];
+
+            // If the last non-padding row is the only row of a section for instruction `lt` on
+            // operands 0 and 0, column `Result` is 0. In the padding section, where `CopyFlag` is
+            // always 0, `Result` needs to be 2 instead.
+            if padding_row[[CI.base_table_index()]] == Instruction::Lt.opcode_b() {
+                padding_row[[Result.base_table_index()]] = BFieldElement::new(2);
+            }
         }
 
         u32_table
@@ -0,0 +1,1573 @@
+use itertools::Itertools;
+use ndarray::parallel::prelude::*;
+use ndarray::s;
+use ndarray::Array1;
+use ndarray::ArrayView1;
+use ndarray::ArrayView2;
+use ndarray::ArrayViewMut1;
+use ndarray::ArrayViewMut2;
+use ndarray::Axis;
+use num_traits::One;
+use num_traits::Zero;
+use strum::EnumCount;
+use strum::IntoEnumIterator;
+use triton_opcodes::instruction::Instruction as TritonInstruction;
+use triton_zmips::instruction::Instruction;
+use triton_zmips::instruction::Syscall;
+use triton_zmips::instruction::ALL_INSTRUCTIONS;
+use triton_zmips::instruction::IMM_SHIFT;
+use triton_zmips::instruction::IS_IMM_SHIFT;
+use triton_zmips::instruction::RD_SHIFT;
+use triton_zmips::instruction::RS_SHIFT;
+use triton_zmips::instruction::RT_SHIFT;
+use triton_zmips::regs::Reg;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+use twenty_first::shared_math::b_field_element::BFIELD_ONE;
+use twenty_first::shared_math::traits::Inverse;
+use twenty_first::shared_math::x_field_element::XFieldElement;
+
+use crate::table::challenges::ChallengeId::*;
+use crate::table::challenges::Challenges;
+use crate::table::constraint_circuit::ConstraintCircuit;
+use crate::table::constraint_circuit::ConstraintCircuitBuilder;
+use crate::table::constraint_circuit::ConstraintCircuitMonad;
+use crate::table::constraint_circuit::DualRowIndicator;
+use crate::table::constraint_circuit::DualRowIndicator::*;
+use crate::table::constraint_circuit::InputIndicator;
+use crate::table::constraint_circuit::SingleRowIndicator;
+use crate::table::constraint_circuit::SingleRowIndicator::*;
+use crate::table::cross_table_argument::CrossTableArg;
+use crate::table::cross_table_argument::EvalArg;
+use crate::table::cross_table_argument::LookupArg;
+use crate::table::cross_table_argument::PermArg;
+use crate::table::table_column::MasterBaseTableColumn;
+use crate::table::table_column::MasterExtTableColumn;
+use crate::table::table_column::ProcessorBaseTableColumn;
+use crate::table::table_column::ZmipsProcessorBaseTableColumn;
+use crate::table::table_column::ZmipsProcessorBaseTableColumn::*;
+use crate::table::table_column::ZmipsProcessorExtTableColumn;
+use crate::table::table_column::ZmipsProcessorExtTableColumn::*;
+use crate::vm::AlgebraicExecutionTrace;
+
+pub const BASE_WIDTH: usize = ZmipsProcessorBaseTableColumn::COUNT;
+pub const EXT_WIDTH: usize = ZmipsProcessorExtTableColumn::COUNT;
+pub const FULL_WIDTH: usize = BASE_WIDTH + EXT_WIDTH;
+
+/// The instruction flags are laid out in the order of the opcodes.
+const _: () = assert!(IsSyscall as usize - IsBeq as usize + 1 == Instruction::COUNT);
+
+/// The flags indicating the service requested by instruction `syscall`, in the order of
+/// [`Syscall`]. For every `syscall`, exactly one of them is set, namely the one whose
+/// [`Syscall::code`] is the content of `$v0`. A `syscall` with an unknown code cannot be proven.
+pub const SYSCALL_SERVICE_FLAGS: [ZmipsProcessorBaseTableColumn; Syscall::COUNT] = [
+    SyscallPrintInt,
+    SyscallReadInt,
+    SyscallExit,
+    SyscallExit2,
+    SyscallReadSecretInt,
+    SyscallAnswer,
+];
+
+/// The instructions and `syscall` services after which the instruction pointer is incremented
+/// by 1.
+const SEQUENTIAL_INSTRUCTION_FLAGS: [ZmipsProcessorBaseTableColumn; 34] = [
+    IsSeq,
+    IsSne,
+    IsSlt,
+    IsSltu,
+    IsSle,
+    IsLw,
+    IsSw,
+    IsAdd,
+    IsAddu,
+    IsSub,
+    IsSubu,
+    IsMult,
+    IsDiv,
+    IsDivu,
+    IsMod,
+    IsMove,
+    IsLa,
+    IsAnd,
+    IsXor,
+    IsOr,
+    IsNot,
+    IsSll,
+    IsSrl,
+    IsSra,
+    IsPubread,
+    IsSecread,
+    IsPubseek,
+    IsSecseek,
+    IsPrint,
+    IsAnswer,
+    SyscallPrintInt,
+    SyscallReadInt,
+    SyscallReadSecretInt,
+    SyscallAnswer,
+];
+
+/// The instructions writing to their destination register `rd`. All other instructions only read
+/// register `rd`.
+pub const REGISTER_WRITING_INSTRUCTION_FLAGS: [ZmipsProcessorBaseTableColumn; 28] = [
+    IsSeq, IsSne, IsSlt, IsSltu, IsSle, IsJal, IsJalr, IsLw, IsAdd, IsAddu, IsSub, IsSubu, IsMult,
+    IsDiv, IsDivu, IsMod, IsMove, IsLa, IsAnd, IsXor, IsOr, IsNot, IsSll, IsSrl, IsSra, IsPubread,
+    IsSecread, IsSyscall,
+];
+
+/// The columns of the four slots for lookups into the U32 Table, each as
+/// `[ci, lhs, rhs, result]`.
+pub const U32_LOOKUP_SLOTS: [[ZmipsProcessorBaseTableColumn; 4]; 4] = [
+    [U32LookupCi0, U32LookupLhs0, U32LookupRhs0, U32LookupResult0],
+    [U32LookupCi1, U32LookupLhs1, U32LookupRhs1, U32LookupResult1],
+    [U32LookupCi2, U32LookupLhs2, U32LookupRhs2, U32LookupResult2],
+    [U32LookupCi3, U32LookupLhs3, U32LookupRhs3, U32LookupResult3],
+];
+
+const TWO_POW_31: u64 = 1 << 31;
+const TWO_POW_32: u64 = 1 << 32;
+
+/// The index of the flag indicating execution of the given instruction in the zMIPS Processor
+/// Table.
+pub fn instruction_flag_index(instruction: &Instruction) -> usize {
+    IsBeq.base_table_index() + instruction.opcode() as usize
+}
+
+/// The zMIPS Processor Table records the state of the zMIPS register machine, one row per
+/// executed instruction. A program is executed by either the Processor Table or the zMIPS
+/// Processor Table, never by both. The table of the processor not executing the program only
+/// consists of padding.
+///
+/// The table reads its instructions from the Program Table and writes its output to the same
+/// evaluation argument as the Processor Table. Instructions `pubread` and `secread` read the
+/// symbol under the cursor of the public and secret input tape, respectively, from the Tape
+/// Table. Instructions `pubseek` and `secseek` move a cursor to position `rs_val + operand`
+/// modulo 2^32, after which reading continues from there. Instruction `syscall` dispatches on the
+/// code in register `$v0` to the equivalent of `print`, `pubread`, `exit`, `secread`, or `answer`;
+/// the requested service is indicated by one of the `Syscall…` flags. Consistency of the register
+/// values between instructions is established by the Register Table, consistency of the memory
+/// accessed by instructions `lw` and `sw` by the Memory Table. Both tables look up their clock
+/// jump differences in this table. Since padding rows have lookup multiplicity 0, every clock
+/// jump difference is smaller than the number of executed instructions.
+///
+/// The exit code of the program and its final answer, _i.e._, the argument of the last executed
+/// `answer`, are bound to the claim by terminal constraints. Columns `FinalAnswer` and
+/// `HasFinalAnswer` carry the most recently reported answer through the table.
+///
+/// Registers hold 32-bit words. The results of the arithmetic, bitwise, and comparison
+/// instructions are established using the U32 Table: every row looks up exactly four entries,
+/// which range-check the written register, the operands' sign bits, and the helper variables `Hi`
+/// and `Lo`, or compute bitwise `and`, `lt`, and powers of 2. Instructions `add`, `sub`, `div`,
+/// `mod`, `slt`, `sle`, `blt`, `ble`, `bgt`, and `sra` interpret words as two's complement
+/// integers. Instructions `sltu`, `bltu`, and `bgeu` compare words as unsigned integers.
+#[derive(Debug, Clone)]
+pub struct ZmipsProcessorTable {}
+
+#[derive(Debug, Clone)]
+pub struct ExtZmipsProcessorTable {}
+
+impl ZmipsProcessorTable {
+    pub fn fill_trace(
+        zmips_processor_table: &mut ArrayViewMut2<BFieldElement>,
+        aet: &AlgebraicExecutionTrace,
+        clk_jump_diffs_register: &[BFieldElement],
+        clk_jump_diffs_memory: &[BFieldElement],
+    ) {
+        // compute the lookup multiplicities of the clock jump differences
+        let num_rows = aet.zmips_processor_trace.nrows();
+        let mut clk_jump_diff_multiplicities = Array1::zeros([num_rows]);
+        for clk_jump_diff in clk_jump_diffs_register.iter() {
+            let clk = clk_jump_diff.value() as usize;
+            match clk < num_rows {
+                true => clk_jump_diff_multiplicities[clk] += BFIELD_ONE,
+                false => panic!(
+                    "Register: clock jump diff {clk} must fit in trace with {num_rows} rows."
+                ),
+            }
+        }
+        for clk_jump_diff in clk_jump_diffs_memory.iter() {
+            let clk = clk_jump_diff.value() as usize;
+            match clk < num_rows {
+                true => clk_jump_diff_multiplicities[clk] += BFIELD_ONE,
+                false => {
+                    panic!("Memory: clock jump diff {clk} must fit in trace with {num_rows} rows.")
+                }
+            }
+        }
+
+        // fill the zMIPS processor table from the AET and the lookup multiplicities
+        let mut zmips_processor_table_to_fill =
+            zmips_processor_table.slice_mut(s![0..num_rows, ..]);
+        aet.zmips_processor_trace
+            .clone()
+            .move_into(&mut zmips_processor_table_to_fill);
+        zmips_processor_table_to_fill
+            .column_mut(ClockJumpDifferenceLookupMultiplicity.base_table_index())
+            .assign(&clk_jump_diff_multiplicities);
+    }
+
+    pub fn pad_trace(
+        zmips_processor_table: &mut ArrayViewMut2<BFieldElement>,
+        zmips_processor_table_len: usize,
+    ) {
+        let padding_template = match zmips_processor_table_len {
+            0 => Self::padding_row_without_execution(),
+            _ => {
+                let mut padding_template = zmips_processor_table
+                    .row(zmips_processor_table_len - 1)
+                    .to_owned();
+                padding_template[IsPadding.base_table_index()] = BFieldElement::one();
+                padding_template[ClockJumpDifferenceLookupMultiplicity.base_table_index()] =
+                    BFieldElement::zero();
+                padding_template
+            }
+        };
+        zmips_processor_table
+            .slice_mut(s![zmips_processor_table_len.., ..])
+            .axis_iter_mut(Axis(0))
+            .into_par_iter()
+            .for_each(|mut row| row.assign(&padding_template));
+
+        let clk_range = zmips_processor_table_len..zmips_processor_table.nrows();
+        let clk_col = Array1::from_iter(clk_range.map(|clk| BFieldElement::new(clk as u64)));
+        clk_col.move_into(
+            zmips_processor_table
+                .slice_mut(s![zmips_processor_table_len.., CLK.base_table_index()]),
+        );
+    }
+
+    /// The padding row used if the zMIPS register machine did not execute anything. It looks like
+    /// the padding following instruction `exit $zero` at address 0.
+    fn padding_row_without_execution() -> Array1<BFieldElement> {
+        let exit = Instruction::EXIT(Reg::Zero);
+        let mut padding_row = Array1::zeros(BASE_WIDTH);
+        padding_row[IsPadding.base_table_index()] = BFieldElement::one();
+        padding_row[CI.base_table_index()] = exit.opcode_b();
+        padding_row[IW.base_table_index()] = exit.word();
+        padding_row[instruction_flag_index(&exit)] = BFieldElement::one();
+        Self::fill_u32_helper_variables(padding_row.view_mut());
+        padding_row
+    }
+
+    /// Fill the sign bits, helper variables `Hi`, `Lo`, and `HV` where needed, and the U32 lookup
+    /// slots of the given row. Requires the row's instruction, its operands, and the destination
+    /// register's content _after_ execution to be set.
+    pub fn fill_u32_helper_variables(mut row: ArrayViewMut1<BFieldElement>) {
+        use triton_opcodes::instruction::AnInstruction::{And, Lt, Pow, Split};
+        use triton_zmips::instruction::AnInstruction::*;
+
+        let instruction: Instruction = row[CI.base_table_index()]
+            .value()
+            .try_into()
+            .expect("Unknown zMIPS instruction");
+        let rs = row[RsVal.base_table_index()].value();
+        let op = row[Operand.base_table_index()].value();
+        let rd = row[RdVal.base_table_index()].value();
+        let ramp = row[RAMP.base_table_index()].value();
+
+        let sign = |x: u64| x >> 31;
+        let (sign_rs, sign_op) = (sign(rs), sign(op));
+        row[SignRs.base_table_index()] = BFieldElement::new(sign_rs);
+        row[SignOp.base_table_index()] = BFieldElement::new(sign_op);
+        row[SignRd.base_table_index()] = BFieldElement::new(sign(rd));
+
+        // `x` without its sign bit, shifted left by 1, is a u32 if and only if the sign bit is
+        // correct
+        let sign_check = |x: u64| 2 * x - sign(x) * TWO_POW_32;
+        let magnitude = |x: u64| match sign(x) {
+            0 => x,
+            _ => TWO_POW_32 - x,
+        };
+        let flip_sign_bit = |x: u64| x ^ TWO_POW_31;
+        let signs_lookup = (Split, sign_check(rs), sign_check(op), 0);
+        let shift_amount = op & 31;
+
+        let mut hi = 0;
+        let mut lo = 0;
+        let lookups: Vec<(TritonInstruction, _, _, _)> = match instruction {
+            ADD(_) | SUB(_) => vec![signs_lookup, (Split, sign_check(rd), 0, 0)],
+            ADDU(_) | SUBU(_) | PUBREAD(_) | SECREAD(_) | SYSCALL => vec![(Split, rd, 0, 0)],
+            LW(_) => vec![(Split, rd, 0, 0), (Split, ramp, 0, 0)],
+            SW(_) | PUBSEEK(_) | SECSEEK(_) => vec![(Split, ramp, 0, 0)],
+            MULT(_) => {
+                (hi, lo) = ((rs * op) >> 32, rs * op % TWO_POW_32);
+                vec![(Split, hi, lo, 0)]
+            }
+            DIVU(_) => {
+                (hi, lo) = (rs / op, rs % op);
+                vec![(Lt, lo, op, 1), (Split, hi, 0, 0)]
+            }
+            DIV(_) | MOD(_) => {
+                let (dividend, divisor) = (magnitude(rs), magnitude(op));
+                (hi, lo) = (dividend / divisor, dividend % divisor);
+                // the quotient of `div` must fit into a signed word
+                let quotient_range_check = match instruction {
+                    DIV(_) => TWO_POW_31 - 1 + (sign_rs ^ sign_op) - hi,
+                    _ => 0,
+                };
+                vec![
+                    (Lt, lo, divisor, 1),
+                    signs_lookup,
+                    (Split, hi, quotient_range_check, 0),
+                ]
+            }
+            AND(_) => vec![(And, rs, op, rd)],
+            OR(_) | XOR(_) => {
+                lo = rs & op;
+                vec![(And, rs, op, lo)]
+            }
+            SLL(_) => {
+                let power = 1 << shift_amount;
+                (hi, lo) = ((rs * power) >> 32, rs * power % TWO_POW_32);
+                vec![
+                    (And, op, 31, shift_amount),
+                    (Pow, 2, shift_amount, power),
+                    (Split, hi, lo, 0),
+                ]
+            }
+            SRL(_) | SRA(_) => {
+                let power = 1 << (32 - shift_amount);
+                (hi, lo) = ((rs * power) >> 32, rs * power % TWO_POW_32);
+                let mut lookups = vec![
+                    (And, op, 31, shift_amount),
+                    (Pow, 2, 32 - shift_amount, power),
+                    (Split, hi, lo, 0),
+                ];
+                if matches!(instruction, SRA(_)) {
+                    lookups.push((Split, sign_check(rs), 0, 0));
+                }
+                lookups
+            }
+            SLT(_) => vec![(Lt, flip_sign_bit(rs), flip_sign_bit(op), rd), signs_lookup],
+            SLE(_) => vec![
+                (Lt, flip_sign_bit(op), flip_sign_bit(rs), 1 - rd),
+                signs_lookup,
+            ],
+            SLTU(_) => vec![(Lt, rs, op, rd)],
+            BLT(_) => {
+                let is_less = flip_sign_bit(rs) < flip_sign_bit(op);
+                vec![
+                    (Lt, flip_sign_bit(rs), flip_sign_bit(op), is_less as u64),
+                    signs_lookup,
+                ]
+            }
+            BLE(_) | BGT(_) => {
+                let is_greater = flip_sign_bit(op) < flip_sign_bit(rs);
+                vec![
+                    (Lt, flip_sign_bit(op), flip_sign_bit(rs), is_greater as u64),
+                    signs_lookup,
+                ]
+            }
+            BLTU(_) | BGEU(_) => vec![(Lt, rs, op, (rs < op) as u64)],
+            _ => vec![],
+        };
+
+        row[Hi.base_table_index()] = BFieldElement::new(hi);
+        row[Lo.base_table_index()] = BFieldElement::new(lo);
+        match instruction {
+            MULT(_) | SLL(_) | SRL(_) | SRA(_) => {
+                row[HV.base_table_index()] =
+                    (BFieldElement::new(hi) - BFieldElement::new(TWO_POW_32 - 1)).inverse_or_zero();
+            }
+            DIV(_) => row[HV.base_table_index()] = BFieldElement::new(hi).inverse_or_zero(),
+            MOD(_) => row[HV.base_table_index()] = BFieldElement::new(lo).inverse_or_zero(),
+            _ => (),
+        }
+
+        let unused_slot = (Split, 0, 0, 0);
+        let lookups = lookups.into_iter().chain(std::iter::repeat(unused_slot));
+        for ([ci, lhs, rhs, result], (u32_instruction, lhs_val, rhs_val, result_val)) in
+            U32_LOOKUP_SLOTS.into_iter().zip(lookups)
+        {
+            row[ci.base_table_index()] = u32_instruction.opcode_b();
+            row[lhs.base_table_index()] = BFieldElement::new(lhs_val);
+            row[rhs.base_table_index()] = BFieldElement::new(rhs_val);
+            row[result.base_table_index()] = BFieldElement::new(result_val);
+        }
+    }
+
+    /// The entries the given row looks up in the U32 Table, as `(instruction, lhs, rhs)`.
+    pub fn u32_table_entries(
+        row: ArrayView1<BFieldElement>,
+    ) -> [(TritonInstruction, BFieldElement, BFieldElement); 4] {
+        U32_LOOKUP_SLOTS.map(|[ci, lhs, rhs, _]| {
+            let instruction = row[ci.base_table_index()]
+                .value()
+                .try_into()
+                .expect("Unknown u32 instruction");
+            (
+                instruction,
+                row[lhs.base_table_index()],
+                row[rhs.base_table_index()],
+            )
+        })
+    }
+
+    /// The accesses of the given row to the register file as triples
+    /// `(register index, register value, is write)`, in the order they happen: first, registers
+    /// `rs` and `rt` are read, then register `rd` is either written to or read.
+    pub fn register_accesses(row: ArrayView1<BFieldElement>) -> [[BFieldElement; 3]; 3] {
+        let is_write = REGISTER_WRITING_INSTRUCTION_FLAGS
+            .iter()
+            .map(|flag| row[flag.base_table_index()])
+            .sum();
+        let zero = BFieldElement::zero();
+        [
+            [
+                row[RS.base_table_index()],
+                row[RsVal.base_table_index()],
+                zero,
+            ],
+            [
+                row[RT.base_table_index()],
+                row[RtVal.base_table_index()],
+                zero,
+            ],
+            [
+                row[RD.base_table_index()],
+                row[RdVal.base_table_index()],
+                is_write,
+            ],
+        ]
+    }
+
+    /// The access of the given row to memory as `[clk, address, value, is write]`, if the row
+    /// executes `lw` or `sw`.
+    pub fn memory_access(row: ArrayView1<BFieldElement>) -> Option<[BFieldElement; 4]> {
+        let is_write = row[IsSw.base_table_index()];
+        let is_access = row[IsLw.base_table_index()] + is_write;
+        if row[IsPadding.base_table_index()].is_one() || is_access.is_zero() {
+            return None;
+        }
+        Some([
+            row[CLK.base_table_index()],
+            row[RAMP.base_table_index()],
+            row[RAMV.base_table_index()],
+            is_write,
+        ])
+    }
+
+    pub fn extend(
+        base_table: ArrayView2<BFieldElement>,
+        mut ext_table: ArrayViewMut2<XFieldElement>,
+        challenges: &Challenges,
+    ) {
+        assert_eq!(BASE_WIDTH, base_table.ncols());
+        assert_eq!(EXT_WIDTH, ext_table.ncols());
+        assert_eq!(base_table.nrows(), ext_table.nrows());
+
+        let mut tape_lookup_log_derivative = LookupArg::default_initial();
+        let mut output_table_running_evaluation = EvalArg::default_initial();
+        let mut instruction_lookup_log_derivative = LookupArg::default_initial();
+        let mut register_table_running_product = PermArg::default_initial();
+        let mut memory_table_running_product = PermArg::default_initial();
+        let mut u32_lookup_log_derivative = LookupArg::default_initial();
+        let mut clock_jump_diff_lookup_log_derivative = LookupArg::default_initial();
+
+        for row_idx in 0..base_table.nrows() {
+            let current_row = base_table.row(row_idx);
+
+            // Tape table
+            let is_pubread = current_row[IsPubread.base_table_index()]
+                + current_row[SyscallReadInt.base_table_index()];
+            let is_secread = current_row[IsSecread.base_table_index()]
+                + current_row[SyscallReadSecretInt.base_table_index()];
+            if is_pubread.is_one() || is_secread.is_one() {
+                let (is_secret, position) = match is_secread.is_one() {
+                    true => (
+                        BFieldElement::one(),
+                        current_row[SecCursor.base_table_index()],
+                    ),
+                    false => (
+                        BFieldElement::zero(),
+                        current_row[PubCursor.base_table_index()],
+                    ),
+                };
+                let symbol = current_row[RdVal.base_table_index()];
+                let compressed_row_for_tape_lookup = is_secret
+                    * challenges.get_challenge(TapeIsSecretWeight)
+                    + position * challenges.get_challenge(TapePositionWeight)
+                    + symbol * challenges.get_challenge(TapeValueWeight);
+                tape_lookup_log_derivative += (challenges.get_challenge(TapeIndeterminate)
+                    - compressed_row_for_tape_lookup)
+                    .inverse();
+            }
+
+            // Output table
+            let is_print = current_row[IsPrint.base_table_index()].is_one();
+            let is_print_syscall = current_row[SyscallPrintInt.base_table_index()].is_one();
+            if is_print || is_print_syscall {
+                let output_symbol = match is_print {
+                    true => current_row[RsVal.base_table_index()],
+                    false => current_row[RtVal.base_table_index()],
+                };
+                output_table_running_evaluation = output_table_running_evaluation
+                    * challenges.get_challenge(StandardOutputIndeterminate)
+                    + output_symbol;
+            }
+
+            // Program table
+            if current_row[IsPadding.base_table_index()].is_zero() {
+                let ip = current_row[IP.base_table_index()];
+                let iw = current_row[IW.base_table_index()];
+                let niw = current_row[NIW.base_table_index()];
+                let compressed_row_for_instruction_lookup = ip
+                    * challenges.get_challenge(ProgramAddressWeight)
+                    + iw * challenges.get_challenge(ProgramInstructionWeight)
+                    + niw * challenges.get_challenge(ProgramNextInstructionWeight);
+                instruction_lookup_log_derivative += (challenges
+                    .get_challenge(InstructionLookupIndeterminate)
+                    - compressed_row_for_instruction_lookup)
+                    .inverse();
+            }
+
+            // Register table
+            if current_row[IsPadding.base_table_index()].is_zero() {
+                for [register, value, is_write] in Self::register_accesses(current_row) {
+                    let compressed_row_for_register_access = current_row[CLK.base_table_index()]
+                        * challenges.get_challenge(RegisterClkWeight)
+                        + register * challenges.get_challenge(RegisterIndexWeight)
+                        + value * challenges.get_challenge(RegisterValueWeight)
+                        + is_write * challenges.get_challenge(RegisterIsWriteWeight);
+                    register_table_running_product *= challenges
+                        .get_challenge(RegisterIndeterminate)
+                        - compressed_row_for_register_access;
+                }
+            }
+
+            // Memory table
+            if let Some([clk, address, value, is_write]) = Self::memory_access(current_row) {
+                let compressed_row_for_memory_access = clk
+                    * challenges.get_challenge(MemoryClkWeight)
+                    + address * challenges.get_challenge(MemoryAddressWeight)
+                    + value * challenges.get_challenge(MemoryValueWeight)
+                    + is_write * challenges.get_challenge(MemoryIsWriteWeight);
+                memory_table_running_product *= challenges.get_challenge(MemoryIndeterminate)
+                    - compressed_row_for_memory_access;
+            }
+
+            // U32 table
+            if current_row[IsPadding.base_table_index()].is_zero() {
+                for [ci, lhs, rhs, result] in U32_LOOKUP_SLOTS {
+                    let compressed_row_for_u32_lookup = current_row[ci.base_table_index()]
+                        * challenges.get_challenge(U32CiWeight)
+                        + current_row[lhs.base_table_index()]
+                            * challenges.get_challenge(U32LhsWeight)
+                        + current_row[rhs.base_table_index()]
+                            * challenges.get_challenge(U32RhsWeight)
+                        + current_row[result.base_table_index()]
+                            * challenges.get_challenge(U32ResultWeight);
+                    u32_lookup_log_derivative += (challenges.get_challenge(U32Indeterminate)
+                        - compressed_row_for_u32_lookup)
+                        .inverse();
+                }
+            }
+
+            // Lookup Argument for clock jump differences with the Register Table and the Memory
+            // Table
+            let lookup_multiplicity =
+                current_row[ClockJumpDifferenceLookupMultiplicity.base_table_index()];
+            let clk = current_row[CLK.base_table_index()];
+            clock_jump_diff_lookup_log_derivative +=
+                (challenges.get_challenge(ClockJumpDifferenceLookupIndeterminate) - clk).inverse()
+                    * lookup_multiplicity;
+
+            let mut extension_row = ext_table.row_mut(row_idx);
+            extension_row[TapeLookupClientLogDerivative.ext_table_index()] =
+                tape_lookup_log_derivative;
+            extension_row[OutputTableEvalArg.ext_table_index()] = output_table_running_evaluation;
+            extension_row[InstructionLookupClientLogDerivative.ext_table_index()] =
+                instruction_lookup_log_derivative;
+            extension_row[RegisterTablePermArg.ext_table_index()] = register_table_running_product;
+            extension_row[MemoryTablePermArg.ext_table_index()] = memory_table_running_product;
+            extension_row[U32LookupClientLogDerivative.ext_table_index()] =
+                u32_lookup_log_derivative;
+            extension_row[ClockJumpDifferenceLookupServerLogDerivative.ext_table_index()] =
+                clock_jump_diff_lookup_log_derivative;
+        }
+    }
+}
+
+impl ExtZmipsProcessorTable {
+    /// 1 if the row reads from the public input tape, using either `pubread` or `syscall`, else 0.
+    fn is_public_tape_read<II: InputIndicator>(
+        row: impl Fn(ZmipsProcessorBaseTableColumn) -> ConstraintCircuitMonad<II>,
+    ) -> ConstraintCircuitMonad<II> {
+        row(IsPubread) + row(SyscallReadInt)
+    }
+
+    /// 1 if the row reads from the secret input tape, using either `secread` or `syscall`, else 0.
+    fn is_secret_tape_read<II: InputIndicator>(
+        row: impl Fn(ZmipsProcessorBaseTableColumn) -> ConstraintCircuitMonad<II>,
+    ) -> ConstraintCircuitMonad<II> {
+        row(IsSecread) + row(SyscallReadSecretInt)
+    }
+
+    /// The compressed row for the Lookup Argument with the Tape Table. Only meaningful if the
+    /// row reads from one of the input tapes.
+    fn compressed_row_for_tape_lookup<II: InputIndicator>(
+        circuit_builder: &ConstraintCircuitBuilder<II>,
+        row: impl Fn(ZmipsProcessorBaseTableColumn) -> ConstraintCircuitMonad<II>,
+    ) -> ConstraintCircuitMonad<II> {
+        let position = Self::is_public_tape_read(&row) * row(PubCursor)
+            + Self::is_secret_tape_read(&row) * row(SecCursor);
+        circuit_builder.challenge(TapeIsSecretWeight) * Self::is_secret_tape_read(&row)
+            + circuit_builder.challenge(TapePositionWeight) * position
+            + circuit_builder.challenge(TapeValueWeight) * row(RdVal)
+    }
+
+    /// 1 if the row writes to standard output, using either `print` or `syscall`, else 0.
+    fn is_print<II: InputIndicator>(
+        row: impl Fn(ZmipsProcessorBaseTableColumn) -> ConstraintCircuitMonad<II>,
+    ) -> ConstraintCircuitMonad<II> {
+        row(IsPrint) + row(SyscallPrintInt)
+    }
+
+    /// The symbol the row writes to standard output. Only meaningful if the row writes to
+    /// standard output. Instruction `print` writes register `rs`, while `syscall` writes `$a0`,
+    /// which is register `rt`.
+    fn output_symbol<II: InputIndicator>(
+        row: impl Fn(ZmipsProcessorBaseTableColumn) -> ConstraintCircuitMonad<II>,
+    ) -> ConstraintCircuitMonad<II> {
+        row(IsPrint) * row(RsVal) + row(SyscallPrintInt) * row(RtVal)
+    }
+
+    /// 1 if the row reports a final answer, using either `answer` or `syscall`, else 0.
+    fn is_answer<II: InputIndicator>(
+        row: impl Fn(ZmipsProcessorBaseTableColumn) -> ConstraintCircuitMonad<II>,
+    ) -> ConstraintCircuitMonad<II> {
+        row(IsAnswer) + row(SyscallAnswer)
+    }
+
+    /// The final answer the row reports, or 0 if the row does not report a final answer.
+    /// Instruction `answer` reports register `rs`, while `syscall` reports `$a0`, which is
+    /// register `rt`.
+    fn answer_symbol<II: InputIndicator>(
+        row: impl Fn(ZmipsProcessorBaseTableColumn) -> ConstraintCircuitMonad<II>,
+    ) -> ConstraintCircuitMonad<II> {
+        row(IsAnswer) * row(RsVal) + row(SyscallAnswer) * row(RtVal)
+    }
+
+    /// 1 if the row halts the zMIPS register machine, using either `exit` or `syscall`, else 0.
+    fn is_exit<II: InputIndicator>(
+        row: impl Fn(ZmipsProcessorBaseTableColumn) -> ConstraintCircuitMonad<II>,
+    ) -> ConstraintCircuitMonad<II> {
+        row(IsExit) + row(SyscallExit) + row(SyscallExit2)
+    }
+
+    /// The factor by which the running product of the Permutation Argument with the Register Table
+    /// is multiplied for the given row, one factor for each of the row's register accesses.
+    fn register_accesses_factor<II: InputIndicator>(
+        circuit_builder: &ConstraintCircuitBuilder<II>,
+        row: impl Fn(ZmipsProcessorBaseTableColumn) -> ConstraintCircuitMonad<II>,
+    ) -> ConstraintCircuitMonad<II> {
+        let is_write = REGISTER_WRITING_INSTRUCTION_FLAGS
+            .into_iter()
+            .map(&row)
+            .sum::<ConstraintCircuitMonad<_>>();
+        let compress_access = |register, value, is_write| {
+            circuit_builder.challenge(RegisterClkWeight) * row(CLK)
+                + circuit_builder.challenge(RegisterIndexWeight) * row(register)
+                + circuit_builder.challenge(RegisterValueWeight) * row(value)
+                + circuit_builder.challenge(RegisterIsWriteWeight) * is_write
+        };
+        let zero = circuit_builder.b_constant(0_u32.into());
+        [
+            compress_access(RS, RsVal, zero.clone()),
+            compress_access(RT, RtVal, zero),
+            compress_access(RD, RdVal, is_write),
+        ]
+        .into_iter()
+        .map(|compressed_access| {
+            circuit_builder.challenge(RegisterIndeterminate) - compressed_access
+        })
+        .fold(circuit_builder.b_constant(1_u32.into()), |a, b| a * b)
+    }
+
+    /// The factor by which the running product of the Permutation Argument with the Memory Table
+    /// is multiplied for the given row: 1 unless the row executes `lw` or `sw`.
+    fn memory_access_factor<II: InputIndicator>(
+        circuit_builder: &ConstraintCircuitBuilder<II>,
+        row: impl Fn(ZmipsProcessorBaseTableColumn) -> ConstraintCircuitMonad<II>,
+    ) -> ConstraintCircuitMonad<II> {
+        let one = circuit_builder.b_constant(1_u32.into());
+        let is_access = row(IsLw) + row(IsSw);
+        let compressed_access = circuit_builder.challenge(MemoryClkWeight) * row(CLK)
+            + circuit_builder.challenge(MemoryAddressWeight) * row(RAMP)
+            + circuit_builder.challenge(MemoryValueWeight) * row(RAMV)
+            + circuit_builder.challenge(MemoryIsWriteWeight) * row(IsSw);
+        is_access.clone() * (circuit_builder.challenge(MemoryIndeterminate) - compressed_access)
+            + (one - is_access)
+    }
+
+    /// The entries the instruction with the given flag looks up in the U32 Table, one
+    /// `[ci, lhs, rhs, result]` per slot, starting with the first slot. A component of `None` is
+    /// unconstrained: the instruction uses the looked-up value itself. All remaining slots look up
+    /// `(split, 0, 0, 0)`.
+    fn u32_lookups<II: InputIndicator>(
+        circuit_builder: &ConstraintCircuitBuilder<II>,
+        row: impl Fn(ZmipsProcessorBaseTableColumn) -> ConstraintCircuitMonad<II>,
+        instruction_flag: ZmipsProcessorBaseTableColumn,
+    ) -> Vec<[Option<ConstraintCircuitMonad<II>>; 4]> {
+        let constant = |c: u64| circuit_builder.b_constant(BFieldElement::new(c));
+        let opcode = |instruction: TritonInstruction| {
+            Some(circuit_builder.b_constant(instruction.opcode_b()))
+        };
+        let value_of = |col| Some(row(col));
+        let zero = || Some(constant(0));
+        let one = || Some(constant(1));
+
+        // `value` without its sign bit, shifted left by 1, is a u32 if and only if `sign` is the
+        // sign bit of `value`
+        let sign_check =
+            |value, sign| Some(constant(2) * row(value) - row(sign) * constant(TWO_POW_32));
+        let magnitude = |value, sign| {
+            row(value) + row(sign) * (constant(TWO_POW_32) - constant(2) * row(value))
+        };
+        let flip_sign_bit = |value, sign| {
+            Some(row(value) + constant(TWO_POW_31) - row(sign) * constant(TWO_POW_32))
+        };
+        let signs = || {
+            [
+                opcode(TritonInstruction::Split),
+                sign_check(RsVal, SignRs),
+                sign_check(Operand, SignOp),
+                zero(),
+            ]
+        };
+        let range_check = |value| {
+            [
+                opcode(TritonInstruction::Split),
+                value_of(value),
+                zero(),
+                zero(),
+            ]
+        };
+        let hi_lo = || {
+            [
+                opcode(TritonInstruction::Split),
+                value_of(Hi),
+                value_of(Lo),
+                zero(),
+            ]
+        };
+        let shift_amount = || {
+            [
+                opcode(TritonInstruction::And),
+                value_of(Operand),
+                Some(constant(31)),
+                None,
+            ]
+        };
+
+        match instruction_flag {
+            IsAdd | IsSub => vec![
+                signs(),
+                [
+                    opcode(TritonInstruction::Split),
+                    sign_check(RdVal, SignRd),
+                    zero(),
+                    zero(),
+                ],
+            ],
+            IsAddu | IsSubu | IsPubread | IsSecread | IsSyscall => vec![range_check(RdVal)],
+            IsLw => vec![range_check(RdVal), range_check(RAMP)],
+            IsSw | IsPubseek | IsSecseek => vec![range_check(RAMP)],
+            IsMult => vec![hi_lo()],
+            IsDivu => vec![
+                [
+                    opcode(TritonInstruction::Lt),
+                    value_of(Lo),
+                    value_of(Operand),
+                    one(),
+                ],
+                range_check(Hi),
+            ],
+            IsDiv | IsMod => {
+                // the quotient of `div` must fit into a signed word
+                let quotient_range_check = match instruction_flag {
+                    IsDiv => Some(
+                        constant(TWO_POW_31 - 1) + row(SignRs) + row(SignOp)
+                            - constant(2) * row(SignRs) * row(SignOp)
+                            - row(Hi),
+                    ),
+                    _ => zero(),
+                };
+                vec![
+                    [
+                        opcode(TritonInstruction::Lt),
+                        value_of(Lo),
+                        Some(magnitude(Operand, SignOp)),
+                        one(),
+                    ],
+                    signs(),
+                    [
+                        opcode(TritonInstruction::Split),
+                        value_of(Hi),
+                        quotient_range_check,
+                        zero(),
+                    ],
+                ]
+            }
+            IsAnd => vec![[
+                opcode(TritonInstruction::And),
+                value_of(RsVal),
+                value_of(Operand),
+                value_of(RdVal),
+            ]],
+            IsOr | IsXor => vec![[
+                opcode(TritonInstruction::And),
+                value_of(RsVal),
+                value_of(Operand),
+                value_of(Lo),
+            ]],
+            IsSll => vec![
+                shift_amount(),
+                [
+                    opcode(TritonInstruction::Pow),
+                    Some(constant(2)),
+                    value_of(U32LookupResult0),
+                    None,
+                ],
+                hi_lo(),
+            ],
+            IsSrl | IsSra => {
+                let mut lookups = vec![
+                    shift_amount(),
+                    [
+                        opcode(TritonInstruction::Pow),
+                        Some(constant(2)),
+                        Some(constant(32) - row(U32LookupResult0)),
+                        None,
+                    ],
+                    hi_lo(),
+                ];
+                if instruction_flag == IsSra {
+                    lookups.push([
+                        opcode(TritonInstruction::Split),
+                        sign_check(RsVal, SignRs),
+                        zero(),
+                        zero(),
+                    ]);
+                }
+                lookups
+            }
+            IsSlt => vec![
+                [
+                    opcode(TritonInstruction::Lt),
+                    flip_sign_bit(RsVal, SignRs),
+                    flip_sign_bit(Operand, SignOp),
+                    value_of(RdVal),
+                ],
+                signs(),
+            ],
+            IsSle => vec![
+                [
+                    opcode(TritonInstruction::Lt),
+                    flip_sign_bit(Operand, SignOp),
+                    flip_sign_bit(RsVal, SignRs),
+                    Some(constant(1) - row(RdVal)),
+                ],
+                signs(),
+            ],
+            IsSltu => vec![[
+                opcode(TritonInstruction::Lt),
+                value_of(RsVal),
+                value_of(Operand),
+                value_of(RdVal),
+            ]],
+            IsBlt => vec![
+                [
+                    opcode(TritonInstruction::Lt),
+                    flip_sign_bit(RsVal, SignRs),
+                    flip_sign_bit(Operand, SignOp),
+                    None,
+                ],
+                signs(),
+            ],
+            IsBle | IsBgt => vec![
+                [
+                    opcode(TritonInstruction::Lt),
+                    flip_sign_bit(Operand, SignOp),
+                    flip_sign_bit(RsVal, SignRs),
+                    None,
+                ],
+                signs(),
+            ],
+            IsBltu | IsBgeu => vec![[
+                opcode(TritonInstruction::Lt),
+                value_of(RsVal),
+                value_of(Operand),
+                None,
+            ]],
+            _ => vec![],
+        }
+    }
+
+    /// The factors `α - compressed_entry` for the four entries the given row looks up in the U32
+    /// Table, where `α` is the indeterminate of the Lookup Argument.
+    fn u32_lookup_factors<II: InputIndicator>(
+        circuit_builder: &ConstraintCircuitBuilder<II>,
+        row: impl Fn(ZmipsProcessorBaseTableColumn) -> ConstraintCircuitMonad<II>,
+    ) -> [ConstraintCircuitMonad<II>; 4] {
+        U32_LOOKUP_SLOTS.map(|[ci, lhs, rhs, result]| {
+            circuit_builder.challenge(U32Indeterminate)
+                - (circuit_builder.challenge(U32CiWeight) * row(ci)
+                    + circuit_builder.challenge(U32LhsWeight) * row(lhs)
+                    + circuit_builder.challenge(U32RhsWeight) * row(rhs)
+                    + circuit_builder.challenge(U32ResultWeight) * row(result))
+        })
+    }
+
+    /// The constraint that the given `difference` of the U32 Table's logarithmic derivative is the
+    /// sum of the inverses of the given factors. Multiplied by all factors to avoid inverses.
+    fn u32_lookup_log_derivative_accumulates<II: InputIndicator>(
+        difference: ConstraintCircuitMonad<II>,
+        factors: [ConstraintCircuitMonad<II>; 4],
+    ) -> ConstraintCircuitMonad<II> {
+        let product_of_factors = |skip_idx: Option<usize>| {
+            factors
+                .iter()
+                .enumerate()
+                .filter(|&(idx, _)| Some(idx) != skip_idx)
+                .map(|(_, factor)| factor.clone())
+                .reduce(|a, b| a * b)
+                .unwrap()
+        };
+        let sum_of_products_of_all_other_factors = (0..factors.len())
+            .map(|idx| product_of_factors(Some(idx)))
+            .sum::<ConstraintCircuitMonad<_>>();
+        difference * product_of_factors(None) - sum_of_products_of_all_other_factors
+    }
+
+    pub fn ext_initial_constraints_as_circuits() -> Vec<ConstraintCircuit<SingleRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let one = circuit_builder.b_constant(1_u32.into());
+        let base_row = |col: ZmipsProcessorBaseTableColumn| {
+            circuit_builder.input(BaseRow(col.master_base_table_index()))
+        };
+        let ext_row = |col: ZmipsProcessorExtTableColumn| {
+            circuit_builder.input(ExtRow(col.master_ext_table_index()))
+        };
+
+        let clk_is_0 = base_row(CLK);
+        let ip_is_0 = base_row(IP);
+
+        // The arguments below look at the first row with `clk` and the cursors set to the 0 they
+        // are constrained to be. A column forming an entire constraint must not reappear in
+        // another constraint: consuming the circuits would duplicate its node.
+        let first_row = |col: ZmipsProcessorBaseTableColumn| match col {
+            CLK | PubCursor | SecCursor => circuit_builder.b_constant(0_u32.into()),
+            _ => base_row(col),
+        };
+
+        // Exactly one of the Processor Table and the zMIPS Processor Table executes the program.
+        let processor_is_padding = circuit_builder.input(BaseRow(
+            ProcessorBaseTableColumn::IsPadding.master_base_table_index(),
+        ));
+        let exactly_one_processor_executes =
+            base_row(IsPadding) + processor_is_padding - one.clone();
+
+        // input tapes
+        let pub_cursor_is_0 = base_row(PubCursor);
+        let sec_cursor_is_0 = base_row(SecCursor);
+
+        // final answer
+        let final_answer_is_initialized_correctly =
+            base_row(FinalAnswer) - Self::answer_symbol(base_row);
+        let has_final_answer_is_initialized_correctly =
+            base_row(HasFinalAnswer) - Self::is_answer(base_row);
+        let is_tape_read =
+            Self::is_public_tape_read(base_row) + Self::is_secret_tape_read(base_row);
+        let tape_lookup_log_derivative_has_accumulated_first_row =
+            ext_row(TapeLookupClientLogDerivative)
+                * (circuit_builder.challenge(TapeIndeterminate)
+                    - Self::compressed_row_for_tape_lookup(&circuit_builder, first_row))
+                - one.clone();
+        let tape_lookup_log_derivative_is_default_initial = ext_row(TapeLookupClientLogDerivative)
+            - circuit_builder.x_constant(LookupArg::default_initial());
+        let tape_lookup_log_derivative_is_initialized_correctly = is_tape_read.clone()
+            * tape_lookup_log_derivative_has_accumulated_first_row
+            + (one.clone() - is_tape_read) * tape_lookup_log_derivative_is_default_initial;
+
+        // standard output
+        let output_indeterminate = circuit_builder.challenge(StandardOutputIndeterminate);
+        let output_eval_default_initial = circuit_builder.x_constant(EvalArg::default_initial());
+        let is_print = Self::is_print(base_row);
+        let running_evaluation_for_standard_output_is_initialized_correctly = is_print.clone()
+            * (ext_row(OutputTableEvalArg)
+                - output_indeterminate * output_eval_default_initial.clone()
+                - Self::output_symbol(base_row))
+            + (one.clone() - is_print)
+                * (ext_row(OutputTableEvalArg) - output_eval_default_initial);
+
+        // program table
+        // note: `ip` is already constrained to be 0
+        let instruction_lookup_indeterminate =
+            circuit_builder.challenge(InstructionLookupIndeterminate);
+        let compressed_row_for_instruction_lookup =
+            circuit_builder.challenge(ProgramInstructionWeight) * base_row(IW)
+                + circuit_builder.challenge(ProgramNextInstructionWeight) * base_row(NIW);
+        let instruction_lookup_log_derivative_has_accumulated_first_row =
+            ext_row(InstructionLookupClientLogDerivative)
+                * (instruction_lookup_indeterminate - compressed_row_for_instruction_lookup)
+                - one.clone();
+        let instruction_lookup_log_derivative_is_default_initial =
+            ext_row(InstructionLookupClientLogDerivative)
+                - circuit_builder.x_constant(LookupArg::default_initial());
+        let instruction_lookup_log_derivative_is_initialized_correctly = (one.clone()
+            - base_row(IsPadding))
+            * instruction_lookup_log_derivative_has_accumulated_first_row
+            + base_row(IsPadding) * instruction_lookup_log_derivative_is_default_initial;
+
+        // register table
+        let register_table_running_product_has_accumulated_first_row =
+            ext_row(RegisterTablePermArg)
+                - circuit_builder.x_constant(PermArg::default_initial())
+                    * Self::register_accesses_factor(&circuit_builder, first_row);
+        let register_table_running_product_is_default_initial =
+            ext_row(RegisterTablePermArg) - circuit_builder.x_constant(PermArg::default_initial());
+        let register_table_running_product_is_initialized_correctly = (one.clone()
+            - base_row(IsPadding))
+            * register_table_running_product_has_accumulated_first_row
+            + base_row(IsPadding) * register_table_running_product_is_default_initial;
+
+        // memory table
+        let memory_table_running_product_has_accumulated_first_row = ext_row(MemoryTablePermArg)
+            - circuit_builder.x_constant(PermArg::default_initial())
+                * Self::memory_access_factor(&circuit_builder, first_row);
+        let memory_table_running_product_is_default_initial =
+            ext_row(MemoryTablePermArg) - circuit_builder.x_constant(PermArg::default_initial());
+        let memory_table_running_product_is_initialized_correctly = (one.clone()
+            - base_row(IsPadding))
+            * memory_table_running_product_has_accumulated_first_row
+            + base_row(IsPadding) * memory_table_running_product_is_default_initial;
+
+        // u32 table
+        let u32_lookup_log_derivative_has_accumulated_first_row =
+            Self::u32_lookup_log_derivative_accumulates(
+                ext_row(U32LookupClientLogDerivative),
+                Self::u32_lookup_factors(&circuit_builder, base_row),
+            );
+        let u32_lookup_log_derivative_is_default_initial = ext_row(U32LookupClientLogDerivative)
+            - circuit_builder.x_constant(LookupArg::default_initial());
+        let u32_lookup_log_derivative_is_initialized_correctly = (one - base_row(IsPadding))
+            * u32_lookup_log_derivative_has_accumulated_first_row
+            + base_row(IsPadding) * u32_lookup_log_derivative_is_default_initial;
+
+        // clock jump difference lookup argument with the register table and the memory table
+        // note: `clk` is already constrained to be 0
+        let clock_jump_diff_log_derivative_is_initialized_correctly =
+            ext_row(ClockJumpDifferenceLookupServerLogDerivative)
+                * circuit_builder.challenge(ClockJumpDifferenceLookupIndeterminate)
+                - base_row(ClockJumpDifferenceLookupMultiplicity);
+
+        let mut constraints = [
+            clk_is_0,
+            ip_is_0,
+            exactly_one_processor_executes,
+            pub_cursor_is_0,
+            sec_cursor_is_0,
+            final_answer_is_initialized_correctly,
+            has_final_answer_is_initialized_correctly,
+            tape_lookup_log_derivative_is_initialized_correctly,
+            running_evaluation_for_standard_output_is_initialized_correctly,
+            instruction_lookup_log_derivative_is_initialized_correctly,
+            register_table_running_product_is_initialized_correctly,
+            memory_table_running_product_is_initialized_correctly,
+            u32_lookup_log_derivative_is_initialized_correctly,
+            clock_jump_diff_log_derivative_is_initialized_correctly,
+        ];
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints.map(|circuit| circuit.consume()).to_vec()
+    }
+
+    pub fn ext_consistency_constraints_as_circuits() -> Vec<ConstraintCircuit<SingleRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let one = circuit_builder.b_constant(1_u32.into());
+        let constant = |c: u64| circuit_builder.b_constant(BFieldElement::new(c));
+        let base_row = |col: ZmipsProcessorBaseTableColumn| {
+            circuit_builder.input(BaseRow(col.master_base_table_index()))
+        };
+        let is_bit = |col| base_row(col) * (base_row(col) - one.clone());
+
+        let is_padding_is_bit = is_bit(IsPadding);
+        let is_imm_is_bit = is_bit(IsImm);
+        let sign_bits_are_bits = vec![is_bit(SignRs), is_bit(SignOp), is_bit(SignRd)];
+        let syscall_service_flags_are_bits = SYSCALL_SERVICE_FLAGS.map(is_bit).to_vec();
+
+        // The instruction flags are a one-hot encoding of the current instruction.
+        let instruction_flags = ALL_INSTRUCTIONS
+            .iter()
+            .map(|instruction| {
+                let flag = ZmipsProcessorBaseTableColumn::iter()
+                    .nth(instruction_flag_index(instruction))
+                    .unwrap();
+                (instruction.opcode(), flag)
+            })
+            .collect_vec();
+        let instruction_flags_are_bits = instruction_flags
+            .iter()
+            .map(|&(_, flag)| is_bit(flag))
+            .collect_vec();
+        let exactly_one_instruction_flag_is_set = instruction_flags
+            .iter()
+            .map(|&(_, flag)| base_row(flag))
+            .sum::<ConstraintCircuitMonad<_>>()
+            - one.clone();
+        let ci_corresponds_to_instruction_flags = base_row(CI)
+            - instruction_flags
+                .iter()
+                .map(|&(opcode, flag)| constant(opcode as u64) * base_row(flag))
+                .sum::<ConstraintCircuitMonad<_>>();
+
+        // The instruction word binds the operands to the instruction looked up in the Program
+        // Table.
+        let instruction_word_is_composed_correctly = base_row(IW)
+            - (base_row(CI)
+                + constant(1 << RS_SHIFT) * base_row(RS)
+                + constant(1 << RT_SHIFT) * base_row(RT)
+                + constant(1 << RD_SHIFT) * base_row(RD)
+                + constant(1 << IS_IMM_SHIFT) * base_row(IsImm)
+                + constant(1 << IMM_SHIFT) * base_row(Imm));
+        let operand_is_immediate_or_rt_val = base_row(Operand)
+            - (base_row(IsImm) * base_row(Imm) + (one.clone() - base_row(IsImm)) * base_row(RtVal));
+
+        // Comparisons set `rd` to 1 or 0. Instructions `seq` and `sne` use the helper variable as
+        // the inverse-or-zero of the difference between both source operands.
+        let difference = base_row(RsVal) - base_row(Operand);
+        let seq_result_is_0_if_operands_differ =
+            base_row(IsSeq) * difference.clone() * base_row(RdVal);
+        let seq_result_is_1_if_operands_are_equal =
+            base_row(IsSeq) * (one.clone() - base_row(RdVal) - difference.clone() * base_row(HV));
+        let sne_result_is_1_if_operands_differ =
+            base_row(IsSne) * difference.clone() * (one.clone() - base_row(RdVal));
+        let sne_result_is_0_if_operands_are_equal =
+            base_row(IsSne) * (base_row(RdVal) - difference * base_row(HV));
+
+        // Integer arithmetic on 32-bit words. All registers hold values in range 0..2^32: every
+        // instruction writing a register either range-checks the result using the U32 Table or
+        // implies its range. Signed instructions interpret words as two's complement integers; the
+        // U32 Table establishes the sign bits of the involved words.
+        let two_pow_32 = constant(TWO_POW_32);
+        let signed = |col, sign| base_row(col) - base_row(sign) * two_pow_32.clone();
+        let add_result_is_exact_sum = base_row(IsAdd)
+            * (signed(RsVal, SignRs) + signed(Operand, SignOp) - signed(RdVal, SignRd));
+        let addu_result_is_sum_modulo_2_pow_32 = base_row(IsAddu)
+            * (base_row(RsVal) + base_row(Operand) - base_row(RdVal))
+            * (base_row(RsVal) + base_row(Operand) - base_row(RdVal) - two_pow_32.clone());
+        let sub_result_is_exact_difference = base_row(IsSub)
+            * (signed(RsVal, SignRs) - signed(Operand, SignOp) - signed(RdVal, SignRd));
+        let subu_result_is_difference_modulo_2_pow_32 = base_row(IsSubu)
+            * (base_row(RsVal) - base_row(Operand) - base_row(RdVal))
+            * (base_row(RsVal) - base_row(Operand) - base_row(RdVal) + two_pow_32.clone());
+
+        // Products and shifts split `rs_val · factor` into high word `Hi` and low word `Lo`. Shifts
+        // multiply by `2^k` for left shifts and by `2^(32-k)` for right shifts, where `k` is the
+        // shift amount. The decomposition is unique if it does not exceed the field's modulus,
+        // _i.e._, if `Lo` is 0 whenever `Hi` is 2^32 - 1. The helper variable is the
+        // inverse-or-zero of `Hi - (2^32 - 1)`.
+        let is_shift = base_row(IsSll) + base_row(IsSrl) + base_row(IsSra);
+        let hi_lo = base_row(Hi) * two_pow_32.clone() + base_row(Lo);
+        let mult_product_is_hi_lo =
+            base_row(IsMult) * (base_row(RsVal) * base_row(Operand) - hi_lo.clone());
+        let shift_product_is_hi_lo =
+            is_shift.clone() * (base_row(RsVal) * base_row(U32LookupResult1) - hi_lo.clone());
+        let hi_lo_does_not_wrap_around = (base_row(IsMult) + is_shift)
+            * base_row(Lo)
+            * (one.clone() - (base_row(Hi) - constant(TWO_POW_32 - 1)) * base_row(HV));
+        let mult_result_is_lo = base_row(IsMult) * (base_row(RdVal) - base_row(Lo));
+        let sll_result_is_lo = base_row(IsSll) * (base_row(RdVal) - base_row(Lo));
+        let srl_result_is_hi = base_row(IsSrl) * (base_row(RdVal) - base_row(Hi));
+        let sra_result_is_hi_with_sign_extension = base_row(IsSra)
+            * (base_row(RdVal)
+                - base_row(Hi)
+                - base_row(SignRs) * (two_pow_32.clone() - base_row(U32LookupResult1)));
+
+        // Divisions decompose the dividend into quotient `Hi` and remainder `Lo`. The U32 Table
+        // establishes that the remainder is smaller than the divisor, which also rules out
+        // division by 0. Signed divisions operate on the magnitudes and truncate towards 0; the
+        // remainder has the sign of the dividend. The helper variable is the inverse-or-zero of
+        // the quotient for `div`, and of the remainder for `mod`.
+        let magnitude = |col, sign| {
+            base_row(col) + base_row(sign) * (two_pow_32.clone() - constant(2) * base_row(col))
+        };
+        let divu_decomposes_dividend =
+            base_row(IsDivu) * (base_row(RsVal) - base_row(Hi) * base_row(Operand) - base_row(Lo));
+        let divu_result_is_quotient = base_row(IsDivu) * (base_row(RdVal) - base_row(Hi));
+        let signed_division_decomposes_dividend = (base_row(IsDiv) + base_row(IsMod))
+            * (magnitude(RsVal, SignRs) - base_row(Hi) * magnitude(Operand, SignOp) - base_row(Lo));
+        let quotient_is_negative =
+            base_row(SignRs) + base_row(SignOp) - constant(2) * base_row(SignRs) * base_row(SignOp);
+        let quotient_is_zero = one.clone() - base_row(Hi) * base_row(HV);
+        let remainder_is_zero = one.clone() - base_row(Lo) * base_row(HV);
+        let div_hv_is_inverse_of_nonzero_quotient =
+            base_row(IsDiv) * base_row(Hi) * quotient_is_zero.clone();
+        let div_result_is_signed_quotient = base_row(IsDiv)
+            * (base_row(RdVal)
+                - base_row(Hi)
+                - quotient_is_negative.clone() * (two_pow_32.clone() - constant(2) * base_row(Hi))
+                + quotient_is_negative * two_pow_32.clone() * quotient_is_zero);
+        let mod_hv_is_inverse_of_nonzero_remainder =
+            base_row(IsMod) * base_row(Lo) * remainder_is_zero.clone();
+        let mod_result_is_signed_remainder = base_row(IsMod)
+            * (base_row(RdVal)
+                - base_row(Lo)
+                - base_row(SignRs) * (two_pow_32.clone() - constant(2) * base_row(Lo))
+                + base_row(SignRs) * two_pow_32.clone() * remainder_is_zero);
+
+        // Bitwise `or` and `xor` are derived from bitwise `and`, which is stored in `Lo`.
+        let or_result =
+            base_row(IsOr) * (base_row(RdVal) - base_row(RsVal) - base_row(Operand) + base_row(Lo));
+        let xor_result = base_row(IsXor)
+            * (base_row(RdVal) - base_row(RsVal) - base_row(Operand) + constant(2) * base_row(Lo));
+        let not_result =
+            base_row(IsNot) * (base_row(RdVal) + base_row(Operand) - constant(TWO_POW_32 - 1));
+
+        let move_result = base_row(IsMove) * (base_row(RdVal) - base_row(Operand));
+        let la_result = base_row(IsLa) * (base_row(RdVal) - base_row(Imm));
+
+        // Subroutine calls write the return address to register `$ra`.
+        let jal_or_jalr_result_is_return_address =
+            (base_row(IsJal) + base_row(IsJalr)) * (base_row(RdVal) - base_row(IP) - one.clone());
+
+        // Memory access and seeking. Like all words, addresses and seek targets wrap around modulo
+        // 2^32, which allows negative offsets. The U32 Table range-checks the address or target.
+        let is_addressing =
+            base_row(IsLw) + base_row(IsSw) + base_row(IsPubseek) + base_row(IsSecseek);
+        let ramp_is_rs_val_plus_operand_modulo_2_pow_32 = is_addressing
+            * (base_row(RsVal) + base_row(Operand) - base_row(RAMP))
+            * (base_row(RsVal) + base_row(Operand) - base_row(RAMP) - two_pow_32.clone());
+        let lw_result_is_ramv = base_row(IsLw) * (base_row(RdVal) - base_row(RAMV));
+        let sw_writes_rd_val = base_row(IsSw) * (base_row(RAMV) - base_row(RdVal));
+
+        // Instruction `syscall` requests the service identified by the code in register `$v0`,
+        // which is register `rs`. Only the reading services change `$v0`, which is register `rd`.
+        let syscall_service_flags = SYSCALL_SERVICE_FLAGS.map(base_row);
+        let exactly_one_syscall_service_flag_is_set_iff_syscall = syscall_service_flags
+            .iter()
+            .cloned()
+            .sum::<ConstraintCircuitMonad<_>>()
+            - base_row(IsSyscall);
+        let syscall_service_corresponds_to_v0 = Syscall::iter()
+            .zip_eq(syscall_service_flags)
+            .map(|(syscall, flag)| flag * (base_row(RsVal) - constant(syscall.code() as u64)))
+            .sum::<ConstraintCircuitMonad<_>>();
+        let syscall_only_changes_v0_when_reading =
+            (base_row(IsSyscall) - base_row(SyscallReadInt) - base_row(SyscallReadSecretInt))
+                * (base_row(RdVal) - base_row(RsVal));
+
+        // Padding rows are not looked up by the Register Table or the Memory Table.
+        let clock_jump_diff_lookup_multiplicity_is_0_in_padding_section =
+            base_row(IsPadding) * base_row(ClockJumpDifferenceLookupMultiplicity);
+
+        // The U32 lookup slots contain the entries required by the current instruction.
+        let default_u32_lookup = [
+            Some(circuit_builder.b_constant(TritonInstruction::Split.opcode_b())),
+            Some(constant(0)),
+            Some(constant(0)),
+            Some(constant(0)),
+        ];
+        let u32_lookups = instruction_flags
+            .iter()
+            .map(|&(_, flag)| (flag, Self::u32_lookups(&circuit_builder, base_row, flag)))
+            .collect_vec();
+        let mut u32_lookup_slots_contain_required_entries = vec![];
+        for (slot_idx, slot) in U32_LOOKUP_SLOTS.into_iter().enumerate() {
+            for (component_idx, slot_column) in slot.into_iter().enumerate() {
+                let constraint = u32_lookups
+                    .iter()
+                    .filter_map(|(flag, lookups)| {
+                        let required_entry = match lookups.get(slot_idx) {
+                            Some(lookup) => lookup[component_idx].clone(),
+                            None => default_u32_lookup[component_idx].clone(),
+                        };
+                        required_entry
+                            .map(|entry| base_row(*flag) * (base_row(slot_column) - entry))
+                    })
+                    .sum::<ConstraintCircuitMonad<_>>();
+                u32_lookup_slots_contain_required_entries.push(constraint);
+            }
+        }
+
+        let mut constraints = [
+            vec![is_padding_is_bit],
+            instruction_flags_are_bits,
+            sign_bits_are_bits,
+            syscall_service_flags_are_bits,
+            vec![
+                exactly_one_instruction_flag_is_set,
+                ci_corresponds_to_instruction_flags,
+                is_imm_is_bit,
+                instruction_word_is_composed_correctly,
+                operand_is_immediate_or_rt_val,
+                seq_result_is_0_if_operands_differ,
+                seq_result_is_1_if_operands_are_equal,
+                sne_result_is_1_if_operands_differ,
+                sne_result_is_0_if_operands_are_equal,
+                add_result_is_exact_sum,
+                addu_result_is_sum_modulo_2_pow_32,
+                sub_result_is_exact_difference,
+                subu_result_is_difference_modulo_2_pow_32,
+                mult_product_is_hi_lo,
+                shift_product_is_hi_lo,
+                hi_lo_does_not_wrap_around,
+                mult_result_is_lo,
+                sll_result_is_lo,
+                srl_result_is_hi,
+                sra_result_is_hi_with_sign_extension,
+                divu_decomposes_dividend,
+                divu_result_is_quotient,
+                signed_division_decomposes_dividend,
+                div_hv_is_inverse_of_nonzero_quotient,
+                div_result_is_signed_quotient,
+                mod_hv_is_inverse_of_nonzero_remainder,
+                mod_result_is_signed_remainder,
+                or_result,
+                xor_result,
+                not_result,
+                move_result,
+                la_result,
+                jal_or_jalr_result_is_return_address,
+                ramp_is_rs_val_plus_operand_modulo_2_pow_32,
+                lw_result_is_ramv,
+                sw_writes_rd_val,
+                exactly_one_syscall_service_flag_is_set_iff_syscall,
+                syscall_service_corresponds_to_v0,
+                syscall_only_changes_v0_when_reading,
+                clock_jump_diff_lookup_multiplicity_is_0_in_padding_section,
+            ],
+            u32_lookup_slots_contain_required_entries,
+        ]
+        .concat();
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints
+            .into_iter()
+            .map(|circuit| circuit.consume())
+            .collect_vec()
+    }
+
+    pub fn ext_transition_constraints_as_circuits() -> Vec<ConstraintCircuit<DualRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let one = circuit_builder.b_constant(1_u32.into());
+        let curr_base_row = |col: ZmipsProcessorBaseTableColumn| {
+            circuit_builder.input(CurrentBaseRow(col.master_base_table_index()))
+        };
+        let next_base_row = |col: ZmipsProcessorBaseTableColumn| {
+            circuit_builder.input(NextBaseRow(col.master_base_table_index()))
+        };
+        let curr_ext_row = |col: ZmipsProcessorExtTableColumn| {
+            circuit_builder.input(CurrentExtRow(col.master_ext_table_index()))
+        };
+        let next_ext_row = |col: ZmipsProcessorExtTableColumn| {
+            circuit_builder.input(NextExtRow(col.master_ext_table_index()))
+        };
+
+        let clk_increases_by_1 = next_base_row(CLK) - curr_base_row(CLK) - one.clone();
+        let is_padding_is_0_or_remains_1 =
+            curr_base_row(IsPadding) * (next_base_row(IsPadding) - one.clone());
+        let ip_remains_in_padding_section =
+            next_base_row(IsPadding) * (next_base_row(IP) - curr_base_row(IP));
+        let ci_remains_in_padding_section =
+            next_base_row(IsPadding) * (next_base_row(CI) - curr_base_row(CI));
+        let exit_is_followed_by_padding =
+            Self::is_exit(curr_base_row) * (one.clone() - next_base_row(IsPadding));
+
+        // control flow
+        let ip_increments = next_base_row(IP) - curr_base_row(IP) - one.clone();
+        let ip_jumps = next_base_row(IP) - curr_base_row(Imm);
+        let sequential_instruction_increments_ip = SEQUENTIAL_INSTRUCTION_FLAGS
+            .into_iter()
+            .map(curr_base_row)
+            .sum::<ConstraintCircuitMonad<_>>()
+            * ip_increments.clone();
+        let j_or_jal_jumps_to_immediate =
+            (curr_base_row(IsJ) + curr_base_row(IsJal)) * ip_jumps.clone();
+        let jr_or_jalr_jumps_to_rs_val = (curr_base_row(IsJr) + curr_base_row(IsJalr))
+            * (next_base_row(IP) - curr_base_row(RsVal));
+
+        // Instructions `beq` and `bne` use the helper variable as the inverse-or-zero of the
+        // difference between both compared registers. The ordering branches look up the comparison
+        // of both registers in the U32 Table, see `u32_lookups`; its result is a bit.
+        let difference = curr_base_row(RsVal) - curr_base_row(RtVal);
+        let difference_is_zero = one.clone() - difference.clone() * curr_base_row(HV);
+        let beq_increments_ip_if_registers_differ =
+            curr_base_row(IsBeq) * difference.clone() * ip_increments.clone();
+        let beq_jumps_if_registers_are_equal =
+            curr_base_row(IsBeq) * difference_is_zero.clone() * ip_jumps.clone();
+        let bne_jumps_if_registers_differ = curr_base_row(IsBne) * difference * ip_jumps.clone();
+        let bne_increments_ip_if_registers_are_equal =
+            curr_base_row(IsBne) * difference_is_zero * ip_increments.clone();
+        let is_less = curr_base_row(U32LookupResult0);
+        let is_not_less = one.clone() - is_less.clone();
+        let ordering_branch_is_taken =
+            (curr_base_row(IsBlt) + curr_base_row(IsBgt) + curr_base_row(IsBltu)) * is_less.clone()
+                + (curr_base_row(IsBle) + curr_base_row(IsBgeu)) * is_not_less.clone();
+        let ordering_branch_is_not_taken =
+            (curr_base_row(IsBlt) + curr_base_row(IsBgt) + curr_base_row(IsBltu)) * is_not_less
+                + (curr_base_row(IsBle) + curr_base_row(IsBgeu)) * is_less;
+        let ordering_branch_jumps_if_taken = ordering_branch_is_taken * ip_jumps;
+        let ordering_branch_increments_ip_if_not_taken =
+            ordering_branch_is_not_taken * ip_increments;
+
+        // Reading from an input tape advances its cursor by 1. Seeking moves the cursor to
+        // position `rs_val + operand` modulo 2^32, held in `RAMP`. All other instructions leave
+        // the cursors unchanged.
+        let seek_position = curr_base_row(RAMP);
+        let pub_cursor_updates_correctly = next_base_row(PubCursor)
+            - curr_base_row(PubCursor)
+            - Self::is_public_tape_read(curr_base_row)
+            - curr_base_row(IsPubseek) * (seek_position.clone() - curr_base_row(PubCursor));
+        let sec_cursor_updates_correctly = next_base_row(SecCursor)
+            - curr_base_row(SecCursor)
+            - Self::is_secret_tape_read(curr_base_row)
+            - curr_base_row(IsSecseek) * (seek_position - curr_base_row(SecCursor));
+
+        // Reporting a final answer supersedes all previously reported answers.
+        let next_is_answer = Self::is_answer(next_base_row);
+        let final_answer_updates_correctly = next_base_row(FinalAnswer)
+            - Self::answer_symbol(next_base_row)
+            - (one.clone() - next_is_answer.clone()) * curr_base_row(FinalAnswer);
+        let has_final_answer_updates_correctly = next_base_row(HasFinalAnswer)
+            - curr_base_row(HasFinalAnswer)
+            - next_is_answer * (one.clone() - curr_base_row(HasFinalAnswer));
+
+        // input tapes
+        let tape_lookup_log_derivative_remains = next_ext_row(TapeLookupClientLogDerivative)
+            - curr_ext_row(TapeLookupClientLogDerivative);
+        let tape_lookup_log_derivative_accumulates = tape_lookup_log_derivative_remains.clone()
+            * (circuit_builder.challenge(TapeIndeterminate)
+                - Self::compressed_row_for_tape_lookup(&circuit_builder, next_base_row))
+            - one.clone();
+        let next_is_tape_read =
+            Self::is_public_tape_read(next_base_row) + Self::is_secret_tape_read(next_base_row);
+        let tape_lookup_log_derivative_updates_correctly = next_is_tape_read.clone()
+            * tape_lookup_log_derivative_accumulates
+            + (one.clone() - next_is_tape_read) * tape_lookup_log_derivative_remains;
+
+        // standard output
+        let next_is_print = Self::is_print(next_base_row);
+        let running_evaluation_for_standard_output_updates_correctly =
+            next_ext_row(OutputTableEvalArg)
+                - next_is_print.clone()
+                    * (curr_ext_row(OutputTableEvalArg)
+                        * circuit_builder.challenge(StandardOutputIndeterminate)
+                        + Self::output_symbol(next_base_row))
+                - (one.clone() - next_is_print) * curr_ext_row(OutputTableEvalArg);
+
+        // program table
+        let log_derivative_remains = next_ext_row(InstructionLookupClientLogDerivative)
+            - curr_ext_row(InstructionLookupClientLogDerivative);
+        let compressed_row_for_instruction_lookup = circuit_builder.challenge(ProgramAddressWeight)
+            * next_base_row(IP)
+            + circuit_builder.challenge(ProgramInstructionWeight) * next_base_row(IW)
+            + circuit_builder.challenge(ProgramNextInstructionWeight) * next_base_row(NIW);
+        let log_derivative_accumulates = log_derivative_remains.clone()
+            * (circuit_builder.challenge(InstructionLookupIndeterminate)
+                - compressed_row_for_instruction_lookup)
+            - one.clone();
+        let log_derivative_updates_correctly = (one.clone() - next_base_row(IsPadding))
+            * log_derivative_accumulates
+            + next_base_row(IsPadding) * log_derivative_remains;
+
+        // register table
+        let register_table_running_product_accumulates = next_ext_row(RegisterTablePermArg)
+            - curr_ext_row(RegisterTablePermArg)
+                * Self::register_accesses_factor(&circuit_builder, next_base_row);
+        let register_table_running_product_remains =
+            next_ext_row(RegisterTablePermArg) - curr_ext_row(RegisterTablePermArg);
+        let register_table_running_product_updates_correctly =
+            (one.clone() - next_base_row(IsPadding)) * register_table_running_product_accumulates
+                + next_base_row(IsPadding) * register_table_running_product_remains;
+
+        // memory table
+        let memory_table_running_product_accumulates = next_ext_row(MemoryTablePermArg)
+            - curr_ext_row(MemoryTablePermArg)
+                * Self::memory_access_factor(&circuit_builder, next_base_row);
+        let memory_table_running_product_remains =
+            next_ext_row(MemoryTablePermArg) - curr_ext_row(MemoryTablePermArg);
+        let memory_table_running_product_updates_correctly =
+            (one.clone() - next_base_row(IsPadding)) * memory_table_running_product_accumulates
+                + next_base_row(IsPadding) * memory_table_running_product_remains;
+
+        // u32 table
+        let u32_lookup_log_derivative_remains =
+            next_ext_row(U32LookupClientLogDerivative) - curr_ext_row(U32LookupClientLogDerivative);
+        let u32_lookup_log_derivative_accumulates = Self::u32_lookup_log_derivative_accumulates(
+            u32_lookup_log_derivative_remains.clone(),
+            Self::u32_lookup_factors(&circuit_builder, next_base_row),
+        );
+        let u32_lookup_log_derivative_updates_correctly = (one - next_base_row(IsPadding))
+            * u32_lookup_log_derivative_accumulates
+            + next_base_row(IsPadding) * u32_lookup_log_derivative_remains;
+
+        // clock jump difference lookup argument with the register table and the memory table
+        let clock_jump_diff_log_derivative_updates_correctly =
+            (next_ext_row(ClockJumpDifferenceLookupServerLogDerivative)
+                - curr_ext_row(ClockJumpDifferenceLookupServerLogDerivative))
+                * (circuit_builder.challenge(ClockJumpDifferenceLookupIndeterminate)
+                    - next_base_row(CLK))
+                - next_base_row(ClockJumpDifferenceLookupMultiplicity);
+
+        let mut constraints = [
+            clk_increases_by_1,
+            is_padding_is_0_or_remains_1,
+            ip_remains_in_padding_section,
+            ci_remains_in_padding_section,
+            exit_is_followed_by_padding,
+            sequential_instruction_increments_ip,
+            j_or_jal_jumps_to_immediate,
+            jr_or_jalr_jumps_to_rs_val,
+            beq_increments_ip_if_registers_differ,
+            beq_jumps_if_registers_are_equal,
+            bne_jumps_if_registers_differ,
+            bne_increments_ip_if_registers_are_equal,
+            ordering_branch_jumps_if_taken,
+            ordering_branch_increments_ip_if_not_taken,
+            pub_cursor_updates_correctly,
+            sec_cursor_updates_correctly,
+            final_answer_updates_correctly,
+            has_final_answer_updates_correctly,
+            tape_lookup_log_derivative_updates_correctly,
+            running_evaluation_for_standard_output_updates_correctly,
+            log_derivative_updates_correctly,
+            register_table_running_product_updates_correctly,
+            memory_table_running_product_updates_correctly,
+            u32_lookup_log_derivative_updates_correctly,
+            clock_jump_diff_log_derivative_updates_correctly,
+        ];
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints.map(|circuit| circuit.consume()).to_vec()
+    }
+
+    pub fn ext_terminal_constraints_as_circuits() -> Vec<ConstraintCircuit<SingleRowIndicator>> {
+        let circuit_builder = ConstraintCircuitBuilder::new();
+        let base_row = |col: ZmipsProcessorBaseTableColumn| {
+            circuit_builder.input(BaseRow(col.master_base_table_index()))
+        };
+
+        // In the last row, the current instruction is `exit` or a `syscall` requesting to exit.
+        let last_instruction_exits =
+            circuit_builder.b_constant(1_u32.into()) - Self::is_exit(base_row);
+
+        // The exit code and the final answer are the ones in the claim. Instruction `exit` passes
+        // register `rs` as the exit code, while `syscall` passes 0 or `$a0`, which is register
+        // `rt`.
+        let exit_code =
+            base_row(IsExit) * base_row(RsVal) + base_row(SyscallExit2) * base_row(RtVal);
+        let exit_code_is_claimed = exit_code - circuit_builder.challenge(ClaimedExitCode);
+        let final_answer_is_claimed =
+            base_row(FinalAnswer) - circuit_builder.challenge(ClaimedFinalAnswer);
+        let has_final_answer_is_claimed =
+            base_row(HasFinalAnswer) - circuit_builder.challenge(ClaimedHasFinalAnswer);
+
+        let mut constraints = [
+            last_instruction_exits,
+            exit_code_is_claimed,
+            final_answer_is_claimed,
+            has_final_answer_is_claimed,
+        ];
+        ConstraintCircuitMonad::constant_folding(&mut constraints);
+        constraints.map(|circuit| circuit.consume()).to_vec()
+    }
+}
@@ -22,26 +22,44 @@ use twenty_first::shared_math::x_field_element::XFieldElement;
 use twenty_first::util_types::algebraic_hasher::Domain;
 
 use triton_opcodes::instruction::AnInstruction::*;
+use triton_opcodes::instruction::DivinationHint;
 use triton_opcodes::instruction::Instruction;
 use triton_opcodes::ord_n::Ord16;
 use triton_opcodes::ord_n::Ord16::*;
 use triton_opcodes::ord_n::Ord8;
 use triton_program::AbstractProgram;
+use triton_program::Isa;
+use triton_program::Machine;
+use triton_program::NonDeterminism;
+use triton_program::SourceLocation;
+use triton_program::SourceMap;
+use triton_zmips::instruction::Syscall;
+use triton_zmips::regs::Reg;
 use triton_zmips::regs::RegA;
+use triton_zmips::regs::REG_NAMES;
 
 use crate::error::vm_err;
 use crate::error::vm_fail;
+use crate::error::CallFrame;
+use crate::error::InstructionError;
 use crate::error::InstructionError::InstructionPointerOverflow;
 use crate::error::InstructionError::*;
+use crate::error::VmError;
+use crate::machine;
+use crate::machine::MachineVisitor;
 use crate::op_stack::OpStack;
+use crate::op_stack::OP_STACK_REG_COUNT;
 use crate::regs_pool::RegsPool;
 use crate::table::hash_table;
 use crate::table::hash_table::HashTable;
+use crate::table::master_table::MasterBaseTable;
 use crate::table::processor_table;
 use crate::table::processor_table::ProcessorTraceRow;
 use crate::table::table_column::HashBaseTableColumn::*;
 use crate::table::table_column::MasterBaseTableColumn;
 use crate::table::table_column::ProcessorBaseTableColumn;
+use crate::table::table_column::ZmipsProcessorBaseTableColumn;
+use crate::table::zmips_processor_table;
 use crate::vm::VMOutput::*;
 
 /// The number of helper variable registers
@@ -54,6 +72,9 @@ pub struct VMState<'pgm, T: Debug + Clone + PartialEq> {
     /// currently being executed by Triton VM. It is read-only.
     pub program: &'pgm [T],
 
+    /// The locations in the source code the program's instructions originate from, if known.
+    pub source_map: Option<&'pgm SourceMap>,
+
     /// The read-write **random-access memory** allows Triton VM to store arbitrary data.
     pub ram: HashMap<BFieldElement, BFieldElement>,
 
@@ -67,7 +88,7 @@ pub struct VMState<'pgm, T: Debug + Clone + PartialEq> {
 
     // Registers
     /// Number of cycles the program has been running for
-    pub cycle_count: u32,
+    pub cycle_count: u64,
 
     /// Current instruction's address in program memory
     pub instruction_pointer: usize,
@@ -106,7 +127,76 @@ pub enum VMOutput {
     /// Executed u32 instruction as well as its left-hand side and right-hand side
     U32TableEntries(Vec<(Instruction, BFieldElement, BFieldElement)>),
 
+    /// The final answer reported by `answer`. Later answers supersede earlier ones.
     FinalAnswer(BFieldElement),
+
+    /// The exit code passed to `exit`, which terminates execution.
+    ExitCode(BFieldElement),
+}
+
+/// The maximal distance beyond the end of the secret input tape that [`InputTape::fill_from`]
+/// fills the tape across. Every symbol on a tape occupies a row of the Tape Table, so programs
+/// seeking further ahead are not provable in practice. Without the bound, a far `secseek` would
+/// make a lazy [`NonDeterminism`] exhaust memory.
+pub const MAX_FILL_DISTANCE: usize = 1 << 20;
+
+/// An input tape of the zMIPS register machine: an indexed stream of symbols. Reading returns the
+/// symbol under the tape's cursor and advances the cursor by 1. Seeking moves the cursor to an
+/// arbitrary position, allowing to read symbols out of order or more than once.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InputTape {
+    symbols: Vec<BFieldElement>,
+    cursor: usize,
+}
+
+impl InputTape {
+    pub fn new(symbols: Vec<BFieldElement>) -> Self {
+        Self { symbols, cursor: 0 }
+    }
+
+    /// All symbols on the tape, independent of the cursor's position.
+    pub fn symbols(&self) -> &[BFieldElement] {
+        &self.symbols
+    }
+
+    /// The position of the symbol the next read returns.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Return the symbol under the cursor and advance the cursor by 1. Fails if the cursor is
+    /// beyond the end of the tape.
+    pub fn read(&mut self) -> Result<BFieldElement> {
+        let symbol = self
+            .symbols
+            .get(self.cursor)
+            .copied()
+            .ok_or_else(|| vm_fail(InputTapeExhausted(self.cursor)))?;
+        self.cursor += 1;
+        Ok(symbol)
+    }
+
+    /// Move the cursor to the given position. The position may lie beyond the end of the tape,
+    /// in which case the next read fails.
+    pub fn seek(&mut self, position: BFieldElement) {
+        self.cursor = position.value() as usize;
+    }
+
+    /// Append individual tokens of the given secret input to the tape until the tape has a
+    /// symbol under the cursor, or until the secret input is exhausted. If the cursor lies more
+    /// than [`MAX_FILL_DISTANCE`] symbols beyond the end of the tape, nothing is appended, and
+    /// the next read fails.
+    pub fn fill_from(&mut self, non_determinism: &mut dyn NonDeterminism) {
+        if self.cursor.saturating_sub(self.symbols.len()) > MAX_FILL_DISTANCE {
+            return;
+        }
+        while self.cursor >= self.symbols.len() {
+            match non_determinism.next_token() {
+                Some(symbol) => self.symbols.push(symbol),
+                None => return,
+            }
+        }
+    }
 }
 
 impl<'pgm, T: Debug + PartialEq + Clone> VMState<'pgm, T> {
@@ -118,10 +208,81 @@ impl<'pgm, T: Debug + PartialEq + Clone> VMState<'pgm, T> {
     }
 }
 
+impl<'pgm, T: Debug + PartialEq + Clone> VMState<'pgm, T> {
+    /// The RAM cells whose content in `self` differs from the content in the `previous` state,
+    /// alongside their content in `self`, sorted by address.
+    pub fn ram_diff(&self, previous: &Self) -> Vec<(BFieldElement, BFieldElement)> {
+        let mut ram_diff = self
+            .ram
+            .iter()
+            .filter(|&(address, value)| {
+                let previous_value = previous.ram.get(address).copied();
+                previous_value.unwrap_or_else(BFieldElement::zero) != *value
+            })
+            .map(|(&address, &value)| (address, value))
+            .collect::<Vec<_>>();
+        ram_diff.sort_by_key(|&(address, _)| address.value());
+        ram_diff
+    }
+}
+
+impl<'pgm, T: Debug + PartialEq + Clone + Display> VMState<'pgm, T> {
+    /// The location in the source code of the instruction at the given address, if known.
+    pub fn source_location(&self, address: usize) -> Option<&'pgm SourceLocation> {
+        self.source_map
+            .and_then(|source_map| source_map.get(address))
+    }
+
+    /// Turn an error raised by the instruction at `instruction_pointer` into a [`VmError`]
+    /// reporting the given registers and the current jump stack. Errors other than
+    /// [`InstructionError`]s are passed through unchanged.
+    fn report(
+        &self,
+        err: anyhow::Error,
+        instruction_pointer: usize,
+        cycle_count: u64,
+        registers: Vec<(String, BFieldElement)>,
+    ) -> anyhow::Error {
+        let source = match err.downcast::<InstructionError>() {
+            Ok(source) => source,
+            Err(err) => return err,
+        };
+        let backtrace = self
+            .jump_stack
+            .iter()
+            .rev()
+            .map(|&(return_address, callee)| {
+                let callee = callee.value() as usize;
+                CallFrame {
+                    callee,
+                    return_address: return_address.value() as usize,
+                    callee_label: self
+                        .source_location(callee)
+                        .and_then(|location| location.label.clone()),
+                }
+            })
+            .collect();
+        let vm_error = VmError {
+            source,
+            instruction_pointer,
+            cycle_count,
+            instruction: self
+                .program
+                .get(instruction_pointer)
+                .map(|instruction| instruction.to_string()),
+            source_location: self.source_location(instruction_pointer).cloned(),
+            registers,
+            backtrace,
+        };
+        anyhow::Error::new(vm_error)
+    }
+}
+
 impl<'pgm, T: Debug + PartialEq + Clone> Default for VMState<'pgm, T> {
     fn default() -> Self {
         Self {
             program: &[],
+            source_map: None,
             ram: Default::default(),
             op_stack: Default::default(),
             regs_pool: RegsPool::default(),
@@ -157,21 +318,202 @@ impl<'pgm, T: Debug + PartialEq + Clone> VMState<'pgm, T> {
 }
 
 impl<'pgm> VMState<'pgm, triton_zmips::instruction::Instruction> {
-    pub fn new_zmips(program: &'pgm [triton_zmips::instruction::Instruction]) -> Self {
-        // let program = program.get_instructions();
+    /// The initial state for the given program, with the program's data segment loaded into
+    /// memory.
+    pub fn new_zmips(program: &'pgm triton_zmips::program::Program) -> Self {
         Self {
-            program,
+            program: &program.instructions,
+            source_map: Some(&program.source_map),
+            ram: program.initial_ram(),
             ..VMState::default()
         }
     }
-    pub fn to_processor_row(&self) -> Array1<BFieldElement> {
-        Array1::zeros(processor_table::BASE_WIDTH)
+
+    /// Whether the current instruction reads from the secret input tape, either directly or
+    /// through a syscall.
+    pub fn reads_secret_input(&self) -> bool {
+        use triton_zmips::instruction::Instruction;
+        match self.current_instruction() {
+            Ok(Instruction::SECREAD(_)) => true,
+            Ok(Instruction::SYSCALL) => {
+                matches!(
+                    Syscall::try_from(self.word(Reg::V0)),
+                    Ok(Syscall::ReadSecretInt)
+                )
+            }
+            _ => false,
+        }
+    }
+
+    /// The row of the zMIPS Processor Table corresponding to the current state. The zMIPS
+    /// Processor Table's column `RdVal` holds the destination register's content _after_
+    /// execution of the current instruction. Since that content is not known yet, the returned
+    /// row holds the content _before_ execution, and `ZmipsMachine::record_step` overwrites it
+    /// once the instruction has been executed.
+    pub fn to_processor_row(
+        &self,
+        public_input: &InputTape,
+        secret_input: &InputTape,
+    ) -> Array1<BFieldElement> {
+        use ZmipsProcessorBaseTableColumn::*;
+        let mut row = Array1::zeros(zmips_processor_table::BASE_WIDTH);
+        let current_instruction = match self.current_instruction() {
+            Ok(instruction) => instruction,
+            Err(_) => return row,
+        };
+        let operands = current_instruction.operands();
+        let rs_val = self.reg(operands.rs);
+        let rt_val = self.reg(operands.rt);
+        let operand = match operands.is_imm {
+            true => operands.imm,
+            false => rt_val,
+        };
+        let next_instruction_word = self
+            .program
+            .get(self.instruction_pointer + 1)
+            .map(|instruction| instruction.word())
+            .unwrap_or_else(BFieldElement::zero);
+
+        row[CLK.base_table_index()] = BFieldElement::new(self.cycle_count);
+        row[IP.base_table_index()] = BFieldElement::new(self.instruction_pointer as u64);
+        row[CI.base_table_index()] = current_instruction.opcode_b();
+        row[IW.base_table_index()] = current_instruction.word();
+        row[NIW.base_table_index()] = next_instruction_word;
+        row[RS.base_table_index()] = operands.rs.into();
+        row[RT.base_table_index()] = operands.rt.into();
+        row[RD.base_table_index()] = operands.rd.into();
+        row[IsImm.base_table_index()] = BFieldElement::new(operands.is_imm as u64);
+        row[Imm.base_table_index()] = operands.imm;
+        row[RsVal.base_table_index()] = rs_val;
+        row[RtVal.base_table_index()] = rt_val;
+        row[RdVal.base_table_index()] = self.reg(operands.rd);
+        row[Operand.base_table_index()] = operand;
+        row[zmips_processor_table::instruction_flag_index(&current_instruction)] =
+            BFieldElement::one();
+        row[PubCursor.base_table_index()] = BFieldElement::new(public_input.cursor() as u64);
+        row[SecCursor.base_table_index()] = BFieldElement::new(secret_input.cursor() as u64);
+
+        use triton_zmips::instruction::Instruction;
+        match current_instruction {
+            Instruction::LW(_) => {
+                let ramp = Self::memory_address(rs_val.value() as u32, operand.value() as u32);
+                row[RAMP.base_table_index()] = ramp;
+                row[RAMV.base_table_index()] = self.memory_get(&ramp);
+            }
+            Instruction::SW(_) => {
+                row[RAMP.base_table_index()] =
+                    Self::memory_address(rs_val.value() as u32, operand.value() as u32);
+                row[RAMV.base_table_index()] = self.reg(operands.rd);
+            }
+            Instruction::PUBSEEK(_) | Instruction::SECSEEK(_) => {
+                row[RAMP.base_table_index()] =
+                    Self::memory_address(rs_val.value() as u32, operand.value() as u32);
+            }
+            Instruction::SEQ(_) | Instruction::SNE(_) => {
+                row[HV.base_table_index()] = (rs_val - operand).inverse_or_zero();
+            }
+            Instruction::BEQ(_) | Instruction::BNE(_) => {
+                row[HV.base_table_index()] = (rs_val - rt_val).inverse_or_zero();
+            }
+            Instruction::SYSCALL => {
+                if let Ok(syscall) = Syscall::try_from(rs_val.value() as u32) {
+                    let service_flag =
+                        zmips_processor_table::SYSCALL_SERVICE_FLAGS[syscall as usize];
+                    row[service_flag.base_table_index()] = BFieldElement::one();
+                }
+            }
+            _ => (),
+        }
+
+        row
+    }
+
+    /// The content of the given register.
+    fn reg(&self, reg: Reg) -> BFieldElement {
+        let index: usize = reg.into();
+        self.regs_pool.regs[index]
+    }
+
+    /// The value of the second source operand: either the immediate or the register's content.
+    fn operand_value(&self, a: RegA) -> BFieldElement {
+        match a {
+            RegA::Imm(imm) => imm.into(),
+            RegA::RegName(reg) => self.reg(reg),
+        }
+    }
+
+    /// The content of the given register as a 32-bit word. Registers only ever hold words.
+    fn word(&self, reg: Reg) -> u32 {
+        self.reg(reg).value() as u32
+    }
+
+    /// The value of the second source operand as a 32-bit word.
+    fn operand_word(&self, a: RegA) -> u32 {
+        self.operand_value(a).value() as u32
+    }
+
+    /// The address `base + offset` accessed by `lw` and `sw`, or the tape position targeted by
+    /// `pubseek` and `secseek`. Like all words, addresses wrap around modulo 2^32, so negative
+    /// offsets reach below the base address.
+    fn memory_address(base: u32, offset: u32) -> BFieldElement {
+        base.wrapping_add(offset).into()
+    }
+
+    /// Write to the given register. Like in MIPS, register `$zero` is hardwired to 0: writes to it
+    /// are discarded.
+    fn set_reg(&mut self, reg: Reg, value: BFieldElement) {
+        if reg == Reg::Zero {
+            return;
+        }
+        let index: usize = reg.into();
+        self.regs_pool.regs[index] = value;
     }
-    /// Perform the state transition as a mutable operation on `self`.
+
+    fn set_word(&mut self, reg: Reg, word: u32) {
+        self.set_reg(reg, word.into());
+    }
+
+    /// Continue at the given address if the branch condition holds, else at the next instruction.
+    fn branch_if(&mut self, condition: bool, addr: BFieldElement) {
+        self.instruction_pointer = match condition {
+            true => addr.value() as usize,
+            false => self.instruction_pointer + 1,
+        };
+    }
+
+    /// Read the next symbol from the given input tape, which must be a 32-bit word.
+    fn read_word(input: &mut InputTape) -> Result<u32> {
+        let symbol = input.read()?;
+        match u32::try_from(symbol.value()) {
+            Ok(word) => Ok(word),
+            Err(_) => vm_err(FailedU32Conversion(symbol)),
+        }
+    }
+
+    /// Perform the state transition as a mutable operation on `self`. A failing instruction
+    /// results in a [`VmError`] reporting the state in which the instruction failed.
     pub fn step_mut(
         &mut self,
-        stdin: &mut Vec<BFieldElement>,
-        secret_in: &mut Vec<BFieldElement>,
+        public_input: &mut InputTape,
+        secret_input: &mut InputTape,
+    ) -> Result<Option<VMOutput>> {
+        let instruction_pointer = self.instruction_pointer;
+        let cycle_count = self.cycle_count;
+        self.execute(public_input, secret_input).map_err(|err| {
+            // zMIPS instructions fail before writing to any register.
+            let registers = REG_NAMES
+                .iter()
+                .zip(self.regs_pool.regs.iter())
+                .map(|(name, &value)| (format!("${name}"), value))
+                .collect();
+            self.report(err, instruction_pointer, cycle_count, registers)
+        })
+    }
+
+    fn execute(
+        &mut self,
+        public_input: &mut InputTape,
+        secret_input: &mut InputTape,
     ) -> Result<Option<VMOutput>> {
         // All instructions increase the cycle count
         self.cycle_count += 1;
@@ -185,379 +527,266 @@ impl<'pgm> VMState<'pgm, triton_zmips::instruction::Instruction> {
 
         match self.current_instruction()? {
             Instruction::BEQ((r1, r2, addr)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r1: &BFieldElement = &self.regs_pool.regs[v1];
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                self.instruction_pointer = if r1.value() == r2.value() {
+                self.instruction_pointer = if self.reg(r1) == self.reg(r2) {
                     addr.value() as usize
                 } else {
                     self.instruction_pointer + 1
                 };
             }
             Instruction::BNE((r1, r2, addr)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r1: &BFieldElement = &self.regs_pool.regs[v1];
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                self.instruction_pointer = if r1.value() != r2.value() {
+                self.instruction_pointer = if self.reg(r1) != self.reg(r2) {
                     addr.value() as usize
                 } else {
                     self.instruction_pointer + 1
                 };
             }
             Instruction::BLT((r1, r2, addr)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r1: &BFieldElement = &self.regs_pool.regs[v1];
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                self.instruction_pointer = if r1.value() < r2.value() {
-                    addr.value() as usize
-                } else {
-                    self.instruction_pointer + 1
-                };
+                let is_less = (self.word(r1) as i32) < (self.word(r2) as i32);
+                self.branch_if(is_less, addr);
             }
             Instruction::BLE((r1, r2, addr)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r1: &BFieldElement = &self.regs_pool.regs[v1];
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                self.instruction_pointer = if r1.value() <= r2.value() {
-                    addr.value() as usize
-                } else {
-                    self.instruction_pointer + 1
-                };
+                let is_less_or_equal = (self.word(r1) as i32) <= (self.word(r2) as i32);
+                self.branch_if(is_less_or_equal, addr);
             }
             Instruction::BGT((r1, r2, addr)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r1: &BFieldElement = &self.regs_pool.regs[v1];
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                self.instruction_pointer = if r1.value() > r2.value() {
-                    addr.value() as usize
-                } else {
-                    self.instruction_pointer + 1
-                };
+                let is_greater = (self.word(r1) as i32) > (self.word(r2) as i32);
+                self.branch_if(is_greater, addr);
+            }
+            Instruction::BLTU((r1, r2, addr)) => {
+                let is_less = self.word(r1) < self.word(r2);
+                self.branch_if(is_less, addr);
+            }
+            Instruction::BGEU((r1, r2, addr)) => {
+                let is_greater_or_equal = self.word(r1) >= self.word(r2);
+                self.branch_if(is_greater_or_equal, addr);
             }
             Instruction::SEQ((r1, r2, a)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r1: &BFieldElement = &self.regs_pool.regs[v1];
-                match a {
-                    RegA::Imm(imm) => {
-                        self.regs_pool.regs[v2] =
-                            BFieldElement::new((r1.value() == imm as u64) as u64);
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v2] = BFieldElement::new(
-                            (r1.value() == BFieldElement::from(a).value()) as u64,
-                        );
-                    }
-                }
+                let is_equal = self.reg(r1) == self.operand_value(a);
+                self.set_word(r2, is_equal as u32);
                 self.instruction_pointer += 1;
             }
             Instruction::SNE((r1, r2, a)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r1: &BFieldElement = &self.regs_pool.regs[v1];
-                match a {
-                    RegA::Imm(imm) => {
-                        self.regs_pool.regs[v2] =
-                            BFieldElement::new((r1.value() != imm as u64) as u64);
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v2] = BFieldElement::new(
-                            (r1.value() != BFieldElement::from(a).value()) as u64,
-                        );
-                    }
-                }
+                let is_unequal = self.reg(r1) != self.operand_value(a);
+                self.set_word(r2, is_unequal as u32);
                 self.instruction_pointer += 1;
             }
             Instruction::SLT((r1, r2, a)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r1: &BFieldElement = &self.regs_pool.regs[v1];
-                match a {
-                    RegA::Imm(imm) => {
-                        self.regs_pool.regs[v2] =
-                            BFieldElement::new((r1.value() < imm as u64) as u64);
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v2] = BFieldElement::new(
-                            (r1.value() < BFieldElement::from(a).value()) as u64,
-                        );
-                    }
-                }
+                let is_less = (self.word(r1) as i32) < (self.operand_word(a) as i32);
+                self.set_word(r2, is_less as u32);
+                self.instruction_pointer += 1;
+            }
+            Instruction::SLTU((r1, r2, a)) => {
+                let is_less = self.word(r1) < self.operand_word(a);
+                self.set_word(r2, is_less as u32);
                 self.instruction_pointer += 1;
             }
             Instruction::SLE((r1, r2, a)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r1: &BFieldElement = &self.regs_pool.regs[v1];
-                match a {
-                    RegA::Imm(imm) => {
-                        self.regs_pool.regs[v2] =
-                            BFieldElement::new((r1.value() <= imm as u64) as u64);
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v2] = BFieldElement::new(
-                            (r1.value() <= BFieldElement::from(a).value()) as u64,
-                        );
-                    }
-                }
+                let is_less_or_equal = (self.word(r1) as i32) <= (self.operand_word(a) as i32);
+                self.set_word(r2, is_less_or_equal as u32);
                 self.instruction_pointer += 1;
             }
             Instruction::J(addr) => {
                 self.instruction_pointer = addr.value() as usize;
             }
             Instruction::JR(r) => {
-                self.instruction_pointer = BFieldElement::from(r).value() as usize;
+                self.instruction_pointer = self.reg(r).value() as usize;
+            }
+            Instruction::JAL(addr) => {
+                let return_address = self.instruction_pointer as u32 + 1;
+                self.set_word(Reg::Ra, return_address);
+                self.instruction_pointer = addr.value() as usize;
+            }
+            Instruction::JALR(r) => {
+                let target = self.reg(r).value() as usize;
+                let return_address = self.instruction_pointer as u32 + 1;
+                self.set_word(Reg::Ra, return_address);
+                self.instruction_pointer = target;
             }
             Instruction::LW((r1, a, r2)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                match a {
-                    RegA::Imm(imm) => {
-                        let addr = BFieldElement::from((imm as u64 + r2.value()) as u64);
-                        self.regs_pool.regs[v1] = self.memory_get(&addr);
-                    }
-                    RegA::RegName(a) => {
-                        let addr = BFieldElement::from(
-                            (BFieldElement::from(a).value() + r2.value()) as u64,
-                        );
-                        self.regs_pool.regs[v1] = self.memory_get(&addr);
-                    }
-                }
+                let addr = Self::memory_address(self.word(r2), self.operand_word(a));
+                self.set_reg(r1, self.memory_get(&addr));
                 self.instruction_pointer += 1;
             }
             Instruction::SW((r1, a, r2)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r1: &BFieldElement = &self.regs_pool.regs[v1];
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                match a {
-                    RegA::Imm(imm) => {
-                        let addr = BFieldElement::new((imm as u64 + r2.value()) as u64);
-                        self.memory_set(addr, *r1);
-                    }
-                    RegA::RegName(a) => {
-                        let addr = BFieldElement::new(
-                            (BFieldElement::from(a).value() + r2.value()) as u64,
-                        );
-                        self.memory_set(addr, *r1);
-                    }
-                }
+                let addr = Self::memory_address(self.word(r2), self.operand_word(a));
+                self.memory_set(addr, self.reg(r1));
                 self.instruction_pointer += 1;
             }
             Instruction::ADD((r1, r2, a)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                match a {
-                    RegA::Imm(imm) => {
-                        self.regs_pool.regs[v1] = *r2 + BFieldElement::from(imm);
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v1] = *r2 + BFieldElement::from(a);
-                    }
-                }
+                let lhs = self.word(r2) as i32;
+                let rhs = self.operand_word(a) as i32;
+                let sum = match lhs.checked_add(rhs) {
+                    Some(sum) => sum,
+                    None => return vm_err(IntegerOverflow),
+                };
+                self.set_word(r1, sum as u32);
+                self.instruction_pointer += 1;
+            }
+            Instruction::ADDU((r1, r2, a)) => {
+                let sum = self.word(r2).wrapping_add(self.operand_word(a));
+                self.set_word(r1, sum);
                 self.instruction_pointer += 1;
             }
             Instruction::SUB((r1, r2, a)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                match a {
-                    RegA::Imm(imm) => {
-                        self.regs_pool.regs[v1] = *r2 - BFieldElement::from(imm);
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v1] = *r2 - BFieldElement::from(a);
-                    }
-                }
+                let lhs = self.word(r2) as i32;
+                let rhs = self.operand_word(a) as i32;
+                let difference = match lhs.checked_sub(rhs) {
+                    Some(difference) => difference,
+                    None => return vm_err(IntegerOverflow),
+                };
+                self.set_word(r1, difference as u32);
+                self.instruction_pointer += 1;
+            }
+            Instruction::SUBU((r1, r2, a)) => {
+                let difference = self.word(r2).wrapping_sub(self.operand_word(a));
+                self.set_word(r1, difference);
                 self.instruction_pointer += 1;
             }
             Instruction::MULT((r1, r2, a)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                match a {
-                    RegA::Imm(imm) => {
-                        self.regs_pool.regs[v1] = *r2 * BFieldElement::from(imm);
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v1] = *r2 * BFieldElement::from(a);
-                    }
-                }
+                let product = self.word(r2).wrapping_mul(self.operand_word(a));
+                self.set_word(r1, product);
                 self.instruction_pointer += 1;
             }
             Instruction::DIV((r1, r2, a)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                match a {
-                    RegA::Imm(imm) => {
-                        self.regs_pool.regs[v1] = *r2 / BFieldElement::from(imm);
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v1] = *r2 / BFieldElement::from(a);
-                    }
+                let numerator = self.word(r2) as i32;
+                let denominator = self.operand_word(a) as i32;
+                if denominator == 0 {
+                    return vm_err(DivisionByZero);
+                }
+                let quotient = match numerator.checked_div(denominator) {
+                    Some(quotient) => quotient,
+                    None => return vm_err(IntegerOverflow),
+                };
+                self.set_word(r1, quotient as u32);
+                self.instruction_pointer += 1;
+            }
+            Instruction::DIVU((r1, r2, a)) => {
+                let numerator = self.word(r2);
+                let denominator = self.operand_word(a);
+                if denominator == 0 {
+                    return vm_err(DivisionByZero);
                 }
+                self.set_word(r1, numerator / denominator);
                 self.instruction_pointer += 1;
             }
             Instruction::MOD((r1, r2, a)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                match a {
-                    RegA::Imm(imm) => {
-                        // FIXME: this is may not the correct way to do it...
-                        self.regs_pool.regs[v1] = BFieldElement::from(r2.value() % (imm as u64));
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v1] =
-                            BFieldElement::from(r2.value() % BFieldElement::from(a).value());
-                    }
+                let numerator = self.word(r2) as i32;
+                let denominator = self.operand_word(a) as i32;
+                if denominator == 0 {
+                    return vm_err(DivisionByZero);
                 }
+                let remainder = numerator.wrapping_rem(denominator);
+                self.set_word(r1, remainder as u32);
                 self.instruction_pointer += 1;
             }
             Instruction::MOVE((r, a)) => {
-                let v1: usize = r.into();
-                match a {
-                    RegA::Imm(imm) => self.regs_pool.regs[v1] = BFieldElement::from(imm),
-                    RegA::RegName(r2) => {
-                        let v2: usize = r2.into();
-                        self.regs_pool.regs[v1] = self.regs_pool.regs[v2];
-                    }
-                }
+                self.set_reg(r, self.operand_value(a));
                 self.instruction_pointer += 1;
             }
             Instruction::LA((r, a)) => {
-                let v1: usize = r.into();
-                self.regs_pool.regs[v1] = a;
+                self.set_reg(r, a);
                 self.instruction_pointer += 1;
             }
             Instruction::AND((r1, r2, a)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                match a {
-                    RegA::Imm(imm) => {
-                        self.regs_pool.regs[v1] = BFieldElement::from(r2.value() & imm as u64);
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v1] =
-                            BFieldElement::from(r2.value() & BFieldElement::from(a).value());
-                    }
-                }
+                let result = self.word(r2) & self.operand_word(a);
+                self.set_word(r1, result);
                 self.instruction_pointer += 1;
             }
             Instruction::XOR((r1, r2, a)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                match a {
-                    RegA::Imm(imm) => {
-                        self.regs_pool.regs[v1] = BFieldElement::from(r2.value() ^ imm as u64);
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v1] =
-                            BFieldElement::from(r2.value() ^ BFieldElement::from(a).value());
-                    }
-                }
+                let result = self.word(r2) ^ self.operand_word(a);
+                self.set_word(r1, result);
                 self.instruction_pointer += 1;
             }
             Instruction::OR((r1, r2, a)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                match a {
-                    RegA::Imm(imm) => {
-                        self.regs_pool.regs[v1] = BFieldElement::from(r2.value() | imm as u64);
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v1] =
-                            BFieldElement::from(r2.value() ^ BFieldElement::from(a).value());
-                    }
-                }
+                let result = self.word(r2) | self.operand_word(a);
+                self.set_word(r1, result);
                 self.instruction_pointer += 1;
             }
             Instruction::NOT((r1, _r2, a)) => {
-                let v1: usize = r1.into();
-                match a {
-                    RegA::Imm(imm) => {
-                        self.regs_pool.regs[v1] =
-                            BFieldElement::from(!(imm as u64) & (usize::MAX) as u64);
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v1] = BFieldElement::from(
-                            BFieldElement::from(a).value() & (usize::MAX) as u64,
-                        );
-                    }
-                }
+                self.set_word(r1, !self.operand_word(a));
                 self.instruction_pointer += 1;
             }
+            // Shifts only use the lowest 5 bits of the shift amount.
             Instruction::SLL((r1, r2, a)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                match a {
-                    RegA::Imm(imm) => {
-                        self.regs_pool.regs[v1] = BFieldElement::from(r2.value() << imm as u64);
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v1] =
-                            BFieldElement::from(r2.value() << BFieldElement::from(a).value());
-                    }
-                }
+                let result = self.word(r2).wrapping_shl(self.operand_word(a));
+                self.set_word(r1, result);
                 self.instruction_pointer += 1;
             }
             Instruction::SRL((r1, r2, a)) => {
-                let v1: usize = r1.into();
-                let v2: usize = r2.into();
-                let r2: &BFieldElement = &self.regs_pool.regs[v2];
-                match a {
-                    RegA::Imm(imm) => {
-                        self.regs_pool.regs[v1] = BFieldElement::from(r2.value() >> imm as u64);
-                    }
-                    RegA::RegName(a) => {
-                        self.regs_pool.regs[v1] =
-                            BFieldElement::from(r2.value() >> BFieldElement::from(a).value());
-                    }
-                }
+                let result = self.word(r2).wrapping_shr(self.operand_word(a));
+                self.set_word(r1, result);
+                self.instruction_pointer += 1;
+            }
+            Instruction::SRA((r1, r2, a)) => {
+                let result = (self.word(r2) as i32).wrapping_shr(self.operand_word(a));
+                self.set_word(r1, result as u32);
                 self.instruction_pointer += 1;
             }
             Instruction::PUBREAD(r) => {
-                let v1: usize = r.into();
-                self.regs_pool.regs[v1] = stdin.remove(0);
+                let word = Self::read_word(public_input)?;
+                self.set_word(r, word);
                 self.instruction_pointer += 1;
             }
             Instruction::SECREAD(r) => {
-                let v1: usize = r.into();
-                self.regs_pool.regs[v1] = secret_in.remove(0);
+                let word = Self::read_word(secret_input)?;
+                self.set_word(r, word);
+                self.instruction_pointer += 1;
+            }
+            Instruction::PUBSEEK((r, a)) => {
+                public_input.seek(Self::memory_address(self.word(r), self.operand_word(a)));
+                self.instruction_pointer += 1;
+            }
+            Instruction::SECSEEK((r, a)) => {
+                secret_input.seek(Self::memory_address(self.word(r), self.operand_word(a)));
                 self.instruction_pointer += 1;
             }
-            // TODO
-            Instruction::PUBSEEK(_) => {}
-            Instruction::SECSEEK(_) => {}
             Instruction::PRINT(r) => {
-                let v1: usize = r.into();
-                let r = self.regs_pool.regs[v1];
-                vm_output = Some(WriteOutputSymbol(r));
+                vm_output = Some(WriteOutputSymbol(self.reg(r)));
                 self.instruction_pointer += 1;
             }
             Instruction::EXIT(r) => {
-                let v1: usize = r.into();
-                self.instruction_pointer += 1;
-                return vm_err(ExecuteReturnFailureValue(self.regs_pool.regs[v1]));
+                // `exit` terminates execution, just like `halt` does in Triton assembly. The exit
+                // code becomes part of the claim, whether it signals success or failure.
+                self.halting = true;
+                vm_output = Some(ExitCode(self.reg(r)));
             }
             Instruction::ANSWER(r) => {
-                let v1: usize = r.into();
-                let r = self.regs_pool.regs[v1];
-                vm_output = Some(FinalAnswer(r));
+                vm_output = Some(FinalAnswer(self.reg(r)));
                 self.instruction_pointer += 1;
             }
+            Instruction::SYSCALL => {
+                // Like SPIM and MARS, `syscall` requests the service identified by the code in
+                // register `$v0`. Its argument, if any, is in register `$a0`; read words are
+                // returned in register `$v0`.
+                let code = self.word(Reg::V0);
+                let syscall = match Syscall::try_from(code) {
+                    Ok(syscall) => syscall,
+                    Err(_) => return vm_err(UnknownSyscall(code)),
+                };
+                match syscall {
+                    Syscall::PrintInt => vm_output = Some(WriteOutputSymbol(self.reg(Reg::A0))),
+                    Syscall::ReadInt => {
+                        let word = Self::read_word(public_input)?;
+                        self.set_word(Reg::V0, word);
+                    }
+                    Syscall::ReadSecretInt => {
+                        let word = Self::read_word(secret_input)?;
+                        self.set_word(Reg::V0, word);
+                    }
+                    Syscall::Answer => vm_output = Some(FinalAnswer(self.reg(Reg::A0))),
+                    Syscall::Exit => {
+                        self.halting = true;
+                        vm_output = Some(ExitCode(BFieldElement::zero()));
+                    }
+                    Syscall::Exit2 => {
+                        self.halting = true;
+                        vm_output = Some(ExitCode(self.reg(Reg::A0)));
+                    }
+                }
+                if !self.halting {
+                    self.instruction_pointer += 1;
+                }
+            }
         }
 
         // Check that no instruction left the OpStack with too few elements
@@ -582,15 +811,15 @@ impl<'pgm> VMState<'pgm, triton_zmips::instruction::Instruction> {
     /// Given a state, compute `(next_state, vm_output)`.
     pub fn step(
         &self,
-        stdin: &mut Vec<BFieldElement>,
-        secret_in: &mut Vec<BFieldElement>,
+        public_input: &mut InputTape,
+        secret_input: &mut InputTape,
     ) -> Result<(
         VMState<'pgm, triton_zmips::instruction::Instruction>,
         Option<VMOutput>,
     )> {
         let mut next_state = self.clone();
         next_state
-            .step_mut(stdin, secret_in)
+            .step_mut(public_input, secret_input)
             .map(|vm_output| (next_state, vm_output))
     }
 }
@@ -613,11 +842,11 @@ impl<'pgm> VMState<'pgm, Instruction> {
     pub fn step(
         &self,
         stdin: &mut Vec<BFieldElement>,
-        secret_in: &mut Vec<BFieldElement>,
+        non_determinism: &mut dyn NonDeterminism,
     ) -> Result<(VMState<'pgm, Instruction>, Option<VMOutput>)> {
         let mut next_state = self.clone();
         next_state
-            .step_mut(stdin, secret_in)
+            .step_mut(stdin, non_determinism)
             .map(|vm_output| (next_state, vm_output))
     }
 
@@ -693,11 +922,42 @@ impl<'pgm> VMState<'pgm, Instruction> {
         hvs
     }
 
-    /// Perform the state transition as a mutable operation on `self`.
+    /// Perform the state transition as a mutable operation on `self`. A failing instruction
+    /// results in a [`VmError`] reporting the state in which the instruction failed.
     pub fn step_mut(
         &mut self,
         stdin: &mut Vec<BFieldElement>,
-        secret_in: &mut Vec<BFieldElement>,
+        non_determinism: &mut dyn NonDeterminism,
+    ) -> Result<Option<VMOutput>> {
+        // Failing instructions might already have popped elements off the op stack.
+        let instruction_pointer = self.instruction_pointer;
+        let cycle_count = self.cycle_count;
+        let op_stack_top = self.op_stack_top();
+        self.execute(stdin, non_determinism).map_err(|err| {
+            let registers = op_stack_top
+                .iter()
+                .enumerate()
+                .map(|(i, &element)| (format!("st{i}"), element))
+                .collect();
+            self.report(err, instruction_pointer, cycle_count, registers)
+        })
+    }
+
+    /// The top [`OP_STACK_REG_COUNT`] elements of the op stack, `st0` first. Missing elements
+    /// are 0.
+    fn op_stack_top(&self) -> [BFieldElement; OP_STACK_REG_COUNT] {
+        let mut op_stack_top = [BFieldElement::zero(); OP_STACK_REG_COUNT];
+        let elements = self.op_stack.stack.iter().rev();
+        for (register, &element) in op_stack_top.iter_mut().zip(elements) {
+            *register = element;
+        }
+        op_stack_top
+    }
+
+    fn execute(
+        &mut self,
+        stdin: &mut Vec<BFieldElement>,
+        non_determinism: &mut dyn NonDeterminism,
     ) -> Result<Option<VMOutput>> {
         // All instructions increase the cycle count
         self.cycle_count += 1;
@@ -719,8 +979,13 @@ impl<'pgm> VMState<'pgm, Instruction> {
                 self.instruction_pointer += 2;
             }
 
-            Divine(_) => {
-                let elem = secret_in.remove(0);
+            Divine(hint) => {
+                let elem = match hint {
+                    Some(hint) => self.divine_with_hint(hint)?,
+                    None => non_determinism
+                        .next_token()
+                        .ok_or_else(|| vm_fail(SecretInputExhausted))?,
+                };
                 self.op_stack.push(elem);
                 self.instruction_pointer += 1;
             }
@@ -771,11 +1036,7 @@ impl<'pgm> VMState<'pgm, Instruction> {
             Assert => {
                 let elem = self.op_stack.pop()?;
                 if !elem.is_one() {
-                    return vm_err(AssertionFailed(
-                        self.instruction_pointer,
-                        self.cycle_count,
-                        elem,
-                    ));
+                    return vm_err(AssertionFailed(elem));
                 }
                 self.instruction_pointer += 1;
             }
@@ -854,15 +1115,13 @@ impl<'pgm> VMState<'pgm, Instruction> {
             }
 
             DivineSibling => {
-                self.divine_sibling(secret_in)?;
+                self.divine_sibling(non_determinism)?;
                 self.instruction_pointer += 1;
             }
 
             AssertVector => {
                 if !self.assert_vector() {
                     return vm_err(AssertionFailed(
-                        self.instruction_pointer,
-                        self.cycle_count,
                         self.op_stack
                             .peek(0)
                             .expect("Could not unwrap top of stack."),
@@ -1050,7 +1309,7 @@ impl<'pgm> VMState<'pgm, Instruction> {
         let hvs = self.derive_helper_variables();
         let ramp = self.ramp.into();
 
-        row[CLK.base_table_index()] = BFieldElement::new(self.cycle_count as u64);
+        row[CLK.base_table_index()] = BFieldElement::new(self.cycle_count);
         row[PreviousInstruction.base_table_index()] = self.previous_instruction;
         row[IP.base_table_index()] = (self.instruction_pointer as u32).into();
         row[CI.base_table_index()] = current_instruction.opcode_b();
@@ -1204,7 +1463,73 @@ impl<'pgm> VMState<'pgm, Instruction> {
         }
     }
 
-    fn divine_sibling(&mut self, secret_in: &mut Vec<BFieldElement>) -> Result<()> {
+    /// The element instruction `divine` pushes when given the [`DivinationHint`], computed from
+    /// the stack.
+    fn divine_with_hint(&self, hint: DivinationHint) -> Result<BFieldElement> {
+        let st0 = self.op_stack.safe_peek(ST0);
+        match hint {
+            DivinationHint::Quotient => {
+                let to_u32 = |elem: BFieldElement| {
+                    u32::try_from(elem).map_err(|_| vm_fail(FailedU32Conversion(elem)))
+                };
+                let numer = to_u32(st0)?;
+                let denom = to_u32(self.op_stack.safe_peek(ST1))?;
+                if denom.is_zero() {
+                    return vm_err(DivisionByZero);
+                }
+                Ok(BFieldElement::new((numer / denom) as u64))
+            }
+            DivinationHint::Inverse => {
+                if st0.is_zero() {
+                    return vm_err(InverseOfZero);
+                }
+                Ok(st0.inverse())
+            }
+            DivinationHint::Sqrt => {
+                let root = Self::square_root(st0).ok_or_else(|| vm_fail(NotASquare(st0)))?;
+                Ok(std::cmp::min_by_key(root, -root, |root| root.value()))
+            }
+        }
+    }
+
+    /// A square root of the given element, if any, using the Tonelli-Shanks algorithm.
+    fn square_root(elem: BFieldElement) -> Option<BFieldElement> {
+        if elem.is_zero() {
+            return Some(elem);
+        }
+
+        // Euler's criterion
+        let legendre_symbol = elem.mod_pow((BFieldElement::P - 1) / 2);
+        if !legendre_symbol.is_one() {
+            return None;
+        }
+
+        // p - 1 = 2^32 · q with odd q
+        let two_adicity = (BFieldElement::P - 1).trailing_zeros();
+        let q = (BFieldElement::P - 1) >> two_adicity;
+
+        // 7 generates the multiplicative group, making it a quadratic non-residue.
+        let mut c = BFieldElement::new(7).mod_pow(q);
+        let mut t = elem.mod_pow(q);
+        let mut root = elem.mod_pow(q.div_ceil(2));
+        let mut m = two_adicity;
+        while !t.is_one() {
+            let mut i = 0;
+            let mut t_pow = t;
+            while !t_pow.is_one() {
+                t_pow = t_pow * t_pow;
+                i += 1;
+            }
+            let b = c.mod_pow(1 << (m - i - 1));
+            m = i;
+            c = b * b;
+            t *= c;
+            root *= b;
+        }
+        Some(root)
+    }
+
+    fn divine_sibling(&mut self, non_determinism: &mut dyn NonDeterminism) -> Result<()> {
         // st0-st4
         let _ = self.op_stack.pop_n::<{ DIGEST_LENGTH }>()?;
 
@@ -1217,18 +1542,11 @@ impl<'pgm> VMState<'pgm, Instruction> {
             .try_into()
             .unwrap_or_else(|_| panic!("{node_index_elem:?} is not a u32"));
 
-        // nondeterministic guess, flipped
-        let sibling_digest: [BFieldElement; DIGEST_LENGTH] = {
-            let mut tmp = [
-                secret_in.remove(0),
-                secret_in.remove(0),
-                secret_in.remove(0),
-                secret_in.remove(0),
-                secret_in.remove(0),
-            ];
-            tmp.reverse();
-            tmp
-        };
+        // nondeterministic guess
+        let sibling_digest: [BFieldElement; DIGEST_LENGTH] = non_determinism
+            .next_digest()
+            .ok_or_else(|| vm_fail(SecretDigestsExhausted))?
+            .values();
 
         // least significant bit
         let hv0 = node_index % 2;
@@ -1277,6 +1595,9 @@ impl<'pgm> Display for VMState<'pgm, Instruction> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.current_instruction() {
             Ok(_) => {
+                if let Some(source_location) = self.source_location(self.instruction_pointer) {
+                    writeln!(f, "at {source_location}")?;
+                }
                 let row = self.to_processor_row();
                 write!(f, "{}", ProcessorTraceRow { row: row.view() })
             }
@@ -1285,202 +1606,298 @@ impl<'pgm> Display for VMState<'pgm, Instruction> {
     }
 }
 
-/// Simulate, _i.e._, execute a [`Program`]. Returns
+impl<'pgm> Display for VMState<'pgm, triton_zmips::instruction::Instruction> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let current_instruction = match self.current_instruction() {
+            Ok(instruction) => instruction,
+            Err(_) => return write!(f, "END-OF-FILE"),
+        };
+        write!(
+            f,
+            "ip: {} ╷ clk: {} ╷ {current_instruction}",
+            self.instruction_pointer, self.cycle_count
+        )?;
+        match self.source_location(self.instruction_pointer) {
+            Some(source_location) => writeln!(f, " ╷ {source_location}")?,
+            None => writeln!(f)?,
+        }
+        for (names, values) in REG_NAMES.chunks(4).zip(self.regs_pool.regs.chunks(4)) {
+            let registers = names
+                .iter()
+                .zip(values)
+                .map(|(name, value)| format!("${name: <4} {: >10}", value.value()))
+                .collect::<Vec<_>>();
+            writeln!(f, "{}", registers.join(" │ "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Limits on the resources the execution of a program may use. Execution exceeding the budget
+/// is aborted with [`CycleLimitExceeded`] or [`PaddedHeightLimitExceeded`], before proof
+/// generation allocates the tables holding the execution trace. The default budget is unlimited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionBudget {
+    /// The maximum number of cycles the program may execute.
+    pub max_cycle_count: u64,
+
+    /// The maximum padded height of the tables recording the execution trace. Since the
+    /// processor tables have one row per cycle, this also limits the number of cycles.
+    pub max_padded_height: usize,
+}
+
+impl Default for ExecutionBudget {
+    fn default() -> Self {
+        Self {
+            max_cycle_count: u64::MAX,
+            max_padded_height: usize::MAX,
+        }
+    }
+}
+
+impl ExecutionBudget {
+    pub fn new(max_cycle_count: u64, max_padded_height: usize) -> Self {
+        Self {
+            max_cycle_count,
+            max_padded_height,
+        }
+    }
+}
+
+/// Simulate, _i.e._, execute a [`Program`] on the given public input and [`NonDeterminism`].
+/// Returns
 /// 1. an [`AlgebraicExecutionTrace`],
 /// 1. the output of the program, and
 /// 1. an [`anyhow::Error`] if the program failed to execute.
 ///
-/// On premature termination of the VM, returns the `AlgebraicExecutionTrace` and output for the
-/// execution up to the point of failure.
+/// Execution is aborted if it exceeds the given [`ExecutionBudget`]. On premature termination
+/// of the VM, returns the `AlgebraicExecutionTrace` and output for the execution up to the point
+/// of failure.
 ///
 /// See also [`debug`] and [`run`].
 pub fn simulate(
     program: Box<dyn AbstractProgram>,
-    mut stdin: Vec<BFieldElement>,
-    mut secret_in: Vec<BFieldElement>,
+    stdin: Vec<BFieldElement>,
+    non_determinism: Box<dyn NonDeterminism>,
+    budget: ExecutionBudget,
 ) -> (
     AlgebraicExecutionTrace,
     Vec<BFieldElement>,
     Option<anyhow::Error>,
 ) {
-    let mut aet = AlgebraicExecutionTrace::new(program.clone());
-    match program
-        .as_any()
-        .downcast_ref::<triton_opcodes::program::Program>()
-    {
-        None => {
-            let program = program
-                .as_any()
-                .downcast_ref::<triton_zmips::program::Program>()
-                .unwrap();
-            let mut state = VMState::new_zmips(&program.instructions);
-            // let mut state = VMState::new(program.get_instructions());
-            assert_eq!(program.len_bwords(), aet.instruction_multiplicities.len());
-
-            let mut stdout = vec![];
-            while !state.halting {
-                aet.processor_trace
-                    .push_row(state.to_processor_row().view())
-                    .expect("shapes must be identical");
-
-                if state.instruction_pointer < aet.instruction_multiplicities.len() {
-                    aet.instruction_multiplicities[state.instruction_pointer] += 1;
-                } else {
-                    let failure_reason =
-                        vm_fail(InstructionPointerOverflow(state.instruction_pointer));
-                    return (aet, stdout, Some(failure_reason));
-                }
+    let simulation = Simulation {
+        stdin,
+        non_determinism,
+        budget,
+    };
+    match machine::dispatch(program.as_ref(), simulation) {
+        Ok(simulation_result) => simulation_result,
+        Err(err) => (AlgebraicExecutionTrace::new(program), vec![], Some(err)),
+    }
+}
 
-                let vm_output = match state.step_mut(&mut stdin, &mut secret_in) {
-                    Err(err) => return (aet, stdout, Some(err)),
-                    Ok(vm_output) => vm_output,
-                };
-                match vm_output {
-                    Some(Tip5Trace(Hash, tip5_trace)) => aet.append_hash_trace(*tip5_trace),
-                    Some(Tip5Trace(instruction, tip5_trace)) => {
-                        aet.append_sponge_trace(instruction, *tip5_trace)
-                    }
-                    Some(U32TableEntries(u32_entries)) => {
-                        for u32_entry in u32_entries {
-                            aet.u32_entries
-                                .entry(u32_entry)
-                                .and_modify(|multiplicity| *multiplicity += 1)
-                                .or_insert(1);
-                        }
-                    }
-                    Some(WriteOutputSymbol(written_word)) => stdout.push(written_word),
-                    None => (),
-                    _ => {}
-                }
-            }
+/// Simulate the given program on the [`Machine`] `M`. See also [`simulate`], which determines
+/// the machine from the program's type.
+pub fn simulate_on<'pgm, M>(
+    program: &'pgm <M::Isa as Isa>::Program,
+    stdin: Vec<BFieldElement>,
+    non_determinism: Box<dyn NonDeterminism>,
+    budget: ExecutionBudget,
+) -> (
+    AlgebraicExecutionTrace,
+    Vec<BFieldElement>,
+    Option<anyhow::Error>,
+)
+where
+    M: Machine<'pgm, Trace = AlgebraicExecutionTrace, Output = VMOutput>,
+{
+    let mut aet = AlgebraicExecutionTrace::new(program.clone_());
+    let mut machine = M::new(program, stdin, non_determinism);
+    machine.initialize_trace(&mut aet);
+
+    let mut stdout = vec![];
+    let mut num_executed_cycles = 0;
+    while !machine.is_halting() {
+        if num_executed_cycles == budget.max_cycle_count {
+            let failure_reason = vm_fail(CycleLimitExceeded(budget.max_cycle_count));
+            return (aet, stdout, Some(failure_reason));
+        }
+        match machine.record_step(&mut aet) {
+            Err(err) => return (aet, stdout, Some(err)),
+            Ok(Some(WriteOutputSymbol(written_word))) => stdout.push(written_word),
+            Ok(_) => (),
+        }
+        num_executed_cycles += 1;
 
-            (aet, stdout, None)
+        // Every cycle adds a row to a processor table, bounding the padded height from below.
+        if num_executed_cycles > budget.max_padded_height as u64 {
+            let failure_reason = vm_fail(PaddedHeightLimitExceeded(budget.max_padded_height));
+            return (aet, stdout, Some(failure_reason));
         }
-        Some(program) => {
-            let mut state = VMState::new_triton(&program.instructions);
-            // let mut state = VMState::new(program.get_instructions());
-            assert_eq!(program.len_bwords(), aet.instruction_multiplicities.len());
+    }
 
-            let mut stdout = vec![];
-            while !state.halting {
-                aet.processor_trace
-                    .push_row(state.to_processor_row().view())
-                    .expect("shapes must be identical");
+    if MasterBaseTable::padded_height(&aet) > budget.max_padded_height {
+        let failure_reason = vm_fail(PaddedHeightLimitExceeded(budget.max_padded_height));
+        return (aet, stdout, Some(failure_reason));
+    }
 
-                if state.instruction_pointer < aet.instruction_multiplicities.len() {
-                    aet.instruction_multiplicities[state.instruction_pointer] += 1;
-                } else {
-                    let failure_reason =
-                        vm_fail(InstructionPointerOverflow(state.instruction_pointer));
-                    return (aet, stdout, Some(failure_reason));
-                }
+    (aet, stdout, None)
+}
 
-                let vm_output = match state.step_mut(&mut stdin, &mut secret_in) {
-                    Err(err) => return (aet, stdout, Some(err)),
-                    Ok(vm_output) => vm_output,
-                };
-                match vm_output {
-                    Some(Tip5Trace(Hash, tip5_trace)) => aet.append_hash_trace(*tip5_trace),
-                    Some(Tip5Trace(instruction, tip5_trace)) => {
-                        aet.append_sponge_trace(instruction, *tip5_trace)
-                    }
-                    Some(U32TableEntries(u32_entries)) => {
-                        for u32_entry in u32_entries {
-                            aet.u32_entries
-                                .entry(u32_entry)
-                                .and_modify(|multiplicity| *multiplicity += 1)
-                                .or_insert(1);
-                        }
-                    }
-                    Some(WriteOutputSymbol(written_word)) => stdout.push(written_word),
-                    Some(FinalAnswer(answer)) => {
-                        // aet.final_answer = Some(answer);
-                    }
-                    None => (),
-                }
-            }
+struct Simulation {
+    stdin: Vec<BFieldElement>,
+    non_determinism: Box<dyn NonDeterminism>,
+    budget: ExecutionBudget,
+}
 
-            (aet, stdout, None)
-        }
+impl MachineVisitor for Simulation {
+    type Output = (
+        AlgebraicExecutionTrace,
+        Vec<BFieldElement>,
+        Option<anyhow::Error>,
+    );
+
+    fn visit<'pgm, M>(self, program: &'pgm <M::Isa as Isa>::Program) -> Self::Output
+    where
+        M: Machine<'pgm, Trace = AlgebraicExecutionTrace, Output = VMOutput>,
+    {
+        simulate_on::<M>(program, self.stdin, self.non_determinism, self.budget)
     }
 }
 
-/// Similar to [`run`], but also returns a [`Vec`] of [`VMState`]s, one for each step of the VM.
-/// On premature termination of the VM, returns all [`VMState`]s and output for the execution up
-/// to the point of failure.
+/// Similar to [`run`], but also returns a [`Vec`] of [`Machine`]s, one for each step of the VM.
+/// Each machine holds a [`VMState`], giving access to, _e.g._, the op stack, the registers, the
+/// RAM, the jump stack, and the Sponge state. On premature termination of the VM, returns all
+/// machines and output for the execution up to the point of failure.
 ///
-/// The VM's initial state is either the provided `initial_state`, or a new [`VMState`] if
-/// `initial_state` is `None`. The initial state is not included in the returned [`Vec`] of
-/// [`VMState`]s. The initial state is the state of the VM before the first instruction is
-/// executed. The initial state must contain the same program as provided by parameter `program`,
-/// else the method will panic.
+/// The VM's initial state is either the provided `initial_state`, or a new [`Machine`] for the
+/// given `program` and input if `initial_state` is `None`. Since a machine's state includes its
+/// remaining input, `stdin` and `non_determinism` are ignored if an `initial_state` is provided. The
+/// initial state is not included in the returned [`Vec`]. The initial state is the state of the
+/// VM before the first instruction is executed.
 ///
 /// If `num_cycles_to_execute` is `Some(number_of_cycles)`, the VM will execute at most
 /// `number_of_cycles` cycles. If `num_cycles_to_execute` is `None`, the VM will execute until
 /// it halts.
 ///
 /// See also [`simulate`].
-// pub fn debug<'pgm, T: Debug + PartialEq + Clone>(
-//     program: &'pgm [T],
-//     mut stdin: Vec<BFieldElement>,
-//     mut secret_in: Vec<BFieldElement>,
-//     initial_state: Option<VMState<'pgm, T>>,
-//     num_cycles_to_execute: Option<u32>,
-// ) -> (
-//     Vec<VMState<'pgm, T>>,
-//     Vec<BFieldElement>,
-//     Option<anyhow::Error>,
-// ) {
-//     let mut states = vec![];
-//     let mut stdout = vec![];
-//     let mut current_state = initial_state.unwrap_or(VMState::new(program));
-//     let max_cycles = match num_cycles_to_execute {
-//         Some(number_of_cycles) => current_state.cycle_count + number_of_cycles,
-//         None => u32::MAX,
-//     };
-//
-//     assert_eq!(
-//         current_state.program, program,
-//         "The (optional) initial state must be for the given program."
-//     );
-//
-//     // while !current_state.halting && current_state.cycle_count < max_cycles {
-//     //     states.push(current_state.clone());
-//     //     let step = current_state.step(&mut stdin, &mut secret_in);
-//     //     let (next_state, vm_output) = match step {
-//     //         Err(err) => return (states, stdout, Some(err)),
-//     //         Ok((next_state, vm_output)) => (next_state, vm_output),
-//     //     };
-//     //
-//     //     if let Some(WriteOutputSymbol(written_word)) = vm_output {
-//     //         stdout.push(written_word);
-//     //     }
-//     //     current_state = next_state;
-//     // }
-//
-//     (states, stdout, None)
-// }
+pub fn debug<'pgm, M>(
+    program: &'pgm <M::Isa as Isa>::Program,
+    stdin: Vec<BFieldElement>,
+    non_determinism: Box<dyn NonDeterminism>,
+    initial_state: Option<M>,
+    num_cycles_to_execute: Option<u64>,
+) -> (Vec<M>, Vec<BFieldElement>, Option<anyhow::Error>)
+where
+    M: Machine<'pgm, Output = VMOutput> + Clone,
+{
+    let mut states = vec![];
+    let mut stdout = vec![];
+    let mut current_state =
+        initial_state.unwrap_or_else(|| M::new(program, stdin, non_determinism));
+    let max_cycles = num_cycles_to_execute.unwrap_or(u64::MAX);
+
+    let mut num_executed_cycles = 0;
+    while !current_state.is_halting() && num_executed_cycles < max_cycles {
+        match current_state.step() {
+            Err(err) => return (states, stdout, Some(err)),
+            Ok(Some(WriteOutputSymbol(written_word))) => stdout.push(written_word),
+            Ok(_) => (),
+        }
+        states.push(current_state.clone());
+        num_executed_cycles += 1;
+    }
 
-/// Run Triton VM on the given [`Program`] with the given public and secret input.
+    (states, stdout, None)
+}
+
+/// Run Triton VM on the given [`Program`] with the given public input and [`NonDeterminism`],
+/// without recording an [`AlgebraicExecutionTrace`]. Returns
+/// 1. the output of the program,
+/// 1. the final answer of the program, if any, and
+/// 1. an [`anyhow::Error`] if the program failed to execute.
+///
+/// Execution is aborted with [`CycleLimitExceeded`] if the program does not terminate within
+/// `cycle_limit` cycles. On premature termination of the VM, returns the output and final answer
+/// up to the point of failure.
 ///
 /// See also [`simulate`] and [`debug`].
 pub fn run(
     program: Box<dyn AbstractProgram>,
-    mut stdin: Vec<BFieldElement>,
-    mut secret_in: Vec<BFieldElement>,
-) -> Result<Vec<BFieldElement>, anyhow::Error> {
-    let mut state = VMState::new(&program.get_instructions());
-    // let mut stdout = vec![];
-    let stdout = vec![];
-
-    // while !state.halting {
-    //     let vm_output = state.step_mut(&mut stdin, &mut secret_in)?;
-    //     if let Some(WriteOutputSymbol(written_word)) = vm_output {
-    //         stdout.push(written_word);
-    //     }
-    // }
-
-    Ok(stdout)
+    stdin: Vec<BFieldElement>,
+    non_determinism: Box<dyn NonDeterminism>,
+    cycle_limit: u64,
+) -> (
+    Vec<BFieldElement>,
+    Option<BFieldElement>,
+    Option<anyhow::Error>,
+) {
+    let run = Run {
+        stdin,
+        non_determinism,
+        cycle_limit,
+    };
+    match machine::dispatch(program.as_ref(), run) {
+        Ok(run_result) => run_result,
+        Err(err) => (vec![], None, Some(err)),
+    }
+}
+
+/// Run the given program on the [`Machine`] `M`. See also [`run`], which determines the machine
+/// from the program's type.
+pub fn run_on<'pgm, M>(
+    program: &'pgm <M::Isa as Isa>::Program,
+    stdin: Vec<BFieldElement>,
+    non_determinism: Box<dyn NonDeterminism>,
+    cycle_limit: u64,
+) -> (
+    Vec<BFieldElement>,
+    Option<BFieldElement>,
+    Option<anyhow::Error>,
+)
+where
+    M: Machine<'pgm, Output = VMOutput>,
+{
+    let mut machine = M::new(program, stdin, non_determinism);
+    let mut stdout = vec![];
+    let mut final_answer = None;
+    let mut num_executed_cycles = 0;
+    while !machine.is_halting() {
+        if num_executed_cycles == cycle_limit {
+            let failure_reason = vm_fail(CycleLimitExceeded(cycle_limit));
+            return (stdout, final_answer, Some(failure_reason));
+        }
+        match machine.step() {
+            Err(err) => return (stdout, final_answer, Some(err)),
+            Ok(Some(WriteOutputSymbol(written_word))) => stdout.push(written_word),
+            Ok(Some(FinalAnswer(answer))) => final_answer = Some(answer),
+            Ok(_) => (),
+        }
+        num_executed_cycles += 1;
+    }
+
+    (stdout, final_answer, None)
+}
+
+struct Run {
+    stdin: Vec<BFieldElement>,
+    non_determinism: Box<dyn NonDeterminism>,
+    cycle_limit: u64,
+}
+
+impl MachineVisitor for Run {
+    type Output = (
+        Vec<BFieldElement>,
+        Option<BFieldElement>,
+        Option<anyhow::Error>,
+    );
+
+    fn visit<'pgm, M>(self, program: &'pgm <M::Isa as Isa>::Program) -> Self::Output
+    where
+        M: Machine<'pgm, Trace = AlgebraicExecutionTrace, Output = VMOutput>,
+    {
+        run_on::<M>(program, self.stdin, self.non_determinism, self.cycle_limit)
+    }
 }
 
 /// An Algebraic Execution Trace (AET) is the primary witness required for proof generation. It
@@ -1502,6 +1919,18 @@ pub struct AlgebraicExecutionTrace {
     /// Records the state of the processor after each instruction.
     pub processor_trace: Array2<BFieldElement>,
 
+    /// Records the state of the zMIPS register machine for each executed instruction. Empty if
+    /// the program is not a zMIPS program.
+    pub zmips_processor_trace: Array2<BFieldElement>,
+
+    /// The public input tape of the zMIPS register machine. Empty if the program is not a zMIPS
+    /// program.
+    pub public_input_tape: Vec<BFieldElement>,
+
+    /// The secret input tape of the zMIPS register machine. Empty if the program is not a zMIPS
+    /// program.
+    pub secret_input_tape: Vec<BFieldElement>,
+
     /// For the `hash` instruction, the hash trace records the internal state of the Tip5
     /// permutation for each round.
     pub hash_trace: Array2<BFieldElement>,
@@ -1520,6 +1949,13 @@ pub struct AlgebraicExecutionTrace {
 
     /// Records how often each entry in the lookup table was looked up.
     pub lookup_table_lookup_multiplicities: [u64; 1 << 8],
+
+    /// The exit code of the program. Always 0 for programs that are not zMIPS programs.
+    pub exit_code: BFieldElement,
+
+    /// The argument of the last executed `answer`, if any. Always `None` for programs that are not
+    /// zMIPS programs.
+    pub final_answer: Option<BFieldElement>,
 }
 
 impl AlgebraicExecutionTrace {
@@ -1529,14 +1965,45 @@ impl AlgebraicExecutionTrace {
             program,
             instruction_multiplicities,
             processor_trace: Array2::default([0, processor_table::BASE_WIDTH]),
+            zmips_processor_trace: Array2::default([0, zmips_processor_table::BASE_WIDTH]),
+            public_input_tape: vec![],
+            secret_input_tape: vec![],
             hash_trace: Array2::default([0, hash_table::BASE_WIDTH]),
             sponge_trace: Array2::default([0, hash_table::BASE_WIDTH]),
             u32_entries: HashMap::new(),
             cascade_table_lookup_multiplicities: HashMap::new(),
             lookup_table_lookup_multiplicities: [0; 1 << 8],
+            exit_code: BFieldElement::zero(),
+            final_answer: None,
         }
     }
 
+    /// The number of cycles spent executing the instructions of each label, according to the
+    /// program's [`SourceMap`], sorted by decreasing number of cycles. Cycles spent on
+    /// instructions without enclosing label or without known source location are attributed to
+    /// `None`.
+    pub fn profile(&self) -> Vec<(Option<String>, u64)> {
+        let source_map = self.program.source_map();
+        let mut cycles_per_label = HashMap::<Option<String>, u64>::new();
+        for (address, &multiplicity) in self.instruction_multiplicities.iter().enumerate() {
+            if multiplicity == 0 {
+                continue;
+            }
+            let label = source_map
+                .get(address)
+                .and_then(|location| location.label.clone());
+            *cycles_per_label.entry(label).or_default() += multiplicity as u64;
+        }
+
+        let mut profile = cycles_per_label.into_iter().collect::<Vec<_>>();
+        profile.sort_by(|(label, cycles), (other_label, other_cycles)| {
+            other_cycles
+                .cmp(cycles)
+                .then_with(|| label.cmp(other_label))
+        });
+        profile
+    }
+
     pub fn append_hash_trace(
         &mut self,
         hash_permutation_trace: [[BFieldElement; tip5::STATE_SIZE]; tip5::NUM_ROUNDS + 1],
@@ -1602,6 +2069,7 @@ impl AlgebraicExecutionTrace {
 
 #[cfg(test)]
 pub mod triton_vm_tests {
+    use std::any::Any;
     use std::ops::BitAnd;
     use std::ops::BitXor;
 
@@ -1611,9 +2079,12 @@ pub mod triton_vm_tests {
     use num_traits::One;
     use num_traits::Zero;
     use rand::rngs::ThreadRng;
+    use rand::seq::SliceRandom;
     use rand::Rng;
     use rand::RngCore;
     use triton_opcodes::program::Program;
+    use triton_program::AbstractInstruction;
+    use triton_program::PrecomputedNonDeterminism;
     use twenty_first::shared_math::b_field_element::BFIELD_ZERO;
     use twenty_first::shared_math::other::log_2_floor;
     use twenty_first::shared_math::other::random_elements;
@@ -1628,6 +2099,9 @@ pub mod triton_vm_tests {
     use twenty_first::util_types::merkle_tree_maker::MerkleTreeMaker;
 
     use crate::error::InstructionError;
+    use crate::error::VmError;
+    use crate::machine::TritonMachine;
+    use crate::machine::ZmipsMachine;
     use crate::op_stack::OP_STACK_REG_COUNT;
     use crate::shared_tests::SourceCodeAndInput;
     use crate::shared_tests::FIBONACCI_SEQUENCE;
@@ -1635,110 +2109,1009 @@ pub mod triton_vm_tests {
     use crate::stark::MTMaker;
     use crate::table::processor_table::ProcessorTraceRow;
 
-    use super::*;
+    use super::*;
+
+    fn pretty_print_array_view<FF: FiniteField>(array: ArrayView1<FF>) -> String {
+        array
+            .iter()
+            .map(|ff| format!("{ff}"))
+            .collect_vec()
+            .join(", ")
+    }
+
+    pub const GCD_X_Y: &str = "
+        read_io  // _ a
+        read_io  // _ a b
+        dup 1    // _ a b a
+        dup 1    // _ a b a b
+        lt       // _ a b b<a
+        skiz     // _ a b
+            swap 1   // _ d n where n > d
+
+        // ---
+        loop_cond:
+        dup 1
+        push 0 
+        eq 
+        skiz 
+            call terminate  // _ d n where d != 0
+        dup 1  // _ d n d
+        dup 1  // _ d n d n
+        div    // _ d n q r
+        swap 2 // _ d r q n
+        pop    // _ d r q
+        pop    // _ d r
+        swap 1 // _ r d
+        call loop_cond
+        // ---
+        
+        terminate:
+            // _ d n where d == 0
+            write_io // _ d
+            halt
+        ";
+
+    #[test]
+    fn initialise_table_test() {
+        let code = GCD_X_Y;
+        let program = Program::from_code(code).unwrap();
+
+        let stdin = vec![BFieldElement::new(42), BFieldElement::new(56)];
+
+        let (aet, stdout, err) = simulate(
+            Box::new(program),
+            stdin,
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+
+        println!(
+            "VM output: [{}]",
+            pretty_print_array_view(Array1::from(stdout).view())
+        );
+
+        if let Some(e) = err {
+            panic!("Execution failed: {e}");
+        }
+        for row in aet.processor_trace.rows() {
+            println!("{}", ProcessorTraceRow { row });
+        }
+    }
+
+    #[test]
+    fn initialise_table_42_test() {
+        // 1. Execute program
+        let code = "
+        push 5
+        push 18446744069414584320
+        add
+        halt
+    ";
+        let program = Program::from_code(code).unwrap();
+
+        println!("{program}");
+
+        let (aet, _, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+
+        println!("{err:?}");
+        for row in aet.processor_trace.rows() {
+            println!("{}", ProcessorTraceRow { row });
+        }
+    }
+
+    #[test]
+    fn simulate_tvm_gcd_test() {
+        let code = GCD_X_Y;
+        let program = Program::from_code(code).unwrap();
+
+        let stdin = vec![42_u64.into(), 56_u64.into()];
+        let (_, stdout, err) = simulate(
+            Box::new(program),
+            stdin,
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+
+        let stdout = Array1::from(stdout);
+        println!("VM output: [{}]", pretty_print_array_view(stdout.view()));
+
+        if let Some(e) = err {
+            panic!("Execution failed: {e}");
+        }
+
+        let expected_symbol = BFieldElement::new(14);
+        let computed_symbol = stdout[0];
+
+        assert_eq!(expected_symbol, computed_symbol);
+    }
+
+    #[test]
+    fn simulate_zmips_subroutine_calls_test() {
+        let code = "
+            pubread $a0
+            jal double
+            print $v0
+            move $a0, $v0
+            la $t0, double
+            jalr $t0
+            print $v0
+            exit $zero
+
+            double:
+                addu $v0, $a0, $a0
+                jr $ra
+        ";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+
+        let stdin = vec![21_u64.into()];
+        let (_, stdout, err) = simulate(
+            Box::new(program),
+            stdin,
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+
+        if let Some(e) = err {
+            panic!("Execution failed: {e}");
+        }
+        assert_eq!(vec![BFieldElement::new(42), BFieldElement::new(84)], stdout);
+    }
+
+    #[test]
+    fn simulate_zmips_pseudo_instructions_test() {
+        let code = "
+            li $t0, 5
+            li $t1, 1
+            loop:
+                beqz $t0, done
+                mul $t1, $t1, $t0
+                subi $t0, $t0, 1
+                b loop
+            done:
+                push $t1
+                nop
+                pop $t2
+                neg $t3, $t2
+                print $t2
+                print $t3
+                exit $zero
+        ";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let (_, stdout, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+
+        if let Some(e) = err {
+            panic!("Execution failed: {e}");
+        }
+        let expected = [120, 120_u32.wrapping_neg()].map(|word| BFieldElement::new(word.into()));
+        assert_eq!(expected.to_vec(), stdout);
+    }
+
+    #[test]
+    fn simulate_zmips_negative_immediates_test() {
+        let code = "
+            pubread $a0
+            li $fp, 64
+            add $sp, $fp, -8
+            sw $a0, -4($fp)
+            lw $t0, 4($sp)
+            addu $t1, $t0, $t0
+            print $t1
+
+            // addresses wrap around modulo 2^32
+            sw $a0, -8($zero)
+            lw $t2, 0xfffffff8($zero)
+            print $t2
+
+            li $t3, -1
+            print $t3
+            exit $zero
+        ";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let stdin = vec![21_u64.into()];
+        let (_, stdout, err) = simulate(
+            Box::new(program),
+            stdin,
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+
+        if let Some(e) = err {
+            panic!("Execution failed: {e}");
+        }
+        let expected = [42, 21, u32::MAX].map(|word| BFieldElement::new(word.into()));
+        assert_eq!(expected.to_vec(), stdout);
+    }
+
+    #[test]
+    fn simulate_zmips_signed_and_unsigned_comparisons_test() {
+        let code = "
+            li $t0, -1
+            li $t1, 1
+            li $t3, 10
+            slt $t0, $t2, $t1
+            print $t2
+            sltu $t0, $t2, $t1
+            print $t2
+            sle $t1, $t2, $t0
+            print $t2
+
+            blt $t0, $t1, signed_less
+            print $t3
+            signed_less:
+            bltu $t0, $t1, unsigned_less
+            li $t4, 2
+            print $t4
+            unsigned_less:
+            bgeu $t0, $t1, unsigned_greater_or_equal
+            print $t3
+            unsigned_greater_or_equal:
+            bgt $t0, $t1, signed_greater
+            li $t4, 3
+            print $t4
+            signed_greater:
+            ble $t0, $t0, signed_less_or_equal
+            print $t3
+            signed_less_or_equal:
+            bge $t1, $t0, signed_greater_or_equal
+            print $t3
+            signed_greater_or_equal:
+            bltz $t0, negative
+            print $t3
+            negative:
+            exit $zero
+        ";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let (_, stdout, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+
+        if let Some(e) = err {
+            panic!("Execution failed: {e}");
+        }
+        let expected = [1, 0, 0, 2, 3].map(BFieldElement::new);
+        assert_eq!(expected.to_vec(), stdout);
+    }
+
+    #[test]
+    fn simulate_zmips_data_segment_test() {
+        let code = "
+            .data
+            greeting: .asciiz \"hi\"
+            numbers: .word 7, -1
+            .text
+            la $t0, numbers
+            lw $t1, 4($t0)
+            print $t1
+            la $t2, greeting
+            lw $t3, 0($t2)
+            print $t3
+            exit $zero
+        ";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let (_, stdout, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+
+        if let Some(e) = err {
+            panic!("Execution failed: {e}");
+        }
+        let expected = [u32::MAX, 0x6968].map(|word| BFieldElement::new(word.into()));
+        assert_eq!(expected.to_vec(), stdout);
+    }
+
+    #[test]
+    fn simulate_zmips_syscalls_test() {
+        let code = "
+            li $v0, 5
+            syscall
+            move $t0, $v0
+            li $v0, 100
+            syscall
+            addu $a0, $t0, $v0
+            li $v0, 1
+            syscall
+            li $v0, 10
+            syscall
+            print $t0
+        ";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let stdin = vec![40_u64.into()];
+        let non_determinism = PrecomputedNonDeterminism::from(vec![BFieldElement::new(2)]);
+        let (_, stdout, err) = simulate(
+            Box::new(program),
+            stdin,
+            Box::new(non_determinism),
+            ExecutionBudget::default(),
+        );
+
+        if let Some(e) = err {
+            panic!("Execution failed: {e}");
+        }
+        assert_eq!(vec![BFieldElement::new(42)], stdout);
+    }
+
+    #[test]
+    fn divination_hints_leave_the_stack_unchanged_test() {
+        let code = "push 7 push 100 divine quotient write_io write_io write_io \
+            push 5 divine inverse write_io write_io \
+            push 9 divine sqrt write_io write_io halt";
+        let program = Program::from_code(code).unwrap();
+        let (_, stdout, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+        assert!(err.is_none(), "Execution must succeed.");
+        let expected_stdout = [
+            BFieldElement::new(14),
+            BFieldElement::new(100),
+            BFieldElement::new(7),
+            BFieldElement::new(5).inverse(),
+            BFieldElement::new(5),
+            BFieldElement::new(3),
+            BFieldElement::new(9),
+        ];
+        assert_eq!(expected_stdout.to_vec(), stdout);
+    }
+
+    #[test]
+    fn divine_sqrt_computes_smaller_square_root_test() {
+        let mut rng = ThreadRng::default();
+        for _ in 0..100 {
+            let root = BFieldElement::new(rng.gen_range(0..BFieldElement::P));
+            let square = root * root;
+            let code = format!("push {square} divine sqrt write_io halt");
+            let program = Program::from_code(&code).unwrap();
+            let (_, stdout, err) = simulate(
+                Box::new(program),
+                vec![],
+                Box::new(PrecomputedNonDeterminism::default()),
+                ExecutionBudget::default(),
+            );
+            assert!(err.is_none(), "Execution of `{code}` must succeed.");
+            let smaller_root = std::cmp::min_by_key(root, -root, |root| root.value());
+            assert_eq!(vec![smaller_root], stdout);
+        }
+
+        let program = Program::from_code("push 7 divine sqrt halt").unwrap();
+        let (_, _, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+        let Some(err) = err else {
+            panic!("7 is not a square.");
+        };
+        let Ok(VmError {
+            source: NotASquare(_),
+            ..
+        }) = err.downcast::<VmError>()
+        else {
+            panic!("Divining the square root of 7 must fail with NotASquare.");
+        };
+    }
+
+    /// Secret input that is computed on demand: the `i`-th token is `i`, and the `i`-th digest
+    /// consists of `i`s only.
+    #[derive(Debug, Default, Clone)]
+    struct CountingNonDeterminism {
+        num_tokens: u64,
+        num_digests: u64,
+    }
+
+    impl NonDeterminism for CountingNonDeterminism {
+        fn next_token(&mut self) -> Option<BFieldElement> {
+            self.num_tokens += 1;
+            Some(BFieldElement::new(self.num_tokens - 1))
+        }
+
+        fn next_digest(&mut self) -> Option<Digest> {
+            self.num_digests += 1;
+            Some(Digest::new(
+                [BFieldElement::new(self.num_digests - 1); DIGEST_LENGTH],
+            ))
+        }
+
+        fn initial_ram(&self) -> HashMap<BFieldElement, BFieldElement> {
+            HashMap::new()
+        }
+
+        fn clone_(&self) -> Box<dyn NonDeterminism> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[test]
+    fn secret_input_can_be_computed_lazily_test() {
+        let program = Program::from_code("divine divine divine add add write_io halt").unwrap();
+        let non_determinism = Box::new(CountingNonDeterminism::default());
+        let (_, stdout, err) = simulate(
+            Box::new(program),
+            vec![],
+            non_determinism,
+            ExecutionBudget::default(),
+        );
+        assert!(err.is_none(), "Execution must succeed.");
+        assert_eq!(vec![BFieldElement::new(3)], stdout);
+
+        // The secret input tape is filled up to the highest position the program reads from.
+        let code = "secseek $zero, 10\n secread $t0\n print $t0\n exit $zero";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let non_determinism = Box::new(CountingNonDeterminism::default());
+        let (aet, stdout, err) = simulate(
+            Box::new(program),
+            vec![],
+            non_determinism,
+            ExecutionBudget::default(),
+        );
+        assert!(err.is_none(), "Execution must succeed.");
+        assert_eq!(vec![BFieldElement::new(10)], stdout);
+        assert_eq!(11, aet.secret_input_tape.len());
+    }
+
+    #[test]
+    fn far_secseek_does_not_fill_secret_input_tape_test() {
+        let code = "li $t0, 2000000000\n secseek $t0, 0\n secread $t1\n exit $zero";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let non_determinism = Box::new(CountingNonDeterminism::default());
+        let (_, _, err) = simulate(
+            Box::new(program),
+            vec![],
+            non_determinism,
+            ExecutionBudget::default(),
+        );
+        let Some(err) = err else {
+            panic!("Reading far beyond the end of the secret input tape must fail.");
+        };
+        let Ok(VmError {
+            source: InputTapeExhausted(2_000_000_000),
+            ..
+        }) = err.downcast::<VmError>()
+        else {
+            panic!("Reading far beyond the end of the secret input tape must fail.");
+        };
+    }
+
+    #[test]
+    fn seek_target_wraps_around_modulo_2_pow_32_test() {
+        let code = "li $t0, 5\n pubseek $t0, -3\n pubread $t1\n print $t1\n exit $zero";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let public_input = [10, 11, 12].map(BFieldElement::new).to_vec();
+        let (_, stdout, err) = simulate(
+            Box::new(program),
+            public_input,
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+        assert!(err.is_none(), "Execution must succeed.");
+        assert_eq!(vec![BFieldElement::new(12)], stdout);
+    }
+
+    #[test]
+    fn exhausted_secret_input_is_an_error_test() {
+        for code in [
+            "divine halt",
+            "push 0 push 0 push 0 push 0 push 0 push 0 push 0 push 0 \
+            push 0 push 0 push 0 divine_sibling halt",
+        ] {
+            let program = Program::from_code(code).unwrap();
+            let non_determinism = Box::new(PrecomputedNonDeterminism::default());
+            let (_, _, err) = simulate(
+                Box::new(program),
+                vec![],
+                non_determinism,
+                ExecutionBudget::default(),
+            );
+            let Some(err) = err else {
+                panic!("Execution of `{code}` must fail.");
+            };
+            let Ok(VmError {
+                source: SecretInputExhausted | SecretDigestsExhausted,
+                ..
+            }) = err.downcast::<VmError>()
+            else {
+                panic!("Execution of `{code}` must fail because the secret input is exhausted.");
+            };
+        }
+    }
+
+    #[test]
+    fn ram_can_be_initialized_non_deterministically_test() {
+        let ram = HashMap::from([(BFieldElement::new(5), BFieldElement::new(42))]);
+        let non_determinism = PrecomputedNonDeterminism::new(vec![], vec![], ram);
+
+        let program = Program::from_code("push 5 read_mem write_io halt").unwrap();
+        let (_, stdout, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(non_determinism.clone()),
+            ExecutionBudget::default(),
+        );
+        assert!(err.is_none(), "Execution must succeed.");
+        assert_eq!(vec![BFieldElement::new(42)], stdout);
+
+        let code = "li $t0, 5\n lw $t1, 0($t0)\n print $t1\n exit $zero";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let (stdout, _, err) = run(Box::new(program), vec![], Box::new(non_determinism), 10);
+        assert!(err.is_none(), "Execution must succeed.");
+        assert_eq!(vec![BFieldElement::new(42)], stdout);
+    }
+
+    #[test]
+    fn zmips_zero_register_is_hardwired_test() {
+        use triton_zmips::instruction::AnInstruction::*;
+        use triton_zmips::regs::Reg;
+        use triton_zmips::regs::RegA;
+
+        // The assembler rejects writes to `$zero`, so the program is built by hand.
+        let program = triton_zmips::program::Program {
+            instructions: vec![
+                ADDU((Reg::Zero, Reg::Zero, RegA::Imm(7))),
+                MOVE((Reg::Zero, RegA::Imm(3))),
+                PRINT(Reg::Zero),
+                EXIT(Reg::Zero),
+            ],
+            ..Default::default()
+        };
+        let (stdout, _, err) = run(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            100,
+        );
+        assert!(err.is_none());
+        assert_eq!(vec![BFieldElement::zero()], stdout);
+    }
+
+    #[test]
+    fn simulate_zmips_unknown_syscall_test() {
+        let code = "li $v0, 7\n syscall\n exit $zero";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let (_, _, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+        assert!(err.is_some(), "Execution of `{code}` must fail.");
+    }
+
+    #[test]
+    fn run_agrees_with_simulate_for_all_isas_test() {
+        let program = Program::from_code("read_io read_io add write_io halt").unwrap();
+        let stdin = vec![BFieldElement::new(3), BFieldElement::new(4)];
+        let (_, simulated_stdout, _) = simulate(
+            Box::new(program.clone()),
+            stdin.clone(),
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+        let (stdout, final_answer, err) = run(
+            Box::new(program),
+            stdin,
+            Box::new(PrecomputedNonDeterminism::default()),
+            100,
+        );
+        assert!(err.is_none());
+        assert_eq!(vec![BFieldElement::new(7)], stdout);
+        assert_eq!(simulated_stdout, stdout);
+        assert_eq!(None, final_answer);
+
+        let code = "
+            li $v0, 5
+            syscall
+            move $a0, $v0
+            li $v0, 1
+            syscall
+            answer $a0
+            exit $zero
+        ";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let stdin = vec![BFieldElement::new(42)];
+        let (aet, simulated_stdout, _) = simulate(
+            Box::new(program.clone()),
+            stdin.clone(),
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+        let (stdout, final_answer, err) = run(
+            Box::new(program),
+            stdin,
+            Box::new(PrecomputedNonDeterminism::default()),
+            100,
+        );
+        assert!(err.is_none());
+        assert_eq!(vec![BFieldElement::new(42)], stdout);
+        assert_eq!(simulated_stdout, stdout);
+        assert_eq!(Some(BFieldElement::new(42)), final_answer);
+        assert_eq!(aet.final_answer, final_answer);
+    }
+
+    #[test]
+    fn run_respects_cycle_limit_test() {
+        let program = Program::from_code("call loop halt loop: push 1 pop recurse").unwrap();
+        let (_, _, err) = run(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            1000,
+        );
+        assert!(matches!(
+            err.unwrap().downcast::<InstructionError>(),
+            Ok(CycleLimitExceeded(1000))
+        ));
+
+        let code = "li $v0, 1\n loop:\n syscall\n b loop";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let (stdout, _, err) = run(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            10,
+        );
+        assert!(matches!(
+            err.unwrap().downcast::<InstructionError>(),
+            Ok(CycleLimitExceeded(10))
+        ));
+        assert_eq!(5, stdout.len());
+
+        let program = Program::from_code("push 1 pop halt").unwrap();
+        let (_, _, err) = run(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            3,
+        );
+        assert!(
+            err.is_none(),
+            "A program halting within the cycle limit must not fail."
+        );
+    }
+
+    #[test]
+    fn simulate_respects_execution_budget_test() {
+        let program = Program::from_code("call loop halt loop: push 1 pop recurse").unwrap();
+        let budget = ExecutionBudget::new(1000, usize::MAX);
+        let (aet, _, err) = simulate(
+            Box::new(program.clone()),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            budget,
+        );
+        assert!(matches!(
+            err.unwrap().downcast::<InstructionError>(),
+            Ok(CycleLimitExceeded(1000))
+        ));
+        assert_eq!(1000, aet.processor_trace.nrows());
+
+        let budget = ExecutionBudget::new(u64::MAX, 128);
+        let (aet, _, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            budget,
+        );
+        assert!(matches!(
+            err.unwrap().downcast::<InstructionError>(),
+            Ok(PaddedHeightLimitExceeded(128))
+        ));
+        assert_eq!(129, aet.processor_trace.nrows());
+    }
+
+    #[test]
+    fn failing_instruction_reports_machine_state_test() {
+        let code = "push 2 call f halt f: call g return g: push 0 assert return";
+        let program = Program::from_code(code).unwrap();
+        let (_, _, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+        let Ok(err) = err.unwrap().downcast::<VmError>() else {
+            panic!("A failing assertion must result in a VmError.");
+        };
+
+        assert!(matches!(err.source, AssertionFailed(elem) if elem.is_zero()));
+        assert_eq!(10, err.instruction_pointer);
+        assert_eq!(4, err.cycle_count);
+        assert_eq!(Some("assert".to_string()), err.instruction);
+        assert_eq!(OP_STACK_REG_COUNT, err.registers.len());
+        assert_eq!(("st0".to_string(), BFieldElement::zero()), err.registers[0]);
+        assert_eq!(("st1".to_string(), BFieldElement::new(2)), err.registers[1]);
+
+        let expected_source_location = SourceLocation {
+            file: None,
+            line: 1,
+            column: 47,
+            label: Some("g".to_string()),
+        };
+        assert_eq!(Some(expected_source_location), err.source_location);
+
+        let expected_backtrace = vec![
+            CallFrame {
+                callee: 8,
+                return_address: 7,
+                callee_label: Some("g".to_string()),
+            },
+            CallFrame {
+                callee: 5,
+                return_address: 4,
+                callee_label: Some("f".to_string()),
+            },
+        ];
+        assert_eq!(expected_backtrace, err.backtrace);
+
+        let rendered = err.to_string();
+        assert!(rendered.contains("at ip 10, clk 4: assert (1:47 in `g`)"));
+        assert!(rendered.contains("#0: in call of `g` (8), returning to 7"));
+        assert!(rendered.contains("#1: in call of `f` (5), returning to 4"));
+    }
+
+    #[test]
+    fn profile_attributes_cycles_to_labels_test() {
+        let program = Program::from_code("call f call f halt f: push 1 pop return").unwrap();
+        let (aet, _, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+        assert!(err.is_none());
+
+        let expected_profile = vec![(Some("f".to_string()), 6), (None, 3)];
+        assert_eq!(expected_profile, aet.profile());
+    }
+
+    #[test]
+    fn simulate_checks_padded_height_of_all_tables_test() {
+        let program = Program::from_code("push 1 pop halt").unwrap();
+        let (aet, _, err) = simulate(
+            Box::new(program.clone()),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+        assert!(err.is_none());
+        let padded_height = MasterBaseTable::padded_height(&aet);
+
+        let budget = ExecutionBudget::new(u64::MAX, padded_height);
+        let (_, _, err) = simulate(
+            Box::new(program.clone()),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            budget,
+        );
+        assert!(err.is_none());
+
+        let budget = ExecutionBudget::new(u64::MAX, padded_height - 1);
+        let (_, _, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            budget,
+        );
+        assert!(matches!(
+            err.unwrap().downcast::<InstructionError>(),
+            Ok(PaddedHeightLimitExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn debug_can_resume_from_intermediate_state_test() {
+        let program = test_program_for_write_mem_read_mem().source_code;
+        let program = Program::from_code(&program).unwrap();
+        let (states, _, err) = debug::<TritonMachine>(
+            &program,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            None,
+            Some(2),
+        );
+        assert!(err.is_none());
+        assert_eq!(2, states.len());
 
-    fn pretty_print_array_view<FF: FiniteField>(array: ArrayView1<FF>) -> String {
-        array
-            .iter()
-            .map(|ff| format!("{ff}"))
-            .collect_vec()
-            .join(", ")
-    }
+        let intermediate_state = states.last().cloned();
+        let (remaining_states, _, err) = debug::<TritonMachine>(
+            &program,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            intermediate_state,
+            None,
+        );
+        assert!(err.is_none());
+        assert!(remaining_states.last().unwrap().is_halting());
 
-    pub const GCD_X_Y: &str = "
-        read_io  // _ a
-        read_io  // _ a b
-        dup 1    // _ a b a
-        dup 1    // _ a b a b
-        lt       // _ a b b<a
-        skiz     // _ a b
-            swap 1   // _ d n where n > d
+        let (all_states, _, _) = debug::<TritonMachine>(
+            &program,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            None,
+            None,
+        );
+        assert_eq!(all_states.len(), states.len() + remaining_states.len());
+        let final_state = &all_states.last().unwrap().state;
+        let resumed_final_state = &remaining_states.last().unwrap().state;
+        assert_eq!(final_state.cycle_count, resumed_final_state.cycle_count);
+        assert_eq!(
+            final_state.op_stack.stack,
+            resumed_final_state.op_stack.stack
+        );
+    }
 
-        // ---
-        loop_cond:
-        dup 1
-        push 0 
-        eq 
-        skiz 
-            call terminate  // _ d n where d != 0
-        dup 1  // _ d n d
-        dup 1  // _ d n d n
-        div    // _ d n q r
-        swap 2 // _ d r q n
-        pop    // _ d r q
-        pop    // _ d r
-        swap 1 // _ r d
-        call loop_cond
-        // ---
-        
-        terminate:
-            // _ d n where d == 0
-            write_io // _ d
-            halt
+    #[test]
+    fn debug_zmips_records_ram_diff_test() {
+        let code = "
+            .data
+            numbers: .word 7
+            .text
+            la $t0, numbers
+            li $t1, 5
+            sw $t1, 0($t0)
+            print $t1
+            exit $zero
         ";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let (states, stdout, err) = debug::<ZmipsMachine>(
+            &program,
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            None,
+            None,
+        );
+        assert!(err.is_none());
+        assert_eq!(vec![BFieldElement::new(5)], stdout);
+        assert!(states.last().unwrap().is_halting());
 
-    #[test]
-    fn initialise_table_test() {
-        let code = GCD_X_Y;
-        let program = Program::from_code(code).unwrap();
+        let ram_diffs = states
+            .iter()
+            .tuple_windows()
+            .map(|(previous, current)| current.state.ram_diff(&previous.state))
+            .filter(|ram_diff| !ram_diff.is_empty())
+            .collect_vec();
+        assert_eq!(1, ram_diffs.len());
+        assert_eq!(1, ram_diffs[0].len());
+        let (_, written_value) = ram_diffs[0][0];
+        assert_eq!(BFieldElement::new(5), written_value);
+    }
 
-        let stdin = vec![BFieldElement::new(42), BFieldElement::new(56)];
+    #[derive(Debug, Clone, Default)]
+    struct UnknownProgram(SourceMap);
 
-        let (aet, stdout, err) = simulate(Box::new(program), stdin, vec![]);
+    impl AbstractProgram for UnknownProgram {
+        fn to_bwords(&self) -> Vec<BFieldElement> {
+            vec![]
+        }
 
-        println!(
-            "VM output: [{}]",
-            pretty_print_array_view(Array1::from(stdout).view())
-        );
+        fn len_bwords(&self) -> usize {
+            0
+        }
 
-        if let Some(e) = err {
-            panic!("Execution failed: {e}");
+        fn is_empty(&self) -> bool {
+            true
         }
-        for row in aet.processor_trace.rows() {
-            println!("{}", ProcessorTraceRow { row });
+
+        fn get_instructions(&self) -> Vec<Box<dyn AbstractInstruction>> {
+            vec![]
         }
-    }
 
-    #[test]
-    fn initialise_table_42_test() {
-        // 1. Execute program
-        let code = "
-        push 5
-        push 18446744069414584320
-        add
-        halt
-    ";
-        let program = Program::from_code(code).unwrap();
+        fn to_sequence_(&self) -> Vec<BFieldElement> {
+            vec![]
+        }
 
-        println!("{program}");
+        fn data_bwords(&self) -> Vec<BFieldElement> {
+            vec![]
+        }
 
-        let (aet, _, err) = simulate(Box::new(program), vec![], vec![]);
+        fn clone_(&self) -> Box<dyn AbstractProgram> {
+            Box::new(self.clone())
+        }
 
-        println!("{err:?}");
-        for row in aet.processor_trace.rows() {
-            println!("{}", ProcessorTraceRow { row });
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn source_map(&self) -> &SourceMap {
+            &self.0
         }
     }
 
     #[test]
-    fn simulate_tvm_gcd_test() {
-        let code = GCD_X_Y;
-        let program = Program::from_code(code).unwrap();
+    fn unknown_program_type_is_an_error_test() {
+        let (_, _, err) = simulate(
+            Box::new(UnknownProgram::default()),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+        let Ok(err) = err.unwrap().downcast::<InstructionError>() else {
+            panic!("Simulating a program of unknown type must fail with an `InstructionError`.");
+        };
+        assert!(matches!(err, UnknownProgramType));
 
-        let stdin = vec![42_u64.into(), 56_u64.into()];
-        let (_, stdout, err) = simulate(Box::new(program), stdin, vec![]);
+        let (_, _, err) = run(
+            Box::new(UnknownProgram::default()),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            1,
+        );
+        assert!(matches!(
+            err.unwrap().downcast::<InstructionError>(),
+            Ok(UnknownProgramType)
+        ));
+    }
 
-        let stdout = Array1::from(stdout);
-        println!("VM output: [{}]", pretty_print_array_view(stdout.view()));
+    #[test]
+    fn simulate_zmips_exit_code_and_final_answer_test() {
+        let code = "
+            li $t0, 7
+            answer $t0
+            li $v0, 101
+            li $a0, 42
+            syscall
+            li $t1, 3
+            exit $t1
+        ";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let (aet, _, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
 
         if let Some(e) = err {
             panic!("Execution failed: {e}");
         }
+        assert_eq!(BFieldElement::new(3), aet.exit_code);
+        assert_eq!(Some(BFieldElement::new(42)), aet.final_answer);
 
-        let expected_symbol = BFieldElement::new(14);
-        let computed_symbol = stdout[0];
+        let code = "li $v0, 17\n li $a0, 5\n syscall";
+        let program = triton_zmips::program::Program::from_code(code).unwrap();
+        let (aet, _, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+        assert!(err.is_none());
+        assert_eq!(BFieldElement::new(5), aet.exit_code);
+        assert_eq!(None, aet.final_answer);
+    }
 
-        assert_eq!(expected_symbol, computed_symbol);
+    #[test]
+    fn simulate_zmips_elf_test() {
+        let elf = include_bytes!("../../triton-zmips/samples/sum.elf");
+        let program = triton_zmips::program::Program::from_elf(elf).unwrap();
+        let (_, stdout, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
+
+        if let Some(e) = err {
+            panic!("Execution failed: {e}");
+        }
+        assert_eq!(vec![BFieldElement::new(13)], stdout);
     }
 
     pub fn test_hash_nop_nop_lt() -> SourceCodeAndInput {
@@ -1763,9 +3136,18 @@ pub mod triton_vm_tests {
             source_code: "divine assert halt".to_string(),
             input: vec![],
             secret_input: vec![1],
+            digests: vec![],
         }
     }
 
+    pub fn test_program_for_divine_with_hints() -> SourceCodeAndInput {
+        SourceCodeAndInput::without_input(
+            "push 7 push 100 divine quotient push 14 eq assert pop pop \
+             push 5 divine inverse mul push 1 eq assert \
+             push 9 divine sqrt dup 0 mul eq assert halt",
+        )
+    }
+
     pub fn test_program_for_skiz() -> SourceCodeAndInput {
         SourceCodeAndInput::without_input("push 1 skiz push 0 skiz assert push 1 skiz halt")
     }
@@ -1788,6 +3170,7 @@ pub mod triton_vm_tests {
             source_code: source_code.to_string(),
             input: vec![digest.to_vec()[0]],
             secret_input: vec![],
+            digests: vec![],
         }
     }
 
@@ -1807,7 +3190,8 @@ pub mod triton_vm_tests {
         SourceCodeAndInput {
             source_code: source_code.to_string(),
             input: vec![],
-            secret_input: vec![1, 1, 1, 1, 0],
+            secret_input: vec![],
+            digests: vec![Digest::new([0, 1, 1, 1, 1].map(BFieldElement::new))],
         }
     }
 
@@ -1827,7 +3211,8 @@ pub mod triton_vm_tests {
         SourceCodeAndInput {
             source_code: source_code.to_string(),
             input: vec![],
-            secret_input: vec![1, 1, 1, 1, 0],
+            secret_input: vec![],
+            digests: vec![Digest::new([0, 1, 1, 1, 1].map(BFieldElement::new))],
         }
     }
 
@@ -1877,6 +3262,7 @@ pub mod triton_vm_tests {
             source_code,
             input: vec![st4, st3, st2, st1, st0],
             secret_input: vec![],
+            digests: vec![],
         }
     }
 
@@ -1929,6 +3315,7 @@ pub mod triton_vm_tests {
             source_code,
             input: sponge_output.map(|e| e.value()).to_vec(),
             secret_input: vec![],
+            digests: vec![],
         }
     }
 
@@ -1952,6 +3339,7 @@ pub mod triton_vm_tests {
             source_code,
             input: vec![lo, hi],
             secret_input: vec![],
+            digests: vec![],
         }
     }
 
@@ -1960,6 +3348,7 @@ pub mod triton_vm_tests {
             source_code: "read_io divine eq assert halt".to_string(),
             input: vec![42],
             secret_input: vec![42],
+            digests: vec![],
         }
     }
 
@@ -1972,6 +3361,7 @@ pub mod triton_vm_tests {
             source_code,
             input: vec![st0],
             secret_input: vec![st0],
+            digests: vec![],
         }
     }
 
@@ -2002,6 +3392,7 @@ pub mod triton_vm_tests {
             source_code,
             input: vec![lsb.into(), st0_shift_right.into()],
             secret_input: vec![],
+            digests: vec![],
         }
     }
 
@@ -2036,6 +3427,7 @@ pub mod triton_vm_tests {
             source_code,
             input: vec![result_0, result_1],
             secret_input: vec![],
+            digests: vec![],
         }
     }
 
@@ -2064,6 +3456,7 @@ pub mod triton_vm_tests {
             source_code,
             input: vec![result_0.into(), result_1.into()],
             secret_input: vec![],
+            digests: vec![],
         }
     }
 
@@ -2092,6 +3485,7 @@ pub mod triton_vm_tests {
             source_code,
             input: vec![result_0.into(), result_1.into()],
             secret_input: vec![],
+            digests: vec![],
         }
     }
 
@@ -2129,6 +3523,7 @@ pub mod triton_vm_tests {
             source_code,
             input: vec![l2f_0.into(), l2f_1.into()],
             secret_input: vec![],
+            digests: vec![],
         }
     }
 
@@ -2179,6 +3574,7 @@ pub mod triton_vm_tests {
             source_code,
             input: vec![result_0, result_1],
             secret_input: vec![],
+            digests: vec![],
         }
     }
 
@@ -2201,6 +3597,7 @@ pub mod triton_vm_tests {
             source_code,
             input: vec![remainder.into(), quotient.into()],
             secret_input: vec![],
+            digests: vec![],
         }
     }
 
@@ -2221,6 +3618,7 @@ pub mod triton_vm_tests {
             source_code,
             input: vec![pop_count.into()],
             secret_input: vec![],
+            digests: vec![],
         }
     }
 
@@ -2329,8 +3727,8 @@ pub mod triton_vm_tests {
         ));
         let (_aet, _out, err) = program.simulate();
         let err = err.unwrap();
-        let err = err.downcast::<InstructionError>().unwrap();
-        let AssertionFailed(_, _, _) = err else {
+        let err = err.downcast::<VmError>().unwrap();
+        let AssertionFailed(_) = err.source else {
             panic!("Non-u32 must not pass u32-ness test.");
         };
     }
@@ -2370,6 +3768,7 @@ pub mod triton_vm_tests {
                     .to_string(),
             input: vec![1, 3, 14],
             secret_input: vec![],
+            digests: vec![],
         }
     }
 
@@ -2379,6 +3778,7 @@ pub mod triton_vm_tests {
             test_hash_nop_nop_lt(),
             test_program_for_push_pop_dup_swap_nop(),
             test_program_for_divine(),
+            test_program_for_divine_with_hints(),
             test_program_for_skiz(),
             test_program_for_call_recurse_return(),
             test_program_for_write_mem_read_mem(),
@@ -2427,6 +3827,350 @@ pub mod triton_vm_tests {
         ]
     }
 
+    /// A zMIPS program executing every instruction, every syscall service except the exits, and
+    /// every branch both taken and not taken. Exits with code 1 if any result is unexpected.
+    pub fn test_program_for_all_zmips_instructions() -> SourceCodeAndInput {
+        let source_code = "
+            .data
+            table: .word 3, -7, 0x7fffffff
+            .text
+                pubread $t0
+                secread $t1
+                pubseek $zero, 0
+                pubread $t2
+                secseek $t1, -5
+                secread $t3
+                bne $t1, $t3, fail
+                add $s0, $t0, $t1
+                add $s0, $s0, -20
+                addu $s1, $t0, -1
+                sub $s2, $t1, $t0
+                subu $s3, $t1, $t0
+                mult $s4, $s2, $s0
+                div $s5, $s2, 2
+                divu $s6, $s3, 3
+                mod $s7, $s2, 3
+                move $t4, $s7
+                move $t5, -42
+                la $t6, table
+                lw $t7, 4($t6)
+                bne $t7, $s2, fail
+                sw $s4, 8($t6)
+                lw $t8, 8($t6)
+                bne $t8, $s4, fail
+                and $t9, $t8, 0xff
+                xor $t9, $t9, $t7
+                or $t9, $t9, $t0
+                not $t9, $t9, $t9
+                sll $a1, $t0, 3
+                srl $a2, $s3, 4
+                sra $a3, $s2, 1
+                sll $a1, $a1, $t1
+                srl $a2, $a2, $t1
+                sra $a3, $a3, $t1
+                seq $t0, $k0, $t2
+                sne $t0, $k1, 13
+                slt $s2, $gp, $zero
+                sltu $s2, $sp, $zero
+                sle $s2, $fp, -7
+
+                beq $t0, $t2, equal
+                j fail
+            equal:
+                beq $t0, $t1, fail
+                bne $t0, $t1, unequal
+                j fail
+            unequal:
+                bne $t0, $t2, fail
+                blt $s2, $t0, less
+                j fail
+            less:
+                blt $t0, $s2, fail
+                ble $s2, $s2, less_or_equal
+                j fail
+            less_or_equal:
+                ble $t0, $s2, fail
+                bgt $t0, $s2, greater
+                j fail
+            greater:
+                bgt $s2, $t0, fail
+                bltu $t0, $s2, unsigned_less
+                j fail
+            unsigned_less:
+                bltu $s2, $t0, fail
+                bgeu $s2, $t0, unsigned_greater_or_equal
+                j fail
+            unsigned_greater_or_equal:
+                bgeu $t0, $s2, fail
+
+                jal increment
+                la $t6, increment
+                jalr $t6
+                j after_calls
+            increment:
+                addu $v1, $v1, 1
+                jr $ra
+            after_calls:
+                li $v0, 1
+                move $a0, $v1
+                syscall
+                li $v0, 5
+                syscall
+                move $s0, $v0
+                li $v0, 100
+                syscall
+                addu $s0, $s0, $v0
+                li $v0, 101
+                move $a0, $s4
+                syscall
+                answer $s0
+                print $s0
+                exit $zero
+            fail:
+                li $a0, 1
+                li $v0, 17
+                syscall
+        ";
+        SourceCodeAndInput {
+            source_code: source_code.to_string(),
+            input: vec![12, 99],
+            secret_input: vec![5, 77],
+            digests: vec![],
+        }
+    }
+
+    pub fn test_program_for_zmips_exit_syscall() -> SourceCodeAndInput {
+        SourceCodeAndInput::without_input("li $v0, 10\n syscall")
+    }
+
+    pub fn test_program_for_zmips_exit_with_code_syscall() -> SourceCodeAndInput {
+        SourceCodeAndInput::without_input("li $a0, 0\n li $v0, 17\n syscall")
+    }
+
+    pub fn zmips_test_programs() -> Vec<SourceCodeAndInput> {
+        vec![
+            test_program_for_all_zmips_instructions(),
+            test_program_for_zmips_exit_syscall(),
+            test_program_for_zmips_exit_with_code_syscall(),
+        ]
+    }
+
+    /// Wrap the body of a zMIPS program, which jumps to label `fail` if it computes an unexpected
+    /// result, such that the program exits with code 0 on success and with code 1 on failure.
+    fn zmips_program_with_failure_exit(body: &str) -> String {
+        format!("{body}\nexit $zero\nfail:\nli $a0, 1\nli $v0, 17\nsyscall")
+    }
+
+    /// The binary arithmetic, bitwise, shift, and comparison instructions of zMIPS on random
+    /// operands, with both a register and an immediate as second operand. The expected results
+    /// are read from the public input.
+    pub fn property_based_test_program_for_zmips_alu() -> SourceCodeAndInput {
+        let mut rng = ThreadRng::default();
+        let half = |x: i32| (x / 2) as u32;
+        let non_zero = |x: u32| if x == 0 { 1 } else { x };
+        let divisor = |x: u32| if x == 0 || x == u32::MAX { 3 } else { x };
+        type AluInstruction = (&'static str, fn(u32, u32) -> u32);
+        let instructions: [AluInstruction; 19] = [
+            ("add", |l, r| (l as i32 + r as i32) as u32),
+            ("addu", u32::wrapping_add),
+            ("sub", |l, r| (l as i32 - r as i32) as u32),
+            ("subu", u32::wrapping_sub),
+            ("mult", u32::wrapping_mul),
+            ("div", |l, r| (l as i32).wrapping_div(r as i32) as u32),
+            ("divu", |l, r| l / r),
+            ("mod", |l, r| (l as i32).wrapping_rem(r as i32) as u32),
+            ("and", |l, r| l & r),
+            ("or", |l, r| l | r),
+            ("xor", |l, r| l ^ r),
+            ("not", |_, r| !r),
+            ("sll", u32::wrapping_shl),
+            ("srl", u32::wrapping_shr),
+            ("sra", |l, r| (l as i32).wrapping_shr(r) as u32),
+            ("slt", |l, r| ((l as i32) < (r as i32)) as u32),
+            ("sltu", |l, r| (l < r) as u32),
+            ("sle", |l, r| ((l as i32) <= (r as i32)) as u32),
+            ("seq", |l, r| (l == r) as u32),
+        ];
+
+        let mut source_code = String::new();
+        let mut input = vec![];
+        for (mnemonic, result) in instructions {
+            for is_immediate in [false, true] {
+                let (lhs, rhs) = match mnemonic {
+                    "add" | "sub" => (half(rng.gen()), half(rng.gen())),
+                    "div" | "mod" => (rng.next_u32(), divisor(rng.next_u32())),
+                    "divu" => (rng.next_u32(), non_zero(rng.next_u32())),
+                    "seq" if rng.gen() => {
+                        let operand = rng.next_u32();
+                        (operand, operand)
+                    }
+                    _ => (rng.next_u32(), rng.next_u32()),
+                };
+                let operand = match is_immediate {
+                    true => rhs.to_string(),
+                    false => "$t1".to_string(),
+                };
+                // comparisons name the source register first and the destination second
+                let operands = match mnemonic {
+                    "slt" | "sltu" | "sle" | "seq" => format!("$t0, $t2, {operand}"),
+                    _ => format!("$t2, $t0, {operand}"),
+                };
+                source_code.push_str(&format!(
+                    "li $t0, {lhs}\n li $t1, {rhs}\n {mnemonic} {operands}\n \
+                     pubread $t3\n bne $t2, $t3, fail\n"
+                ));
+                input.push(result(lhs, rhs) as u64);
+            }
+        }
+
+        SourceCodeAndInput {
+            source_code: zmips_program_with_failure_exit(&source_code),
+            input,
+            secret_input: vec![],
+            digests: vec![],
+        }
+    }
+
+    /// Every branch instruction of zMIPS on random, sometimes equal operands. Whether the branch
+    /// is expected to be taken is read from the public input.
+    pub fn property_based_test_program_for_zmips_branches() -> SourceCodeAndInput {
+        let mut rng = ThreadRng::default();
+        type Branch = (&'static str, fn(u32, u32) -> bool);
+        let branches: [Branch; 7] = [
+            ("beq", |l, r| l == r),
+            ("bne", |l, r| l != r),
+            ("blt", |l, r| (l as i32) < (r as i32)),
+            ("ble", |l, r| (l as i32) <= (r as i32)),
+            ("bgt", |l, r| (l as i32) > (r as i32)),
+            ("bltu", |l, r| l < r),
+            ("bgeu", |l, r| l >= r),
+        ];
+
+        let mut source_code = String::new();
+        let mut input = vec![];
+        for (branch_idx, (mnemonic, is_taken)) in branches.into_iter().cycle().take(28).enumerate()
+        {
+            let lhs = rng.next_u32();
+            let rhs = match rng.gen_bool(0.25) {
+                true => lhs,
+                false => rng.next_u32(),
+            };
+            source_code.push_str(&format!(
+                "li $t0, {lhs}\n li $t1, {rhs}\n li $t2, 1\n \
+                 {mnemonic} $t0, $t1, taken_{branch_idx}\n li $t2, 0\n \
+                 taken_{branch_idx}:\n pubread $t3\n bne $t2, $t3, fail\n"
+            ));
+            input.push(is_taken(lhs, rhs) as u64);
+        }
+
+        SourceCodeAndInput {
+            source_code: zmips_program_with_failure_exit(&source_code),
+            input,
+            secret_input: vec![],
+            digests: vec![],
+        }
+    }
+
+    /// Store random words at random addresses through random offsets, and load them back in
+    /// random order, alongside words from a random data segment and from untouched memory.
+    pub fn property_based_test_program_for_zmips_memory() -> SourceCodeAndInput {
+        let mut rng = ThreadRng::default();
+        let num_data_words = rng.gen_range(1..10);
+        let data_words = (0..num_data_words).map(|_| rng.next_u32()).collect_vec();
+        let data_words_str = data_words.iter().map(|word| word.to_string()).join(", ");
+        let mut source_code = format!(".data\nwords: .word {data_words_str}\n.text\n");
+
+        for (idx, word) in data_words.iter().enumerate() {
+            let offset = 4 * idx;
+            source_code.push_str(&format!(
+                "la $t0, words\n lw $t1, {offset}($t0)\n li $t2, {word}\n bne $t1, $t2, fail\n"
+            ));
+        }
+
+        let num_memory_accesses = rng.gen_range(5..20);
+        let addresses = (0..num_memory_accesses)
+            .map(|_| rng.gen_range(0..1 << 28) * 4)
+            .unique()
+            .collect_vec();
+        let values = addresses.iter().map(|_| rng.next_u32()).collect_vec();
+        let offsets = addresses
+            .iter()
+            .map(|_| rng.gen_range(-1024..1024) * 4)
+            .collect_vec();
+        for ((address, value), offset) in addresses.iter().zip_eq(&values).zip_eq(&offsets) {
+            let base = (*address as i64 - *offset as i64) as u32;
+            source_code.push_str(&format!(
+                "li $t0, {base}\n li $t1, {value}\n sw $t1, {offset}($t0)\n"
+            ));
+        }
+
+        let mut reading_order = (0..addresses.len()).collect_vec();
+        reading_order.shuffle(&mut rng);
+        for idx in reading_order {
+            let (address, value) = (addresses[idx], values[idx]);
+            source_code.push_str(&format!(
+                "li $t0, {address}\n lw $t1, 0($t0)\n li $t2, {value}\n bne $t1, $t2, fail\n"
+            ));
+        }
+
+        let untouched_address = rng.gen_range(1_u32 << 30..1 << 31) * 2;
+        source_code.push_str(&format!(
+            "li $t0, {untouched_address}\n lw $t1, 0($t0)\n bne $t1, $zero, fail\n"
+        ));
+
+        SourceCodeAndInput {
+            source_code: zmips_program_with_failure_exit(&source_code),
+            input: vec![],
+            secret_input: vec![],
+            digests: vec![],
+        }
+    }
+
+    /// Seek both input tapes to random positions, given as a register plus a random immediate,
+    /// and check the words read there.
+    pub fn property_based_test_program_for_zmips_seek() -> SourceCodeAndInput {
+        let mut rng = ThreadRng::default();
+        let public_input = (0..rng.gen_range(1..10))
+            .map(|_| rng.next_u32())
+            .collect_vec();
+        let secret_input = (0..rng.gen_range(1..10))
+            .map(|_| rng.next_u32())
+            .collect_vec();
+
+        let mut source_code = String::new();
+        for _ in 0..10 {
+            for (seek, read, tape) in [
+                ("pubseek", "pubread", &public_input),
+                ("secseek", "secread", &secret_input),
+            ] {
+                let position = rng.gen_range(0..tape.len());
+                let offset = rng.next_u32();
+                let base = (position as u32).wrapping_sub(offset);
+                let word = tape[position];
+                source_code.push_str(&format!(
+                    "li $t0, {base}\n {seek} $t0, {offset}\n {read} $t1\n \
+                     li $t2, {word}\n bne $t1, $t2, fail\n"
+                ));
+            }
+        }
+
+        SourceCodeAndInput {
+            source_code: zmips_program_with_failure_exit(&source_code),
+            input: public_input.into_iter().map(u64::from).collect(),
+            secret_input: secret_input.into_iter().map(u64::from).collect(),
+            digests: vec![],
+        }
+    }
+
+    pub fn property_based_zmips_test_programs() -> Vec<SourceCodeAndInput> {
+        vec![
+            property_based_test_program_for_zmips_alu(),
+            property_based_test_program_for_zmips_branches(),
+            property_based_test_program_for_zmips_memory(),
+            property_based_test_program_for_zmips_seek(),
+        ]
+    }
+
     #[test]
     fn xxadd_test() {
         let stdin_words = vec![2, 3, 5, 7, 11, 13];
@@ -2442,6 +4186,7 @@ pub mod triton_vm_tests {
             source_code: xxadd_code.to_string(),
             input: stdin_words,
             secret_input: vec![],
+            digests: vec![],
         };
 
         let (_, actual_stdout, _) = program.simulate();
@@ -2465,6 +4210,7 @@ pub mod triton_vm_tests {
             source_code: xxmul_code.to_string(),
             input: stdin_words,
             secret_input: vec![],
+            digests: vec![],
         };
 
         let (_, actual_stdout, _) = program.simulate();
@@ -2491,6 +4237,7 @@ pub mod triton_vm_tests {
             source_code: xinv_code.to_string(),
             input: stdin_words,
             secret_input: vec![],
+            digests: vec![],
         };
 
         let (_, actual_stdout, _) = program.simulate();
@@ -2520,6 +4267,7 @@ pub mod triton_vm_tests {
             source_code: xbmul_code.to_string(),
             input: stdin_words,
             secret_input: vec![],
+            digests: vec![],
         };
 
         let (_, actual_stdout, _) = program.simulate();
@@ -2574,7 +4322,12 @@ pub mod triton_vm_tests {
             write_io write_io write_io write_io write_io write_io write_io
         ";
         let program = Program::from_code(code).unwrap();
-        let (aet, _out, _err) = simulate(Box::new(program), vec![], vec![]);
+        let (aet, _out, _err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
         let last_processor_row = aet.processor_trace.rows().into_iter().last().unwrap();
         let st0 = last_processor_row[ProcessorBaseTableColumn::ST0.base_table_index()];
         assert_eq!(BFIELD_ZERO, st0);
@@ -2586,7 +4339,12 @@ pub mod triton_vm_tests {
     fn run_tvm_halt_then_do_stuff_test() {
         let halt_then_do_stuff = "halt push 1 push 2 add invert write_io";
         let program = Program::from_code(halt_then_do_stuff).unwrap();
-        let (aet, _out, err) = simulate(Box::new(program), vec![], vec![]);
+        let (aet, _out, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
         if let Some(err) = err {
             panic!("Simulation failed: {err}");
         }
@@ -2612,7 +4370,12 @@ pub mod triton_vm_tests {
             halt
             ";
         let program = Program::from_code(basic_ram_read_write_code).unwrap();
-        let (aet, _out, err) = simulate(Box::new(program), vec![], vec![]);
+        let (aet, _out, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
         if let Some(e) = err {
             panic!("Error: {e}");
         }
@@ -2649,7 +4412,12 @@ pub mod triton_vm_tests {
             halt
         ";
         let program = Program::from_code(edgy_ram_writes_code).unwrap();
-        let (aet, _out, err) = simulate(Box::new(program), vec![], vec![]);
+        let (aet, _out, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
         if let Some(e) = err {
             panic!("Error: {e}");
         }
@@ -2695,7 +4463,12 @@ pub mod triton_vm_tests {
         let program = Program::from_code(sample_weights_code).unwrap();
         println!("Successfully parsed the program.");
         let input_symbols = vec![BFieldElement::new(11)];
-        let (aet, _out, err) = simulate(Box::new(program), input_symbols, vec![]);
+        let (aet, _out, err) = simulate(
+            Box::new(program),
+            input_symbols,
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
 
         if let Some(e) = err {
             panic!("The VM encountered an error: {e}");
@@ -2795,21 +4568,12 @@ pub mod triton_vm_tests {
 
         let selected_leaf_indices = [0, 28, 55];
 
-        let secret_input = selected_leaf_indices
+        let secret_digests = selected_leaf_indices
             .iter()
-            .flat_map(|leaf_index| {
-                let auth_path = merkle_tree.get_authentication_path(*leaf_index);
-                let selected_values: Vec<_> = (0..6)
-                    .flat_map(|i| {
-                        let values = auth_path[i].values();
-                        let reordered_values: Vec<BFieldElement> =
-                            order.iter().map(|ord| values[*ord]).collect();
-                        reordered_values
-                    })
-                    .collect();
-                selected_values
-            })
+            .flat_map(|&leaf_index| merkle_tree.get_authentication_path(leaf_index)[..6].to_vec())
             .collect_vec();
+        let non_determinism =
+            PrecomputedNonDeterminism::new(vec![], secret_digests, HashMap::new());
 
         let input = vec![
             // number of path tests
@@ -2850,7 +4614,12 @@ pub mod triton_vm_tests {
             leafs[55].values()[order[4]],
         ];
 
-        let (aet, _out, err) = simulate(Box::new(program), input, secret_input);
+        let (aet, _out, err) = simulate(
+            Box::new(program),
+            input,
+            Box::new(non_determinism),
+            ExecutionBudget::default(),
+        );
 
         if let Some(e) = err {
             panic!("The VM encountered an error: {e}");
@@ -2879,7 +4648,12 @@ pub mod triton_vm_tests {
         let program = Program::from_code(get_colinear_y_code).unwrap();
         println!("Successfully parsed the program.");
         let input_symbols = [7, 2, 1, 3, 4].map(BFieldElement::new).to_vec();
-        let (aet, out, err) = simulate(Box::new(program), input_symbols, vec![]);
+        let (aet, out, err) = simulate(
+            Box::new(program),
+            input_symbols,
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
         assert_eq!(BFieldElement::new(4), out[0]);
         if let Some(e) = err {
             panic!("The VM encountered an error: {e}");
@@ -2909,7 +4683,12 @@ pub mod triton_vm_tests {
             ";
 
         let program = Program::from_code(countdown_code).unwrap();
-        let (_aet, out, err) = simulate(Box::new(program), vec![], vec![]);
+        let (_aet, out, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
 
         if let Some(e) = err {
             panic!("The VM encountered an error: {e}");
@@ -2922,7 +4701,12 @@ pub mod triton_vm_tests {
     fn run_tvm_fibonacci_tvm() {
         let code = FIBONACCI_SEQUENCE;
         let program = Program::from_code(code).unwrap();
-        let (_aet, out, err) = simulate(Box::new(program), vec![7_u64.into()], vec![]);
+        let (_aet, out, err) = simulate(
+            Box::new(program),
+            vec![7_u64.into()],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
         if let Some(e) = err {
             panic!("The VM encountered an error: {e}");
         }
@@ -2939,7 +4723,8 @@ pub mod triton_vm_tests {
         let (_aet, out, _err) = simulate(
             Box::new(program),
             vec![42_u64.into(), 56_u64.into()],
-            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
         );
 
         let expected = BFieldElement::new(14);
@@ -2951,13 +4736,23 @@ pub mod triton_vm_tests {
     fn run_tvm_swap_test() {
         let code = "push 1 push 2 swap 1 halt";
         let program = Program::from_code(code).unwrap();
-        simulate(Box::new(program), vec![], vec![]);
+        simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
     }
 
     #[test]
     fn read_mem_unitialized() {
         let program = Program::from_code("read_mem halt").unwrap();
-        let (aet, _out, err) = simulate(Box::new(program), vec![], vec![]);
+        let (aet, _out, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
         assert!(err.is_none(), "Reading from uninitialized memory address");
         assert_eq!(2, aet.processor_trace.nrows());
     }
@@ -2965,14 +4760,19 @@ pub mod triton_vm_tests {
     #[test]
     fn program_without_halt_test() {
         let program = Program::from_code("nop").unwrap();
-        let (_aet, _out, err) = simulate(Box::new(program), vec![], vec![]);
+        let (_aet, _out, err) = simulate(
+            Box::new(program),
+            vec![],
+            Box::new(PrecomputedNonDeterminism::default()),
+            ExecutionBudget::default(),
+        );
         let Some(err) = err else {
             panic!("Program without halt must fail.");
         };
-        let Ok(err) = err.downcast::<InstructionError>() else {
-            panic!("Program without halt must fail with InstructionError.");
+        let Ok(err) = err.downcast::<VmError>() else {
+            panic!("Program without halt must fail with VmError.");
         };
-        let InstructionPointerOverflow(_) = err else {
+        let InstructionPointerOverflow(_) = err.source else {
             panic!("Program without halt must fail with InstructionPointerOverflow.");
         };
     }
@@ -2995,8 +4795,13 @@ pub mod triton_vm_tests {
         ]
         .map(BFieldElement::new)
         .to_vec();
-        let secret_in = vec![];
-        let (_aet, _stdout, err) = simulate(Box::new(program.clone()), stdin, secret_in);
+        let non_determinism = Box::new(PrecomputedNonDeterminism::default());
+        let (_aet, _stdout, err) = simulate(
+            Box::new(program.clone()),
+            stdin,
+            non_determinism,
+            ExecutionBudget::default(),
+        );
 
         if let Some(e) = err {
             panic!("The VM encountered an error: {e}");
@@ -3018,19 +4823,24 @@ pub mod triton_vm_tests {
         ]
         .map(BFieldElement::new)
         .to_vec();
-        let secret_in = vec![];
-        let (_aet, _stdout, err) = simulate(Box::new(program), bad_stdin, secret_in);
+        let non_determinism = Box::new(PrecomputedNonDeterminism::default());
+        let (_aet, _stdout, err) = simulate(
+            Box::new(program),
+            bad_stdin,
+            non_determinism,
+            ExecutionBudget::default(),
+        );
         let Some(err) = err else {
             panic!("Sudoku verifier must fail on bad Sudoku.");
         };
-        let Ok(err) = err.downcast::<InstructionError>() else {
-            panic!("Sudoku verifier must fail with InstructionError on bad Sudoku.");
+        let Ok(err) = err.downcast::<VmError>() else {
+            panic!("Sudoku verifier must fail with VmError on bad Sudoku.");
         };
-        let AssertionFailed(ip, _, _) = err else {
+        let AssertionFailed(_) = err.source else {
             panic!("Sudoku verifier must fail with AssertionFailed on bad Sudoku.");
         };
         assert_eq!(
-            15, ip,
+            15, err.instruction_pointer,
             "Sudoku verifier must fail on line 15 on bad Sudoku."
         );
     }
@@ -0,0 +1,523 @@
+//! Differential tests of the zMIPS register machine: random programs are executed both by
+//! [`VMState::step_mut`] and by a small reference interpreter, and must leave registers, memory,
+//! and output in the same state.
+
+use std::collections::BTreeMap;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::select;
+use triton_vm::shared_tests::SPECK64_ZMIPS;
+use triton_vm::vm::InputTape;
+use triton_vm::vm::VMOutput;
+use triton_vm::vm::VMState;
+use triton_zmips::data_segment::DATA_SEGMENT_START;
+use triton_zmips::data_segment::WORD_SIZE;
+use triton_zmips::instruction::AnInstruction::*;
+use triton_zmips::instruction::Instruction;
+use triton_zmips::program::Program;
+use triton_zmips::regs::Reg;
+use triton_zmips::regs::RegA;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+
+/// The state a program leaves behind, either after `exit` or after the instruction that failed.
+/// Memory only lists addresses holding a non-zero word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Outcome {
+    registers: Vec<u32>,
+    memory: BTreeMap<u32, u32>,
+    output: Vec<u32>,
+    answers: Vec<u32>,
+    exit_code: u32,
+    is_failure: bool,
+}
+
+/// Why the reference interpreter stopped executing a program before `exit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Trap {
+    IntegerOverflow,
+    DivisionByZero,
+    InputExhausted,
+    InstructionPointerOutOfBounds,
+    UnknownSyscall,
+}
+
+/// An input tape with a cursor, like the VM's [`InputTape`]. Reading moves the cursor to the next
+/// word; seeking moves it to an absolute position, possibly beyond the end of the tape.
+struct ReferenceTape {
+    words: Vec<u32>,
+    cursor: usize,
+}
+
+impl ReferenceTape {
+    fn new(words: &[u32]) -> Self {
+        let words = words.to_vec();
+        Self { words, cursor: 0 }
+    }
+
+    fn read(&mut self) -> Result<u32, Trap> {
+        let word = self.words.get(self.cursor).ok_or(Trap::InputExhausted)?;
+        self.cursor += 1;
+        Ok(*word)
+    }
+
+    fn seek(&mut self, position: u32) {
+        self.cursor = position as usize;
+    }
+}
+
+/// A reference interpreter for the MIPS subset supported by zMIPS. It shares nothing with the VM
+/// but the parsed program: registers and memory hold plain `u32`s, and every instruction is
+/// implemented from its MIPS semantics.
+///
+/// Where zMIPS deviates from MIPS, the interpreter follows zMIPS: memory maps addresses to whole
+/// words, the set instructions write their _second_ register, `mult` keeps the low word of the
+/// product, and `not` complements its second source operand. Like in MIPS, `$zero` is hardwired
+/// to 0; the assembler additionally rejects instructions writing it.
+struct ReferenceMachine<'pgm> {
+    program: &'pgm [Instruction],
+    pc: usize,
+    registers: [u32; 32],
+    memory: BTreeMap<u32, u32>,
+    public_input: ReferenceTape,
+    secret_input: ReferenceTape,
+    output: Vec<u32>,
+    answers: Vec<u32>,
+}
+
+impl<'pgm> ReferenceMachine<'pgm> {
+    fn new(program: &'pgm Program, public_input: &[u32], secret_input: &[u32]) -> Self {
+        let memory = (0..)
+            .zip(program.data.iter())
+            .map(|(i, &word)| (DATA_SEGMENT_START + WORD_SIZE * i, word))
+            .collect();
+        Self {
+            program: &program.instructions,
+            pc: 0,
+            registers: [0; 32],
+            memory,
+            public_input: ReferenceTape::new(public_input),
+            secret_input: ReferenceTape::new(secret_input),
+            output: vec![],
+            answers: vec![],
+        }
+    }
+
+    fn run(mut self) -> Outcome {
+        let (exit_code, is_failure) = loop {
+            match self.step() {
+                Ok(Some(exit_code)) => break (exit_code, false),
+                Ok(None) => (),
+                Err(_) => break (0, true),
+            }
+        };
+        Outcome {
+            registers: self.registers.to_vec(),
+            memory: self.memory.into_iter().filter(|&(_, w)| w != 0).collect(),
+            output: self.output,
+            answers: self.answers,
+            exit_code,
+            is_failure,
+        }
+    }
+
+    fn get(&self, reg: Reg) -> u32 {
+        self.registers[u32::from(reg) as usize]
+    }
+
+    fn set(&mut self, reg: Reg, value: u32) {
+        self.registers[u32::from(reg) as usize] = value;
+    }
+
+    fn value(&self, a: RegA) -> u32 {
+        match a {
+            RegA::Imm(imm) => imm,
+            RegA::RegName(reg) => self.get(reg),
+        }
+    }
+
+    fn load(&self, address: u32) -> u32 {
+        self.memory.get(&address).copied().unwrap_or(0)
+    }
+
+    /// Execute one instruction. Returns the exit code if the program exited.
+    fn step(&mut self) -> Result<Option<u32>, Trap> {
+        let instruction = match self.program.get(self.pc) {
+            Some(&instruction) => instruction,
+            None => return Err(Trap::InstructionPointerOutOfBounds),
+        };
+        let mut next_pc = self.pc + 1;
+        let mut branch = |condition: bool, target: BFieldElement| {
+            if condition {
+                next_pc = target.value() as usize;
+            }
+        };
+        let signed = |word: u32| word as i32;
+
+        match instruction {
+            BEQ((r1, r2, target)) => branch(self.get(r1) == self.get(r2), target),
+            BNE((r1, r2, target)) => branch(self.get(r1) != self.get(r2), target),
+            BLT((r1, r2, target)) => branch(signed(self.get(r1)) < signed(self.get(r2)), target),
+            BLE((r1, r2, target)) => branch(signed(self.get(r1)) <= signed(self.get(r2)), target),
+            BGT((r1, r2, target)) => branch(signed(self.get(r1)) > signed(self.get(r2)), target),
+            BLTU((r1, r2, target)) => branch(self.get(r1) < self.get(r2), target),
+            BGEU((r1, r2, target)) => branch(self.get(r1) >= self.get(r2), target),
+            SEQ((rs, rd, a)) => self.set(rd, (self.get(rs) == self.value(a)) as u32),
+            SNE((rs, rd, a)) => self.set(rd, (self.get(rs) != self.value(a)) as u32),
+            SLT((rs, rd, a)) => self.set(rd, (signed(self.get(rs)) < signed(self.value(a))) as u32),
+            SLTU((rs, rd, a)) => self.set(rd, (self.get(rs) < self.value(a)) as u32),
+            SLE((rs, rd, a)) => {
+                self.set(rd, (signed(self.get(rs)) <= signed(self.value(a))) as u32)
+            }
+            J(target) => branch(true, target),
+            JR(rs) => next_pc = self.get(rs) as usize,
+            JAL(target) => {
+                self.set(Reg::Ra, self.pc as u32 + 1);
+                branch(true, target);
+            }
+            JALR(rs) => {
+                next_pc = self.get(rs) as usize;
+                self.set(Reg::Ra, self.pc as u32 + 1);
+            }
+            LW((rt, offset, base)) => {
+                let address = self.get(base).wrapping_add(self.value(offset));
+                self.set(rt, self.load(address));
+            }
+            SW((rt, offset, base)) => {
+                let address = self.get(base).wrapping_add(self.value(offset));
+                self.memory.insert(address, self.get(rt));
+            }
+            ADD((rd, rs, a)) => {
+                let sum = signed(self.get(rs)).checked_add(signed(self.value(a)));
+                self.set(rd, sum.ok_or(Trap::IntegerOverflow)? as u32);
+            }
+            ADDU((rd, rs, a)) => self.set(rd, self.get(rs).wrapping_add(self.value(a))),
+            SUB((rd, rs, a)) => {
+                let difference = signed(self.get(rs)).checked_sub(signed(self.value(a)));
+                self.set(rd, difference.ok_or(Trap::IntegerOverflow)? as u32);
+            }
+            SUBU((rd, rs, a)) => self.set(rd, self.get(rs).wrapping_sub(self.value(a))),
+            MULT((rd, rs, a)) => {
+                let product = self.get(rs) as u64 * self.value(a) as u64;
+                self.set(rd, product as u32);
+            }
+            DIV((rd, rs, a)) | MOD((rd, rs, a)) => {
+                let (dividend, divisor) = (signed(self.get(rs)), signed(self.value(a)));
+                if divisor == 0 {
+                    return Err(Trap::DivisionByZero);
+                }
+                // MIPS truncates towards 0, the remainder has the sign of the dividend
+                let (dividend, divisor) = (dividend as i64, divisor as i64);
+                let (quotient, remainder) = (dividend / divisor, dividend % divisor);
+                match instruction {
+                    DIV(_) => {
+                        let quotient = i32::try_from(quotient).or(Err(Trap::IntegerOverflow))?;
+                        self.set(rd, quotient as u32);
+                    }
+                    _ => self.set(rd, remainder as u32),
+                }
+            }
+            DIVU((rd, rs, a)) => {
+                let divisor = self.value(a);
+                if divisor == 0 {
+                    return Err(Trap::DivisionByZero);
+                }
+                self.set(rd, self.get(rs) / divisor);
+            }
+            MOVE((rd, a)) => self.set(rd, self.value(a)),
+            LA((rd, address)) => self.set(rd, address.value() as u32),
+            AND((rd, rs, a)) => self.set(rd, self.get(rs) & self.value(a)),
+            XOR((rd, rs, a)) => self.set(rd, self.get(rs) ^ self.value(a)),
+            OR((rd, rs, a)) => self.set(rd, self.get(rs) | self.value(a)),
+            NOT((rd, _, a)) => self.set(rd, !self.value(a)),
+            SLL((rd, rs, a)) => self.set(rd, self.get(rs) << (self.value(a) % 32)),
+            SRL((rd, rs, a)) => self.set(rd, self.get(rs) >> (self.value(a) % 32)),
+            SRA((rd, rs, a)) => {
+                let shifted = signed(self.get(rs)) >> (self.value(a) % 32);
+                self.set(rd, shifted as u32);
+            }
+            PUBREAD(rd) => {
+                let word = self.public_input.read()?;
+                self.set(rd, word);
+            }
+            SECREAD(rd) => {
+                let word = self.secret_input.read()?;
+                self.set(rd, word);
+            }
+            PUBSEEK((rs, a)) => {
+                let position = self.get(rs).wrapping_add(self.value(a));
+                self.public_input.seek(position);
+            }
+            SECSEEK((rs, a)) => {
+                let position = self.get(rs).wrapping_add(self.value(a));
+                self.secret_input.seek(position);
+            }
+            PRINT(rs) => self.output.push(self.get(rs)),
+            EXIT(rs) => return Ok(Some(self.get(rs))),
+            ANSWER(rs) => self.answers.push(self.get(rs)),
+            SYSCALL => match self.get(Reg::V0) {
+                1 => self.output.push(self.get(Reg::A0)),
+                5 => {
+                    let word = self.public_input.read()?;
+                    self.set(Reg::V0, word);
+                }
+                10 => return Ok(Some(0)),
+                17 => return Ok(Some(self.get(Reg::A0))),
+                100 => {
+                    let word = self.secret_input.read()?;
+                    self.set(Reg::V0, word);
+                }
+                101 => self.answers.push(self.get(Reg::A0)),
+                _ => return Err(Trap::UnknownSyscall),
+            },
+        }
+        self.pc = next_pc;
+        Ok(None)
+    }
+}
+
+fn run_vm(program: &Program, public_input: &[u32], secret_input: &[u32]) -> Outcome {
+    let tape = |words: &[u32]| InputTape::new(words.iter().map(|&w| w.into()).collect());
+    let (mut public_input, mut secret_input) = (tape(public_input), tape(secret_input));
+    let word = |symbol: BFieldElement| {
+        u32::try_from(symbol.value()).expect("the VM must only ever handle 32-bit words")
+    };
+
+    let mut state = VMState::new_zmips(program);
+    let mut output = vec![];
+    let mut answers = vec![];
+    let mut exit_code = 0;
+    let mut is_failure = false;
+    while !state.halting {
+        match state.step_mut(&mut public_input, &mut secret_input) {
+            Ok(Some(VMOutput::WriteOutputSymbol(symbol))) => output.push(word(symbol)),
+            Ok(Some(VMOutput::FinalAnswer(answer))) => answers.push(word(answer)),
+            Ok(Some(VMOutput::ExitCode(code))) => exit_code = word(code),
+            Ok(_) => (),
+            Err(_) => {
+                is_failure = true;
+                break;
+            }
+        }
+    }
+
+    Outcome {
+        registers: state.regs_pool.regs.iter().map(|&r| word(r)).collect(),
+        memory: state
+            .ram
+            .iter()
+            .map(|(&address, &value)| (word(address), word(value)))
+            .filter(|&(_, value)| value != 0)
+            .collect(),
+        output,
+        answers,
+        exit_code,
+        is_failure,
+    }
+}
+
+/// The registers the generated programs compute with. They are initialized from the public input.
+const DATA_REGISTERS: [&str; 8] = ["$t0", "$t1", "$t2", "$t3", "$t4", "$t5", "$t6", "$t7"];
+
+/// The register counting the iterations of a loop. Not a data register, so loop bodies cannot
+/// modify it.
+const LOOP_COUNTER: &str = "$s0";
+
+/// The register holding the address of the `.data` block. Not a data register, so generated
+/// instructions cannot modify it.
+const DATA_BASE: &str = "$s1";
+
+/// The initial words of the `.data` block the generated programs load from and store to.
+const DATA_WORDS: [u32; 4] = [7, 0, 0xffff_fffe, 0x8000_0000];
+
+const THREE_OPERAND_INSTRUCTIONS: [&str; 20] = [
+    "add", "addu", "sub", "subu", "mult", "div", "divu", "mod", "and", "xor", "or", "not", "sll",
+    "srl", "sra", "seq", "sne", "slt", "sltu", "sle",
+];
+
+/// The codes of all syscall services, and one code no service uses.
+const SYSCALL_CODES: [u32; 7] = [1, 5, 10, 17, 100, 101, 7];
+
+const BRANCH_INSTRUCTIONS: [&str; 8] = ["beq", "bne", "blt", "ble", "bgt", "bge", "bltu", "bgeu"];
+
+fn register() -> impl Strategy<Value = &'static str> {
+    select(DATA_REGISTERS.to_vec())
+}
+
+/// Small immediates make equal operands, zero divisors, and memory address collisions likely.
+fn immediate() -> impl Strategy<Value = String> {
+    prop_oneof![
+        (-4_i64..=4).prop_map(|imm| imm.to_string()),
+        any::<i32>().prop_map(|imm| imm.to_string()),
+        any::<u32>().prop_map(|imm| format!("{imm:#x}")),
+    ]
+}
+
+fn operand() -> impl Strategy<Value = String> {
+    prop_oneof![register().prop_map(String::from), immediate()]
+}
+
+/// Offsets into the `.data` block, including one word on either side of it.
+fn data_offset() -> impl Strategy<Value = i32> {
+    (-1..=DATA_WORDS.len() as i32).prop_map(|i| i * WORD_SIZE as i32)
+}
+
+fn instruction() -> impl Strategy<Value = String> {
+    let memory_base = prop_oneof![register(), Just("$zero")];
+    let offset = (-2_i32..=2).prop_map(|i| i * 4);
+    let seek_base = prop_oneof![register(), Just("$zero")];
+    let seek_offset = prop_oneof![(0_u32..8).prop_map(|i| i.to_string()), operand()];
+    prop_oneof![
+        4 => (select(THREE_OPERAND_INSTRUCTIONS.to_vec()), register(), register(), operand())
+            .prop_map(|(name, r1, r2, a)| format!("{name} {r1}, {r2}, {a}")),
+        1 => (register(), operand()).prop_map(|(r, a)| format!("move {r}, {a}")),
+        1 => (register(), register()).prop_map(|(r1, r2)| format!("neg {r1}, {r2}")),
+        1 => (register(), offset.clone(), memory_base.clone())
+            .prop_map(|(r, offset, base)| format!("lw {r}, {offset}({base})")),
+        1 => (register(), offset, memory_base)
+            .prop_map(|(r, offset, base)| format!("sw {r}, {offset}({base})")),
+        1 => (register(), data_offset())
+            .prop_map(|(r, offset)| format!("lw {r}, {offset}({DATA_BASE})")),
+        1 => (register(), data_offset())
+            .prop_map(|(r, offset)| format!("sw {r}, {offset}({DATA_BASE})")),
+        1 => register().prop_map(|r| format!("la {r}, table")),
+        1 => (select(vec!["pubseek", "secseek"]), seek_base, seek_offset)
+            .prop_map(|(name, base, offset)| format!("{name} {base}, {offset}")),
+        1 => register().prop_map(|r| format!("pubread {r}")),
+        1 => register().prop_map(|r| format!("secread {r}")),
+        1 => register().prop_map(|r| format!("print {r}")),
+        1 => register().prop_map(|r| format!("answer {r}")),
+        1 => (select(SYSCALL_CODES.to_vec()), register()).prop_map(|(code, r)| {
+            format!("move $v0, {code}\nmove $a0, {r}\nsyscall\nmove {r}, $v0")
+        }),
+    ]
+}
+
+#[derive(Debug, Clone)]
+enum Block {
+    Instruction(String),
+    /// A forward branch over `body`.
+    Skip {
+        branch: &'static str,
+        lhs: &'static str,
+        rhs: &'static str,
+        body: Vec<String>,
+    },
+    /// Execute `body` the given number of times.
+    Loop {
+        iterations: u32,
+        body: Vec<String>,
+    },
+    /// Call a subroutine executing `body`, either directly with `jal`, or indirectly with `jalr`
+    /// through the given register.
+    Call {
+        target: Option<&'static str>,
+        body: Vec<String>,
+    },
+}
+
+fn block() -> impl Strategy<Value = Block> {
+    prop_oneof![
+        3 => instruction().prop_map(Block::Instruction),
+        1 => (
+            select(BRANCH_INSTRUCTIONS.to_vec()),
+            register(),
+            register(),
+            vec(instruction(), 0..5)
+        )
+            .prop_map(|(branch, lhs, rhs, body)| Block::Skip {
+                branch,
+                lhs,
+                rhs,
+                body
+            }),
+        1 => (1_u32..5, vec(instruction(), 1..8))
+            .prop_map(|(iterations, body)| Block::Loop { iterations, body }),
+        1 => (proptest::option::of(register()), vec(instruction(), 0..5))
+            .prop_map(|(target, body)| Block::Call { target, body }),
+    ]
+}
+
+/// Assembly code with a `.data` block labelled `table`, initializing all data registers from the
+/// public input and [`DATA_BASE`] to the address of `table`. The given blocks and `exit $zero`
+/// follow, then the blocks' subroutines, each returning with `jr $ra`.
+fn assemble(blocks: &[Block]) -> String {
+    let table = DATA_WORDS.map(|word| word.to_string()).join(", ");
+    let mut code = vec![".data".to_string(), format!("table: .word {table}")];
+    code.push(".text".to_string());
+    code.extend(DATA_REGISTERS.map(|r| format!("pubread {r}")));
+    code.push(format!("la {DATA_BASE}, table"));
+    let mut subroutines = vec![];
+    for (i, block) in blocks.iter().enumerate() {
+        match block {
+            Block::Instruction(instruction) => code.push(instruction.clone()),
+            Block::Skip {
+                branch,
+                lhs,
+                rhs,
+                body,
+            } => {
+                code.push(format!("{branch} {lhs}, {rhs}, skip_{i}"));
+                code.extend(body.iter().cloned());
+                code.push(format!("skip_{i}:"));
+            }
+            Block::Loop { iterations, body } => {
+                code.push(format!("li {LOOP_COUNTER}, {iterations}"));
+                code.push(format!("loop_{i}:"));
+                code.extend(body.iter().cloned());
+                code.push(format!("subu {LOOP_COUNTER}, {LOOP_COUNTER}, 1"));
+                code.push(format!("bne {LOOP_COUNTER}, $zero, loop_{i}"));
+            }
+            Block::Call { target, body } => {
+                match target {
+                    Some(target) => {
+                        code.push(format!("la {target}, subroutine_{i}"));
+                        code.push(format!("jalr {target}"));
+                    }
+                    None => code.push(format!("jal subroutine_{i}")),
+                }
+                subroutines.push(format!("subroutine_{i}:"));
+                subroutines.extend(body.iter().cloned());
+                subroutines.push("jr $ra".to_string());
+            }
+        }
+    }
+    code.push("exit $zero".to_string());
+    code.extend(subroutines);
+    code.join("\n")
+}
+
+fn assert_vm_agrees_with_reference(code: &str, public_input: &[u32], secret_input: &[u32]) {
+    let program = Program::from_code(code).unwrap();
+    let expected = ReferenceMachine::new(&program, public_input, secret_input).run();
+    let actual = run_vm(&program, public_input, secret_input);
+    assert_eq!(expected, actual, "program:\n{code}");
+}
+
+proptest! {
+    #[test]
+    fn straight_line_programs_agree_with_reference(
+        instructions in vec(instruction(), 1..40),
+        public_input in vec(any::<u32>(), DATA_REGISTERS.len()..2 * DATA_REGISTERS.len()),
+        secret_input in vec(any::<u32>(), 0..8),
+    ) {
+        let blocks = instructions.into_iter().map(Block::Instruction).collect::<Vec<_>>();
+        assert_vm_agrees_with_reference(&assemble(&blocks), &public_input, &secret_input);
+    }
+
+    #[test]
+    fn branching_and_looping_programs_agree_with_reference(
+        blocks in vec(block(), 1..12),
+        public_input in vec(any::<u32>(), DATA_REGISTERS.len()..4 * DATA_REGISTERS.len()),
+        secret_input in vec(any::<u32>(), 0..16),
+    ) {
+        assert_vm_agrees_with_reference(&assemble(&blocks), &public_input, &secret_input);
+    }
+}
+
+#[test]
+fn reference_interpreter_runs_speck() {
+    let round_keys = (0..27).map(|i| 0x0101_0101 * i).collect::<Vec<u32>>();
+    let secret_input = [vec![1, 2], round_keys].concat();
+    assert_vm_agrees_with_reference(SPECK64_ZMIPS, &[], &secret_input);
+}
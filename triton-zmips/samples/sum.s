@@ -0,0 +1,82 @@
+# Sums an array with a subroutine, exercises the supported MIPS32 subset, and prints the sum
+# using syscalls.
+#
+# Assembled and linked into `sum.elf` with
+#   llvm-mc -triple=mipsel-unknown-linux -mcpu=mips32 -mattr=+noabicalls -filetype=obj sum.s -o sum.o
+#   ld.lld -static -n -s -e __start -Ttext=0x00400000 -Tdata=0x10010000 sum.o -o sum.elf
+
+	.set noreorder
+	.text
+
+# $v0 = sum of the $a1 words starting at address $a0
+sum:
+	addu	$v0, $zero, $zero
+loop:
+	blez	$a1, done
+	nop
+	lw	$t0, 0($a0)
+	addu	$v0, $v0, $t0
+	addiu	$a0, $a0, 4
+	addiu	$a1, $a1, -1
+	j	loop
+	nop
+done:
+	jr	$ra
+	nop
+
+	.globl	__start
+__start:
+	lui	$a0, %hi(array)
+	ori	$a0, $a0, %lo(array)
+	lui	$t1, %hi(length)
+	lw	$a1, %lo(length)($t1)
+	jal	sum
+	nop
+	lui	$t1, %hi(total)
+	sw	$v0, %lo(total)($t1)
+	mul	$t3, $v0, $v0
+	slt	$t2, $v0, $a1
+	sltu	$t2, $a1, $v0
+	slti	$t4, $t3, -3
+	sltiu	$t4, $t3, 7
+	nor	$t4, $t3, $zero
+	sll	$t5, $t4, 3
+	srl	$t5, $t5, 1
+	sra	$t6, $t5, 2
+	sllv	$t6, $t6, $t2
+	srlv	$t6, $t6, $t2
+	srav	$t6, $t6, $t2
+	xori	$t7, $t6, 0xffff
+	andi	$t7, $t7, 0xff
+	add	$t8, $t7, $t6
+	addi	$t8, $t8, -1
+	sub	$t9, $t8, $t7
+	subu	$t9, $t9, $t8
+	and	$s0, $t9, $t8
+	or	$s0, $s0, $t7
+	xor	$s0, $s0, $t6
+	bgez	$s0, positive
+	nop
+	bltz	$s0, positive
+	nop
+positive:
+	bgtz	$s0, halt
+	nop
+	bne	$s0, $zero, halt
+	nop
+	beq	$s0, $s0, halt
+	nop
+halt:
+	lw	$a0, %lo(total)($t1)
+	addiu	$v0, $zero, 1
+	syscall
+	addiu	$v0, $zero, 10
+	syscall
+
+	.data
+array:
+	.word	3, 5, 7, -2
+length:
+	.word	4
+total:
+	.word	0
@@ -0,0 +1,19 @@
+# Uses MIPS32 instructions that zMIPS does not support, for testing the ELF loader's error report.
+#
+# Assembled and linked into `unsupported.elf` with
+#   llvm-mc -triple=mipsel-unknown-linux -mcpu=mips32 -mattr=+noabicalls -filetype=obj unsupported.s -o unsupported.o
+#   ld.lld -static -n -s -e __start -Ttext=0x00400000 -Tdata=0x10010000 unsupported.o -o unsupported.elf
+
+	.set noreorder
+	.text
+	.globl	__start
+__start:
+	lb	$t0, 0($a0)
+	mult	$t0, $t1
+	mflo	$t2
+	addiu	$zero, $t0, 1
+	beq	$t0, $t1, __start
+	addu	$t3, $t3, $t3
+	jalr	$t0, $t1
+	nop
+	break
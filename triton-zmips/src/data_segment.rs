@@ -0,0 +1,210 @@
+use std::fmt::Display;
+
+use crate::instruction::AssemblyError;
+
+/// The address of the first byte of the data segment.
+pub const DATA_SEGMENT_START: u32 = 0x1001_0000;
+
+/// The number of bytes in a word.
+pub const WORD_SIZE: u32 = 4;
+
+/// The maximal size of the data segment in bytes. Every word of the data segment is laid out
+/// in memory, the Program Table, and the Memory Table, so segments reaching far into the
+/// address space are rejected instead of being padded with zeros up to their address.
+pub const DATA_SEGMENT_MAX_SIZE: u32 = 1 << 20;
+
+/// An assembler directive. Directives do not occupy program memory: they switch between the
+/// text and the data segment, or lay out the initial content of the data segment.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Directive {
+    /// Subsequent labels and data belong to the data segment.
+    Data,
+
+    /// Subsequent labels and instructions belong to the text segment.
+    Text,
+
+    /// Words, the first of which is aligned to a word boundary.
+    Word(Vec<u32>),
+
+    /// The given number of zero bytes.
+    Space(u32),
+
+    /// The bytes of a string, followed by a terminating zero byte.
+    Asciiz(String),
+
+    /// Align the next datum to a multiple of `2^n` bytes.
+    Align(u32),
+
+    /// Export a label. Has no effect, since every label is visible to the entire program.
+    Globl(String),
+}
+
+impl Display for Directive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Directive::Data => write!(f, ".data"),
+            Directive::Text => write!(f, ".text"),
+            Directive::Word(words) => {
+                let words = words
+                    .iter()
+                    .map(|word| word.to_string())
+                    .collect::<Vec<_>>();
+                write!(f, ".word {}", words.join(", "))
+            }
+            Directive::Space(num_bytes) => write!(f, ".space {num_bytes}"),
+            Directive::Asciiz(string) => write!(f, ".asciiz \"{}\"", escape(string)),
+            Directive::Align(n) => write!(f, ".align {n}"),
+            Directive::Globl(label) => write!(f, ".globl {label}"),
+        }
+    }
+}
+
+impl Directive {
+    /// The alignment in bytes the directive's first byte requires.
+    fn alignment(&self) -> u32 {
+        match self {
+            Directive::Word(_) => WORD_SIZE,
+            Directive::Align(n) => 1 << n,
+            _ => 1,
+        }
+    }
+
+    /// The number of bytes the directive contributes to the data segment, excluding alignment
+    /// padding.
+    fn num_bytes(&self) -> u64 {
+        match self {
+            Directive::Word(words) => words.len() as u64 * WORD_SIZE as u64,
+            Directive::Space(num_bytes) => *num_bytes as u64,
+            Directive::Asciiz(string) => string.len() as u64 + 1,
+            _ => 0,
+        }
+    }
+
+    /// The bytes the directive contributes to the data segment, excluding alignment padding.
+    fn bytes(&self) -> Vec<u8> {
+        match self {
+            Directive::Word(words) => words.iter().flat_map(|word| word.to_le_bytes()).collect(),
+            Directive::Space(num_bytes) => vec![0; *num_bytes as usize],
+            Directive::Asciiz(string) => string.bytes().chain(std::iter::once(0)).collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Whether the directive lays out data, as opposed to switching segments or exporting labels.
+    pub fn is_data(&self) -> bool {
+        matches!(
+            self,
+            Directive::Word(_) | Directive::Space(_) | Directive::Asciiz(_) | Directive::Align(_)
+        )
+    }
+}
+
+fn escape(string: &str) -> String {
+    string
+        .chars()
+        .map(|c| match c {
+            '\n' => "\\n".to_string(),
+            '\t' => "\\t".to_string(),
+            '\0' => "\\0".to_string(),
+            '\\' => "\\\\".to_string(),
+            '"' => "\\\"".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// The data segment, laid out byte by byte starting at address [`DATA_SEGMENT_START`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DataSegment {
+    bytes: Vec<u8>,
+}
+
+impl DataSegment {
+    /// The address of the next byte.
+    pub fn address(&self) -> u32 {
+        DATA_SEGMENT_START + self.bytes.len() as u32
+    }
+
+    /// Fails if the data segment would grow beyond [`DATA_SEGMENT_MAX_SIZE`] bytes.
+    fn check_size(size: u64) -> Result<(), AssemblyError> {
+        match size > DATA_SEGMENT_MAX_SIZE as u64 {
+            true => Err(AssemblyError::DataSegmentTooLarge(size)),
+            false => Ok(()),
+        }
+    }
+
+    /// Pad the data segment with zero bytes until the next byte is aligned as the directive
+    /// requires. Returns the address of the next byte. Fails if the padding would grow the data
+    /// segment beyond [`DATA_SEGMENT_MAX_SIZE`] bytes.
+    pub fn align_for(&mut self, directive: &Directive) -> Result<u32, AssemblyError> {
+        let alignment = directive.alignment() as u64;
+        let padded_len = (self.bytes.len() as u64).div_ceil(alignment) * alignment;
+        Self::check_size(padded_len)?;
+        self.bytes.resize(padded_len as usize, 0);
+        Ok(self.address())
+    }
+
+    /// Append the directive's data to the data segment. Fails if the data segment would grow
+    /// beyond [`DATA_SEGMENT_MAX_SIZE`] bytes.
+    pub fn push(&mut self, directive: &Directive) -> Result<(), AssemblyError> {
+        self.align_for(directive)?;
+        Self::check_size(self.bytes.len() as u64 + directive.num_bytes())?;
+        self.bytes.extend(directive.bytes());
+        Ok(())
+    }
+
+    /// Write the bytes starting at the given address. The bytes must lie in the
+    /// [`DATA_SEGMENT_MAX_SIZE`] bytes starting at [`DATA_SEGMENT_START`]. Bytes between the
+    /// current end of the data segment and the address are 0.
+    pub fn write(&mut self, address: u32, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        let start = (address - DATA_SEGMENT_START) as usize;
+        let end = start + bytes.len();
+        if self.bytes.len() < end {
+            self.bytes.resize(end, 0);
+        }
+        self.bytes[start..end].copy_from_slice(bytes);
+    }
+
+    /// The data segment as little-endian words, padded with zero bytes to a multiple of the
+    /// word size. Word `i` is stored at address `DATA_SEGMENT_START + 4·i`.
+    pub fn words(&self) -> Vec<u32> {
+        self.bytes
+            .chunks(WORD_SIZE as usize)
+            .map(|chunk| {
+                let mut word = [0; WORD_SIZE as usize];
+                word[..chunk.len()].copy_from_slice(chunk);
+                u32::from_le_bytes(word)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod data_segment_tests {
+    use super::*;
+
+    #[test]
+    fn words_are_aligned_and_strings_are_packed_little_endian() {
+        let mut data_segment = DataSegment::default();
+        data_segment
+            .push(&Directive::Asciiz("hi".to_string()))
+            .unwrap();
+        assert_eq!(DATA_SEGMENT_START + 3, data_segment.address());
+
+        let word_address = data_segment.align_for(&Directive::Word(vec![])).unwrap();
+        assert_eq!(DATA_SEGMENT_START + 4, word_address);
+
+        data_segment
+            .push(&Directive::Word(vec![7, u32::MAX]))
+            .unwrap();
+        data_segment.push(&Directive::Space(1)).unwrap();
+        assert_eq!(
+            vec![0x0000_6968, 7, u32::MAX, 0],
+            data_segment.words(),
+            "\"hi\" is followed by a zero byte and padding, the space by padding"
+        );
+    }
+}
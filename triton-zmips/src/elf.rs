@@ -0,0 +1,580 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use triton_program::SourceMap;
+use twenty_first::shared_math::b_field_element::BFieldElement;
+
+use crate::data_segment::DataSegment;
+use crate::data_segment::DATA_SEGMENT_MAX_SIZE;
+use crate::data_segment::DATA_SEGMENT_START;
+use crate::data_segment::WORD_SIZE;
+use crate::instruction::AnInstruction::*;
+use crate::instruction::Instruction;
+use crate::program::Program;
+use crate::regs::Reg;
+use crate::regs::RegA;
+use crate::regs::REGS;
+
+const ELF_MAGIC: [u8; 4] = [0x7f, b'E', b'L', b'F'];
+const ELF_CLASS_32: u8 = 1;
+const ELF_DATA_LITTLE_ENDIAN: u8 = 1;
+const ELF_TYPE_EXECUTABLE: u16 = 2;
+const ELF_MACHINE_MIPS: u16 = 8;
+const ELF_HEADER_SIZE: usize = 52;
+const PROGRAM_HEADER_SIZE: usize = 32;
+const SEGMENT_TYPE_LOAD: u32 = 1;
+const SEGMENT_FLAG_EXECUTABLE: u32 = 1;
+
+/// The machine code of `sll $zero, $zero, 0`, which MIPS assemblers emit for `nop`.
+const NOP: u32 = 0;
+
+/// An error in loading an ELF file, see [`load`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ElfError {
+    /// The file is not a well-formed MIPS32 little-endian executable, or its segments cannot be
+    /// mapped onto a zMIPS program.
+    Malformed(String),
+
+    /// Some words of the text segment are not machine code of the MIPS32 subset zMIPS supports.
+    /// Lists all of them, ordered by address.
+    UnsupportedInstructions(Vec<UnsupportedInstruction>),
+}
+
+/// A word of the text segment that has no zMIPS counterpart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedInstruction {
+    pub address: u32,
+    pub word: u32,
+    pub reason: String,
+}
+
+impl Display for ElfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ElfError::Malformed(reason) => write!(f, "malformed ELF file: {reason}"),
+            ElfError::UnsupportedInstructions(instructions) => {
+                write!(f, "unsupported instructions:")?;
+                for instruction in instructions {
+                    write!(f, "\n{instruction}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for UnsupportedInstruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let UnsupportedInstruction {
+            address,
+            word,
+            reason,
+        } = self;
+        write!(f, "{address:#010x}: {word:#010x} ({reason})")
+    }
+}
+
+impl Error for ElfError {}
+
+fn malformed(reason: impl Into<String>) -> ElfError {
+    ElfError::Malformed(reason.into())
+}
+
+/// Load a statically linked MIPS32 little-endian ELF executable as a zMIPS [`Program`].
+///
+/// The only executable segment is decoded into instructions, one per word, such that the
+/// instruction at byte address `a` gets instruction address `(a - text_start) / 4`. If the entry
+/// point is not the start of the text segment, the program starts with a jump to it, and all
+/// instruction addresses are shifted by one. All other loadable segments make up the initial
+/// data segment; they must lie in the [`DATA_SEGMENT_MAX_SIZE`] bytes starting at
+/// [`DATA_SEGMENT_START`], for example by linking with `-Tdata=0x10010000`.
+///
+/// zMIPS has no branch delay slots, hence every branch and jump must be followed by a `nop`.
+/// Since zMIPS jumps to instruction addresses, `jr` and `jalr` only work with return addresses
+/// written by `jal` and `jalr`, not with code addresses computed by the program. Words that
+/// cannot be translated are reported with their addresses, see
+/// [`ElfError::UnsupportedInstructions`].
+///
+/// A proof attests to the execution of the loaded program, not of the ELF file: the claimed
+/// program digest is the hash of the translated instructions and the data segment.
+pub fn load(elf: &[u8]) -> Result<Program, ElfError> {
+    let entry_point = parse_header(elf)?;
+    let segments = parse_segments(elf)?;
+    let (text_segments, data_segments): (Vec<_>, Vec<_>) = segments
+        .into_iter()
+        .filter(|segment| segment.kind == SEGMENT_TYPE_LOAD)
+        .partition(|segment| segment.flags & SEGMENT_FLAG_EXECUTABLE != 0);
+
+    let text_segment = match text_segments.as_slice() {
+        [text_segment] => text_segment,
+        _ => return Err(malformed("expected exactly one executable segment")),
+    };
+    let text = Text::new(text_segment, entry_point, elf)?;
+    let instructions = text.decode()?;
+
+    let mut data_segment = DataSegment::default();
+    for segment in data_segments {
+        let address = segment.address;
+        let content = segment.content(elf)?;
+        let start = DATA_SEGMENT_START;
+        if address < start {
+            let reason = format!("segment at {address:#010x} lies below address {start:#010x}");
+            return Err(malformed(reason));
+        }
+        let end = address as u64 + content.len() as u64;
+        if end > start as u64 + DATA_SEGMENT_MAX_SIZE as u64 {
+            let max_size = DATA_SEGMENT_MAX_SIZE;
+            let reason = format!(
+                "segment at {address:#010x} exceeds the {max_size} bytes starting at {start:#010x}"
+            );
+            return Err(malformed(reason));
+        }
+        data_segment.write(address, content);
+    }
+    let data = data_segment.words();
+    let source_map = SourceMap::default();
+
+    Ok(Program {
+        instructions,
+        data,
+        source_map,
+    })
+}
+
+fn read_u16(elf: &[u8], offset: usize) -> Result<u16, ElfError> {
+    match elf.get(offset..offset + 2) {
+        Some(bytes) => Ok(u16::from_le_bytes([bytes[0], bytes[1]])),
+        None => Err(malformed(format!(
+            "unexpected end of file at offset {offset}"
+        ))),
+    }
+}
+
+fn read_u32(elf: &[u8], offset: usize) -> Result<u32, ElfError> {
+    match elf.get(offset..offset + 4) {
+        Some(bytes) => Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])),
+        None => Err(malformed(format!(
+            "unexpected end of file at offset {offset}"
+        ))),
+    }
+}
+
+/// Check the ELF header's identification, and return the entry point.
+fn parse_header(elf: &[u8]) -> Result<u32, ElfError> {
+    if elf.len() < ELF_HEADER_SIZE || elf[..4] != ELF_MAGIC {
+        return Err(malformed("not an ELF file"));
+    }
+    if elf[4] != ELF_CLASS_32 || elf[5] != ELF_DATA_LITTLE_ENDIAN {
+        return Err(malformed("expected a 32-bit little-endian ELF file"));
+    }
+    if read_u16(elf, 16)? != ELF_TYPE_EXECUTABLE {
+        return Err(malformed("expected an executable"));
+    }
+    if read_u16(elf, 18)? != ELF_MACHINE_MIPS {
+        return Err(malformed("expected MIPS machine code"));
+    }
+    read_u32(elf, 24)
+}
+
+/// An entry of the ELF file's program header table.
+struct Segment {
+    kind: u32,
+    offset: u32,
+    address: u32,
+    file_size: u32,
+    flags: u32,
+}
+
+impl Segment {
+    /// The bytes of the segment that are stored in the file. The remaining bytes up to the
+    /// segment's size in memory are 0, as are all bytes of memory not covered by any segment.
+    fn content<'elf>(&self, elf: &'elf [u8]) -> Result<&'elf [u8], ElfError> {
+        let start = self.offset as usize;
+        let end = start + self.file_size as usize;
+        elf.get(start..end).ok_or_else(|| {
+            let address = self.address;
+            malformed(format!("segment at {address:#010x} exceeds the file"))
+        })
+    }
+}
+
+fn parse_segments(elf: &[u8]) -> Result<Vec<Segment>, ElfError> {
+    let table_offset = read_u32(elf, 28)? as usize;
+    let entry_size = read_u16(elf, 42)? as usize;
+    let num_entries = read_u16(elf, 44)? as usize;
+    if num_entries > 0 && entry_size < PROGRAM_HEADER_SIZE {
+        return Err(malformed(format!("program header of {entry_size} bytes")));
+    }
+
+    (0..num_entries)
+        .map(|i| {
+            let entry = table_offset + i * entry_size;
+            Ok(Segment {
+                kind: read_u32(elf, entry)?,
+                offset: read_u32(elf, entry + 4)?,
+                address: read_u32(elf, entry + 8)?,
+                file_size: read_u32(elf, entry + 16)?,
+                flags: read_u32(elf, entry + 24)?,
+            })
+        })
+        .collect()
+}
+
+/// The text segment as words, together with the information to translate byte addresses into
+/// instruction addresses.
+struct Text {
+    start: u32,
+    words: Vec<u32>,
+    entry_point: u32,
+}
+
+impl Text {
+    fn new(segment: &Segment, entry_point: u32, elf: &[u8]) -> Result<Self, ElfError> {
+        let content = segment.content(elf)?;
+        if !segment.address.is_multiple_of(WORD_SIZE)
+            || !content.len().is_multiple_of(WORD_SIZE as usize)
+        {
+            return Err(malformed("text segment is not word aligned"));
+        }
+        let words = content
+            .chunks(WORD_SIZE as usize)
+            .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect();
+        let text = Text {
+            start: segment.address,
+            words,
+            entry_point,
+        };
+        text.instruction_address(entry_point).map_err(malformed)?;
+        Ok(text)
+    }
+
+    /// The number of instructions preceding the text segment's first one.
+    fn num_prepended_instructions(&self) -> u32 {
+        match self.entry_point == self.start {
+            true => 0,
+            false => 1,
+        }
+    }
+
+    /// The instruction address of the instruction at the given byte address.
+    fn instruction_address(&self, address: u32) -> Result<BFieldElement, String> {
+        let offset = address.wrapping_sub(self.start);
+        let is_in_text = offset < self.words.len() as u32 * WORD_SIZE;
+        if !address.is_multiple_of(WORD_SIZE) || !is_in_text {
+            return Err(format!(
+                "target {address:#010x} lies outside the text segment"
+            ));
+        }
+        let index = self.num_prepended_instructions() + offset / WORD_SIZE;
+        Ok(index.into())
+    }
+
+    /// Decode all words, collecting every word that cannot be decoded.
+    fn decode(&self) -> Result<Vec<Instruction>, ElfError> {
+        let mut instructions = vec![];
+        if self.num_prepended_instructions() > 0 {
+            let entry_point = self
+                .instruction_address(self.entry_point)
+                .map_err(malformed)?;
+            instructions.push(J(entry_point));
+        }
+
+        let mut unsupported = vec![];
+        let mut branch_address = None;
+        for (address, &word) in (self.start..).step_by(WORD_SIZE as usize).zip(&self.words) {
+            let instruction = match branch_address {
+                Some(branch) if word != NOP => Err(format!(
+                    "the delay slot of the branch at {branch:#010x} must hold a nop"
+                )),
+                _ => self.decode_word(address, word),
+            };
+            branch_address = match &instruction {
+                Ok(instruction) if has_delay_slot(instruction) => Some(address),
+                _ => None,
+            };
+            match instruction {
+                Ok(instruction) => instructions.push(instruction),
+                Err(reason) => unsupported.push(UnsupportedInstruction {
+                    address,
+                    word,
+                    reason,
+                }),
+            }
+        }
+        if let Some(address) = branch_address {
+            let word = self.words[self.words.len() - 1];
+            let reason = "the branch lacks a delay slot".to_string();
+            unsupported.push(UnsupportedInstruction {
+                address,
+                word,
+                reason,
+            });
+        }
+
+        match unsupported.is_empty() {
+            true => Ok(instructions),
+            false => Err(ElfError::UnsupportedInstructions(unsupported)),
+        }
+    }
+
+    /// Translate one word of MIPS32 machine code into the equivalent zMIPS instruction.
+    fn decode_word(&self, address: u32, word: u32) -> Result<Instruction, String> {
+        let field = |shift: u32| (word >> shift) & 0x1f;
+        let (rs, rt, rd) = (
+            REGS[field(21) as usize],
+            REGS[field(16) as usize],
+            REGS[field(11) as usize],
+        );
+        let shift_amount = RegA::Imm(field(6));
+        let (rs_operand, rt_operand) = (RegA::RegName(rs), RegA::RegName(rt));
+        let immediate = word & 0xffff;
+        let sign_extended = RegA::Imm(immediate as i16 as i32 as u32);
+        let zero_extended = RegA::Imm(immediate);
+
+        let branch_target = || {
+            let offset = (immediate as i16 as i32 as u32) << 2;
+            self.instruction_address(address.wrapping_add(WORD_SIZE).wrapping_add(offset))
+        };
+        let jump_target = || {
+            let region = address.wrapping_add(WORD_SIZE) & 0xf000_0000;
+            self.instruction_address(region | (word & 0x03ff_ffff) << 2)
+        };
+
+        let opcode = word >> 26;
+        let function = word & 0x3f;
+        let instruction = match opcode {
+            0x00 => match function {
+                0x00 => SLL((rd, rt, shift_amount)),
+                0x02 => SRL((rd, rt, shift_amount)),
+                0x03 => SRA((rd, rt, shift_amount)),
+                0x04 => SLL((rd, rt, rs_operand)),
+                0x06 => SRL((rd, rt, rs_operand)),
+                0x07 => SRA((rd, rt, rs_operand)),
+                0x08 => JR(rs),
+                0x09 if rd == Reg::Ra => JALR(rs),
+                0x09 => return Err("`jalr` must link register $ra".to_string()),
+                0x0c => SYSCALL,
+                0x20 => ADD((rd, rs, rt_operand)),
+                0x21 => ADDU((rd, rs, rt_operand)),
+                0x22 => SUB((rd, rs, rt_operand)),
+                0x23 => SUBU((rd, rs, rt_operand)),
+                0x24 => AND((rd, rs, rt_operand)),
+                0x25 => OR((rd, rs, rt_operand)),
+                0x26 => XOR((rd, rs, rt_operand)),
+                0x27 if rt == Reg::Zero => NOT((rd, rs, rs_operand)),
+                0x27 if rs == Reg::Zero => NOT((rd, rt, rt_operand)),
+                0x27 => return Err("`nor` is only supported with operand $zero".to_string()),
+                0x2a => SLT((rs, rd, rt_operand)),
+                0x2b => SLTU((rs, rd, rt_operand)),
+                _ => return Err(unsupported_function(function)),
+            },
+            0x01 => match field(16) {
+                0x00 => BLT((rs, Reg::Zero, branch_target()?)),
+                0x01 => BLE((Reg::Zero, rs, branch_target()?)),
+                _ => return Err("unsupported `regimm` instruction".to_string()),
+            },
+            0x02 => J(jump_target()?),
+            0x03 => JAL(jump_target()?),
+            0x04 => BEQ((rs, rt, branch_target()?)),
+            0x05 => BNE((rs, rt, branch_target()?)),
+            0x06 => BLE((rs, Reg::Zero, branch_target()?)),
+            0x07 => BGT((rs, Reg::Zero, branch_target()?)),
+            0x08 => ADD((rt, rs, sign_extended)),
+            0x09 => ADDU((rt, rs, sign_extended)),
+            0x0a => SLT((rs, rt, sign_extended)),
+            0x0b => SLTU((rs, rt, sign_extended)),
+            0x0c => AND((rt, rs, zero_extended)),
+            0x0d => OR((rt, rs, zero_extended)),
+            0x0e => XOR((rt, rs, zero_extended)),
+            0x0f => MOVE((rt, RegA::Imm(immediate << 16))),
+            0x1c if function == 0x02 => MULT((rd, rs, rt_operand)),
+            0x23 => LW((rt, sign_extended, rs)),
+            0x2b => SW((rt, sign_extended, rs)),
+            _ => return Err(unsupported_opcode(opcode)),
+        };
+
+        if instruction.writes_zero_register() {
+            return Err("writes register $zero, which zMIPS cannot prove".to_string());
+        }
+        Ok(instruction)
+    }
+}
+
+fn has_delay_slot(instruction: &Instruction) -> bool {
+    matches!(
+        instruction,
+        BEQ(_) | BNE(_) | BLT(_) | BLE(_) | BGT(_) | BLTU(_) | BGEU(_)
+    ) || matches!(instruction, J(_) | JR(_) | JAL(_) | JALR(_))
+}
+
+fn unsupported_opcode(opcode: u32) -> String {
+    let mnemonic = match opcode {
+        0x20 => "lb",
+        0x21 => "lh",
+        0x24 => "lbu",
+        0x25 => "lhu",
+        0x28 => "sb",
+        0x29 => "sh",
+        _ => return format!("unknown opcode {opcode:#04x}"),
+    };
+    format!("`{mnemonic}` is not supported")
+}
+
+fn unsupported_function(function: u32) -> String {
+    let mnemonic = match function {
+        0x0d => "break",
+        0x10 => "mfhi",
+        0x12 => "mflo",
+        0x18 => "mult",
+        0x19 => "multu",
+        0x1a => "div",
+        0x1b => "divu",
+        _ => return format!("unknown function {function:#04x}"),
+    };
+    format!("`{mnemonic}` is not supported")
+}
+
+#[cfg(test)]
+mod elf_tests {
+    use super::*;
+
+    const SUM_ELF: &[u8] = include_bytes!("../samples/sum.elf");
+    const UNSUPPORTED_ELF: &[u8] = include_bytes!("../samples/unsupported.elf");
+
+    /// The zMIPS equivalent of `samples/sum.s`.
+    const SUM_ZMIPS: &str = "
+        j __start
+        sum:
+            addu $v0, $zero, $zero
+        loop:
+            ble $a1, $zero, done
+            sll $zero, $zero, 0
+            lw $t0, 0($a0)
+            addu $v0, $v0, $t0
+            addu $a0, $a0, 4
+            addu $a1, $a1, -1
+            j loop
+            sll $zero, $zero, 0
+        done:
+            jr $ra
+            sll $zero, $zero, 0
+        __start:
+            move $a0, 0x10010000
+            or $a0, $a0, 0
+            move $t1, 0x10010000
+            lw $a1, 16($t1)
+            jal sum
+            sll $zero, $zero, 0
+            move $t1, 0x10010000
+            sw $v0, 20($t1)
+            mult $t3, $v0, $v0
+            slt $v0, $t2, $a1
+            sltu $a1, $t2, $v0
+            slt $t3, $t4, -3
+            sltu $t3, $t4, 7
+            not $t4, $t3, $t3
+            sll $t5, $t4, 3
+            srl $t5, $t5, 1
+            sra $t6, $t5, 2
+            sll $t6, $t6, $t2
+            srl $t6, $t6, $t2
+            sra $t6, $t6, $t2
+            xor $t7, $t6, 0xffff
+            and $t7, $t7, 0xff
+            add $t8, $t7, $t6
+            add $t8, $t8, -1
+            sub $t9, $t8, $t7
+            subu $t9, $t9, $t8
+            and $s0, $t9, $t8
+            or $s0, $s0, $t7
+            xor $s0, $s0, $t6
+            ble $zero, $s0, positive
+            sll $zero, $zero, 0
+            blt $s0, $zero, positive
+            sll $zero, $zero, 0
+        positive:
+            bgt $s0, $zero, halt
+            sll $zero, $zero, 0
+            bne $s0, $zero, halt
+            sll $zero, $zero, 0
+            beq $s0, $s0, halt
+            sll $zero, $zero, 0
+        halt:
+            lw $a0, 20($t1)
+            addu $v0, $zero, 1
+            syscall
+            addu $v0, $zero, 10
+            syscall
+    ";
+
+    #[test]
+    fn load_sample_elf_test() {
+        let program = load(SUM_ELF).unwrap();
+        let expected = Program::from_code(SUM_ZMIPS).unwrap();
+        assert_eq!(expected.instructions, program.instructions);
+
+        let array_length_and_total = [3, 5, 7, -2_i32 as u32, 4, 0];
+        assert_eq!(array_length_and_total, program.data[..6]);
+    }
+
+    #[test]
+    fn unsupported_instructions_are_reported_with_their_addresses_test() {
+        let error = load(UNSUPPORTED_ELF).unwrap_err();
+        let unsupported = match &error {
+            ElfError::UnsupportedInstructions(unsupported) => unsupported,
+            _ => panic!("expected unsupported instructions, got {error}"),
+        };
+        let addresses = unsupported
+            .iter()
+            .map(|instruction| instruction.address)
+            .collect::<Vec<_>>();
+        let expected = [
+            0x0040_0000, // lb
+            0x0040_0004, // mult
+            0x0040_0008, // mflo
+            0x0040_000c, // addiu $zero, …
+            0x0040_0014, // no nop in delay slot
+            0x0040_0018, // jalr $t0, $t1
+            0x0040_0020, // break
+        ];
+        assert_eq!(expected.to_vec(), addresses);
+        assert!(error
+            .to_string()
+            .contains("0x00400020: 0x0000000d (`break` is not supported)"));
+    }
+
+    #[test]
+    fn malformed_files_are_rejected_test() {
+        assert!(matches!(load(b"MIPS"), Err(ElfError::Malformed(_))));
+        assert!(matches!(
+            load(&SUM_ELF[..ELF_HEADER_SIZE]),
+            Err(ElfError::Malformed(_))
+        ));
+
+        let mut big_endian = SUM_ELF.to_vec();
+        big_endian[5] = 2;
+        assert!(matches!(load(&big_endian), Err(ElfError::Malformed(_))));
+    }
+
+    #[test]
+    fn data_segments_far_above_the_data_segment_start_are_rejected_test() {
+        let segments = parse_segments(SUM_ELF).unwrap();
+        let data_segment_index = segments
+            .iter()
+            .position(|segment| {
+                segment.kind == SEGMENT_TYPE_LOAD && segment.flags & SEGMENT_FLAG_EXECUTABLE == 0
+            })
+            .unwrap();
+        let table_offset = read_u32(SUM_ELF, 28).unwrap() as usize;
+        let entry_size = read_u16(SUM_ELF, 42).unwrap() as usize;
+        let address_offset = table_offset + data_segment_index * entry_size + 8;
+
+        let mut far_away = SUM_ELF.to_vec();
+        far_away[address_offset..address_offset + 4]
+            .copy_from_slice(&0x2000_0000_u32.to_le_bytes());
+        let error = load(&far_away).unwrap_err();
+        assert!(error.to_string().contains("segment at 0x20000000 exceeds"));
+    }
+}
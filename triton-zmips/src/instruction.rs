@@ -1,9 +1,11 @@
 use std::collections::HashMap;
+use std::error::Error;
 use std::fmt::Display;
 use std::vec;
 
 use anyhow::bail;
 use anyhow::Result;
+use itertools::Itertools;
 use strum::EnumCount;
 use strum::IntoEnumIterator;
 use strum_macros::Display as DisplayMacro;
@@ -12,7 +14,14 @@ use strum_macros::EnumIter;
 use twenty_first::shared_math::b_field_element::BFieldElement;
 use twenty_first::shared_math::b_field_element::BFIELD_ZERO;
 
-use crate::regs::{Reg, RegA};
+use crate::data_segment::DataSegment;
+use crate::data_segment::Directive;
+use crate::data_segment::DATA_SEGMENT_MAX_SIZE;
+use crate::pseudo_instruction::expand_pseudo_instructions;
+use crate::pseudo_instruction::PseudoInstruction;
+use crate::regs::parse_immediate;
+use crate::regs::DisplaySigned;
+use crate::regs::{Reg, RegA, RegParseError, REGS};
 use triton_program::AbstractInstruction;
 use AnInstruction::*;
 
@@ -36,15 +45,25 @@ pub enum LabelledInstruction {
     /// <https://triton-vm.org/spec/isa.html>
     Instruction(AnInstruction<String, String, String>),
 
-    /// Labels look like "`<name>:`" and are translated into absolute addresses.
+    /// Pseudo-instructions are shorthand for instructions of the ISA, see [`PseudoInstruction`].
+    Pseudo(PseudoInstruction),
+
+    /// Labels look like "`<name>:`" and are translated into absolute addresses. Labels in the
+    /// text segment resolve to instruction addresses, labels in the data segment to byte
+    /// addresses.
     Label(String),
+
+    /// Assembler directives look like "`.<name> <args>`", see [`Directive`].
+    Directive(Directive),
 }
 
 impl Display for LabelledInstruction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             LabelledInstruction::Instruction(instr) => write!(f, "{instr}"),
+            LabelledInstruction::Pseudo(pseudo) => write!(f, "{pseudo}"),
             LabelledInstruction::Label(label_name) => write!(f, "{label_name}:"),
+            LabelledInstruction::Directive(directive) => write!(f, "{directive}"),
         }
     }
 }
@@ -52,6 +71,54 @@ impl Display for LabelledInstruction {
 #[derive(Debug, DisplayMacro, Clone, Copy, PartialEq, Eq, Hash, EnumCountMacro)]
 pub enum DivinationHint {}
 
+/// The services of instruction `syscall`, selected by the code in register `$v0`. Services also
+/// offered by SPIM and MARS use their codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumCountMacro, EnumIter)]
+pub enum Syscall {
+    /// Write the word in `$a0` to the standard output.
+    PrintInt,
+
+    /// Read a word from the public input into `$v0`.
+    ReadInt,
+
+    /// Terminate execution with exit code 0.
+    Exit,
+
+    /// Terminate execution with the exit code in `$a0`.
+    Exit2,
+
+    /// Read a word from the secret input into `$v0`.
+    ReadSecretInt,
+
+    /// Report the word in `$a0` as the program's final answer.
+    Answer,
+}
+
+impl Syscall {
+    /// The code identifying the service in register `$v0`.
+    pub const fn code(&self) -> u32 {
+        match self {
+            Syscall::PrintInt => 1,
+            Syscall::ReadInt => 5,
+            Syscall::Exit => 10,
+            Syscall::Exit2 => 17,
+            Syscall::ReadSecretInt => 100,
+            Syscall::Answer => 101,
+        }
+    }
+}
+
+impl TryFrom<u32> for Syscall {
+    type Error = anyhow::Error;
+
+    fn try_from(code: u32) -> Result<Self> {
+        match Syscall::iter().find(|syscall| syscall.code() == code) {
+            Some(syscall) => Ok(syscall),
+            None => bail!("No syscall with code {code} exists."),
+        }
+    }
+}
+
 /// A Triton VM instruction. See the
 /// [Instruction Set Architecture](https://triton-vm.org/spec/isa.html)
 /// for more details.
@@ -65,42 +132,59 @@ pub enum AnInstruction<L: PartialEq + Default, R: PartialEq + Default, A: Partia
     BLT((R, R, L)),
     BLE((R, R, L)),
     BGT((R, R, L)),
+    BLTU((R, R, L)),
+    BGEU((R, R, L)),
     SEQ((R, R, A)),
     SNE((R, R, A)),
     SLT((R, R, A)),
+    SLTU((R, R, A)),
     SLE((R, R, A)),
     J(L),
     JR(R),
+    JAL(L),
+    JALR(R),
 
     // Memory access
     LW((R, A, R)),
     SW((R, A, R)),
 
-    // Base field arithmetic on stack
+    // 32-bit integer arithmetic
     ADD((R, R, A)),
+    ADDU((R, R, A)),
     SUB((R, R, A)),
+    SUBU((R, R, A)),
     MULT((R, R, A)),
     DIV((R, R, A)),
+    DIVU((R, R, A)),
     MOD((R, R, A)),
     MOVE((R, A)),
     LA((R, L)),
 
-    // Bitwise arithmetic on stack
+    // Bitwise arithmetic
     AND((R, R, A)),
     XOR((R, R, A)),
     OR((R, R, A)),
     NOT((R, R, A)),
     SLL((R, R, A)),
     SRL((R, R, A)),
+    SRA((R, R, A)),
 
     // Read/write
     PUBREAD(R),
     SECREAD(R),
+
+    /// Move the cursor of the public input tape to position `(rs + operand) mod 2^32`, counted
+    /// from the start of the tape. Seeks are absolute: no instruction exposes the current
+    /// position, so programs seeking relative to it must count their reads themselves.
     PUBSEEK((R, A)),
+
+    /// Like [`PUBSEEK`], but for the secret input tape.
     SECSEEK((R, A)),
+
     PRINT(R),
     EXIT(R),
     ANSWER(R),
+    SYSCALL,
 }
 
 impl<Dest: PartialEq + Default, R: PartialEq + Default, I: PartialEq + Default>
@@ -147,6 +231,16 @@ impl<Dest: PartialEq + Default, R: PartialEq + Default, I: PartialEq + Default>
             ANSWER(_) => 30,
             OR(_) => 31,
             BGT(_) => 32,
+            ADDU(_) => 33,
+            SUBU(_) => 34,
+            DIVU(_) => 35,
+            SRA(_) => 36,
+            SLTU(_) => 37,
+            JAL(_) => 38,
+            JALR(_) => 39,
+            BLTU(_) => 40,
+            BGEU(_) => 41,
+            SYSCALL => 42,
         }
     }
 
@@ -157,18 +251,26 @@ impl<Dest: PartialEq + Default, R: PartialEq + Default, I: PartialEq + Default>
             BLT(_) => "blt",
             BLE(_) => "ble",
             BGT(_) => "bgt",
+            BLTU(_) => "bltu",
+            BGEU(_) => "bgeu",
             SEQ(_) => "seq",
             SNE(_) => "sne",
             SLT(_) => "slt",
+            SLTU(_) => "sltu",
             SLE(_) => "sle",
             J(_) => "j",
             JR(_) => "jr",
+            JAL(_) => "jal",
+            JALR(_) => "jalr",
             LW(_) => "lw",
             SW(_) => "sw",
             ADD(_) => "add",
+            ADDU(_) => "addu",
             SUB(_) => "sub",
+            SUBU(_) => "subu",
             MULT(_) => "mult",
             DIV(_) => "div",
+            DIVU(_) => "divu",
             MOD(_) => "mod",
             MOVE(_) => "move",
             LA(_) => "la",
@@ -178,6 +280,7 @@ impl<Dest: PartialEq + Default, R: PartialEq + Default, I: PartialEq + Default>
             NOT(_) => "not",
             SLL(_) => "sll",
             SRL(_) => "srl",
+            SRA(_) => "sra",
             PUBREAD(_) => "pubread",
             SECREAD(_) => "secread",
             PUBSEEK(_) => "pubseek",
@@ -185,6 +288,7 @@ impl<Dest: PartialEq + Default, R: PartialEq + Default, I: PartialEq + Default>
             PRINT(_) => "print",
             EXIT(_) => "exit",
             ANSWER(_) => "answer",
+            SYSCALL => "syscall",
         }
     }
 
@@ -200,6 +304,23 @@ impl<Dest: PartialEq + Default, R: PartialEq + Default, I: PartialEq + Default>
         1
     }
 
+    /// The register the instruction writes to, if it names one. Instructions `jal`, `jalr`, and
+    /// `syscall` write to registers `$ra` and `$v0`, respectively, without naming them.
+    pub fn destination(&self) -> Option<&R> {
+        match self {
+            SEQ((_, rd, _)) | SNE((_, rd, _)) | SLT((_, rd, _)) | SLTU((_, rd, _))
+            | SLE((_, rd, _)) => Some(rd),
+            LW((rd, _, _)) => Some(rd),
+            ADD((rd, _, _)) | ADDU((rd, _, _)) | SUB((rd, _, _)) | SUBU((rd, _, _))
+            | MULT((rd, _, _)) | DIV((rd, _, _)) | DIVU((rd, _, _)) | MOD((rd, _, _))
+            | AND((rd, _, _)) | XOR((rd, _, _)) | OR((rd, _, _)) | NOT((rd, _, _))
+            | SLL((rd, _, _)) | SRL((rd, _, _)) | SRA((rd, _, _)) => Some(rd),
+            MOVE((rd, _)) | LA((rd, _)) => Some(rd),
+            PUBREAD(rd) | SECREAD(rd) => Some(rd),
+            _ => None,
+        }
+    }
+
     // /// Get the i'th instruction bit
     // pub fn ib(&self, arg: Ord8) -> BFieldElement {
     //     let opcode = self.opcode();
@@ -210,56 +331,79 @@ impl<Dest: PartialEq + Default, R: PartialEq + Default, I: PartialEq + Default>
 }
 
 impl<Dest: PartialEq + Default> AnInstruction<Dest, String, String> {
+    /// Replace every target by the result of `f`, and parse every register and operand.
     fn map_call_address<F, NewDest: PartialEq + Default>(
         &self,
         f: F,
-    ) -> AnInstruction<NewDest, Reg, RegA>
+    ) -> Result<AnInstruction<NewDest, Reg, RegA>, AssemblyError>
     where
-        F: Fn(&Dest) -> NewDest,
+        F: Fn(&Dest) -> Result<NewDest, AssemblyError>,
         Dest: Clone,
     {
-        match self {
-            BEQ((r1, r2, addr)) => BEQ((r1.into(), r2.into(), f(addr))),
-            BNE((r1, r2, addr)) => BNE((r1.into(), r2.into(), f(addr))),
-            BLT((r1, r2, addr)) => BLT((r1.into(), r2.into(), f(addr))),
-            BLE((r1, r2, addr)) => BLE((r1.into(), r2.into(), f(addr))),
-            BGT((r1, r2, addr)) => BGT((r1.into(), r2.into(), f(addr))),
-            SEQ((r1, r2, a)) => SEQ((r1.into(), r2.into(), a.into())),
-            SNE((r1, r2, a)) => SNE((r1.into(), r2.into(), a.into())),
-            SLT((r1, r2, a)) => SLT((r1.into(), r2.into(), a.into())),
-            SLE((r1, r2, a)) => SLE((r1.into(), r2.into(), a.into())),
-            J(label) => J(f(label)),
-            JR(r) => JR(r.into()),
-            LW((r1, a, r2)) => LW((r1.into(), a.into(), r2.into())),
-            SW((r1, a, r2)) => SW((r1.into(), a.into(), r2.into())),
-            ADD((r1, r2, a)) => ADD((r1.into(), r2.into(), a.into())),
-            SUB((r1, r2, a)) => SUB((r1.into(), r2.into(), a.into())),
-            MULT((r1, r2, a)) => MULT((r1.into(), r2.into(), a.into())),
-            DIV((r1, r2, a)) => DIV((r1.into(), r2.into(), a.into())),
-            MOD((r1, r2, a)) => MOD((r1.into(), r2.into(), a.into())),
-            MOVE((r, a)) => MOVE((r.into(), a.into())),
-            LA((r, a)) => LA((r.into(), f(a))),
-            AND((r1, r2, a)) => AND((r1.into(), r2.into(), a.into())),
-            XOR((r1, r2, a)) => XOR((r1.into(), r2.into(), a.into())),
-            OR((r1, r2, a)) => OR((r1.into(), r2.into(), a.into())),
-            NOT((r1, r2, a)) => NOT((r1.into(), r2.into(), a.into())),
-            SLL((r1, r2, a)) => SLL((r1.into(), r2.into(), a.into())),
-            SRL((r1, r2, a)) => SRL((r1.into(), r2.into(), a.into())),
-            PUBREAD(r) => PUBREAD(r.into()),
-            SECREAD(r) => SECREAD(r.into()),
-            PUBSEEK((r, a)) => PUBSEEK((r.into(), a.into())),
-            SECSEEK((r, a)) => SECSEEK((r.into(), a.into())),
-            PRINT(r) => PRINT(r.into()),
-            EXIT(r) => EXIT(r.into()),
-            ANSWER(r) => ANSWER(r.into()),
-        }
+        let instruction = match self {
+            BEQ((r1, r2, addr)) => BEQ((r1.parse()?, r2.parse()?, f(addr)?)),
+            BNE((r1, r2, addr)) => BNE((r1.parse()?, r2.parse()?, f(addr)?)),
+            BLT((r1, r2, addr)) => BLT((r1.parse()?, r2.parse()?, f(addr)?)),
+            BLE((r1, r2, addr)) => BLE((r1.parse()?, r2.parse()?, f(addr)?)),
+            BGT((r1, r2, addr)) => BGT((r1.parse()?, r2.parse()?, f(addr)?)),
+            BLTU((r1, r2, addr)) => BLTU((r1.parse()?, r2.parse()?, f(addr)?)),
+            BGEU((r1, r2, addr)) => BGEU((r1.parse()?, r2.parse()?, f(addr)?)),
+            SEQ((r1, r2, a)) => SEQ((r1.parse()?, r2.parse()?, a.parse()?)),
+            SNE((r1, r2, a)) => SNE((r1.parse()?, r2.parse()?, a.parse()?)),
+            SLT((r1, r2, a)) => SLT((r1.parse()?, r2.parse()?, a.parse()?)),
+            SLTU((r1, r2, a)) => SLTU((r1.parse()?, r2.parse()?, a.parse()?)),
+            SLE((r1, r2, a)) => SLE((r1.parse()?, r2.parse()?, a.parse()?)),
+            J(label) => J(f(label)?),
+            JR(r) => JR(r.parse()?),
+            JAL(label) => JAL(f(label)?),
+            JALR(r) => JALR(r.parse()?),
+            LW((r1, a, r2)) => LW((r1.parse()?, a.parse()?, r2.parse()?)),
+            SW((r1, a, r2)) => SW((r1.parse()?, a.parse()?, r2.parse()?)),
+            ADD((r1, r2, a)) => ADD((r1.parse()?, r2.parse()?, a.parse()?)),
+            ADDU((r1, r2, a)) => ADDU((r1.parse()?, r2.parse()?, a.parse()?)),
+            SUB((r1, r2, a)) => SUB((r1.parse()?, r2.parse()?, a.parse()?)),
+            SUBU((r1, r2, a)) => SUBU((r1.parse()?, r2.parse()?, a.parse()?)),
+            MULT((r1, r2, a)) => MULT((r1.parse()?, r2.parse()?, a.parse()?)),
+            DIV((r1, r2, a)) => DIV((r1.parse()?, r2.parse()?, a.parse()?)),
+            DIVU((r1, r2, a)) => DIVU((r1.parse()?, r2.parse()?, a.parse()?)),
+            MOD((r1, r2, a)) => MOD((r1.parse()?, r2.parse()?, a.parse()?)),
+            MOVE((r, a)) => MOVE((r.parse()?, a.parse()?)),
+            LA((r, a)) => LA((r.parse()?, f(a)?)),
+            AND((r1, r2, a)) => AND((r1.parse()?, r2.parse()?, a.parse()?)),
+            XOR((r1, r2, a)) => XOR((r1.parse()?, r2.parse()?, a.parse()?)),
+            OR((r1, r2, a)) => OR((r1.parse()?, r2.parse()?, a.parse()?)),
+            NOT((r1, r2, a)) => NOT((r1.parse()?, r2.parse()?, a.parse()?)),
+            SLL((r1, r2, a)) => SLL((r1.parse()?, r2.parse()?, a.parse()?)),
+            SRL((r1, r2, a)) => SRL((r1.parse()?, r2.parse()?, a.parse()?)),
+            SRA((r1, r2, a)) => SRA((r1.parse()?, r2.parse()?, a.parse()?)),
+            PUBREAD(r) => PUBREAD(r.parse()?),
+            SECREAD(r) => SECREAD(r.parse()?),
+            PUBSEEK((r, a)) => PUBSEEK((r.parse()?, a.parse()?)),
+            SECSEEK((r, a)) => SECSEEK((r.parse()?, a.parse()?)),
+            PRINT(r) => PRINT(r.parse()?),
+            EXIT(r) => EXIT(r.parse()?),
+            ANSWER(r) => ANSWER(r.parse()?),
+            SYSCALL => SYSCALL,
+        };
+        Ok(instruction)
+    }
+}
+
+/// Displays an operand with [`DisplaySigned::fmt_signed`].
+struct Signed<'a, I>(&'a I);
+
+impl<I: DisplaySigned> Display for Signed<'_, I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt_signed(f)
     }
 }
 
+/// Immediates of instructions interpreting them as signed words, or as offsets, are written as
+/// signed words. Immediates of unsigned and bitwise instructions are written as unsigned words.
 impl<
         Dest: Display + PartialEq + Default,
         R: Display + PartialEq + Default + Clone,
-        I: Display + PartialEq + Default + Clone,
+        I: Display + DisplaySigned + PartialEq + Default + Clone,
     > Display for AnInstruction<Dest, R, I>
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -270,15 +414,27 @@ impl<
             BLT((r1, r2, addr)) => write!(f, " {}, {}, {}", r1, r2, addr),
             BLE((r1, r2, addr)) => write!(f, " {}, {}, {}", r1, r2, addr),
             BGT((r1, r2, addr)) => write!(f, " {}, {}, {}", r1, r2, addr),
+            BLTU((r1, r2, addr)) => write!(f, " {}, {}, {}", r1, r2, addr),
+            BGEU((r1, r2, addr)) => write!(f, " {}, {}, {}", r1, r2, addr),
+            SEQ((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, Signed(a)),
+            SNE((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, Signed(a)),
+            SLT((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, Signed(a)),
+            SLTU((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, a),
+            SLE((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, Signed(a)),
             J(addr) => write!(f, " {}", addr),
             JR(r) => write!(f, " {}", r),
-            LW((r1, a, r2)) => write!(f, " {}, {}({})", r1, a, r2),
-            SW((r1, a, r2)) => write!(f, " {}, {}({})", r1, a, r2),
-            ADD((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, a),
-            SUB((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, a),
-            MULT((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, a),
-            DIV((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, a),
-            MOD((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, a),
+            JAL(addr) => write!(f, " {}", addr),
+            JALR(r) => write!(f, " {}", r),
+            LW((r1, a, r2)) => write!(f, " {}, {}({})", r1, Signed(a), r2),
+            SW((r1, a, r2)) => write!(f, " {}, {}({})", r1, Signed(a), r2),
+            ADD((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, Signed(a)),
+            ADDU((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, Signed(a)),
+            SUB((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, Signed(a)),
+            SUBU((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, Signed(a)),
+            MULT((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, Signed(a)),
+            DIV((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, Signed(a)),
+            DIVU((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, a),
+            MOD((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, Signed(a)),
             MOVE((r, a)) => write!(f, " {}, {}", r, a),
             LA((r, a)) => write!(f, " {}, {}", r, a),
             AND((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, a),
@@ -287,28 +443,346 @@ impl<
             NOT((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, a),
             SLL((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, a),
             SRL((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, a),
+            SRA((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, a),
             PUBREAD(r) => write!(f, " {}", r),
             SECREAD(r) => write!(f, " {}", r),
-            PUBSEEK((r, a)) => write!(f, " {}, {}", r, a),
-            SECSEEK((r, a)) => write!(f, " {}, {}", r, a),
+            PUBSEEK((r, a)) => write!(f, " {}, {}", r, Signed(a)),
+            SECSEEK((r, a)) => write!(f, " {}, {}", r, Signed(a)),
             PRINT(r) => write!(f, " {}", r),
             EXIT(r) => write!(f, " {}", r),
             ANSWER(r) => write!(f, " {}", r),
-            _ => Ok(()),
+            SYSCALL => Ok(()),
         }
     }
 }
 
 impl Instruction {
+    /// The operands in the order of the slots of [`Operands`]: `rs`, `rt`, `rd`, `is_imm`, and
+    /// `imm`. Together with the opcode, they determine the instruction uniquely.
     pub fn args(&self) -> Vec<BFieldElement> {
-        match self {
-            BEQ((r1, r2, addr)) => vec![BFieldElement::from(r1), BFieldElement::from(r2), *addr],
-            BNE((r1, r2, addr)) => vec![BFieldElement::from(r1), BFieldElement::from(r2), *addr],
-            BLT((r1, r2, addr)) => vec![BFieldElement::from(r1), BFieldElement::from(r2), *addr],
-            BLE((r1, r2, addr)) => vec![BFieldElement::from(r1), BFieldElement::from(r2), *addr],
-            BGT((r1, r2, addr)) => vec![BFieldElement::from(r1), BFieldElement::from(r2), *addr],
-            J(addr) => vec![*addr],
-            _ => vec![],
+        let operands = self.operands();
+        vec![
+            operands.rs.into(),
+            operands.rt.into(),
+            operands.rd.into(),
+            BFieldElement::new(operands.is_imm as u64),
+            operands.imm,
+        ]
+    }
+
+    /// The register indices and the immediate this instruction operates on, arranged in the
+    /// fixed slots used by the zMIPS processor table. Slots not used by an instruction are zero.
+    pub fn operands(&self) -> Operands {
+        let mut operands = Operands::default();
+        match *self {
+            BEQ((r1, r2, addr)) | BNE((r1, r2, addr)) | BLT((r1, r2, addr))
+            | BLE((r1, r2, addr)) | BGT((r1, r2, addr)) | BLTU((r1, r2, addr))
+            | BGEU((r1, r2, addr)) => {
+                operands.rs = r1;
+                operands.rt = r2;
+                operands.imm = addr;
+            }
+            SEQ((r1, r2, a)) | SNE((r1, r2, a)) | SLT((r1, r2, a)) | SLTU((r1, r2, a))
+            | SLE((r1, r2, a)) => {
+                operands.rs = r1;
+                operands.rd = r2;
+                operands.set_second_source(a);
+            }
+            J(addr) => {
+                operands.is_imm = true;
+                operands.imm = addr;
+            }
+            JR(r) | PRINT(r) | EXIT(r) | ANSWER(r) => operands.rs = r,
+            JAL(addr) => {
+                operands.rd = Reg::Ra;
+                operands.is_imm = true;
+                operands.imm = addr;
+            }
+            JALR(r) => {
+                operands.rs = r;
+                operands.rd = Reg::Ra;
+            }
+            LW((r1, a, r2)) | SW((r1, a, r2)) => {
+                operands.rd = r1;
+                operands.rs = r2;
+                operands.set_second_source(a);
+            }
+            ADD((r1, r2, a)) | ADDU((r1, r2, a)) | SUB((r1, r2, a)) | SUBU((r1, r2, a))
+            | MULT((r1, r2, a)) | DIV((r1, r2, a)) | DIVU((r1, r2, a)) | MOD((r1, r2, a))
+            | AND((r1, r2, a)) | XOR((r1, r2, a)) | OR((r1, r2, a)) | NOT((r1, r2, a))
+            | SLL((r1, r2, a)) | SRL((r1, r2, a)) | SRA((r1, r2, a)) => {
+                operands.rd = r1;
+                operands.rs = r2;
+                operands.set_second_source(a);
+            }
+            MOVE((r, a)) => {
+                operands.rd = r;
+                operands.set_second_source(a);
+            }
+            LA((r, addr)) => {
+                operands.rd = r;
+                operands.is_imm = true;
+                operands.imm = addr;
+            }
+            PUBREAD(r) | SECREAD(r) => operands.rd = r,
+            PUBSEEK((r, a)) | SECSEEK((r, a)) => {
+                operands.rs = r;
+                operands.set_second_source(a);
+            }
+            SYSCALL => return SYSCALL_OPERANDS,
+        }
+        operands
+    }
+
+    /// The instruction packed into a single word, as looked up by the zMIPS processor table and
+    /// hashed into the program digest. The encoding is stable:
+    ///
+    /// | bits    | field    | content                                                   |
+    /// |:--------|:---------|:----------------------------------------------------------|
+    /// | 0..8    | `opcode` | see [`AnInstruction::opcode`]                             |
+    /// | 8..13   | `rs`     | index of the first source register                        |
+    /// | 13..18  | `rt`     | index of the second source register                       |
+    /// | 18..23  | `rd`     | index of the destination register                         |
+    /// | 23      | `is_imm` | 1 if the second source operand is the immediate           |
+    /// | 24..56  | `imm`    | immediate as 32-bit word, or target address               |
+    ///
+    /// Which instruction argument occupies which field is defined by [`Instruction::operands`].
+    /// Fields an instruction does not use are 0. The inverse is [`Instruction::from_word`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a target address exceeds 32 bits, as it would overflow into other fields.
+    pub fn word(&self) -> BFieldElement {
+        let operands = self.operands();
+        assert!(
+            operands.imm.value() <= u32::MAX as u64,
+            "Target address of `{self}` exceeds 32 bits."
+        );
+        let rs: u64 = u32::from(operands.rs).into();
+        let rt: u64 = u32::from(operands.rt).into();
+        let rd: u64 = u32::from(operands.rd).into();
+        let word = self.opcode() as u64
+            + (rs << RS_SHIFT)
+            + (rt << RT_SHIFT)
+            + (rd << RD_SHIFT)
+            + ((operands.is_imm as u64) << IS_IMM_SHIFT)
+            + (operands.imm.value() << IMM_SHIFT);
+        BFieldElement::new(word)
+    }
+
+    /// Decode an instruction from its word, see [`Instruction::word`]. Fails if the word is not
+    /// the encoding of any instruction, for example because of an unknown opcode, an immediate
+    /// exceeding 32 bits, or a non-zero field the instruction does not use. Also fails if the
+    /// instruction writes register `$zero`, see [`Instruction::writes_zero_register`].
+    pub fn from_word(word: BFieldElement) -> Result<Self> {
+        let word = word.value();
+        let field = |shift: u32, num_bits: u32| (word >> shift) & ((1 << num_bits) - 1);
+        let reg = |shift: u32| REGS[field(shift, REG_BITS) as usize];
+        let operands = Operands {
+            rs: reg(RS_SHIFT),
+            rt: reg(RT_SHIFT),
+            rd: reg(RD_SHIFT),
+            is_imm: field(IS_IMM_SHIFT, 1) == 1,
+            imm: BFieldElement::new(word >> IMM_SHIFT),
+        };
+        let instruction = Instruction::from_operands(field(0, RS_SHIFT) as u32, operands)?;
+
+        // Decoding ignores fields the instruction does not use. Re-encoding reveals them.
+        if instruction.word().value() != word {
+            bail!("Word {word} is not the canonical encoding of instruction `{instruction}`.");
+        }
+        if instruction.writes_zero_register() {
+            bail!("Instruction `{instruction}` writes register $zero.");
+        }
+        Ok(instruction)
+    }
+
+    /// The instruction with the given opcode, taking its arguments from the corresponding slots
+    /// of `operands`, see [`Instruction::operands`]. Slots the instruction does not use are
+    /// ignored. Fails if the opcode is unknown or the immediate exceeds 32 bits.
+    pub fn from_operands(opcode: u32, operands: Operands) -> Result<Self> {
+        let Operands {
+            rs,
+            rt,
+            rd,
+            is_imm,
+            imm: addr,
+        } = operands;
+        let imm = match u32::try_from(addr.value()) {
+            Ok(imm) => imm,
+            Err(_) => bail!("Immediate {addr} exceeds 32 bits."),
+        };
+        let a = match is_imm {
+            true => RegA::Imm(imm),
+            false => RegA::RegName(rt),
+        };
+
+        let instruction = match Instruction::try_from(opcode)? {
+            BEQ(_) => BEQ((rs, rt, addr)),
+            BNE(_) => BNE((rs, rt, addr)),
+            BLT(_) => BLT((rs, rt, addr)),
+            BLE(_) => BLE((rs, rt, addr)),
+            BGT(_) => BGT((rs, rt, addr)),
+            BLTU(_) => BLTU((rs, rt, addr)),
+            BGEU(_) => BGEU((rs, rt, addr)),
+            SEQ(_) => SEQ((rs, rd, a)),
+            SNE(_) => SNE((rs, rd, a)),
+            SLT(_) => SLT((rs, rd, a)),
+            SLTU(_) => SLTU((rs, rd, a)),
+            SLE(_) => SLE((rs, rd, a)),
+            J(_) => J(addr),
+            JR(_) => JR(rs),
+            JAL(_) => JAL(addr),
+            JALR(_) => JALR(rs),
+            LW(_) => LW((rd, a, rs)),
+            SW(_) => SW((rd, a, rs)),
+            ADD(_) => ADD((rd, rs, a)),
+            ADDU(_) => ADDU((rd, rs, a)),
+            SUB(_) => SUB((rd, rs, a)),
+            SUBU(_) => SUBU((rd, rs, a)),
+            MULT(_) => MULT((rd, rs, a)),
+            DIV(_) => DIV((rd, rs, a)),
+            DIVU(_) => DIVU((rd, rs, a)),
+            MOD(_) => MOD((rd, rs, a)),
+            MOVE(_) => MOVE((rd, a)),
+            LA(_) => LA((rd, addr)),
+            AND(_) => AND((rd, rs, a)),
+            XOR(_) => XOR((rd, rs, a)),
+            OR(_) => OR((rd, rs, a)),
+            NOT(_) => NOT((rd, rs, a)),
+            SLL(_) => SLL((rd, rs, a)),
+            SRL(_) => SRL((rd, rs, a)),
+            SRA(_) => SRA((rd, rs, a)),
+            PUBREAD(_) => PUBREAD(rd),
+            SECREAD(_) => SECREAD(rd),
+            PUBSEEK(_) => PUBSEEK((rs, a)),
+            SECSEEK(_) => SECSEEK((rs, a)),
+            PRINT(_) => PRINT(rs),
+            EXIT(_) => EXIT(rs),
+            ANSWER(_) => ANSWER(rs),
+            SYSCALL => SYSCALL,
+        };
+
+        Ok(instruction)
+    }
+
+    /// Whether the instruction writes to register `$zero`, other than `nop`, which is
+    /// `sll $zero, $zero, 0`. Register `$zero` is hardwired to 0, but the zMIPS Processor Table
+    /// requires the destination register to hold the instruction's result. Such instructions are
+    /// rejected when assembling or decoding a program.
+    pub fn writes_zero_register(&self) -> bool {
+        let is_nop = *self == SLL((Reg::Zero, Reg::Zero, RegA::Imm(0)));
+        !is_nop && self.destination() == Some(&Reg::Zero)
+    }
+
+    /// The address this instruction branches or jumps to, or loads, if any.
+    pub fn target_address(&self) -> Option<BFieldElement> {
+        match *self {
+            BEQ((_, _, addr)) | BNE((_, _, addr)) | BLT((_, _, addr)) | BLE((_, _, addr))
+            | BGT((_, _, addr)) | BLTU((_, _, addr)) | BGEU((_, _, addr)) | J(addr) | JAL(addr)
+            | LA((_, addr)) => Some(addr),
+            _ => None,
+        }
+    }
+
+    /// The instruction in the form produced by the parser, the inverse of [`convert_labels`].
+    /// Target addresses are replaced by the labels `label` provides, or kept as they are.
+    pub fn to_labelled<F>(&self, label: F) -> AnInstruction<String, String, String>
+    where
+        F: Fn(BFieldElement) -> Option<String>,
+    {
+        let r = |r: Reg| r.to_string();
+        let o = |a: RegA| a.to_string();
+        let l = |addr: BFieldElement| label(addr).unwrap_or_else(|| addr.value().to_string());
+        match *self {
+            BEQ((r1, r2, addr)) => BEQ((r(r1), r(r2), l(addr))),
+            BNE((r1, r2, addr)) => BNE((r(r1), r(r2), l(addr))),
+            BLT((r1, r2, addr)) => BLT((r(r1), r(r2), l(addr))),
+            BLE((r1, r2, addr)) => BLE((r(r1), r(r2), l(addr))),
+            BGT((r1, r2, addr)) => BGT((r(r1), r(r2), l(addr))),
+            BLTU((r1, r2, addr)) => BLTU((r(r1), r(r2), l(addr))),
+            BGEU((r1, r2, addr)) => BGEU((r(r1), r(r2), l(addr))),
+            SEQ((r1, r2, a)) => SEQ((r(r1), r(r2), o(a))),
+            SNE((r1, r2, a)) => SNE((r(r1), r(r2), o(a))),
+            SLT((r1, r2, a)) => SLT((r(r1), r(r2), o(a))),
+            SLTU((r1, r2, a)) => SLTU((r(r1), r(r2), o(a))),
+            SLE((r1, r2, a)) => SLE((r(r1), r(r2), o(a))),
+            J(addr) => J(l(addr)),
+            JR(r1) => JR(r(r1)),
+            JAL(addr) => JAL(l(addr)),
+            JALR(r1) => JALR(r(r1)),
+            LW((r1, a, r2)) => LW((r(r1), o(a), r(r2))),
+            SW((r1, a, r2)) => SW((r(r1), o(a), r(r2))),
+            ADD((r1, r2, a)) => ADD((r(r1), r(r2), o(a))),
+            ADDU((r1, r2, a)) => ADDU((r(r1), r(r2), o(a))),
+            SUB((r1, r2, a)) => SUB((r(r1), r(r2), o(a))),
+            SUBU((r1, r2, a)) => SUBU((r(r1), r(r2), o(a))),
+            MULT((r1, r2, a)) => MULT((r(r1), r(r2), o(a))),
+            DIV((r1, r2, a)) => DIV((r(r1), r(r2), o(a))),
+            DIVU((r1, r2, a)) => DIVU((r(r1), r(r2), o(a))),
+            MOD((r1, r2, a)) => MOD((r(r1), r(r2), o(a))),
+            MOVE((r1, a)) => MOVE((r(r1), o(a))),
+            LA((r1, addr)) => LA((r(r1), l(addr))),
+            AND((r1, r2, a)) => AND((r(r1), r(r2), o(a))),
+            XOR((r1, r2, a)) => XOR((r(r1), r(r2), o(a))),
+            OR((r1, r2, a)) => OR((r(r1), r(r2), o(a))),
+            NOT((r1, r2, a)) => NOT((r(r1), r(r2), o(a))),
+            SLL((r1, r2, a)) => SLL((r(r1), r(r2), o(a))),
+            SRL((r1, r2, a)) => SRL((r(r1), r(r2), o(a))),
+            SRA((r1, r2, a)) => SRA((r(r1), r(r2), o(a))),
+            PUBREAD(r1) => PUBREAD(r(r1)),
+            SECREAD(r1) => SECREAD(r(r1)),
+            PUBSEEK((r1, a)) => PUBSEEK((r(r1), o(a))),
+            SECSEEK((r1, a)) => SECSEEK((r(r1), o(a))),
+            PRINT(r1) => PRINT(r(r1)),
+            EXIT(r1) => EXIT(r(r1)),
+            ANSWER(r1) => ANSWER(r(r1)),
+            SYSCALL => SYSCALL,
+        }
+    }
+}
+
+pub const REG_BITS: u32 = 5;
+pub const RS_SHIFT: u32 = 8;
+pub const RT_SHIFT: u32 = 13;
+pub const RD_SHIFT: u32 = 18;
+pub const IS_IMM_SHIFT: u32 = 23;
+pub const IMM_SHIFT: u32 = 24;
+
+/// The operands of a zMIPS [`Instruction`], see [`Instruction::operands`].
+///
+/// - `rs` is the first source register,
+/// - `rt` is the second source register, used if `is_imm` is `false`,
+/// - `rd` is the destination register, or the register holding the value to store for `sw`,
+/// - `imm` is the immediate, used if `is_imm` is `true`, or the target of a branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Operands {
+    pub rs: Reg,
+    pub rt: Reg,
+    pub rd: Reg,
+    pub is_imm: bool,
+    pub imm: BFieldElement,
+}
+
+/// The operands of `syscall`, which are implicit: it reads the service code from register `$v0`
+/// and the argument from register `$a0`, and writes its result to register `$v0`, see
+/// [`Syscall`].
+pub const SYSCALL_OPERANDS: Operands = Operands {
+    rs: Reg::V0,
+    rt: Reg::A0,
+    rd: Reg::V0,
+    is_imm: false,
+    imm: BFIELD_ZERO,
+};
+
+impl Operands {
+    fn set_second_source(&mut self, a: RegA) {
+        match a {
+            RegA::Imm(imm) => {
+                self.is_imm = true;
+                self.imm = imm.into();
+            }
+            RegA::RegName(r) => self.rt = r,
         }
     }
 }
@@ -343,14 +817,103 @@ impl TryFrom<usize> for Instruction {
     }
 }
 
-/// Convert a program with labels to a program with absolute positions
-pub fn convert_labels(program: &[LabelledInstruction]) -> Vec<Instruction> {
+/// An error in assembling [`LabelledInstruction`]s into a program, see [`convert_labels`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AssemblyError {
+    /// A register or an operand is malformed.
+    Operand(RegParseError),
+
+    /// A branch, jump, or `la` refers to a label that is not defined.
+    UnknownLabel(String),
+
+    /// A pseudo-instruction was not expanded into instructions of the ISA.
+    UnexpandedPseudoInstruction(PseudoInstruction),
+
+    /// An instruction writes register `$zero`, see [`Instruction::writes_zero_register`].
+    ZeroRegisterWrite(Instruction),
+
+    /// The data directives lay out more than [`DATA_SEGMENT_MAX_SIZE`] bytes. Holds the size the
+    /// data segment would have.
+    DataSegmentTooLarge(u64),
+}
+
+impl Display for AssemblyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssemblyError::Operand(err) => write!(f, "{err}"),
+            AssemblyError::UnknownLabel(label_name) => write!(f, "unknown label `{label_name}`"),
+            AssemblyError::UnexpandedPseudoInstruction(pseudo) => {
+                write!(f, "pseudo-instruction `{pseudo}` must be expanded")
+            }
+            AssemblyError::ZeroRegisterWrite(instruction) => {
+                write!(f, "instruction `{instruction}` writes register $zero")
+            }
+            AssemblyError::DataSegmentTooLarge(size) => {
+                let max_size = DATA_SEGMENT_MAX_SIZE;
+                write!(f, "data segment of {size} bytes exceeds {max_size} bytes")
+            }
+        }
+    }
+}
+
+impl Error for AssemblyError {}
+
+impl From<RegParseError> for AssemblyError {
+    fn from(err: RegParseError) -> Self {
+        AssemblyError::Operand(err)
+    }
+}
+
+/// Convert a program with labels to a program with absolute positions. Fails if a register or
+/// an operand is malformed, if a label is not defined, or if an instruction writes register
+/// `$zero`.
+pub fn convert_labels(program: &[LabelledInstruction]) -> Result<Vec<Instruction>, AssemblyError> {
+    let program = expand_pseudo_instructions(program);
+
+    // 1. Add all labels to a map
+    let (label_map, _) = layout(&program)?;
+
+    // 2. Convert every label to the lookup value of that map
+    program
+        .iter()
+        .map(|labelled_instruction| convert_labels_helper(labelled_instruction, &label_map))
+        .flatten_ok()
+        .collect()
+}
+
+/// The absolute address of every label in the program. Labels in the text segment map to
+/// instruction addresses, labels in the data segment to memory addresses.
+pub fn label_map(program: &[LabelledInstruction]) -> Result<HashMap<String, usize>, AssemblyError> {
+    let (label_map, _) = layout(&expand_pseudo_instructions(program))?;
+    Ok(label_map)
+}
+
+/// The initial content of the data segment, as laid out by the program's data directives.
+pub fn data_segment(program: &[LabelledInstruction]) -> Result<DataSegment, AssemblyError> {
+    let (_, data_segment) = layout(&expand_pseudo_instructions(program))?;
+    Ok(data_segment)
+}
+
+/// Assign an absolute address to every label and lay out the data segment. Labels in the data
+/// segment refer to the following datum, including any padding needed for its alignment.
+///
+/// Fails if the program contains pseudo-instructions, see [`expand_pseudo_instructions`], or if
+/// the data segment exceeds [`DATA_SEGMENT_MAX_SIZE`] bytes.
+fn layout(
+    program: &[LabelledInstruction],
+) -> Result<(HashMap<String, usize>, DataSegment), AssemblyError> {
     let mut label_map = HashMap::<String, usize>::new();
     let mut instruction_pointer: usize = 0;
+    let mut data_segment = DataSegment::default();
+    let mut is_data_section = false;
+    let mut pending_data_labels = vec![];
 
-    // 1. Add all labels to a map
     for labelled_instruction in program.iter() {
         match labelled_instruction {
+            LabelledInstruction::Label(label_name) if is_data_section => {
+                pending_data_labels.push(label_name.clone());
+            }
+
             LabelledInstruction::Label(label_name) => {
                 label_map.insert(label_name.clone(), instruction_pointer);
             }
@@ -358,38 +921,69 @@ pub fn convert_labels(program: &[LabelledInstruction]) -> Vec<Instruction> {
             LabelledInstruction::Instruction(instr) => {
                 instruction_pointer += instr.size();
             }
+
+            LabelledInstruction::Pseudo(pseudo) => {
+                return Err(AssemblyError::UnexpandedPseudoInstruction(pseudo.clone()))
+            }
+
+            LabelledInstruction::Directive(directive) => {
+                let address = data_segment.align_for(directive)? as usize;
+                if directive.is_data() || *directive == Directive::Text {
+                    for label_name in pending_data_labels.drain(..) {
+                        label_map.insert(label_name, address);
+                    }
+                }
+                match directive {
+                    Directive::Data => is_data_section = true,
+                    Directive::Text => is_data_section = false,
+                    directive => data_segment.push(directive)?,
+                }
+            }
         }
     }
 
-    // 2. Convert every label to the lookup value of that map
-    program
-        .iter()
-        .flat_map(|labelled_instruction| convert_labels_helper(labelled_instruction, &label_map))
-        .collect()
+    let address = data_segment.address() as usize;
+    for label_name in pending_data_labels {
+        label_map.insert(label_name, address);
+    }
+
+    Ok((label_map, data_segment))
 }
 
 fn convert_labels_helper(
     instruction: &LabelledInstruction,
     label_map: &HashMap<String, usize>,
-) -> Vec<Instruction> {
+) -> Result<Vec<Instruction>, AssemblyError> {
     match instruction {
-        LabelledInstruction::Label(_) => vec![],
+        LabelledInstruction::Label(_) | LabelledInstruction::Directive(_) => Ok(vec![]),
+
+        LabelledInstruction::Pseudo(pseudo) => {
+            Err(AssemblyError::UnexpandedPseudoInstruction(pseudo.clone()))
+        }
 
         LabelledInstruction::Instruction(instr) => {
             let unlabelled_instruction: AnInstruction<BFieldElement, Reg, RegA> = instr
                 .map_call_address(|label_name| {
-                    let label_not_found = format!("Label not found: {label_name}");
-                    let absolute_address = label_map.get(label_name).expect(&label_not_found);
-                    BFieldElement::new(*absolute_address as u64)
-                });
+                    if let Some(&absolute_address) = label_map.get(label_name) {
+                        return Ok(BFieldElement::new(absolute_address as u64));
+                    }
+                    // `la` also accepts an absolute address
+                    match parse_immediate(label_name) {
+                        Ok(absolute_address) => Ok(absolute_address.into()),
+                        Err(_) => Err(AssemblyError::UnknownLabel(label_name.to_string())),
+                    }
+                })?;
+            if unlabelled_instruction.writes_zero_register() {
+                return Err(AssemblyError::ZeroRegisterWrite(unlabelled_instruction));
+            }
 
-            vec![unlabelled_instruction]
+            Ok(vec![unlabelled_instruction])
         }
     }
 }
 
 const DEFAULT_BRANCH_INFO: (Reg, Reg, BFieldElement) = (Reg::Zero, Reg::Zero, BFIELD_ZERO);
-const DEFAULT_LOAD_SAVE: (Reg, RegA, Reg) = (Reg::Zero, RegA::RegName(Reg::Zero), Reg::Zero);
+const DEFAULT_LOAD_SAVE: (Reg, RegA, Reg) = (Reg::Zero, RegA::Imm(0), Reg::Zero);
 const DEFAULT_INFO3: (Reg, Reg, RegA) = (Reg::Zero, Reg::Zero, RegA::Imm(0));
 const DEFAULT_INFO2: (Reg, RegA) = (Reg::Zero, RegA::Imm(0));
 const DEFAULT_REG_R: Reg = Reg::Zero;
@@ -402,18 +996,26 @@ const fn all_instructions_without_args(
         BLT(DEFAULT_BRANCH_INFO),
         BLE(DEFAULT_BRANCH_INFO),
         BGT(DEFAULT_BRANCH_INFO),
+        BLTU(DEFAULT_BRANCH_INFO),
+        BGEU(DEFAULT_BRANCH_INFO),
         SEQ(DEFAULT_INFO3),
         SNE(DEFAULT_INFO3),
         SLT(DEFAULT_INFO3),
+        SLTU(DEFAULT_INFO3),
         SLE(DEFAULT_INFO3),
         J(BFIELD_ZERO),
         JR(DEFAULT_REG_R),
+        JAL(BFIELD_ZERO),
+        JALR(DEFAULT_REG_R),
         LW(DEFAULT_LOAD_SAVE),
         SW(DEFAULT_LOAD_SAVE),
         ADD(DEFAULT_INFO3),
+        ADDU(DEFAULT_INFO3),
         SUB(DEFAULT_INFO3),
+        SUBU(DEFAULT_INFO3),
         MULT(DEFAULT_INFO3),
         DIV(DEFAULT_INFO3),
+        DIVU(DEFAULT_INFO3),
         MOD(DEFAULT_INFO3),
         MOVE(DEFAULT_INFO2),
         LA((Reg::Zero, BFIELD_ZERO)),
@@ -423,6 +1025,7 @@ const fn all_instructions_without_args(
         NOT(DEFAULT_INFO3),
         SLL(DEFAULT_INFO3),
         SRL(DEFAULT_INFO3),
+        SRA(DEFAULT_INFO3),
         PUBREAD(DEFAULT_REG_R),
         SECREAD(DEFAULT_REG_R),
         PUBSEEK(DEFAULT_INFO2),
@@ -430,6 +1033,7 @@ const fn all_instructions_without_args(
         PRINT(DEFAULT_REG_R),
         EXIT(DEFAULT_REG_R),
         ANSWER(DEFAULT_REG_R),
+        SYSCALL,
     ]
 }
 
@@ -444,19 +1048,21 @@ const fn all_instruction_names() -> [&'static str; Instruction::COUNT] {
 }
 
 pub mod sample_programs {
-    pub const SPECK128: &str = "
-        move $t4, 32
+    /// SPECK64/128: reads one block `(y, x)` followed by the 27 round keys from the secret input
+    /// and prints the encrypted block.
+    pub const SPECK64: &str = "
+        move $t4, 27
         secread $t2
         secread $t3
         __L1__:
             srl $t5, $t3, 8
-            sll $t6, $t3, 56
+            sll $t6, $t3, 24
             or $t6, $t5, $t6
-            add $t3, $t6, $t2
+            addu $t3, $t6, $t2
             secread $t7
 
             xor $t3, $t3, $t7
-            srl $t5, $t2, 61
+            srl $t5, $t2, 29
             sll $t6, $t2, 3
             or $t6, $t5, $t6
             xor $t2, $t6, $t3
@@ -478,9 +1084,25 @@ mod instruction_tests {
     // use strum::IntoEnumIterator;
     // use twenty_first::shared_math::b_field_element::BFieldElement;
 
+    use itertools::Itertools;
+    use twenty_first::shared_math::b_field_element::BFieldElement;
+
     use crate::instruction::ALL_INSTRUCTIONS;
     use crate::program::Program;
 
+    use super::AnInstruction::ADD;
+    use super::AnInstruction::ADDU;
+    use super::AnInstruction::J;
+    use super::AnInstruction::LA;
+    use super::AnInstruction::SLL;
+    use super::AssemblyError;
+    use super::Instruction;
+    use super::LabelledInstruction;
+    use super::Reg;
+    use super::RegA;
+    use super::RegParseError;
+    use super::RS_SHIFT;
+
     // use super::AnInstruction;
     // use super::AnInstruction::*;
 
@@ -601,4 +1223,172 @@ mod instruction_tests {
             println!("{:>3} {: <10}", instr.opcode(), format!("{}", instr.name()));
         }
     }
+
+    const ALL_INSTRUCTIONS_PROGRAM: &str = "
+        .data
+        msg: .asciiz \"hi\"
+        .text
+        start:
+            beq $t0, $t1, start     bne $t0, $t1, end       blt $t0, $t1, start
+            ble $t0, $t1, start     bgt $t0, $t1, start     seq $t0, $t1, 5
+            sne $t0, $t1, $t2       slt $t0, $t1, -5        sltu $t0, $t1, 5
+            sle $t0, $t1, $t2       j start                 jr $ra
+            jal start               jalr $t0                lw $t0, -4($sp)
+            sw $t0, 8($sp)          add $t0, $t1, $t2       addu $t0, $t1, 1
+            sub $t0, $t1, $t2       subu $t0, $t1, 1        mult $t0, $t1, $t2
+            div $t0, $t1, 3         divu $t0, $t1, 3        mod $t0, $t1, $t2
+            move $t0, 0xffffffff    la $t0, msg             la $t1, start
+            and $t0, $t1, 0xff      xor $t0, $t1, $t2       or $t0, $t1, $t2
+            not $t0, $t1, $t2       sll $t0, $t1, 3         srl $t0, $t1, $t2
+            sra $t0, $t1, 31        pubread $t0             secread $t0
+            pubseek $t0, 3          secseek $t0, $t1        print $t0
+            bltu $t0, $t1, start    bgeu $t0, $t1, end      exit $zero
+            answer $t0              syscall
+        end:
+    ";
+
+    #[test]
+    fn display_round_trip_test() {
+        let code = "
+            add $sp, $sp, -8        lw $t0, -4($fp)         sw $t0, -2147483648($sp)
+            slt $t0, $t1, -5        subu $t0, $t1, -1       pubseek $t0, -3
+            and $t0, $t1, -16       sltu $t0, $t1, 0xffffffff
+            exit $zero
+        ";
+        let program = Program::from_code(code).unwrap();
+        let displayed = program.instructions.iter().map(|instr| instr.to_string());
+        let expected = [
+            "add $sp, $sp, -8",
+            "lw $t0, -4($fp)",
+            "sw $t0, -2147483648($sp)",
+            "slt $t0, $t1, -5",
+            "subu $t0, $t1, -1",
+            "pubseek $t0, -3",
+            "and $t0, $t1, 4294967280",
+            "sltu $t0, $t1, 4294967295",
+            "exit $zero",
+        ];
+        assert_eq!(expected.to_vec(), displayed.collect_vec());
+
+        let displayed_code = program.instructions.iter().join("\n");
+        let reparsed = Program::from_code(&displayed_code).unwrap();
+        assert_eq!(program.instructions, reparsed.instructions);
+    }
+
+    #[test]
+    fn instruction_word_round_trip_test() {
+        let program = Program::from_code(ALL_INSTRUCTIONS_PROGRAM).unwrap();
+        let opcodes = program.instructions.iter().map(|instr| instr.opcode());
+        assert_eq!(ALL_INSTRUCTIONS.len(), opcodes.unique().count());
+
+        for instr in program.instructions.iter() {
+            assert_eq!(*instr, Instruction::from_word(instr.word()).unwrap());
+        }
+
+        let decoded = Program::from_bwords(&program.to_bwords()).unwrap();
+        assert_eq!(program.instructions, decoded.instructions);
+        assert!(decoded.data.is_empty());
+    }
+
+    #[test]
+    fn malformed_words_are_rejected_test() {
+        let word = |w: u64| BFieldElement::new(w);
+        let jump = J(BFieldElement::new(3)).word().value();
+
+        assert!(Instruction::from_word(word(255)).is_err(), "unknown opcode");
+        assert!(
+            Instruction::from_word(word(jump + (1 << 56))).is_err(),
+            "immediate too big"
+        );
+        assert!(
+            Instruction::from_word(word(jump + (1 << RS_SHIFT))).is_err(),
+            "unused field is not 0"
+        );
+
+        let out_of_bounds_jump = [word(jump)];
+        assert!(Program::from_bwords(&out_of_bounds_jump).is_err());
+
+        let zero_register_write = ADD((Reg::Zero, Reg::T0, RegA::Imm(1)));
+        assert!(
+            Instruction::from_word(zero_register_write.word()).is_err(),
+            "writes register $zero"
+        );
+        let nop = SLL((Reg::Zero, Reg::Zero, RegA::Imm(0)));
+        assert_eq!(nop, Instruction::from_word(nop.word()).unwrap());
+    }
+
+    #[test]
+    fn writes_to_zero_register_are_errors_test() {
+        let zero_register_write = [LabelledInstruction::Instruction(ADDU((
+            "$zero".to_string(),
+            "$t0".to_string(),
+            "1".to_string(),
+        )))];
+        assert_eq!(
+            Err(AssemblyError::ZeroRegisterWrite(ADDU((
+                Reg::Zero,
+                Reg::T0,
+                RegA::Imm(1)
+            )))),
+            Program::new(&zero_register_write)
+        );
+    }
+
+    #[test]
+    fn disassembled_program_reassembles_test() {
+        let program = Program::from_code(ALL_INSTRUCTIONS_PROGRAM).unwrap();
+        let source = program.disassemble();
+        let reassembled = Program::from_code(&source).unwrap();
+        assert_eq!(program, reassembled, "disassembly:\n{source}");
+        assert!(source.contains("j __L0__"));
+        assert!(source.contains("bne $t0, $t1, __L44__"));
+    }
+
+    #[test]
+    fn malformed_operands_of_labelled_instructions_are_errors_test() {
+        let operands =
+            |rd: &str, rs: &str, a: &str| (rd.to_string(), rs.to_string(), a.to_string());
+        let unknown_register = [LabelledInstruction::Instruction(ADD(operands(
+            "$t0", "$t10", "1",
+        )))];
+        assert_eq!(
+            Err(AssemblyError::Operand(RegParseError::UnknownRegister(
+                "$t10".to_string()
+            ))),
+            Program::new(&unknown_register)
+        );
+
+        let invalid_immediate = [LabelledInstruction::Instruction(ADD(operands(
+            "$t0",
+            "$t1",
+            "0x1_0000_0000",
+        )))];
+        assert_eq!(
+            Err(AssemblyError::Operand(RegParseError::InvalidImmediate(
+                "0x1_0000_0000".to_string()
+            ))),
+            Program::new(&invalid_immediate)
+        );
+    }
+
+    #[test]
+    fn undefined_labels_are_errors_test() {
+        let dangling_jump = [
+            LabelledInstruction::Label("start".to_string()),
+            LabelledInstruction::Instruction(J("end".to_string())),
+        ];
+        assert_eq!(
+            Err(AssemblyError::UnknownLabel("end".to_string())),
+            Program::new(&dangling_jump)
+        );
+
+        let dangling_load = [LabelledInstruction::Instruction(LA((
+            "$t0".to_string(),
+            "table".to_string(),
+        )))];
+        assert_eq!(
+            Err(AssemblyError::UnknownLabel("table".to_string())),
+            Program::new(&dangling_load)
+        );
+    }
 }
@@ -1,5 +1,8 @@
+pub mod data_segment;
+pub mod elf;
 pub mod instruction;
 pub mod parser;
 pub mod program;
+pub mod pseudo_instruction;
 pub mod regs;
 pub mod shortcuts;
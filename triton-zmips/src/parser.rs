@@ -16,13 +16,29 @@ use nom::error::VerboseError;
 use nom::error::VerboseErrorKind;
 use nom::multi::many0;
 use nom::multi::many1;
+use nom::multi::separated_list1;
 use nom::Finish;
 use nom::IResult;
+use triton_program::line_column;
+use triton_program::SourceLocation;
+use triton_program::SourceMap;
 
+use crate::data_segment::Directive;
 use crate::instruction::AnInstruction;
 use crate::instruction::AnInstruction::*;
 use crate::instruction::LabelledInstruction;
 use crate::instruction::ALL_INSTRUCTION_NAMES;
+use crate::pseudo_instruction::PseudoInstruction;
+use crate::pseudo_instruction::ALL_PSEUDO_INSTRUCTION_NAMES;
+use crate::regs::closest_register_name;
+use crate::regs::edit_distance;
+use crate::regs::parse_immediate;
+use crate::regs::Reg;
+use crate::regs::RegA;
+use crate::regs::RegParseError;
+
+const UNKNOWN_REGISTER: &str = "unknown register";
+const EXPECTING_INSTRUCTION: &str = "expecting label, instruction or eof";
 
 #[derive(Debug, PartialEq)]
 pub struct ParseError<'a> {
@@ -34,14 +50,18 @@ pub struct ParseError<'a> {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ParsedInstruction<'a> {
     Instruction(AnInstruction<String, String, String>, &'a str),
+    Pseudo(PseudoInstruction, &'a str),
     Label(String, &'a str),
+    Directive(Directive, &'a str),
 }
 
 impl<'a> std::fmt::Display for ParsedInstruction<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ParsedInstruction::Instruction(instr, _) => write!(f, "{instr}"),
+            ParsedInstruction::Pseudo(pseudo, _) => write!(f, "{pseudo}"),
             ParsedInstruction::Label(label_name, _) => write!(f, "{label_name}:"),
+            ParsedInstruction::Directive(directive, _) => write!(f, "{directive}"),
         }
     }
 }
@@ -54,11 +74,53 @@ impl<'a> std::fmt::Display for ParseError<'a> {
 
 impl<'a> Error for ParseError<'a> {}
 
+impl<'a> ParseError<'a> {
+    /// The line and column, both starting at 1, of the first error.
+    pub fn line_column(&self) -> Option<(usize, usize)> {
+        self.errors
+            .errors
+            .first()
+            .map(|&(substring, _)| line_column(self.input, substring))
+    }
+}
+
+/// The token at the start of `s`, as shown in error messages.
+fn offending_token(s: &str) -> &str {
+    let is_separator = |c: char| c.is_whitespace() || matches!(c, ',' | '(' | ')');
+    let token = s.split(is_separator).next().unwrap_or_default();
+    if token.is_empty() {
+        return s.chars().next().map_or("", |c| &s[..c.len_utf8()]);
+    }
+    token
+}
+
+/// A suggestion to fix the offending token, if it is close to a known name.
+fn suggestion(context: &str, token: &str) -> Option<String> {
+    match context {
+        UNKNOWN_REGISTER => closest_register_name(token),
+        EXPECTING_INSTRUCTION => closest_instruction_name(token).map(|name| name.to_string()),
+        _ => None,
+    }
+}
+
+fn closest_instruction_name(name: &str) -> Option<&'static str> {
+    let max_distance = 2;
+    ALL_INSTRUCTION_NAMES
+        .iter()
+        .chain(ALL_PSEUDO_INSTRUCTION_NAMES.iter())
+        .map(|&instruction_name| (edit_distance(name, instruction_name), instruction_name))
+        .filter(|&(distance, _)| 0 < distance && distance <= max_distance)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, instruction_name)| instruction_name)
+}
+
 impl<'a> ParsedInstruction<'a> {
     pub fn token_str(&self) -> &'a str {
         match self {
             ParsedInstruction::Instruction(_, token_str) => token_str,
+            ParsedInstruction::Pseudo(_, token_str) => token_str,
             ParsedInstruction::Label(_, token_str) => token_str,
+            ParsedInstruction::Directive(_, token_str) => token_str,
         }
     }
 
@@ -66,7 +128,9 @@ impl<'a> ParsedInstruction<'a> {
         use ParsedInstruction::*;
         match self {
             Instruction(instr, _) => LabelledInstruction::Instruction(instr.to_owned()),
+            Pseudo(pseudo, _) => LabelledInstruction::Pseudo(pseudo.to_owned()),
             Label(label, _) => LabelledInstruction::Label(label.to_owned()),
+            Directive(directive, _) => LabelledInstruction::Directive(directive.to_owned()),
         }
     }
 }
@@ -78,9 +142,53 @@ pub fn to_labelled(instructions: &[ParsedInstruction]) -> Vec<LabelledInstructio
         .collect()
 }
 
+/// The [`SourceMap`] of the program parsed from `input`. Every instruction a pseudo-instruction
+/// expands to is mapped to the pseudo-instruction's location. Labels in the data section don't
+/// enclose any instructions.
+pub fn source_map(input: &str, instructions: &[ParsedInstruction]) -> SourceMap {
+    let mut source_map = SourceMap::default();
+    let mut address = 0;
+    let mut label = None;
+    let mut is_data_section = false;
+    for instruction in instructions {
+        let size = match instruction {
+            ParsedInstruction::Instruction(instr, _) => instr.size(),
+            ParsedInstruction::Pseudo(pseudo, _) => pseudo.expand().len(),
+            ParsedInstruction::Label(label_name, _) => {
+                if !is_data_section {
+                    label = Some(label_name.to_owned());
+                }
+                continue;
+            }
+            ParsedInstruction::Directive(directive, _) => {
+                match directive {
+                    Directive::Data => is_data_section = true,
+                    Directive::Text => is_data_section = false,
+                    _ => (),
+                }
+                continue;
+            }
+        };
+        let (line, column) = line_column(input, instruction.token_str());
+        let location = SourceLocation {
+            file: None,
+            line,
+            column,
+            label: label.clone(),
+        };
+        for _ in 0..size {
+            source_map.insert(address, location.clone());
+            address += 1;
+        }
+    }
+    source_map
+}
+
 /// Pretty-print a parse error
 ///
-/// This function wraps `convert_error()`.
+/// Every error is summarized by its line, column, offending token and, if the token is close to
+/// a known register or instruction name, a suggestion. The summary is followed by the output of
+/// `convert_error()`, which shows the errors in the context of their lines.
 ///
 /// `VerboseError` accumulates each nested contexts in which an error occurs.
 ///
@@ -94,7 +202,25 @@ pub fn pretty_print_error(s: &str, mut e: VerboseError<&str>) -> String {
     {
         e.errors.remove(0);
     }
-    convert_error(s, e)
+
+    let mut summary = String::new();
+    for (substring, kind) in e.errors.iter() {
+        let context = match kind {
+            VerboseErrorKind::Context(context) => context,
+            _ => continue,
+        };
+        let (line, column) = line_column(s, substring);
+        let token = offending_token(substring);
+        summary.push_str(&format!("line {line}, column {column}: {context}"));
+        if !token.is_empty() {
+            summary.push_str(&format!(" `{token}`"));
+        }
+        if let Some(suggestion) = suggestion(context, token) {
+            summary.push_str(&format!(", did you mean `{suggestion}`?"));
+        }
+        summary.push('\n');
+    }
+    summary + &convert_error(s, e)
 }
 
 /// Parse a program
@@ -105,6 +231,8 @@ pub fn parse(input: &str) -> Result<Vec<ParsedInstruction>, ParseError> {
     }?;
 
     scan_missing_duplicate_labels(input, &instructions)?;
+    scan_misplaced_instructions(input, &instructions)?;
+    scan_zero_register_writes(input, &instructions)?;
 
     Ok(instructions)
 }
@@ -138,10 +266,19 @@ fn scan_missing_duplicate_labels<'a>(
                 BLT(info) => Some((&info.2, instruction.to_owned())),
                 BLE(info) => Some((&info.2, instruction.to_owned())),
                 BGT(info) => Some((&info.2, instruction.to_owned())),
-                J(addr) => Some((addr, instruction.to_owned())),
+                BLTU(info) => Some((&info.2, instruction.to_owned())),
+                BGEU(info) => Some((&info.2, instruction.to_owned())),
+                J(addr) | JAL(addr) => Some((addr, instruction.to_owned())),
+                LA((_, addr)) if !is_address_literal(addr) => Some((addr, instruction.to_owned())),
                 _ => None,
             },
-            ParsedInstruction::Label(_, _) => None,
+            ParsedInstruction::Pseudo(pseudo, _) => {
+                pseudo.label().map(|addr| (addr, instruction.to_owned()))
+            }
+            ParsedInstruction::Directive(Directive::Globl(label), _) => {
+                Some((label, instruction.to_owned()))
+            }
+            ParsedInstruction::Label(_, _) | ParsedInstruction::Directive(_, _) => None,
         };
         if let Some((addr, instruction)) = jump_ins {
             if !seen.contains_key(addr.as_str()) {
@@ -178,14 +315,91 @@ fn scan_missing_duplicate_labels<'a>(
     Err(ParseError { input, errors })
 }
 
+/// Instructions belong to the text segment, which is the default. Data belongs to the data
+/// segment, which starts with directive `.data`.
+fn scan_misplaced_instructions<'a>(
+    input: &'a str,
+    instructions: &[ParsedInstruction<'a>],
+) -> Result<(), ParseError<'a>> {
+    let mut is_data_section = false;
+    let mut errors: Vec<(&str, VerboseErrorKind)> = vec![];
+    for instruction in instructions.iter() {
+        match instruction {
+            ParsedInstruction::Directive(Directive::Data, _) => is_data_section = true,
+            ParsedInstruction::Directive(Directive::Text, _) => is_data_section = false,
+            ParsedInstruction::Directive(directive, token_s)
+                if directive.is_data() && !is_data_section =>
+            {
+                let error = VerboseErrorKind::Context("data directive outside data section");
+                errors.push((token_s, error));
+            }
+            ParsedInstruction::Instruction(_, token_s) | ParsedInstruction::Pseudo(_, token_s)
+                if is_data_section =>
+            {
+                let error = VerboseErrorKind::Context("instruction inside data section");
+                errors.push((token_s, error));
+            }
+            _ => (),
+        }
+    }
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    let errors = VerboseError { errors };
+    Err(ParseError { input, errors })
+}
+
+/// Like in MIPS, register `$zero` is hardwired to 0 in zMIPS. However, the zMIPS Processor Table
+/// requires the destination register to hold the instruction's result, so no instruction may
+/// write to `$zero`. The only exception is `nop`, which is `sll $zero, $zero, 0` and leaves
+/// `$zero` unchanged. Pseudo-instructions are checked by their expansion. See also
+/// [`Instruction::writes_zero_register`](crate::instruction::Instruction::writes_zero_register).
+fn scan_zero_register_writes<'a>(
+    input: &'a str,
+    instructions: &[ParsedInstruction<'a>],
+) -> Result<(), ParseError<'a>> {
+    let is_zero = |register: &String| matches!(register.parse::<Reg>(), Ok(Reg::Zero));
+    let writes_zero = |instruction: &AnInstruction<String, String, String>| {
+        let is_nop = matches!(instruction, SLL((_, rs, shamt)) if is_zero(rs) && shamt == "0");
+        !is_nop && matches!(instruction.destination(), Some(rd) if is_zero(rd))
+    };
+
+    let mut errors: Vec<(&str, VerboseErrorKind)> = vec![];
+    for instruction in instructions.iter() {
+        let (expansion, token_s) = match instruction {
+            ParsedInstruction::Instruction(instr, token_s) => (vec![instr.to_owned()], token_s),
+            ParsedInstruction::Pseudo(pseudo, token_s) => (pseudo.expand(), token_s),
+            _ => continue,
+        };
+        if expansion.iter().any(writes_zero) {
+            let error = VerboseErrorKind::Context("instruction writes register $zero");
+            errors.push((token_s, error));
+        }
+    }
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    let errors = VerboseError { errors };
+    Err(ParseError { input, errors })
+}
+
 /// Auxiliary type alias: `IResult` defaults to `nom::error::Error` as concrete
 /// error type, but we want `nom::error::VerboseError` as it allows `context()`.
 type ParseResult<'input, Out> = IResult<&'input str, Out, VerboseError<&'input str>>;
 
 fn program(s: &str) -> ParseResult<Vec<ParsedInstruction>> {
     let (s, _) = comment_or_whitespace0(s)?;
-    let (s, instructions) = many0(alt((label, labelled_instruction)))(s)?;
-    let (s, _) = context("expecting label, instruction or eof", eof)(s)?;
+    let (s, instructions) = many0(alt((
+        label,
+        directive,
+        labelled_instruction,
+        pseudo_instruction,
+    )))(s)?;
+    let (s, _) = context(EXPECTING_INSTRUCTION, eof)(s)?;
 
     Ok((s, instructions))
 }
@@ -195,6 +409,135 @@ fn labelled_instruction(s_instr: &str) -> ParseResult<ParsedInstruction> {
     Ok((s, ParsedInstruction::Instruction(instr, s_instr)))
 }
 
+fn pseudo_instruction<'a>(s_instr: &'a str) -> ParseResult<'a, ParsedInstruction<'a>> {
+    let li = instruction2("li", |r, imm| {
+        PseudoInstruction::LI((r.to_string(), imm.to_string()))
+    });
+    let nop = |s: &'a str| -> ParseResult<PseudoInstruction> {
+        let (s, _) = token1("nop")(s)?;
+        Ok((s, PseudoInstruction::NOP))
+    };
+    let neg = instruction2("neg", |r1, r2| {
+        PseudoInstruction::NEG((r1.to_string(), r2.to_string()))
+    });
+    let mul = instruction3("mul", |r1, r2, a| {
+        PseudoInstruction::MUL((r1.to_string(), r2.to_string(), a.to_string()))
+    });
+    let subi = instruction3("subi", |r1, r2, imm| {
+        PseudoInstruction::SUBI((r1.to_string(), r2.to_string(), imm.to_string()))
+    });
+    let push = instruction1("push", |r| PseudoInstruction::PUSH(r.to_string()));
+    let pop = instruction1("pop", |r| PseudoInstruction::POP(r.to_string()));
+
+    let b = jump_instruction("b", PseudoInstruction::B);
+    let beqz = instruction_reg_label("beqz", |r, addr| {
+        PseudoInstruction::BEQZ((r.to_string(), addr))
+    });
+    let bnez = instruction_reg_label("bnez", |r, addr| {
+        PseudoInstruction::BNEZ((r.to_string(), addr))
+    });
+    let bltz = instruction_reg_label("bltz", |r, addr| {
+        PseudoInstruction::BLTZ((r.to_string(), addr))
+    });
+    let bge = branch_instruction("bge", PseudoInstruction::BGE);
+
+    let (s, pseudo) = alt((li, nop, neg, mul, subi, push, pop, b, beqz, bnez, bltz, bge))(s_instr)?;
+    Ok((s, ParsedInstruction::Pseudo(pseudo, s_instr)))
+}
+
+fn directive<'a>(s_directive: &'a str) -> ParseResult<'a, ParsedInstruction<'a>> {
+    let data = |s: &'a str| -> ParseResult<Directive> {
+        let (s, _) = token1(".data")(s)?;
+        Ok((s, Directive::Data))
+    };
+
+    let text = |s: &'a str| -> ParseResult<Directive> {
+        let (s, _) = token1(".text")(s)?;
+        Ok((s, Directive::Text))
+    };
+
+    let word = |s: &'a str| -> ParseResult<Directive> {
+        let (s, _) = token1(".word")(s)?;
+        let (s, words) = separated_list1(token0(","), word_value)(s)?;
+        let (s, _) = comment_or_whitespace1(s)?;
+        Ok((s, Directive::Word(words)))
+    };
+
+    let space = |s: &'a str| -> ParseResult<Directive> {
+        let (s, _) = token1(".space")(s)?;
+        let (s, num_bytes) = unsigned_value(s)?;
+        let (s, _) = comment_or_whitespace1(s)?;
+        Ok((s, Directive::Space(num_bytes)))
+    };
+
+    let asciiz = |s: &'a str| -> ParseResult<Directive> {
+        let (s, _) = token1(".asciiz")(s)?;
+        let (s, string) = string_literal(s)?;
+        let (s, _) = comment_or_whitespace1(s)?;
+        Ok((s, Directive::Asciiz(string)))
+    };
+
+    let align = |s_orig: &'a str| -> ParseResult<Directive> {
+        let (s, _) = token1(".align")(s_orig)?;
+        let (s, n) = unsigned_value(s)?;
+        if n >= u32::BITS {
+            return cut(context("alignment exponent must be smaller than 32", fail))(s_orig);
+        }
+        let (s, _) = comment_or_whitespace1(s)?;
+        Ok((s, Directive::Align(n)))
+    };
+
+    let globl = |s: &'a str| -> ParseResult<Directive> {
+        let (s, _) = token1(".globl")(s)?;
+        let (s, label) = label_addr(s)?;
+        let (s, _) = comment_or_whitespace1(s)?;
+        Ok((s, Directive::Globl(label)))
+    };
+
+    let (s, directive) = alt((data, text, word, space, asciiz, align, globl))(s_directive)?;
+    Ok((s, ParsedInstruction::Directive(directive, s_directive)))
+}
+
+/// A 32-bit word, given like an immediate, see [`parse_immediate`].
+fn word_value(s_orig: &str) -> ParseResult<u32> {
+    let (s, value) = is_not(" \t\r\n,")(s_orig)?;
+    match parse_immediate(value) {
+        Ok(word) => Ok((s, word)),
+        Err(_) => cut(context("word must be a 32-bit integer", fail))(s_orig),
+    }
+}
+
+fn unsigned_value(s_orig: &str) -> ParseResult<u32> {
+    let (s, value) = is_not(" \t\r\n,")(s_orig)?;
+    match value.parse::<u32>() {
+        Ok(value) => Ok((s, value)),
+        Err(_) => cut(context("expecting an unsigned 32-bit integer", fail))(s_orig),
+    }
+}
+
+/// A string in double quotes. Supports escape sequences `\n`, `\t`, `\0`, `\\`, and `\"`.
+fn string_literal(s_orig: &str) -> ParseResult<String> {
+    let (s, _) = tag("\"")(s_orig)?;
+    let mut string = String::new();
+    let mut chars = s.char_indices();
+    while let Some((idx, c)) = chars.next() {
+        let unescaped = match c {
+            '"' => return Ok((&s[idx + 1..], string)),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => '\n',
+                Some((_, 't')) => '\t',
+                Some((_, '0')) => '\0',
+                Some((_, '\\')) => '\\',
+                Some((_, '"')) => '"',
+                _ => return cut(context("unknown escape sequence", fail))(&s[idx..]),
+            },
+            c => c,
+        };
+        string.push(unescaped);
+    }
+    cut(context("unterminated string", fail))(s_orig)
+}
+
 fn label(label_s: &str) -> ParseResult<ParsedInstruction> {
     let (s, addr) = label_addr(label_s)?;
     let (s, _) = token0(":")(s)?; // don't require space after ':'
@@ -211,12 +554,15 @@ fn label(label_s: &str) -> ParseResult<ParsedInstruction> {
 
 fn an_instruction(s: &str) -> ParseResult<AnInstruction<String, String, String>> {
     // Control flow
-    let beq = branch_instruction("beq", BEQ(Default::default()));
-    let bne = branch_instruction("bne", BNE(Default::default()));
-    let blt = branch_instruction("blt", BLT(Default::default()));
-    let ble = branch_instruction("ble", BLE(Default::default()));
-    let bgt = branch_instruction("bgt", BGT(Default::default()));
-    let j = jump_instruction();
+    let beq = branch_instruction("beq", BEQ);
+    let bne = branch_instruction("bne", BNE);
+    let blt = branch_instruction("blt", BLT);
+    let ble = branch_instruction("ble", BLE);
+    let bgt = branch_instruction("bgt", BGT);
+    let bltu = branch_instruction("bltu", BLTU);
+    let bgeu = branch_instruction("bgeu", BGEU);
+    let j = jump_instruction("j", J);
+    let jal = jump_instruction("jal", JAL);
     let seq = instruction3("seq", |r1, r2, a| {
         SEQ((r1.to_string(), r2.to_string(), a.to_string()))
     });
@@ -226,12 +572,18 @@ fn an_instruction(s: &str) -> ParseResult<AnInstruction<String, String, String>>
     let slt = instruction3("slt", |r1, r2, a| {
         SLT((r1.to_string(), r2.to_string(), a.to_string()))
     });
+    let sltu = instruction3("sltu", |r1, r2, a| {
+        SLTU((r1.to_string(), r2.to_string(), a.to_string()))
+    });
     let sle = instruction3("sle", |r1, r2, a| {
         SLE((r1.to_string(), r2.to_string(), a.to_string()))
     });
     let jr = instruction1("jr", |r| JR(r.to_string()));
+    let jalr = instruction1("jalr", |r| JALR(r.to_string()));
 
-    let control_flow = alt((beq, bne, blt, ble, bgt, j, seq, sne, slt, sle, jr));
+    let control_flow = alt((
+        beq, bne, blt, ble, bgt, bltu, bgeu, j, jal, seq, sne, slt, sltu, sle, jr, jalr,
+    ));
 
     // Memory access
     let lw = instruction_load_save("lw", |r1, a, r2| {
@@ -243,24 +595,33 @@ fn an_instruction(s: &str) -> ParseResult<AnInstruction<String, String, String>>
 
     let memory_access = alt((lw, sw));
 
-    // Arithmetic on stack instructions
+    // Arithmetic instructions
     let add = instruction3("add", |r1, r2, a| {
         ADD((r1.to_string(), r2.to_string(), a.to_string()))
     });
+    let addu = instruction3("addu", |r1, r2, a| {
+        ADDU((r1.to_string(), r2.to_string(), a.to_string()))
+    });
     let sub = instruction3("sub", |r1, r2, a| {
         SUB((r1.to_string(), r2.to_string(), a.to_string()))
     });
+    let subu = instruction3("subu", |r1, r2, a| {
+        SUBU((r1.to_string(), r2.to_string(), a.to_string()))
+    });
     let mult = instruction3("mult", |r1, r2, a| {
         MULT((r1.to_string(), r2.to_string(), a.to_string()))
     });
     let div = instruction3("div", |r1, r2, a| {
         DIV((r1.to_string(), r2.to_string(), a.to_string()))
     });
+    let divu = instruction3("divu", |r1, r2, a| {
+        DIVU((r1.to_string(), r2.to_string(), a.to_string()))
+    });
     let mod_ = instruction3("mod", |r1, r2, a| {
         MOD((r1.to_string(), r2.to_string(), a.to_string()))
     });
     let move_ = instruction2("move", |r1, a| MOVE((r1.to_string(), a.to_string())));
-    let la = instruction2("la", |r1, a| LA((r1.to_string(), a.to_string())));
+    let la = load_address_instruction();
     let and = instruction3("and", |r1, r2, a| {
         AND((r1.to_string(), r2.to_string(), a.to_string()))
     });
@@ -279,10 +640,13 @@ fn an_instruction(s: &str) -> ParseResult<AnInstruction<String, String, String>>
     let srl = instruction3("srl", |r1, r2, a| {
         SRL((r1.to_string(), r2.to_string(), a.to_string()))
     });
+    let sra = instruction3("sra", |r1, r2, a| {
+        SRA((r1.to_string(), r2.to_string(), a.to_string()))
+    });
 
-    let base_field_arithmetic_on_stack = alt((add, sub, mult, div, mod_, move_, la));
-    let bitwise_arithmetic_on_stack = alt((and, xor, or, not, sll, srl));
-    let arithmetic_on_stack = alt((base_field_arithmetic_on_stack, bitwise_arithmetic_on_stack));
+    let integer_arithmetic = alt((add, addu, sub, subu, mult, div, divu, mod_, move_, la));
+    let bitwise_arithmetic = alt((and, xor, or, not, sll, srl, sra));
+    let arithmetic = alt((integer_arithmetic, bitwise_arithmetic));
 
     // Read/write
     let pub_read = instruction1("pubread", |r| PUBREAD(r.to_string()));
@@ -292,8 +656,11 @@ fn an_instruction(s: &str) -> ParseResult<AnInstruction<String, String, String>>
     let print = instruction1("print", |r| PRINT(r.to_string()));
     let exit = instruction1("exit", |r| EXIT(r.to_string()));
     let answer = instruction1("answer", |r| ANSWER(r.to_string()));
+    let syscall = instruction0("syscall", SYSCALL);
 
-    let read_write = alt((pub_read, sec_read, pub_seek, sec_seek, print, exit, answer));
+    let read_write = alt((
+        pub_read, sec_read, pub_seek, sec_seek, print, exit, answer, syscall,
+    ));
 
     // Because of common prefixes, the following parsers are sensitive to order:
     //
@@ -302,37 +669,31 @@ fn an_instruction(s: &str) -> ParseResult<AnInstruction<String, String, String>>
     // backtracking is necessary.
     // let syntax_ambiguous = alt((assert_vector, assert_, divine));
 
-    alt((control_flow, memory_access, arithmetic_on_stack, read_write))(s)
+    alt((control_flow, memory_access, arithmetic, read_write))(s)
 }
 
 fn is_instruction_name(s: &str) -> bool {
-    ALL_INSTRUCTION_NAMES.contains(&s)
+    ALL_INSTRUCTION_NAMES.contains(&s) || ALL_PSEUDO_INSTRUCTION_NAMES.contains(&s)
 }
 
-fn instruction_load_save<'a, F>(
-    name: &'a str,
-    f: F,
-) -> impl Fn(&'a str) -> ParseResult<AnInstruction<String, String, String>>
+fn instruction_load_save<'a, T, F>(name: &'a str, f: F) -> impl Fn(&'a str) -> ParseResult<T>
 where
-    F: Fn(&str, &str, &str) -> AnInstruction<String, String, String>,
+    F: Fn(&str, &str, &str) -> T,
 {
     move |s: &'a str| {
         let (s, _) = token1(name)(s)?; // require space after instruction name
         let (s, r1) = reg1(s)?;
         let (s, _) = token1(",")(s)?;
-        let (s, a) = immediate_value(s)?;
-        let (s, _) = token1("(")(s)?;
+        let (s, a) = operand(s)?;
+        let (s, _) = token0("(")(s)?;
         let (s, r2) = reg1(s)?;
         let (s, _) = token1(")")(s)?;
         Ok((s, f(r1, a, r2)))
     }
 }
-fn instruction3<'a, F>(
-    name: &'a str,
-    f: F,
-) -> impl Fn(&'a str) -> ParseResult<AnInstruction<String, String, String>>
+fn instruction3<'a, T, F>(name: &'a str, f: F) -> impl Fn(&'a str) -> ParseResult<T>
 where
-    F: Fn(&str, &str, &str) -> AnInstruction<String, String, String>,
+    F: Fn(&str, &str, &str) -> T,
 {
     move |s: &'a str| {
         let (s, _) = token1(name)(s)?; // require space after instruction name
@@ -340,33 +701,27 @@ where
         let (s, _) = token1(",")(s)?;
         let (s, r2) = reg1(s)?;
         let (s, _) = token1(",")(s)?;
-        let (s, a) = immediate_value(s)?;
+        let (s, a) = operand(s)?;
         let (s, _) = comment_or_whitespace1(s)?;
         Ok((s, f(r1, r2, a)))
     }
 }
-fn instruction2<'a, F>(
-    name: &'a str,
-    f: F,
-) -> impl Fn(&'a str) -> ParseResult<AnInstruction<String, String, String>>
+fn instruction2<'a, T, F>(name: &'a str, f: F) -> impl Fn(&'a str) -> ParseResult<T>
 where
-    F: Fn(&str, &str) -> AnInstruction<String, String, String>,
+    F: Fn(&str, &str) -> T,
 {
     move |s: &'a str| {
         let (s, _) = token1(name)(s)?; // require space after instruction name
         let (s, r1) = reg1(s)?;
         let (s, _) = token1(",")(s)?;
-        let (s, a) = immediate_value(s)?;
+        let (s, a) = operand(s)?;
         let (s, _) = comment_or_whitespace1(s)?;
         Ok((s, f(r1, a)))
     }
 }
-fn instruction1<'a, F>(
-    name: &'a str,
-    f: F,
-) -> impl Fn(&'a str) -> ParseResult<AnInstruction<String, String, String>>
+fn instruction1<'a, T, F>(name: &'a str, f: F) -> impl Fn(&'a str) -> ParseResult<T>
 where
-    F: Fn(&str) -> AnInstruction<String, String, String>,
+    F: Fn(&str) -> T,
 {
     move |s: &'a str| {
         let (s, _) = token1(name)(s)?; // require space after instruction name
@@ -375,55 +730,101 @@ where
         Ok((s, f(r)))
     }
 }
+fn instruction0<'a, T>(name: &'a str, instruction: T) -> impl Fn(&'a str) -> ParseResult<T>
+where
+    T: Clone,
+{
+    move |s: &'a str| {
+        let (s, _) = token1(name)(s)?; // also munches whitespace after instruction name
+        Ok((s, instruction.clone()))
+    }
+}
 
 // j __L1__
-fn jump_instruction<'a>() -> impl Fn(&'a str) -> ParseResult<AnInstruction<String, String, String>>
+// jal __L1__
+fn jump_instruction<'a, T, F>(name: &'a str, f: F) -> impl Fn(&'a str) -> ParseResult<T>
+where
+    F: Fn(String) -> T,
 {
     move |s: &'a str| {
-        let (s, _) = token1("j")(s)?; // require space before called label
+        let (s, _) = token1(name)(s)?; // require space before called label
         let (s, addr) = label_addr(s)?;
         let (s, _) = comment_or_whitespace1(s)?; // require space after called label
 
-        Ok((s, J(addr)))
+        Ok((s, f(addr)))
     }
 }
 
 // bgt $t4, $t1, __L1__
-fn branch_instruction<'a>(
-    name: &'a str,
-    instruction: AnInstruction<String, String, String>,
-) -> impl Fn(&'a str) -> ParseResult<AnInstruction<String, String, String>> {
-    let call_syntax = move |s: &'a str| {
+fn branch_instruction<'a, T, F>(name: &'a str, f: F) -> impl Fn(&'a str) -> ParseResult<T>
+where
+    F: Fn((String, String, String)) -> T,
+{
+    move |s: &'a str| {
         let (s, _) = token1(name)(s)?; // require space before called label
         let (s, r1) = reg1(s)?;
         let (s, _) = token1(",")(s)?;
         let (s, r2) = reg1(s)?;
         let (s, _) = token1(",")(s)?;
-        let (s, addr) = label_addr(s)?;
-        let (s, _) = comment_or_whitespace1(s)?; // require space after called label
+        let (s, addr) = label_operand(s)?;
 
-        Ok((s, (r1.to_string(), r2.to_string(), addr)))
-    };
+        Ok((s, f((r1.to_string(), r2.to_string(), addr))))
+    }
+}
 
+// beqz $t4, __L1__
+fn instruction_reg_label<'a, T, F>(name: &'a str, f: F) -> impl Fn(&'a str) -> ParseResult<T>
+where
+    F: Fn(&str, String) -> T,
+{
     move |s: &'a str| {
-        let (s, info) = call_syntax(s)?;
+        let (s, _) = token1(name)(s)?; // require space before called label
+        let (s, r) = reg1(s)?;
+        let (s, _) = token1(",")(s)?;
+        let (s, addr) = label_operand(s)?;
+
+        Ok((s, f(r, addr)))
+    }
+}
 
-        // This check cannot be moved into `label_addr`, since `label_addr` is shared
-        // between the scenarios `<label>:` and `call <label>`; the former requires
-        // parsing the `:` before rejecting a possible instruction name in the label.
-        if is_instruction_name(&info.2) {
-            return cut(context("label cannot be named after instruction", fail))(s);
+// la $t0, __msg__
+// la $t0, 0x10010000
+fn load_address_instruction<'a>(
+) -> impl Fn(&'a str) -> ParseResult<AnInstruction<String, String, String>> {
+    move |s: &'a str| {
+        let (s, _) = token1("la")(s)?; // require space after instruction name
+        let (s, r) = reg1(s)?;
+        let (s, _) = token1(",")(s)?;
+        if !is_address_literal(s) {
+            let (s, addr) = label_operand(s)?;
+            return Ok((s, LA((r.to_string(), addr))));
         }
-        let r = match instruction {
-            BEQ(_) => BEQ(info),
-            BNE(_) => BNE(info),
-            BLT(_) => BLT(info),
-            BLE(_) => BLE(info),
-            BGT(_) => BGT(info),
-            _ => return context("unknown branch instruction", fail)(s),
-        };
-        Ok((s, r))
+        let (s_addr, addr) = is_not(" \t\r\n")(s)?;
+        if parse_immediate(addr).is_err() {
+            return cut(context("invalid immediate", fail))(s);
+        }
+        let (s_addr, _) = comment_or_whitespace1(s_addr)?;
+        Ok((s_addr, LA((r.to_string(), addr.to_string()))))
+    }
+}
+
+/// Whether the operand of `la` is an absolute address rather than a label.
+fn is_address_literal(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Parse the label a branch instruction jumps to, or whose address an instruction loads.
+fn label_operand(s_orig: &str) -> ParseResult<String> {
+    let (s, addr) = label_addr(s_orig)?;
+    let (s, _) = comment_or_whitespace1(s)?; // require space after called label
+
+    // This check cannot be moved into `label_addr`, since `label_addr` is shared
+    // between the scenarios `<label>:` and `call <label>`; the former requires
+    // parsing the `:` before rejecting a possible instruction name in the label.
+    if is_instruction_name(&addr) {
+        return cut(context("label cannot be named after instruction", fail))(s);
     }
+    Ok((s, addr))
 }
 
 /// Parse a label address. This is used in "`<label>:`" and in "`call <label>`".
@@ -504,26 +905,35 @@ fn token1<'a>(token: &'a str) -> impl Fn(&'a str) -> ParseResult<()> {
     }
 }
 
-fn reg1(s: &str) -> ParseResult<&str> {
-    if s.is_empty() {
-        return context("expected register", fail)(s);
+/// Parse a register name, including its prefix `$`.
+fn reg1(s_orig: &str) -> ParseResult<&str> {
+    if s_orig.is_empty() {
+        return context("expected register", fail)(s_orig);
     }
-    if s.as_bytes()[0] != b'$' {
-        context("expected register", fail)(s)
+    if s_orig.as_bytes()[0] != b'$' {
+        context("expected register", fail)(s_orig)
     } else {
-        let (s, _) = tag("$")(s)?;
+        let (s, _) = tag("$")(s_orig)?;
         let (s, reg) = is_not(" \t\r\n,()[]~!@#$%^&*()`[]\\{}:\";',./<>?")(s)?;
+        let reg = &s_orig[..1 + reg.len()];
+        if reg.parse::<Reg>().is_err() {
+            return cut(context(UNKNOWN_REGISTER, fail))(s_orig);
+        }
         Ok((s, reg))
     }
 }
 
-fn immediate_value(s: &str) -> ParseResult<&str> {
-    if s.is_empty() {
-        return context("expect immediate value", fail)(s);
+/// Parse the second source operand: a register or an immediate.
+fn operand(s_orig: &str) -> ParseResult<&str> {
+    if s_orig.is_empty() {
+        return context("expect immediate value", fail)(s_orig);
+    }
+    let (s, a) = is_not(" \t\r\n,(")(s_orig)?;
+    match a.parse::<RegA>() {
+        Ok(_) => Ok((s, a)),
+        Err(RegParseError::UnknownRegister(_)) => cut(context(UNKNOWN_REGISTER, fail))(s_orig),
+        Err(RegParseError::InvalidImmediate(_)) => cut(context("invalid immediate", fail))(s_orig),
     }
-    // TODO: parse value
-    let (s, a) = is_not(" \t\r\n,")(s)?;
-    Ok((s, a))
 }
 
 #[cfg(test)]
@@ -532,10 +942,13 @@ mod parser_tests {
     use rand::distributions::WeightedIndex;
     use rand::prelude::*;
     use rand::Rng;
-    use twenty_first::shared_math::b_field_element::BFieldElement;
 
     use LabelledInstruction::*;
 
+    use crate::data_segment::Directive;
+    use crate::data_segment::DATA_SEGMENT_MAX_SIZE;
+    use crate::data_segment::WORD_SIZE;
+    use crate::instruction::AssemblyError;
     use crate::program::Program;
 
     use super::*;
@@ -557,7 +970,7 @@ mod parser_tests {
         match parse(test_case.input) {
             Ok(actual) => assert_eq!(
                 test_case.expected,
-                Program::new(&to_labelled(&actual)),
+                Program::new(&to_labelled(&actual)).unwrap(),
                 "{}",
                 test_case.message
             ),
@@ -622,62 +1035,77 @@ mod parser_tests {
         })
     }
 
-    fn instruction_gen(labels: &mut Vec<String>) -> Vec<String> {
-        let mut rng = thread_rng();
+    fn register_gen() -> String {
+        let registers = ["$t0", "$t1", "$t9", "$s0", "$a0", "$v0", "$sp", "$ra", "$8"];
+        registers.choose(&mut thread_rng()).unwrap().to_string()
+    }
 
-        let difficult_instructions = vec!["push", "dup", "swap", "skiz", "call"];
-        let simple_instructions = ALL_INSTRUCTION_NAMES
-            .into_iter()
-            .filter(|name| !difficult_instructions.contains(name))
-            .collect_vec();
+    fn operand_gen() -> String {
+        let mut rng = thread_rng();
+        match rng.gen_range(0..3) {
+            0 => register_gen(),
+            1 => format!("{}", rng.gen_range(i32::MIN..=i32::MAX)),
+            _ => format!("{:#x}", rng.gen::<u32>()),
+        }
+    }
 
-        let generators = vec![vec!["simple"], difficult_instructions].concat();
-        // Test difficult instructions more frequently.
-        let weights = vec![simple_instructions.len(), 2, 6, 6, 2, 10];
+    fn instruction_gen(labels: &mut Vec<String>) -> Vec<String> {
+        let mut rng = thread_rng();
 
-        assert_eq!(
-            generators.len(),
-            weights.len(),
-            "all generators must have weights"
-        );
-        let dist = WeightedIndex::new(&weights).expect("a weighted distribution of generators");
+        let generators = [
+            "branch", "three", "jump", "register", "memory", "two", "label", "nullary",
+        ];
+        // Test instructions with many operands more frequently.
+        let weights = [4, 10, 2, 3, 3, 3, 2, 1];
+        let dist = WeightedIndex::new(weights).expect("a weighted distribution of generators");
 
-        match generators[dist.sample(&mut rng)] {
-            "simple" => {
-                let index: usize = rng.gen_range(0..simple_instructions.len());
-                let instruction = simple_instructions[index];
-                vec![instruction.to_string()]
+        let instruction = match generators[dist.sample(&mut rng)] {
+            "branch" => {
+                let names = ["beq", "bne", "blt", "ble", "bgt", "bltu", "bgeu"];
+                let name = names.choose(&mut rng).unwrap();
+                let (rs, rt) = (register_gen(), register_gen());
+                format!("{name} {rs}, {rt}, {}", new_label_gen(labels))
             }
-
-            "push" => {
-                let max: i128 = BFieldElement::MAX as i128;
-                let arg: i128 = rng.gen_range(-max..max);
-                vec!["push".to_string(), format!("{arg}")]
+            "three" => {
+                let names = [
+                    "seq", "sne", "slt", "sltu", "sle", "add", "addu", "sub", "subu", "mult",
+                    "div", "divu", "mod", "and", "or", "xor", "not", "sll", "srl", "sra",
+                ];
+                let name = names.choose(&mut rng).unwrap();
+                format!(
+                    "{name} {}, {}, {}",
+                    register_gen(),
+                    register_gen(),
+                    operand_gen()
+                )
             }
-
-            "dup" => {
-                let arg: usize = rng.gen_range(0..15);
-                vec!["dup".to_string(), format!("{arg}")]
+            "jump" => {
+                let name = ["j", "jal"].choose(&mut rng).unwrap();
+                format!("{name} {}", new_label_gen(labels))
             }
-
-            "swap" => {
-                let arg: usize = rng.gen_range(1..15);
-                vec!["swap".to_string(), format!("{arg}")]
+            "register" => {
+                let names = [
+                    "jr", "jalr", "pubread", "secread", "print", "answer", "exit",
+                ];
+                let name = names.choose(&mut rng).unwrap();
+                format!("{name} {}", register_gen())
             }
-
-            "skiz" => {
-                let mut target: Vec<String> = instruction_gen(labels);
-                target.insert(0, "skiz".to_string());
-                target
+            "memory" => {
+                let name = ["lw", "sw"].choose(&mut rng).unwrap();
+                let offset = rng.gen_range(-64..64) * 4;
+                format!("{name} {}, {offset}({})", register_gen(), register_gen())
             }
-
-            "call" => {
-                let some_label: String = new_label_gen(labels);
-                vec!["call".to_string(), some_label]
+            "two" => {
+                let name = ["move", "li", "pubseek", "secseek"]
+                    .choose(&mut rng)
+                    .unwrap();
+                format!("{name} {}, {}", register_gen(), operand_gen())
             }
-
+            "label" => format!("la {}, {}", register_gen(), new_label_gen(labels)),
+            "nullary" => ["syscall", "nop"].choose(&mut rng).unwrap().to_string(),
             unknown => panic!("Unknown generator, {unknown}"),
-        }
+        };
+        vec![instruction]
     }
 
     // FIXME: Apply shrinking.
@@ -706,55 +1134,55 @@ mod parser_tests {
     fn parse_program_empty_test() {
         parse_program_prop(TestCase {
             input: "",
-            expected: Program::new(&[]),
+            expected: Program::new(&[]).unwrap(),
             message: "empty string should parse as empty program",
         });
 
         parse_program_prop(TestCase {
             input: "   ",
-            expected: Program::new(&[]),
+            expected: Program::new(&[]).unwrap(),
             message: "spaces should parse as empty program",
         });
 
         parse_program_prop(TestCase {
             input: "\n",
-            expected: Program::new(&[]),
+            expected: Program::new(&[]).unwrap(),
             message: "linebreaks should parse as empty program (1)",
         });
 
         parse_program_prop(TestCase {
             input: "   \n ",
-            expected: Program::new(&[]),
+            expected: Program::new(&[]).unwrap(),
             message: "linebreaks should parse as empty program (2)",
         });
 
         parse_program_prop(TestCase {
             input: "   \n \n",
-            expected: Program::new(&[]),
+            expected: Program::new(&[]).unwrap(),
             message: "linebreaks should parse as empty program (3)",
         });
 
         parse_program_prop(TestCase {
             input: "// empty program",
-            expected: Program::new(&[]),
+            expected: Program::new(&[]).unwrap(),
             message: "single comment should parse as empty program",
         });
 
         parse_program_prop(TestCase {
             input: "// empty program\n",
-            expected: Program::new(&[]),
+            expected: Program::new(&[]).unwrap(),
             message: "single comment with linebreak should parse as empty program",
         });
 
         parse_program_prop(TestCase {
             input: "// multi-line\n// comment",
-            expected: Program::new(&[]),
+            expected: Program::new(&[]).unwrap(),
             message: "multiple comments should parse as empty program",
         });
 
         parse_program_prop(TestCase {
             input: "// multi-line\n// comment\n ",
-            expected: Program::new(&[]),
+            expected: Program::new(&[]).unwrap(),
             message: "multiple comments with trailing whitespace should parse as empty program",
         });
 
@@ -762,7 +1190,7 @@ mod parser_tests {
             let input = whitespace_gen(size);
             parse_program_prop(TestCase {
                 input: &input,
-                expected: Program::new(&[]),
+                expected: Program::new(&[]).unwrap(),
                 message: "arbitrary whitespace should parse as empty program",
             });
         }
@@ -846,15 +1274,189 @@ mod parser_tests {
                     "$t1".to_string(),
                     "__L1__".to_string(),
                 ))),
-            ]),
+            ])
+            .unwrap(),
             message: "branch err",
         });
     }
 
+    #[test]
+    fn parse_subroutine_call_ins() {
+        parse_program_prop(TestCase {
+            input: "jal func\n
+                    jalr $t0\n
+                    func: jr $ra",
+            expected: Program::new(&[
+                Instruction(JAL("func".to_string())),
+                Instruction(JALR("$t0".to_string())),
+                Label("func".to_string()),
+                Instruction(JR("$ra".to_string())),
+            ])
+            .unwrap(),
+            message: "subroutine call err",
+        });
+    }
+
+    #[test]
+    fn parse_error_reports_location_and_suggestion() {
+        let code = "move $t0, 1\nadd $t1, $zer0, $t0\n";
+        let error = parse(code).unwrap_err();
+        assert_eq!(Some((2, 10)), error.line_column());
+        let expected = "line 2, column 10: unknown register `$zer0`, did you mean `$zero`?";
+        let message = error.to_string();
+        assert!(message.contains(expected), "{message}");
+
+        let message = parse("move $t0, 1\n  addd $t1, $t0, 1")
+            .unwrap_err()
+            .to_string();
+        let expected = "line 2, column 3: expecting label, instruction or eof `addd`, \
+            did you mean `add`?";
+        assert!(message.contains(expected), "{message}");
+
+        let message = parse("sw $t0, 0x1g($sp)").unwrap_err().to_string();
+        let expected = "line 1, column 9: invalid immediate `0x1g`";
+        assert!(message.contains(expected), "{message}");
+    }
+
+    #[test]
+    fn parse_signed_immediates() {
+        let signed = Program::from_code("add $sp, $sp, -8\nlw $t0, -0x4($fp)").unwrap();
+        let unsigned =
+            Program::from_code("add $sp, $sp, 0xfffffff8\nlw $t0, 4294967292($fp)").unwrap();
+        assert_eq!(
+            unsigned, signed,
+            "negative immediates are words in two's complement"
+        );
+
+        let positive = Program::from_code("add $sp, $sp, 8\nlw $t0, 4($fp)").unwrap();
+        assert_ne!(
+            positive.to_bwords(),
+            signed.to_bwords(),
+            "the encoding must distinguish negative from positive immediates"
+        );
+
+        parse_program_prop(TestCase {
+            input: "move $t0, -2147483648",
+            expected: Program::new(&[Instruction(MOVE((
+                "$t0".to_string(),
+                "0x80000000".to_string(),
+            )))])
+            .unwrap(),
+            message: "smallest signed word",
+        });
+
+        for input in [
+            "move $t0, -2147483649",
+            "move $t0, 4294967296",
+            "move $t0, --1",
+            "move $t0, -+1",
+        ] {
+            parse_program_neg_prop(NegativeTestCase {
+                input,
+                expected_error: "invalid immediate",
+                expected_error_count: 2,
+                message: "immediates must be 32-bit integers",
+            });
+        }
+    }
+
+    #[test]
+    fn parse_data_directives() {
+        parse_program_prop(TestCase {
+            input: ".data\n
+                    msg: .asciiz \"hi\\n\"\n
+                    .align 2\n
+                    nums: .word 7, 0x10, -1\n
+                    .space 8\n
+                    .text\n
+                    .globl main\n
+                    main: la $t0, nums\n
+                    lw $t1, 4($t0)",
+            expected: Program::new(&[
+                LabelledInstruction::Directive(Directive::Data),
+                Label("msg".to_string()),
+                LabelledInstruction::Directive(Directive::Asciiz("hi\n".to_string())),
+                LabelledInstruction::Directive(Directive::Align(2)),
+                Label("nums".to_string()),
+                LabelledInstruction::Directive(Directive::Word(vec![7, 16, u32::MAX])),
+                LabelledInstruction::Directive(Directive::Space(8)),
+                LabelledInstruction::Directive(Directive::Text),
+                LabelledInstruction::Directive(Directive::Globl("main".to_string())),
+                Label("main".to_string()),
+                Instruction(LA(("$t0".to_string(), "nums".to_string()))),
+                Instruction(LW(("$t1".to_string(), "4".to_string(), "$t0".to_string()))),
+            ])
+            .unwrap(),
+            message: "data directive err",
+        });
+    }
+
+    #[test]
+    fn oversized_data_segment_err() {
+        let num_words = (DATA_SEGMENT_MAX_SIZE / WORD_SIZE) as usize;
+        let fitting_words = ".word 0\n".repeat(num_words);
+        let too_many_words = ".word 0\n".repeat(num_words + 1);
+        let fitting_code = format!(".data\n{fitting_words}");
+        let program = Program::new(&to_labelled(&parse(&fitting_code).unwrap())).unwrap();
+        assert_eq!(num_words, program.data.len());
+
+        for code in [
+            ".data .space 4294967295".to_string(),
+            ".data .asciiz \"a\" .align 31".to_string(),
+            format!(".data\n{too_many_words}"),
+        ] {
+            let parsed_program = parse(&code).unwrap();
+            let result = Program::new(&to_labelled(&parsed_program));
+            assert!(
+                matches!(result, Err(AssemblyError::DataSegmentTooLarge(_))),
+                "data segment must not exceed {DATA_SEGMENT_MAX_SIZE} bytes: {}",
+                &code[..code.len().min(40)]
+            );
+        }
+    }
+
+    #[test]
+    fn parse_zero_register_write_err() {
+        for input in [
+            "move $zero, 5",
+            "addu $0, $t0, $t1",
+            "slt $t0, $zero, $t1",
+            "li $zero, 1",
+            "pop $zero",
+        ] {
+            parse_program_neg_prop(NegativeTestCase {
+                input,
+                expected_error: "instruction writes register $zero",
+                expected_error_count: 2,
+                message: "$zero must not be written to",
+            });
+        }
+
+        let result = parse("nop sll $zero, $zero, 0 addu $t0, $zero, $zero");
+        assert!(result.is_ok(), "nop and reading $zero must be allowed");
+    }
+
+    #[test]
+    fn parse_misplaced_data_directive_err() {
+        parse_program_neg_prop(NegativeTestCase {
+            input: ".data nums: .word 1 add $t0, $t1, $t2",
+            expected_error: "instruction inside data section",
+            expected_error_count: 2,
+            message: "instructions must not appear in the data section",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "nums: .word 1",
+            expected_error: "data directive outside data section",
+            expected_error_count: 2,
+            message: "data must not appear in the text section",
+        });
+    }
+
     #[test]
     fn parse_speck_code() {
         let expected = Program::new(&[
-            Instruction(MOVE(("$t4".to_string(), "32".to_string()))),
+            Instruction(MOVE(("$t4".to_string(), "27".to_string()))),
             Instruction(SECREAD("$t2".to_string())),
             Instruction(SECREAD("$t3".to_string())),
             Label("__L1__".to_string()),
@@ -862,14 +1464,14 @@ mod parser_tests {
             Instruction(SLL((
                 "$t6".to_string(),
                 "$t3".to_string(),
-                "56".to_string(),
+                "24".to_string(),
             ))),
             Instruction(OR((
                 "$t6".to_string(),
                 "$t5".to_string(),
                 "$t6".to_string(),
             ))),
-            Instruction(ADD((
+            Instruction(ADDU((
                 "$t3".to_string(),
                 "$t6".to_string(),
                 "$t2".to_string(),
@@ -883,7 +1485,7 @@ mod parser_tests {
             Instruction(SRL((
                 "$t5".to_string(),
                 "$t2".to_string(),
-                "61".to_string(),
+                "29".to_string(),
             ))),
             Instruction(SLL(("$t6".to_string(), "$t2".to_string(), "3".to_string()))),
             Instruction(OR((
@@ -905,11 +1507,37 @@ mod parser_tests {
             Instruction(PRINT("$t2".to_string())),
             Instruction(PRINT("$t3".to_string())),
             Instruction(ANSWER("$t3".to_string())),
-        ]);
+        ])
+        .unwrap();
         parse_program_prop(TestCase {
-            input: crate::instruction::sample_programs::SPECK128,
+            input: crate::instruction::sample_programs::SPECK64,
             expected,
             message: "parse code err",
         })
     }
+
+    #[test]
+    fn source_map_test() {
+        let code =
+            "main: li $t0, 7\n  push $t0\n.data\nnums: .word 1\n.text\nloop:\n  add $t1, $t1, 1";
+        let source_map = Program::from_code(code).unwrap().source_map;
+
+        let location = |line, column, label: &str| SourceLocation {
+            file: None,
+            line,
+            column,
+            label: Some(label.to_string()),
+        };
+        assert_eq!(Some(&location(1, 7, "main")), source_map.get(0));
+        assert_eq!(Some(&location(2, 3, "main")), source_map.get(1));
+        assert_eq!(Some(&location(2, 3, "main")), source_map.get(2));
+        assert_eq!(Some(&location(7, 3, "loop")), source_map.get(3));
+        assert_eq!(None, source_map.get(4));
+
+        let source_map = source_map.in_file("prog.s");
+        assert_eq!(
+            "prog.s:7:3 in `loop`",
+            source_map.get(3).unwrap().to_string()
+        );
+    }
 }
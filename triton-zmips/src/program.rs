@@ -1,22 +1,66 @@
 use std::any::Any;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::io::Cursor;
 
+use anyhow::bail;
 use anyhow::Result;
 use itertools::Itertools;
-use triton_program::{AbstractInstruction, AbstractLabelledInstruction, AbstractProgram, FromCode};
+use triton_program::{
+    AbstractInstruction, AbstractLabelledInstruction, AbstractProgram, FromCode, Isa, SourceMap,
+};
 use twenty_first::shared_math::b_field_element::BFieldElement;
 use twenty_first::util_types::algebraic_hasher::Hashable;
 
+use crate::data_segment::Directive;
+use crate::data_segment::DATA_SEGMENT_START;
+use crate::data_segment::WORD_SIZE;
+use crate::elf;
 use crate::instruction::convert_labels;
+use crate::instruction::data_segment;
+use crate::instruction::AnInstruction::LA;
+use crate::instruction::AssemblyError;
 use crate::instruction::Instruction;
 use crate::instruction::LabelledInstruction;
 use crate::parser::parse;
+use crate::parser::source_map;
 use crate::parser::to_labelled;
 
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// A zMIPS program. Registers, memory words, and immediates are 32 bits wide, and arithmetic
+/// wraps modulo `2^32` like on MIPS32.
+#[derive(Debug, Clone, Default)]
 pub struct Program {
     pub instructions: Vec<Instruction>,
+
+    /// The initial content of the data segment. Word `i` is stored at address
+    /// `DATA_SEGMENT_START + 4·i`.
+    pub data: Vec<u32>,
+
+    /// The locations in the source code the instructions originate from. Empty unless the
+    /// program was parsed from source code.
+    pub source_map: SourceMap,
+}
+
+/// The source map is not part of a program's identity: programs parsed from differently
+/// formatted source code are equal if their instructions and data are.
+impl PartialEq for Program {
+    fn eq(&self, other: &Self) -> bool {
+        self.instructions == other.instructions && self.data == other.data
+    }
+}
+
+impl Eq for Program {}
+
+/// zMIPS, the MIPS32-like register-machine instruction set architecture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZmipsIsa;
+
+impl Isa for ZmipsIsa {
+    const NAME: &'static str = "zMIPS";
+
+    type Instruction = Instruction;
+    type Program = Program;
 }
 
 impl AbstractProgram for Program {
@@ -43,6 +87,13 @@ impl AbstractProgram for Program {
         self.to_sequence()
     }
 
+    fn data_bwords(&self) -> Vec<BFieldElement> {
+        self.data
+            .iter()
+            .map(|&word| BFieldElement::from(word))
+            .collect()
+    }
+
     fn clone_(&self) -> Box<dyn AbstractProgram> {
         Box::new(self.clone()) as Box<dyn AbstractProgram>
     }
@@ -50,6 +101,10 @@ impl AbstractProgram for Program {
     fn as_any(&self) -> &dyn Any {
         self as &dyn Any
     }
+
+    fn source_map(&self) -> &SourceMap {
+        &self.source_map
+    }
 }
 
 impl FromCode for Program {
@@ -60,13 +115,13 @@ impl FromCode for Program {
         Program::from_code(code).map(|x| Box::new(x) as Box<dyn AbstractProgram>)
     }
 
-    fn create(input: &[Box<dyn AbstractLabelledInstruction>]) -> Box<dyn AbstractProgram> {
+    fn create(input: &[Box<dyn AbstractLabelledInstruction>]) -> Result<Box<dyn AbstractProgram>> {
         let input = input
             .iter()
             .map(|x| x.as_any().downcast_ref::<LabelledInstruction>().unwrap())
             .cloned()
             .collect_vec();
-        Box::new(Program::new(input.as_slice())) as Box<dyn AbstractProgram>
+        Ok(Box::new(Program::new(input.as_slice())?) as Box<dyn AbstractProgram>)
     }
 }
 
@@ -85,9 +140,13 @@ impl Display for Program {
     }
 }
 
+/// The program's instructions, followed by its initial data segment and the data segment's
+/// length. Hence, the program digest also commits to the initial memory.
 impl Hashable for Program {
     fn to_sequence(&self) -> Vec<BFieldElement> {
-        self.to_bwords()
+        let data = self.data.iter().map(|&word| BFieldElement::from(word));
+        let data_len = BFieldElement::new(self.data.len() as u64);
+        [self.to_bwords(), data.collect(), vec![data_len]].concat()
     }
 }
 
@@ -125,44 +184,121 @@ impl IntoIterator for Program {
 /// corresponds to the VM's `instruction_pointer`. These duplicate values
 /// should most often be skipped/ignored, e.g. when pretty-printing.
 impl Program {
-    /// Create a `Program` from a slice of `Instruction`.
-    pub fn new(input: &[LabelledInstruction]) -> Self {
-        let instructions = convert_labels(input)
+    /// Create a `Program` from a slice of `Instruction`. Fails if a register or an operand is
+    /// malformed, if a label is not defined, or if an instruction writes register `$zero`.
+    pub fn new(input: &[LabelledInstruction]) -> Result<Self, AssemblyError> {
+        let instructions = convert_labels(input)?
             .iter()
             .flat_map(|instr| vec![*instr; instr.size()])
             .collect::<Vec<_>>();
 
-        Program { instructions }
+        let data = data_segment(input)?.words();
+        let source_map = SourceMap::default();
+
+        Ok(Program {
+            instructions,
+            data,
+            source_map,
+        })
     }
 
-    /// Create a `Program` by parsing source code.
+    /// Create a `Program` by parsing source code. The program's [`SourceMap`] refers to the
+    /// given source code.
     pub fn from_code(code: &str) -> Result<Self> {
-        parse(code)
-            .map(|program| Program::new(&to_labelled(&program)))
-            .map_err(|err| anyhow::anyhow!("{}", err))
+        let parsed_program = parse(code).map_err(|err| anyhow::anyhow!("{}", err))?;
+        let mut program = Program::new(&to_labelled(&parsed_program))?;
+        program.source_map = source_map(code, &parsed_program);
+        Ok(program)
+    }
+
+    /// Create a `Program` from a statically linked MIPS32 little-endian ELF executable, see
+    /// [`elf::load`].
+    pub fn from_elf(elf: &[u8]) -> Result<Self> {
+        Ok(elf::load(elf)?)
     }
 
     /// Convert a `Program` to a `Vec<BFieldElement>`.
     ///
-    /// Every single-word instruction is converted to a single word.
-    ///
-    /// Every double-word instruction is converted to two words.
+    /// Every instruction, including its operands, is packed into a single word, see
+    /// [`Instruction::word`]. Hence, the index of a word equals the instruction's address.
     pub fn to_bwords(&self) -> Vec<BFieldElement> {
         self.clone()
             .into_iter()
-            .flat_map(|instruction| {
-                let opcode = instruction.opcode_b();
-                let mut v = vec![opcode];
-                for arg in instruction.args() {
-                    v.push(arg);
-                }
-                v
-            })
+            .map(|instruction| instruction.word())
             .collect()
     }
 
-    /// The total length of the program as `BFieldElement`s. Double-word instructions contribute
-    /// two `BFieldElement`s.
+    /// Decode a `Program` from its words, the inverse of [`Program::to_bwords`]. The data segment
+    /// is empty.
+    ///
+    /// Fails if some word does not encode an instruction, see [`Instruction::from_word`], or if
+    /// some branch or jump targets an address outside of the program.
+    pub fn from_bwords(words: &[BFieldElement]) -> Result<Self> {
+        let instructions = words
+            .iter()
+            .map(|&word| Instruction::from_word(word))
+            .collect::<Result<Vec<_>>>()?;
+
+        for (address, instruction) in instructions.iter().enumerate() {
+            let jump_target = match instruction {
+                LA(_) => None,
+                _ => instruction.target_address(),
+            };
+            if let Some(target) = jump_target {
+                if target.value() > instructions.len() as u64 {
+                    bail!("Instruction `{instruction}` at address {address} jumps out of bounds.");
+                }
+            }
+        }
+
+        let data = vec![];
+        let source_map = SourceMap::default();
+        Ok(Program {
+            instructions,
+            data,
+            source_map,
+        })
+    }
+
+    /// Disassemble the `Program` into source code that assembles to the same `Program`.
+    ///
+    /// Branch and jump targets, as well as addresses loaded by `la` that lie inside the program,
+    /// get the synthesized label `__L<address>__`. The data segment is listed as words.
+    pub fn disassemble(&self) -> String {
+        let num_instructions = self.instructions.len() as u64;
+        let label_addresses = self
+            .instructions
+            .iter()
+            .filter_map(|instruction| instruction.target_address())
+            .map(|address| address.value())
+            .filter(|&address| address <= num_instructions)
+            .collect::<BTreeSet<_>>();
+        let label = |address: u64| format!("__L{address}__");
+        let label_for = |address: BFieldElement| {
+            let address = address.value();
+            label_addresses.contains(&address).then(|| label(address))
+        };
+
+        let mut source = vec![];
+        if !self.data.is_empty() {
+            source.push(Directive::Data.to_string());
+            source.push(Directive::Word(self.data.clone()).to_string());
+            source.push(Directive::Text.to_string());
+        }
+        for (address, instruction) in (0..).zip(self.instructions.iter()) {
+            if label_addresses.contains(&address) {
+                source.push(format!("{}:", label(address)));
+            }
+            source.push(format!("    {}", instruction.to_labelled(label_for)));
+        }
+        if label_addresses.contains(&num_instructions) {
+            source.push(format!("{}:", label(num_instructions)));
+        }
+        source.into_iter().map(|line| line + "\n").collect()
+    }
+
+    /// The total length of the program as `BFieldElement`s. Every instruction contributes one
+    /// `BFieldElement`.
     pub fn len_bwords(&self) -> usize {
         self.instructions.len()
     }
@@ -170,4 +306,117 @@ impl Program {
     pub fn is_empty(&self) -> bool {
         self.instructions.is_empty()
     }
+
+    /// The initial content of memory, mapping addresses to words. All other addresses initially
+    /// hold 0.
+    pub fn initial_ram(&self) -> HashMap<BFieldElement, BFieldElement> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, &word)| {
+                let address = DATA_SEGMENT_START as u64 + WORD_SIZE as u64 * i as u64;
+                (BFieldElement::new(address), BFieldElement::from(word))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod program_tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use strum::EnumCount;
+    use twenty_first::shared_math::tip5::Tip5;
+    use twenty_first::util_types::algebraic_hasher::AlgebraicHasher;
+
+    use crate::instruction::Operands;
+    use crate::instruction::ALL_INSTRUCTIONS;
+    use crate::regs::REGS;
+
+    use super::*;
+
+    fn arbitrary_instruction() -> impl Strategy<Value = Instruction> {
+        let opcode = (0..Instruction::COUNT).prop_map(|i| ALL_INSTRUCTIONS[i].opcode());
+        let reg = || (0..REGS.len()).prop_map(|i| REGS[i]);
+        let operands = (reg(), reg(), reg(), any::<bool>(), any::<u32>()).prop_map(
+            |(rs, rt, rd, is_imm, imm)| Operands {
+                rs,
+                rt,
+                rd,
+                is_imm,
+                imm: imm.into(),
+            },
+        );
+        (opcode, operands)
+            .prop_map(|(opcode, operands)| Instruction::from_operands(opcode, operands).unwrap())
+            .prop_filter("writes register $zero", |instruction| {
+                !instruction.writes_zero_register()
+            })
+    }
+
+    fn arbitrary_program(
+        num_instructions: std::ops::Range<usize>,
+    ) -> impl Strategy<Value = Program> {
+        (
+            vec(arbitrary_instruction(), num_instructions),
+            vec(any::<u32>(), 0..8),
+        )
+            .prop_map(|(instructions, data)| Program {
+                instructions,
+                data,
+                source_map: SourceMap::default(),
+            })
+    }
+
+    #[test]
+    fn digest_depends_on_operands_test() {
+        let program = Program::from_code("add $t1, $t1, 1").unwrap();
+        let other_program = Program::from_code("add $t2, $t3, 99").unwrap();
+        assert_ne!(program.to_bwords(), other_program.to_bwords());
+        assert_ne!(Tip5::hash(&program), Tip5::hash(&other_program));
+    }
+
+    proptest! {
+        #[test]
+        fn instruction_word_is_injective(
+            instruction in arbitrary_instruction(),
+            other_instruction in arbitrary_instruction(),
+        ) {
+            let word = instruction.word();
+            prop_assert_eq!(instruction, Instruction::from_word(word).unwrap());
+            prop_assert_eq!(instruction == other_instruction, word == other_instruction.word());
+        }
+
+        #[test]
+        fn distinct_programs_have_distinct_digests(
+            program in arbitrary_program(0..20),
+            other_program in arbitrary_program(0..20),
+        ) {
+            prop_assume!(program != other_program);
+            prop_assert_ne!(Tip5::hash(&program), Tip5::hash(&other_program));
+        }
+
+        #[test]
+        fn changing_one_instruction_changes_the_digest(
+            program in arbitrary_program(1..20),
+            index in any::<prop::sample::Index>(),
+            instruction in arbitrary_instruction(),
+        ) {
+            let index = index.index(program.instructions.len());
+            prop_assume!(program.instructions[index] != instruction);
+            let mut changed_program = program.clone();
+            changed_program.instructions[index] = instruction;
+            prop_assert_ne!(Tip5::hash(&program), Tip5::hash(&changed_program));
+        }
+
+        #[test]
+        fn moving_words_between_text_and_data_changes_the_digest(
+            program in arbitrary_program(1..20),
+        ) {
+            let mut changed_program = program.clone();
+            let last_instruction = changed_program.instructions.pop().unwrap();
+            changed_program.data.insert(0, last_instruction.word().value() as u32);
+            prop_assert_ne!(Tip5::hash(&program), Tip5::hash(&changed_program));
+        }
+    }
 }
@@ -0,0 +1,196 @@
+use std::fmt::Display;
+
+use crate::instruction::AnInstruction;
+use crate::instruction::AnInstruction::*;
+use crate::instruction::LabelledInstruction;
+
+pub const ALL_PSEUDO_INSTRUCTION_NAMES: [&str; 12] = [
+    "li", "nop", "neg", "b", "beqz", "bnez", "bge", "bltz", "mul", "subi", "push", "pop",
+];
+
+/// A pseudo-instruction is shorthand for a short sequence of instructions of the ISA. The
+/// assembler replaces every pseudo-instruction by its [expansion](PseudoInstruction::expand)
+/// before translating labels into absolute addresses.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PseudoInstruction {
+    /// `li $rd, imm`: load an immediate into `$rd`.
+    LI((String, String)),
+
+    /// `nop`: do nothing.
+    NOP,
+
+    /// `neg $rd, $rs`: store `-$rs` in `$rd`.
+    NEG((String, String)),
+
+    /// `b label`: branch unconditionally.
+    B(String),
+
+    /// `beqz $rs, label`: branch if `$rs` is zero.
+    BEQZ((String, String)),
+
+    /// `bnez $rs, label`: branch if `$rs` is not zero.
+    BNEZ((String, String)),
+
+    /// `bge $rs, $rt, label`: branch if `$rs` is greater than or equal to `$rt`.
+    BGE((String, String, String)),
+
+    /// `bltz $rs, label`: branch if `$rs` is less than zero.
+    BLTZ((String, String)),
+
+    /// `mul $rd, $rs, $rt`: store the lower 32 bits of the product in `$rd`.
+    MUL((String, String, String)),
+
+    /// `subi $rd, $rs, imm`: subtract an immediate.
+    SUBI((String, String, String)),
+
+    /// `push $rt`: grow the stack by one word and store `$rt` on top of it.
+    PUSH(String),
+
+    /// `pop $rt`: load the top of the stack into `$rt` and shrink the stack by one word.
+    POP(String),
+}
+
+impl Display for PseudoInstruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())?;
+        match self {
+            PseudoInstruction::LI((r, imm)) => write!(f, " {}, {}", r, imm),
+            PseudoInstruction::NOP => Ok(()),
+            PseudoInstruction::NEG((r1, r2)) => write!(f, " {}, {}", r1, r2),
+            PseudoInstruction::B(addr) => write!(f, " {}", addr),
+            PseudoInstruction::BEQZ((r, addr)) => write!(f, " {}, {}", r, addr),
+            PseudoInstruction::BNEZ((r, addr)) => write!(f, " {}, {}", r, addr),
+            PseudoInstruction::BGE((r1, r2, addr)) => write!(f, " {}, {}, {}", r1, r2, addr),
+            PseudoInstruction::BLTZ((r, addr)) => write!(f, " {}, {}", r, addr),
+            PseudoInstruction::MUL((r1, r2, a)) => write!(f, " {}, {}, {}", r1, r2, a),
+            PseudoInstruction::SUBI((r1, r2, imm)) => write!(f, " {}, {}, {}", r1, r2, imm),
+            PseudoInstruction::PUSH(r) => write!(f, " {}", r),
+            PseudoInstruction::POP(r) => write!(f, " {}", r),
+        }
+    }
+}
+
+impl PseudoInstruction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PseudoInstruction::LI(_) => "li",
+            PseudoInstruction::NOP => "nop",
+            PseudoInstruction::NEG(_) => "neg",
+            PseudoInstruction::B(_) => "b",
+            PseudoInstruction::BEQZ(_) => "beqz",
+            PseudoInstruction::BNEZ(_) => "bnez",
+            PseudoInstruction::BGE(_) => "bge",
+            PseudoInstruction::BLTZ(_) => "bltz",
+            PseudoInstruction::MUL(_) => "mul",
+            PseudoInstruction::SUBI(_) => "subi",
+            PseudoInstruction::PUSH(_) => "push",
+            PseudoInstruction::POP(_) => "pop",
+        }
+    }
+
+    /// The label the pseudo-instruction branches to, if any.
+    pub fn label(&self) -> Option<&String> {
+        match self {
+            PseudoInstruction::B(addr)
+            | PseudoInstruction::BEQZ((_, addr))
+            | PseudoInstruction::BNEZ((_, addr))
+            | PseudoInstruction::BGE((_, _, addr))
+            | PseudoInstruction::BLTZ((_, addr)) => Some(addr),
+            _ => None,
+        }
+    }
+
+    /// The instructions of the ISA the pseudo-instruction stands for. The stack grows downwards
+    /// from `$sp`, which points to the top of the stack.
+    pub fn expand(&self) -> Vec<AnInstruction<String, String, String>> {
+        let zero = || "$zero".to_string();
+        let sp = || "$sp".to_string();
+        match self.clone() {
+            PseudoInstruction::LI((r, imm)) => vec![MOVE((r, imm))],
+            PseudoInstruction::NOP => vec![SLL((zero(), zero(), "0".to_string()))],
+            PseudoInstruction::NEG((r1, r2)) => vec![SUB((r1, zero(), r2))],
+            PseudoInstruction::B(addr) => vec![BEQ((zero(), zero(), addr))],
+            PseudoInstruction::BEQZ((r, addr)) => vec![BEQ((r, zero(), addr))],
+            PseudoInstruction::BNEZ((r, addr)) => vec![BNE((r, zero(), addr))],
+            PseudoInstruction::BGE((r1, r2, addr)) => vec![BLE((r2, r1, addr))],
+            PseudoInstruction::BLTZ((r, addr)) => vec![BLT((r, zero(), addr))],
+            PseudoInstruction::MUL((r1, r2, a)) => vec![MULT((r1, r2, a))],
+            PseudoInstruction::SUBI((r1, r2, imm)) => vec![SUB((r1, r2, imm))],
+            PseudoInstruction::PUSH(r) => vec![
+                SUBU((sp(), sp(), "4".to_string())),
+                SW((r, "0".to_string(), sp())),
+            ],
+            PseudoInstruction::POP(r) => vec![
+                LW((r, "0".to_string(), sp())),
+                ADDU((sp(), sp(), "4".to_string())),
+            ],
+        }
+    }
+}
+
+/// Replace every pseudo-instruction in the program by its expansion.
+pub fn expand_pseudo_instructions(program: &[LabelledInstruction]) -> Vec<LabelledInstruction> {
+    program
+        .iter()
+        .flat_map(|labelled_instruction| match labelled_instruction {
+            LabelledInstruction::Pseudo(pseudo) => pseudo
+                .expand()
+                .into_iter()
+                .map(LabelledInstruction::Instruction)
+                .collect(),
+            labelled_instruction => vec![labelled_instruction.clone()],
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod pseudo_instruction_tests {
+    use crate::parser::parse;
+    use crate::parser::to_labelled;
+    use crate::program::Program;
+
+    use super::*;
+
+    #[test]
+    fn pseudo_instructions_round_trip_through_display() {
+        let code = "
+            loop: li $t0, 7 nop neg $t1, $t0 b loop beqz $t0, loop bnez $t0, loop
+            bge $t0, $t1, loop bltz $t0, loop mul $t2, $t0, $t1 subi $t3, $t2, 3
+            push $t3 pop $t4
+        ";
+        let parsed = to_labelled(&parse(code).unwrap());
+        let pseudo_count = parsed
+            .iter()
+            .filter(|instruction| matches!(instruction, LabelledInstruction::Pseudo(_)))
+            .count();
+        assert_eq!(ALL_PSEUDO_INSTRUCTION_NAMES.len(), pseudo_count);
+
+        let printed = parsed
+            .iter()
+            .map(|instruction| instruction.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let reparsed = to_labelled(&parse(&printed).unwrap());
+        assert_eq!(parsed, reparsed);
+    }
+
+    #[test]
+    fn pseudo_instructions_expand_to_isa_instructions() {
+        let pseudo_code = "
+            li $t0, 7 nop neg $t1, $t0 beqz $t0, end bge $t0, $t1, end
+            push $t0 pop $t1
+            end: exit $zero
+        ";
+        let expanded_code = "
+            move $t0, 7 sll $zero, $zero, 0 sub $t1, $zero, $t0 beq $t0, $zero, end
+            ble $t1, $t0, end
+            subu $sp, $sp, 4 sw $t0, 0($sp)
+            lw $t1, 0($sp) addu $sp, $sp, 4
+            end: exit $zero
+        ";
+        assert_eq!(
+            Program::from_code(expanded_code).unwrap(),
+            Program::from_code(pseudo_code).unwrap()
+        );
+    }
+}
@@ -1,4 +1,7 @@
+use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
 use twenty_first::shared_math::b_field_element::BFieldElement;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
@@ -72,64 +75,89 @@ pub const REGS: [Reg; 32] = [
     Reg::Ra,
 ];
 
-impl From<&str> for Reg {
-    fn from(value: &str) -> Self {
-        if value.len() < 2 {
-            panic!("Invalid register name: {}", value);
+/// The names of the registers, without prefix `$`, in the order of [`REGS`].
+pub const REG_NAMES: [&str; 32] = [
+    "zero", "at", "v0", "v1", "a0", "a1", "a2", "a3", "t0", "t1", "t2", "t3", "t4", "t5", "t6",
+    "t7", "s0", "s1", "s2", "s3", "s4", "s5", "s6", "s7", "t8", "t9", "k0", "k1", "gp", "sp", "fp",
+    "ra",
+];
+
+/// An error in the name of a register or in an immediate value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RegParseError {
+    UnknownRegister(String),
+    InvalidImmediate(String),
+}
+
+impl Display for RegParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegParseError::UnknownRegister(name) => {
+                write!(f, "unknown register `{name}`")?;
+                match closest_register_name(name) {
+                    Some(suggestion) => write!(f, ", did you mean `{suggestion}`?"),
+                    None => Ok(()),
+                }
+            }
+            RegParseError::InvalidImmediate(imm) => {
+                write!(f, "invalid immediate `{imm}`, expected a 32-bit integer")
+            }
         }
-        // remove '$' prefix
-        let value = match value.strip_prefix('$') {
-            None => value,
-            Some(v) => v,
-        };
-        match value {
-            "zero" => Reg::Zero,
-            "at" => Reg::At,
-            "v0" => Reg::V0,
-            "v1" => Reg::V1,
-            "a0" => Reg::A0,
-            "a1" => Reg::A1,
-            "a2" => Reg::A2,
-            "a3" => Reg::A3,
-            "t0" => Reg::T0,
-            "t1" => Reg::T1,
-            "t2" => Reg::T2,
-            "t3" => Reg::T3,
-            "t4" => Reg::T4,
-            "t5" => Reg::T5,
-            "t6" => Reg::T6,
-            "t7" => Reg::T7,
-            "s0" => Reg::S0,
-            "s1" => Reg::S1,
-            "s2" => Reg::S2,
-            "s3" => Reg::S3,
-            "s4" => Reg::S4,
-            "s5" => Reg::S5,
-            "s6" => Reg::S6,
-            "s7" => Reg::S7,
-            "t8" => Reg::T8,
-            "t9" => Reg::T9,
-            "k0" => Reg::K0,
-            "k1" => Reg::K1,
-            "gp" => Reg::Gp,
-            "sp" => Reg::Sp,
-            "fp" => Reg::Fp,
-            "ra" => Reg::Ra,
-            _ => match value.parse::<usize>() {
-                Ok(n) if n < 32 => REGS[n],
-                _ => panic!("Unknown register: {}", value),
-            },
+    }
+}
+
+impl Error for RegParseError {}
+
+/// The register name closest to the given, unknown one, if any is close enough to be a likely typo.
+pub fn closest_register_name(name: &str) -> Option<String> {
+    let name = name.strip_prefix('$').unwrap_or(name).to_lowercase();
+    let max_distance = 2;
+    REG_NAMES
+        .iter()
+        .map(|reg_name| (edit_distance(&name, reg_name), reg_name))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, reg_name)| format!("${reg_name}"))
+}
+
+/// The Levenshtein distance between two strings.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut distances = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut previous_diagonal = distances[0];
+        distances[0] = i + 1;
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = previous_diagonal + usize::from(a_char != b_char);
+            previous_diagonal = distances[j + 1];
+            distances[j + 1] = substitution.min(distances[j] + 1).min(distances[j + 1] + 1);
         }
     }
+    distances[b.len()]
 }
-impl From<&String> for Reg {
-    fn from(value: &String) -> Self {
-        value.as_str().into()
+
+impl FromStr for Reg {
+    type Err = RegParseError;
+
+    /// Parse a register by its name, like `$t0`, or by its number, like `$8`. The prefix `$` is
+    /// optional.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let name = value.strip_prefix('$').unwrap_or(value);
+        if let Some(index) = REG_NAMES.iter().position(|&reg_name| reg_name == name) {
+            return Ok(REGS[index]);
+        }
+        match name.parse::<usize>() {
+            Ok(n) if n < REGS.len() => Ok(REGS[n]),
+            _ => Err(RegParseError::UnknownRegister(value.to_string())),
+        }
     }
 }
+
+/// The register's name in assembly syntax, like `$t0`.
 impl From<&Reg> for String {
     fn from(value: &Reg) -> Self {
-        format!("{:?}", value)
+        let index: usize = (*value).into();
+        format!("${}", REG_NAMES[index])
     }
 }
 impl Display for Reg {
@@ -211,34 +239,45 @@ impl Default for RegA {
         RegA::Imm(0)
     }
 }
-impl From<&String> for RegA {
-    fn from(value: &String) -> Self {
-        if let Some(value) = value.strip_prefix("0x") {
-            let value = u32::from_str_radix(value, 16).unwrap();
-            return RegA::Imm(value);
-        }
-        if let Some(value) = value.strip_prefix("0b") {
-            let value = u32::from_str_radix(value, 2).unwrap();
-            return RegA::Imm(value);
-        }
-        if let Some(value) = value.strip_prefix("0o") {
-            let value = u32::from_str_radix(value, 8).unwrap();
-            return RegA::Imm(value);
-        }
-        match value.parse::<u32>() {
-            Ok(imm) => RegA::Imm(imm),
-            Err(_) => RegA::RegName(value.into()),
+impl FromStr for RegA {
+    type Err = RegParseError;
+
+    /// Parse a register, which starts with `$`, or an immediate, see [`parse_immediate`].
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.starts_with('$') {
+            return Ok(RegA::RegName(value.parse()?));
         }
+        Ok(RegA::Imm(parse_immediate(value)?))
     }
 }
-impl From<&RegA> for BFieldElement {
-    fn from(value: &RegA) -> Self {
-        match value {
-            RegA::Imm(imm) => (*imm).into(),
-            RegA::RegName(r) => r.into(),
-        }
+
+/// Parse an immediate, given in decimal or with prefix `0x`, `0b`, or `0o`, and optionally
+/// negated by a leading `-`. Immediates are 32-bit words: negative immediates down to `-2^31` are
+/// stored in two's complement, non-negative ones up to `2^32 - 1` as they are. Instructions
+/// interpret the word as signed or unsigned, like they do with register contents.
+pub fn parse_immediate(value: &str) -> Result<u32, RegParseError> {
+    let (is_negative, magnitude) = match value.strip_prefix('-') {
+        Some(magnitude) => (true, magnitude),
+        None => (false, value),
+    };
+    let (digits, radix) = match magnitude.get(..2) {
+        Some("0x") => (&magnitude[2..], 16),
+        Some("0b") => (&magnitude[2..], 2),
+        Some("0o") => (&magnitude[2..], 8),
+        _ => (magnitude, 10),
+    };
+    // `from_str_radix` accepts a leading `+`, which would allow `-+1`
+    let magnitude = match digits.starts_with('+') {
+        false => u32::from_str_radix(digits, radix).ok(),
+        true => None,
+    };
+    match (is_negative, magnitude) {
+        (false, Some(magnitude)) => Ok(magnitude),
+        (true, Some(magnitude)) if magnitude <= 1 << 31 => Ok(magnitude.wrapping_neg()),
+        _ => Err(RegParseError::InvalidImmediate(value.to_string())),
     }
 }
+
 impl Display for RegA {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match &self {
@@ -247,3 +286,27 @@ impl Display for RegA {
         }
     }
 }
+
+/// Operands of instructions that interpret their immediate as a signed word, like `add` or the
+/// offset of `lw`.
+pub trait DisplaySigned {
+    /// Like [`Display`], but writes an immediate as a signed word, _e.g._, `-8` instead of
+    /// `4294967288`. [`parse_immediate`] turns the result back into the same word.
+    fn fmt_signed(&self, f: &mut Formatter<'_>) -> std::fmt::Result;
+}
+
+impl DisplaySigned for RegA {
+    fn fmt_signed(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            RegA::Imm(imm) => write!(f, "{}", *imm as i32),
+            RegA::RegName(r) => write!(f, "{}", r),
+        }
+    }
+}
+
+/// Operands that have not been parsed yet are written as they are.
+impl DisplaySigned for String {
+    fn fmt_signed(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+}